@@ -1,6 +1,7 @@
 #!/usr/bin/env swift
 
 import Foundation
+import ImageIO
 import Vision
 
 func writeError(_ message: String) {
@@ -69,68 +70,39 @@ guard let observations = textRequest.results, !observations.isEmpty else {
     exit(1)
 }
 
-// Group observations by Y-coordinate to preserve line breaks
-// Observations with similar Y-coordinates are on the same line
-var lineGroups: [[VNRecognizedTextObservation]] = []
-var currentLine: [VNRecognizedTextObservation] = []
-var lastY: CGFloat? = nil
-let yTolerance: CGFloat = 10.0 // Pixels - text within this Y range is considered same line
-
-// Sort observations by Y-coordinate (top to bottom)
-let sortedObservations = observations.sorted { obs1, obs2 in
-    let y1 = obs1.boundingBox.midY
-    let y2 = obs2.boundingBox.midY
-    if abs(y1 - y2) < yTolerance {
-        // Same line, sort by X (left to right)
-        return obs1.boundingBox.minX < obs2.boundingBox.minX
-    }
-    return y1 < y2
-}
-
-for observation in sortedObservations {
-    let currentY = observation.boundingBox.midY
-    
-    if let lastYValue = lastY {
-        // Check if this observation is on a new line
-        if abs(currentY - lastYValue) > yTolerance {
-            // New line detected
-            if !currentLine.isEmpty {
-                lineGroups.append(currentLine)
-                currentLine = []
-            }
-        }
+// Print one fragment per line: left, top, width, height (pixels from the top
+// left corner), confidence and text, separated by tabs. Insight Reader puts
+// the fragments in reading order (line by line, column by column).
+let imageSize: CGSize = {
+    if let source = CGImageSourceCreateWithData(imageData as CFData, nil),
+       let image = CGImageSourceCreateImageAtIndex(source, 0, nil) {
+        return CGSize(width: image.width, height: image.height)
     }
-    
-    currentLine.append(observation)
-    lastY = currentY
-}
-
-// Add the last line
-if !currentLine.isEmpty {
-    lineGroups.append(currentLine)
-}
+    return CGSize(width: 1, height: 1)
+}()
 
-// Extract text from each line group
-var extractedLines: [String] = []
-for lineGroup in lineGroups {
-    var lineTextParts: [String] = []
-    for observation in lineGroup {
-        let topCandidates = observation.topCandidates(1)
-        guard let topCandidate = topCandidates.first else {
-            continue
-        }
-        lineTextParts.append(topCandidate.string)
+var fragments: [String] = []
+for observation in observations {
+    guard let topCandidate = observation.topCandidates(1).first else {
+        continue
     }
-    if !lineTextParts.isEmpty {
-        // Join words on the same line with spaces
-        extractedLines.append(lineTextParts.joined(separator: " "))
+    let text = topCandidate.string
+        .replacingOccurrences(of: "\t", with: " ")
+        .replacingOccurrences(of: "\n", with: " ")
+    if text.trimmingCharacters(in: .whitespaces).isEmpty {
+        continue
     }
+    // Vision's bounding boxes are normalized, with the origin at the bottom left
+    let box = observation.boundingBox
+    let left = box.minX * imageSize.width
+    let top = (1 - box.maxY) * imageSize.height
+    let width = box.width * imageSize.width
+    let height = box.height * imageSize.height
+    fragments.append(String(format: "%.1f\t%.1f\t%.1f\t%.1f\t%.3f\t", left, top, width, height, topCandidate.confidence) + text)
 }
 
-// Join lines with newlines to preserve line breaks
-let extractedText = extractedLines.joined(separator: "\n")
-if extractedText.isEmpty {
+if fragments.isEmpty {
     exit(1)
 }
 
-print(extractedText)
+print(fragments.joined(separator: "\n"))
@@ -6,7 +6,7 @@ use iced::{Element, Point, Size, Subscription, Task};
 use iced::window;
 use tracing::{debug, info};
 
-use crate::model::{App, Message, PlaybackState};
+use crate::model::{App, Message, PlaybackState, WindowRole};
 use crate::update;
 use crate::view;
 
@@ -33,20 +33,24 @@ pub fn new() -> (App, Task<Message>) {
         }
     }
     
+    // Apply the do-not-disturb schedule (and tray badge) right away
+    update::refresh_quiet_hours(&mut app);
+    
     // Initialize hotkey manager (skip if disabled on Wayland/Hyprland)
     if !app.hotkeys_disabled_wayland {
         match crate::system::HotkeyManager::new() {
-            Ok(mut hotkey_manager) => {
-                // Register hotkey if enabled
+            Ok(hotkey_manager) => {
+                app.hotkey_manager = Some(hotkey_manager);
+                // Register hotkeys if enabled; an action whose shortcut is taken is left out
                 if app.hotkey_enabled {
-                    if let Err(e) = hotkey_manager.register(app.hotkey_config.clone()) {
-                        tracing::warn!(error = %e, "Failed to register hotkey, continuing without it");
-                        app.hotkey_enabled = false;
+                    if let Err(e) = update::register_hotkeys(&mut app) {
+                        tracing::warn!(error = %e, "Some hotkeys could not be registered, continuing without them");
+                        // Shown in settings, next to the hotkeys
+                        app.error_message = Some(e);
                     } else {
-                        info!("Hotkey registered successfully");
+                        info!("Hotkeys registered successfully");
                     }
                 }
-                app.hotkey_manager = Some(hotkey_manager);
             }
             Err(e) => {
                 tracing::warn!(error = %e, "Failed to initialize hotkey manager, continuing without it");
@@ -56,12 +60,20 @@ pub fn new() -> (App, Task<Message>) {
     }
     // Note: app.hotkey_manager is already None by default, so no need to set it explicitly
     
+    // What is in use, for crash reports
+    crate::crash_report::set_capability("tts_provider", format!("{:?}", app.selected_backend));
+    crate::crash_report::set_capability("ocr_backend", format!("{:?}", app.selected_ocr_backend));
+    crate::crash_report::set_capability("system_tray", app.system_tray.is_some());
+    crate::crash_report::set_capability("global_hotkey", app.hotkey_manager.is_some() && app.hotkey_enabled);
+    crate::crash_report::set_capability("wayland_hyprland", app.hotkeys_disabled_wayland);
+    crate::crash_report::set_capability("piper_onnx", cfg!(feature = "piper-onnx"));
+    
     info!("App created, opening UI immediately");
     
     // Open the main window (daemon doesn't open one by default)
     // This happens synchronously but is very fast - just window creation
-    let (_main_window_id, open_task) = window::open(window::Settings {
-        size: Size::new(410.0, 70.0),
+    let (main_window_id, open_task) = window::open(window::Settings {
+        size: Size::new(460.0, 70.0),
         resizable: false,
         decorations: false,
         transparent: true,
@@ -78,26 +90,43 @@ pub fn new() -> (App, Task<Message>) {
         ..Default::default()
     });
     let open_task = open_task.map(Message::WindowOpened);
+    app.windows.insert(main_window_id, WindowRole::Main);
+    
+    // `insight-reader path/to/file.txt`: read the file instead of the selection,
+    // `command | insight-reader --stdin`: read what the command prints
+    let file_argument = std::env::args().skip(1).find(|arg| !arg.starts_with('-'));
+    let read_stdin = std::env::args().skip(1).any(|arg| arg == "--stdin");
     
     // Fetch selected text asynchronously after UI appears (non-blocking)
     // This runs in a background task so it doesn't delay the UI
-    let fetch_text_task = Task::perform(
-        async {
-            debug!("Starting async text fetch task");
-            // Use spawn_blocking for the blocking shell command
-            let result = tokio::task::spawn_blocking(|| {
-                debug!("Executing get_selected_text in blocking thread");
-                crate::system::get_selected_text()
-            })
-            .await;
-            debug!("Text fetch task completed");
-            result.unwrap_or_else(|e| {
-                tracing::warn!(error = %e, "Failed to join blocking task for text fetch");
-                None
-            })
-        },
-        Message::SelectedTextFetched,
-    );
+    let fetch_text_task = if read_stdin {
+        update::read_stdin_task()
+    } else if let Some(path) = file_argument {
+        update::read_file_argument(path.into())
+    } else {
+        let source_app = update::detect_source_app(&app);
+        Task::perform(
+            async move {
+                debug!("Starting async text fetch task");
+                // Use spawn_blocking for the blocking shell command
+                let result = tokio::task::spawn_blocking(move || {
+                    debug!("Executing get_selected_text in blocking thread");
+                    let started = std::time::Instant::now();
+                    let text = crate::system::get_selected_text();
+                    crate::latency::record(crate::latency::Stage::Capture, started.elapsed());
+                    let source_app = source_app.and_then(|detection| detection.join().ok().flatten());
+                    text.map(|text| crate::system::spreadsheet_selection(text, source_app.as_deref()))
+                })
+                .await;
+                debug!("Text fetch task completed");
+                result.unwrap_or_else(|e| {
+                    tracing::warn!(error = %e, "Failed to join blocking task for text fetch");
+                    None
+                })
+            },
+            Message::SelectedTextFetched,
+        )
+    };
     
     // Fetch voices.json asynchronously on startup (Piper voices)
     let fetch_voices_task = Task::perform(
@@ -108,6 +137,10 @@ pub fn new() -> (App, Task<Message>) {
         Message::VoicesJsonLoaded,
     );
     
+    // Point the AWS SDK at the profile and region picked in settings before any client is created
+    crate::voices::aws::set_aws_profile(app.aws_profile.as_deref());
+    crate::voices::aws::set_aws_region(app.aws_region.as_deref());
+    
     // Fetch AWS Polly voices asynchronously on startup (only if AWS credentials are available)
     let fetch_polly_voices_task = Task::perform(
         async {
@@ -123,20 +156,56 @@ pub fn new() -> (App, Task<Message>) {
         Message::PollyVoicesLoaded,
     );
     
-    (app, Task::batch([open_task, fetch_text_task, fetch_voices_task, fetch_polly_voices_task]))
+    // List audio output devices for the settings (device enumeration can be slow)
+    let list_devices_task = Task::perform(
+        async {
+            tokio::task::spawn_blocking(crate::providers::list_output_devices)
+                .await
+                .unwrap_or_default()
+        },
+        Message::OutputDevicesLoaded,
+    );
+    
+    // Detect network/hostname for workspace profiles
+    let detect_network_task = update::detect_network_task();
+    
+    // Set up the virtual microphone early so other apps can select it before reading starts
+    let virtual_mic_task = if app.output.virtual_mic || app.profiles.iter().any(|p| p.output.virtual_mic) {
+        update::prepare_virtual_mic_task()
+    } else {
+        Task::none()
+    };
+    
+    // `--debug-overlay`: show the latency overlay for this run, whatever the setting
+    if std::env::args().skip(1).any(|arg| arg == "--debug-overlay") {
+        app.debug_overlay = true;
+    }
+    let debug_overlay_task = if app.debug_overlay { update::open_debug_overlay(&mut app) } else { Task::none() };
+    
+    // Offer the report if the last run crashed
+    let crash_report_task = match crate::crash_report::take_pending_report() {
+        Some(report) => update::offer_crash_report(&mut app, report),
+        None => Task::none(),
+    };
+    
+    (
+        app,
+        Task::batch([
+            open_task,
+            fetch_text_task,
+            fetch_voices_task,
+            fetch_polly_voices_task,
+            list_devices_task,
+            detect_network_task,
+            virtual_mic_task,
+            crash_report_task,
+            debug_overlay_task,
+        ]),
+    )
 }
 
 pub fn title(app: &App, window: window::Id) -> String {
-    match window {
-        w if app.settings_window_id == Some(w) => "Settings",
-        w if app.voice_selection_window_id == Some(w) => "Select Voice",
-        w if app.polly_info_window_id == Some(w) => "AWS Polly Pricing Information",
-        w if app.screenshot_window_id == Some(w) => "Screenshot",
-        w if app.text_cleanup_info_window_id == Some(w) => "Natural Reading",
-        w if app.extracted_text_dialog_window_id == Some(w) => "Extracted Text",
-        _ => "Insight Reader",
-    }
-    .to_string()
+    app.window_role(window).unwrap_or(WindowRole::Main).title().to_string()
 }
 
 pub fn update(app: &mut App, message: Message) -> Task<Message> {
@@ -144,42 +213,23 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
 }
 
 pub fn view(app: &App, window: window::Id) -> Element<'_, Message> {
-    // Show settings window if this is the settings window
-    if app.settings_window_id == Some(window) {
-        return view::settings_window_view(app);
-    }
-    
-    // Show voice selection window if this is the voice selection window
-    if app.voice_selection_window_id == Some(window) {
-        return view::voice_selection_window_view(app);
-    }
-    
-    // Show AWS Polly info modal if this is the info modal window
-    if app.polly_info_window_id == Some(window) {
-        return view::polly_info_window_view(app);
-    }
-    
-    // Show screenshot viewer if this is the screenshot window
-    if app.screenshot_window_id == Some(window) {
-        return view::screenshot_viewer_view(app);
-    }
-    
-    // Show Better OCR info modal if this is the OCR info modal window
-    if app.ocr_info_window_id == Some(window) {
-        return view::ocr_info_window_view(app);
-    }
-    
-    // Show Natural Reading info modal if this is the Natural Reading info modal window
-    if app.text_cleanup_info_window_id == Some(window) {
-        return view::text_cleanup_info_window_view(app);
-    }
-    
-    // Show extracted text dialog if this is the extracted text dialog window
-    if app.extracted_text_dialog_window_id == Some(window) {
-        return view::extracted_text_dialog_view(app);
+    match app.window_role(window).unwrap_or(WindowRole::Main) {
+        WindowRole::Main => view::main_view(app),
+        WindowRole::Settings => view::settings_window_view(app),
+        WindowRole::VoiceSelection => view::voice_selection_window_view(app),
+        WindowRole::PollyInfo => view::polly_info_window_view(app),
+        WindowRole::Screenshot => view::screenshot_viewer_view(app),
+        WindowRole::OcrInfo => view::ocr_info_window_view(app),
+        WindowRole::TextCleanupInfo => view::text_cleanup_info_window_view(app),
+        WindowRole::ExtractedText => view::extracted_text_dialog_view(app),
+        WindowRole::Queue => view::queue_window_view(app),
+        WindowRole::PhraseBoard => view::phrase_board_window_view(app),
+        WindowRole::CrashReport => view::crash_report_window_view(app),
+        WindowRole::TypeToTalk => view::type_to_talk_window_view(app),
+        WindowRole::ReadLater => view::read_later_window_view(app),
+        WindowRole::DebugOverlay => view::debug_overlay_view(app),
+        WindowRole::Review => view::review_window_view(app),
     }
-    
-    view::main_view(app)
 }
 
 pub fn subscription(app: &App) -> Subscription<Message> {
@@ -192,10 +242,21 @@ pub fn subscription(app: &App) -> Subscription<Message> {
         Message::WindowClosed(id)
     });
     
+    // Files dropped on any window are read
+    let file_dropped = window::events().filter_map(|(_, event)| match event {
+        window::Event::FileDropped(path) => Some(Message::FileDropped(path)),
+        _ => None,
+    });
+    
     // Run animation/polling at ~75ms intervals
-    // Poll when playing, paused, loading, or downloading a voice
-    let tick = match (app.playback_state, app.is_loading, app.downloading_voice.is_some()) {
-        (PlaybackState::Stopped, false, false) => Subscription::none(),
+    // Poll when playing, paused, loading, downloading a voice, between queued items or replaying
+    let tick = match (
+        app.playback_state,
+        app.is_loading,
+        app.downloading_voice.is_some(),
+        app.reading_queue.is_waiting() || app.replay_provider.is_some(),
+    ) {
+        (PlaybackState::Stopped, false, false, false) => Subscription::none(),
         _ => time::every(Duration::from_millis(75)).map(|_| Message::Tick),
     };
     
@@ -215,8 +276,40 @@ pub fn subscription(app: &App) -> Subscription<Message> {
         Subscription::none()
     };
     
+    // Re-check the do-not-disturb schedule every 30 seconds while it is enabled
+    let quiet_hours_poll = if app.quiet_hours_enabled {
+        time::every(Duration::from_secs(30)).map(|_| Message::QuietHoursCheck)
+    } else {
+        Subscription::none()
+    };
+    
+    // While idle and holding an engine or cached audio, check whether it is time to release them
+    let idle_poll = if app.idle_release_minutes.is_some()
+        && app.playback_state == PlaybackState::Stopped
+        && !app.is_loading
+        && update::holds_idle_resources(app)
+    {
+        time::every(Duration::from_secs(30)).map(|_| Message::IdleCheck)
+    } else {
+        Subscription::none()
+    };
+    
+    // Re-detect the network every minute so workspace profiles follow network changes
+    let profile_poll = if app.profiles_auto_switch && !app.profiles.is_empty() {
+        time::every(Duration::from_secs(60)).map(|_| Message::ProfileCheck)
+    } else {
+        Subscription::none()
+    };
+    
+    // Check the watched documents for added paragraphs
+    let watch_poll = if app.watched_documents.is_empty() {
+        Subscription::none()
+    } else {
+        time::every(crate::system::WATCH_INTERVAL).map(|_| Message::CheckWatched)
+    };
+    
     // Subscribe to keyboard events when listening for hotkey input
-    let keyboard_sub = if app.listening_for_hotkey {
+    let keyboard_sub = if app.listening_for_hotkey.is_some() {
         keyboard::listen().filter_map(|event| {
             use iced::keyboard::{key::Named, Event, Key};
             
@@ -241,8 +334,74 @@ pub fn subscription(app: &App) -> Subscription<Message> {
             }
         })
     } else {
-        Subscription::none()
+        // Otherwise, `S` (without modifiers) cycles playback speed presets, `N` skips to
+        // the next queued item, `W` ("wait, what?") replays the last seconds heard,
+        // `P` spells out the word just heard, `L` marks the start and end of a
+        // loop, then clears it, `[` / `]` jump between detected chapters, and `F`
+        // reads a skimmed text in full from the paragraph being skimmed. `B` reads
+        // the next sentence with the comparison voice set in the Piper settings.
+        // Only keys not captured by a focused widget (e.g. text inputs) reach here.
+        keyboard::listen().filter_map(|event| {
+            use iced::keyboard::{Event, Key};
+
+            match event {
+                Event::KeyPressed { key: Key::Character(c), modifiers, .. }
+                    if modifiers.is_empty() && c.eq_ignore_ascii_case("s") =>
+                {
+                    Some(Message::CycleSpeed)
+                }
+                Event::KeyPressed { key: Key::Character(c), modifiers, .. }
+                    if modifiers.is_empty() && c.eq_ignore_ascii_case("n") =>
+                {
+                    Some(Message::NextQueuedItem)
+                }
+                Event::KeyPressed { key: Key::Character(c), modifiers, .. }
+                    if modifiers.is_empty() && c.eq_ignore_ascii_case("l") =>
+                {
+                    Some(Message::LoopMarked)
+                }
+                Event::KeyPressed { key: Key::Character(c), modifiers, .. }
+                    if modifiers.is_empty() && c.eq_ignore_ascii_case("w") =>
+                {
+                    Some(Message::ReplayRecent)
+                }
+                Event::KeyPressed { key: Key::Character(c), modifiers, .. }
+                    if modifiers.is_empty() && c.eq_ignore_ascii_case("p") =>
+                {
+                    Some(Message::SpellWord)
+                }
+                Event::KeyPressed { key: Key::Character(c), modifiers, .. }
+                    if modifiers.is_empty() && c.eq_ignore_ascii_case("f") =>
+                {
+                    Some(Message::ExpandSkim)
+                }
+                Event::KeyPressed { key: Key::Character(c), modifiers, .. }
+                    if modifiers.is_empty() && c.eq_ignore_ascii_case("b") =>
+                {
+                    Some(Message::CompareVoice)
+                }
+                Event::KeyPressed { key: Key::Character(c), modifiers, .. } if modifiers.is_empty() && c == "]" => {
+                    Some(Message::NextChapter)
+                }
+                Event::KeyPressed { key: Key::Character(c), modifiers, .. } if modifiers.is_empty() && c == "[" => {
+                    Some(Message::PreviousChapter)
+                }
+                _ => None,
+            }
+        })
     };
     
-    Subscription::batch(vec![window_opened, window_closed, tick, tray_poll, hotkey_poll, keyboard_sub])
+    Subscription::batch(vec![
+        window_opened,
+        window_closed,
+        file_dropped,
+        tick,
+        tray_poll,
+        hotkey_poll,
+        quiet_hours_poll,
+        idle_poll,
+        profile_poll,
+        watch_poll,
+        keyboard_sub,
+    ])
 }
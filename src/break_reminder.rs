@@ -0,0 +1,101 @@
+//! Break reminders
+//!
+//! Counts how long the user has been listening and, once the configured
+//! interval is reached, has a short reminder spoken ("You've been listening
+//! for 50 minutes. Time for a break."). The reminder is spoken when the
+//! current reading ends, so nothing is cut off mid-sentence, and a pause in
+//! listening as long as a short break starts the count again.
+
+use std::time::{Duration, Instant};
+
+/// Reminder intervals in minutes offered in the playback settings.
+pub const BREAK_INTERVAL_PRESETS: [u32; 3] = [25, 50, 90];
+
+/// Reminder spoken when no custom message is set; `{minutes}` is replaced.
+pub const DEFAULT_BREAK_MESSAGE: &str = "You've been listening for {minutes} minutes. Time for a break.";
+
+/// Not listening for this long counts as having taken a break.
+const BREAK_RESET: Duration = Duration::from_secs(5 * 60);
+
+/// Longest gap between two ticks still counted as continuous listening.
+const MAX_TICK_GAP: Duration = Duration::from_secs(2);
+
+/// Listening time since the last break.
+#[derive(Debug, Default)]
+pub struct BreakTimer {
+    /// Time spent listening since the last break or reminder
+    listened: Duration,
+    /// Last tick at which audio was playing
+    last_listened: Option<Instant>,
+}
+
+impl BreakTimer {
+    /// Audio is playing at `now` (called on every tick during playback).
+    pub fn listening(&mut self, now: Instant) {
+        if let Some(last) = self.last_listened {
+            let gap = now.saturating_duration_since(last);
+            if gap >= BREAK_RESET {
+                self.listened = Duration::ZERO;
+            } else if gap <= MAX_TICK_GAP {
+                self.listened += gap;
+            }
+        }
+        self.last_listened = Some(now);
+    }
+
+    /// Time spent listening since the last break.
+    pub fn listened(&self) -> Duration {
+        self.listened
+    }
+
+    /// Whether a reminder is due at `now`, `interval` being the listening time between reminders.
+    pub fn is_due(&self, interval: Duration, now: Instant) -> bool {
+        let on_break = self
+            .last_listened
+            .is_none_or(|last| now.saturating_duration_since(last) >= BREAK_RESET);
+        !on_break && self.listened >= interval
+    }
+
+    /// A reminder was given: start counting again.
+    pub fn reminded(&mut self) {
+        self.listened = Duration::ZERO;
+    }
+}
+
+/// Text of the reminder: `message` (or the default) with `{minutes}` filled in.
+pub fn reminder_text(message: &str, listened: Duration) -> String {
+    let message = if message.trim().is_empty() { DEFAULT_BREAK_MESSAGE } else { message.trim() };
+    let minutes = (listened.as_secs_f32() / 60.0).round() as u32;
+    message.replace("{minutes}", &minutes.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_break_timer() {
+        let interval = Duration::from_secs(60);
+        let start = Instant::now();
+        let mut timer = BreakTimer::default();
+        for tick in 0..=60 {
+            timer.listening(start + Duration::from_secs(tick));
+        }
+        let now = start + Duration::from_secs(60);
+        assert!(timer.is_due(interval, now));
+        assert_eq!(reminder_text("", timer.listened()), "You've been listening for 1 minutes. Time for a break.");
+        timer.reminded();
+        assert!(!timer.is_due(interval, now));
+
+        // A pause (not ticking) isn't counted; a long one resets the count
+        let mut timer = BreakTimer::default();
+        timer.listening(start);
+        timer.listening(start + Duration::from_secs(1));
+        timer.listening(start + Duration::from_secs(61));
+        assert_eq!(timer.listened(), Duration::from_secs(1));
+        timer.listening(start + Duration::from_secs(62));
+        timer.listening(start + Duration::from_secs(62) + BREAK_RESET);
+        assert_eq!(timer.listened(), Duration::ZERO);
+        assert_eq!(reminder_text(" Stretch after {minutes} min ", Duration::from_secs(3000)), "Stretch after 50 min");
+    }
+}
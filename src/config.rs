@@ -3,7 +3,11 @@
 //! Persists the selected voice provider and log level in a simple JSON file:
 //! `~/.config/insight-reader/config.json` with fields like:
 //! `{ "voice_provider": "piper", "log_level": "INFO" }`.
+//!
+//! Each voice provider keeps its own settings block under `providers`, e.g.
+//! `{ "providers": { "polly": { "voice": "Joanna", "engine": "Neural" } } }`.
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -11,7 +15,16 @@ use std::path::{Path, PathBuf};
 use dirs::config_dir;
 use tracing::{debug, error, warn};
 
-use crate::model::{LogLevel, OCRBackend, TTSBackend};
+use crate::model::{LogLevel, OCRBackend, TTSBackend, TypingEcho, DEFAULT_IDLE_RELEASE_MINUTES, DEFAULT_LARGE_TEXT_KB};
+use crate::providers::synthesis_load::SynthesisLoad;
+use crate::providers::word_timing::PreRoll;
+use crate::providers::{ChannelRouting, CoquiConfig, OpenTTSConfig, OutputSettings};
+use crate::queue::EndOfReading;
+use crate::system::{
+    CalendarSettings, FeedSubscription, FilterRule, HotkeyAction, HotkeyConfig, LexiconEntry, LinkReading,
+    ListEnumeration, ReadLaterSettings, ReadingProfile, TableReading, TextProcessingOptions, TransformStep,
+    TranscriptFormat, TranscriptMode, UrlReading,
+};
 
 const APP_CONFIG_DIR_NAME: &str = "insight-reader";
 const CONFIG_FILE_NAME: &str = "config.json";
@@ -45,7 +58,7 @@ impl From<serde_json::Error> for ConfigError {
 
 #[derive(Debug, serde::Serialize, serde::Deserialize, Default)]
 struct RawConfig {
-    /// Voice provider name ("piper" or "polly").
+    /// Voice provider name ("piper", "polly", "windows", "coqui", "opentts" or "command").
     #[serde(default)]
     voice_provider: Option<String>,
 
@@ -53,21 +66,48 @@ struct RawConfig {
     #[serde(default)]
     log_level: Option<String>,
 
+    /// Whether the latency overlay is shown (also `--debug-overlay` for one run).
+    #[serde(default)]
+    debug_overlay: Option<bool>,
+
     /// Whether Natural Reading is enabled (sends text to cloud service before TTS).
     #[serde(default)]
     text_cleanup_enabled: Option<bool>,
 
-    /// Selected Piper voice key (e.g., "en_US-lessac-medium").
+    /// Transforms run in order when Natural Reading is enabled (unset = the Natural Reading service).
     #[serde(default)]
-    selected_voice: Option<String>,
-    /// Selected AWS Polly voice ID (e.g., "Matthew", "Joanna").
+    text_transforms: Option<Vec<TransformStep>>,
+
+    /// Settings of each voice provider, one block per provider.
     #[serde(default)]
+    providers: ProviderSettings,
+
+    /// Legacy Piper voice key, moved into `providers.piper` on load.
+    #[serde(default, skip_serializing)]
+    selected_voice: Option<String>,
+    /// Legacy AWS Polly voice key, moved into `providers.polly` on load.
+    #[serde(default, skip_serializing)]
     selected_polly_voice: Option<String>,
+    /// Legacy AWS profile, moved into `providers.polly` on load.
+    #[serde(default, skip_serializing)]
+    aws_profile: Option<String>,
 
-    /// OCR backend name ("default" or "better_ocr").
+    /// OCR backend name ("default", "tesseract", "remote" or "better_ocr").
     #[serde(default)]
     ocr_backend: Option<String>,
 
+    /// URL captures are posted to by the remote OCR backend.
+    #[serde(default)]
+    ocr_endpoint: Option<String>,
+
+    /// Whether captures are read as a single column (no column detection).
+    #[serde(default)]
+    ocr_single_column: Option<bool>,
+
+    /// Whether repeated captures only read the lines not read from earlier ones.
+    #[serde(default)]
+    ocr_new_lines_only: Option<bool>,
+
     /// Hotkey enabled flag.
     #[serde(default)]
     hotkey_enabled: Option<bool>,
@@ -79,6 +119,346 @@ struct RawConfig {
     /// Hotkey key code (e.g., "r", "t", "space").
     #[serde(default)]
     hotkey_key: Option<String>,
+
+    /// Pause/resume hotkey modifiers (same format as `hotkey_modifiers`).
+    #[serde(default)]
+    pause_hotkey_modifiers: Option<String>,
+
+    /// Pause/resume hotkey key code (same format as `hotkey_key`).
+    #[serde(default)]
+    pause_hotkey_key: Option<String>,
+
+    /// Stop hotkey modifiers (same format as `hotkey_modifiers`).
+    #[serde(default)]
+    stop_hotkey_modifiers: Option<String>,
+
+    /// Stop hotkey key code (same format as `hotkey_key`).
+    #[serde(default)]
+    stop_hotkey_key: Option<String>,
+
+    /// List item enumeration style ("off", "ordinal" or "bullet").
+    #[serde(default)]
+    list_enumeration: Option<String>,
+
+    /// Hyperlink reading policy ("text", "text_link" or "text_domain").
+    #[serde(default)]
+    link_reading: Option<String>,
+
+    /// How URLs written in the text are read ("full", "no_query" or "domain").
+    #[serde(default)]
+    url_reading: Option<String>,
+
+    /// How markdown and HTML tables are read ("rows", "skip" or "as_written").
+    #[serde(default)]
+    table_reading: Option<String>,
+
+    /// Whether image alt text is read aloud as "Image: <alt>".
+    #[serde(default)]
+    read_image_alt_text: Option<bool>,
+
+    /// Whether copied HTML markup is read as text, tags stripped (unset = true).
+    #[serde(default)]
+    strip_html: Option<bool>,
+
+    /// Regex search/replace rules applied before synthesis, in order.
+    #[serde(default)]
+    filter_rules: Option<Vec<FilterRule>>,
+
+    /// Pronunciation lexicon entries (word → alias/phoneme).
+    #[serde(default)]
+    lexicon: Option<Vec<LexiconEntry>>,
+
+    /// Written forms of the abbreviation rules turned off (e.g. ["Dr.", "km"]).
+    #[serde(default)]
+    disabled_abbreviations: Option<Vec<String>>,
+
+    /// Whether citation markers, footnotes and reference sections are skipped (unset = true).
+    #[serde(default)]
+    skip_citations: Option<bool>,
+
+    /// Whether unknown all-caps acronyms are spelled out letter by letter.
+    #[serde(default)]
+    spell_acronyms: Option<bool>,
+
+    /// Whether numbers and dates are rewritten the way the voice's locale reads them (unset = true).
+    #[serde(default)]
+    localize_numbers: Option<bool>,
+
+    /// Whether cells selected in a spreadsheet are read row by row (unset = true).
+    #[serde(default)]
+    spreadsheet_cells: Option<bool>,
+
+    /// Playback speed multiplier (e.g., 1.0, 1.25, 1.5, 2.0).
+    #[serde(default)]
+    playback_speed: Option<f32>,
+    /// Speed adjustments by language family, as factors of the playback speed (e.g. {"de": 0.8}).
+    #[serde(default)]
+    language_speeds: Option<std::collections::HashMap<String, f32>>,
+    /// Pitch offset in semitones (unset = 0).
+    #[serde(default)]
+    pitch_semitones: Option<f32>,
+    /// "Finish within" target in minutes for speed suggestions (unset = off).
+    #[serde(default)]
+    finish_target_minutes: Option<u32>,
+    /// Pause between queued items in seconds (0 = none).
+    #[serde(default)]
+    queue_pause_seconds: Option<u32>,
+    /// What happens when a reading ends: "close", "stay_open", "next", "hook" or "tray" (unset = close).
+    #[serde(default)]
+    end_of_reading: Option<String>,
+    /// Shell command run when a reading ends with the "hook" action.
+    #[serde(default)]
+    end_of_reading_hook: Option<String>,
+    /// Where playback picks up after a pause or a skip ("off", "sentence" or seconds like "2s").
+    #[serde(default)]
+    pre_roll: Option<String>,
+    /// Whether only headings and the first sentence of each paragraph are read.
+    #[serde(default)]
+    skim_mode: Option<bool>,
+    /// Whether captured selections are shown for editing before they are read.
+    #[serde(default)]
+    review_before_reading: Option<bool>,
+    /// Idle minutes after which the engine, audio output and cached audio are released (0 = never, unset = 15).
+    #[serde(default)]
+    idle_release_minutes: Option<u32>,
+    /// Size in KB above which captured selections are read only after confirmation (0 = never ask, unset = 50).
+    #[serde(default)]
+    large_text_limit_kb: Option<u32>,
+    /// Listening minutes between break reminders (unset = off).
+    #[serde(default)]
+    break_interval_minutes: Option<u32>,
+    /// Break reminder text; `{minutes}` is replaced (unset = default text).
+    #[serde(default)]
+    break_message: Option<String>,
+    /// Whether the do-not-disturb schedule is enabled.
+    #[serde(default)]
+    quiet_hours_enabled: Option<bool>,
+    /// Quiet hours ranges (e.g., "22:00-07:00, 12:30-13:00").
+    #[serde(default)]
+    quiet_hours: Option<String>,
+    /// Whether readings are also saved as audio files.
+    #[serde(default)]
+    save_readings: Option<bool>,
+    /// Folder for saved readings (unset = default music folder).
+    #[serde(default)]
+    recordings_folder: Option<String>,
+    /// Folder synced to a phone that readings are handed off to (unset = off).
+    #[serde(default)]
+    handoff_folder: Option<String>,
+    /// Webhook a summary of each finished reading is POSTed to (unset = off).
+    #[serde(default)]
+    listen_webhook: Option<String>,
+    /// Whether the webhook summary includes the text that was read.
+    #[serde(default)]
+    listen_webhook_text: Option<bool>,
+    /// Transcript log of readings: "off", "excerpt" or "full" (unset = off).
+    #[serde(default)]
+    transcript: Option<String>,
+    /// Transcript file format: "markdown" or "jsonl" (unset = markdown).
+    #[serde(default)]
+    transcript_format: Option<String>,
+    /// Subscribed RSS/Atom feeds for the morning briefing.
+    #[serde(default)]
+    feeds: Option<Vec<FeedSubscription>>,
+    /// Ids of feed items already read in a briefing (most recent last).
+    #[serde(default)]
+    heard_feed_items: Option<Vec<String>>,
+    /// URLs and files watched for added paragraphs.
+    #[serde(default)]
+    watched_documents: Option<Vec<String>>,
+    /// Read-later service (Wallabag, Readwise Reader) connection settings.
+    #[serde(default)]
+    read_later: Option<ReadLaterSettings>,
+    /// Calendar read by the agenda action.
+    #[serde(default)]
+    calendar: Option<CalendarSettings>,
+    /// Folder holding voice models and caches (unset = default data and cache folders).
+    #[serde(default)]
+    storage_folder: Option<String>,
+    /// Whether synthesized readings are cached on disk (unset = on).
+    #[serde(default)]
+    audio_cache: Option<bool>,
+    /// Size limit of the audio cache in megabytes (unset = default).
+    #[serde(default)]
+    audio_cache_limit_mb: Option<u32>,
+    /// Hours screenshot captures are kept after OCR (unset = deleted right away).
+    #[serde(default)]
+    capture_retention_hours: Option<u32>,
+
+    /// Audio output device name (unset = system default).
+    #[serde(default)]
+    output_device: Option<String>,
+    /// Playback volume (0.0 - 1.0).
+    #[serde(default)]
+    volume: Option<f32>,
+    /// Second output device that mirrors playback (unset = off).
+    #[serde(default)]
+    mirror_output_device: Option<String>,
+    /// Whether playback is also sent into the virtual microphone.
+    #[serde(default)]
+    virtual_mic: Option<bool>,
+    /// Whether every voice is brought to the same loudness (unset = on).
+    #[serde(default)]
+    normalize_loudness: Option<bool>,
+    /// Whether long silences between sentences are shortened.
+    #[serde(default)]
+    shorten_pauses: Option<bool>,
+    /// Side speech is played on ("both", "left" or "right"; unset = both).
+    #[serde(default)]
+    output_channels: Option<String>,
+    /// Whether workspace profiles switch automatically by network/hostname.
+    #[serde(default)]
+    profiles_auto_switch: Option<bool>,
+    /// Workspace profiles (name, matched SSIDs/hostnames, output settings).
+    #[serde(default)]
+    profiles: Option<Vec<ReadingProfile>>,
+
+    /// Legacy Coqui server URL, moved into `providers.coqui` on load.
+    #[serde(default, skip_serializing)]
+    coqui_url: Option<String>,
+    /// Legacy Coqui speaker ID, moved into `providers.coqui` on load.
+    #[serde(default, skip_serializing)]
+    coqui_speaker: Option<String>,
+    /// Legacy OpenTTS server URL, moved into `providers.opentts` on load.
+    #[serde(default, skip_serializing)]
+    opentts_url: Option<String>,
+    /// Legacy OpenTTS voice name, moved into `providers.opentts` on load.
+    #[serde(default, skip_serializing)]
+    opentts_voice: Option<String>,
+
+    /// Phrase board entries in display order.
+    #[serde(default)]
+    phrases: Option<Vec<String>>,
+
+    /// What is spoken while typing in the type-to-talk window ("off", "characters" or "words").
+    #[serde(default)]
+    typing_echo: Option<String>,
+}
+
+/// Per-provider settings: each voice provider stores its own block.
+///
+/// Blocks of providers this version doesn't know (e.g. API keys saved by a
+/// newer version) are kept as they are when the config is saved.
+#[derive(Debug, serde::Serialize, serde::Deserialize, Default)]
+struct ProviderSettings {
+    #[serde(default)]
+    piper: PiperSettings,
+    #[serde(default)]
+    polly: PollySettings,
+    #[serde(default)]
+    coqui: ServerSettings,
+    #[serde(default)]
+    opentts: ServerSettings,
+    #[serde(default)]
+    command: CommandSettings,
+    #[serde(flatten)]
+    other: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Default)]
+struct PiperSettings {
+    /// Voice key (e.g., "en_US-lessac-medium").
+    #[serde(default)]
+    voice: Option<String>,
+    /// Whether passages in other languages are read with their own voice.
+    #[serde(default)]
+    mixed_language: Option<bool>,
+    /// Voice key per language code, recorded whenever a voice is selected.
+    #[serde(default)]
+    language_voices: BTreeMap<String, String>,
+    /// Whether each text is read with the voice for its detected language.
+    #[serde(default)]
+    auto_language: Option<bool>,
+    /// Voice key of the "B" voice the next sentence is read with for comparison.
+    #[serde(default)]
+    compare_voice: Option<String>,
+    /// How much of the machine synthesis may take ("normal", "background" or "eco").
+    #[serde(default)]
+    synthesis_load: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Default)]
+struct PollySettings {
+    /// Voice ID (e.g., "Matthew", "Joanna").
+    #[serde(default)]
+    voice: Option<String>,
+    /// Engine of the voice ("Standard", "Neural", "Generative" or "LongForm").
+    #[serde(default)]
+    engine: Option<String>,
+    /// AWS region (unset = from the environment or ~/.aws/config).
+    #[serde(default)]
+    region: Option<String>,
+    /// AWS profile (unset = AWS_PROFILE from the environment).
+    #[serde(default)]
+    profile: Option<String>,
+    /// Cost in US cents above which a synthesis is flagged (0 = off, unset = default).
+    #[serde(default)]
+    cost_warning_cents: Option<u32>,
+}
+
+/// Settings of an HTTP TTS server provider (Coqui, OpenTTS).
+#[derive(Debug, serde::Serialize, serde::Deserialize, Default)]
+struct ServerSettings {
+    /// Server base URL.
+    #[serde(default)]
+    url: Option<String>,
+    /// Coqui speaker ID or OpenTTS voice name.
+    #[serde(default)]
+    voice: Option<String>,
+}
+
+/// Settings of the external command provider (the voices themselves are
+/// defined in `command-voices.toml`).
+#[derive(Debug, serde::Serialize, serde::Deserialize, Default)]
+struct CommandSettings {
+    /// Name of the selected command voice.
+    #[serde(default)]
+    voice: Option<String>,
+}
+
+impl RawConfig {
+    /// Move provider settings saved by older versions as top-level keys into
+    /// the provider blocks. Saving drops the old keys.
+    fn migrate_legacy_provider_settings(&mut self) {
+        let providers = &mut self.providers;
+        if let Some(voice) = self.selected_voice.take() {
+            providers.piper.voice.get_or_insert(voice);
+        }
+        if let Some(key) = self.selected_polly_voice.take() {
+            if providers.polly.voice.is_none() {
+                let (voice, engine) = split_polly_voice_key(&key);
+                providers.polly.voice = Some(voice);
+                providers.polly.engine = engine;
+            }
+        }
+        if let Some(profile) = self.aws_profile.take() {
+            providers.polly.profile.get_or_insert(profile);
+        }
+        if let Some(url) = self.coqui_url.take() {
+            providers.coqui.url.get_or_insert(url);
+        }
+        if let Some(speaker) = self.coqui_speaker.take() {
+            providers.coqui.voice.get_or_insert(speaker);
+        }
+        if let Some(url) = self.opentts_url.take() {
+            providers.opentts.url.get_or_insert(url);
+        }
+        if let Some(voice) = self.opentts_voice.take() {
+            providers.opentts.voice.get_or_insert(voice);
+        }
+    }
+}
+
+/// Split a Polly voice key ("Joanna:Neural") into voice ID and engine.
+fn split_polly_voice_key(key: &str) -> (String, Option<String>) {
+    match key.split_once(':') {
+        Some((voice, engine)) => (voice.to_string(), Some(engine.to_string())),
+        None => (key.to_string(), None),
+    }
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|s| !s.is_empty())
 }
 
 fn config_path() -> Option<PathBuf> {
@@ -93,6 +473,30 @@ fn ensure_config_dir_exists(path: &Path) -> io::Result<()> {
     Ok(())
 }
 
+/// Default settings an administrator provisioned in the system-wide install.
+///
+/// Used until the user saves their own settings, which then start from these.
+fn load_system_defaults() -> RawConfig {
+    let Some(path) = crate::storage::find_system_file(Path::new(CONFIG_FILE_NAME)) else {
+        debug!("Config file does not exist, using defaults");
+        return RawConfig::default();
+    };
+    let parsed = fs::read_to_string(&path)
+        .map_err(ConfigError::from)
+        .and_then(|data| serde_json::from_str::<RawConfig>(&data).map_err(ConfigError::from));
+    match parsed {
+        Ok(mut cfg) => {
+            cfg.migrate_legacy_provider_settings();
+            debug!(?path, "Using system-wide default settings");
+            cfg
+        }
+        Err(e) => {
+            warn!(?path, error = %e, "Invalid system-wide settings, using defaults");
+            RawConfig::default()
+        }
+    }
+}
+
 fn load_raw_config() -> Result<RawConfig, ConfigError> {
     let Some(path) = config_path() else {
         // No config directory available on this platform; treat as empty config.
@@ -101,12 +505,12 @@ fn load_raw_config() -> Result<RawConfig, ConfigError> {
     };
 
     if !path.exists() {
-        debug!(?path, "Config file does not exist, using defaults");
-        return Ok(RawConfig::default());
+        return Ok(load_system_defaults());
     }
 
     let data = fs::read_to_string(&path)?;
-    let cfg = serde_json::from_str(&data)?;
+    let mut cfg: RawConfig = serde_json::from_str(&data)?;
+    cfg.migrate_legacy_provider_settings();
     debug!(?path, "Config loaded");
     Ok(cfg)
 }
@@ -120,13 +524,30 @@ fn save_raw_config(mut cfg: RawConfig) -> Result<(), ConfigError> {
 
     ensure_config_dir_exists(&path)?;
     // Normalize by dropping empty strings if present.
-    cfg.selected_polly_voice = cfg.selected_polly_voice.filter(|s| !s.is_empty());
     cfg.voice_provider = cfg.voice_provider.filter(|s| !s.is_empty());
     cfg.log_level = cfg.log_level.filter(|s| !s.is_empty());
-    cfg.selected_voice = cfg.selected_voice.filter(|s| !s.is_empty());
+    let providers = &mut cfg.providers;
+    providers.piper.voice = non_empty(providers.piper.voice.take());
+    providers.polly.voice = non_empty(providers.polly.voice.take());
+    providers.polly.engine = non_empty(providers.polly.engine.take());
+    providers.polly.region = non_empty(providers.polly.region.take());
+    providers.polly.profile = non_empty(providers.polly.profile.take());
+    for server in [&mut providers.coqui, &mut providers.opentts] {
+        server.url = non_empty(server.url.take());
+        server.voice = non_empty(server.voice.take());
+    }
     cfg.ocr_backend = cfg.ocr_backend.filter(|s| !s.is_empty());
     cfg.hotkey_modifiers = cfg.hotkey_modifiers.filter(|s| !s.is_empty());
     cfg.hotkey_key = cfg.hotkey_key.filter(|s| !s.is_empty());
+    cfg.list_enumeration = cfg.list_enumeration.filter(|s| !s.is_empty());
+    cfg.link_reading = cfg.link_reading.filter(|s| !s.is_empty());
+    cfg.url_reading = cfg.url_reading.filter(|s| !s.is_empty());
+    cfg.quiet_hours = cfg.quiet_hours.filter(|s| !s.is_empty());
+    cfg.recordings_folder = cfg.recordings_folder.filter(|s| !s.is_empty());
+    cfg.handoff_folder = cfg.handoff_folder.filter(|s| !s.is_empty());
+    cfg.listen_webhook = cfg.listen_webhook.filter(|s| !s.is_empty());
+    cfg.output_device = cfg.output_device.filter(|s| !s.is_empty());
+    cfg.mirror_output_device = cfg.mirror_output_device.filter(|s| !s.is_empty());
 
     let data = serde_json::to_string_pretty(&cfg)?;
     fs::write(&path, data)?;
@@ -134,10 +555,14 @@ fn save_raw_config(mut cfg: RawConfig) -> Result<(), ConfigError> {
     Ok(())
 }
 
-fn backend_from_str(s: &str) -> Option<TTSBackend> {
+pub(crate) fn backend_from_str(s: &str) -> Option<TTSBackend> {
     match s {
         "piper" => Some(TTSBackend::Piper),
         "polly" => Some(TTSBackend::AwsPolly),
+        "windows" => Some(TTSBackend::WindowsSpeech),
+        "coqui" => Some(TTSBackend::Coqui),
+        "opentts" => Some(TTSBackend::OpenTTS),
+        "command" => Some(TTSBackend::Command),
         _ => None,
     }
 }
@@ -146,6 +571,10 @@ fn backend_to_str(backend: TTSBackend) -> &'static str {
     match backend {
         TTSBackend::Piper => "piper",
         TTSBackend::AwsPolly => "polly",
+        TTSBackend::WindowsSpeech => "windows",
+        TTSBackend::Coqui => "coqui",
+        TTSBackend::OpenTTS => "opentts",
+        TTSBackend::Command => "command",
     }
 }
 
@@ -170,17 +599,29 @@ fn log_level_to_str(level: LogLevel) -> &'static str {
     }
 }
 
-/// Load the persisted voice provider, defaulting to Piper if not set or invalid.
+/// Default voice provider for this platform.
+///
+/// Windows ships a native speech synthesizer, so it works without downloading
+/// a Piper voice first; other platforms default to Piper.
+fn default_backend() -> TTSBackend {
+    if cfg!(target_os = "windows") {
+        TTSBackend::WindowsSpeech
+    } else {
+        TTSBackend::Piper
+    }
+}
+
+/// Load the persisted voice provider, defaulting to the platform default if not set or invalid.
 pub fn load_voice_provider() -> TTSBackend {
     let backend = match load_raw_config() {
         Ok(cfg) => cfg
             .voice_provider
             .as_deref()
             .and_then(backend_from_str)
-            .unwrap_or(TTSBackend::Piper),
+            .unwrap_or_else(default_backend),
         Err(err) => {
             warn!(error = ?err, "Failed to load config, using default backend");
-            TTSBackend::Piper
+            default_backend()
         }
     };
     debug!(?backend, "Loaded voice provider");
@@ -238,6 +679,29 @@ pub fn save_log_level(level: LogLevel) {
     }
 }
 
+/// Load whether the latency overlay is shown, defaulting to `false`.
+pub fn load_debug_overlay() -> bool {
+    match load_raw_config() {
+        Ok(cfg) => cfg.debug_overlay.unwrap_or(false),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, debug overlay hidden");
+            false
+        }
+    }
+}
+
+/// Persist whether the latency overlay is shown.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_debug_overlay(enabled: bool) {
+    debug!(enabled, "Saving debug overlay setting");
+    let mut cfg = load_or_default_config();
+    cfg.debug_overlay = Some(enabled);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
 /// Load the persisted Natural Reading enabled setting, defaulting to `false` if not set.
 pub fn load_text_cleanup_enabled() -> bool {
     match load_raw_config() {
@@ -261,10 +725,33 @@ pub fn save_text_cleanup_enabled(enabled: bool) {
     }
 }
 
+/// Load the transform chain, defaulting to the Natural Reading service alone.
+pub fn load_text_transforms() -> Vec<TransformStep> {
+    match load_raw_config() {
+        Ok(cfg) => cfg.text_transforms.unwrap_or_else(|| vec![TransformStep::NaturalReading]),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using Natural Reading as the only transform");
+            vec![TransformStep::NaturalReading]
+        }
+    }
+}
+
+/// Persist the transform chain.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_text_transforms(steps: &[TransformStep]) {
+    debug!(count = steps.len(), "Saving text transforms");
+    let mut cfg = load_or_default_config();
+    cfg.text_transforms = Some(steps.to_vec());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
 /// Load the persisted selected voice, returning None if not set or invalid.
 pub fn load_selected_voice() -> Option<String> {
     match load_raw_config() {
-        Ok(cfg) => cfg.selected_voice.filter(|s| !s.is_empty()),
+        Ok(cfg) => cfg.providers.piper.voice,
         Err(err) => {
             warn!(error = ?err, "Failed to load config, no voice selected");
             None
@@ -278,107 +765,392 @@ pub fn load_selected_voice() -> Option<String> {
 pub fn save_selected_voice(voice_key: String) {
     debug!(voice_key = %voice_key, "Saving selected voice");
     let mut cfg = load_or_default_config();
-    cfg.selected_voice = Some(voice_key);
+    cfg.providers.piper.voice = Some(voice_key);
     if let Err(err) = save_raw_config(cfg) {
         error!(error = ?err, "Failed to save config");
     }
 }
 
-/// Load the persisted selected AWS Polly voice, returning None if not set or invalid.
-pub fn load_selected_polly_voice() -> Option<String> {
+/// Load the name of the selected command voice.
+pub fn load_command_voice() -> Option<String> {
     match load_raw_config() {
-        Ok(cfg) => cfg.selected_polly_voice.filter(|s| !s.is_empty()),
+        Ok(cfg) => cfg.providers.command.voice,
         Err(err) => {
-            warn!(error = ?err, "Failed to load config, no AWS voice selected");
+            warn!(error = ?err, "Failed to load config, no command voice selected");
             None
         }
     }
 }
 
-/// Persist the selected AWS Polly voice to disk.
+/// Persist the selected command voice.
 ///
 /// Errors are logged and otherwise ignored.
-pub fn save_selected_polly_voice(voice_id: String) {
-    debug!(voice_id = %voice_id, "Saving selected AWS Polly voice");
+pub fn save_command_voice(name: &str) {
+    debug!(name, "Saving command voice");
     let mut cfg = load_or_default_config();
-    cfg.selected_polly_voice = Some(voice_id);
+    cfg.providers.command.voice = Some(name.to_string());
     if let Err(err) = save_raw_config(cfg) {
         error!(error = ?err, "Failed to save config");
     }
 }
 
-fn ocr_backend_from_str(s: &str) -> Option<OCRBackend> {
-    match s {
-        "default" => Some(OCRBackend::Default),
-        "better_ocr" => Some(OCRBackend::BetterOCR),
-        _ => None,
+/// Load the mixed-language setting and the Piper voice set for each language.
+pub fn load_language_voices() -> (bool, BTreeMap<String, String>) {
+    match load_raw_config() {
+        Ok(cfg) => {
+            let piper = cfg.providers.piper;
+            (piper.mixed_language.unwrap_or(false), piper.language_voices)
+        }
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, mixed-language reading disabled");
+            (false, BTreeMap::new())
+        }
     }
 }
 
-fn ocr_backend_to_str(backend: OCRBackend) -> &'static str {
-    match backend {
-        OCRBackend::Default => "default",
-        OCRBackend::BetterOCR => "better_ocr",
+/// Persist the mixed-language setting and per-language Piper voices.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_language_voices(enabled: bool, voices: &BTreeMap<String, String>) {
+    debug!(enabled, count = voices.len(), "Saving language voices");
+    let mut cfg = load_or_default_config();
+    cfg.providers.piper.mixed_language = Some(enabled);
+    cfg.providers.piper.language_voices = voices.clone();
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
     }
 }
 
-/// Load the persisted OCR backend, defaulting to `Default` if not set.
-pub fn load_ocr_backend() -> OCRBackend {
+/// Load whether texts are read with the Piper voice for their detected language.
+pub fn load_auto_language() -> bool {
     match load_raw_config() {
-        Ok(cfg) => {
-            cfg.ocr_backend
-                .and_then(|s| ocr_backend_from_str(&s))
-                .unwrap_or(OCRBackend::Default)
-        }
+        Ok(cfg) => cfg.providers.piper.auto_language.unwrap_or(false),
         Err(err) => {
-            warn!(error = ?err, "Failed to load config, using default OCR backend");
-            OCRBackend::Default
+            warn!(error = ?err, "Failed to load config, automatic language voices disabled");
+            false
         }
     }
 }
 
-/// Persist the OCR backend to disk.
+/// Persist whether texts are read with the Piper voice for their detected language.
 ///
 /// Errors are logged and otherwise ignored.
-pub fn save_ocr_backend(backend: OCRBackend) {
-    debug!(?backend, "Saving OCR backend");
+pub fn save_auto_language(enabled: bool) {
+    debug!(enabled, "Saving automatic language voice setting");
     let mut cfg = load_or_default_config();
-    cfg.ocr_backend = Some(ocr_backend_to_str(backend).to_string());
+    cfg.providers.piper.auto_language = Some(enabled);
     if let Err(err) = save_raw_config(cfg) {
         error!(error = ?err, "Failed to save config");
     }
 }
 
-use crate::system::HotkeyConfig;
-
-fn modifiers_to_string(modifiers: global_hotkey::hotkey::Modifiers) -> String {
-    use global_hotkey::hotkey::Modifiers;
-    let mut parts = Vec::new();
-    // Check for common modifier flags
-    if modifiers.contains(Modifiers::SHIFT) {
-        parts.push("shift");
-    }
-    if modifiers.contains(Modifiers::ALT) {
-        parts.push("alt");
-    }
-    if modifiers.contains(Modifiers::CONTROL) {
-        parts.push("control");
-    }
-    // META is used for Command on macOS
-    #[cfg(target_os = "macos")]
-    if modifiers.contains(Modifiers::META) {
-        parts.push("command");
-    }
-    #[cfg(not(target_os = "macos"))]
-    if modifiers.contains(Modifiers::META) {
-        parts.push("meta");
+/// Load the voice key of the comparison ("B") voice, empty if none is set.
+pub fn load_compare_voice() -> String {
+    match load_raw_config() {
+        Ok(cfg) => cfg.providers.piper.compare_voice.unwrap_or_default(),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, no comparison voice");
+            String::new()
+        }
     }
-    parts.join(",")
 }
 
-fn string_to_modifiers(s: &str) -> global_hotkey::hotkey::Modifiers {
-    use global_hotkey::hotkey::Modifiers;
-    let mut modifiers = Modifiers::empty();
+/// Persist the voice key of the comparison ("B") voice (empty clears it).
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_compare_voice(voice_key: &str) {
+    debug!(voice_key, "Saving comparison voice");
+    let mut cfg = load_or_default_config();
+    cfg.providers.piper.compare_voice = Some(voice_key.to_string()).filter(|key| !key.is_empty());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load how much of the machine Piper synthesis may take.
+pub fn load_synthesis_load() -> SynthesisLoad {
+    match load_raw_config() {
+        Ok(cfg) => match cfg.providers.piper.synthesis_load.as_deref() {
+            Some("background") => SynthesisLoad::Background,
+            Some("eco") => SynthesisLoad::Eco,
+            _ => SynthesisLoad::Normal,
+        },
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using normal synthesis load");
+            SynthesisLoad::Normal
+        }
+    }
+}
+
+/// Persist how much of the machine Piper synthesis may take.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_synthesis_load(load: SynthesisLoad) {
+    debug!(?load, "Saving synthesis load");
+    let mut cfg = load_or_default_config();
+    cfg.providers.piper.synthesis_load = Some(
+        match load {
+            SynthesisLoad::Normal => "normal",
+            SynthesisLoad::Background => "background",
+            SynthesisLoad::Eco => "eco",
+        }
+        .to_string(),
+    );
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the persisted selected AWS Polly voice, returning None if not set or invalid.
+pub fn load_selected_polly_voice() -> Option<String> {
+    match load_raw_config() {
+        Ok(cfg) => {
+            let polly = cfg.providers.polly;
+            polly.voice.map(|voice| match polly.engine {
+                Some(engine) => format!("{voice}:{engine}"),
+                None => voice,
+            })
+        }
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, no AWS voice selected");
+            None
+        }
+    }
+}
+
+/// Persist the selected AWS Polly voice to disk.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_selected_polly_voice(voice_id: String) {
+    debug!(voice_id = %voice_id, "Saving selected AWS Polly voice");
+    let mut cfg = load_or_default_config();
+    let (voice, engine) = split_polly_voice_key(&voice_id);
+    cfg.providers.polly.voice = Some(voice);
+    cfg.providers.polly.engine = engine;
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the AWS profile picked for Polly, returning None to use the environment's.
+pub fn load_aws_profile() -> Option<String> {
+    match load_raw_config() {
+        Ok(cfg) => cfg.providers.polly.profile,
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using AWS profile from environment");
+            None
+        }
+    }
+}
+
+/// Persist the AWS profile picked for Polly (None = use the environment's).
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_aws_profile(profile: Option<&str>) {
+    debug!(?profile, "Saving AWS profile");
+    let mut cfg = load_or_default_config();
+    cfg.providers.polly.profile = profile.map(str::to_string);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the AWS region picked for Polly, returning None to use the environment's.
+pub fn load_aws_region() -> Option<String> {
+    match load_raw_config() {
+        Ok(cfg) => cfg.providers.polly.region,
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using AWS region from environment");
+            None
+        }
+    }
+}
+
+/// Persist the AWS region picked for Polly (None = use the environment's).
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_aws_region(region: Option<&str>) {
+    debug!(?region, "Saving AWS region");
+    let mut cfg = load_or_default_config();
+    cfg.providers.polly.region = region.map(str::to_string);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Polly cost warning threshold used until the user picks one, in US cents.
+const DEFAULT_POLLY_COST_WARNING_CENTS: u32 = 50;
+
+/// Load the Polly cost warning threshold in US cents, or `None` if warnings are off.
+pub fn load_polly_cost_warning_cents() -> Option<u32> {
+    match load_raw_config() {
+        Ok(cfg) => cfg
+            .providers
+            .polly
+            .cost_warning_cents
+            .map_or(Some(DEFAULT_POLLY_COST_WARNING_CENTS), |cents| (cents > 0).then_some(cents)),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default Polly cost warning");
+            Some(DEFAULT_POLLY_COST_WARNING_CENTS)
+        }
+    }
+}
+
+/// Persist the Polly cost warning threshold (`None` turns warnings off).
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_polly_cost_warning_cents(cents: Option<u32>) {
+    debug!(?cents, "Saving Polly cost warning threshold");
+    let mut cfg = load_or_default_config();
+    cfg.providers.polly.cost_warning_cents = Some(cents.unwrap_or(0));
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+fn ocr_backend_from_str(s: &str) -> Option<OCRBackend> {
+    match s {
+        "default" => Some(OCRBackend::Default),
+        "tesseract" => Some(OCRBackend::Tesseract),
+        "remote" => Some(OCRBackend::Remote),
+        "better_ocr" => Some(OCRBackend::BetterOCR),
+        _ => None,
+    }
+}
+
+fn ocr_backend_to_str(backend: OCRBackend) -> &'static str {
+    match backend {
+        OCRBackend::Default => "default",
+        OCRBackend::Tesseract => "tesseract",
+        OCRBackend::Remote => "remote",
+        OCRBackend::BetterOCR => "better_ocr",
+    }
+}
+
+/// Load the persisted OCR backend, defaulting to `Default` if not set.
+pub fn load_ocr_backend() -> OCRBackend {
+    match load_raw_config() {
+        Ok(cfg) => {
+            cfg.ocr_backend
+                .and_then(|s| ocr_backend_from_str(&s))
+                .unwrap_or(OCRBackend::Default)
+        }
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default OCR backend");
+            OCRBackend::Default
+        }
+    }
+}
+
+/// Persist the OCR backend to disk.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_ocr_backend(backend: OCRBackend) {
+    debug!(?backend, "Saving OCR backend");
+    let mut cfg = load_or_default_config();
+    cfg.ocr_backend = Some(ocr_backend_to_str(backend).to_string());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the remote OCR endpoint URL (empty if not set).
+pub fn load_ocr_endpoint() -> String {
+    match load_raw_config() {
+        Ok(cfg) => cfg.ocr_endpoint.unwrap_or_default(),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, no remote OCR endpoint");
+            String::new()
+        }
+    }
+}
+
+/// Persist the remote OCR endpoint URL.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_ocr_endpoint(url: &str) {
+    debug!(url, "Saving remote OCR endpoint");
+    let mut cfg = load_or_default_config();
+    cfg.ocr_endpoint = Some(url.to_string()).filter(|url| !url.trim().is_empty());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load whether captures are read as a single column.
+pub fn load_ocr_single_column() -> bool {
+    match load_raw_config() {
+        Ok(cfg) => cfg.ocr_single_column.unwrap_or(false),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, detecting columns in captures");
+            false
+        }
+    }
+}
+
+/// Persist whether captures are read as a single column.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_ocr_single_column(single_column: bool) {
+    debug!(single_column, "Saving OCR single column setting");
+    let mut cfg = load_or_default_config();
+    cfg.ocr_single_column = Some(single_column);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load whether repeated captures only read their new lines.
+pub fn load_ocr_new_lines_only() -> bool {
+    match load_raw_config() {
+        Ok(cfg) => cfg.ocr_new_lines_only.unwrap_or(false),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, reading captures in full");
+            false
+        }
+    }
+}
+
+/// Persist whether repeated captures only read their new lines.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_ocr_new_lines_only(new_lines_only: bool) {
+    debug!(new_lines_only, "Saving OCR new lines only setting");
+    let mut cfg = load_or_default_config();
+    cfg.ocr_new_lines_only = Some(new_lines_only);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+fn modifiers_to_string(modifiers: global_hotkey::hotkey::Modifiers) -> String {
+    use global_hotkey::hotkey::Modifiers;
+    let mut parts = Vec::new();
+    // Check for common modifier flags
+    if modifiers.contains(Modifiers::SHIFT) {
+        parts.push("shift");
+    }
+    if modifiers.contains(Modifiers::ALT) {
+        parts.push("alt");
+    }
+    if modifiers.contains(Modifiers::CONTROL) {
+        parts.push("control");
+    }
+    // META is used for Command on macOS
+    #[cfg(target_os = "macos")]
+    if modifiers.contains(Modifiers::META) {
+        parts.push("command");
+    }
+    #[cfg(not(target_os = "macos"))]
+    if modifiers.contains(Modifiers::META) {
+        parts.push("meta");
+    }
+    parts.join(",")
+}
+
+fn string_to_modifiers(s: &str) -> global_hotkey::hotkey::Modifiers {
+    use global_hotkey::hotkey::Modifiers;
+    let mut modifiers = Modifiers::empty();
     for part in s.split(',') {
         match part.trim().to_lowercase().as_str() {
             "command" | "cmd" | "meta" => modifiers |= Modifiers::META,
@@ -400,18 +1172,36 @@ fn code_to_string(code: global_hotkey::hotkey::Code) -> String {
 }
 
 fn string_to_code(s: &str) -> Option<global_hotkey::hotkey::Code> {
-    // Simple mapping for common keys
-    match s.to_lowercase().as_str() {
-        "r" => Some(global_hotkey::hotkey::Code::KeyR),
-        "t" => Some(global_hotkey::hotkey::Code::KeyT),
-        "s" => Some(global_hotkey::hotkey::Code::KeyS),
-        "space" => Some(global_hotkey::hotkey::Code::Space),
-        _ => {
-            // Try to parse as Code enum variant
-            // This is a simplified version - in production you'd want a full mapping
-            warn!(key = %s, "Unknown hotkey key, using default");
-            Some(global_hotkey::hotkey::Code::KeyR)
-        }
+    use std::str::FromStr;
+    use global_hotkey::hotkey::Code;
+    // Any key the settings can capture, matched by its `code_to_string` form ("r", "digit1", "f5", "pageup")
+    let s = s.trim().to_lowercase();
+    let letters = ('A'..='Z').map(|c| format!("Key{c}"));
+    let digits = (0..=9).map(|d| format!("Digit{d}"));
+    let function_keys = (1..=12).map(|n| format!("F{n}"));
+    let named = [
+        "Space", "Enter", "Tab", "Backspace", "Escape", "ArrowUp", "ArrowDown", "ArrowLeft", "ArrowRight", "Home",
+        "End", "PageUp", "PageDown", "Insert", "Delete",
+    ]
+    .map(String::from);
+    let code = letters
+        .chain(digits)
+        .chain(function_keys)
+        .chain(named)
+        .filter_map(|name| Code::from_str(&name).ok())
+        .find(|code| code_to_string(*code) == s);
+    if code.is_none() {
+        warn!(key = %s, "Unknown hotkey key, using default");
+    }
+    code
+}
+
+/// The config fields (modifiers, key) holding the hotkey of `action`.
+fn hotkey_fields(cfg: &mut RawConfig, action: HotkeyAction) -> (&mut Option<String>, &mut Option<String>) {
+    match action {
+        HotkeyAction::Read => (&mut cfg.hotkey_modifiers, &mut cfg.hotkey_key),
+        HotkeyAction::PauseResume => (&mut cfg.pause_hotkey_modifiers, &mut cfg.pause_hotkey_key),
+        HotkeyAction::Stop => (&mut cfg.stop_hotkey_modifiers, &mut cfg.stop_hotkey_key),
     }
 }
 
@@ -461,3 +1251,1105 @@ pub fn save_hotkey_config(config: &HotkeyConfig, enabled: bool) {
         error!(error = ?err, "Failed to save hotkey config");
     }
 }
+
+/// Load the hotkey triggering `action`, defaulting to the action's default shortcut.
+pub fn load_action_hotkey(action: HotkeyAction) -> HotkeyConfig {
+    let default = action.default_config();
+    match load_raw_config() {
+        Ok(mut cfg) => {
+            let (modifiers, key) = hotkey_fields(&mut cfg, action);
+            HotkeyConfig {
+                modifiers: modifiers.as_deref().map(string_to_modifiers).unwrap_or(default.modifiers),
+                key: key.as_deref().and_then(string_to_code).unwrap_or(default.key),
+            }
+        }
+        Err(err) => {
+            warn!(error = ?err, ?action, "Failed to load hotkey config, using defaults");
+            default
+        }
+    }
+}
+
+/// Persist the hotkey triggering `action` to disk.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_action_hotkey(action: HotkeyAction, config: &HotkeyConfig) {
+    debug!(?action, ?config, "Saving hotkey");
+    let mut cfg = load_or_default_config();
+    let (modifiers, key) = hotkey_fields(&mut cfg, action);
+    *modifiers = Some(modifiers_to_string(config.modifiers));
+    *key = Some(code_to_string(config.key));
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save hotkey config");
+    }
+}
+
+fn list_enumeration_from_str(s: &str) -> Option<ListEnumeration> {
+    match s {
+        "off" => Some(ListEnumeration::Off),
+        "ordinal" => Some(ListEnumeration::Ordinal),
+        "bullet" => Some(ListEnumeration::Bullet),
+        _ => None,
+    }
+}
+
+fn list_enumeration_to_str(style: ListEnumeration) -> &'static str {
+    match style {
+        ListEnumeration::Off => "off",
+        ListEnumeration::Ordinal => "ordinal",
+        ListEnumeration::Bullet => "bullet",
+    }
+}
+
+fn link_reading_from_str(s: &str) -> Option<LinkReading> {
+    match s {
+        "text" => Some(LinkReading::Text),
+        "text_link" => Some(LinkReading::WithLink),
+        "text_domain" => Some(LinkReading::WithDomain),
+        _ => None,
+    }
+}
+
+fn link_reading_to_str(policy: LinkReading) -> &'static str {
+    match policy {
+        LinkReading::Text => "text",
+        LinkReading::WithLink => "text_link",
+        LinkReading::WithDomain => "text_domain",
+    }
+}
+
+fn url_reading_from_str(s: &str) -> Option<UrlReading> {
+    match s {
+        "full" => Some(UrlReading::Full),
+        "no_query" => Some(UrlReading::WithoutQuery),
+        "domain" => Some(UrlReading::Domain),
+        _ => None,
+    }
+}
+
+fn url_reading_to_str(reading: UrlReading) -> &'static str {
+    match reading {
+        UrlReading::Full => "full",
+        UrlReading::WithoutQuery => "no_query",
+        UrlReading::Domain => "domain",
+    }
+}
+
+fn table_reading_from_str(s: &str) -> Option<TableReading> {
+    match s {
+        "rows" => Some(TableReading::Rows),
+        "skip" => Some(TableReading::Skip),
+        "as_written" => Some(TableReading::AsWritten),
+        _ => None,
+    }
+}
+
+fn table_reading_to_str(reading: TableReading) -> &'static str {
+    match reading {
+        TableReading::Rows => "rows",
+        TableReading::Skip => "skip",
+        TableReading::AsWritten => "as_written",
+    }
+}
+
+/// Load the persisted playback speed, defaulting to 1.0 if not set or out of range.
+pub fn load_playback_speed() -> f32 {
+    match load_raw_config() {
+        Ok(cfg) => cfg
+            .playback_speed
+            .filter(|speed| (0.5..=3.0).contains(speed))
+            .unwrap_or(1.0),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default playback speed");
+            1.0
+        }
+    }
+}
+
+/// Persist the playback speed to disk.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_playback_speed(speed: f32) {
+    debug!(speed, "Saving playback speed");
+    let mut cfg = load_or_default_config();
+    cfg.playback_speed = Some(speed);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the per-language speed adjustments (factors of the playback speed).
+pub fn load_language_speeds() -> std::collections::HashMap<String, f32> {
+    match load_raw_config() {
+        Ok(cfg) => cfg
+            .language_speeds
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, adjustment)| adjustment.is_finite() && *adjustment > 0.0)
+            .collect(),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, no language speed adjustments");
+            std::collections::HashMap::new()
+        }
+    }
+}
+
+/// Persist the per-language speed adjustments.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_language_speeds(adjustments: &std::collections::HashMap<String, f32>) {
+    debug!(?adjustments, "Saving language speed adjustments");
+    let mut cfg = load_or_default_config();
+    cfg.language_speeds = Some(adjustments.clone());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the "finish within" target in minutes, or `None` if suggestions are off.
+pub fn load_finish_target_minutes() -> Option<u32> {
+    match load_raw_config() {
+        Ok(cfg) => cfg.finish_target_minutes.filter(|minutes| *minutes > 0),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, speed suggestions off");
+            None
+        }
+    }
+}
+
+/// Persist the "finish within" target (`None` turns suggestions off).
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_finish_target_minutes(minutes: Option<u32>) {
+    debug!(?minutes, "Saving finish target");
+    let mut cfg = load_or_default_config();
+    cfg.finish_target_minutes = minutes;
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the pitch offset in semitones, defaulting to 0 if not set or out of range.
+pub fn load_pitch_semitones() -> f32 {
+    let max = crate::model::MAX_PITCH_SEMITONES;
+    match load_raw_config() {
+        Ok(cfg) => cfg
+            .pitch_semitones
+            .filter(|pitch| (-max..=max).contains(pitch))
+            .unwrap_or(0.0),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default pitch");
+            0.0
+        }
+    }
+}
+
+/// Persist the pitch offset.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_pitch_semitones(semitones: f32) {
+    debug!(semitones, "Saving pitch");
+    let mut cfg = load_or_default_config();
+    cfg.pitch_semitones = Some(semitones);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the pause between queued items in seconds, defaulting to a short pause.
+pub fn load_queue_pause_seconds() -> u32 {
+    match load_raw_config() {
+        Ok(cfg) => cfg
+            .queue_pause_seconds
+            .unwrap_or(crate::queue::DEFAULT_QUEUE_PAUSE_SECONDS),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default queue pause");
+            crate::queue::DEFAULT_QUEUE_PAUSE_SECONDS
+        }
+    }
+}
+
+/// Persist the pause between queued items.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_queue_pause_seconds(seconds: u32) {
+    debug!(seconds, "Saving queue pause");
+    let mut cfg = load_or_default_config();
+    cfg.queue_pause_seconds = Some(seconds);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load what happens when a reading ends, with the hook command.
+pub fn load_end_of_reading() -> (EndOfReading, String) {
+    match load_raw_config() {
+        Ok(cfg) => {
+            let action = match cfg.end_of_reading.as_deref() {
+                Some("stay_open") => EndOfReading::StayOpen,
+                Some("next") => EndOfReading::NextInQueue,
+                Some("hook") => EndOfReading::RunHook,
+                Some("tray") => EndOfReading::HideToTray,
+                _ => EndOfReading::CloseWindow,
+            };
+            (action, cfg.end_of_reading_hook.unwrap_or_default())
+        }
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, closing the window when a reading ends");
+            (EndOfReading::CloseWindow, String::new())
+        }
+    }
+}
+
+/// Persist what happens when a reading ends, with the hook command.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_end_of_reading(action: EndOfReading, hook: &str) {
+    debug!(?action, hook, "Saving end-of-reading action");
+    let mut cfg = load_or_default_config();
+    cfg.end_of_reading = Some(
+        match action {
+            EndOfReading::CloseWindow => "close",
+            EndOfReading::StayOpen => "stay_open",
+            EndOfReading::NextInQueue => "next",
+            EndOfReading::RunHook => "hook",
+            EndOfReading::HideToTray => "tray",
+        }
+        .to_string(),
+    );
+    cfg.end_of_reading_hook = Some(hook.to_string());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load where playback picks up after a pause or a skip.
+pub fn load_pre_roll() -> PreRoll {
+    match load_raw_config() {
+        Ok(cfg) => cfg.pre_roll.as_deref().and_then(pre_roll_from_str).unwrap_or_default(),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default pre-roll");
+            PreRoll::default()
+        }
+    }
+}
+
+/// Persist where playback picks up after a pause or a skip.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_pre_roll(pre_roll: PreRoll) {
+    debug!(?pre_roll, "Saving pre-roll");
+    let mut cfg = load_or_default_config();
+    cfg.pre_roll = Some(pre_roll_to_string(pre_roll));
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+fn pre_roll_from_str(s: &str) -> Option<PreRoll> {
+    match s {
+        "off" => Some(PreRoll::Off),
+        "sentence" => Some(PreRoll::SentenceStart),
+        _ => s.strip_suffix('s')?.parse().ok().map(PreRoll::Seconds),
+    }
+}
+
+fn pre_roll_to_string(pre_roll: PreRoll) -> String {
+    match pre_roll {
+        PreRoll::Off => "off".to_string(),
+        PreRoll::SentenceStart => "sentence".to_string(),
+        PreRoll::Seconds(seconds) => format!("{seconds}s"),
+    }
+}
+
+/// Load the break reminder settings as (interval in minutes, message); no interval means off.
+pub fn load_break_reminder() -> (Option<u32>, String) {
+    match load_raw_config() {
+        Ok(cfg) => (
+            cfg.break_interval_minutes.filter(|minutes| *minutes > 0),
+            cfg.break_message.unwrap_or_default(),
+        ),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, break reminders off");
+            (None, String::new())
+        }
+    }
+}
+
+/// Persist the break reminder interval (`None` turns reminders off).
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_break_interval_minutes(minutes: Option<u32>) {
+    debug!(?minutes, "Saving break reminder interval");
+    let mut cfg = load_or_default_config();
+    cfg.break_interval_minutes = minutes;
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Persist the break reminder text (empty = default text).
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_break_message(message: &str) {
+    debug!("Saving break reminder message");
+    let mut cfg = load_or_default_config();
+    cfg.break_message = (!message.is_empty()).then(|| message.to_string());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load whether readings are skimmed, defaulting to `false`.
+pub fn load_skim_mode() -> bool {
+    match load_raw_config() {
+        Ok(cfg) => cfg.skim_mode.unwrap_or(false),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, skim mode off");
+            false
+        }
+    }
+}
+
+/// Persist whether readings are skimmed.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_skim_mode(enabled: bool) {
+    debug!(enabled, "Saving skim mode");
+    let mut cfg = load_or_default_config();
+    cfg.skim_mode = Some(enabled);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load whether captured selections are reviewed before reading, defaulting to `false`.
+pub fn load_review_before_reading() -> bool {
+    match load_raw_config() {
+        Ok(cfg) => cfg.review_before_reading.unwrap_or(false),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, reading selections without review");
+            false
+        }
+    }
+}
+
+/// Persist whether captured selections are reviewed before reading.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_review_before_reading(enabled: bool) {
+    debug!(enabled, "Saving review before reading");
+    let mut cfg = load_or_default_config();
+    cfg.review_before_reading = Some(enabled);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the idle time in minutes after which resources are released, or `None` to keep them.
+pub fn load_idle_release_minutes() -> Option<u32> {
+    match load_raw_config() {
+        Ok(cfg) => match cfg.idle_release_minutes {
+            Some(0) => None,
+            Some(minutes) => Some(minutes),
+            None => Some(DEFAULT_IDLE_RELEASE_MINUTES),
+        },
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default idle release time");
+            Some(DEFAULT_IDLE_RELEASE_MINUTES)
+        }
+    }
+}
+
+/// Persist the idle release time (`None` keeps resources for as long as the app runs).
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_idle_release_minutes(minutes: Option<u32>) {
+    debug!(?minutes, "Saving idle release time");
+    let mut cfg = load_or_default_config();
+    cfg.idle_release_minutes = Some(minutes.unwrap_or(0));
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the size in KB above which captured selections need confirmation, or `None` to always read them.
+pub fn load_large_text_limit_kb() -> Option<u32> {
+    match load_raw_config() {
+        Ok(cfg) => match cfg.large_text_limit_kb {
+            Some(0) => None,
+            Some(kb) => Some(kb),
+            None => Some(DEFAULT_LARGE_TEXT_KB),
+        },
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default large text limit");
+            Some(DEFAULT_LARGE_TEXT_KB)
+        }
+    }
+}
+
+/// Persist the large text limit (`None` reads selections of any size right away).
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_large_text_limit_kb(kb: Option<u32>) {
+    debug!(?kb, "Saving large text limit");
+    let mut cfg = load_or_default_config();
+    cfg.large_text_limit_kb = Some(kb.unwrap_or(0));
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the do-not-disturb schedule as (enabled, ranges).
+pub fn load_quiet_hours() -> (bool, String) {
+    match load_raw_config() {
+        Ok(cfg) => (
+            cfg.quiet_hours_enabled.unwrap_or(false),
+            cfg.quiet_hours.unwrap_or_default(),
+        ),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, quiet hours off");
+            (false, String::new())
+        }
+    }
+}
+
+/// Persist the do-not-disturb schedule.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_quiet_hours(enabled: bool, ranges: &str) {
+    debug!(enabled, ranges, "Saving quiet hours");
+    let mut cfg = load_or_default_config();
+    cfg.quiet_hours_enabled = Some(enabled);
+    cfg.quiet_hours = Some(ranges.to_string());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the saved-readings setting as (enabled, folder; empty = default).
+pub fn load_recordings() -> (bool, String) {
+    match load_raw_config() {
+        Ok(cfg) => (
+            cfg.save_readings.unwrap_or(false),
+            cfg.recordings_folder.unwrap_or_default(),
+        ),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, readings not saved");
+            (false, String::new())
+        }
+    }
+}
+
+/// Persist the saved-readings setting.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_recordings(enabled: bool, folder: &str) {
+    debug!(enabled, folder, "Saving recordings setting");
+    let mut cfg = load_or_default_config();
+    cfg.save_readings = Some(enabled);
+    cfg.recordings_folder = Some(folder.to_string());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the storage folder for models and caches (None = default locations).
+pub fn load_storage_folder() -> Option<PathBuf> {
+    match load_raw_config() {
+        Ok(cfg) => cfg.storage_folder.filter(|folder| !folder.trim().is_empty()).map(PathBuf::from),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default storage folder");
+            None
+        }
+    }
+}
+
+/// Persist the storage folder for models and caches (None = default locations).
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_storage_folder(folder: Option<&Path>) {
+    debug!(?folder, "Saving storage folder");
+    let mut cfg = load_or_default_config();
+    cfg.storage_folder = folder.map(|folder| folder.to_string_lossy().into_owned());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the audio cache setting as (enabled, size limit in megabytes).
+pub fn load_audio_cache() -> (bool, u32) {
+    let default_limit = crate::providers::audio_cache::DEFAULT_CACHE_LIMIT_MB;
+    match load_raw_config() {
+        Ok(cfg) => (
+            cfg.audio_cache.unwrap_or(true),
+            cfg.audio_cache_limit_mb.filter(|&limit| limit > 0).unwrap_or(default_limit),
+        ),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default audio cache");
+            (true, default_limit)
+        }
+    }
+}
+
+/// Persist the audio cache setting.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_audio_cache(enabled: bool, limit_mb: u32) {
+    debug!(enabled, limit_mb, "Saving audio cache setting");
+    let mut cfg = load_or_default_config();
+    cfg.audio_cache = Some(enabled);
+    cfg.audio_cache_limit_mb = Some(limit_mb);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load how many hours screenshot captures are kept after OCR (None = deleted right away).
+pub fn load_capture_retention_hours() -> Option<u32> {
+    match load_raw_config() {
+        Ok(cfg) => cfg.capture_retention_hours.filter(|&hours| hours > 0),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, captures are deleted after OCR");
+            None
+        }
+    }
+}
+
+/// Persist how many hours screenshot captures are kept after OCR.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_capture_retention_hours(hours: Option<u32>) {
+    debug!(?hours, "Saving capture retention");
+    let mut cfg = load_or_default_config();
+    cfg.capture_retention_hours = hours;
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the transcript log setting as (mode, format).
+pub fn load_transcript() -> (TranscriptMode, TranscriptFormat) {
+    match load_raw_config() {
+        Ok(cfg) => {
+            let mode = match cfg.transcript.as_deref() {
+                Some("excerpt") => TranscriptMode::Excerpt,
+                Some("full") => TranscriptMode::FullText,
+                _ => TranscriptMode::Off,
+            };
+            let format = match cfg.transcript_format.as_deref() {
+                Some("jsonl") => TranscriptFormat::Jsonl,
+                _ => TranscriptFormat::Markdown,
+            };
+            (mode, format)
+        }
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, transcript log off");
+            (TranscriptMode::Off, TranscriptFormat::Markdown)
+        }
+    }
+}
+
+/// Persist the transcript log setting.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_transcript(mode: TranscriptMode, format: TranscriptFormat) {
+    debug!(?mode, ?format, "Saving transcript setting");
+    let mut cfg = load_or_default_config();
+    cfg.transcript = Some(
+        match mode {
+            TranscriptMode::Off => "off",
+            TranscriptMode::Excerpt => "excerpt",
+            TranscriptMode::FullText => "full",
+        }
+        .to_string(),
+    );
+    cfg.transcript_format = Some(
+        match format {
+            TranscriptFormat::Markdown => "markdown",
+            TranscriptFormat::Jsonl => "jsonl",
+        }
+        .to_string(),
+    );
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the phone handoff folder (empty = off).
+pub fn load_handoff_folder() -> String {
+    match load_raw_config() {
+        Ok(cfg) => cfg.handoff_folder.unwrap_or_default(),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, phone handoff off");
+            String::new()
+        }
+    }
+}
+
+/// Persist the phone handoff folder.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_handoff_folder(folder: &str) {
+    debug!(folder, "Saving phone handoff folder");
+    let mut cfg = load_or_default_config();
+    cfg.handoff_folder = Some(folder.to_string());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the listening webhook as (URL, include text); an empty URL means off.
+pub fn load_listen_webhook() -> (String, bool) {
+    match load_raw_config() {
+        Ok(cfg) => (cfg.listen_webhook.unwrap_or_default(), cfg.listen_webhook_text.unwrap_or(false)),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, listening webhook off");
+            (String::new(), false)
+        }
+    }
+}
+
+/// Persist the listening webhook.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_listen_webhook(url: &str, include_text: bool) {
+    debug!(url, include_text, "Saving listening webhook");
+    let mut cfg = load_or_default_config();
+    cfg.listen_webhook = Some(url.to_string());
+    cfg.listen_webhook_text = Some(include_text);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the subscribed feeds.
+pub fn load_feeds() -> Vec<FeedSubscription> {
+    match load_raw_config() {
+        Ok(cfg) => cfg.feeds.unwrap_or_default(),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, no feeds");
+            Vec::new()
+        }
+    }
+}
+
+/// Persist the subscribed feeds.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_feeds(feeds: &[FeedSubscription]) {
+    debug!(count = feeds.len(), "Saving feeds");
+    let mut cfg = load_or_default_config();
+    cfg.feeds = Some(feeds.to_vec());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the watched documents (URLs and file paths).
+pub fn load_watched_documents() -> Vec<String> {
+    match load_raw_config() {
+        Ok(cfg) => cfg.watched_documents.unwrap_or_default(),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, no watched documents");
+            Vec::new()
+        }
+    }
+}
+
+/// Persist the watched documents.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_watched_documents(sources: &[String]) {
+    debug!(count = sources.len(), "Saving watched documents");
+    let mut cfg = load_or_default_config();
+    cfg.watched_documents = Some(sources.to_vec());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the ids of feed items already heard.
+pub fn load_heard_feed_items() -> Vec<String> {
+    match load_raw_config() {
+        Ok(cfg) => cfg.heard_feed_items.unwrap_or_default(),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, no heard feed items");
+            Vec::new()
+        }
+    }
+}
+
+/// Persist the ids of feed items already heard.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_heard_feed_items(ids: &[String]) {
+    debug!(count = ids.len(), "Saving heard feed items");
+    let mut cfg = load_or_default_config();
+    cfg.heard_feed_items = Some(ids.to_vec());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the read-later service settings.
+pub fn load_read_later_settings() -> ReadLaterSettings {
+    match load_raw_config() {
+        Ok(cfg) => cfg.read_later.unwrap_or_default(),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, read-later service not configured");
+            ReadLaterSettings::default()
+        }
+    }
+}
+
+/// Persist the read-later service settings.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_read_later_settings(settings: &ReadLaterSettings) {
+    debug!(service = ?settings.service, "Saving read-later settings");
+    let mut cfg = load_or_default_config();
+    cfg.read_later = Some(settings.clone());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the calendar read by the agenda action.
+pub fn load_calendar_settings() -> CalendarSettings {
+    match load_raw_config() {
+        Ok(cfg) => cfg.calendar.unwrap_or_default(),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, no calendar configured");
+            CalendarSettings::default()
+        }
+    }
+}
+
+/// Persist the calendar settings.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_calendar_settings(settings: &CalendarSettings) {
+    debug!("Saving calendar settings");
+    let mut cfg = load_or_default_config();
+    cfg.calendar = Some(settings.clone());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load the persisted text processing options, defaulting each unset option.
+pub fn load_text_processing_options() -> TextProcessingOptions {
+    match load_raw_config() {
+        Ok(cfg) => TextProcessingOptions {
+            list_enumeration: cfg
+                .list_enumeration
+                .as_deref()
+                .and_then(list_enumeration_from_str)
+                .unwrap_or_default(),
+            link_reading: cfg
+                .link_reading
+                .as_deref()
+                .and_then(link_reading_from_str)
+                .unwrap_or_default(),
+            url_reading: cfg
+                .url_reading
+                .as_deref()
+                .and_then(url_reading_from_str)
+                .unwrap_or_default(),
+            skip_citations: cfg.skip_citations.unwrap_or(true),
+            table_reading: cfg
+                .table_reading
+                .as_deref()
+                .and_then(table_reading_from_str)
+                .unwrap_or_default(),
+            read_image_alt_text: cfg.read_image_alt_text.unwrap_or(true),
+            strip_html: cfg.strip_html.unwrap_or(true),
+            filter_rules: cfg.filter_rules.unwrap_or_default(),
+            lexicon: cfg.lexicon.unwrap_or_default(),
+            disabled_abbreviations: cfg.disabled_abbreviations.unwrap_or_default(),
+            spell_acronyms: cfg.spell_acronyms.unwrap_or(false),
+            localize_numbers: cfg.localize_numbers.unwrap_or(true),
+            spreadsheet_cells: cfg.spreadsheet_cells.unwrap_or(true),
+        },
+        Err(err) => {
+            warn!(error = ?err, "Failed to load text processing options, using defaults");
+            TextProcessingOptions::default()
+        }
+    }
+}
+
+/// Persist the text processing options to disk.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_text_processing_options(options: &TextProcessingOptions) {
+    debug!(?options, "Saving text processing options");
+    let mut cfg = load_or_default_config();
+    cfg.list_enumeration = Some(list_enumeration_to_str(options.list_enumeration).to_string());
+    cfg.link_reading = Some(link_reading_to_str(options.link_reading).to_string());
+    cfg.url_reading = Some(url_reading_to_str(options.url_reading).to_string());
+    cfg.skip_citations = Some(options.skip_citations);
+    cfg.table_reading = Some(table_reading_to_str(options.table_reading).to_string());
+    cfg.read_image_alt_text = Some(options.read_image_alt_text);
+    cfg.strip_html = Some(options.strip_html);
+    cfg.filter_rules = Some(options.filter_rules.clone());
+    cfg.lexicon = Some(options.lexicon.clone());
+    cfg.disabled_abbreviations = Some(options.disabled_abbreviations.clone());
+    cfg.spell_acronyms = Some(options.spell_acronyms);
+    cfg.localize_numbers = Some(options.localize_numbers);
+    cfg.spreadsheet_cells = Some(options.spreadsheet_cells);
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save text processing options");
+    }
+}
+
+/// Load the Coqui TTS server settings, defaulting to a local server.
+pub fn load_coqui_config() -> CoquiConfig {
+    match load_raw_config() {
+        Ok(cfg) => {
+            let defaults = CoquiConfig::default();
+            CoquiConfig {
+                url: cfg.providers.coqui.url.unwrap_or(defaults.url),
+                speaker: cfg.providers.coqui.voice.unwrap_or(defaults.speaker),
+            }
+        }
+        Err(err) => {
+            warn!(error = ?err, "Failed to load Coqui config, using defaults");
+            CoquiConfig::default()
+        }
+    }
+}
+
+/// Persist the Coqui TTS server settings to disk.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_coqui_config(coqui: &CoquiConfig) {
+    debug!(url = %coqui.url, speaker = %coqui.speaker, "Saving Coqui config");
+    let mut cfg = load_or_default_config();
+    cfg.providers.coqui.url = Some(coqui.url.clone());
+    cfg.providers.coqui.voice = Some(coqui.speaker.clone());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save Coqui config");
+    }
+}
+
+/// Load the OpenTTS / Mimic 3 server settings, defaulting to a local server.
+pub fn load_opentts_config() -> OpenTTSConfig {
+    match load_raw_config() {
+        Ok(cfg) => {
+            let defaults = OpenTTSConfig::default();
+            OpenTTSConfig {
+                url: cfg.providers.opentts.url.unwrap_or(defaults.url),
+                voice: cfg.providers.opentts.voice.unwrap_or(defaults.voice),
+            }
+        }
+        Err(err) => {
+            warn!(error = ?err, "Failed to load OpenTTS config, using defaults");
+            OpenTTSConfig::default()
+        }
+    }
+}
+
+/// Persist the OpenTTS / Mimic 3 server settings to disk.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_opentts_config(opentts: &OpenTTSConfig) {
+    debug!(url = %opentts.url, voice = %opentts.voice, "Saving OpenTTS config");
+    let mut cfg = load_or_default_config();
+    cfg.providers.opentts.url = Some(opentts.url.clone());
+    cfg.providers.opentts.voice = Some(opentts.voice.clone());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save OpenTTS config");
+    }
+}
+
+fn channel_routing_from_str(s: &str) -> Option<ChannelRouting> {
+    match s {
+        "both" => Some(ChannelRouting::Both),
+        "left" => Some(ChannelRouting::Left),
+        "right" => Some(ChannelRouting::Right),
+        _ => None,
+    }
+}
+
+fn channel_routing_to_str(channels: ChannelRouting) -> &'static str {
+    match channels {
+        ChannelRouting::Both => "both",
+        ChannelRouting::Left => "left",
+        ChannelRouting::Right => "right",
+    }
+}
+
+/// Load the default output devices and volume (used when no profile is active).
+pub fn load_output_settings() -> OutputSettings {
+    match load_raw_config() {
+        Ok(cfg) => OutputSettings {
+            device: cfg.output_device,
+            volume: cfg.volume.map(|v| v.clamp(0.0, 1.0)).unwrap_or(1.0),
+            mirror_device: cfg.mirror_output_device,
+            virtual_mic: cfg.virtual_mic.unwrap_or(false),
+            normalize_loudness: cfg.normalize_loudness.unwrap_or(true),
+            shorten_pauses: cfg.shorten_pauses.unwrap_or(false),
+            channels: cfg
+                .output_channels
+                .and_then(|s| channel_routing_from_str(&s))
+                .unwrap_or_default(),
+        },
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default output");
+            OutputSettings::default()
+        }
+    }
+}
+
+/// Persist the default output devices and volume.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_output_settings(output: &OutputSettings) {
+    debug!(?output, "Saving output settings");
+    let mut cfg = load_or_default_config();
+    cfg.output_device = output.device.clone();
+    cfg.volume = Some(output.volume);
+    cfg.mirror_output_device = output.mirror_device.clone();
+    cfg.virtual_mic = Some(output.virtual_mic);
+    cfg.normalize_loudness = Some(output.normalize_loudness);
+    cfg.shorten_pauses = Some(output.shorten_pauses);
+    cfg.output_channels = Some(channel_routing_to_str(output.channels).to_string());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load workspace profiles as (auto-switch enabled, profiles).
+pub fn load_profiles() -> (bool, Vec<ReadingProfile>) {
+    match load_raw_config() {
+        Ok(cfg) => (
+            cfg.profiles_auto_switch.unwrap_or(false),
+            cfg.profiles.unwrap_or_default(),
+        ),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, no workspace profiles");
+            (false, Vec::new())
+        }
+    }
+}
+
+/// Persist workspace profiles.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_profiles(auto_switch: bool, profiles: &[ReadingProfile]) {
+    debug!(auto_switch, count = profiles.len(), "Saving workspace profiles");
+    let mut cfg = load_or_default_config();
+    cfg.profiles_auto_switch = Some(auto_switch);
+    cfg.profiles = Some(profiles.to_vec());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Phrases on the board until the user edits it.
+const DEFAULT_PHRASES: [&str; 6] = [
+    "Yes",
+    "No",
+    "One moment please",
+    "Thank you",
+    "Could you repeat that?",
+    "I'm typing my answer",
+];
+
+/// Load the phrase board entries (the defaults until edited).
+pub fn load_phrases() -> Vec<String> {
+    let default = || DEFAULT_PHRASES.iter().map(|p| p.to_string()).collect();
+    match load_raw_config() {
+        Ok(cfg) => cfg.phrases.unwrap_or_else(default),
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, using default phrases");
+            default()
+        }
+    }
+}
+
+/// Persist the phrase board entries.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_phrases(phrases: &[String]) {
+    debug!(count = phrases.len(), "Saving phrases");
+    let mut cfg = load_or_default_config();
+    cfg.phrases = Some(phrases.to_vec());
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+/// Load what is spoken while typing in the type-to-talk window, defaulting to nothing.
+pub fn load_typing_echo() -> TypingEcho {
+    match load_raw_config() {
+        Ok(cfg) => match cfg.typing_echo.as_deref() {
+            Some("characters") => TypingEcho::Characters,
+            Some("words") => TypingEcho::Words,
+            _ => TypingEcho::Off,
+        },
+        Err(err) => {
+            warn!(error = ?err, "Failed to load config, typing echo off");
+            TypingEcho::Off
+        }
+    }
+}
+
+/// Persist what is spoken while typing in the type-to-talk window.
+///
+/// Errors are logged and otherwise ignored.
+pub fn save_typing_echo(echo: TypingEcho) {
+    debug!(?echo, "Saving typing echo");
+    let mut cfg = load_or_default_config();
+    cfg.typing_echo = Some(
+        match echo {
+            TypingEcho::Off => "off",
+            TypingEcho::Characters => "characters",
+            TypingEcho::Words => "words",
+        }
+        .to_string(),
+    );
+    if let Err(err) = save_raw_config(cfg) {
+        error!(error = ?err, "Failed to save config");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_provider_settings_are_migrated() {
+        let mut cfg: RawConfig = serde_json::from_str(
+            r#"{
+                "selected_voice": "en_US-lessac-medium",
+                "selected_polly_voice": "Joanna:Neural",
+                "coqui_url": "http://localhost:5002",
+                "providers": { "opentts": { "url": "http://tts:5500" }, "elevenlabs": { "api_key": "k" } }
+            }"#,
+        )
+        .unwrap();
+        cfg.migrate_legacy_provider_settings();
+
+        assert_eq!(cfg.providers.piper.voice.as_deref(), Some("en_US-lessac-medium"));
+        assert_eq!(cfg.providers.polly.voice.as_deref(), Some("Joanna"));
+        assert_eq!(cfg.providers.polly.engine.as_deref(), Some("Neural"));
+        assert_eq!(cfg.providers.coqui.url.as_deref(), Some("http://localhost:5002"));
+        assert_eq!(cfg.providers.opentts.url.as_deref(), Some("http://tts:5500"));
+
+        let saved = serde_json::to_value(&cfg).unwrap();
+        assert!(saved.get("selected_voice").is_none());
+        assert_eq!(saved["providers"]["elevenlabs"]["api_key"], "k");
+    }
+
+    #[test]
+    fn test_hotkey_keys_round_trip() {
+        use global_hotkey::hotkey::Code;
+        for code in [Code::KeyX, Code::Digit7, Code::F11, Code::Space, Code::PageDown] {
+            assert_eq!(string_to_code(&code_to_string(code)), Some(code));
+        }
+        assert_eq!(string_to_code("R"), Some(Code::KeyR));
+        assert_eq!(string_to_code("volume"), None);
+    }
+}
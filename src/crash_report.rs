@@ -0,0 +1,174 @@
+//! Local crash reports
+//!
+//! A panic hook writes a plain-text report, readable only by the user, to a
+//! private folder in the user's data directory: panic message and location,
+//! backtrace, a map of the capabilities in use (OS, desktop session, TTS/OCR
+//! backends, tray and hotkey support) and the last log messages. No text that was read is included: log lines keep only
+//! their message (not their fields), and quoted strings in the panic message
+//! are redacted. Nothing is sent anywhere; on the next launch the user is
+//! offered to open or copy the report for a bug report.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+use crate::logging;
+use crate::storage;
+
+/// Name of the file pointing at a report not yet offered to the user.
+const PENDING_MARKER: &str = "pending";
+
+/// Capabilities in use, written into reports (name → value).
+static CAPABILITIES: LazyLock<Mutex<BTreeMap<&'static str, String>>> = LazyLock::new(|| Mutex::new(BTreeMap::new()));
+
+/// Folder crash reports are written to, created readable only by the user.
+fn reports_dir() -> io::Result<PathBuf> {
+    storage::private_data_dir("crashes")
+}
+
+/// Write `contents` to a new file at `path` readable only by the user, replacing any file there.
+fn write_private(path: &Path, contents: &[u8]) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+    storage::create_private_file(path)?.write_all(contents)
+}
+
+/// Record a capability for future reports, replacing its previous value.
+pub fn set_capability(name: &'static str, value: impl ToString) {
+    if let Ok(mut capabilities) = CAPABILITIES.lock() {
+        capabilities.insert(name, value.to_string());
+    }
+}
+
+/// Replace text quoted in a panic message (`...` or "..."), which may be text that was read.
+fn redact_panic_message(message: &str) -> String {
+    let mut result = String::with_capacity(message.len());
+    let mut quote = None;
+    for c in message.chars() {
+        match quote {
+            Some(open) if c == open => {
+                result.push_str("<redacted>");
+                result.push(c);
+                quote = None;
+            }
+            Some(_) => {}
+            None => {
+                result.push(c);
+                if c == '`' || c == '"' {
+                    quote = Some(c);
+                }
+            }
+        }
+    }
+    // Unterminated quote: drop the rest
+    if quote.is_some() {
+        result.push_str("<redacted>");
+    }
+    result
+}
+
+/// Text of a crash report.
+fn format_report(
+    message: &str,
+    location: &str,
+    backtrace: &str,
+    capabilities: &BTreeMap<&'static str, String>,
+    log_lines: &[String],
+) -> String {
+    let mut report = String::new();
+    let _ = writeln!(report, "Insight Reader crash report");
+    let _ = writeln!(report, "Version: {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(report, "Time: {}", chrono::Local::now().to_rfc3339());
+    let _ = writeln!(report, "Panic: {}", redact_panic_message(message));
+    let _ = writeln!(report, "Location: {location}");
+
+    let _ = writeln!(report, "\nCapabilities:");
+    let _ = writeln!(report, "  os: {} ({})", std::env::consts::OS, std::env::consts::ARCH);
+    for variable in ["XDG_SESSION_TYPE", "XDG_CURRENT_DESKTOP"] {
+        if let Ok(value) = std::env::var(variable) {
+            let _ = writeln!(report, "  {}: {value}", variable.to_ascii_lowercase());
+        }
+    }
+    for (name, value) in capabilities {
+        let _ = writeln!(report, "  {name}: {value}");
+    }
+
+    let _ = writeln!(report, "\nBacktrace:\n{backtrace}");
+
+    let _ = writeln!(report, "\nLast {} log messages:", log_lines.len());
+    for line in log_lines {
+        let _ = writeln!(report, "{line}");
+    }
+    report
+}
+
+/// Write a report for a panic and mark it as pending.
+fn write_report(info: &std::panic::PanicHookInfo<'_>) -> io::Result<PathBuf> {
+    let message = info
+        .payload()
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "(no message)".to_string());
+    let location = info
+        .location()
+        .map(|location| format!("{}:{}", location.file(), location.line()))
+        .unwrap_or_default();
+    let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+    // Never wait on a lock in the panic hook: the panicking thread may hold it
+    let capabilities = CAPABILITIES.try_lock().map(|c| c.clone()).unwrap_or_default();
+    let report = format_report(&message, &location, &backtrace, &capabilities, &logging::recent_lines());
+
+    let dir = reports_dir()?;
+    let path = dir.join(format!("crash-{}.txt", chrono::Local::now().format("%Y-%m-%d-%H%M%S")));
+    write_private(&path, report.as_bytes())?;
+    write_private(&dir.join(PENDING_MARKER), path.to_string_lossy().as_bytes())?;
+    Ok(path)
+}
+
+/// Install the panic hook that writes crash reports (the default hook still runs).
+pub fn install() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        match write_report(info) {
+            Ok(path) => eprintln!("Crash report written to {}", path.display()),
+            Err(e) => eprintln!("Failed to write crash report: {e}"),
+        }
+        default_hook(info);
+    }));
+}
+
+/// The report of the last crash, if it hasn't been offered to the user yet.
+///
+/// Clears the pending mark, so each report is offered once.
+pub fn take_pending_report() -> Option<PathBuf> {
+    let marker = reports_dir().ok()?.join(PENDING_MARKER);
+    let path = PathBuf::from(fs::read_to_string(&marker).ok()?.trim());
+    let _ = fs::remove_file(&marker);
+    path.is_file().then_some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_redacts_text() {
+        assert_eq!(
+            redact_panic_message("byte index 3 is not a char boundary; it is inside 'é' of `Olá mundo`"),
+            "byte index 3 is not a char boundary; it is inside 'é' of `<redacted>`"
+        );
+        assert_eq!(redact_panic_message("bad \"secret"), "bad \"<redacted>");
+
+        let capabilities = BTreeMap::from([("tts_provider", "Piper".to_string())]);
+        let report = format_report("boom", "src/main.rs:1", "<backtrace>", &capabilities, &["INFO – Started".to_string()]);
+        assert!(report.contains("Panic: boom\nLocation: src/main.rs:1\n"));
+        assert!(report.contains("  tts_provider: Piper\n"));
+        assert!(report.ends_with("Last 1 log messages:\nINFO – Started\n"));
+    }
+}
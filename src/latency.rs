@@ -0,0 +1,156 @@
+//! Latency budget
+//!
+//! Where the time between a trigger and the first audio goes: fetching the
+//! selected text, recognizing text in a capture, Natural Reading, synthesis
+//! up to the first audio, and while playing, how late the animation ticks
+//! fire and how long the waveform's FFT takes. Every stage records its
+//! timings here, from the UI thread or from worker threads alike; the debug
+//! overlay shows them live.
+
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// Interval the playback tick is scheduled at.
+pub const TICK_INTERVAL: Duration = Duration::from_millis(75);
+
+/// Weight of the newest timing in a stage's running average.
+const AVERAGE_WEIGHT: f64 = 0.2;
+
+/// A timed stage of reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Fetching the selected text
+    Capture,
+    /// Recognizing text in a screen capture
+    Ocr,
+    /// Natural Reading round trip
+    Cleanup,
+    /// From starting synthesis to the first audio playing
+    FirstAudio,
+    /// How much later than scheduled a playback tick fired
+    TickJitter,
+    /// Computing the waveform's frequency bands
+    Fft,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 6] = [
+        Stage::Capture,
+        Stage::Ocr,
+        Stage::Cleanup,
+        Stage::FirstAudio,
+        Stage::TickJitter,
+        Stage::Fft,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Stage::Capture => "Capture",
+            Stage::Ocr => "OCR",
+            Stage::Cleanup => "Cleanup",
+            Stage::FirstAudio => "First audio",
+            Stage::TickJitter => "Tick jitter",
+            Stage::Fft => "FFT",
+        }
+    }
+}
+
+/// Timings of one stage.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StageTimings {
+    /// Latest timing
+    pub last: Duration,
+    /// Running average, favoring recent timings
+    pub average: Duration,
+    /// Longest timing so far
+    pub max: Duration,
+    pub count: u64,
+}
+
+impl StageTimings {
+    fn record(&mut self, duration: Duration) {
+        self.average = if self.count == 0 {
+            duration
+        } else {
+            self.average.mul_f64(1.0 - AVERAGE_WEIGHT) + duration.mul_f64(AVERAGE_WEIGHT)
+        };
+        self.last = duration;
+        self.max = self.max.max(duration);
+        self.count += 1;
+    }
+}
+
+#[derive(Debug, Default)]
+struct Latency {
+    stages: [StageTimings; Stage::ALL.len()],
+    last_tick: Option<Instant>,
+}
+
+static LATENCY: LazyLock<Mutex<Latency>> = LazyLock::new(|| Mutex::new(Latency::default()));
+
+/// Record how long `stage` took.
+pub fn record(stage: Stage, duration: Duration) {
+    if let Ok(mut latency) = LATENCY.lock() {
+        latency.stages[stage as usize].record(duration);
+    }
+}
+
+/// A playback tick fired at `now`: records how late it was.
+///
+/// Ticks only run during playback, so a gap of several intervals is a new
+/// playback starting rather than jitter, and is not recorded.
+pub fn tick(now: Instant) {
+    if let Ok(mut latency) = LATENCY.lock() {
+        if let Some(gap) = latency.last_tick.map(|last| now.saturating_duration_since(last)) {
+            if gap < TICK_INTERVAL * 4 {
+                latency.stages[Stage::TickJitter as usize].record(gap.saturating_sub(TICK_INTERVAL));
+            }
+        }
+        latency.last_tick = Some(now);
+    }
+}
+
+/// Timings of `stage` so far.
+pub fn timings(stage: Stage) -> StageTimings {
+    LATENCY
+        .lock()
+        .map(|latency| latency.stages[stage as usize])
+        .unwrap_or_default()
+}
+
+/// Forget all timings.
+pub fn reset() {
+    if let Ok(mut latency) = LATENCY.lock() {
+        *latency = Latency::default();
+    }
+}
+
+/// "12.3 ms" or "1.25 s".
+pub fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_secs_f64() * 1000.0;
+    if millis < 1000.0 {
+        format!("{millis:.1} ms")
+    } else {
+        format!("{:.2} s", millis / 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_timings() {
+        let mut timings = StageTimings::default();
+        timings.record(Duration::from_millis(100));
+        assert_eq!(timings.average, Duration::from_millis(100));
+        timings.record(Duration::from_millis(200));
+        assert_eq!(timings.last, Duration::from_millis(200));
+        assert_eq!(timings.average, Duration::from_millis(120));
+        assert_eq!(timings.max, Duration::from_millis(200));
+        assert_eq!(timings.count, 2);
+
+        assert_eq!(format_duration(Duration::from_micros(12_345)), "12.3 ms");
+        assert_eq!(format_duration(Duration::from_millis(1250)), "1.25 s");
+    }
+}
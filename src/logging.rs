@@ -4,17 +4,19 @@
 //! - Human-readable format: timestamp, level, target, file:line, message
 //! - Runtime log level control via GUI and `RUST_LOG` environment variable
 //! - Dual output to stderr and rotating log files
+//! - The last log messages kept in memory for crash reports
 
+use std::collections::VecDeque;
 use std::fmt;
 use std::io;
 use std::path::PathBuf;
-use std::sync::OnceLock;
+use std::sync::{LazyLock, Mutex, OnceLock};
 
 use tracing::{Event, Level, Subscriber};
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_subscriber::fmt::format::{self, FormatEvent, FormatFields};
 use tracing_subscriber::fmt::FmtContext;
-use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
 use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{reload, EnvFilter};
@@ -25,6 +27,12 @@ use crate::model::LogLevel;
 static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, tracing_subscriber::Registry>> =
     OnceLock::new();
 
+/// Log messages kept in memory for crash reports.
+const RECENT_LINES: usize = 100;
+
+/// The last log messages, oldest first.
+static RECENT: LazyLock<Mutex<VecDeque<String>>> = LazyLock::new(|| Mutex::new(VecDeque::with_capacity(RECENT_LINES)));
+
 /// Error type for logging initialization failures.
 #[derive(Debug)]
 pub enum LogInitError {
@@ -168,6 +176,52 @@ where
     }
 }
 
+/// Keeps the message of the last events for crash reports.
+///
+/// Only the message is kept, not the event's fields, so the text being read
+/// (logged as fields) never ends up in a report.
+struct RecentLinesLayer;
+
+/// Collects the `message` field of an event.
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl tracing::field::Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{value:?}");
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for RecentLinesLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut message = MessageVisitor::default();
+        event.record(&mut message);
+        let metadata = event.metadata();
+        let line = format!(
+            "{} {:5} {} – {}",
+            chrono::Utc::now().format("%H:%M:%S%.3f"),
+            metadata.level(),
+            metadata.target(),
+            message.0
+        );
+        if let Ok(mut recent) = RECENT.lock() {
+            if recent.len() == RECENT_LINES {
+                recent.pop_front();
+            }
+            recent.push_back(line);
+        }
+    }
+}
+
+/// The last log messages (without their fields), oldest first.
+///
+/// Called from the panic hook, so it never waits for the lock.
+pub fn recent_lines() -> Vec<String> {
+    RECENT.try_lock().map(|recent| recent.iter().cloned().collect()).unwrap_or_default()
+}
+
 /// Initialize the logging system with the given configuration.
 ///
 /// This should be called once at application startup, before any logging occurs.
@@ -209,6 +263,7 @@ pub fn init_logging(config: &LoggingConfig) -> Result<(), LogInitError> {
 
             tracing_subscriber::registry()
                 .with(filter_layer)
+                .with(RecentLinesLayer)
                 .with(stderr_layer)
                 .with(file_layer)
                 .init();
@@ -216,6 +271,7 @@ pub fn init_logging(config: &LoggingConfig) -> Result<(), LogInitError> {
         (true, false) => {
             tracing_subscriber::registry()
                 .with(filter_layer)
+                .with(RecentLinesLayer)
                 .with(stderr_layer)
                 .init();
         }
@@ -230,12 +286,13 @@ pub fn init_logging(config: &LoggingConfig) -> Result<(), LogInitError> {
 
             tracing_subscriber::registry()
                 .with(filter_layer)
+                .with(RecentLinesLayer)
                 .with(file_layer)
                 .init();
         }
         (false, false) => {
-            // No output configured - just set up the filter (unusual but valid)
-            tracing_subscriber::registry().with(filter_layer).init();
+            // No output configured - just set up the filter and crash report lines (unusual but valid)
+            tracing_subscriber::registry().with(filter_layer).with(RecentLinesLayer).init();
         }
     }
 
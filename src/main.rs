@@ -3,13 +3,22 @@
 #![cfg_attr(target_os = "windows", windows_subsystem = "windows")]
 
 mod app;
+mod break_reminder;
 mod config;
+mod crash_report;
 mod flags;
+mod latency;
 mod logging;
 mod model;
 mod providers;
+mod queue;
+mod self_test;
+mod speed_calibration;
+mod storage;
 mod styles;
 mod system;
+#[cfg(test)]
+mod test_harness;
 mod update;
 mod ui;
 mod view;
@@ -32,6 +41,15 @@ fn main() -> iced::Result {
         // Continue anyway - app can run without logging
     }
 
+    // Write a local crash report if anything panics from here on
+    crash_report::install();
+
+    // `--self-test [--silent]`: check synthesis and playback without any window, then exit
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--self-test") {
+        std::process::exit(self_test::run(args.iter().any(|arg| arg == "--silent")));
+    }
+
     info!("Insight Reader starting up");
 
     // Use daemon for multi-window support (view receives window::Id)
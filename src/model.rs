@@ -1,6 +1,7 @@
 //! Domain model for the application state
 
 use std::collections::HashMap;
+use std::time::Instant;
 use iced::window;
 use crate::providers::TTSProvider;
 use crate::config;
@@ -9,11 +10,24 @@ use crate::config;
 pub enum TTSBackend {
     Piper,
     AwsPolly,
+    /// Native Windows speech synthesizer (Windows only)
+    WindowsSpeech,
+    /// Locally running Coqui TTS / XTTS server
+    Coqui,
+    /// OpenTTS or Mimic 3 server
+    OpenTTS,
+    /// User-configured TTS command line program
+    Command,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OCRBackend {
+    /// The platform's own engine (Vision, Windows.Media.Ocr or EasyOCR)
     Default,
+    /// Tesseract command line program
+    Tesseract,
+    /// User-configured HTTP endpoint (e.g. a self-hosted PaddleOCR server)
+    Remote,
     BetterOCR,
 }
 
@@ -26,6 +40,161 @@ pub enum LogLevel {
     Trace,
 }
 
+/// Playback speed presets cycled by the speed badge and the `S` key.
+pub const SPEED_PRESETS: [f32; 4] = [1.0, 1.25, 1.5, 2.0];
+
+/// Next speed preset after `current`, wrapping around to the first.
+pub fn next_speed_preset(current: f32) -> f32 {
+    SPEED_PRESETS
+        .iter()
+        .copied()
+        .find(|&preset| preset > current + 0.01)
+        .unwrap_or(SPEED_PRESETS[0])
+}
+
+/// Fastest speed ever suggested for finishing on time.
+pub const MAX_SUGGESTED_SPEED: f32 = 3.0;
+
+/// Largest pitch offset in semitones offered in settings (either direction).
+pub const MAX_PITCH_SEMITONES: f32 = 6.0;
+
+/// "Finish within" targets in minutes offered in the playback settings.
+pub const FINISH_TARGET_PRESETS: [u32; 4] = [5, 10, 15, 30];
+
+/// Idle times in minutes after which resources can be released, offered in the playback settings.
+pub const IDLE_RELEASE_PRESETS: [u32; 3] = [5, 15, 60];
+
+/// Idle time in minutes after which resources are released unless configured otherwise.
+pub const DEFAULT_IDLE_RELEASE_MINUTES: u32 = 15;
+
+/// Sizes in KB above which captured selections wait for confirmation, offered in the playback settings.
+pub const LARGE_TEXT_PRESETS: [u32; 3] = [20, 50, 200];
+
+/// Size in KB above which captured selections wait for confirmation unless configured otherwise.
+pub const DEFAULT_LARGE_TEXT_KB: u32 = 50;
+
+/// Characters read per minute at normal speed, for estimates made before synthesis.
+const CHARS_PER_MINUTE: f32 = 900.0;
+
+/// Estimated listening time of `text` in seconds at `speed`, before it is synthesized.
+pub fn estimated_seconds(text: &str, speed: f32) -> f32 {
+    text.chars().count() as f32 / CHARS_PER_MINUTE * 60.0 / speed.max(0.1)
+}
+
+/// A captured selection over the large-text limit, waiting for confirmation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LargeText {
+    pub text: String,
+    /// Estimated listening time of all of it in seconds
+    pub seconds: f32,
+    /// Leading paragraphs within the limit, offered instead (0 when even the first is over it)
+    pub paragraphs: usize,
+}
+
+impl LargeText {
+    /// Hold `text` for confirmation, offering the paragraphs that fit within `limit_bytes`.
+    pub fn new(text: String, limit_bytes: usize, speed: f32) -> Self {
+        let paragraphs = crate::providers::paragraphs(&text)
+            .iter()
+            .take_while(|(start, paragraph)| start + paragraph.trim_end().len() <= limit_bytes)
+            .count();
+        Self { seconds: estimated_seconds(&text, speed), paragraphs, text }
+    }
+
+    /// The text up to the end of its `count`th paragraph.
+    pub fn first_paragraphs(&self, count: usize) -> &str {
+        let paragraphs = crate::providers::paragraphs(&self.text);
+        match count.checked_sub(1).and_then(|last| paragraphs.get(last)) {
+            Some((start, paragraph)) => self.text[..start + paragraph.len()].trim_end(),
+            None if count == 0 => "",
+            None => &self.text,
+        }
+    }
+}
+
+/// Listening time left in seconds at `speed`, given the total duration at normal speed.
+pub fn remaining_seconds(duration: f32, progress: f32, speed: f32) -> f32 {
+    (duration * (1.0 - progress.clamp(0.0, 1.0)) / speed.max(0.1)).max(0.0)
+}
+
+/// Speed needed to finish `remaining` seconds of normal-speed audio within
+/// `target_minutes`, rounded up to 0.05×.
+///
+/// Returns `None` when `current_speed` already finishes in time.
+pub fn suggested_speed(remaining: f32, current_speed: f32, target_minutes: u32) -> Option<f32> {
+    let target_seconds = target_minutes as f32 * 60.0;
+    if target_seconds <= 0.0 || remaining <= target_seconds * current_speed {
+        return None;
+    }
+    let needed = (remaining / target_seconds * 20.0).ceil() / 20.0;
+    Some(needed.min(MAX_SUGGESTED_SPEED))
+}
+
+/// Names of the voices in command-voices.toml and the error reading it, if any.
+pub fn load_command_voice_names() -> (Vec<String>, Option<String>) {
+    match crate::providers::load_command_voices() {
+        Ok(voices) => (voices.into_iter().map(|voice| voice.name).collect(), None),
+        Err(e) => (Vec::new(), Some(e)),
+    }
+}
+
+/// Output device entry meaning "use the system default device".
+pub const DEFAULT_OUTPUT_DEVICE: &str = "System default";
+
+/// Mirror device entry meaning "don't mirror playback".
+pub const NO_MIRROR_DEVICE: &str = "Off";
+
+/// AWS profile entry meaning "use AWS_PROFILE from the environment".
+pub const AWS_PROFILE_FROM_ENV: &str = "From environment";
+
+/// AWS region entry meaning "use the region from the environment or ~/.aws/config".
+pub const AWS_REGION_FROM_CONFIG: &str = "From AWS config";
+
+/// Polly cost warning thresholds in US cents offered in the provider settings.
+pub const POLLY_COST_WARNING_PRESETS: [u32; 4] = [10, 50, 100, 500];
+
+/// Lines kept in the type-to-talk session history.
+pub const MAX_TALK_HISTORY: usize = 100;
+
+/// Widget ID of the type-to-talk text box (focused when the window opens).
+pub const TALK_INPUT_ID: &str = "type-to-talk-input";
+
+/// What is spoken while typing in the type-to-talk window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TypingEcho {
+    #[default]
+    Off,
+    /// Each character as it is typed
+    Characters,
+    /// Each word, once a space or punctuation ends it
+    Words,
+}
+
+impl TypingEcho {
+    /// What to speak after the talk input changed from `before` to `after`, if anything.
+    ///
+    /// Only a single character typed at the end echoes: deleting, pasting or
+    /// editing earlier in the line doesn't.
+    pub fn echo(self, before: &str, after: &str) -> Option<String> {
+        let mut typed = after.strip_prefix(before)?.chars();
+        let (Some(typed), None) = (typed.next(), typed.next()) else {
+            return None;
+        };
+        let in_word = |c: char| c.is_alphanumeric() || c == '\'' || c == '-';
+        match self {
+            TypingEcho::Off => None,
+            TypingEcho::Characters if typed == ' ' => Some("space".to_string()),
+            TypingEcho::Characters => (!typed.is_whitespace()).then(|| typed.to_string()),
+            TypingEcho::Words if in_word(typed) => None,
+            TypingEcho::Words => before
+                .rsplit(|c: char| !in_word(c))
+                .next()
+                .filter(|word| !word.is_empty())
+                .map(str::to_string),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PlaybackState {
     Stopped,
@@ -33,27 +202,252 @@ pub enum PlaybackState {
     Paused,
 }
 
+/// What a window of the app shows.
+///
+/// Every window is registered with its role when it is opened and forgotten
+/// when its close event arrives, so a close request always targets the window
+/// it is meant for and no id outlives its window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowRole {
+    /// The floating player
+    Main,
+    Settings,
+    VoiceSelection,
+    PollyInfo,
+    Screenshot,
+    OcrInfo,
+    TextCleanupInfo,
+    ExtractedText,
+    Queue,
+    PhraseBoard,
+    CrashReport,
+    TypeToTalk,
+    ReadLater,
+    DebugOverlay,
+    /// The captured text, edited before it is read
+    Review,
+}
+
+impl WindowRole {
+    /// Window title.
+    pub fn title(self) -> &'static str {
+        match self {
+            WindowRole::Main => "Insight Reader",
+            WindowRole::Settings => "Settings",
+            WindowRole::VoiceSelection => "Select Voice",
+            WindowRole::PollyInfo => "AWS Polly Pricing Information",
+            WindowRole::Screenshot => "Screenshot",
+            WindowRole::OcrInfo => "Better OCR",
+            WindowRole::TextCleanupInfo => "Natural Reading",
+            WindowRole::ExtractedText => "Extracted Text",
+            WindowRole::Queue => "Reading Queue",
+            WindowRole::PhraseBoard => "Phrase Board",
+            WindowRole::CrashReport => "Crash Report",
+            WindowRole::TypeToTalk => "Type to Talk",
+            WindowRole::ReadLater => "Read Later",
+            WindowRole::DebugOverlay => "Debug Overlay",
+            WindowRole::Review => "Review Text",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Message {
     SkipBackward,
     SkipForward,
     PlayPause,
     Stop,
+    CycleSpeed, // Switch to the next playback speed preset
+    StartSpeedCalibration, // Speed calibration wizard started for the selected voice's language
+    SpeedCalibrationFaster, // Read the next sample at the next faster speed
+    ReplaySpeedSample, // Read the current sample again
+    SpeedCalibrationDefaultToggled(bool), // Whether the picked speed becomes the default speed
+    FinishSpeedCalibration, // Current speed picked as the fastest comfortable one
+    SpeedCalibrationTooFast, // Current speed too fast: the previous one is picked
+    CancelSpeedCalibration, // Speed calibration abandoned
+    ResetLanguageSpeed(String), // Speed adjustment for a language family removed
+    FinishTargetSelected(Option<u32>), // "Finish within" target in minutes (None = off)
+    QueuePauseSelected(u32), // Pause between queued items in seconds
+    EndOfReadingSelected(crate::queue::EndOfReading), // What happens when a reading ends
+    EndOfReadingHookChanged(String), // Command run when a reading ends
+    PreRollSelected(crate::providers::word_timing::PreRoll), // Where playback picks up after a pause or a skip
+    BreakIntervalSelected(Option<u32>), // Break reminder interval in minutes (None = off)
+    IdleReleaseSelected(Option<u32>), // Idle minutes before resources are released (None = never)
+    LargeTextLimitSelected(Option<u32>), // Size in KB above which selections need confirmation (None = off)
+    SkimModeToggled(bool), // Reading only headings and first sentences enabled/disabled
+    ReviewBeforeReadingToggled(bool), // Editing captured text before it is read enabled/disabled
+    ReviewEditorAction(iced::widget::text_editor::Action), // Edit in the review window
+    SpeakReviewed, // Read the reviewed text
+    CancelReview, // Drop the reviewed text without reading it
+    IdleCheck, // Periodic check whether the app has been idle long enough to release resources
+    BreakMessageChanged(String), // Break reminder text edited
+    ReplayRecent, // Replay the last seconds of played audio ("what did it just say?")
+    ReplayReady(Result<crate::providers::ReadySpeech, String>), // Replay or spelling started (or error)
+    NextChapter, // Jump to the next detected chapter (])
+    PreviousChapter, // Jump to the start of this chapter, or the previous one near its start ([)
+    ExpandSkim, // Read the skimmed text in full from the paragraph being skimmed (F)
+    SpellWord, // Spell out the word just heard, then carry on (P)
+    SkipQueuePause, // Start the next queued item without waiting
+    ResumeSavedPosition, // Jump to where this text was stopped last time
+    LoopMarked, // Mark the A or B point of a loop, or clear the loop (L)
+    QuietHoursToggled(bool), // Do-not-disturb schedule enabled/disabled
+    QuietHoursChanged(String), // Quiet hours ranges edited (e.g. "22:00-07:00")
+    QuietHoursCheck, // Periodic check whether quiet hours started or ended
+    SaveReadingsToggled(bool), // Saving readings as audio files enabled/disabled
+    RecordingsFolderChanged(String), // Folder typed for saved readings
+    ReadingSaved(Result<std::path::PathBuf, String>), // Saved reading's audio file (or error)
+    HandoffFolderChanged(String), // Phone handoff folder typed (empty = off)
+    HandoffExported(Result<std::path::PathBuf, String>), // Reading's audio file in the handoff folder (or error)
+    ListenWebhookChanged(String), // Webhook URL typed for finished readings (empty = off)
+    ListenWebhookTextToggled(bool), // Whether the webhook summary includes the text
+    ListenWebhookSent(Result<(), String>), // Finished reading posted to the webhook (or error)
+    TranscriptModeSelected(crate::system::TranscriptMode), // How much of each reading goes into the transcript log
+    TranscriptFormatSelected(crate::system::TranscriptFormat), // Transcript file format selected
+    NewFeedUrlChanged(String), // URL of the feed being added edited
+    AddFeed, // Subscribe to the typed feed URL
+    RemoveFeed(usize), // Unsubscribe from a feed by index
+    FeedVoiceChanged(usize, String), // Voice a feed's items are read with edited (empty = selected voice)
+    OpmlPathChanged(String), // OPML import file path edited
+    ImportOpml, // Subscribe to the feeds of an OPML file
+    PlayBriefing, // Fetch new feed items and queue them (tray/settings)
+    BriefingFetched(Result<Vec<crate::system::BriefingItem>, String>), // New feed items (or error)
+    NewWatchSourceChanged(String), // URL or file path of the document being watched edited
+    AddWatch, // Watch the typed URL or file for added paragraphs
+    RemoveWatch(usize), // Stop watching a document by index
+    CheckWatched, // Periodic check of the watched documents
+    WatchedChecked(Result<Vec<crate::system::DocumentChange>, String>), // Paragraphs added to watched documents (or error)
+    NextQueuedItem, // Skip the current item and read the next queued one
+    OutputDevicesLoaded(Vec<String>), // Audio output device names listed on startup
+    OutputDeviceSelected(String), // Output device picked (DEFAULT_OUTPUT_DEVICE = system default)
+    MirrorDeviceSelected(String), // Second output device picked (NO_MIRROR_DEVICE = off)
+    VirtualMicToggled(bool), // Speaking into the virtual microphone enabled/disabled
+    NormalizeLoudnessToggled(bool), // Loudness normalization enabled/disabled
+    ShortenPausesToggled(bool), // Shortening long pauses enabled/disabled
+    OutputChannelsSelected(crate::providers::ChannelRouting), // Side of the output speech is played on
+    VirtualMicPrepared(Result<String, String>), // Virtual microphone created/found (its name) or why not
+    VolumeChanged(f32), // Volume slider moved
+    VolumeReleased, // Volume slider released (persist the new volume)
+    PitchChanged(f32), // Pitch slider moved (semitones)
+    PitchReleased, // Pitch slider released (persist the new pitch)
+    ProfilesAutoSwitchToggled(bool), // Workspace profile auto-switching enabled/disabled
+    ProfileCheck, // Periodic re-detection of network/hostname for profiles
+    NetworkDetected(crate::system::NetworkContext), // Result of network/hostname detection
+    NewProfileNameChanged(String), // Name typed for a new workspace profile
+    NewProfileMatchChanged(String), // SSIDs/hostnames typed for a new workspace profile
+    AddProfile, // Save a new workspace profile from the current output settings
+    DeleteProfile(usize), // Remove the workspace profile at this index
+    OpenPhraseBoard, // Open the phrase board window
+    ClosePhraseBoard, // Close the phrase board window
+    SpeakPhrase(usize), // Speak the phrase at this index right away
+    PhraseReady(Result<crate::providers::ReadySpeech, String>), // Phrase synthesized (or replayed from cache) and playing
+    TogglePhraseEditing, // Show/hide the phrase board's edit controls
+    NewPhraseChanged(String), // Text typed for a new phrase
+    AddPhrase, // Add the typed phrase to the board
+    DeletePhrase(usize), // Remove the phrase at this index
+    OpenCrashReport, // Open the last crash report in the default text editor
+    CopyCrashReport, // Copy the last crash report to the clipboard
+    CloseCrashReport, // Close the crash report window
+    OpenTypeToTalk, // Open the type-to-talk window
+    CloseTypeToTalk, // Close the type-to-talk window
+    TypingEchoSelected(TypingEcho), // What is spoken while typing in the type-to-talk window
+    OpenReadLater, // Open the read-later articles window (tray/settings)
+    CloseReadLater, // Close the read-later articles window
+    DebugOverlayToggled(bool), // Latency overlay shown/hidden
+    ResetLatency, // Forget the latency timings shown in the overlay
+    RefreshReadLater, // Fetch the unread articles again
+    ReadLaterFetched(Result<Vec<crate::system::Article>, String>), // Unread articles (or error)
+    ReadArticle(usize), // Read an article by index, archiving it when finished
+    ArticleMarkedRead(Result<String, String>), // Archived article id (or error)
+    ReadLaterServiceSelected(crate::system::ReadLaterService), // Read-later service picked in settings
+    OpenQueue, // Open the reading queue window
+    CloseQueue, // Close the reading queue window
+    QueueDragStarted(crate::queue::QueuedItemId), // Queued item picked up by its handle
+    QueueDraggedOver(usize), // Dragged item moved over the row at this index
+    QueueDragEnded, // Dragged item dropped
+    RemoveQueuedItem(crate::queue::QueuedItemId), // Take an item out of the queue
+    EditQueuedItem(crate::queue::QueuedItemId), // Open a queued item's text in the editor
+    QueueEditorAction(iced::widget::text_editor::Action), // Edit in the queued item editor
+    SaveQueuedItem, // Write the edited text back to the queued item
+    CancelQueuedItemEdit, // Drop the edit, releasing the item
+    NewPlaylistNameChanged(String), // Name typed for a new playlist
+    SaveQueueAsPlaylist, // Save the waiting items as a playlist with the typed name
+    LoadPlaylist(usize), // Add a playlist's items to the queue
+    DeletePlaylist(usize), // Delete the playlist at this index
+    PlaylistPathChanged(String), // Path typed for playlist import/export
+    ImportPlaylists, // Add the playlists of a JSON file
+    ExportPlaylist(usize), // Write the playlist at this index to a JSON file
+    OpenFilePathChanged(String), // Path typed for a file to read
+    OpenFile, // Read the file at the typed path
+    FileDropped(std::path::PathBuf), // File dropped on one of the windows, to read
+    FileOpened(std::path::PathBuf, Result<crate::system::Document, String>), // Text extracted from an opened file
+    WallabagUrlChanged(String), // Wallabag server URL edited
+    WallabagClientIdChanged(String), // Wallabag API client id edited
+    WallabagClientSecretChanged(String), // Wallabag API client secret edited
+    WallabagUsernameChanged(String), // Wallabag user name edited
+    WallabagPasswordChanged(String), // Wallabag password edited
+    ReadwiseTokenChanged(String), // Readwise access token edited
+    ReadAgenda, // Read today's calendar events (tray/settings)
+    AgendaFetched(Result<String, String>), // Agenda text to read (or error)
+    CalendarSourceChanged(String), // Calendar file path or URL edited
+    CalendarUsernameChanged(String), // Calendar user name edited
+    CalendarPasswordChanged(String), // Calendar password edited
+    TalkInputChanged(String), // Text typed in the type-to-talk box
+    TalkSubmit, // Enter pressed: speak the typed line now
+    ResayTalk(usize), // Speak the history line at this index again
     Tick,
     Settings,
     CloseSettings,
     ProviderSelected(TTSBackend),
+    AwsProfileSelected(String), // AWS profile picked for Polly (AWS_PROFILE_FROM_ENV = environment's)
+    AwsRegionSelected(String), // AWS region picked for Polly (AWS_REGION_FROM_CONFIG = detected)
+    PollyCostWarningSelected(Option<u32>), // Polly cost warning threshold in cents (None = off)
     LogLevelSelected(LogLevel),
     TextCleanupToggled(bool),
+    TransformKindSelected(crate::system::TransformKind), // Kind of the transform step being added picked
+    NewTransformUrlChanged(String), // Endpoint URL of the transform step being added
+    NewTransformApiKeyChanged(String), // API key of the transform step being added
+    NewTransformModelChanged(String), // Model of the transform step being added
+    NewTransformPromptChanged(String), // Prompt of the transform step being added
+    AddTransformStep, // Append the transform step being added to the chain
+    MoveTransformStepUp(usize), // Run a transform step (by index) one step earlier
+    DeleteTransformStep(usize), // Remove a transform step (by index) from the chain
     WindowOpened(window::Id),
     WindowClosed(window::Id),
-    TTSInitialized(Result<(), String>), // Result of async TTS initialization
+    TTSInitialized(Result<crate::providers::ReadySpeech, String>), // Result of async TTS initialization
     SelectedTextFetched(Option<String>), // Result of async text selection fetch
     TextCleanupResponse(Result<String, String>), // Result of Natural Reading API call
     StartDrag, // Begin dragging the window
     VoiceSelected(String), // Voice key selected (e.g., "en_US-lessac-medium")
     VoiceDownloadRequested(String), // Voice key to download
+    MixedLanguageToggled(bool), // Reading other-language passages with their own voice enabled/disabled
+    SynthesisLoadSelected(crate::providers::synthesis_load::SynthesisLoad), // Piper priority / thread limit changed
+    RemoveLanguageVoice(String), // Forget the voice set for this language code
+    AutoLanguageToggled(bool), // Reading each text with the voice for its detected language enabled/disabled
+    CompareVoiceChanged(String), // Comparison ("B") voice key edited
+    CompareVoice, // Read the next sentence with the comparison voice, then carry on (B)
+    DismissLanguagePrompt, // Hide the offer to pick a voice for the detected language
+    ReadLargeText(Option<usize>), // Read the selection held as too large: all of it, or its first paragraphs
+    DismissLargeText, // Drop the selection held as too large
     VoiceDownloaded(Result<String, String>), // Download completion (voice key or error)
+    VoiceLicenseToggled(String), // Show/hide the license of this voice in the voice list
+    VoiceLicenseLoaded(String, Result<crate::voices::license::VoiceLicense, String>), // License of a voice (voice key, license or error)
+    VoicePackPathChanged(String), // Voice pack path typed
+    StorageFolderChanged(String), // Storage folder typed (empty = default locations)
+    MoveStorage, // Move models and caches to the typed storage folder
+    StorageMoved(Option<std::path::PathBuf>, Result<usize, String>), // Storage move result (target folder, files moved)
+    AudioCacheToggled(bool), // On-disk cache of synthesized readings enabled/disabled
+    AudioCacheLimitSelected(u32), // Audio cache size limit selected (megabytes)
+    AudioCacheMeasured(u64), // Current size of the audio cache (bytes)
+    ClearAudioCache, // Delete every cached reading
+    AudioCacheCleared(Result<u64, String>), // Audio cache cleared (bytes freed, or error)
+    ReadingCached(Result<(), String>), // Reading's audio written to the cache (or error)
+    CaptureRetentionSelected(Option<u32>), // Hours screenshot captures are kept after OCR (None = delete right away)
+    ClearCaptureCache, // Delete every kept screenshot capture
+    CaptureCacheCleared(Result<usize, String>), // Captures deleted (count, or error)
+    ImportVoicePack, // Install the voices of the pack at the typed path
+    ExportVoicePack, // Write the installed voices to a pack at the typed path
+    VoicePackImported(Result<crate::voices::pack::ImportedPack, String>), // Voice pack import result
+    VoicePackExported(Result<usize, String>), // Voice pack export result (number of voices)
     VoicesJsonLoaded(Result<HashMap<String, VoiceInfo>, String>), // voices.json loaded
     PollyVoicesLoaded(Result<HashMap<String, PollyVoiceInfo>, String>), // AWS Polly voices loaded
     OpenVoiceSelection(String), // Open voice selection window for language code
@@ -62,13 +456,16 @@ pub enum Message {
     ClosePollyInfo, // Close AWS Polly pricing info modal
     OpenPollyPricingUrl, // Open AWS Polly pricing URL in browser
     OCRBackendSelected(OCRBackend), // OCR backend selected
+    OcrEndpointChanged(String), // Remote OCR endpoint URL edited
+    OcrSingleColumnToggled(bool), // Column detection in captures disabled/enabled
+    OcrNewLinesOnlyToggled(bool), // Reading only the new lines of repeated captures enabled/disabled
     OpenOCRInfo, // Open Better OCR info modal
     CloseOCRInfo, // Close Better OCR info modal
     OpenTextCleanupInfo, // Open Natural Reading info modal
     CloseTextCleanupInfo, // Close Natural Reading info modal
     ScreenshotRequested, // User clicked screenshot button
     ScreenshotCaptured(Result<String, String>), // Screenshot result (file path or error)
-    ScreenshotTextExtracted(Result<String, String>), // Text extracted from screenshot (text or error)
+    ScreenshotTextExtracted(Result<Vec<crate::system::OcrLine>, String>), // Lines extracted from screenshot (in reading order, or error)
     #[allow(dead_code)] // Message variant - matched but not directly constructed
     OpenScreenshotViewer, // Open screenshot viewer window
     CloseScreenshotViewer, // Close screenshot viewer window
@@ -86,12 +483,57 @@ pub enum Message {
     HotkeyPressed, // Global hotkey was pressed
     HotkeyConfigChanged(crate::system::HotkeyConfig), // Hotkey configuration changed
     HotkeyToggled(bool), // Hotkey enabled/disabled
-    StartListeningForHotkey, // Start listening for hotkey input
+    StartListeningForHotkey(crate::system::HotkeyAction), // Start listening for input of an action's hotkey
     StopListeningForHotkey, // Stop listening for hotkey input
     HotkeyCaptured(iced::keyboard::Key, iced::keyboard::Modifiers), // Hotkey combination captured
+    ListEnumerationSelected(crate::system::ListEnumeration), // List item enumeration style selected
+    LinkReadingSelected(crate::system::LinkReading), // Hyperlink reading policy selected
+    UrlReadingSelected(crate::system::UrlReading), // Reading of URLs written in the text selected
+    TableReadingSelected(crate::system::TableReading), // Reading of markdown and HTML tables selected
+    ImageAltTextToggled(bool), // Image alt text reading enabled/disabled
+    StripHtmlToggled(bool), // Reading copied HTML as text enabled/disabled
+    AbbreviationToggled(&'static str, bool), // Abbreviation rule (by written form) enabled/disabled
+    SkipCitationsToggled(bool), // Skipping citations and footnotes enabled/disabled
+    SpellAcronymsToggled(bool), // Spelling out unknown acronyms enabled/disabled
+    LocalizeNumbersToggled(bool), // Reading numbers and dates in the voice's locale enabled/disabled
+    SpreadsheetCellsToggled(bool), // Reading spreadsheet cells row by row enabled/disabled
+    LexiconPathChanged(String), // Lexicon import/export file path edited
+    ImportLexicon, // Import a PLS or CSV lexicon from the path
+    ExportLexicon, // Export the lexicon to the path
+    ClearLexicon, // Remove all lexicon entries
+    NewLexiconWordChanged(String), // Word of the new lexicon entry edited
+    NewLexiconAliasChanged(String), // Spoken form of the new lexicon entry edited
+    AddLexiconEntry, // Add the new lexicon entry
+    DeleteLexiconEntry(usize), // Remove a lexicon entry by index
+    NewFilterPatternChanged(String), // Pattern of the new filter rule edited
+    NewFilterReplacementChanged(String), // Replacement of the new filter rule edited
+    AddFilterRule, // Add the new filter rule
+    FilterRuleToggled(usize, bool), // Filter rule (by index) enabled/disabled
+    MoveFilterRuleUp(usize), // Apply a filter rule (by index) one step earlier
+    DeleteFilterRule(usize), // Remove a filter rule by index
+    FilterTestAction(iced::widget::text_editor::Action), // Edit in the filter rule test area
+    CoquiUrlChanged(String), // Coqui server URL edited
+    CoquiSpeakerChanged(String), // Coqui speaker ID edited
+    OpenTTSUrlChanged(String), // OpenTTS / Mimic 3 server URL edited
+    OpenTTSVoiceChanged(String), // OpenTTS / Mimic 3 voice name edited
+    CommandVoiceSelected(String), // Command voice picked by name
+    ReloadCommandVoices, // Re-read command-voices.toml
 }
 
 /// Voice metadata from piper-voices repository
+/// A sentence of the reading to be read with the comparison ("B") voice.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoiceComparison {
+    /// Start of the sentence, as a share of the reading
+    pub start: f32,
+    /// End of the sentence (where the reading carries on), as a share of the reading
+    pub end: f32,
+    /// The sentence
+    pub text: String,
+    /// Whether the comparison voice is reading it (the reading is paused)
+    pub speaking: bool,
+}
+
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub struct VoiceInfo {
     pub key: String,
@@ -136,21 +578,42 @@ pub struct App {
     pub progress: f32,
     pub frequency_bands: Vec<f32>,
     pub provider: Option<Box<dyn TTSProvider>>,
+    /// Provider of the reading (or phrase) waiting for its synthesis, by synthesis id
+    pub synthesizing: Option<(u64, Box<dyn TTSProvider>)>,
     pub selected_backend: TTSBackend,
     pub log_level: LogLevel,
     pub text_cleanup_enabled: bool,
+    /// Transforms the text goes through when Natural Reading is on, in order
+    pub text_transforms: Vec<crate::system::TransformStep>,
+    /// Transform step being set up in settings, before it is added to the chain
+    pub new_transform: crate::system::TransformStep,
     pub show_settings_modal: bool,
-    pub settings_window_id: Option<window::Id>,
-    pub current_window_id: Option<window::Id>,
-    pub main_window_id: Option<window::Id>,
+    /// Open windows, with what each of them shows
+    pub windows: HashMap<window::Id, WindowRole>,
     pub pending_text: Option<String>,
     pub error_message: Option<String>,
     pub is_loading: bool,
     pub loading_animation_time: f32,
     /// Status text shown during loading (e.g., "Cleaning text...", "Synthesizing voice...")
     pub status_text: Option<String>,
+    /// Whether playback caught up with synthesis and waits for the next chunk
+    pub buffering: bool,
     /// Selected voice key (e.g., "en_US-lessac-medium")
     pub selected_voice: Option<String>,
+    /// Whether passages in other languages are read with that language's Piper voice
+    pub mixed_language: bool,
+    /// Piper voice per language code ("fr" -> "fr_FR-siwis-medium")
+    pub language_voices: std::collections::BTreeMap<String, String>,
+    /// Whether each text is read with the voice set for its detected language
+    pub auto_language: bool,
+    /// Piper voice key of the comparison ("B") voice (empty = none)
+    pub compare_voice: String,
+    /// Sentence waiting to be, or being, read with the comparison voice
+    pub voice_comparison: Option<VoiceComparison>,
+    /// Detected language of the current text that has no downloaded voice yet
+    pub language_prompt: Option<String>,
+    /// How much of the machine Piper synthesis may take
+    pub synthesis_load: crate::providers::synthesis_load::SynthesisLoad,
     /// Selected language code for voice selection (e.g., "en_US")
     pub selected_language: Option<String>,
     /// All available voices loaded from voices.json (Piper)
@@ -161,24 +624,64 @@ pub struct App {
     pub polly_error_message: Option<String>,
     /// Selected AWS Polly voice ID (e.g., "Matthew", "Joanna")
     pub selected_polly_voice: Option<String>,
-    /// Voice selection window ID
-    pub voice_selection_window_id: Option<window::Id>,
+    /// Profiles found in ~/.aws/credentials and ~/.aws/config
+    pub aws_profiles: Vec<String>,
+    /// AWS profile picked for Polly (None = AWS_PROFILE from the environment)
+    pub aws_profile: Option<String>,
+    /// AWS region picked for Polly (None = from the environment or ~/.aws/config)
+    pub aws_region: Option<String>,
+    /// Polly cost in US cents above which a synthesis is flagged (None = off)
+    pub polly_cost_warning_cents: Option<u32>,
     /// Voice currently being downloaded (if any)
     pub downloading_voice: Option<String>,
-    /// AWS Polly info modal window ID
-    pub polly_info_window_id: Option<window::Id>,
-    /// Path to the captured screenshot file
+    /// Piper voice whose license is shown in the voice list
+    pub voice_license_shown: Option<String>,
+    /// Voice licenses looked up so far, by voice key
+    pub voice_licenses: HashMap<String, Result<crate::voices::license::VoiceLicense, String>>,
+    /// Storage folder for models and caches in use (empty = default locations)
+    pub storage_folder: String,
+    /// Storage folder as typed, moved to on request
+    pub storage_folder_input: String,
+    /// Whether models and caches are being moved
+    pub storage_moving: bool,
+    /// Result of the last move (message or error)
+    pub storage_status: Option<Result<String, String>>,
+    /// Whether synthesized readings are cached on disk
+    pub audio_cache_enabled: bool,
+    /// Size limit of the audio cache, in megabytes
+    pub audio_cache_limit_mb: u32,
+    /// Current size of the audio cache in bytes, once measured
+    pub audio_cache_size: Option<u64>,
+    /// Result of the last cache clearing (message or error)
+    pub audio_cache_status: Option<Result<String, String>>,
+    /// Voice and text of the current reading, until its audio is cached
+    pub audio_cache_pending: Option<(String, String)>,
+    /// Hours screenshot captures are kept after OCR (None = deleted right away)
+    pub capture_retention_hours: Option<u32>,
+    /// Result of the last capture clearing (message or error)
+    pub capture_cache_status: Option<Result<String, String>>,
+    /// Voice pack path typed for import or export
+    pub voice_pack_path: String,
+    /// Whether a voice pack is being imported or exported
+    pub voice_pack_busy: bool,
+    /// Result of the last voice pack import or export (message or error)
+    pub voice_pack_status: Option<Result<String, String>>,
+    /// Path to the captured screenshot file (deleted after OCR unless captures are kept)
     pub screenshot_path: Option<String>,
-    /// Screenshot viewer window ID
-    pub screenshot_window_id: Option<window::Id>,
+    /// Captured screenshot, kept in memory for the viewer once its file is gone
+    pub screenshot_image: Option<iced::widget::image::Handle>,
+    /// Size in pixels of the captured screenshot, telling captures of different areas apart
+    pub screenshot_size: Option<(u32, u32)>,
     /// Selected OCR backend
     pub selected_ocr_backend: OCRBackend,
-    /// Better OCR info modal window ID
-    pub ocr_info_window_id: Option<window::Id>,
-    /// Natural Reading info modal window ID
-    pub text_cleanup_info_window_id: Option<window::Id>,
-    /// Extracted text dialog window ID
-    pub extracted_text_dialog_window_id: Option<window::Id>,
+    /// URL captures are posted to by the remote OCR backend
+    pub ocr_endpoint: String,
+    /// Whether captures are read as a single column instead of column by column
+    pub ocr_single_column: bool,
+    /// Whether repeated captures only read the lines not read from earlier ones
+    pub ocr_new_lines_only: bool,
+    /// Lines read from earlier captures, while only new lines are read
+    pub seen_capture_lines: crate::system::SeenLines,
     /// Extracted text to display in dialog (editable)
     pub extracted_text: Option<String>,
     /// Text editor content state for the extracted text dialog
@@ -189,14 +692,246 @@ pub struct App {
     pub window_hidden: bool,
     /// Hotkey manager for global shortcuts
     pub hotkey_manager: Option<crate::system::HotkeyManager>,
-    /// Current hotkey configuration
+    /// Current hotkey configuration (reading the selection)
     pub hotkey_config: crate::system::HotkeyConfig,
-    /// Whether hotkey is enabled
+    /// Hotkey pausing and resuming the current reading
+    pub pause_hotkey_config: crate::system::HotkeyConfig,
+    /// Hotkey stopping the current reading
+    pub stop_hotkey_config: crate::system::HotkeyConfig,
+    /// Whether hotkeys are enabled
     pub hotkey_enabled: bool,
-    /// Whether currently listening for hotkey input
-    pub listening_for_hotkey: bool,
+    /// Action whose hotkey is being set, while listening for hotkey input
+    pub listening_for_hotkey: Option<crate::system::HotkeyAction>,
     /// Whether hotkeys are disabled due to Wayland/Hyprland (not supported)
     pub hotkeys_disabled_wayland: bool,
+    /// Local text processing options applied before synthesis
+    pub text_processing: crate::system::TextProcessingOptions,
+    /// File path typed for lexicon import/export
+    pub lexicon_path: String,
+    /// Result of the last lexicon import/export
+    pub lexicon_status: Option<Result<String, String>>,
+    /// Word of the lexicon entry being added
+    pub new_lexicon_word: String,
+    /// Spoken form of the lexicon entry being added
+    pub new_lexicon_alias: String,
+    /// Pattern of the filter rule being added
+    pub new_filter_pattern: String,
+    /// Replacement of the filter rule being added
+    pub new_filter_replacement: String,
+    /// Why the filter rule being added was rejected
+    pub filter_error: Option<String>,
+    /// Sample text the filter rules are tried on in settings
+    pub filter_test: iced::widget::text_editor::Content,
+    /// Coqui TTS server connection settings
+    pub coqui_config: crate::providers::CoquiConfig,
+    /// OpenTTS / Mimic 3 server connection settings
+    pub opentts_config: crate::providers::OpenTTSConfig,
+    /// Names of the voices in command-voices.toml
+    pub command_voices: Vec<String>,
+    /// Selected command voice (None = first in the file)
+    pub command_voice: Option<String>,
+    /// Error reading command-voices.toml, if any
+    pub command_voices_error: Option<String>,
+    /// Playback speed multiplier (one of `SPEED_PRESETS` unless edited by hand)
+    pub playback_speed: f32,
+    /// Speed adjustments by language family, as factors of `playback_speed` (see `speed_calibration`)
+    pub language_speeds: HashMap<String, f32>,
+    /// Speed calibration wizard in progress, if any
+    pub speed_calibration: Option<crate::speed_calibration::SpeedCalibration>,
+    /// Pitch offset in semitones (0.0 = the voice's own pitch)
+    pub pitch_semitones: f32,
+    /// Listening time left at the current speed, in seconds
+    pub remaining_seconds: f32,
+    /// Optional "finish within" target in minutes used for speed suggestions
+    pub finish_target_minutes: Option<u32>,
+    /// Speed needed to meet `finish_target_minutes`, if faster than the current one
+    pub suggested_speed: Option<f32>,
+    /// Texts waiting to be read after the current one
+    pub reading_queue: crate::queue::ReadingQueue,
+    /// Engine, voice and speed of the current reading, when it has its own (queued items)
+    pub item_voice: crate::queue::VoiceOverride,
+    /// Where the pages of the current reading start, as shares of its text (opened PDFs)
+    pub item_pages: Vec<f32>,
+    /// Queued item being dragged to a new place
+    pub queue_drag: Option<crate::queue::QueuedItemId>,
+    /// Queued item being edited, with its editor content
+    pub queue_editor: Option<(crate::queue::QueuedItemId, iced::widget::text_editor::Content)>,
+    /// Saved playlists
+    pub playlists: Vec<crate::system::Playlist>,
+    /// Name typed for a new playlist
+    pub new_playlist_name: String,
+    /// Path typed for playlist import/export
+    pub playlist_path: String,
+    /// Result of the last playlist save/import/export
+    pub playlist_status: Option<Result<String, String>>,
+    /// Path typed for a file to read
+    pub open_file_path: String,
+    /// Result of the last file opened
+    pub open_file_status: Option<Result<String, String>>,
+    /// Pause between queued items in seconds (0 = none)
+    pub queue_pause_seconds: u32,
+    /// What happens when a reading ends
+    pub end_of_reading: crate::queue::EndOfReading,
+    /// Shell command run when a reading ends, with the hook action
+    pub end_of_reading_hook: String,
+    /// Item being read, as it was started (to read it again)
+    pub reading_item: Option<crate::queue::QueuedItem>,
+    /// Reading that ended with the window kept open, read again by Play
+    pub finished_item: Option<crate::queue::QueuedItem>,
+    /// Where playback picks up after a pause or a skip
+    pub pre_roll: crate::providers::word_timing::PreRoll,
+    /// Listening minutes between break reminders (None = off)
+    pub break_interval_minutes: Option<u32>,
+    /// Idle minutes after which the engine, audio output and cached audio are released (None = never)
+    pub idle_release_minutes: Option<u32>,
+    /// Last time something was playing, paused or loading
+    pub last_active: Instant,
+    /// Break reminder text as typed in settings (empty = default)
+    pub break_message: String,
+    /// Listening time since the last break
+    pub break_timer: crate::break_reminder::BreakTimer,
+    /// Whether the phrase being spoken is a break reminder (the queue continues after it)
+    pub speaking_break_reminder: bool,
+    /// Provider replaying the last seconds of audio (or spelling a word), alongside the paused reading
+    pub replay_provider: Option<Box<dyn TTSProvider>>,
+    /// Replay (or spelling) provider waiting for its synthesis, by synthesis id
+    pub replay_synthesizing: Option<(u64, Box<dyn TTSProvider>)>,
+    /// Whether the reading was paused for the replay and resumes after it
+    pub resume_after_replay: bool,
+    /// Estimated start of each word of the current reading (to spell the word just heard)
+    pub word_timings: Option<crate::providers::word_timing::WordTimings>,
+    /// Plain text of the current reading (to read its next sentence with another voice)
+    pub reading_text: Option<String>,
+    /// Chapters detected in the current reading: start (share of the reading) and title
+    pub chapters: Vec<(f32, String)>,
+    /// Start of each page of the current reading (share of the reading), when it is an opened PDF
+    pub pages: Vec<f32>,
+    /// Whether readings are skimmed: only headings and the first sentence of each paragraph
+    pub skim_mode: bool,
+    /// The current reading's skim, while it is being skimmed
+    pub skim: Option<crate::providers::Skim>,
+    /// Whether captured selections open in the review window instead of being read right away
+    pub review_before_reading: bool,
+    /// Text in the review window, while it is open
+    pub review_editor: Option<iced::widget::text_editor::Content>,
+    /// Size in KB above which captured selections are only read after confirmation (None = always read)
+    pub large_text_limit_kb: Option<u32>,
+    /// Captured selection over the limit, waiting for confirmation
+    pub large_text: Option<LargeText>,
+    /// Whether the do-not-disturb schedule is enabled
+    pub quiet_hours_enabled: bool,
+    /// Quiet hours ranges as typed in settings
+    pub quiet_hours_input: String,
+    /// Parsed quiet hours (last valid input)
+    pub quiet_hours: Vec<crate::system::QuietRange>,
+    /// Parse error for `quiet_hours_input`, if any
+    pub quiet_hours_error: Option<String>,
+    /// Whether quiet hours are in effect right now
+    pub quiet_hours_active: bool,
+    /// Whether readings are also saved as audio files
+    pub save_readings: bool,
+    /// Folder for saved readings as typed (empty = default folder)
+    pub recordings_folder: String,
+    /// Folder synced to a phone that readings are handed off to (empty = off)
+    pub handoff_folder: String,
+    /// Text of the current reading, until its audio has been saved
+    pub recording_text: Option<String>,
+    /// URL a summary of each finished reading is POSTed to (empty = off)
+    pub listen_webhook: String,
+    /// Whether the webhook summary includes the text that was read
+    pub listen_webhook_text: bool,
+    /// Summary of the current reading, posted to the webhook when it finishes
+    pub listened_reading: Option<crate::system::ReadingSummary>,
+    /// Outcome of the last webhook post
+    pub listen_webhook_status: Option<Result<String, String>>,
+    /// How much of each reading is logged to the daily transcript
+    pub transcript_mode: crate::system::TranscriptMode,
+    /// File format of the daily transcript
+    pub transcript_format: crate::system::TranscriptFormat,
+    /// Subscribed RSS/Atom feeds for the morning briefing
+    pub feeds: Vec<crate::system::FeedSubscription>,
+    /// Ids of feed items already read, so briefings only bring new ones
+    pub heard_feed_items: Vec<String>,
+    /// URL typed for a new feed
+    pub new_feed_url: String,
+    /// File path typed for OPML import
+    pub opml_path: String,
+    /// Result of the last feed import or briefing fetch
+    pub feeds_status: Option<Result<String, String>>,
+    /// Whether the briefing feeds are being fetched
+    pub briefing_fetching: bool,
+    /// URLs and files whose added paragraphs are read
+    pub watched_documents: Vec<String>,
+    /// URL or file path typed for a new watched document
+    pub new_watch_source: String,
+    /// Result of the last check of the watched documents
+    pub watch_status: Option<Result<String, String>>,
+    /// Whether the watched documents are being checked
+    pub watch_checking: bool,
+    /// Output device and volume used when no workspace profile is active
+    pub output: crate::providers::OutputSettings,
+    /// Names of the available audio output devices
+    pub output_devices: Vec<String>,
+    /// Virtual microphone setup result: the name other apps see, or the error
+    pub virtual_mic_status: Option<Result<String, String>>,
+    /// Whether workspace profiles switch automatically by network/hostname
+    pub profiles_auto_switch: bool,
+    /// Workspace profiles in match priority order
+    pub profiles: Vec<crate::system::ReadingProfile>,
+    /// Last detected Wi-Fi network and hostname
+    pub network: crate::system::NetworkContext,
+    /// Index of the workspace profile in effect, if any
+    pub active_profile: Option<usize>,
+    /// Name typed for a new workspace profile
+    pub new_profile_name: String,
+    /// SSIDs/hostnames typed for a new workspace profile
+    pub new_profile_match: String,
+    /// Phrase board entries in display order
+    pub phrases: Vec<String>,
+    /// Text typed for a new phrase
+    pub new_phrase: String,
+    /// Whether the phrase board shows its edit controls
+    pub editing_phrases: bool,
+    /// Whether the current playback is a phrase (finishing it keeps windows open)
+    pub speaking_phrase: bool,
+    /// Report written when the app last crashed
+    pub crash_report: Option<std::path::PathBuf>,
+    /// Result of the last open/copy of the crash report
+    pub crash_report_status: Option<Result<String, String>>,
+    /// Whether the latency overlay is shown
+    pub debug_overlay: bool,
+    /// Read-later service connection settings
+    pub read_later: crate::system::ReadLaterSettings,
+    /// Unread articles from the read-later service
+    pub read_later_articles: Vec<crate::system::Article>,
+    /// Whether the unread articles are being fetched
+    pub read_later_loading: bool,
+    /// Error from the last read-later request
+    pub read_later_error: Option<String>,
+    /// Id of the article being read, archived once playback finishes
+    pub reading_article: Option<String>,
+    /// Key of the text being read, for remembering where it was stopped
+    pub reading_key: Option<String>,
+    /// Saved position (seconds at normal speed) offered when the text was read before
+    pub resume_offer: Option<f32>,
+    /// Position being resumed to, reached as soon as enough audio is synthesized
+    pub resume_target: Option<f32>,
+    /// Start of the A–B loop being marked (seconds at normal speed)
+    pub loop_start: Option<f32>,
+    /// A–B loop being played, as (start, end) seconds at normal speed
+    pub loop_region: Option<(f32, f32)>,
+    /// Calendar read by the agenda action
+    pub calendar: crate::system::CalendarSettings,
+    /// Whether today's agenda is being fetched
+    pub agenda_fetching: bool,
+    /// Result of the last agenda fetch (shown in settings)
+    pub calendar_status: Option<Result<String, String>>,
+    /// Line being typed in the type-to-talk box
+    pub talk_input: String,
+    /// Lines spoken this session, oldest first
+    pub talk_history: Vec<String>,
+    /// What is spoken while typing in the type-to-talk box
+    pub typing_echo: TypingEcho,
 }
 
 impl Default for App {
@@ -206,42 +941,187 @@ impl Default for App {
             progress: 0.0,
             frequency_bands: vec![0.0; 10],
             provider: None,
+            synthesizing: None,
             selected_backend: TTSBackend::Piper,
             log_level: LogLevel::Info,
             text_cleanup_enabled: false,
+            text_transforms: vec![crate::system::TransformStep::NaturalReading],
+            new_transform: crate::system::TransformStep::NaturalReading,
             show_settings_modal: false,
-            settings_window_id: None,
-            current_window_id: None,
-            main_window_id: None,
+            windows: HashMap::new(),
             pending_text: None,
             error_message: None,
             is_loading: false,
             loading_animation_time: 0.0,
             status_text: None,
+            buffering: false,
             selected_voice: None,
+            mixed_language: false,
+            language_voices: std::collections::BTreeMap::new(),
+            auto_language: false,
+            compare_voice: String::new(),
+            voice_comparison: None,
+            language_prompt: None,
+            synthesis_load: Default::default(),
             selected_language: None,
             voices: None,
             polly_voices: None,
             polly_error_message: None,
             selected_polly_voice: None,
-            voice_selection_window_id: None,
+            aws_profiles: Vec::new(),
+            aws_profile: None,
+            aws_region: None,
+            polly_cost_warning_cents: None,
             downloading_voice: None,
-            polly_info_window_id: None,
+            voice_license_shown: None,
+            voice_licenses: HashMap::new(),
+            storage_folder: String::new(),
+            storage_folder_input: String::new(),
+            storage_moving: false,
+            storage_status: None,
+            audio_cache_enabled: true,
+            audio_cache_limit_mb: crate::providers::audio_cache::DEFAULT_CACHE_LIMIT_MB,
+            audio_cache_size: None,
+            audio_cache_status: None,
+            audio_cache_pending: None,
+            capture_retention_hours: None,
+            capture_cache_status: None,
+            voice_pack_path: String::new(),
+            voice_pack_busy: false,
+            voice_pack_status: None,
             screenshot_path: None,
-            screenshot_window_id: None,
+            screenshot_image: None,
+            screenshot_size: None,
             selected_ocr_backend: OCRBackend::Default,
-            ocr_info_window_id: None,
-            text_cleanup_info_window_id: None,
-            extracted_text_dialog_window_id: None,
+            ocr_endpoint: String::new(),
+            ocr_single_column: false,
+            ocr_new_lines_only: false,
+            seen_capture_lines: crate::system::SeenLines::default(),
             extracted_text: None,
             extracted_text_editor: None,
             system_tray: None,
             window_hidden: false,
             hotkey_manager: None,
             hotkey_config: crate::system::HotkeyConfig::default(),
+            pause_hotkey_config: crate::system::HotkeyAction::PauseResume.default_config(),
+            stop_hotkey_config: crate::system::HotkeyAction::Stop.default_config(),
             hotkey_enabled: false,
-            listening_for_hotkey: false,
+            listening_for_hotkey: None,
             hotkeys_disabled_wayland: false,
+            text_processing: crate::system::TextProcessingOptions::default(),
+            lexicon_path: String::new(),
+            lexicon_status: None,
+            new_lexicon_word: String::new(),
+            new_lexicon_alias: String::new(),
+            new_filter_pattern: String::new(),
+            new_filter_replacement: String::new(),
+            filter_error: None,
+            filter_test: iced::widget::text_editor::Content::new(),
+            coqui_config: crate::providers::CoquiConfig::default(),
+            opentts_config: crate::providers::OpenTTSConfig::default(),
+            command_voices: Vec::new(),
+            command_voice: None,
+            command_voices_error: None,
+            playback_speed: 1.0,
+            language_speeds: HashMap::new(),
+            speed_calibration: None,
+            pitch_semitones: 0.0,
+            remaining_seconds: 0.0,
+            finish_target_minutes: None,
+            suggested_speed: None,
+            reading_queue: crate::queue::ReadingQueue::default(),
+            item_voice: crate::queue::VoiceOverride::default(),
+            item_pages: Vec::new(),
+            queue_drag: None,
+            queue_editor: None,
+            playlists: Vec::new(),
+            new_playlist_name: String::new(),
+            playlist_path: String::new(),
+            playlist_status: None,
+            open_file_path: String::new(),
+            open_file_status: None,
+            queue_pause_seconds: crate::queue::DEFAULT_QUEUE_PAUSE_SECONDS,
+            end_of_reading: crate::queue::EndOfReading::default(),
+            end_of_reading_hook: String::new(),
+            reading_item: None,
+            finished_item: None,
+            pre_roll: Default::default(),
+            break_interval_minutes: None,
+            idle_release_minutes: Some(DEFAULT_IDLE_RELEASE_MINUTES),
+            skim_mode: false,
+            skim: None,
+            review_before_reading: false,
+            review_editor: None,
+            large_text_limit_kb: Some(DEFAULT_LARGE_TEXT_KB),
+            large_text: None,
+            last_active: Instant::now(),
+            break_message: String::new(),
+            break_timer: crate::break_reminder::BreakTimer::default(),
+            speaking_break_reminder: false,
+            replay_provider: None,
+            replay_synthesizing: None,
+            resume_after_replay: false,
+            word_timings: None,
+            reading_text: None,
+            chapters: Vec::new(),
+            pages: Vec::new(),
+            quiet_hours_enabled: false,
+            quiet_hours_input: String::new(),
+            quiet_hours: Vec::new(),
+            quiet_hours_error: None,
+            quiet_hours_active: false,
+            save_readings: false,
+            recordings_folder: String::new(),
+            handoff_folder: String::new(),
+            recording_text: None,
+            listen_webhook: String::new(),
+            listen_webhook_text: false,
+            listened_reading: None,
+            listen_webhook_status: None,
+            transcript_mode: crate::system::TranscriptMode::Off,
+            transcript_format: crate::system::TranscriptFormat::Markdown,
+            feeds: Vec::new(),
+            heard_feed_items: Vec::new(),
+            new_feed_url: String::new(),
+            opml_path: String::new(),
+            feeds_status: None,
+            briefing_fetching: false,
+            watched_documents: Vec::new(),
+            new_watch_source: String::new(),
+            watch_status: None,
+            watch_checking: false,
+            output: crate::providers::OutputSettings::default(),
+            output_devices: Vec::new(),
+            virtual_mic_status: None,
+            profiles_auto_switch: false,
+            profiles: Vec::new(),
+            network: crate::system::NetworkContext::default(),
+            active_profile: None,
+            new_profile_name: String::new(),
+            new_profile_match: String::new(),
+            phrases: Vec::new(),
+            new_phrase: String::new(),
+            editing_phrases: false,
+            speaking_phrase: false,
+            crash_report: None,
+            crash_report_status: None,
+            debug_overlay: false,
+            read_later: crate::system::ReadLaterSettings::default(),
+            read_later_articles: Vec::new(),
+            read_later_loading: false,
+            read_later_error: None,
+            reading_article: None,
+            reading_key: None,
+            resume_offer: None,
+            resume_target: None,
+            loop_start: None,
+            loop_region: None,
+            calendar: crate::system::CalendarSettings::default(),
+            agenda_fetching: false,
+            calendar_status: None,
+            talk_input: String::new(),
+            talk_history: Vec::new(),
+            typing_echo: TypingEcho::Off,
         }
     }
 }
@@ -253,49 +1133,355 @@ impl App {
         let log_level = config::load_log_level();
         let text_cleanup_enabled = config::load_text_cleanup_enabled();
         let selected_voice = config::load_selected_voice();
+        let (mixed_language, language_voices) = config::load_language_voices();
         let selected_ocr_backend = config::load_ocr_backend();
         let (hotkey_config, hotkey_enabled) = config::load_hotkey_config();
+        let (quiet_hours_enabled, quiet_hours_input) = config::load_quiet_hours();
+        let (break_interval_minutes, break_message) = config::load_break_reminder();
+        let (save_readings, recordings_folder) = config::load_recordings();
+        let (transcript_mode, transcript_format) = config::load_transcript();
+        let (listen_webhook, listen_webhook_text) = config::load_listen_webhook();
+        let (end_of_reading, end_of_reading_hook) = config::load_end_of_reading();
+        let (audio_cache_enabled, audio_cache_limit_mb) = config::load_audio_cache();
+        let capture_retention_hours = config::load_capture_retention_hours();
+        let storage_folder = config::load_storage_folder()
+            .map(|folder| folder.display().to_string())
+            .unwrap_or_default();
+        let (command_voices, command_voices_error) = load_command_voice_names();
+        let (profiles_auto_switch, profiles) = config::load_profiles();
         Self {
             playback_state: PlaybackState::Stopped,
             progress: 0.0,
             frequency_bands: vec![0.0; 10],
             provider: None,
+            synthesizing: None,
             selected_backend,
             log_level,
             text_cleanup_enabled,
+            text_transforms: config::load_text_transforms(),
+            new_transform: crate::system::TransformStep::NaturalReading,
             show_settings_modal: false,
-            settings_window_id: None,
-            current_window_id: None,
-            main_window_id: None,
+            windows: HashMap::new(),
             pending_text,
             error_message: None,
             is_loading: false,
             loading_animation_time: 0.0,
             status_text: None,
+            buffering: false,
             selected_voice,
+            mixed_language,
+            language_voices,
+            auto_language: config::load_auto_language(),
+            compare_voice: config::load_compare_voice(),
+            voice_comparison: None,
+            language_prompt: None,
+            synthesis_load: config::load_synthesis_load(),
             selected_language: None,
             voices: None,
             polly_voices: None,
             polly_error_message: None,
             selected_polly_voice: config::load_selected_polly_voice(),
-            voice_selection_window_id: None,
+            aws_profiles: crate::voices::aws::list_aws_profiles(),
+            aws_profile: config::load_aws_profile(),
+            aws_region: config::load_aws_region(),
+            polly_cost_warning_cents: config::load_polly_cost_warning_cents(),
             downloading_voice: None,
-            polly_info_window_id: None,
+            voice_license_shown: None,
+            voice_licenses: HashMap::new(),
+            storage_folder: storage_folder.clone(),
+            storage_folder_input: storage_folder,
+            storage_moving: false,
+            storage_status: None,
+            audio_cache_enabled,
+            audio_cache_limit_mb,
+            audio_cache_size: None,
+            audio_cache_status: None,
+            audio_cache_pending: None,
+            capture_retention_hours,
+            capture_cache_status: None,
+            voice_pack_path: String::new(),
+            voice_pack_busy: false,
+            voice_pack_status: None,
             screenshot_path: None,
-            screenshot_window_id: None,
+            screenshot_image: None,
+            screenshot_size: None,
             selected_ocr_backend,
-            ocr_info_window_id: None,
-            text_cleanup_info_window_id: None,
-            extracted_text_dialog_window_id: None,
+            ocr_endpoint: config::load_ocr_endpoint(),
+            ocr_single_column: config::load_ocr_single_column(),
+            ocr_new_lines_only: config::load_ocr_new_lines_only(),
+            seen_capture_lines: crate::system::SeenLines::default(),
             extracted_text: None,
             extracted_text_editor: None,
             system_tray: None,
             window_hidden: false,
             hotkey_manager: None,
             hotkey_config,
+            pause_hotkey_config: config::load_action_hotkey(crate::system::HotkeyAction::PauseResume),
+            stop_hotkey_config: config::load_action_hotkey(crate::system::HotkeyAction::Stop),
             hotkey_enabled,
-            listening_for_hotkey: false,
+            listening_for_hotkey: None,
             hotkeys_disabled_wayland: crate::system::is_wayland_hyprland(),
+            text_processing: config::load_text_processing_options(),
+            lexicon_path: String::new(),
+            lexicon_status: None,
+            new_lexicon_word: String::new(),
+            new_lexicon_alias: String::new(),
+            new_filter_pattern: String::new(),
+            new_filter_replacement: String::new(),
+            filter_error: None,
+            filter_test: iced::widget::text_editor::Content::new(),
+            coqui_config: config::load_coqui_config(),
+            opentts_config: config::load_opentts_config(),
+            command_voices,
+            command_voice: config::load_command_voice(),
+            command_voices_error,
+            playback_speed: config::load_playback_speed(),
+            language_speeds: config::load_language_speeds(),
+            speed_calibration: None,
+            pitch_semitones: config::load_pitch_semitones(),
+            remaining_seconds: 0.0,
+            finish_target_minutes: config::load_finish_target_minutes(),
+            suggested_speed: None,
+            reading_queue: crate::queue::ReadingQueue::default(),
+            item_voice: crate::queue::VoiceOverride::default(),
+            item_pages: Vec::new(),
+            queue_drag: None,
+            queue_editor: None,
+            playlists: crate::system::load_playlists(),
+            new_playlist_name: String::new(),
+            playlist_path: String::new(),
+            playlist_status: None,
+            open_file_path: String::new(),
+            open_file_status: None,
+            queue_pause_seconds: config::load_queue_pause_seconds(),
+            end_of_reading,
+            end_of_reading_hook,
+            reading_item: None,
+            finished_item: None,
+            pre_roll: config::load_pre_roll(),
+            break_interval_minutes,
+            idle_release_minutes: config::load_idle_release_minutes(),
+            skim_mode: config::load_skim_mode(),
+            skim: None,
+            review_before_reading: config::load_review_before_reading(),
+            review_editor: None,
+            large_text_limit_kb: config::load_large_text_limit_kb(),
+            large_text: None,
+            last_active: Instant::now(),
+            break_message,
+            break_timer: crate::break_reminder::BreakTimer::default(),
+            speaking_break_reminder: false,
+            replay_provider: None,
+            replay_synthesizing: None,
+            resume_after_replay: false,
+            word_timings: None,
+            reading_text: None,
+            chapters: Vec::new(),
+            pages: Vec::new(),
+            quiet_hours_enabled,
+            quiet_hours: crate::system::parse_quiet_hours(&quiet_hours_input).unwrap_or_default(),
+            quiet_hours_input,
+            quiet_hours_error: None,
+            quiet_hours_active: false,
+            save_readings,
+            recordings_folder,
+            handoff_folder: config::load_handoff_folder(),
+            recording_text: None,
+            listen_webhook,
+            listen_webhook_text,
+            listened_reading: None,
+            listen_webhook_status: None,
+            transcript_mode,
+            transcript_format,
+            feeds: config::load_feeds(),
+            heard_feed_items: config::load_heard_feed_items(),
+            new_feed_url: String::new(),
+            opml_path: String::new(),
+            feeds_status: None,
+            briefing_fetching: false,
+            watched_documents: config::load_watched_documents(),
+            new_watch_source: String::new(),
+            watch_status: None,
+            watch_checking: false,
+            output: config::load_output_settings(),
+            output_devices: Vec::new(),
+            virtual_mic_status: None,
+            profiles_auto_switch,
+            profiles,
+            network: crate::system::NetworkContext::default(),
+            active_profile: None,
+            new_profile_name: String::new(),
+            new_profile_match: String::new(),
+            phrases: config::load_phrases(),
+            new_phrase: String::new(),
+            editing_phrases: false,
+            speaking_phrase: false,
+            crash_report: None,
+            crash_report_status: None,
+            debug_overlay: config::load_debug_overlay(),
+            read_later: config::load_read_later_settings(),
+            read_later_articles: Vec::new(),
+            read_later_loading: false,
+            read_later_error: None,
+            reading_article: None,
+            reading_key: None,
+            resume_offer: None,
+            resume_target: None,
+            loop_start: None,
+            loop_region: None,
+            calendar: config::load_calendar_settings(),
+            agenda_fetching: false,
+            calendar_status: None,
+            talk_input: String::new(),
+            talk_history: Vec::new(),
+            typing_echo: config::load_typing_echo(),
+        }
+    }
+
+    /// Language family ("en", "ar", …) of the selected voice, when known.
+    pub fn voice_language_family(&self) -> Option<String> {
+        match self.selected_backend {
+            TTSBackend::Piper => {
+                let voice = self.selected_voice.as_deref().unwrap_or("en_US-lessac-medium");
+                voice.split(['_', '-']).next().map(str::to_string)
+            }
+            TTSBackend::AwsPolly => {
+                let key = self.selected_polly_voice.as_deref()?;
+                Some(self.polly_voices.as_ref()?.get(key)?.language.family.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Locale ("de_DE") of the voice the current item is read with, when known.
+    pub fn voice_locale(&self) -> Option<String> {
+        let backend = self.item_voice.backend().unwrap_or(self.selected_backend);
+        let voice = self.item_voice.voice.as_deref().filter(|_| self.item_voice.backend().is_some());
+        match backend {
+            TTSBackend::Piper => {
+                let voice = voice.or(self.selected_voice.as_deref()).unwrap_or("en_US-lessac-medium");
+                voice.split('-').next().map(str::to_string)
+            }
+            TTSBackend::AwsPolly => {
+                let key = voice.or(self.selected_polly_voice.as_deref())?;
+                Some(self.polly_voices.as_ref()?.get(key)?.language.code.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Speed the current item is read at: the playback speed with its voice language's adjustment.
+    pub fn reading_speed(&self) -> f32 {
+        let locale = self.voice_locale();
+        let family = locale.as_deref().and_then(|locale| locale.split(['_', '-']).next());
+        crate::speed_calibration::language_speed(self.playback_speed, &self.language_speeds, family)
+    }
+
+    /// Voice list language code for `language` with the selected backend, if it has voices for it.
+    pub fn voice_language_code(&self, language: crate::system::RtlLanguage) -> Option<String> {
+        self.voice_list_code(language.code())
+    }
+
+    /// Voice list language code ("es_ES") for a language family ("es") with the selected backend.
+    pub fn voice_list_code(&self, family: &str) -> Option<String> {
+        let mut codes: Vec<&str> = match self.selected_backend {
+            TTSBackend::Piper => self.voices.as_ref()?.values().map(|v| v.language.code.as_str()).collect(),
+            TTSBackend::AwsPolly => self.polly_voices.as_ref()?.values().map(|v| v.language.code.as_str()).collect(),
+            _ => return None,
+        };
+        codes.sort_unstable();
+        codes
+            .into_iter()
+            .find(|code| code.starts_with(family))
+            .map(str::to_string)
+    }
+
+    /// Output settings in effect: the active workspace profile's, else the defaults.
+    pub fn effective_output(&self) -> &crate::providers::OutputSettings {
+        self.active_profile
+            .and_then(|index| self.profiles.get(index))
+            .map_or(&self.output, |profile| &profile.output)
+    }
+
+    /// Id of the open window with `role`.
+    pub fn window_id(&self, role: WindowRole) -> Option<window::Id> {
+        self.windows.iter().find(|(_, r)| **r == role).map(|(id, _)| *id)
+    }
+
+    /// The hotkey configured for `action`.
+    pub fn hotkey_config_for(&self, action: crate::system::HotkeyAction) -> &crate::system::HotkeyConfig {
+        match action {
+            crate::system::HotkeyAction::Read => &self.hotkey_config,
+            crate::system::HotkeyAction::PauseResume => &self.pause_hotkey_config,
+            crate::system::HotkeyAction::Stop => &self.stop_hotkey_config,
         }
     }
+
+    /// Whether a window with `role` is open.
+    pub fn has_window(&self, role: WindowRole) -> bool {
+        self.window_id(role).is_some()
+    }
+
+    /// Role of the window `id`, if it is one of the app's open windows.
+    pub fn window_role(&self, id: window::Id) -> Option<WindowRole> {
+        self.windows.get(&id).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining_seconds() {
+        assert_eq!(remaining_seconds(120.0, 0.5, 1.0), 60.0);
+        assert_eq!(remaining_seconds(120.0, 0.5, 2.0), 30.0);
+        assert_eq!(remaining_seconds(120.0, 1.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_suggested_speed() {
+        // 14 minutes left, 10 minute target -> 1.4x
+        assert_eq!(suggested_speed(840.0, 1.0, 10), Some(1.4));
+        // Already fast enough
+        assert_eq!(suggested_speed(840.0, 1.5, 10), None);
+        // Capped
+        assert_eq!(suggested_speed(3600.0, 1.0, 5), Some(MAX_SUGGESTED_SPEED));
+    }
+
+    #[test]
+    fn test_large_text() {
+        let text = "First paragraph.\n\nSecond one,\non two lines.\n\n\nThird.";
+        let large = LargeText::new(text.to_string(), 50, 1.0);
+        assert_eq!(large.first_paragraphs(2), "First paragraph.\n\nSecond one,\non two lines.");
+        assert_eq!(LargeText::new("One line.\nAnother line.\n".to_string(), 5, 1.0).first_paragraphs(1), "One line.");
+        assert_eq!(large.paragraphs, 2);
+        assert_eq!(LargeText::new(text.to_string(), 10, 1.0).paragraphs, 0);
+        assert_eq!(estimated_seconds(&"a".repeat(900), 2.0), 30.0);
+    }
+
+    #[test]
+    fn test_typing_echo() {
+        assert_eq!(TypingEcho::Characters.echo("Hi", "Hi!").as_deref(), Some("!"));
+        assert_eq!(TypingEcho::Characters.echo("Hi", "Hi ").as_deref(), Some("space"));
+        assert_eq!(TypingEcho::Words.echo("I don't", "I don't,").as_deref(), Some("don't"));
+        assert_eq!(TypingEcho::Words.echo("I don", "I don'"), None);
+        assert_eq!(TypingEcho::Words.echo("Hi, ", "Hi,  "), None);
+        assert_eq!(TypingEcho::Characters.echo("Hi", "Hi there"), None);
+        assert_eq!(TypingEcho::Characters.echo("Hi", "H"), None);
+        assert_eq!(TypingEcho::Off.echo("Hi", "Hi "), None);
+    }
+
+    #[test]
+    fn test_window_roles() {
+        let mut app = App::default();
+        let (main, settings) = (window::Id::unique(), window::Id::unique());
+        app.windows.insert(main, WindowRole::Main);
+        app.windows.insert(settings, WindowRole::Settings);
+        assert_eq!(app.window_id(WindowRole::Settings), Some(settings));
+        assert_eq!(app.window_role(main), Some(WindowRole::Main));
+        assert!(!app.has_window(WindowRole::Queue));
+        app.windows.remove(&settings);
+        assert_eq!(app.window_role(settings), None);
+    }
 }
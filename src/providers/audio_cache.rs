@@ -0,0 +1,159 @@
+//! On-disk cache of synthesized readings.
+//!
+//! The audio of every fully synthesized reading is kept as a 16-bit WAV in the
+//! cache folder, keyed by a hash of the voice and the text. Reading the same
+//! text again (or after a restart) replays it without synthesizing, which is
+//! instant and avoids paying for Polly twice. Speed and loudness are applied
+//! at playback, so one entry serves every speed. The least recently used
+//! entries are dropped once the cache outgrows its size limit.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use tracing::{debug, warn};
+
+use super::wav::{decode_wav, encode_wav_with_info};
+use super::SynthesizedAudio;
+
+/// Size limits offered in the settings, in megabytes.
+pub const CACHE_LIMIT_PRESETS: [u32; 4] = [100, 500, 1000, 5000];
+
+/// Size limit used until one is chosen, in megabytes.
+pub const DEFAULT_CACHE_LIMIT_MB: u32 = 500;
+
+/// `bytes` in megabytes for display, e.g. "12.3 MB".
+pub fn format_megabytes(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+/// Folder the cached readings are kept in.
+pub fn audio_cache_dir() -> PathBuf {
+    crate::storage::cache_dir().join("audio")
+}
+
+/// File name of the cached audio of `text` read by `voice`.
+fn entry_name(voice: &str, text: &str) -> String {
+    let key = format!("{voice}\0{text}");
+    format!("{:x}.wav", md5::compute(key.as_bytes()))
+}
+
+/// Cached audio of `text` read by `voice`, if any.
+///
+/// A hit refreshes the entry's modification time, so it is evicted last.
+pub(super) fn lookup(voice: &str, text: &str) -> Option<SynthesizedAudio> {
+    let path = audio_cache_dir().join(entry_name(voice, text));
+    let bytes = fs::read(&path).ok()?;
+    let audio = match decode_wav(&bytes) {
+        Ok(audio) => audio,
+        Err(e) => {
+            warn!(path = %path.display(), error = %e, "Dropping unreadable cached audio");
+            let _ = fs::remove_file(&path);
+            return None;
+        }
+    };
+    let _ = fs::File::options()
+        .append(true)
+        .open(&path)
+        .and_then(|file| file.set_modified(SystemTime::now()));
+    Some(SynthesizedAudio {
+        samples: Arc::new(audio.samples),
+        sample_rate: audio.sample_rate,
+    })
+}
+
+/// Cache `audio` as the reading of `text` by `voice`, then trim the cache to `limit_mb`.
+pub fn store(voice: &str, text: &str, audio: &SynthesizedAudio, limit_mb: u32) -> Result<(), String> {
+    let dir = audio_cache_dir();
+    let path = dir.join(entry_name(voice, text));
+    if !path.exists() {
+        fs::create_dir_all(&dir).map_err(|e| format!("Could not create {}: {e}", dir.display()))?;
+        let bytes = encode_wav_with_info(&audio.samples, audio.sample_rate, &[]);
+        crate::storage::write_atomic(&path, &bytes)
+            .map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+        debug!(path = %path.display(), bytes = bytes.len(), "Reading cached");
+    }
+    let evicted = trim(&dir, limit_mb as u64 * 1024 * 1024);
+    if evicted > 0 {
+        debug!(evicted, "Evicted old cached readings");
+    }
+    Ok(())
+}
+
+/// Cached entries of `dir` as (path, size, last used).
+fn entries(dir: &Path) -> Vec<(PathBuf, u64, SystemTime)> {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    read_dir
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "wav"))
+        .filter_map(|path| {
+            let metadata = fs::metadata(&path).ok()?;
+            Some((path, metadata.len(), metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH)))
+        })
+        .collect()
+}
+
+/// Entries to delete, least recently used first, so the rest fit in `limit` bytes.
+fn eviction_order(mut entries: Vec<(PathBuf, u64, SystemTime)>, limit: u64) -> Vec<PathBuf> {
+    let mut total: u64 = entries.iter().map(|(_, size, _)| size).sum();
+    entries.sort_by_key(|&(_, _, used)| used);
+    let mut evicted = Vec::new();
+    for (path, size, _) in entries {
+        if total <= limit {
+            break;
+        }
+        total -= size;
+        evicted.push(path);
+    }
+    evicted
+}
+
+/// Delete the least recently used entries of `dir` beyond `limit` bytes; returns how many.
+fn trim(dir: &Path, limit: u64) -> usize {
+    eviction_order(entries(dir), limit)
+        .into_iter()
+        .filter(|path| fs::remove_file(path).is_ok())
+        .count()
+}
+
+/// Total size of the cached readings, in bytes.
+pub fn cache_size() -> u64 {
+    entries(&audio_cache_dir()).iter().map(|(_, size, _)| size).sum()
+}
+
+/// Delete every cached reading; returns the number of bytes freed.
+pub fn clear() -> Result<u64, String> {
+    let dir = audio_cache_dir();
+    let mut freed = 0;
+    for (path, size, _) in entries(&dir) {
+        fs::remove_file(&path).map_err(|e| format!("Could not delete {}: {e}", path.display()))?;
+        freed += size;
+    }
+    Ok(freed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_eviction_order() {
+        let at = |seconds: u64| SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+        let entries = vec![
+            (PathBuf::from("new.wav"), 400, at(30)),
+            (PathBuf::from("old.wav"), 300, at(10)),
+            (PathBuf::from("mid.wav"), 300, at(20)),
+        ];
+        assert!(eviction_order(entries.clone(), 1000).is_empty());
+        assert_eq!(eviction_order(entries.clone(), 700), [PathBuf::from("old.wav")]);
+        assert_eq!(eviction_order(entries, 100), ["old.wav", "mid.wav", "new.wav"].map(PathBuf::from));
+
+        assert_eq!(entry_name("piper:a", "Hello"), entry_name("piper:a", "Hello"));
+        assert_ne!(entry_name("piper:a", "Hello"), entry_name("piper:b", "Hello"));
+    }
+}
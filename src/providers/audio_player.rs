@@ -1,17 +1,416 @@
 //! Shared audio playback infrastructure for TTS providers.
 //!
 //! Extracts common playback logic (rodio sink, position tracking, FFT visualization)
-//! so providers only need to implement audio synthesis.
+//! so providers only need to implement audio synthesis. Long texts can be streamed:
+//! playback starts with the first synthesized chunk and later chunks are appended
+//! to the live sink as they arrive. Playback can be mirrored to a second output
+//! device (e.g. a loopback device for recording or streaming) or into a virtual
+//! microphone.
+//!
+//! The sinks read from a shared buffer through [`PlayedAudioSource`], so
+//! skipping just moves the read position: nothing is re-encoded and the sinks
+//! are only rebuilt when the speed, pitch or output devices change.
+//!
+//! Loaded audio is brought to a common loudness (see [`loudness`]) unless
+//! that is turned off in the output settings, and long pauses are shortened
+//! (see [`pauses`]) when that is turned on.
 
-use std::io::Cursor;
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::Duration;
 
-use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink};
+use rodio::cpal::traits::{DeviceTrait, HostTrait};
+use rodio::{OutputStream, OutputStreamHandle, Sink, Source};
 use rustfft::{num_complex::Complex, FftPlanner};
-use tracing::{debug, error, trace};
+use tracing::{debug, error, trace, warn};
+
+use super::chunking::ChunkText;
+use super::loudness;
+use super::pauses;
+use super::replay_buffer;
+use super::time_stretch::stretch_and_shift;
+use super::virtual_mic;
+use super::{Speech, Synthesis, TTSError};
+
+/// Which side of the output speech is played on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChannelRouting {
+    /// The same sound on both sides
+    #[default]
+    Both,
+    /// Left side only (e.g. a single-ear headset)
+    Left,
+    /// Right side only
+    Right,
+}
+
+/// Where and how loud synthesized speech is played.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct OutputSettings {
+    /// Output device name (None = system default)
+    #[serde(default)]
+    pub device: Option<String>,
+    /// Volume multiplier (0.0 - 1.0)
+    #[serde(default = "default_volume")]
+    pub volume: f32,
+    /// Second device that plays the same audio at the same time (None = off)
+    #[serde(default)]
+    pub mirror_device: Option<String>,
+    /// Also speak into a virtual microphone (takes the mirror's place)
+    #[serde(default)]
+    pub virtual_mic: bool,
+    /// Bring every voice to the same loudness
+    #[serde(default = "default_normalize_loudness")]
+    pub normalize_loudness: bool,
+    /// Cut long silences between sentences short
+    #[serde(default)]
+    pub shorten_pauses: bool,
+    /// Side of the primary output speech is played on
+    #[serde(default)]
+    pub channels: ChannelRouting,
+}
+
+fn default_volume() -> f32 {
+    1.0
+}
+
+fn default_normalize_loudness() -> bool {
+    true
+}
+
+impl Default for OutputSettings {
+    fn default() -> Self {
+        Self {
+            device: None,
+            volume: default_volume(),
+            mirror_device: None,
+            virtual_mic: false,
+            normalize_loudness: default_normalize_loudness(),
+            shorten_pauses: false,
+            channels: ChannelRouting::Both,
+        }
+    }
+}
+
+/// Fully synthesized speech, as kept by the phrase cache.
+#[derive(Debug, Clone)]
+pub struct SynthesizedAudio {
+    /// Normalized f32 samples (-1.0 to 1.0) at normal speed
+    pub samples: Arc<Vec<f32>>,
+    /// Sample rate of `samples`
+    pub sample_rate: u32,
+}
+
+/// List the names of the available audio output devices.
+pub fn list_output_devices() -> Vec<String> {
+    match rodio::cpal::default_host().output_devices() {
+        Ok(devices) => devices.filter_map(|device| device.name().ok()).collect(),
+        Err(e) => {
+            warn!(error = %e, "Failed to list audio output devices");
+            Vec::new()
+        }
+    }
+}
+
+/// Open an output stream on the named device, if it exists and can be opened.
+pub(super) fn open_named_output(name: &str) -> Option<(OutputStream, OutputStreamHandle)> {
+    let found = rodio::cpal::default_host()
+        .output_devices()
+        .ok()
+        .and_then(|mut devices| devices.find(|d| d.name().is_ok_and(|n| n == name)));
+    match found.map(|d| OutputStream::try_from_device(&d)) {
+        Some(Ok(output)) => Some(output),
+        Some(Err(e)) => {
+            warn!(device = name, error = %e, "Failed to open output device");
+            None
+        }
+        None => {
+            warn!(device = name, "Output device not found");
+            None
+        }
+    }
+}
+
+/// Open an output stream on the named device, falling back to the system default.
+fn open_output(device: Option<&str>) -> Result<(OutputStream, OutputStreamHandle), TTSError> {
+    if let Some(output) = device.and_then(open_named_output) {
+        return Ok(output);
+    }
+    OutputStream::try_default().map_err(|e| {
+        error!("Failed to open audio output: {e}");
+        TTSError::AudioError(format!("Failed to open audio output: {e}"))
+    })
+}
+
+/// Samples read by a source at a time; bounds how late a seek is heard.
+const SOURCE_BLOCK: usize = 512;
+
+/// Length of the fade on pause, stop and seek, in milliseconds.
+const FADE_MS: u32 = 80;
+
+/// How long the sinks keep playing after a pause or stop, for the fade: the fade plus a block at low sample rates.
+const FADE_WAIT: Duration = Duration::from_millis(FADE_MS as u64 + 40);
+
+/// How often the playback position is read from the output (the UI update rate).
+const TRACK_INTERVAL: Duration = Duration::from_millis(75);
+
+/// Audio as played (after speed and pitch changes), shared with the sources reading it.
+struct PlayedAudio {
+    samples: Vec<f32>,
+    /// Bumped by every seek, so each source jumps to `seek_target` once
+    seek_generation: u64,
+    seek_target: usize,
+    /// Whether more samples may still be appended (streaming synthesis)
+    open: bool,
+    /// A–B loop as (start, end) indices: reaching the end jumps back to the start
+    loop_region: Option<(usize, usize)>,
+    /// Whether a source caught up with streaming synthesis and is playing silence
+    starved: bool,
+    /// Whether playback is about to be paused or stopped: sources fade to silence
+    muted: bool,
+    /// Index of the block the primary output is playing, which the position is derived from
+    heard: usize,
+    /// Seek `heard` belongs to (behind `seek_generation` until a seek has faded out and jumped)
+    heard_generation: u64,
+    /// Whether the primary output has played all the audio
+    finished: bool,
+}
+
+/// Rodio source reading [`PlayedAudio`] from its own position.
+///
+/// Samples are copied out a block at a time to keep the lock off the audio
+/// thread's per-sample path. While a stream is still open, reaching the end
+/// plays silence until the next chunk arrives.
+///
+/// Cutting the waveform mid-sample clicks, so the source fades out over
+/// [`FADE_MS`] before a seek jumps (fading back in after it) and while the
+/// audio is muted ahead of a pause or stop.
+///
+/// Speech is mono; routed to one side, each sample becomes a stereo frame
+/// with silence on the other side.
+///
+/// The primary output's source reports the block it reads, so the playback
+/// position follows what is actually played: it holds still while the sink is
+/// paused or the device stalls, and needs no timing of its own.
+struct PlayedAudioSource {
+    audio: Arc<Mutex<PlayedAudio>>,
+    sample_rate: u32,
+    /// Index in `audio.samples` of the next block
+    position: usize,
+    /// Last seek this source has seen
+    seek_generation: u64,
+    /// Seek target waiting for the fade-out to finish
+    pending_seek: Option<usize>,
+    block: Vec<f32>,
+    block_index: usize,
+    /// Current fade gain (0.0 silent to 1.0 full)
+    gain: f32,
+    /// Gain change per sample while fading
+    fade_step: f32,
+    /// Whether the gain is moving toward silence
+    fading_out: bool,
+    /// Side the samples are played on
+    channels: ChannelRouting,
+    /// Second sample of the current stereo frame, when routed to one side
+    frame_rest: Option<f32>,
+    /// Whether this source reports what it plays (the primary output's)
+    tracked: bool,
+}
+
+impl PlayedAudioSource {
+    fn new(audio: Arc<Mutex<PlayedAudio>>, sample_rate: u32, position: usize) -> Self {
+        let seek_generation = audio.lock().unwrap().seek_generation;
+        Self {
+            audio,
+            sample_rate,
+            position,
+            seek_generation,
+            pending_seek: None,
+            block: Vec::with_capacity(SOURCE_BLOCK),
+            block_index: 0,
+            gain: 1.0,
+            fade_step: 1000.0 / (sample_rate * FADE_MS).max(1) as f32,
+            fading_out: false,
+            channels: ChannelRouting::Both,
+            frame_rest: None,
+            tracked: false,
+        }
+    }
+
+    /// Play on one side only (or both).
+    fn routed(mut self, channels: ChannelRouting) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    /// Report the block being played in the shared audio, for position tracking.
+    fn tracked(mut self) -> Self {
+        self.tracked = true;
+        self
+    }
+
+    /// Copy the next block out of the shared audio; false once it has all been played.
+    fn fill_block(&mut self) -> bool {
+        let Ok(mut audio) = self.audio.lock() else {
+            return false;
+        };
+        if audio.seek_generation != self.seek_generation {
+            self.seek_generation = audio.seek_generation;
+            self.pending_seek = Some(audio.seek_target);
+        }
+        // Jump once faded out (or when there is nothing left to fade)
+        if self.gain <= 0.0 || self.position >= audio.samples.len() {
+            if let Some(target) = self.pending_seek.take() {
+                self.position = target;
+            }
+        }
+        self.fading_out = audio.muted || self.pending_seek.is_some();
+        // Blocks stop at the loop end so the jump back is sample-accurate
+        let mut limit = audio.samples.len();
+        if let Some((start, end)) = audio.loop_region {
+            if self.position >= end {
+                self.position = start;
+            }
+            limit = limit.min(end);
+        }
+        self.block.clear();
+        self.block_index = 0;
+        if self.tracked {
+            audio.heard = self.position;
+            if self.pending_seek.is_none() {
+                audio.heard_generation = self.seek_generation;
+            }
+        }
+        let end = (self.position + SOURCE_BLOCK).min(limit);
+        if self.position < end {
+            self.block.extend_from_slice(&audio.samples[self.position..end]);
+            self.position = end;
+            audio.starved = false;
+        } else if audio.open {
+            // Caught up with synthesis: wait for the next chunk
+            self.block.resize(SOURCE_BLOCK, 0.0);
+            audio.starved = true;
+        } else {
+            audio.finished |= self.tracked;
+            return false;
+        }
+        true
+    }
+}
 
-use super::TTSError;
+impl Iterator for PlayedAudioSource {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if let Some(sample) = self.frame_rest.take() {
+            return Some(sample);
+        }
+        if self.block_index >= self.block.len() && !self.fill_block() {
+            return None;
+        }
+        let sample = self.block[self.block_index];
+        self.block_index += 1;
+        self.gain = if self.fading_out {
+            (self.gain - self.fade_step).max(0.0)
+        } else {
+            (self.gain + self.fade_step).min(1.0)
+        };
+        let sample = sample * self.gain;
+        match self.channels {
+            ChannelRouting::Both => Some(sample),
+            ChannelRouting::Left => {
+                self.frame_rest = Some(0.0);
+                Some(sample)
+            }
+            ChannelRouting::Right => {
+                self.frame_rest = Some(sample);
+                Some(0.0)
+            }
+        }
+    }
+}
+
+impl Source for PlayedAudioSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        if self.channels == ChannelRouting::Both {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// Sinks playing the same audio: the primary output plus an optional mirror.
+struct OutputSinks {
+    primary: Sink,
+    mirror: Option<Sink>,
+    /// Audio both sinks read from
+    audio: Arc<Mutex<PlayedAudio>>,
+}
+
+impl OutputSinks {
+    fn iter(&self) -> impl Iterator<Item = &Sink> {
+        std::iter::once(&self.primary).chain(self.mirror.as_ref())
+    }
+
+    fn pause(&self) {
+        self.iter().for_each(Sink::pause);
+    }
+
+    /// Whether audio is coming out: neither paused nor run out.
+    fn audible(&self) -> bool {
+        !self.primary.is_paused() && !self.primary.empty()
+    }
+
+    /// Fade to silence ahead of a pause or stop, so it doesn't click.
+    fn mute(&self) {
+        self.audio.lock().unwrap().muted = true;
+    }
+
+    /// Play (again), fading back in if the audio was muted for a pause.
+    fn play(&self) {
+        self.audio.lock().unwrap().muted = false;
+        self.iter().for_each(Sink::play);
+    }
+
+    fn stop(&self) {
+        self.iter().for_each(Sink::stop);
+    }
+
+    fn set_volume(&self, volume: f32) {
+        self.iter().for_each(|sink| sink.set_volume(volume));
+    }
+
+    /// Queue the samples after the audio already playing.
+    fn append(&self, samples: &[f32]) {
+        self.audio.lock().unwrap().samples.extend_from_slice(samples);
+    }
+
+    /// Move every sink's read position to `index` in the played audio.
+    fn seek(&self, index: usize) {
+        let mut audio = self.audio.lock().unwrap();
+        audio.seek_target = index.min(audio.samples.len());
+        audio.seek_generation += 1;
+    }
+
+    /// No more samples will be appended: end once the played audio runs out.
+    fn finish(&self) {
+        self.audio.lock().unwrap().open = false;
+    }
+}
 
 /// Internal playback state shared between threads.
 #[derive(Default)]
@@ -26,12 +425,28 @@ pub struct PlaybackState {
     pub is_paused: bool,
     /// Recent audio chunk for FFT visualization
     pub current_chunk: Vec<f32>,
+    /// Whether a synthesis thread is still appending audio
+    pub is_streaming: bool,
+    /// Estimated length of the whole audio while streaming, in samples
+    pub estimated_len: usize,
+    /// Incremented on every new load so stale appenders can tell they were replaced
+    pub stream_id: u64,
+    /// Playback speed multiplier (1.0 = normal, pitch is preserved)
+    pub speed: f32,
+    /// Pitch offset in semitones (0.0 = unchanged)
+    pub pitch: f32,
+    /// A–B loop as (start, end) in samples: playback jumps back to start at end
+    pub loop_region: Option<(usize, usize)>,
+    /// Loudness gain of the loaded audio, also applied to streamed chunks
+    pub gain: f32,
+    /// Whether long pauses are shortened in the loaded audio and streamed chunks
+    pub shorten_pauses: bool,
 }
 
 /// Shared audio playback engine for TTS providers.
 ///
 /// Handles rodio output, position tracking, and FFT visualization.
-/// Providers compose with this struct and call `play_audio()` after synthesis.
+/// Providers compose with this struct and call `play_speech()` with what their synthesis produced.
 pub struct AudioPlayer {
     /// Sample rate for audio output
     sample_rate: u32,
@@ -41,48 +456,270 @@ pub struct AudioPlayer {
     _stream: Option<OutputStream>,
     /// Audio output stream handle
     stream_handle: Option<OutputStreamHandle>,
-    /// Audio sink for playback control
-    sink: Option<Sink>,
+    /// Mirror output stream and handle, when playing to a second device
+    mirror: Option<(OutputStream, OutputStreamHandle)>,
+    /// Audio sinks for playback control (shared with stream appenders)
+    sinks: Arc<Mutex<Option<OutputSinks>>>,
+    /// Output device the stream is open on (None = system default)
+    output_device: Option<String>,
+    /// Mirror device requested in the output settings
+    mirror_device: Option<String>,
+    /// Whether the mirror plays into the virtual microphone
+    virtual_mic: bool,
+    /// Volume applied to every sink
+    volume: f32,
+    /// Whether loaded audio is brought to a common loudness
+    normalize_loudness: bool,
+    /// Whether long pauses are shortened in loaded audio
+    shorten_pauses: bool,
+    /// Side of the primary output speech is played on
+    channels: ChannelRouting,
 }
 
 impl AudioPlayer {
     /// Create a new audio player with the given sample rate.
     pub fn new(sample_rate: u32) -> Result<Self, TTSError> {
         trace!(sample_rate, "AudioPlayer::new");
-        let (stream, stream_handle) = OutputStream::try_default().map_err(|e| {
-            error!("Failed to open audio output: {e}");
-            TTSError::AudioError(format!("Failed to open audio output: {e}"))
-        })?;
+        let (stream, stream_handle) = open_output(None)?;
 
         debug!(sample_rate, "Audio output stream initialized");
 
         Ok(Self {
             sample_rate,
-            state: Arc::new(Mutex::new(PlaybackState::default())),
+            state: Arc::new(Mutex::new(PlaybackState {
+                speed: 1.0,
+                gain: 1.0,
+                ..Default::default()
+            })),
             _stream: Some(stream),
             stream_handle: Some(stream_handle),
-            sink: None,
+            mirror: None,
+            sinks: Arc::new(Mutex::new(None)),
+            output_device: None,
+            mirror_device: None,
+            virtual_mic: false,
+            volume: default_volume(),
+            normalize_loudness: default_normalize_loudness(),
+            shorten_pauses: false,
+            channels: ChannelRouting::Both,
         })
     }
 
+    /// Change the sample rate used for subsequently played audio.
+    ///
+    /// For engines whose output rate is only known after synthesis
+    /// (e.g. WAV responses).
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        if sample_rate != self.sample_rate {
+            debug!(from = self.sample_rate, to = sample_rate, "AudioPlayer sample rate changed");
+            self.sample_rate = sample_rate;
+        }
+    }
+
+    /// Sample rate audio is currently played at.
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Set the playback speed multiplier.
+    ///
+    /// Takes effect immediately: active playback is restarted from the current
+    /// position at the new speed; paused playback picks it up on resume.
+    pub fn set_speed(&mut self, speed: f32) {
+        trace!(speed, "AudioPlayer::set_speed");
+        let (playing, paused, position) = {
+            let mut state = self.state.lock().unwrap();
+            if (speed - state.speed).abs() < f32::EPSILON {
+                return;
+            }
+            state.speed = speed;
+            (state.is_playing && !state.is_paused, state.is_paused, state.position)
+        };
+        self.rebuild_sink(playing, paused, position);
+    }
+
+    /// Set the pitch offset in semitones, applied by resampling.
+    ///
+    /// Takes effect immediately, like [`set_speed`](Self::set_speed).
+    pub fn set_pitch(&mut self, semitones: f32) {
+        trace!(semitones, "AudioPlayer::set_pitch");
+        let (playing, paused, position) = {
+            let mut state = self.state.lock().unwrap();
+            if (semitones - state.pitch).abs() < f32::EPSILON {
+                return;
+            }
+            state.pitch = semitones;
+            (state.is_playing && !state.is_paused, state.is_paused, state.position)
+        };
+        self.rebuild_sink(playing, paused, position);
+    }
+
+    /// Switch the output devices and volume.
+    ///
+    /// A volume change applies to the live sinks; a device or channel change
+    /// moves active playback to the new sinks from the current position.
+    pub fn set_output(&mut self, output: &OutputSettings) -> Result<(), TTSError> {
+        trace!(?output, "AudioPlayer::set_output");
+        self.volume = output.volume;
+        // Applies from the next loaded audio
+        self.normalize_loudness = output.normalize_loudness;
+        self.shorten_pauses = output.shorten_pauses;
+        if let Some(ref sinks) = *self.sinks.lock().unwrap() {
+            sinks.set_volume(self.volume);
+        }
+
+        let devices_changed = output.device != self.output_device
+            || output.mirror_device != self.mirror_device
+            || output.virtual_mic != self.virtual_mic;
+        if !devices_changed && output.channels == self.channels {
+            return Ok(());
+        }
+        self.channels = output.channels;
+        if devices_changed {
+            self.open_devices(output)?;
+        }
+
+        let (playing, paused, position) = {
+            let state = self.state.lock().unwrap();
+            (state.is_playing && !state.is_paused, state.is_paused, state.position)
+        };
+        self.rebuild_sink(playing, paused, position);
+        Ok(())
+    }
+
+    /// Open the primary and mirror outputs named in `output`.
+    fn open_devices(&mut self, output: &OutputSettings) -> Result<(), TTSError> {
+        if output.device != self.output_device {
+            debug!(from = ?self.output_device, to = ?output.device, "AudioPlayer output device changed");
+            let (stream, stream_handle) = open_output(output.device.as_deref())?;
+            self._stream = Some(stream);
+            self.stream_handle = Some(stream_handle);
+            self.output_device = output.device.clone();
+        }
+        debug!(mirror = ?output.mirror_device, virtual_mic = output.virtual_mic, "AudioPlayer mirror changed");
+        // Drop the old mirror first so a virtual mic stream isn't routed twice
+        self.mirror = None;
+        self.mirror = if output.virtual_mic {
+            virtual_mic::open_stream()
+                .inspect_err(|e| warn!(error = %e, "Virtual microphone unavailable, playing on primary output only"))
+                .ok()
+        } else {
+            // Mirroring to the primary device would just play everything twice there
+            output
+                .mirror_device
+                .as_deref()
+                .filter(|mirror| Some(*mirror) != output.device.as_deref())
+                .and_then(open_named_output)
+        };
+        self.mirror_device = output.mirror_device.clone();
+        self.virtual_mic = output.virtual_mic;
+        Ok(())
+    }
+
+    /// Rebuild the sink after a speed, pitch or device change.
+    ///
+    /// Active playback restarts from `position`; paused playback drops its sink
+    /// so resume() rebuilds it.
+    fn rebuild_sink(&mut self, playing: bool, paused: bool, position: usize) {
+        if playing {
+            self.restart_at(position).ok();
+        } else if paused {
+            // Drop the sinks so resume() rebuilds them with the new settings
+            if let Some(sinks) = self.sinks.lock().unwrap().take() {
+                sinks.stop();
+            }
+            self.state.lock().unwrap().is_playing = false;
+        }
+    }
+
     /// Load audio data and start playback.
     ///
     /// Call this after synthesizing audio. The audio_data should be normalized
     /// f32 samples in the range -1.0 to 1.0.
-    pub fn play_audio(&mut self, audio_data: Vec<f32>) -> Result<(), TTSError> {
+    fn play_audio(&mut self, audio_data: Vec<f32>) -> Result<(), TTSError> {
         debug!(samples = audio_data.len(), "AudioPlayer::play_audio");
-        // Store audio data
-        {
-            let mut state = self.state.lock().unwrap();
-            state.audio_data = audio_data;
-            state.position = 0;
-            state.is_playing = false;
-            state.is_paused = false;
-            state.current_chunk.clear();
+        self.load_audio(audio_data, false);
+        self.start_playback()
+    }
+
+    /// Start playing `first_chunk` while the rest of the text is still being synthesized.
+    ///
+    /// Returns an appender for the synthesis thread to queue the remaining chunks;
+    /// playback ends after the last chunk once [`StreamAppender::finish`] is called.
+    fn play_stream(&mut self, first_chunk: Vec<f32>) -> Result<StreamAppender, TTSError> {
+        debug!(samples = first_chunk.len(), "AudioPlayer::play_stream");
+        let stream_id = self.load_audio(first_chunk, true);
+        self.start_playback()?;
+        Ok(StreamAppender {
+            state: Arc::clone(&self.state),
+            sinks: Arc::clone(&self.sinks),
+            sample_rate: self.sample_rate,
+            stream_id,
+        })
+    }
+
+    /// Start playing `speech`, streaming in its remaining chunks as they are synthesized.
+    pub fn play_speech(&mut self, speech: Speech) -> Result<(), TTSError> {
+        self.set_sample_rate(speech.sample_rate);
+        let Some(rest) = speech.rest else {
+            return self.play_audio(speech.samples);
+        };
+        let appender = self.play_stream(speech.samples)?;
+        thread::spawn(move || rest(appender));
+        Ok(())
+    }
+
+    /// Prepare synthesizing `chunks` in order, played as a stream at the current sample rate.
+    ///
+    /// See [`synthesize_chunks`] for how the chunks are synthesized.
+    pub fn synthesize_chunks<C, F>(&self, chunks: Vec<C>, synthesize: F) -> Result<Synthesis, TTSError>
+    where
+        C: ChunkText + Send + 'static,
+        F: Fn(&C) -> Result<Vec<f32>, TTSError> + Send + 'static,
+    {
+        if chunks.is_empty() {
+            return Err(TTSError::ProcessError("No text to synthesize".into()));
         }
+        let sample_rate = self.sample_rate;
+        Ok(Synthesis::new(move || synthesize_chunks(chunks, sample_rate, synthesize)))
+    }
 
-        // Start playback
-        self.start_playback()
+    /// The complete audio of the current speech, once synthesis has finished.
+    ///
+    /// Returns None while chunks are still being streamed in.
+    pub fn synthesized_audio(&self) -> Option<SynthesizedAudio> {
+        let state = self.state.lock().unwrap();
+        (!state.is_streaming && !state.audio_data.is_empty()).then(|| SynthesizedAudio {
+            samples: Arc::new(state.audio_data.clone()),
+            sample_rate: self.sample_rate,
+        })
+    }
+
+    /// Replace the loaded audio and reset playback state; returns the new stream id.
+    fn load_audio(&mut self, mut audio_data: Vec<f32>, streaming: bool) -> u64 {
+        let gain = if self.normalize_loudness {
+            loudness::normalization_gain(&audio_data)
+        } else {
+            1.0
+        };
+        debug!(gain, "Loudness gain");
+        loudness::apply_gain(&mut audio_data, gain);
+        if self.shorten_pauses {
+            audio_data = pauses::shorten_pauses(&audio_data, self.sample_rate);
+        }
+        let mut state = self.state.lock().unwrap();
+        state.audio_data = audio_data;
+        state.gain = gain;
+        state.shorten_pauses = self.shorten_pauses;
+        state.position = 0;
+        state.is_playing = false;
+        state.is_paused = false;
+        state.current_chunk.clear();
+        state.loop_region = None;
+        state.is_streaming = streaming;
+        state.estimated_len = 0;
+        state.stream_id += 1;
+        state.stream_id
     }
 
     /// Convert raw PCM bytes (16-bit signed LE mono) to normalized f32 samples.
@@ -99,8 +736,23 @@ impl AudioPlayer {
     /// Pause the current playback.
     pub fn pause(&mut self) -> Result<(), TTSError> {
         trace!("AudioPlayer::pause");
-        if let Some(ref sink) = self.sink {
-            sink.pause();
+        if let Some(ref sinks) = *self.sinks.lock().unwrap() {
+            if sinks.audible() {
+                // Paused once faded out, on another thread: the caller (the UI) doesn't wait
+                sinks.mute();
+                let (current, audio) = (Arc::clone(&self.sinks), Arc::clone(&sinks.audio));
+                thread::spawn(move || {
+                    thread::sleep(FADE_WAIT);
+                    // Unless resumed or replaced during the fade
+                    if let Some(ref sinks) = *current.lock().unwrap() {
+                        if Arc::ptr_eq(&sinks.audio, &audio) && audio.lock().unwrap().muted {
+                            sinks.pause();
+                        }
+                    }
+                });
+            } else {
+                sinks.pause();
+            }
         }
 
         let mut state = self.state.lock().unwrap();
@@ -113,8 +765,16 @@ impl AudioPlayer {
     /// Resume paused playback.
     pub fn resume(&mut self) -> Result<(), TTSError> {
         trace!("AudioPlayer::resume");
-        if let Some(ref sink) = self.sink {
-            sink.play();
+        let has_sinks = match *self.sinks.lock().unwrap() {
+            Some(ref sinks) => {
+                sinks.play();
+                true
+            }
+            None => false,
+        };
+        if !has_sinks && self.state.lock().unwrap().is_paused {
+            // Sink was dropped while paused (e.g. speed change) - rebuild from position
+            return self.start_playback();
         }
 
         let mut state = self.state.lock().unwrap();
@@ -127,8 +787,17 @@ impl AudioPlayer {
     /// Stop playback and reset position.
     pub fn stop(&mut self) -> Result<(), TTSError> {
         trace!("AudioPlayer::stop");
-        if let Some(sink) = self.sink.take() {
-            sink.stop();
+        if let Some(sinks) = self.sinks.lock().unwrap().take() {
+            if sinks.audible() {
+                // Stopped once faded out, on another thread: the caller (the UI) doesn't wait
+                sinks.mute();
+                thread::spawn(move || {
+                    thread::sleep(FADE_WAIT);
+                    sinks.stop();
+                });
+            } else {
+                sinks.stop();
+            }
         }
 
         let mut state = self.state.lock().unwrap();
@@ -136,6 +805,7 @@ impl AudioPlayer {
         state.is_paused = false;
         state.position = 0;
         state.current_chunk.clear();
+        state.is_streaming = false;
         Ok(())
     }
 
@@ -151,6 +821,17 @@ impl AudioPlayer {
         state.is_paused
     }
 
+    /// Check if playback caught up with streaming synthesis and waits for the next chunk.
+    pub fn is_buffering(&self) -> bool {
+        self.is_playing()
+            && self
+                .sinks
+                .lock()
+                .unwrap()
+                .as_ref()
+                .is_some_and(|sinks| sinks.audio.lock().unwrap().starved)
+    }
+
     /// Skip forward by the given number of seconds.
     pub fn skip_forward(&mut self, seconds: f32) {
         trace!(seconds, "AudioPlayer::skip_forward");
@@ -159,7 +840,7 @@ impl AudioPlayer {
             let state = self.state.lock().unwrap();
             (state.position + samples_to_skip).min(state.audio_data.len())
         };
-        self.seek_to(new_position).ok();
+        self.seek_to(new_position);
     }
 
     /// Skip backward by the given number of seconds.
@@ -170,16 +851,47 @@ impl AudioPlayer {
             let state = self.state.lock().unwrap();
             state.position.saturating_sub(samples_to_skip)
         };
-        self.seek_to(new_position).ok();
+        self.seek_to(new_position);
+    }
+
+    /// Loop the region between `start` and `end` seconds (at normal speed), or stop looping with None.
+    ///
+    /// The region is limited to the audio synthesized so far.
+    pub fn set_loop(&mut self, region: Option<(f32, f32)>) {
+        trace!(?region, "AudioPlayer::set_loop");
+        let mut state = self.state.lock().unwrap();
+        let len = state.audio_data.len();
+        let to_samples = |seconds: f32| ((seconds.max(0.0) * self.sample_rate as f32) as usize).min(len);
+        state.loop_region = region
+            .map(|(start, end)| (to_samples(start), to_samples(end)))
+            .filter(|(start, end)| start < end);
+        if let Some(ref sinks) = *self.sinks.lock().unwrap() {
+            let speed = state.speed;
+            sinks.audio.lock().unwrap().loop_region = state
+                .loop_region
+                .map(|(start, end)| (played_index(start, speed), played_index(end, speed)));
+        }
     }
 
     /// Get playback progress as a value between 0.0 and 1.0.
+    ///
+    /// While streaming it is measured against the estimated length of the
+    /// whole text, so it doesn't jump back as chunks arrive.
     pub fn get_progress(&self) -> f32 {
         let state = self.state.lock().unwrap();
-        if state.audio_data.is_empty() {
+        let length = total_len(&state);
+        if length == 0 {
             return 0.0;
         }
-        (state.position as f32 / state.audio_data.len() as f32).clamp(0.0, 1.0)
+        (state.position as f32 / length as f32).clamp(0.0, 1.0)
+    }
+
+    /// Get the total audio length in seconds at normal speed.
+    ///
+    /// While streaming this is an estimate for the whole text.
+    pub fn get_duration(&self) -> f32 {
+        let state = self.state.lock().unwrap();
+        total_len(&state) as f32 / self.sample_rate as f32
     }
 
     /// Get frequency band amplitudes for audio visualization.
@@ -252,8 +964,8 @@ impl AudioPlayer {
     fn start_playback(&mut self) -> Result<(), TTSError> {
         trace!("AudioPlayer::start_playback");
         // Stop any existing playback first
-        if let Some(sink) = self.sink.take() {
-            sink.stop();
+        if let Some(sinks) = self.sinks.lock().unwrap().take() {
+            sinks.stop();
         }
 
         let stream_handle = self
@@ -261,172 +973,378 @@ impl AudioPlayer {
             .as_ref()
             .ok_or_else(|| TTSError::AudioError("No audio output available".into()))?;
 
-        // Get audio data from current position
-        let (audio_slice, position) = {
-            let state = self.state.lock().unwrap();
-            if state.audio_data.is_empty() {
-                return Err(TTSError::AudioError("No audio data to play".into()));
-            }
-            let pos = state.position.min(state.audio_data.len());
-            if pos >= state.audio_data.len() {
-                return Err(TTSError::AudioError("Playback position at end".into()));
-            }
-            (state.audio_data[pos..].to_vec(), pos)
-        };
-
-        // Convert f32 samples back to i16 for WAV encoding
-        let samples_i16: Vec<i16> = audio_slice
-            .iter()
-            .map(|&s| (s * 32767.0).clamp(-32768.0, 32767.0) as i16)
-            .collect();
-
-        // Create a WAV in memory
-        let wav_data = Self::create_wav(&samples_i16, self.sample_rate);
-
-        // Create decoder and sink
-        let cursor = Cursor::new(wav_data);
-        let source = Decoder::new(cursor).map_err(|e| {
-            error!("Failed to decode audio: {e}");
-            TTSError::AudioError(format!("Failed to decode audio: {e}"))
-        })?;
+        // Hold the state lock until the new sink is installed, so a streamed
+        // chunk can't be appended to audio_data without reaching the sink
+        let mut state = self.state.lock().unwrap();
+        if state.audio_data.is_empty() {
+            return Err(TTSError::AudioError("No audio data to play".into()));
+        }
+        let position = state.position.min(state.audio_data.len());
+        if position >= state.audio_data.len() {
+            return Err(TTSError::AudioError("Playback position at end".into()));
+        }
 
-        let sink = Sink::try_new(stream_handle).map_err(|e| {
+        // Apply speed (pitch-preserving) and pitch changes to the whole audio, so
+        // later seeks only move the read position
+        let speed = state.speed;
+        let start = played_index(position, speed);
+        let audio = Arc::new(Mutex::new(PlayedAudio {
+            samples: stretch_and_shift(&state.audio_data, speed, state.pitch, self.sample_rate),
+            seek_generation: 0,
+            seek_target: 0,
+            open: state.is_streaming,
+            loop_region: state
+                .loop_region
+                .map(|(start, end)| (played_index(start, speed), played_index(end, speed))),
+            starved: false,
+            muted: false,
+            heard: start,
+            heard_generation: 0,
+            finished: false,
+        }));
+
+        let primary = Sink::try_new(stream_handle).map_err(|e| {
             error!("Failed to create audio sink: {e}");
             TTSError::AudioError(format!("Failed to create audio sink: {e}"))
         })?;
+        let mirror = self.mirror.as_ref().and_then(|(_, handle)| {
+            Sink::try_new(handle)
+                .inspect_err(|e| warn!(error = %e, "Failed to create mirror sink, playing on primary output only"))
+                .ok()
+        });
 
-        sink.append(source);
-        self.sink = Some(sink);
-
-        // Update state
-        {
-            let mut state = self.state.lock().unwrap();
-            state.is_playing = true;
-            state.is_paused = false;
+        // The mirror (a recording or a virtual mic) always gets both sides
+        primary.append(
+            PlayedAudioSource::new(Arc::clone(&audio), self.sample_rate, start)
+                .routed(self.channels)
+                .tracked(),
+        );
+        if let Some(sink) = &mirror {
+            sink.append(PlayedAudioSource::new(Arc::clone(&audio), self.sample_rate, start));
         }
+        let sinks = OutputSinks { primary, mirror, audio: Arc::clone(&audio) };
+        sinks.set_volume(self.volume);
+        *self.sinks.lock().unwrap() = Some(sinks);
 
-        // Start position tracking in a background thread
-        self.start_position_tracker_from(position);
+        state.is_playing = true;
+        state.is_paused = false;
+        drop(state);
+
+        self.start_position_tracker(audio, speed);
 
         Ok(())
     }
 
-    /// Create a WAV file in memory from i16 samples.
-    fn create_wav(samples: &[i16], sample_rate: u32) -> Vec<u8> {
-        trace!(
-            samples = samples.len(),
-            sample_rate,
-            "AudioPlayer::create_wav"
-        );
-        let num_samples = samples.len();
-        let data_size = num_samples * 2; // 16-bit = 2 bytes per sample
-        let file_size = 36 + data_size;
-
-        let mut wav = Vec::with_capacity(44 + data_size);
-
-        // RIFF header
-        wav.extend_from_slice(b"RIFF");
-        wav.extend_from_slice(&(file_size as u32).to_le_bytes());
-        wav.extend_from_slice(b"WAVE");
-
-        // fmt chunk
-        wav.extend_from_slice(b"fmt ");
-        wav.extend_from_slice(&16u32.to_le_bytes()); // chunk size
-        wav.extend_from_slice(&1u16.to_le_bytes()); // PCM format
-        wav.extend_from_slice(&1u16.to_le_bytes()); // mono
-        wav.extend_from_slice(&sample_rate.to_le_bytes());
-        wav.extend_from_slice(&(sample_rate * 2).to_le_bytes()); // byte rate
-        wav.extend_from_slice(&2u16.to_le_bytes()); // block align
-        wav.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
-
-        // data chunk
-        wav.extend_from_slice(b"data");
-        wav.extend_from_slice(&(data_size as u32).to_le_bytes());
-        for &sample in samples {
-            wav.extend_from_slice(&sample.to_le_bytes());
-        }
-
-        wav
-    }
-
-    /// Start a background thread to track playback position.
-    fn start_position_tracker_from(&self, start_position: usize) {
-        trace!(
-            start_position,
-            sample_rate = self.sample_rate,
-            "AudioPlayer::start_position_tracker_from"
-        );
+    /// Start a background thread updating the playback position from what `audio` has played.
+    ///
+    /// The thread ends when playback stops or moves to new sinks.
+    fn start_position_tracker(&self, audio: Arc<Mutex<PlayedAudio>>, speed: f32) {
+        trace!(sample_rate = self.sample_rate, speed, "AudioPlayer::start_position_tracker");
         let state = Arc::clone(&self.state);
+        let sinks = Arc::clone(&self.sinks);
         let sample_rate = self.sample_rate;
 
         thread::spawn(move || {
-            let chunk_duration_ms = 75; // Match UI update rate
-            let samples_per_chunk = (sample_rate as usize * chunk_duration_ms) / 1000;
-
-            // Initialize position to start position
-            {
-                let mut state_guard = state.lock().unwrap();
-                state_guard.position = start_position;
-            }
+            // Visualize the audio played between two updates (more source samples at higher speeds)
+            let chunk_len = (sample_rate as f32 * speed * TRACK_INTERVAL.as_secs_f32()) as usize;
 
             loop {
-                thread::sleep(std::time::Duration::from_millis(chunk_duration_ms as u64));
+                thread::sleep(TRACK_INTERVAL);
 
                 let mut state_guard = state.lock().unwrap();
-
-                // Exit thread if not playing (stopped or position changed externally)
-                if !state_guard.is_playing {
+                let current = sinks
+                    .lock()
+                    .unwrap()
+                    .as_ref()
+                    .is_some_and(|sinks| Arc::ptr_eq(&sinks.audio, &audio));
+                if !state_guard.is_playing || !current {
                     break;
                 }
-
                 if state_guard.is_paused {
                     continue;
                 }
 
-                // Update position
-                let new_position = state_guard.position + samples_per_chunk;
-                if new_position >= state_guard.audio_data.len() {
+                let (heard, seeking, starved, finished) = {
+                    let audio = audio.lock().unwrap();
+                    (audio.heard, audio.heard_generation != audio.seek_generation, audio.starved, audio.finished)
+                };
+                let end = state_guard.audio_data.len();
+                let from = state_guard.position.min(end);
+                if finished {
+                    replay_buffer::record(&state_guard.audio_data[from..], sample_rate);
                     state_guard.is_playing = false;
-                    state_guard.position = state_guard.audio_data.len();
+                    state_guard.position = end;
                     break;
                 }
+                // A seek already moved the position: wait until the output gets there
+                if seeking {
+                    continue;
+                }
 
-                state_guard.position = new_position;
-
-                // Store current chunk for visualization
-                let start = new_position.saturating_sub(samples_per_chunk);
-                let end = new_position.min(state_guard.audio_data.len());
-                state_guard.current_chunk = state_guard.audio_data[start..end].to_vec();
+                // Keep what was played since the last update for replay (nothing when looping back)
+                let position = ((heard as f32 * speed) as usize).min(end);
+                if position > from {
+                    replay_buffer::record(&state_guard.audio_data[from..position], sample_rate);
+                }
+                state_guard.position = position;
+
+                // Store current chunk for visualization; silent while waiting for synthesis
+                state_guard.current_chunk = if starved {
+                    Vec::new()
+                } else {
+                    state_guard.audio_data[position.saturating_sub(chunk_len)..position].to_vec()
+                };
             }
         });
     }
 
-    /// Seek to a new position and restart playback.
-    fn seek_to(&mut self, position: usize) -> Result<(), TTSError> {
+    /// Move playback to `position` (in source samples) without interrupting it.
+    ///
+    /// The sinks keep playing (or stay paused) and the position tracker carries
+    /// on from the new position.
+    fn seek_to(&mut self, position: usize) {
         trace!(position, "AudioPlayer::seek_to");
-        let was_playing = {
-            let state = self.state.lock().unwrap();
-            state.is_playing && !state.is_paused
-        };
+        let mut state = self.state.lock().unwrap();
+        state.position = position.min(state.audio_data.len());
+        state.current_chunk.clear();
+        if let Some(ref sinks) = *self.sinks.lock().unwrap() {
+            sinks.seek(played_index(state.position, state.speed));
+        }
+    }
 
-        // Update position in state
+    /// Restart playback from `position` on new sinks, after a speed, pitch or device change.
+    fn restart_at(&mut self, position: usize) -> Result<(), TTSError> {
+        trace!(position, "AudioPlayer::restart_at");
         {
             let mut state = self.state.lock().unwrap();
             state.position = position.min(state.audio_data.len());
-            state.is_playing = false; // Stop current tracker thread
         }
+        // The old tracker thread exits by itself once the new sinks are installed
+        self.start_playback()
+    }
+}
 
-        // Give the old tracker thread time to exit (it checks is_playing every 75ms)
-        if was_playing {
-            thread::sleep(std::time::Duration::from_millis(80));
+/// Length of the audio in samples, using the estimate for the whole text while streaming.
+fn total_len(state: &PlaybackState) -> usize {
+    if state.is_streaming {
+        state.estimated_len.max(state.audio_data.len())
+    } else {
+        state.audio_data.len()
+    }
+}
+
+/// Index in the played (speed-adjusted) audio of source sample `position`.
+fn played_index(position: usize, speed: f32) -> usize {
+    (position as f32 / speed) as usize
+}
+
+/// Synthesize `chunks` in order, at `sample_rate`, on the synthesis thread.
+///
+/// Only the first chunk is synthesized here, so its errors are returned; the
+/// returned speech synthesizes the rest on another thread once playback
+/// started, appending them to the same sinks while earlier chunks play, so
+/// there are no gaps between them. A chunk is usually the text itself but may
+/// carry extra data for the synthesizer, such as the voice to use.
+pub(super) fn synthesize_chunks<C, F>(chunks: Vec<C>, sample_rate: u32, synthesize: F) -> Result<Speech, TTSError>
+where
+    C: ChunkText + Send + 'static,
+    F: Fn(&C) -> Result<Vec<f32>, TTSError> + Send + 'static,
+{
+    // Progress covers the whole text: extrapolate its length from the share synthesized so far
+    let total_chars: usize = chunks.iter().map(|chunk| chunk.chunk_text().len()).sum::<usize>().max(1);
+    let mut chunks = chunks.into_iter();
+    let first = chunks
+        .next()
+        .ok_or_else(|| TTSError::ProcessError("No text to synthesize".into()))?;
+    let first_audio = synthesize(&first)?;
+    let rest: Vec<C> = chunks.collect();
+    if rest.is_empty() {
+        return Ok(Speech::complete(first_audio, sample_rate));
+    }
+
+    debug!(chunks = rest.len() + 1, "Streaming synthesis");
+    let mut synthesized_chars = first.chunk_text().len();
+    let stream_rest = move |appender: StreamAppender| {
+        appender.estimate_length(synthesized_chars as f32 / total_chars as f32);
+        for chunk in rest {
+            synthesized_chars += chunk.chunk_text().len();
+            match synthesize(&chunk) {
+                Ok(audio) => {
+                    if !appender.append(&audio) {
+                        debug!("Playback stopped, ending streaming synthesis");
+                        return;
+                    }
+                    appender.estimate_length(synthesized_chars as f32 / total_chars as f32);
+                }
+                Err(e) => error!(error = %e, "Failed to synthesize chunk, skipping it"),
+            }
         }
+        appender.finish();
+    };
+    Ok(Speech { samples: first_audio, sample_rate, rest: Some(Box::new(stream_rest)) })
+}
 
-        // Restart playback if we were playing
-        if was_playing {
-            self.start_playback()?;
+/// Handle for appending synthesized chunks to a streaming playback.
+///
+/// Created by [`AudioPlayer::play_stream`] and moved to the synthesis thread.
+pub struct StreamAppender {
+    state: Arc<Mutex<PlaybackState>>,
+    sinks: Arc<Mutex<Option<OutputSinks>>>,
+    sample_rate: u32,
+    stream_id: u64,
+}
+
+impl StreamAppender {
+    /// Queue a chunk after the audio already loaded.
+    ///
+    /// Returns false once the stream was stopped or replaced by newer audio,
+    /// so the caller can stop synthesizing.
+    pub fn append(&self, samples: &[f32]) -> bool {
+        trace!(samples = samples.len(), "StreamAppender::append");
+        let mut state = self.state.lock().unwrap();
+        if !state.is_streaming || state.stream_id != self.stream_id {
+            return false;
+        }
+        let mut samples = samples.to_vec();
+        loudness::apply_gain(&mut samples, state.gain);
+        if state.shorten_pauses {
+            samples = pauses::shorten_pauses(&samples, self.sample_rate);
         }
+        state.audio_data.extend_from_slice(&samples);
 
-        Ok(())
+        // Without sinks (paused after a speed change) resume() rebuilds from audio_data
+        if let Some(ref sinks) = *self.sinks.lock().unwrap() {
+            sinks.append(&stretch_and_shift(&samples, state.speed, state.pitch, self.sample_rate));
+        }
+        true
+    }
+
+    /// Update the estimated length of the whole audio, `fraction` of the text having been synthesized.
+    pub fn estimate_length(&self, fraction: f32) {
+        let mut state = self.state.lock().unwrap();
+        if state.stream_id == self.stream_id && fraction > 0.0 {
+            state.estimated_len = (state.audio_data.len() as f32 / fraction.min(1.0)) as usize;
+        }
+    }
+
+    /// Mark the stream complete so playback ends after the last appended chunk.
+    pub fn finish(&self) {
+        trace!("StreamAppender::finish");
+        let mut state = self.state.lock().unwrap();
+        if state.stream_id == self.stream_id {
+            state.is_streaming = false;
+            if let Some(ref sinks) = *self.sinks.lock().unwrap() {
+                sinks.finish();
+            }
+        }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_played_audio_source_seeks_and_streams() {
+        let samples: Vec<f32> = (0..SOURCE_BLOCK * 2).map(|i| i as f32).collect();
+        let audio = Arc::new(Mutex::new(PlayedAudio {
+            samples,
+            seek_generation: 0,
+            seek_target: 0,
+            open: true,
+            loop_region: None,
+            starved: false,
+            muted: false,
+            heard: 0,
+            heard_generation: 0,
+            finished: false,
+        }));
+        // 1 kHz: fades last 80 samples
+        let mut source = PlayedAudioSource::new(Arc::clone(&audio), 1000, 10).tracked();
+        assert_eq!(source.next(), Some(10.0));
+        assert_eq!(audio.lock().unwrap().heard, 10);
+
+        // A seek fades out from the next block, then jumps and fades back in
+        let seek = |index: usize| {
+            let mut audio = audio.lock().unwrap();
+            audio.seek_target = index;
+            audio.seek_generation += 1;
+        };
+        seek(SOURCE_BLOCK * 2 - 1);
+        let rest: Vec<f32> = source.by_ref().take(SOURCE_BLOCK * 2 - 10).collect();
+        assert_eq!(rest[SOURCE_BLOCK - 2], (SOURCE_BLOCK + 9) as f32);
+        assert!(rest[SOURCE_BLOCK + 100..SOURCE_BLOCK * 2 - 11].iter().all(|&sample| sample == 0.0));
+        assert_eq!(rest.last(), Some(&((SOURCE_BLOCK * 2 - 1) as f32 * source.fade_step)));
+        // The position follows the jump, not the block faded out before it
+        assert_eq!(audio.lock().unwrap().heard, SOURCE_BLOCK * 2 - 1);
+        assert_eq!(audio.lock().unwrap().heard_generation, 1);
+
+        // Silence while waiting for the next chunk, then the chunk, then the end
+        assert_eq!(source.next(), Some(0.0));
+        assert!(audio.lock().unwrap().starved);
+        audio.lock().unwrap().samples.push(-1.0);
+        audio.lock().unwrap().open = false;
+        let tail: Vec<f32> = source.collect();
+        assert_eq!(tail.last(), Some(&-1.0));
+        assert!(tail[..tail.len() - 1].iter().all(|&sample| sample == 0.0));
+        assert!(audio.lock().unwrap().finished);
+    }
+
+    #[test]
+    fn test_played_audio_source_loops() {
+        let audio = Arc::new(Mutex::new(PlayedAudio {
+            samples: (0..SOURCE_BLOCK * 4).map(|i| i as f32).collect(),
+            seek_generation: 0,
+            seek_target: 0,
+            open: false,
+            loop_region: Some((100, 110)),
+            starved: false,
+            muted: false,
+            heard: 0,
+            heard_generation: 0,
+            finished: false,
+        }));
+        let played: Vec<f32> = PlayedAudioSource::new(Arc::clone(&audio), 22050, 105).take(12).collect();
+        let expected: Vec<f32> = (105..110).chain(100..107).map(|i| i as f32).collect();
+        assert_eq!(played, expected);
+
+        // Routed to one side: stereo frames with silence on the other side
+        let right = PlayedAudioSource::new(audio, 22050, 105).routed(ChannelRouting::Right);
+        assert_eq!(right.channels(), 2);
+        let played: Vec<f32> = right.take(4).collect();
+        assert_eq!(played, [0.0, 105.0, 0.0, 106.0]);
+    }
+
+    #[test]
+    fn test_played_audio_source_fades() {
+        // 1 kHz: the fade lasts 80 samples
+        let audio = Arc::new(Mutex::new(PlayedAudio {
+            samples: vec![1.0; SOURCE_BLOCK * 4],
+            seek_generation: 0,
+            seek_target: 0,
+            open: false,
+            loop_region: None,
+            starved: false,
+            muted: false,
+            heard: 0,
+            heard_generation: 0,
+            finished: false,
+        }));
+        let mut source = PlayedAudioSource::new(Arc::clone(&audio), 1000, 0);
+        assert_eq!(source.next(), Some(1.0));
+
+        // Muting fades to silence from the next block on
+        audio.lock().unwrap().muted = true;
+        let block: Vec<f32> = source.by_ref().take(SOURCE_BLOCK - 1).collect();
+        assert!(block.iter().all(|&sample| sample == 1.0));
+        let faded: Vec<f32> = source.by_ref().take(SOURCE_BLOCK).collect();
+        assert!(faded[..80].windows(2).all(|pair| pair[1] < pair[0]));
+        assert!(faded[90..].iter().all(|&sample| sample == 0.0));
+
+        // Unmuting fades back in
+        audio.lock().unwrap().muted = false;
+        let rest: Vec<f32> = source.take(100).collect();
+        assert!(rest[0] > 0.0 && rest[0] < 0.1);
+        assert_eq!(rest[99], 1.0);
+    }
+}
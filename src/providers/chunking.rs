@@ -0,0 +1,252 @@
+//! Sentence chunking for streaming synthesis.
+//!
+//! Long texts are synthesized in sentence-sized chunks so playback can start
+//! as soon as the first one is ready. The first chunk is kept short to
+//! minimize the time to first audio; later chunks are larger so each engine
+//! call has enough context for natural prosody. Sentences come from
+//! [`segmenter`](crate::system::segmenter), which knows abbreviations and
+//! decimal numbers don't end them.
+//!
+//! In skim mode only the headings and the first sentence of each paragraph
+//! are chunked, remembering where each paragraph is in the full text so the
+//! reading can carry on in full from the paragraph being skimmed.
+
+use crate::system::segmenter::sentences as split_sentences;
+
+/// Target length of the first chunk, in characters.
+const FIRST_CHUNK_CHARS: usize = 120;
+
+/// Target length of the remaining chunks, in characters.
+const CHUNK_CHARS: usize = 400;
+
+/// Longest line read as a heading in skim mode, in characters.
+const MAX_HEADING_CHARS: usize = 70;
+
+/// A chunk of text to synthesize, possibly with extra data for the synthesizer.
+///
+/// Its text length is used to estimate how long the whole reading will be
+/// while it is still being synthesized.
+pub trait ChunkText {
+    fn chunk_text(&self) -> &str;
+}
+
+impl ChunkText for String {
+    fn chunk_text(&self) -> &str {
+        self
+    }
+}
+
+impl<T> ChunkText for (T, String) {
+    fn chunk_text(&self) -> &str {
+        &self.1
+    }
+}
+
+/// Split `text` into chunks of whole sentences.
+///
+/// Sentences are never split, so a single long sentence becomes its own chunk.
+pub fn sentence_chunks(text: &str) -> Vec<String> {
+    pack_sentences(split_sentences(text), true)
+}
+
+/// Split `text` into chunks of whole sentences, each tagged with its language.
+///
+/// `detect` is asked for the language of every sentence and consecutive
+/// sentences in the same language share chunks. Sentences it can't tell stay
+/// with the language before them, so short interjections don't switch voices.
+pub fn language_chunks<F>(text: &str, detect: F) -> Vec<(Option<&'static str>, String)>
+where
+    F: Fn(&str) -> Option<&'static str>,
+{
+    let mut segments: Vec<(Option<&'static str>, Vec<&str>)> = Vec::new();
+    for sentence in split_sentences(text) {
+        let language = detect(sentence).or_else(|| segments.last().and_then(|(language, _)| *language));
+        match segments.last_mut() {
+            Some((last, sentences)) if *last == language => sentences.push(sentence),
+            _ => segments.push((language, vec![sentence])),
+        }
+    }
+
+    let mut chunks = Vec::new();
+    for (language, sentences) in segments {
+        let short_first = chunks.is_empty();
+        chunks.extend(
+            pack_sentences(sentences, short_first)
+                .into_iter()
+                .map(|chunk| (language, chunk)),
+        );
+    }
+    chunks
+}
+
+/// A skimmed reading: the headings and first sentences of a text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Skim {
+    /// What is read: each paragraph's headings and first sentence, one per line
+    pub text: String,
+    /// Full text
+    full: String,
+    /// Start of each paragraph: share of the skimmed reading and byte offset in the full text
+    paragraphs: Vec<(f32, usize)>,
+}
+
+impl Skim {
+    /// Skim `text` (plain text).
+    pub fn new(text: &str) -> Self {
+        let mut skimmed: Vec<(String, usize)> = Vec::new();
+        for (offset, paragraph) in paragraphs(text) {
+            let mut parts = Vec::new();
+            let mut rest = paragraph;
+            // Headings above the paragraph are read whole, then its first sentence
+            while let Some(line) = rest.split_inclusive('\n').next().filter(|line| is_heading(line)) {
+                parts.push(line.trim());
+                rest = &rest[line.len()..];
+            }
+            parts.extend(split_sentences(rest).into_iter().next());
+            if !parts.is_empty() {
+                skimmed.push((parts.join("\n"), offset));
+            }
+        }
+
+        // Shares are estimated from the length of what is read, like word timings
+        let total: usize = skimmed.iter().map(|(part, _)| part.chars().count() + 1).sum();
+        let mut read = 0;
+        let mut paragraphs = Vec::with_capacity(skimmed.len());
+        for (part, offset) in &skimmed {
+            paragraphs.push((read as f32 / total.max(1) as f32, *offset));
+            read += part.chars().count() + 1;
+        }
+        let parts: Vec<String> = skimmed.into_iter().map(|(part, _)| part).collect();
+        Self { text: parts.join("\n"), full: text.to_string(), paragraphs }
+    }
+
+    /// The full text from the start of the paragraph being skimmed at `progress` (0.0 to 1.0).
+    pub fn expand(&self, progress: f32) -> &str {
+        let index = self.paragraphs.partition_point(|(start, _)| *start <= progress);
+        let offset = index.checked_sub(1).map_or(0, |index| self.paragraphs[index].1);
+        &self.full[offset..]
+    }
+}
+
+/// Paragraphs of `text` with their byte offsets: blocks between blank lines,
+/// or single lines in texts without blank lines.
+pub fn paragraphs(text: &str) -> Vec<(usize, &str)> {
+    let blank_separated = text.trim().lines().any(|line| line.trim().is_empty());
+    let mut paragraphs = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                paragraphs.push((start, &text[start..line_start]));
+            }
+        } else if blank_separated {
+            start.get_or_insert(line_start);
+        } else {
+            paragraphs.push((line_start, line));
+        }
+    }
+    if let Some(start) = start {
+        paragraphs.push((start, &text[start..]));
+    }
+    paragraphs
+}
+
+/// A short line that doesn't end like a sentence ("Results", "2.1 Setup").
+fn is_heading(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty()
+        && line.chars().count() <= MAX_HEADING_CHARS
+        && !line.ends_with(['.', '!', '?', ',', ';', ':', '…'])
+}
+
+/// Join sentences into chunks of up to `CHUNK_CHARS` (`FIRST_CHUNK_CHARS` for
+/// the first one when `short_first` is set).
+fn pack_sentences<'a>(sentences: impl IntoIterator<Item = &'a str>, short_first: bool) -> Vec<String> {
+    let mut chunks: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for sentence in sentences {
+        let limit = if chunks.is_empty() && short_first { FIRST_CHUNK_CHARS } else { CHUNK_CHARS };
+        if !current.is_empty() && current.len() + sentence.len() + 1 > limit {
+            chunks.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(if current.ends_with('\n') { '\n' } else { ' ' });
+        }
+        current.push_str(sentence);
+    }
+
+    if !current.trim().is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_text_is_one_chunk() {
+        assert_eq!(sentence_chunks("Hello there. How are you?"), vec!["Hello there. How are you?"]);
+        assert!(sentence_chunks("  ").is_empty());
+    }
+
+    #[test]
+    fn test_long_text_is_split_on_sentences() {
+        let sentence = "This sentence is exactly fifty characters long ok.";
+        let text = [sentence; 20].join(" ");
+        let chunks = sentence_chunks(&text);
+
+        assert!(chunks.len() > 2);
+        assert!(chunks[0].len() <= FIRST_CHUNK_CHARS);
+        assert!(chunks[1..].iter().all(|chunk| chunk.len() <= CHUNK_CHARS));
+        assert!(chunks.iter().all(|chunk| chunk.ends_with('.')));
+        assert_eq!(chunks.join(" "), text);
+    }
+
+    #[test]
+    fn test_decimal_points_do_not_split() {
+        assert_eq!(split_sentences("Pi is 3.14 roughly. Yes"), vec!["Pi is 3.14 roughly.", "Yes"]);
+    }
+
+    #[test]
+    fn test_rtl_sentence_endings() {
+        assert_eq!(split_sentences("كيف حالك؟ أنا بخير."), vec!["كيف حالك؟", "أنا بخير."]);
+        assert_eq!(split_sentences("یہ اچھا ہے۔ شکریہ"), vec!["یہ اچھا ہے۔", "شکریہ"]);
+    }
+
+    #[test]
+    fn test_skim() {
+        let text = "Results\nThe first run failed. It was retried.\n\nOther paragraph here. More.\n\nShort one.";
+        let skim = Skim::new(text);
+        assert_eq!(skim.text, "Results\nThe first run failed.\nOther paragraph here.\nShort one.");
+        assert_eq!(skim.expand(0.0), text);
+        assert_eq!(skim.expand(0.6), "Other paragraph here. More.\n\nShort one.");
+        assert_eq!(skim.expand(1.0), "Short one.");
+
+        // Without blank lines, every line is a paragraph
+        assert_eq!(Skim::new("One. Two.\nThree. Four.").text, "One.\nThree.");
+    }
+
+    #[test]
+    fn test_language_chunks() {
+        let detect = |sentence: &str| match sentence {
+            s if s.starts_with("Bonjour") || s.starts_with("Merci") => Some("fr"),
+            s if s.starts_with("Hello") => Some("en"),
+            _ => None,
+        };
+        assert_eq!(
+            language_chunks("Hello there. Ok. Bonjour tout le monde. Merci. Hello again.", detect),
+            vec![
+                (Some("en"), "Hello there. Ok.".to_string()),
+                (Some("fr"), "Bonjour tout le monde. Merci.".to_string()),
+                (Some("en"), "Hello again.".to_string()),
+            ]
+        );
+        assert_eq!(language_chunks("Hmm. Yes.", detect), vec![(None, "Hmm. Yes.".to_string())]);
+    }
+}
@@ -0,0 +1,392 @@
+//! External command provider implementation.
+//!
+//! Runs any TTS command line program (festival's `text2wave`, `flite`,
+//! `espeak-ng`, custom scripts) configured in
+//! `~/.config/insight-reader/command-voices.toml`, so new engines can be used
+//! without recompiling:
+//!
+//! ```toml
+//! [[voice]]
+//! name = "Flite"
+//! command = "flite"
+//! args = ["-t", "{text}", "-o", "{output}"]
+//!
+//! [[voice]]
+//! name = "eSpeak (raw)"
+//! command = "espeak-ng"
+//! args = ["--stdout"]      # no {text}/{input}: text is sent on stdin
+//! format = "raw"           # 16-bit mono PCM; "wav" (default) reads the header
+//! sample_rate = 22050
+//! ```
+//!
+//! Placeholders in `args`: `{text}` (the text itself), `{input}` (a file
+//! containing the text) and `{output}` (a file the command writes audio to;
+//! without it, audio is read from stdout).
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+use tracing::{debug, error, info};
+
+use super::audio_player::AudioPlayer;
+use super::wav::decode_wav;
+use super::{markup, OutputSettings, Speech, SynthesizedAudio, Synthesis, TTSError, TTSProvider};
+use crate::storage;
+
+/// File (in the app's config directory) listing the command voices.
+const COMMAND_VOICES_FILE: &str = "command-voices.toml";
+
+/// Sample rate of raw output when the voice doesn't set one.
+const DEFAULT_SAMPLE_RATE: u32 = 22050;
+
+/// Audio format a command produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommandOutputFormat {
+    /// WAV file (16-bit PCM); the sample rate is read from the header
+    #[default]
+    Wav,
+    /// Headerless 16-bit little-endian mono PCM at `sample_rate`
+    Raw,
+}
+
+/// One command voice from the TOML file.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct CommandVoice {
+    /// Name shown in settings
+    pub name: String,
+    /// Program to run (looked up on PATH)
+    pub command: String,
+    /// Arguments, with `{text}`, `{input}` and `{output}` placeholders
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Audio format the command produces
+    #[serde(default)]
+    pub format: CommandOutputFormat,
+    /// Sample rate of raw output
+    #[serde(default)]
+    pub sample_rate: Option<u32>,
+}
+
+#[derive(serde::Deserialize)]
+struct CommandVoicesFile {
+    #[serde(default, rename = "voice")]
+    voices: Vec<CommandVoice>,
+}
+
+/// Path of the command voices file.
+pub fn command_voices_path() -> Option<PathBuf> {
+    Some(dirs::config_dir()?.join("insight-reader").join(COMMAND_VOICES_FILE))
+}
+
+/// Parse the contents of a command voices file.
+fn parse_command_voices(contents: &str) -> Result<Vec<CommandVoice>, String> {
+    let file: CommandVoicesFile = toml::from_str(contents).map_err(|e| e.to_string())?;
+    if let Some(voice) = file.voices.iter().find(|voice| voice.command.trim().is_empty()) {
+        return Err(format!("Voice \"{}\" has no command", voice.name));
+    }
+    Ok(file.voices)
+}
+
+/// Load the configured command voices; a missing file means none.
+pub fn load_command_voices() -> Result<Vec<CommandVoice>, String> {
+    let Some(path) = command_voices_path() else {
+        return Ok(Vec::new());
+    };
+    match fs::read_to_string(&path) {
+        Ok(contents) => parse_command_voices(&contents).map_err(|e| format!("{}: {e}", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("Could not read {}: {e}", path.display())),
+    }
+}
+
+/// Substitute the placeholders in `args`.
+///
+/// Done in one pass over each argument, so placeholders written in the text
+/// itself are left as they are.
+fn expand_args(args: &[String], text: &str, input: &Path, output: &Path) -> Vec<String> {
+    let input = input.to_string_lossy();
+    let output = output.to_string_lossy();
+    let placeholders = [("{text}", text), ("{input}", &*input), ("{output}", &*output)];
+    args.iter()
+        .map(|arg| {
+            let mut expanded = String::with_capacity(arg.len());
+            let mut rest = arg.as_str();
+            while let Some(start) = rest.find('{') {
+                expanded.push_str(&rest[..start]);
+                rest = &rest[start..];
+                match placeholders.iter().find(|(placeholder, _)| rest.starts_with(placeholder)) {
+                    Some((placeholder, value)) => {
+                        expanded.push_str(value);
+                        rest = &rest[placeholder.len()..];
+                    }
+                    None => {
+                        expanded.push('{');
+                        rest = &rest[1..];
+                    }
+                }
+            }
+            expanded.push_str(rest);
+            expanded
+        })
+        .collect()
+}
+
+/// Unique paths of the text and audio files of one synthesis, in a folder only the user can read.
+fn temp_paths() -> Result<(PathBuf, PathBuf), TTSError> {
+    static NEXT_TEMP_ID: AtomicUsize = AtomicUsize::new(0);
+    let dir = storage::private_dir("command")
+        .map_err(|e| TTSError::ProcessError(format!("Failed to create folder for command files: {e}")))?;
+    let id = format!("{}-{}", std::process::id(), NEXT_TEMP_ID.fetch_add(1, Ordering::Relaxed));
+    Ok((dir.join(format!("{id}-input.txt")), dir.join(format!("{id}-output.wav"))))
+}
+
+/// Provider that runs a user-configured command to synthesize speech.
+pub struct CommandProvider {
+    /// Command to run and how to read its output
+    voice: CommandVoice,
+    /// Shared audio playback engine
+    player: AudioPlayer,
+}
+
+impl CommandProvider {
+    /// Create a provider for the given command voice.
+    pub fn new(voice: CommandVoice) -> Result<Self, TTSError> {
+        info!(name = %voice.name, command = %voice.command, "Initializing command provider");
+        let player = AudioPlayer::new(voice.sample_rate.unwrap_or(DEFAULT_SAMPLE_RATE))?;
+        Ok(Self { voice, player })
+    }
+}
+
+impl CommandVoice {
+    /// Run the command for `text` and return the audio bytes it produced.
+    fn run(&self, text: &str) -> Result<Vec<u8>, TTSError> {
+        let (input, output) = temp_paths()?;
+        let result = self.run_with_files(text, &input, &output);
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(&output);
+        result
+    }
+
+    /// Run the command for `text`, with `input` and `output` as the `{input}` and `{output}` files.
+    fn run_with_files(&self, text: &str, input: &Path, output: &Path) -> Result<Vec<u8>, TTSError> {
+        let uses_input = self.args.iter().any(|arg| arg.contains("{input}"));
+        let uses_output = self.args.iter().any(|arg| arg.contains("{output}"));
+        let uses_stdin = !uses_input && !self.args.iter().any(|arg| arg.contains("{text}"));
+
+        if uses_input {
+            storage::create_private_file(input)
+                .and_then(|mut file| file.write_all(text.as_bytes()))
+                .map_err(|e| TTSError::ProcessError(format!("Failed to write text file: {e}")))?;
+        }
+
+        let args = expand_args(&self.args, text, input, output);
+        debug!(command = %self.command, ?args, "Running TTS command");
+        let mut command = Command::new(&self.command);
+        command
+            .args(&args)
+            .stdin(if uses_stdin { Stdio::piped() } else { Stdio::null() })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        #[cfg(target_os = "windows")]
+        {
+            const CREATE_NO_WINDOW: u32 = 0x08000000;
+            command.creation_flags(CREATE_NO_WINDOW);
+        }
+
+        command
+            .spawn()
+            .map_err(|e| {
+                error!(error = %e, command = %self.command, "Failed to start TTS command");
+                TTSError::ProcessError(format!("Failed to start {}: {e}", self.command))
+            })
+            .and_then(|mut child| {
+                // Text is written from another thread while the output is read, or a command
+                // answering with more than a pipe's worth of audio would block both sides
+                let writer = child.stdin.take().map(|mut stdin| {
+                    let text = text.to_string();
+                    std::thread::spawn(move || stdin.write_all(text.as_bytes()))
+                });
+                let output = child
+                    .wait_with_output()
+                    .map_err(|e| TTSError::ProcessError(format!("TTS command failed: {e}")))?;
+                if let Some(writer) = writer {
+                    writer
+                        .join()
+                        .unwrap_or_else(|_| Err(std::io::Error::other("writer thread panicked")))
+                        .map_err(|e| TTSError::ProcessError(format!("Failed to send text to command: {e}")))?;
+                }
+                Ok(output)
+            })
+            .and_then(|result| {
+                if !result.status.success() {
+                    let stderr = String::from_utf8_lossy(&result.stderr);
+                    error!(status = %result.status, stderr = %stderr.trim(), "TTS command failed");
+                    return Err(TTSError::ProcessError(format!(
+                        "{} exited with {}: {}",
+                        self.command,
+                        result.status,
+                        stderr.trim()
+                    )));
+                }
+                if uses_output {
+                    fs::read(output)
+                        .map_err(|e| TTSError::ProcessError(format!("Command wrote no audio file: {e}")))
+                } else {
+                    Ok(result.stdout)
+                }
+            })
+    }
+
+    /// Run the command for `text` and decode the audio it produced.
+    fn synthesize(&self, text: &str) -> Result<Speech, TTSError> {
+        let bytes = self.run(text)?;
+        let (samples, sample_rate) = match self.format {
+            CommandOutputFormat::Wav => {
+                let wav = decode_wav(&bytes)?;
+                (wav.samples, wav.sample_rate)
+            }
+            CommandOutputFormat::Raw => (
+                AudioPlayer::pcm_to_f32(&bytes),
+                self.sample_rate.unwrap_or(DEFAULT_SAMPLE_RATE),
+            ),
+        };
+        if samples.is_empty() {
+            return Err(TTSError::ProcessError(format!(
+                "No audio data generated by {}",
+                self.command
+            )));
+        }
+
+        info!(
+            bytes = bytes.len(),
+            sample_rate,
+            duration_sec = format!("{:.1}", samples.len() as f32 / sample_rate as f32),
+            "Command: audio generated"
+        );
+        Ok(Speech::complete(samples, sample_rate))
+    }
+}
+
+impl TTSProvider for CommandProvider {
+    fn synthesize(&mut self, text: &str) -> Result<Synthesis, TTSError> {
+        debug!(chars = text.len(), name = %self.voice.name, "Command: synthesizing speech");
+
+        // Stop any current playback
+        self.player.stop()?;
+
+        let voice = self.voice.clone();
+        let text = markup::to_plain_text(text);
+        Ok(Synthesis::new(move || voice.synthesize(&text)))
+    }
+
+    fn play(&mut self, speech: Speech) -> Result<(), TTSError> {
+        self.player.play_speech(speech)
+    }
+
+    fn pause(&mut self) -> Result<(), TTSError> {
+        self.player.pause()
+    }
+
+    fn resume(&mut self) -> Result<(), TTSError> {
+        self.player.resume()
+    }
+
+    fn stop(&mut self) -> Result<(), TTSError> {
+        self.player.stop()
+    }
+
+    fn is_playing(&self) -> bool {
+        self.player.is_playing()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.player.is_paused()
+    }
+
+    fn is_buffering(&self) -> bool {
+        self.player.is_buffering()
+    }
+
+    fn set_speed(&mut self, speed: f32) {
+        self.player.set_speed(speed);
+    }
+
+    fn set_pitch(&mut self, semitones: f32) {
+        self.player.set_pitch(semitones);
+    }
+
+    fn set_output(&mut self, output: &OutputSettings) -> Result<(), TTSError> {
+        self.player.set_output(output)
+    }
+
+    fn skip_forward(&mut self, seconds: f32) {
+        self.player.skip_forward(seconds);
+    }
+
+    fn skip_backward(&mut self, seconds: f32) {
+        self.player.skip_backward(seconds);
+    }
+
+    fn set_loop(&mut self, region: Option<(f32, f32)>) {
+        self.player.set_loop(region);
+    }
+
+    fn get_progress(&self) -> f32 {
+        self.player.get_progress()
+    }
+
+    fn get_duration(&self) -> f32 {
+        self.player.get_duration()
+    }
+
+    fn get_frequency_bands(&self, num_bands: usize) -> Vec<f32> {
+        self.player.get_frequency_bands(num_bands)
+    }
+
+    fn synthesized_audio(&self) -> Option<SynthesizedAudio> {
+        self.player.synthesized_audio()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command_voices() {
+        let voices = parse_command_voices(
+            r#"
+            [[voice]]
+            name = "Flite"
+            command = "flite"
+            args = ["-t", "{text}", "-o", "{output}"]
+
+            [[voice]]
+            name = "eSpeak"
+            command = "espeak-ng"
+            format = "raw"
+            sample_rate = 22050
+            "#,
+        )
+        .unwrap();
+        assert_eq!(voices.len(), 2);
+        assert_eq!(voices[0].format, CommandOutputFormat::Wav);
+        assert_eq!(voices[1].format, CommandOutputFormat::Raw);
+        assert_eq!(voices[1].sample_rate, Some(22050));
+        assert!(parse_command_voices("[[voice]]\nname = \"x\"\ncommand = \"\"").is_err());
+        assert!(parse_command_voices("").unwrap().is_empty());
+
+        let args = expand_args(&voices[0].args, "Hi there", Path::new("/tmp/in.txt"), Path::new("/tmp/out.wav"));
+        assert_eq!(args, vec!["-t", "Hi there", "-o", "/tmp/out.wav"]);
+        // Placeholders inside the text are read as written
+        let args = expand_args(&voices[0].args, "Use {output} and {x}", Path::new("/in"), Path::new("/out"));
+        assert_eq!(args[1], "Use {output} and {x}");
+    }
+}
@@ -0,0 +1,44 @@
+//! Coqui TTS server provider implementation.
+//!
+//! Talks to a locally running Coqui TTS (or XTTS) server over HTTP
+//! (`tts-server`, default `http://localhost:5002`) and plays the returned WAV
+//! using rodio. Gives higher-quality local neural voices than Piper for users
+//! who run their own server.
+
+use super::http_wav::{HttpWavProvider, WavServer};
+
+/// Default URL of a locally running Coqui TTS server.
+pub const DEFAULT_COQUI_URL: &str = "http://localhost:5002";
+
+/// Connection settings for a Coqui TTS server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoquiConfig {
+    /// Base URL of the server (e.g., "http://localhost:5002")
+    pub url: String,
+    /// Speaker ID for multi-speaker models (empty for single-speaker models)
+    pub speaker: String,
+}
+
+impl Default for CoquiConfig {
+    fn default() -> Self {
+        Self {
+            url: DEFAULT_COQUI_URL.to_string(),
+            speaker: String::new(),
+        }
+    }
+}
+
+impl WavServer for CoquiConfig {
+    const NAME: &'static str = "Coqui server";
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("speaker_id", self.speaker.clone())]
+    }
+}
+
+/// Coqui TTS provider using the server's `/api/tts` endpoint.
+pub type CoquiTTSProvider = HttpWavProvider<CoquiConfig>;
@@ -0,0 +1,196 @@
+//! Shared provider for TTS servers that answer over HTTP with WAV audio.
+//!
+//! Coqui TTS, OpenTTS and Mimic 3 servers all take the text as a query
+//! parameter of `GET /api/tts` and return a WAV file; they only differ in the
+//! other parameters (speaker, voice). Each server describes itself with a
+//! [`WavServer`], and [`HttpWavProvider`] does the requests, error mapping and
+//! playback for all of them.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tracing::{debug, error, info};
+
+use super::audio_player::AudioPlayer;
+use super::wav::wav_chunks_synthesis;
+use super::{markup, OutputSettings, Speech, SynthesizedAudio, Synthesis, TTSError, TTSProvider};
+
+/// Sample rate used until the first response tells us otherwise.
+const DEFAULT_SAMPLE_RATE: u32 = 22050;
+
+/// Longest a server may take to synthesize one chunk; large models on a CPU are slow.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Connection settings of a server with a `GET /api/tts` endpoint returning WAV.
+pub trait WavServer: Send + 'static {
+    /// Name shown in logs and errors, e.g. "Coqui server".
+    const NAME: &'static str;
+
+    /// Base URL of the server (e.g., "http://localhost:5002").
+    fn url(&self) -> &str;
+
+    /// Query parameters sent along with the text; empty values are left out.
+    fn params(&self) -> Vec<(&'static str, String)>;
+}
+
+/// TTS provider for a [`WavServer`].
+pub struct HttpWavProvider<S: WavServer> {
+    /// HTTP client
+    client: reqwest::Client,
+    /// Shared audio playback engine
+    player: AudioPlayer,
+    /// Tokio runtime for async HTTP calls, shared with the chunk synthesis thread
+    runtime: Arc<tokio::runtime::Runtime>,
+    /// Server connection settings
+    server: S,
+}
+
+impl<S: WavServer> HttpWavProvider<S> {
+    /// Create a new provider for the given server.
+    pub fn new(server: S) -> Result<Self, TTSError> {
+        info!(server = S::NAME, url = %server.url(), params = ?server.params(), "Initializing HTTP TTS provider");
+
+        if server.url().trim().is_empty() {
+            return Err(TTSError::ProcessError(format!("{} URL is not configured", S::NAME)));
+        }
+
+        // Create a tokio runtime for async HTTP calls
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| TTSError::ProcessError(format!("Failed to create tokio runtime: {e}")))?;
+
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| TTSError::ProcessError(format!("Failed to create HTTP client: {e}")))?;
+
+        let player = AudioPlayer::new(DEFAULT_SAMPLE_RATE)?;
+
+        Ok(Self {
+            client,
+            player,
+            runtime: Arc::new(runtime),
+            server,
+        })
+    }
+
+    /// Full URL of the synthesis endpoint.
+    fn endpoint(&self) -> String {
+        format!("{}/api/tts", self.server.url().trim().trim_end_matches('/'))
+    }
+}
+
+impl<S: WavServer> TTSProvider for HttpWavProvider<S> {
+    fn synthesize(&mut self, text: &str) -> Result<Synthesis, TTSError> {
+        debug!(server = S::NAME, chars = text.len(), "Synthesizing speech");
+
+        // Stop any current playback
+        self.player.stop()?;
+
+        let text = markup::to_plain_text(text);
+        let endpoint = self.endpoint();
+        let params: Vec<_> = self.server.params().into_iter().filter(|(_, value)| !value.is_empty()).collect();
+        let client = self.client.clone();
+        let runtime = Arc::clone(&self.runtime);
+        // Models differ in output sample rate (e.g. XTTS uses 24kHz, Mimic 3 low-quality voices 16kHz):
+        // the first chunk sets it
+        wav_chunks_synthesis(&text, S::NAME, move |chunk| {
+            let mut query = vec![("text", chunk)];
+            query.extend(params.iter().map(|(name, value)| (*name, value.as_str())));
+            runtime.block_on(async {
+                let response = client
+                    .get(&endpoint)
+                    .query(&query)
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        error!(server = S::NAME, url = %endpoint, error = %e, "TTS server request failed");
+                        TTSError::ProcessError(format!("Could not reach {} at {endpoint}: {e}", S::NAME))
+                    })?;
+
+                let status = response.status();
+                if !status.is_success() {
+                    let body = response.text().await.unwrap_or_default();
+                    error!(server = S::NAME, %status, body = %body, "TTS server returned an error");
+                    return Err(TTSError::ProcessError(format!("{} error ({status}): {body}", S::NAME)));
+                }
+
+                let bytes = response
+                    .bytes()
+                    .await
+                    .map_err(|e| TTSError::ProcessError(format!("Failed to read {} response: {e}", S::NAME)))?;
+
+                Ok::<_, TTSError>(bytes.to_vec())
+            })
+        })
+    }
+
+    fn play(&mut self, speech: Speech) -> Result<(), TTSError> {
+        self.player.play_speech(speech)
+    }
+
+    fn pause(&mut self) -> Result<(), TTSError> {
+        self.player.pause()
+    }
+
+    fn resume(&mut self) -> Result<(), TTSError> {
+        self.player.resume()
+    }
+
+    fn stop(&mut self) -> Result<(), TTSError> {
+        self.player.stop()
+    }
+
+    fn is_playing(&self) -> bool {
+        self.player.is_playing()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.player.is_paused()
+    }
+
+    fn is_buffering(&self) -> bool {
+        self.player.is_buffering()
+    }
+
+    fn set_speed(&mut self, speed: f32) {
+        self.player.set_speed(speed);
+    }
+
+    fn set_pitch(&mut self, semitones: f32) {
+        self.player.set_pitch(semitones);
+    }
+
+    fn set_output(&mut self, output: &OutputSettings) -> Result<(), TTSError> {
+        self.player.set_output(output)
+    }
+
+    fn skip_forward(&mut self, seconds: f32) {
+        self.player.skip_forward(seconds);
+    }
+
+    fn skip_backward(&mut self, seconds: f32) {
+        self.player.skip_backward(seconds);
+    }
+
+    fn set_loop(&mut self, region: Option<(f32, f32)>) {
+        self.player.set_loop(region);
+    }
+
+    fn get_progress(&self) -> f32 {
+        self.player.get_progress()
+    }
+
+    fn get_duration(&self) -> f32 {
+        self.player.get_duration()
+    }
+
+    fn get_frequency_bands(&self, num_bands: usize) -> Vec<f32> {
+        self.player.get_frequency_bands(num_bands)
+    }
+
+    fn synthesized_audio(&self) -> Option<SynthesizedAudio> {
+        self.player.synthesized_audio()
+    }
+}
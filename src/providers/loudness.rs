@@ -0,0 +1,86 @@
+//! Loudness normalization
+//!
+//! Voices and providers come out at very different levels (a quiet Piper
+//! voice next to a hot Polly one). Speech is brought to a common RMS level,
+//! measured over its voiced blocks only so pauses don't count, and a soft
+//! limiter keeps the raised peaks from clipping. Streamed speech keeps the
+//! gain of its first chunk so the level doesn't pump between chunks.
+
+/// RMS level speech is brought to (about -20 dBFS).
+const TARGET_RMS: f32 = 0.1;
+
+/// Blocks quieter than this RMS are pauses and don't count toward the level.
+const SILENCE_GATE: f32 = 0.005;
+
+/// Samples per block when measuring the level.
+const BLOCK: usize = 1024;
+
+/// Limits of the applied gain, so near-silent audio isn't blown up.
+const MIN_GAIN: f32 = 0.25;
+const MAX_GAIN: f32 = 4.0;
+
+/// Level above which the limiter starts compressing peaks.
+const LIMITER_THRESHOLD: f32 = 0.9;
+
+/// RMS of the voiced blocks of `samples`, or None if it is all silence.
+fn voiced_rms(samples: &[f32]) -> Option<f32> {
+    let (sum, count) = samples
+        .chunks(BLOCK)
+        .map(|block| (block.iter().map(|s| s * s).sum::<f32>(), block.len()))
+        .filter(|&(sum, len)| (sum / len as f32).sqrt() >= SILENCE_GATE)
+        .fold((0.0, 0), |(total, count), (sum, len)| (total + sum, count + len));
+    (count > 0).then(|| (sum / count as f32).sqrt())
+}
+
+/// Gain that brings `samples` to the target level; 1.0 for silence.
+pub fn normalization_gain(samples: &[f32]) -> f32 {
+    voiced_rms(samples).map_or(1.0, |rms| (TARGET_RMS / rms).clamp(MIN_GAIN, MAX_GAIN))
+}
+
+/// Soft-limit one sample: unchanged below the threshold, approaching ±1.0 above it.
+fn limit(sample: f32) -> f32 {
+    let magnitude = sample.abs();
+    if magnitude <= LIMITER_THRESHOLD {
+        return sample;
+    }
+    let headroom = 1.0 - LIMITER_THRESHOLD;
+    let limited = LIMITER_THRESHOLD + headroom * ((magnitude - LIMITER_THRESHOLD) / headroom).tanh();
+    limited.copysign(sample)
+}
+
+/// Multiply `samples` by `gain`, limiting the peaks.
+pub fn apply_gain(samples: &mut [f32], gain: f32) {
+    if gain == 1.0 {
+        return;
+    }
+    for sample in samples {
+        *sample = limit(*sample * gain);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalization() {
+        let tone = |amplitude: f32| -> Vec<f32> {
+            (0..BLOCK * 8).map(|i| amplitude * (i as f32 * 0.05).sin()).collect()
+        };
+        // Quiet and loud speech end up at the same level; pauses don't count
+        let mut quiet = tone(0.05);
+        quiet.extend(vec![0.0; BLOCK * 8]);
+        let mut loud = tone(0.5);
+        let (quiet_gain, loud_gain) = (normalization_gain(&quiet), normalization_gain(&loud));
+        apply_gain(&mut quiet, quiet_gain);
+        apply_gain(&mut loud, loud_gain);
+        let (quiet_rms, loud_rms) = (voiced_rms(&quiet).unwrap(), voiced_rms(&loud).unwrap());
+        assert!((quiet_rms - TARGET_RMS).abs() < 0.01, "{quiet_rms}");
+        assert!((loud_rms - TARGET_RMS).abs() < 0.01, "{loud_rms}");
+
+        assert_eq!(normalization_gain(&[0.0; 4096]), 1.0);
+        assert_eq!(normalization_gain(&tone(0.001)), 1.0);
+        assert!(limit(3.0) <= 1.0 && limit(-3.0) >= -1.0 && limit(0.95) < 0.95);
+        assert_eq!(limit(0.5), 0.5);
+    }
+}
@@ -0,0 +1,270 @@
+//! Provider-neutral speech markup.
+//!
+//! Text passes that want a pause, emphasis or a language switch insert
+//! markers built with [`pause`], [`emphasis`] and [`language`] into the text
+//! they return. Each provider lowers the marked-up text to what its engine
+//! understands: SSML for AWS Polly, punctuation for engines that only read
+//! plain text. Text without markers passes through unchanged.
+
+use std::fmt::Write;
+
+const OPEN: char = '⟦';
+const CLOSE: char = '⟧';
+
+/// Pauses at least this long become a sentence break in plain text.
+const LONG_PAUSE_MS: u32 = 500;
+
+/// One piece of marked-up speech.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpeechPart {
+    /// Text read normally
+    Text(String),
+    /// Silence, in milliseconds
+    Pause(u32),
+    /// Text read with emphasis
+    Emphasis(String),
+    /// Text in another language (ISO 639-1 code or locale, e.g. "fr" or "fr-FR")
+    Language { code: String, text: String },
+}
+
+/// Marker for a pause of `ms` milliseconds.
+pub fn pause(ms: u32) -> String {
+    format!("{OPEN}pause {ms}{CLOSE}")
+}
+
+/// Mark `text` to be read with emphasis.
+pub fn emphasis(text: &str) -> String {
+    format!("{OPEN}em{CLOSE}{text}{OPEN}/em{CLOSE}")
+}
+
+/// Mark `text` as being in language `code`.
+#[allow(dead_code)] // No text pass marks languages yet
+pub fn language(code: &str, text: &str) -> String {
+    format!("{OPEN}lang {code}{CLOSE}{text}{OPEN}/lang{CLOSE}")
+}
+
+/// Whether `text` contains any markers.
+pub fn has_markup(text: &str) -> bool {
+    text.contains(OPEN)
+}
+
+/// Span being collected between an opening and closing marker.
+enum Span {
+    Emphasis,
+    Language(String),
+}
+
+impl Span {
+    fn part(&self, text: String) -> SpeechPart {
+        match self {
+            Self::Emphasis => SpeechPart::Emphasis(text),
+            Self::Language(code) => SpeechPart::Language { code: code.clone(), text },
+        }
+    }
+}
+
+/// Parse marked-up text into parts.
+///
+/// Unknown markers are kept as text, stray closing markers are dropped and a
+/// span left open (e.g. cut by chunking) runs to the end of the text.
+pub fn parse(text: &str) -> Vec<SpeechPart> {
+    let mut parts = Vec::new();
+    let mut span: Option<Span> = None;
+    let mut current = String::new();
+
+    let flush = |parts: &mut Vec<SpeechPart>, span: &Option<Span>, current: &mut String| {
+        if current.is_empty() {
+            return;
+        }
+        let text = std::mem::take(current);
+        parts.push(match span {
+            Some(span) => span.part(text),
+            None => SpeechPart::Text(text),
+        });
+    };
+
+    let mut rest = text;
+    while let Some(start) = rest.find(OPEN) {
+        current.push_str(&rest[..start]);
+        let after = &rest[start + OPEN.len_utf8()..];
+        let Some(end) = after.find(CLOSE) else {
+            current.push_str(&rest[start..]);
+            rest = "";
+            break;
+        };
+        let tag = after[..end].trim();
+        rest = &after[end + CLOSE.len_utf8()..];
+
+        let (name, argument) = tag.split_once(' ').unwrap_or((tag, ""));
+        match name {
+            "pause" => match argument.trim().parse() {
+                Ok(ms) => {
+                    flush(&mut parts, &span, &mut current);
+                    parts.push(SpeechPart::Pause(ms));
+                }
+                Err(_) => current.push_str(&format!("{OPEN}{tag}{CLOSE}")),
+            },
+            "em" => {
+                flush(&mut parts, &span, &mut current);
+                span = Some(Span::Emphasis);
+            }
+            "lang" if !argument.trim().is_empty() => {
+                flush(&mut parts, &span, &mut current);
+                span = Some(Span::Language(argument.trim().to_string()));
+            }
+            "/em" | "/lang" => {
+                flush(&mut parts, &span, &mut current);
+                span = None;
+            }
+            _ => current.push_str(&format!("{OPEN}{tag}{CLOSE}")),
+        }
+    }
+    current.push_str(rest);
+    flush(&mut parts, &span, &mut current);
+    parts
+}
+
+/// Lower marked-up text to plain text for engines without markup support.
+///
+/// Short pauses become commas and long ones sentence breaks, which engines
+/// that read plain text turn into silence; emphasis and language switches
+/// are read as normal text.
+pub fn to_plain_text(text: &str) -> String {
+    if !has_markup(text) {
+        return text.to_string();
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut after_pause = false;
+    for part in parse(text) {
+        match part {
+            SpeechPart::Text(text) | SpeechPart::Emphasis(text) | SpeechPart::Language { text, .. } => {
+                // The pause already added the separator
+                result.push_str(if after_pause { text.trim_start() } else { &text });
+                after_pause = false;
+            }
+            SpeechPart::Pause(ms) => {
+                after_pause = true;
+                let trimmed = result.trim_end().len();
+                result.truncate(trimmed);
+                let ends_sentence = result.ends_with(['.', '!', '?', ':', ';', ',']);
+                if ms >= LONG_PAUSE_MS {
+                    result.push_str(if ends_sentence || result.is_empty() { "\n" } else { ".\n" });
+                } else {
+                    result.push_str(if ends_sentence || result.is_empty() { " " } else { ", " });
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Locale for an SSML `xml:lang` attribute, if `code` can be mapped to one.
+fn ssml_locale(code: &str) -> Option<String> {
+    if code.contains(['-', '_']) {
+        return Some(code.replace('_', "-"));
+    }
+    let locale = match code {
+        "ar" => "arb",
+        "da" => "da-DK",
+        "de" => "de-DE",
+        "en" => "en-US",
+        "es" => "es-ES",
+        "fr" => "fr-FR",
+        "hi" => "hi-IN",
+        "it" => "it-IT",
+        "ja" => "ja-JP",
+        "ko" => "ko-KR",
+        "nl" => "nl-NL",
+        "no" => "nb-NO",
+        "pl" => "pl-PL",
+        "pt" => "pt-PT",
+        "ru" => "ru-RU",
+        "sv" => "sv-SE",
+        "tr" => "tr-TR",
+        "zh" => "cmn-CN",
+        _ => return None,
+    };
+    Some(locale.to_string())
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Lower marked-up text to an SSML document.
+///
+/// `emphasis_supported` is false for engines that reject `<emphasis>`
+/// (e.g. Polly's neural voices); emphasized text is then read normally.
+/// A non-zero `pitch_semitones` wraps the text in a `<prosody>` pitch change.
+pub fn to_ssml(text: &str, emphasis_supported: bool, pitch_semitones: f32) -> String {
+    let mut ssml = String::from("<speak>");
+    // Percent is the relative pitch unit every SSML engine accepts
+    let pitch_percent = ((2f32.powf(pitch_semitones / 12.0) - 1.0) * 100.0).round() as i32;
+    if pitch_percent != 0 {
+        let _ = write!(ssml, "<prosody pitch=\"{pitch_percent:+}%\">");
+    }
+    for part in parse(text) {
+        match part {
+            SpeechPart::Text(text) => ssml.push_str(&escape_xml(&text)),
+            SpeechPart::Pause(ms) => {
+                let _ = write!(ssml, "<break time=\"{ms}ms\"/>");
+            }
+            SpeechPart::Emphasis(text) if emphasis_supported => {
+                let _ = write!(ssml, "<emphasis>{}</emphasis>", escape_xml(&text));
+            }
+            SpeechPart::Emphasis(text) => ssml.push_str(&escape_xml(&text)),
+            SpeechPart::Language { code, text } => match ssml_locale(&code) {
+                Some(locale) => {
+                    let _ = write!(ssml, "<lang xml:lang=\"{locale}\">{}</lang>", escape_xml(&text));
+                }
+                None => ssml.push_str(&escape_xml(&text)),
+            },
+        }
+    }
+    if pitch_percent != 0 {
+        ssml.push_str("</prosody>");
+    }
+    ssml.push_str("</speak>");
+    ssml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = format!("Hello{} {} and {}.", pause(300), emphasis("very"), language("fr", "bonjour"));
+        assert_eq!(
+            parse(&text),
+            vec![
+                SpeechPart::Text("Hello".into()),
+                SpeechPart::Pause(300),
+                SpeechPart::Text(" ".into()),
+                SpeechPart::Emphasis("very".into()),
+                SpeechPart::Text(" and ".into()),
+                SpeechPart::Language { code: "fr".into(), text: "bonjour".into() },
+                SpeechPart::Text(".".into()),
+            ]
+        );
+        // Unknown and unterminated markers stay text; an open span runs to the end
+        assert_eq!(parse("a ⟦x⟧ b ⟦pause"), vec![SpeechPart::Text("a ⟦x⟧ b ⟦pause".into())]);
+        assert_eq!(parse("⟦em⟧cut"), vec![SpeechPart::Emphasis("cut".into())]);
+    }
+
+    #[test]
+    fn test_lowering() {
+        let text = format!("Title{}Body {} here{} end. {}", pause(800), emphasis("is"), pause(200), pause(600));
+        assert_eq!(to_plain_text(&text), "Title.\nBody is here, end.\n");
+        assert_eq!(to_plain_text("No markup, <kept> as is"), "No markup, <kept> as is");
+        assert_eq!(
+            to_ssml(&format!("A & B{}{}", pause(250), emphasis("now")), true, 0.0),
+            "<speak>A &amp; B<break time=\"250ms\"/><emphasis>now</emphasis></speak>"
+        );
+        assert_eq!(
+            to_ssml(&format!("{} {}", emphasis("now"), language("fr", "oui")), false, 0.0),
+            "<speak>now <lang xml:lang=\"fr-FR\">oui</lang></speak>"
+        );
+        assert_eq!(to_ssml("Hi", true, -2.0), "<speak><prosody pitch=\"-11%\">Hi</prosody></speak>");
+    }
+}
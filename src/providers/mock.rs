@@ -0,0 +1,178 @@
+//! Mock TTS provider for tests.
+//!
+//! "Synthesizes" a sine tone lasting as long as the text would take to read
+//! and plays it on a clock instead of an audio device, so playback logic can
+//! be tested without models, servers or sound hardware.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use super::{OutputSettings, Speech, SynthesizedAudio, Synthesis, TTSError, TTSProvider};
+
+const SAMPLE_RATE: u32 = 16_000;
+/// Samples of audio per character of text: 65 ms, about 15 characters per second (a usual speaking rate)
+pub const SAMPLES_PER_CHAR: usize = 1_040;
+const TONE_HZ: f32 = 440.0;
+
+/// Provider playing a sine tone, its length proportional to the text's.
+#[derive(Default)]
+pub struct MockTTSProvider {
+    audio: Option<SynthesizedAudio>,
+    /// Position in seconds (at normal speed) when `started` was last set
+    position: f32,
+    /// When playback (re)started from `position`; None while paused or stopped
+    started: Option<Instant>,
+    paused: bool,
+    speed: f32,
+    loop_region: Option<(f32, f32)>,
+}
+
+impl MockTTSProvider {
+    pub fn new() -> Self {
+        Self { speed: 1.0, ..Default::default() }
+    }
+
+    /// Current position in seconds at normal speed.
+    fn position(&self) -> f32 {
+        let elapsed = self.started.map_or(0.0, |started| started.elapsed().as_secs_f32() * self.speed);
+        let position = self.position + elapsed;
+        match self.loop_region {
+            Some((start, end)) if end > start && position >= end => start + (position - start) % (end - start),
+            _ => position.min(self.get_duration()),
+        }
+    }
+
+    /// Restart the clock from `position`, keeping the playing or paused state.
+    fn seek(&mut self, position: f32) {
+        self.position = position.clamp(0.0, self.get_duration());
+        if self.started.is_some() {
+            self.started = Some(Instant::now());
+        }
+    }
+}
+
+impl TTSProvider for MockTTSProvider {
+    fn synthesize(&mut self, text: &str) -> Result<Synthesis, TTSError> {
+        let chars = text.chars().count();
+        Ok(Synthesis::new(move || {
+            let samples = (0..chars * SAMPLES_PER_CHAR)
+                .map(|i| (i as f32 / SAMPLE_RATE as f32 * TONE_HZ * std::f32::consts::TAU).sin() * 0.5)
+                .collect();
+            Ok(Speech::complete(samples, SAMPLE_RATE))
+        }))
+    }
+
+    fn play(&mut self, speech: Speech) -> Result<(), TTSError> {
+        self.audio = Some(SynthesizedAudio { samples: Arc::new(speech.samples), sample_rate: speech.sample_rate });
+        self.position = 0.0;
+        self.paused = false;
+        self.started = Some(Instant::now());
+        Ok(())
+    }
+
+    fn pause(&mut self) -> Result<(), TTSError> {
+        self.position = self.position();
+        self.started = None;
+        self.paused = true;
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<(), TTSError> {
+        if self.paused {
+            self.paused = false;
+            self.started = Some(Instant::now());
+        }
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<(), TTSError> {
+        self.position = 0.0;
+        self.started = None;
+        self.paused = false;
+        Ok(())
+    }
+
+    fn is_playing(&self) -> bool {
+        self.started.is_some() && self.position() < self.get_duration()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    fn is_buffering(&self) -> bool {
+        false
+    }
+
+    fn set_speed(&mut self, speed: f32) {
+        let position = self.position();
+        self.speed = speed;
+        self.seek(position);
+    }
+
+    fn set_pitch(&mut self, _semitones: f32) {}
+
+    fn set_output(&mut self, _output: &OutputSettings) -> Result<(), TTSError> {
+        Ok(())
+    }
+
+    fn skip_forward(&mut self, seconds: f32) {
+        self.seek(self.position() + seconds);
+    }
+
+    fn skip_backward(&mut self, seconds: f32) {
+        self.seek(self.position() - seconds);
+    }
+
+    fn set_loop(&mut self, region: Option<(f32, f32)>) {
+        let position = self.position();
+        self.loop_region = region;
+        self.seek(position);
+    }
+
+    fn get_progress(&self) -> f32 {
+        let duration = self.get_duration();
+        if duration > 0.0 { self.position() / duration } else { 0.0 }
+    }
+
+    fn get_duration(&self) -> f32 {
+        self.audio
+            .as_ref()
+            .map_or(0.0, |audio| audio.samples.len() as f32 / audio.sample_rate as f32)
+    }
+
+    fn get_frequency_bands(&self, num_bands: usize) -> Vec<f32> {
+        // The tone is steady: every band at half height while it plays
+        let level = if self.is_playing() { 0.5 } else { 0.0 };
+        vec![level; num_bands]
+    }
+
+    fn synthesized_audio(&self) -> Option<SynthesizedAudio> {
+        self.audio.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::providers::speak_blocking;
+    use super::*;
+
+    #[test]
+    fn test_mock_provider() {
+        let mut provider = MockTTSProvider::new();
+        speak_blocking(&mut provider, &"a".repeat(100)).unwrap();
+        assert!((provider.get_duration() - 6.5).abs() < 0.01);
+        assert!(provider.is_playing());
+
+        provider.pause().unwrap();
+        provider.skip_forward(2.0);
+        assert!(provider.is_paused() && !provider.is_playing());
+        assert!((provider.get_progress() - 2.0 / 6.5).abs() < 0.01);
+
+        provider.resume().unwrap();
+        provider.skip_forward(10.0);
+        assert_eq!(provider.get_progress(), 1.0);
+        assert!(!provider.is_playing() && !provider.is_paused());
+        assert_eq!(provider.synthesized_audio().unwrap().samples.len(), 104_000);
+    }
+}
@@ -3,14 +3,51 @@
 //! This module defines the [`TTSProvider`] trait and provides implementations
 //! for different TTS engines.
 
+pub mod audio_cache;
 mod audio_player;
+mod chunking;
+mod command;
+mod coqui;
+mod http_wav;
+mod loudness;
+pub mod markup;
+#[cfg(test)]
+pub mod mock;
+mod opentts;
+mod pauses;
+pub mod phrase_cache;
 mod piper;
+#[cfg(feature = "piper-onnx")]
+mod piper_onnx;
 pub mod polly;
+pub mod replay_buffer;
+pub mod synthesis_load;
+mod time_stretch;
+pub mod virtual_mic;
+mod wav;
+#[cfg(target_os = "windows")]
+mod windows;
+pub mod word_timing;
 
+pub use audio_player::{list_output_devices, ChannelRouting, OutputSettings, SynthesizedAudio};
+pub use chunking::{paragraphs, Skim};
+pub use command::{command_voices_path, load_command_voices, CommandProvider};
+pub use coqui::{CoquiConfig, CoquiTTSProvider};
+pub use opentts::{OpenTTSConfig, OpenTTSProvider};
 pub use piper::PiperTTSProvider;
-pub use polly::PollyTTSProvider;
+pub use polly::{PollyCostEstimate, PollyTTSProvider};
+pub use wav::encode_wav_with_info;
+#[cfg(target_os = "windows")]
+pub use windows::WindowsTTSProvider;
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 use thiserror::Error;
+use tracing::debug;
+
+use audio_player::StreamAppender;
 
 /// Errors that can occur during TTS operations.
 #[derive(Debug, Error)]
@@ -24,13 +61,23 @@ pub enum TTSError {
 
 /// Abstract interface for TTS providers.
 ///
-/// Allows plugging in different TTS engines (Piper, Polly, etc.).
+/// Allows plugging in different TTS engines (Piper, Polly, Windows, etc.).
+/// Providers hold the audio output, so they live on the UI thread; speaking
+/// is split in two so synthesis (network calls, subprocesses, inference)
+/// doesn't block it: [`synthesize`](TTSProvider::synthesize) prepares a
+/// [`Synthesis`] that runs on a worker thread, and
+/// [`play`](TTSProvider::play) plays the [`Speech`] it produced.
 pub trait TTSProvider {
-    /// Speak the given text.
+    /// Stop the current speech and prepare the synthesis of `text`.
     ///
-    /// This method synthesizes and plays the audio. It may block during
-    /// synthesis but audio playback is non-blocking.
-    fn speak(&mut self, text: &str) -> Result<(), TTSError>;
+    /// Doesn't block: the returned [`Synthesis`] does the work. Streaming
+    /// providers only synthesize the first chunk there and the rest while it
+    /// plays. `text` may contain [`markup`] markers, which each provider
+    /// lowers to its engine's format.
+    fn synthesize(&mut self, text: &str) -> Result<Synthesis, TTSError>;
+
+    /// Start playing speech produced by this provider's [`Synthesis`], or replayed from a cache.
+    fn play(&mut self, speech: Speech) -> Result<(), TTSError>;
 
     /// Pause the current speech playback.
     fn pause(&mut self) -> Result<(), TTSError>;
@@ -47,17 +94,194 @@ pub trait TTSProvider {
     /// Check if speech is currently paused.
     fn is_paused(&self) -> bool;
 
+    /// Check if playback is waiting for the next chunk to be synthesized.
+    ///
+    /// Upcoming chunks are synthesized while earlier ones play; this is only
+    /// true when the provider is slower than playback and falls behind.
+    fn is_buffering(&self) -> bool;
+
+    /// Set the playback speed multiplier (1.0 = normal).
+    ///
+    /// Pitch is preserved; takes effect immediately if speech is playing.
+    fn set_speed(&mut self, speed: f32);
+
+    /// Set the pitch offset in semitones (0.0 = the voice's own pitch).
+    ///
+    /// Usually applied by resampling the audio, taking effect immediately;
+    /// engines that take it as SSML prosody apply it from the next reading.
+    fn set_pitch(&mut self, semitones: f32);
+
+    /// Set the output device and volume.
+    ///
+    /// Takes effect immediately, moving active playback to the new device.
+    fn set_output(&mut self, output: &OutputSettings) -> Result<(), TTSError>;
+
     /// Skip forward in the current speech playback.
     fn skip_forward(&mut self, seconds: f32);
 
     /// Skip backward in the current speech playback.
     fn skip_backward(&mut self, seconds: f32);
 
+    /// Loop the region between `start` and `end` seconds (at normal speed); None stops looping.
+    fn set_loop(&mut self, region: Option<(f32, f32)>);
+
     /// Get playback progress as a value between 0.0 and 1.0.
     fn get_progress(&self) -> f32;
 
+    /// Get the total length of the current speech in seconds at normal speed.
+    ///
+    /// Returns 0.0 when nothing has been synthesized yet.
+    fn get_duration(&self) -> f32;
+
     /// Get frequency band amplitudes for audio visualization.
     ///
     /// Returns normalized amplitude values (0.0-1.0) for each frequency band.
     fn get_frequency_bands(&self, num_bands: usize) -> Vec<f32>;
+
+    /// Get the complete audio of the last [`play`](TTSProvider::play) call.
+    ///
+    /// Returns None until synthesis has finished (streamed texts) or when
+    /// nothing has been spoken; used to cache phrases for instant replay.
+    fn synthesized_audio(&self) -> Option<SynthesizedAudio>;
+}
+
+/// Speech synthesized off the UI thread, for the provider that prepared it to play.
+pub struct Speech {
+    /// Samples of the whole text, or of its first chunk while the rest is streamed
+    samples: Vec<f32>,
+    /// Sample rate of the samples
+    sample_rate: u32,
+    /// Synthesizes the remaining chunks into the stream once the first one plays
+    rest: Option<Box<dyn FnOnce(StreamAppender) + Send>>,
+}
+
+impl Speech {
+    /// Speech of the whole text.
+    fn complete(samples: Vec<f32>, sample_rate: u32) -> Self {
+        Self { samples, sample_rate, rest: None }
+    }
+
+    /// The audio of the whole text, None while chunks are still to be synthesized.
+    fn audio(&self) -> Option<SynthesizedAudio> {
+        self.rest.is_none().then(|| SynthesizedAudio {
+            samples: Arc::new(self.samples.clone()),
+            sample_rate: self.sample_rate,
+        })
+    }
+}
+
+/// Synthesis of a text, prepared by a provider on the UI thread and run on a worker thread.
+///
+/// Holds only what synthesis needs (chunks, clients, voice paths), never the
+/// provider itself or its audio output, which stay on the UI thread.
+pub struct Synthesis {
+    /// Tells which prepared provider the resulting [`ReadySpeech`] belongs to
+    id: u64,
+    run: Box<dyn FnOnce() -> Result<Speech, TTSError> + Send>,
+}
+
+impl Synthesis {
+    fn new(run: impl FnOnce() -> Result<Speech, TTSError> + Send + 'static) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self { id: NEXT_ID.fetch_add(1, Ordering::Relaxed), run: Box::new(run) }
+    }
+
+    /// Id of the [`ReadySpeech`] this synthesis produces.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Synthesize (blocking).
+    fn run(self) -> Result<Speech, TTSError> {
+        (self.run)()
+    }
+
+    /// Pass the speech to `inspect` once synthesized, e.g. to cache it.
+    fn inspect(self, inspect: impl FnOnce(&Speech) + Send + 'static) -> Self {
+        let Self { id, run } = self;
+        Self { id, run: Box::new(move || run().inspect(inspect)) }
+    }
+
+    /// Replay `text` from the [`audio_cache`] when `voice` read it before, synthesizing it otherwise.
+    fn or_cached(self, voice: String, text: String) -> Self {
+        let Self { id, run } = self;
+        let run = move || match audio_cache::lookup(&voice, &text) {
+            Some(audio) => {
+                debug!("Replaying reading from the audio cache");
+                Ok(Speech::complete(audio.samples.to_vec(), audio.sample_rate))
+            }
+            None => run(),
+        };
+        Self { id, run: Box::new(run) }
+    }
+}
+
+/// Speech synthesized on a worker thread, travelling back to the UI thread inside a message.
+///
+/// Cheap to clone; the speech itself can be taken out only once.
+#[derive(Clone)]
+pub struct ReadySpeech {
+    /// Id of the [`Synthesis`] it came from
+    id: u64,
+    speech: Arc<Mutex<Option<Speech>>>,
+}
+
+impl ReadySpeech {
+    /// Id of the [`Synthesis`] it came from.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Take the speech out, leaving `None` for any other clone.
+    pub fn take(&self) -> Option<Speech> {
+        self.speech.lock().ok()?.take()
+    }
+}
+
+impl fmt::Debug for ReadySpeech {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ReadySpeech({})", self.id)
+    }
+}
+
+/// Set `provider` to `speed`, `pitch` and `output`, and prepare speaking `text` with it.
+///
+/// Cheap, so it runs on the UI thread the provider lives on; run the returned
+/// synthesis with [`synthesize_in_background`] and pass its speech to the
+/// provider's [`TTSProvider::play`]. With `cache_voice` set, a reading of `text`
+/// by that voice found in the [`audio_cache`] is replayed instead of synthesizing it again.
+pub fn prepare_speech(
+    mut provider: Box<dyn TTSProvider>,
+    cache_voice: Option<String>,
+    text: &str,
+    speed: f32,
+    pitch: f32,
+    output: &OutputSettings,
+) -> Result<(Box<dyn TTSProvider>, Synthesis), TTSError> {
+    provider.set_speed(speed);
+    provider.set_pitch(pitch);
+    provider.set_output(output)?;
+    let synthesis = provider.synthesize(text)?;
+    let synthesis = match cache_voice {
+        Some(voice) => synthesis.or_cached(voice, text.to_string()),
+        None => synthesis,
+    };
+    Ok((provider, synthesis))
+}
+
+/// Run `synthesis` on a blocking worker thread, so the UI stays responsive.
+pub async fn synthesize_in_background(synthesis: Synthesis) -> Result<ReadySpeech, TTSError> {
+    let id = synthesis.id;
+    let speech = tokio::task::spawn_blocking(move || synthesis.run())
+        .await
+        .unwrap_or_else(|e| Err(TTSError::ProcessError(format!("Synthesis task failed: {e}"))))?;
+    Ok(ReadySpeech { id, speech: Arc::new(Mutex::new(Some(speech))) })
+}
+
+/// Synthesize `text` and start playing it, blocking the calling thread meanwhile.
+///
+/// For the command line self test, which has no UI thread to keep responsive.
+pub fn speak_blocking(provider: &mut dyn TTSProvider, text: &str) -> Result<(), TTSError> {
+    let speech = provider.synthesize(text)?.run()?;
+    provider.play(speech)
 }
@@ -0,0 +1,45 @@
+//! OpenTTS / Mimic 3 server provider implementation.
+//!
+//! Talks to an OpenTTS (`synesthesiam/opentts`, default port 5500) or Mimic 3
+//! (`mimic3-server`, default port 59125) server over HTTP. Both expose the same
+//! `GET /api/tts?text=&voice=` endpoint returning WAV, so one provider covers
+//! home-lab setups that already run either server.
+
+use super::http_wav::{HttpWavProvider, WavServer};
+
+/// Default URL of a locally running OpenTTS server.
+pub const DEFAULT_OPENTTS_URL: &str = "http://localhost:5500";
+
+/// Connection settings for an OpenTTS or Mimic 3 server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenTTSConfig {
+    /// Base URL of the server (e.g., "http://localhost:5500")
+    pub url: String,
+    /// Voice name (e.g., "larynx:harvard-glow_tts" or "en_US/vctk_low#p239");
+    /// empty to use the server default
+    pub voice: String,
+}
+
+impl Default for OpenTTSConfig {
+    fn default() -> Self {
+        Self {
+            url: DEFAULT_OPENTTS_URL.to_string(),
+            voice: String::new(),
+        }
+    }
+}
+
+impl WavServer for OpenTTSConfig {
+    const NAME: &'static str = "OpenTTS server";
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("voice", self.voice.trim().to_string())]
+    }
+}
+
+/// OpenTTS / Mimic 3 provider using the server's `/api/tts` endpoint.
+pub type OpenTTSProvider = HttpWavProvider<OpenTTSConfig>;
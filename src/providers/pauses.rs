@@ -0,0 +1,70 @@
+//! Pause shortening
+//!
+//! Some voices leave long silences between sentences. When enabled, every
+//! silent stretch longer than [`MAX_PAUSE_MS`] is cut down to that length,
+//! which speeds up listening without touching the speech itself. The start
+//! and end of each pause are kept so word endings and onsets aren't clipped.
+
+/// Longest pause kept, in milliseconds.
+const MAX_PAUSE_MS: u32 = 300;
+
+/// Frames quieter than this RMS count as silence.
+const SILENCE_LEVEL: f32 = 0.01;
+
+/// Length of one analysis frame, in milliseconds.
+const FRAME_MS: u32 = 10;
+
+fn is_silent(frame: &[f32]) -> bool {
+    let energy = frame.iter().map(|s| s * s).sum::<f32>() / frame.len().max(1) as f32;
+    energy.sqrt() < SILENCE_LEVEL
+}
+
+/// `samples` with every pause longer than [`MAX_PAUSE_MS`] shortened to it.
+pub fn shorten_pauses(samples: &[f32], sample_rate: u32) -> Vec<f32> {
+    let frame_len = (sample_rate * FRAME_MS / 1000).max(1) as usize;
+    let max_pause = (MAX_PAUSE_MS / FRAME_MS) as usize;
+    let frames: Vec<&[f32]> = samples.chunks(frame_len).collect();
+    let mut output = Vec::with_capacity(samples.len());
+    let mut index = 0;
+    while index < frames.len() {
+        if !is_silent(frames[index]) {
+            output.extend_from_slice(frames[index]);
+            index += 1;
+            continue;
+        }
+        let end = (index..frames.len()).find(|&i| !is_silent(frames[i])).unwrap_or(frames.len());
+        let run = &frames[index..end];
+        if run.len() <= max_pause {
+            run.iter().for_each(|frame| output.extend_from_slice(frame));
+        } else {
+            // Keep the first and last halves of the allowed pause
+            let head = max_pause / 2;
+            let tail = max_pause - head;
+            run[..head].iter().for_each(|frame| output.extend_from_slice(frame));
+            run[run.len() - tail..].iter().for_each(|frame| output.extend_from_slice(frame));
+        }
+        index = end;
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shorten_pauses() {
+        let rate = 1000; // 10 samples per frame
+        let speech = |ms: usize| vec![0.5_f32; ms];
+        let silence = |ms: usize| vec![0.0_f32; ms];
+
+        let short: Vec<f32> = [speech(100), silence(200), speech(100)].concat();
+        assert_eq!(shorten_pauses(&short, rate), short);
+
+        let long: Vec<f32> = [speech(100), silence(1500), speech(100)].concat();
+        let shortened = shorten_pauses(&long, rate);
+        assert_eq!(shortened.len(), 100 + MAX_PAUSE_MS as usize + 100);
+        assert_eq!(&shortened[..100], &speech(100)[..]);
+        assert_eq!(&shortened[shortened.len() - 100..], &speech(100)[..]);
+    }
+}
@@ -0,0 +1,178 @@
+//! Synthesized-audio cache for the phrase board and type-to-talk.
+//!
+//! Phrases are short and spoken again and again, so the audio of their first
+//! synthesis is kept in memory per voice. Later clicks replay it through a
+//! plain [`AudioPlayer`] without creating an engine, so playback is instant.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use tracing::debug;
+
+use super::audio_player::AudioPlayer;
+use super::{prepare_speech, OutputSettings, Speech, SynthesizedAudio, Synthesis, TTSError, TTSProvider};
+
+/// Cached audio keyed by (voice, phrase text).
+static CACHE: LazyLock<Mutex<HashMap<(String, String), SynthesizedAudio>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Look up the audio of `text` spoken by `voice`.
+fn lookup(voice: &str, text: &str) -> Option<SynthesizedAudio> {
+    CACHE
+        .lock()
+        .ok()?
+        .get(&(voice.to_string(), text.to_string()))
+        .cloned()
+}
+
+fn store(voice: &str, text: &str, audio: SynthesizedAudio) {
+    if let Ok(mut cache) = CACHE.lock() {
+        cache.insert((voice.to_string(), text.to_string()), audio);
+    }
+}
+
+/// Drop the cached audio of every text not in `keep`.
+pub fn retain(keep: &[String]) {
+    if let Ok(mut cache) = CACHE.lock() {
+        cache.retain(|(_, text), _| keep.contains(text));
+    }
+}
+
+/// Drop all cached audio (released when idle; phrases are synthesized again when next spoken).
+pub fn clear() {
+    if let Ok(mut cache) = CACHE.lock() {
+        *cache = HashMap::new();
+    }
+}
+
+/// Whether any phrase audio is cached.
+pub fn is_empty() -> bool {
+    CACHE.lock().map_or(true, |cache| cache.is_empty())
+}
+
+/// Prepare speaking `text` with `voice`, replaying cached audio when available.
+///
+/// On a cache miss the provider from `create` synthesizes the phrase and its
+/// audio is cached once complete. `voice` must identify everything that
+/// changes how the phrase sounds (backend, voice, server). Returns the
+/// provider, which plays the speech the synthesis produces.
+pub fn prepare_phrase<F>(
+    create: F,
+    voice: String,
+    text: &str,
+    speed: f32,
+    pitch: f32,
+    output: &OutputSettings,
+) -> Result<(Box<dyn TTSProvider>, Synthesis), TTSError>
+where
+    F: FnOnce() -> Result<Box<dyn TTSProvider>, TTSError>,
+{
+    let cached = lookup(&voice, text);
+    debug!(hit = cached.is_some(), %voice, "Phrase cache lookup");
+    let hit = cached.is_some();
+    let provider = match cached {
+        Some(audio) => Box::new(CachedAudioProvider::new(audio)?) as Box<dyn TTSProvider>,
+        None => create()?,
+    };
+    let (provider, mut synthesis) = prepare_speech(provider, None, text, speed, pitch, output)?;
+    if !hit {
+        let text = text.to_string();
+        synthesis = synthesis.inspect(move |speech| {
+            if let Some(audio) = speech.audio() {
+                store(&voice, &text, audio);
+            }
+        });
+    }
+    Ok((provider, synthesis))
+}
+
+/// Provider that plays previously synthesized audio.
+pub(super) struct CachedAudioProvider {
+    player: AudioPlayer,
+    audio: SynthesizedAudio,
+}
+
+impl CachedAudioProvider {
+    pub(super) fn new(audio: SynthesizedAudio) -> Result<Self, TTSError> {
+        Ok(Self {
+            player: AudioPlayer::new(audio.sample_rate)?,
+            audio,
+        })
+    }
+}
+
+impl TTSProvider for CachedAudioProvider {
+    fn synthesize(&mut self, _text: &str) -> Result<Synthesis, TTSError> {
+        self.player.stop()?;
+        let audio = self.audio.clone();
+        Ok(Synthesis::new(move || Ok(Speech::complete(audio.samples.to_vec(), audio.sample_rate))))
+    }
+
+    fn play(&mut self, speech: Speech) -> Result<(), TTSError> {
+        self.player.play_speech(speech)
+    }
+
+    fn pause(&mut self) -> Result<(), TTSError> {
+        self.player.pause()
+    }
+
+    fn resume(&mut self) -> Result<(), TTSError> {
+        self.player.resume()
+    }
+
+    fn stop(&mut self) -> Result<(), TTSError> {
+        self.player.stop()
+    }
+
+    fn is_playing(&self) -> bool {
+        self.player.is_playing()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.player.is_paused()
+    }
+
+    fn is_buffering(&self) -> bool {
+        self.player.is_buffering()
+    }
+
+    fn set_speed(&mut self, speed: f32) {
+        self.player.set_speed(speed);
+    }
+
+    fn set_pitch(&mut self, semitones: f32) {
+        self.player.set_pitch(semitones);
+    }
+
+    fn set_output(&mut self, output: &OutputSettings) -> Result<(), TTSError> {
+        self.player.set_output(output)
+    }
+
+    fn skip_forward(&mut self, seconds: f32) {
+        self.player.skip_forward(seconds);
+    }
+
+    fn skip_backward(&mut self, seconds: f32) {
+        self.player.skip_backward(seconds);
+    }
+
+    fn set_loop(&mut self, region: Option<(f32, f32)>) {
+        self.player.set_loop(region);
+    }
+
+    fn get_progress(&self) -> f32 {
+        self.player.get_progress()
+    }
+
+    fn get_duration(&self) -> f32 {
+        self.player.get_duration()
+    }
+
+    fn get_frequency_bands(&self, num_bands: usize) -> Vec<f32> {
+        self.player.get_frequency_bands(num_bands)
+    }
+
+    fn synthesized_audio(&self) -> Option<SynthesizedAudio> {
+        Some(self.audio.clone())
+    }
+}
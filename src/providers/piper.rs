@@ -1,10 +1,17 @@
 //! Piper TTS provider implementation.
 //!
 //! Uses the Piper binary to synthesize speech from text and plays it using rodio.
+//! With the `piper-onnx` feature the voice is run in-process through ONNX
+//! Runtime instead, falling back to the binary if the voice can't be loaded.
 
+use std::collections::BTreeMap;
 use std::env;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+#[cfg(target_os = "windows")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "piper-onnx")]
+use std::sync::{Arc, Mutex};
 
 #[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
@@ -12,7 +19,19 @@ use std::os::windows::process::CommandExt;
 use tracing::{debug, error, info, warn};
 
 use super::audio_player::AudioPlayer;
-use super::{TTSError, TTSProvider};
+use super::chunking::{language_chunks, sentence_chunks};
+#[cfg(feature = "piper-onnx")]
+use super::piper_onnx::PiperOnnxVoice;
+use super::synthesis_load::SynthesisLoad;
+use super::time_stretch::resample_rate;
+use super::{markup, OutputSettings, Speech, SynthesizedAudio, Synthesis, TTSError, TTSProvider};
+use crate::system::{detect_language, voice_language};
+
+/// Sample rate assumed for voices whose config can't be read.
+const DEFAULT_SAMPLE_RATE: u32 = 22050;
+
+/// Synthesizes one chunk of text into samples.
+type Synthesizer = Box<dyn Fn(&str) -> Result<Vec<f32>, TTSError> + Send>;
 
 /// Piper TTS provider using local ONNX models.
 pub struct PiperTTSProvider {
@@ -20,8 +39,17 @@ pub struct PiperTTSProvider {
     piper_bin: PathBuf,
     /// Path to the model file (without .onnx extension)
     model_path: PathBuf,
+    /// Sample rate of the main voice, which the audio is played at
+    sample_rate: u32,
     /// Shared audio playback engine
     player: AudioPlayer,
+    /// Voices for passages in other languages (language code, model path, sample rate)
+    language_models: Vec<(String, PathBuf, u32)>,
+    /// Priority (and thread limit) synthesis runs with
+    load: SynthesisLoad,
+    /// In-process voice, used instead of the binary when loaded
+    #[cfg(feature = "piper-onnx")]
+    onnx_voice: Option<Arc<Mutex<PiperOnnxVoice>>>,
 }
 
 impl PiperTTSProvider {
@@ -31,8 +59,16 @@ impl PiperTTSProvider {
     /// 1. Project root: `./venv/bin/piper` (development)
     /// 2. User installation: `~/.local/share/insight-reader/venv/bin/piper` (XDG Base Directory)
     /// 3. System PATH
-    pub fn new() -> Result<Self, TTSError> {
-        Self::with_config(None, None)
+    ///
+    /// Synthesis runs with the priority of `load`.
+    pub fn new(load: SynthesisLoad) -> Result<Self, TTSError> {
+        Self::with_config(None, None, load)
+    }
+
+    /// Create a Piper TTS provider reading with `voice_key` (e.g. "pt_BR-faber-medium")
+    /// instead of the selected voice.
+    pub fn with_voice(voice_key: &str, load: SynthesisLoad) -> Result<Self, TTSError> {
+        Self::with_config(None, Some(Self::locate_model(voice_key)), load)
     }
 
     /// Create a new Piper TTS provider with custom paths.
@@ -40,24 +76,19 @@ impl PiperTTSProvider {
     /// # Arguments
     /// * `piper_bin` - Path to piper binary (None = auto-detect)
     /// * `model_path` - Path to model file without extension (None = auto-detect)
+    /// * `load` - Priority (and thread limit) synthesis runs with
     pub fn with_config(
         piper_bin: Option<PathBuf>,
         model_path: Option<PathBuf>,
+        load: SynthesisLoad,
     ) -> Result<Self, TTSError> {
         let piper_bin = piper_bin.unwrap_or_else(Self::find_piper_binary);
         let model_path = model_path.unwrap_or_else(Self::find_model);
 
         info!("Initializing Piper TTS provider");
-        debug!(?piper_bin, ?model_path, "Piper configuration");
+        debug!(?piper_bin, ?model_path, ?load, "Piper configuration");
 
-        // Validate that the binary and model actually exist before continuing.
-        if !piper_bin.is_file() {
-            error!(?piper_bin, "Piper binary not found");
-            return Err(TTSError::ProcessError(format!(
-                "Piper binary not found at {}",
-                piper_bin.display()
-            )));
-        }
+        // Validate that the model and binary actually exist before continuing.
         if !model_with_extension(&model_path).is_file() {
             error!(?model_path, "Piper model file (.onnx) not found");
             return Err(TTSError::ProcessError(format!(
@@ -66,16 +97,133 @@ impl PiperTTSProvider {
             )));
         }
 
-        // Piper uses 22050 Hz sample rate
-        let player = AudioPlayer::new(22050)?;
+        #[cfg(feature = "piper-onnx")]
+        let onnx_voice = match PiperOnnxVoice::load(&model_path, load.threads()) {
+            Ok(voice) => Some(Arc::new(Mutex::new(voice))),
+            Err(e) => {
+                warn!(error = %e, "In-process Piper voice unavailable, falling back to piper binary");
+                None
+            }
+        };
+        #[cfg(feature = "piper-onnx")]
+        let needs_binary = onnx_voice.is_none();
+        #[cfg(not(feature = "piper-onnx"))]
+        let needs_binary = true;
+
+        if needs_binary && !piper_bin.is_file() {
+            error!(?piper_bin, "Piper binary not found");
+            return Err(TTSError::ProcessError(format!(
+                "Piper binary not found at {}",
+                piper_bin.display()
+            )));
+        }
+
+        // Voices come in 16, 22.05 and 44.1 kHz; the rate is in the voice's config
+        let sample_rate = voice_sample_rate(&model_path);
+        let player = AudioPlayer::new(sample_rate)?;
 
         Ok(Self {
             piper_bin,
             model_path,
+            sample_rate,
             player,
+            language_models: Vec::new(),
+            load,
+            #[cfg(feature = "piper-onnx")]
+            onnx_voice,
         })
     }
 
+    /// Read passages in other languages with the given voices.
+    ///
+    /// `voices` maps language codes ("fr") to voice keys ("fr_FR-siwis-medium").
+    /// Voices that aren't installed, and the language of the main voice, are skipped.
+    pub fn set_language_voices(&mut self, voices: &BTreeMap<String, String>) {
+        let main_language = self
+            .model_path
+            .file_name()
+            .map(|name| voice_language(&name.to_string_lossy()).to_string());
+        self.language_models = voices
+            .iter()
+            .filter(|(language, _)| Some(*language) != main_language.as_ref())
+            .filter_map(|(language, voice_key)| {
+                let model_path = Self::locate_model(voice_key);
+                if model_with_extension(&model_path).is_file() {
+                    let sample_rate = voice_sample_rate(&model_path);
+                    Some((language.clone(), model_path, sample_rate))
+                } else {
+                    warn!(voice = %voice_key, "Voice for mixed-language reading not installed, skipping it");
+                    None
+                }
+            })
+            .collect();
+        debug!(languages = self.language_models.len(), "Mixed-language voices configured");
+    }
+
+    /// Synthesizer for the main voice, in-process when the ONNX voice is loaded.
+    fn main_voice(&mut self) -> Synthesizer {
+        #[cfg(feature = "piper-onnx")]
+        if let Some(voice) = &self.onnx_voice {
+            let sample_rate = voice.lock().unwrap().sample_rate();
+            self.player.set_sample_rate(sample_rate);
+            let voice = Arc::clone(voice);
+            return Box::new(move |chunk| {
+                let samples = voice.lock().unwrap().synthesize(chunk)?;
+                info!(
+                    samples = samples.len(),
+                    duration_sec = format!("{:.1}", samples.len() as f32 / sample_rate as f32),
+                    "Piper: audio synthesized in-process"
+                );
+                Ok(samples)
+            });
+        }
+
+        self.player.set_sample_rate(self.sample_rate);
+        let piper_bin = self.piper_bin.clone();
+        let model_path = self.model_path.clone();
+        let sample_rate = self.sample_rate;
+        let load = self.load;
+        Box::new(move |chunk| {
+            let audio_data = run_piper(&piper_bin, &model_path, chunk, load)?;
+            let duration_sec = audio_data.len() as f32 / sample_rate as f32;
+            info!(
+                samples = audio_data.len(),
+                duration_sec = format!("{:.1}", duration_sec),
+                "Piper: audio generated"
+            );
+            Ok(audio_data)
+        })
+    }
+
+    /// Split `text` into chunks, each with the model and sample rate of the
+    /// voice to read it (None = main voice).
+    fn voice_chunks(&self, text: &str) -> Vec<(Option<(PathBuf, u32)>, String)> {
+        if self.language_models.is_empty() {
+            return sentence_chunks(text).into_iter().map(|chunk| (None, chunk)).collect();
+        }
+
+        let main_language = self
+            .model_path
+            .file_name()
+            .map(|name| voice_language(&name.to_string_lossy()).to_string())
+            .unwrap_or_default();
+        let mut candidates: Vec<&str> = self.language_models.iter().map(|(language, _, _)| language.as_str()).collect();
+        candidates.push(&main_language);
+
+        language_chunks(text, |sentence| detect_language(sentence, &candidates))
+            .into_iter()
+            .map(|(language, chunk)| {
+                let model = language.and_then(|language| {
+                    self.language_models
+                        .iter()
+                        .find(|(code, _, _)| code == language)
+                        .map(|(_, model_path, sample_rate)| (model_path.clone(), *sample_rate))
+                });
+                (model, chunk)
+            })
+            .collect()
+    }
+
     /// On macOS, check Linux-style path (~/.local/share/insight-reader) for compatibility.
     #[cfg(target_os = "macos")]
     fn check_linux_style_path(relative_path: &str) -> Option<PathBuf> {
@@ -144,6 +292,14 @@ impl PiperTTSProvider {
             }
         }
 
+        // Check a system-wide install provisioned by an administrator
+        let system_piper = crate::storage::find_system_file(&Path::new("venv").join(VENV_BIN_DIR).join(PIPER_BIN_NAME))
+            .or_else(|| crate::storage::find_system_file(&Path::new("bin").join(PIPER_BIN_NAME)));
+        if let Some(system_piper) = system_piper {
+            debug!(path = %system_piper.display(), "Using system-wide piper binary");
+            return system_piper;
+        }
+
         // Check system PATH
         // On Windows use 'where', on Unix use 'which'
         #[cfg(target_os = "windows")]
@@ -208,10 +364,14 @@ impl PiperTTSProvider {
         // Try to load selected voice from config, fallback to default
         let model_name = crate::config::load_selected_voice()
             .unwrap_or_else(|| "en_US-lessac-medium".to_string());
+        Self::locate_model(&model_name)
+    }
 
+    /// Find the model file for voice `model_name` in standard locations.
+    fn locate_model(model_name: &str) -> PathBuf {
         // Check project models directory first (for development)
         if let Ok(current_dir) = env::current_dir() {
-            let project_model = current_dir.join("models").join(&model_name);
+            let project_model = current_dir.join("models").join(model_name);
             if project_model.with_extension("onnx").exists() {
                 debug!(
                     path = %project_model.with_extension("onnx").display(),
@@ -221,11 +381,21 @@ impl PiperTTSProvider {
             }
         }
 
+        // Check the configured storage folder (or the default models folder)
+        let user_model = crate::storage::models_dir().join(model_name);
+        if user_model.with_extension("onnx").exists() {
+            debug!(
+                path = %user_model.with_extension("onnx").display(),
+                "Using user-installed Piper model (storage folder)"
+            );
+            return user_model;
+        }
+
         // Check user installation
         // On Windows: %LOCALAPPDATA%\insight-reader\models
         // On Unix: ~/.local/share/insight-reader/models (via data_dir)
         if let Some(data_dir) = dirs::data_local_dir() {
-            let user_model = data_dir.join("insight-reader").join("models").join(&model_name);
+            let user_model = data_dir.join("insight-reader").join("models").join(model_name);
             if user_model.with_extension("onnx").exists() {
                 debug!(
                     path = %user_model.with_extension("onnx").display(),
@@ -237,7 +407,7 @@ impl PiperTTSProvider {
         
         // Also check data_dir (XDG Base Directory standard on Unix)
         if let Some(data_dir) = dirs::data_dir() {
-            let user_model = data_dir.join("insight-reader").join("models").join(&model_name);
+            let user_model = data_dir.join("insight-reader").join("models").join(model_name);
             if user_model.with_extension("onnx").exists() {
                 debug!(
                     path = %user_model.with_extension("onnx").display(),
@@ -262,16 +432,20 @@ impl PiperTTSProvider {
             }
         }
 
-        // Fallback to user location (will fail validation)
-        #[cfg(target_os = "windows")]
-        let fallback_base = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("C:\\Temp"));
-        #[cfg(not(target_os = "windows"))]
-        let fallback_base = dirs::data_dir().unwrap_or_else(|| PathBuf::from("/tmp"));
-        
-        let fallback = fallback_base
-            .join("insight-reader")
-            .join("models")
-            .join(&model_name);
+        // Check the system-wide install (read-only, provisioned by an administrator)
+        for models_dir in crate::storage::system_models_dirs() {
+            let system_model = models_dir.join(model_name);
+            if system_model.with_extension("onnx").exists() {
+                debug!(
+                    path = %system_model.with_extension("onnx").display(),
+                    "Using system-wide Piper model"
+                );
+                return system_model;
+            }
+        }
+
+        // Fallback to the storage folder (will fail validation)
+        let fallback = crate::storage::models_dir().join(model_name);
         warn!(
             path = %fallback.with_extension("onnx").display(),
             "Piper model not found in known locations, using fallback path"
@@ -285,236 +459,294 @@ fn model_with_extension(path: &Path) -> PathBuf {
     path.with_extension("onnx")
 }
 
-impl TTSProvider for PiperTTSProvider {
-    fn speak(&mut self, text: &str) -> Result<(), TTSError> {
-        // Validate input text
-        let text = text.trim();
-        if text.is_empty() {
-            warn!("Empty text provided to piper, skipping synthesis");
-            return Err(TTSError::ProcessError(
-                "Cannot synthesize empty text".into(),
-            ));
-        }
-
-        debug!(
-            chars = text.len(),
-            text_preview = %text.chars().take(50).collect::<String>(),
-            "Piper: synthesizing speech"
-        );
+/// Sample rate of the voice at `model_path`, from its `.onnx.json` config.
+fn voice_sample_rate(model_path: &Path) -> u32 {
+    #[derive(serde::Deserialize)]
+    struct VoiceConfig {
+        audio: AudioConfig,
+    }
+    #[derive(serde::Deserialize)]
+    struct AudioConfig {
+        sample_rate: u32,
+    }
 
-        // Stop any current playback
-        self.player.stop()?;
+    let config_path = PathBuf::from(format!("{}.json", model_with_extension(model_path).display()));
+    let sample_rate = std::fs::read_to_string(&config_path)
+        .map_err(|e| e.to_string())
+        .and_then(|data| serde_json::from_str::<VoiceConfig>(&data).map_err(|e| e.to_string()))
+        .map(|config| config.audio.sample_rate)
+        .ok()
+        .filter(|&sample_rate| sample_rate > 0);
+    match sample_rate {
+        Some(sample_rate) => {
+            debug!(path = %config_path.display(), sample_rate, "Read voice sample rate");
+            sample_rate
+        }
+        None => {
+            warn!(
+                path = %config_path.display(),
+                sample_rate = DEFAULT_SAMPLE_RATE,
+                "Voice config unreadable, assuming the default sample rate"
+            );
+            DEFAULT_SAMPLE_RATE
+        }
+    }
+}
 
-        // Build command for logging
-        let model_arg = self.model_path.to_str().unwrap_or("");
-        debug!(
-            piper_bin = %self.piper_bin.display(),
-            model_path = %model_arg,
-            "Executing piper command"
-        );
+/// Run the piper binary on `text` and return the synthesized samples.
+fn run_piper(piper_bin: &Path, model_path: &Path, text: &str, load: SynthesisLoad) -> Result<Vec<f32>, TTSError> {
+    // Build command for logging
+    let model_arg = model_path.to_str().unwrap_or("");
+    debug!(
+        piper_bin = %piper_bin.display(),
+        model_path = %model_arg,
+        "Executing piper command"
+    );
+
+    // On Windows, piper has issues with stdout streaming, so we use a temp file
+    // On Unix, we can stream directly to stdout for better performance
+    #[cfg(target_os = "windows")]
+    let audio_data = {
+        use std::fs;
+        use std::io::Write;
+        
+        // Create temp file for output
+        let temp_dir = env::temp_dir();
+        // Unique per call: a streaming thread may still be running piper
+        // for a previous text when the next one starts
+        static NEXT_TEMP_ID: AtomicUsize = AtomicUsize::new(0);
+        let temp_id = NEXT_TEMP_ID.fetch_add(1, Ordering::Relaxed);
+        let temp_file = temp_dir.join(format!("insight-reader-piper-output-{temp_id}.wav"));
+        let temp_file_str = temp_file.to_string_lossy().to_string();
+        
+        debug!(temp_file = %temp_file_str, "Using temp file for piper output (Windows)");
+        
+        // Run piper with temp file output
+        // Use CREATE_NO_WINDOW flag to prevent console window from appearing
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        let mut child = load.command(piper_bin)
+            .args([
+                "--model",
+                model_arg,
+                "--output_file",
+                &temp_file_str,
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .creation_flags(CREATE_NO_WINDOW | load.priority_class())
+            .spawn()
+            .map_err(|e| {
+                error!(
+                    error = %e,
+                    piper_bin = %piper_bin.display(),
+                    "Failed to start piper process"
+                );
+                TTSError::ProcessError(format!("Failed to start piper: {e}"))
+            })?;
 
-        // On Windows, piper has issues with stdout streaming, so we use a temp file
-        // On Unix, we can stream directly to stdout for better performance
-        #[cfg(target_os = "windows")]
-        let audio_data = {
-            use std::fs;
-            use std::io::Write;
-            
-            // Create temp file for output
-            let temp_dir = env::temp_dir();
-            let temp_file = temp_dir.join("insight-reader-piper-output.wav");
-            let temp_file_str = temp_file.to_string_lossy().to_string();
-            
-            debug!(temp_file = %temp_file_str, "Using temp file for piper output (Windows)");
-            
-            // Run piper with temp file output
-            // Use CREATE_NO_WINDOW flag to prevent console window from appearing
-            const CREATE_NO_WINDOW: u32 = 0x08000000;
-            let mut child = Command::new(&self.piper_bin)
-                .args([
-                    "--model",
-                    model_arg,
-                    "--output_file",
-                    &temp_file_str,
-                ])
-                .stdin(Stdio::piped())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .creation_flags(CREATE_NO_WINDOW)
-                .spawn()
+        // Send text to piper
+        {
+            let stdin = child
+                .stdin
+                .as_mut()
+                .ok_or_else(|| TTSError::ProcessError("Failed to open piper stdin".into()))?;
+            stdin
+                .write_all(text.as_bytes())
                 .map_err(|e| {
-                    error!(
-                        error = %e,
-                        piper_bin = %self.piper_bin.display(),
-                        "Failed to start piper process"
-                    );
-                    TTSError::ProcessError(format!("Failed to start piper: {e}"))
+                    error!(error = %e, text_bytes = text.len(), "Failed to write text to piper stdin");
+                    TTSError::ProcessError(format!("Failed to write to piper: {e}"))
                 })?;
+            debug!(text_bytes = text.len(), "Text written to piper stdin");
+        }
 
-            // Send text to piper
-            {
-                let stdin = child
-                    .stdin
-                    .as_mut()
-                    .ok_or_else(|| TTSError::ProcessError("Failed to open piper stdin".into()))?;
-                stdin
-                    .write_all(text.as_bytes())
-                    .map_err(|e| {
-                        error!(error = %e, text_bytes = text.len(), "Failed to write text to piper stdin");
-                        TTSError::ProcessError(format!("Failed to write to piper: {e}"))
-                    })?;
-                debug!(text_bytes = text.len(), "Text written to piper stdin");
-            }
-
-            // Wait for completion
-            let output = child
-                .wait_with_output()
-                .map_err(|e| {
-                    error!(error = %e, "Piper process wait failed");
-                    TTSError::ProcessError(format!("Piper process failed: {e}"))
-                })?;
+        // Wait for completion
+        let output = child
+            .wait_with_output()
+            .map_err(|e| {
+                error!(error = %e, "Piper process wait failed");
+                TTSError::ProcessError(format!("Piper process failed: {e}"))
+            })?;
 
-            let exit_code = output.status.code();
-            let stderr = String::from_utf8_lossy(&output.stderr);
-
-            if !output.status.success() {
-                error!(exit_code = ?exit_code, stderr = %stderr.trim(), "Piper process failed");
-                // Clean up temp file on error
-                let _ = fs::remove_file(&temp_file);
-                return Err(TTSError::ProcessError(format!(
-                    "Piper failed with code {:?}: {}",
-                    exit_code,
-                    stderr.trim()
-                )));
-            }
+        let exit_code = output.status.code();
+        let stderr = String::from_utf8_lossy(&output.stderr);
 
-            // Read the WAV file and extract raw PCM data
-            let wav_data = fs::read(&temp_file).map_err(|e| {
-                error!(error = %e, path = %temp_file_str, "Failed to read piper output file");
-                TTSError::ProcessError(format!("Failed to read piper output: {e}"))
-            })?;
-            
-            // Clean up temp file
+        if !output.status.success() {
+            error!(exit_code = ?exit_code, stderr = %stderr.trim(), "Piper process failed");
+            // Clean up temp file on error
             let _ = fs::remove_file(&temp_file);
-            
-            if wav_data.is_empty() {
-                error!("Piper produced empty output file");
-                return Err(TTSError::ProcessError("No audio data generated by piper".into()));
-            }
-            
-            // WAV files have a 44-byte header, skip it to get raw PCM
-            // Verify it's a valid WAV file
-            if wav_data.len() < 44 || &wav_data[0..4] != b"RIFF" {
-                error!(bytes = wav_data.len(), "Invalid WAV file format from piper");
-                return Err(TTSError::ProcessError("Invalid audio format from piper".into()));
-            }
-            
-            let pcm_data = &wav_data[44..];
-            AudioPlayer::pcm_to_f32(pcm_data)
-        };
+            return Err(TTSError::ProcessError(format!(
+                "Piper failed with code {:?}: {}",
+                exit_code,
+                stderr.trim()
+            )));
+        }
+
+        // Read the WAV file and extract raw PCM data
+        let wav_data = fs::read(&temp_file).map_err(|e| {
+            error!(error = %e, path = %temp_file_str, "Failed to read piper output file");
+            TTSError::ProcessError(format!("Failed to read piper output: {e}"))
+        })?;
         
-        #[cfg(not(target_os = "windows"))]
-        let audio_data = {
-            use std::io::Write;
-            
-            // Run piper to generate audio (stream to stdout)
-            let mut child = Command::new(&self.piper_bin)
-                .args([
-                    "--model",
-                    model_arg,
-                    "--output_file",
-                    "-",
-                ])
-                .stdin(Stdio::piped())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
+        // Clean up temp file
+        let _ = fs::remove_file(&temp_file);
+        
+        if wav_data.is_empty() {
+            error!("Piper produced empty output file");
+            return Err(TTSError::ProcessError("No audio data generated by piper".into()));
+        }
+        
+        // WAV files have a 44-byte header, skip it to get raw PCM
+        // Verify it's a valid WAV file
+        if wav_data.len() < 44 || &wav_data[0..4] != b"RIFF" {
+            error!(bytes = wav_data.len(), "Invalid WAV file format from piper");
+            return Err(TTSError::ProcessError("Invalid audio format from piper".into()));
+        }
+        
+        let pcm_data = &wav_data[44..];
+        AudioPlayer::pcm_to_f32(pcm_data)
+    };
+    
+    #[cfg(not(target_os = "windows"))]
+    let audio_data = {
+        use std::io::Write;
+        
+        // Run piper to generate audio (stream to stdout)
+        let mut child = load.command(piper_bin)
+            .args([
+                "--model",
+                model_arg,
+                "--output_file",
+                "-",
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                error!(
+                    error = %e,
+                    piper_bin = %piper_bin.display(),
+                    "Failed to start piper process"
+                );
+                TTSError::ProcessError(format!("Failed to start piper: {e}"))
+            })?;
+
+        // Send text to piper
+        {
+            let stdin = child
+                .stdin
+                .as_mut()
+                .ok_or_else(|| TTSError::ProcessError("Failed to open piper stdin".into()))?;
+            stdin
+                .write_all(text.as_bytes())
                 .map_err(|e| {
                     error!(
                         error = %e,
-                        piper_bin = %self.piper_bin.display(),
-                        "Failed to start piper process"
+                        text_bytes = text.len(),
+                        "Failed to write text to piper stdin"
                     );
-                    TTSError::ProcessError(format!("Failed to start piper: {e}"))
+                    TTSError::ProcessError(format!("Failed to write to piper: {e}"))
                 })?;
+            debug!(text_bytes = text.len(), "Text written to piper stdin");
+        }
 
-            // Send text to piper
-            {
-                let stdin = child
-                    .stdin
-                    .as_mut()
-                    .ok_or_else(|| TTSError::ProcessError("Failed to open piper stdin".into()))?;
-                stdin
-                    .write_all(text.as_bytes())
-                    .map_err(|e| {
-                        error!(
-                            error = %e,
-                            text_bytes = text.len(),
-                            "Failed to write text to piper stdin"
-                        );
-                        TTSError::ProcessError(format!("Failed to write to piper: {e}"))
-                    })?;
-                debug!(text_bytes = text.len(), "Text written to piper stdin");
-            }
+        // Wait for completion and get output
+        let output = child
+            .wait_with_output()
+            .map_err(|e| {
+                error!(error = %e, "Piper process wait failed");
+                TTSError::ProcessError(format!("Piper process failed: {e}"))
+            })?;
 
-            // Wait for completion and get output
-            let output = child
-                .wait_with_output()
-                .map_err(|e| {
-                    error!(error = %e, "Piper process wait failed");
-                    TTSError::ProcessError(format!("Piper process failed: {e}"))
-                })?;
+        let exit_code = output.status.code();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout_len = output.stdout.len();
+
+        if !output.status.success() {
+            error!(
+                exit_code = ?exit_code,
+                stderr = %stderr.trim(),
+                stdout_bytes = stdout_len,
+                "Piper process failed"
+            );
+            return Err(TTSError::ProcessError(format!(
+                "Piper failed with code {:?}: {}",
+                exit_code,
+                stderr.trim()
+            )));
+        }
 
-            let exit_code = output.status.code();
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            let stdout_len = output.stdout.len();
+        if output.stdout.is_empty() {
+            // Log detailed diagnostics when no audio is generated
+            error!(
+                exit_code = ?exit_code,
+                stderr = %stderr.trim(),
+                stdout_bytes = 0,
+                piper_bin = %piper_bin.display(),
+                model_path = %model_arg,
+                text_preview = %text.chars().take(100).collect::<String>(),
+                text_bytes = text.len(),
+                "Piper exited successfully but produced no audio output"
+            );
+            let error_msg = if stderr.trim().is_empty() {
+                "No audio data generated by piper".to_string()
+            } else {
+                format!("No audio data generated by piper. stderr: {}", stderr.trim())
+            };
+            return Err(TTSError::ProcessError(error_msg));
+        }
 
-            if !output.status.success() {
-                error!(
-                    exit_code = ?exit_code,
-                    stderr = %stderr.trim(),
-                    stdout_bytes = stdout_len,
-                    "Piper process failed"
-                );
-                return Err(TTSError::ProcessError(format!(
-                    "Piper failed with code {:?}: {}",
-                    exit_code,
-                    stderr.trim()
-                )));
-            }
+        // Convert PCM to f32
+        AudioPlayer::pcm_to_f32(&output.stdout)
+    };
 
-            if output.stdout.is_empty() {
-                // Log detailed diagnostics when no audio is generated
-                error!(
-                    exit_code = ?exit_code,
-                    stderr = %stderr.trim(),
-                    stdout_bytes = 0,
-                    piper_bin = %self.piper_bin.display(),
-                    model_path = %model_arg,
-                    text_preview = %text.chars().take(100).collect::<String>(),
-                    text_bytes = text.len(),
-                    "Piper exited successfully but produced no audio output"
-                );
-                let error_msg = if stderr.trim().is_empty() {
-                    "No audio data generated by piper".to_string()
-                } else {
-                    format!("No audio data generated by piper. stderr: {}", stderr.trim())
-                };
-                return Err(TTSError::ProcessError(error_msg));
-            }
+    Ok(audio_data)
+}
 
-            // Convert PCM to f32
-            AudioPlayer::pcm_to_f32(&output.stdout)
-        };
+impl TTSProvider for PiperTTSProvider {
+    fn synthesize(&mut self, text: &str) -> Result<Synthesis, TTSError> {
+        // Validate input text
+        let text = markup::to_plain_text(text);
+        let text = text.trim();
+        if text.is_empty() {
+            warn!("Empty text provided to piper, skipping synthesis");
+            return Err(TTSError::ProcessError(
+                "Cannot synthesize empty text".into(),
+            ));
+        }
 
-        let duration_sec = audio_data.len() as f32 / 22050.0;
-        info!(
-            samples = audio_data.len(),
-            duration_sec = format!("{:.1}", duration_sec),
-            "Piper: audio generated"
+        debug!(
+            chars = text.len(),
+            text_preview = %text.chars().take(50).collect::<String>(),
+            "Piper: synthesizing speech"
         );
 
-        self.player.play_audio(audio_data)
+        // Stop any current playback
+        self.player.stop()?;
+
+        let chunks = self.voice_chunks(text);
+        let main_voice = self.main_voice();
+        let piper_bin = self.piper_bin.clone();
+        let load = self.load;
+        // The in-process voice may report a different rate than its config
+        let played_rate = self.player.sample_rate();
+        self.player.synthesize_chunks(chunks, move |(model, chunk)| match model {
+            // Other-language voices run through the binary, resampled to the
+            // main voice's rate; if that fails the passage is read by the main
+            // voice rather than skipped
+            Some((model_path, sample_rate)) => run_piper(&piper_bin, model_path, chunk, load)
+                .map(|samples| resample_rate(&samples, *sample_rate, played_rate))
+                .or_else(|e| {
+                    warn!(error = %e, model = ?model_path, "Language voice failed, using main voice");
+                    main_voice(chunk)
+                }),
+            None => main_voice(chunk),
+        })
+    }
+
+    fn play(&mut self, speech: Speech) -> Result<(), TTSError> {
+        self.player.play_speech(speech)
     }
 
     fn pause(&mut self) -> Result<(), TTSError> {
@@ -537,6 +769,22 @@ impl TTSProvider for PiperTTSProvider {
         self.player.is_paused()
     }
 
+    fn is_buffering(&self) -> bool {
+        self.player.is_buffering()
+    }
+
+    fn set_speed(&mut self, speed: f32) {
+        self.player.set_speed(speed);
+    }
+
+    fn set_pitch(&mut self, semitones: f32) {
+        self.player.set_pitch(semitones);
+    }
+
+    fn set_output(&mut self, output: &OutputSettings) -> Result<(), TTSError> {
+        self.player.set_output(output)
+    }
+
     fn skip_forward(&mut self, seconds: f32) {
         self.player.skip_forward(seconds);
     }
@@ -545,11 +793,23 @@ impl TTSProvider for PiperTTSProvider {
         self.player.skip_backward(seconds);
     }
 
+    fn set_loop(&mut self, region: Option<(f32, f32)>) {
+        self.player.set_loop(region);
+    }
+
     fn get_progress(&self) -> f32 {
         self.player.get_progress()
     }
 
+    fn get_duration(&self) -> f32 {
+        self.player.get_duration()
+    }
+
     fn get_frequency_bands(&self, num_bands: usize) -> Vec<f32> {
         self.player.get_frequency_bands(num_bands)
     }
+
+    fn synthesized_audio(&self) -> Option<SynthesizedAudio> {
+        self.player.synthesized_audio()
+    }
 }
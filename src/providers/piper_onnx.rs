@@ -0,0 +1,326 @@
+//! In-process Piper inference via ONNX Runtime.
+//!
+//! Loads a Piper voice (`.onnx` model + `.onnx.json` config) directly with the
+//! `ort` crate instead of spawning the `piper` binary for every utterance, which
+//! removes the Python/venv dependency and the per-utterance startup cost.
+//!
+//! Phonemization still needs eSpeak NG for `espeak` voices; the `espeak-ng`
+//! command (a small native package on every platform) is used for that.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+use ort::session::Session;
+use ort::value::Tensor;
+use tracing::{debug, error, info, warn};
+
+use super::TTSError;
+
+/// Piper's special phoneme symbols.
+const PAD: &str = "_";
+const BOS: &str = "^";
+const EOS: &str = "$";
+
+/// Sentence-ending punctuation kept as phonemes so the model pauses naturally.
+const SENTENCE_TERMINATORS: [char; 5] = ['.', '!', '?', ';', ':'];
+
+#[derive(Debug, serde::Deserialize)]
+struct VoiceConfig {
+    audio: AudioConfig,
+    #[serde(default)]
+    espeak: Option<EspeakConfig>,
+    #[serde(default)]
+    inference: InferenceConfig,
+    /// "espeak" (default) or "text"
+    #[serde(default)]
+    phoneme_type: Option<String>,
+    phoneme_id_map: HashMap<String, Vec<i64>>,
+    #[serde(default)]
+    num_speakers: u32,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct AudioConfig {
+    sample_rate: u32,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct EspeakConfig {
+    voice: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct InferenceConfig {
+    #[serde(default = "default_noise_scale")]
+    noise_scale: f32,
+    #[serde(default = "default_length_scale")]
+    length_scale: f32,
+    #[serde(default = "default_noise_w")]
+    noise_w: f32,
+}
+
+fn default_noise_scale() -> f32 {
+    0.667
+}
+
+fn default_length_scale() -> f32 {
+    1.0
+}
+
+fn default_noise_w() -> f32 {
+    0.8
+}
+
+impl Default for InferenceConfig {
+    fn default() -> Self {
+        Self {
+            noise_scale: default_noise_scale(),
+            length_scale: default_length_scale(),
+            noise_w: default_noise_w(),
+        }
+    }
+}
+
+/// A Piper voice loaded into an ONNX Runtime session.
+pub struct PiperOnnxVoice {
+    session: Session,
+    config: VoiceConfig,
+}
+
+impl PiperOnnxVoice {
+    /// Load the voice at `model_path` (path without the `.onnx` extension).
+    ///
+    /// `threads` limits the threads inference may use (None = ONNX Runtime's default).
+    pub fn load(model_path: &Path, threads: Option<usize>) -> Result<Self, TTSError> {
+        let onnx_path = model_path.with_extension("onnx");
+        let config_path = PathBuf::from(format!("{}.json", onnx_path.display()));
+        debug!(?onnx_path, ?config_path, "Loading Piper voice in-process");
+
+        let config_data = std::fs::read_to_string(&config_path).map_err(|e| {
+            TTSError::ProcessError(format!("Failed to read voice config {}: {e}", config_path.display()))
+        })?;
+        let config: VoiceConfig = serde_json::from_str(&config_data).map_err(|e| {
+            TTSError::ProcessError(format!("Invalid voice config {}: {e}", config_path.display()))
+        })?;
+
+        let session = Session::builder()
+            .and_then(|builder| match threads {
+                Some(threads) => builder.with_intra_threads(threads),
+                None => Ok(builder),
+            })
+            .and_then(|builder| builder.commit_from_file(&onnx_path))
+            .map_err(|e| {
+                error!(error = %e, path = %onnx_path.display(), "Failed to load ONNX model");
+                TTSError::ProcessError(format!("Failed to load ONNX model: {e}"))
+            })?;
+
+        info!(
+            sample_rate = config.audio.sample_rate,
+            speakers = config.num_speakers,
+            "Piper voice loaded in-process"
+        );
+        Ok(Self { session, config })
+    }
+
+    /// Output sample rate of the voice.
+    pub fn sample_rate(&self) -> u32 {
+        self.config.audio.sample_rate
+    }
+
+    /// Synthesize `text` to normalized mono f32 samples.
+    pub fn synthesize(&mut self, text: &str) -> Result<Vec<f32>, TTSError> {
+        let mut audio = Vec::new();
+
+        for sentence in split_sentences(text) {
+            let phonemes = self.phonemize(&sentence)?;
+            let ids = self.phoneme_ids(&phonemes);
+            // BOS, PAD, EOS only - nothing to say
+            if ids.len() <= 3 {
+                continue;
+            }
+            audio.extend(self.infer(ids)?);
+        }
+
+        if audio.is_empty() {
+            return Err(TTSError::ProcessError("No audio data generated by Piper voice".into()));
+        }
+        Ok(audio)
+    }
+
+    /// Convert a sentence to phonemes, keeping its terminating punctuation.
+    fn phonemize(&self, sentence: &str) -> Result<String, TTSError> {
+        let (body, terminator) = match sentence.char_indices().last() {
+            Some((i, c)) if SENTENCE_TERMINATORS.contains(&c) || c == ',' => (&sentence[..i], Some(c)),
+            _ => (sentence, None),
+        };
+
+        let mut phonemes = match (self.config.phoneme_type.as_deref(), &self.config.espeak) {
+            (Some("text"), _) => body.to_string(),
+            (_, Some(espeak)) => espeak_phonemize(body, &espeak.voice)?,
+            (_, None) => espeak_phonemize(body, "en-us")?,
+        };
+
+        if let Some(terminator) = terminator {
+            phonemes.push(terminator);
+        }
+        Ok(phonemes)
+    }
+
+    /// Map phonemes to model input IDs (BOS, PAD-interspersed phonemes, EOS).
+    fn phoneme_ids(&self, phonemes: &str) -> Vec<i64> {
+        let map = &self.config.phoneme_id_map;
+        let pad = map.get(PAD).cloned().unwrap_or_default();
+        let mut ids = Vec::with_capacity(phonemes.len() * 2 + 3);
+
+        ids.extend(map.get(BOS).cloned().unwrap_or_default());
+        ids.extend(&pad);
+        for phoneme in phonemes.chars() {
+            match map.get(phoneme.encode_utf8(&mut [0; 4]) as &str) {
+                Some(phoneme_ids) => {
+                    ids.extend(phoneme_ids);
+                    ids.extend(&pad);
+                }
+                None => debug!(%phoneme, "Phoneme not in voice map, skipping"),
+            }
+        }
+        ids.extend(map.get(EOS).cloned().unwrap_or_default());
+        ids
+    }
+
+    /// Run the model on one sentence worth of phoneme IDs.
+    fn infer(&mut self, ids: Vec<i64>) -> Result<Vec<f32>, TTSError> {
+        let to_error = |e: ort::Error| {
+            error!(error = %e, "Piper ONNX inference failed");
+            TTSError::ProcessError(format!("Piper inference failed: {e}"))
+        };
+
+        let len = ids.len();
+        let inference = &self.config.inference;
+        let mut inputs = ort::inputs! {
+            "input" => Tensor::from_array(([1usize, len], ids)).map_err(to_error)?,
+            "input_lengths" => Tensor::from_array(([1usize], vec![len as i64])).map_err(to_error)?,
+            "scales" => Tensor::from_array((
+                [3usize],
+                vec![inference.noise_scale, inference.length_scale, inference.noise_w],
+            ))
+            .map_err(to_error)?,
+        };
+        if self.config.num_speakers > 1 {
+            // Multi-speaker voices: use the first speaker
+            inputs.push((
+                "sid".into(),
+                Tensor::from_array(([1usize], vec![0i64])).map_err(to_error)?.into(),
+            ));
+        }
+
+        let outputs = self.session.run(inputs).map_err(to_error)?;
+        let (_, samples) = outputs[0].try_extract_tensor::<f32>().map_err(to_error)?;
+        Ok(samples.to_vec())
+    }
+}
+
+/// Split text into sentences (terminator included), one per clause-ending
+/// punctuation mark or line break.
+fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\n' {
+            push_sentence(&mut sentences, &mut current);
+            continue;
+        }
+        current.push(c);
+        let at_boundary = chars.peek().is_none_or(|next| next.is_whitespace());
+        if SENTENCE_TERMINATORS.contains(&c) && at_boundary {
+            push_sentence(&mut sentences, &mut current);
+        }
+    }
+    push_sentence(&mut sentences, &mut current);
+    sentences
+}
+
+fn push_sentence(sentences: &mut Vec<String>, current: &mut String) {
+    let sentence = current.trim();
+    if !sentence.is_empty() {
+        sentences.push(sentence.to_string());
+    }
+    current.clear();
+}
+
+/// Phonemize text to IPA with the `espeak-ng` command.
+fn espeak_phonemize(text: &str, voice: &str) -> Result<String, TTSError> {
+    let mut command = Command::new("espeak-ng");
+    command
+        .args(["-q", "--ipa", "-v", voice, "--stdin"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    #[cfg(target_os = "windows")]
+    {
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = command.spawn().map_err(|e| {
+        error!(error = %e, "Failed to start espeak-ng");
+        TTSError::ProcessError(format!("Failed to start espeak-ng (required for phonemization): {e}"))
+    })?;
+
+    if let Some(stdin) = child.stdin.as_mut() {
+        stdin
+            .write_all(text.as_bytes())
+            .map_err(|e| TTSError::ProcessError(format!("Failed to write to espeak-ng: {e}")))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| TTSError::ProcessError(format!("espeak-ng failed: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        warn!(stderr = %stderr.trim(), "espeak-ng exited with an error");
+        return Err(TTSError::ProcessError(format!("espeak-ng failed: {}", stderr.trim())));
+    }
+
+    Ok(clean_espeak_output(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Join espeak's clause lines and drop language-switch markers like "(en)".
+fn clean_espeak_output(output: &str) -> String {
+    let mut cleaned = String::with_capacity(output.len());
+    let mut in_marker = false;
+    for c in output.split_whitespace().collect::<Vec<_>>().join(" ").chars() {
+        match c {
+            '(' => in_marker = true,
+            ')' => in_marker = false,
+            _ if !in_marker => cleaned.push(c),
+            _ => {}
+        }
+    }
+    cleaned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_sentences() {
+        assert_eq!(
+            split_sentences("Hello there. How are you?\nFine 3.5 times"),
+            vec!["Hello there.", "How are you?", "Fine 3.5 times"]
+        );
+    }
+
+    #[test]
+    fn test_clean_espeak_output() {
+        assert_eq!(clean_espeak_output("həlˈoʊ\n (fr)bɔ̃ʒˈuʁ(en) wˈɜːld\n"), "həlˈoʊ bɔ̃ʒˈuʁ wˈɜːld");
+    }
+}
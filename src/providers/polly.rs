@@ -2,12 +2,15 @@
 //!
 //! Uses the AWS SDK for Rust to synthesize speech and plays it using rodio.
 
+use std::sync::Arc;
+
 use aws_config::BehaviorVersion;
-use aws_sdk_polly::types::{Engine, OutputFormat, VoiceId};
+use aws_sdk_polly::types::{Engine, OutputFormat, TextType, VoiceId};
 use tracing::{debug, info};
 
 use super::audio_player::AudioPlayer;
-use super::{TTSError, TTSProvider};
+use super::chunking::sentence_chunks;
+use super::{markup, OutputSettings, Speech, SynthesizedAudio, Synthesis, TTSError, TTSProvider};
 use crate::voices::aws;
 
 const CREDENTIALS_ERROR_MSG: &str = "AWS credentials not found. Please configure credentials via:\n  - Environment variables: AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY\n  - Or credentials file: ~/.aws/credentials";
@@ -18,12 +21,14 @@ pub struct PollyTTSProvider {
     client: aws_sdk_polly::Client,
     /// Shared audio playback engine
     player: AudioPlayer,
-    /// Tokio runtime for async AWS calls
-    runtime: tokio::runtime::Runtime,
+    /// Tokio runtime for async AWS calls (shared with the streaming synthesis thread)
+    runtime: Arc<tokio::runtime::Runtime>,
     /// Selected voice ID (e.g., "Matthew", "Joanna")
     voice_id: String,
     /// Selected engine type (e.g., "Standard", "Neural", "Generative", "LongForm")
     engine: Engine,
+    /// Pitch offset in semitones sent as SSML prosody (standard voices only)
+    pitch: f32,
 }
 
 impl PollyTTSProvider {
@@ -84,9 +89,10 @@ impl PollyTTSProvider {
         Ok(Self {
             client,
             player,
-            runtime,
+            runtime: Arc::new(runtime),
             voice_id: voice_id_str,
             engine,
+            pitch: 0.0,
         })
     }
 
@@ -102,26 +108,52 @@ impl PollyTTSProvider {
 
         // Check for credentials file
         if let Some(home) = dirs::home_dir() {
+            let profile = std::env::var("AWS_PROFILE").unwrap_or_else(|_| "default".to_string());
             let credentials_path = home.join(".aws").join("credentials");
             if credentials_path.exists() {
                 if let Ok(content) = std::fs::read_to_string(&credentials_path) {
-                    let profile = std::env::var("AWS_PROFILE").unwrap_or_else(|_| "default".to_string());
-                    let section_header = if profile == "default" {
-                        "[default]".to_string()
-                    } else {
-                        format!("[profile {}]", profile)
-                    };
+                    // The credentials file names sections without the "profile " prefix
+                    let section_header = format!("[{}]", profile);
 
                     if Self::parse_credentials_from_section(&content, &section_header) {
                         return Ok(());
                     }
                 }
             }
+
+            // Profiles in ~/.aws/config may get credentials from SSO, a role or a process
+            if let Ok(content) = std::fs::read_to_string(home.join(".aws").join("config")) {
+                let section_header = if profile == "default" {
+                    "[default]".to_string()
+                } else {
+                    format!("[profile {}]", profile)
+                };
+                if Self::has_credential_source(&content, &section_header) {
+                    return Ok(());
+                }
+            }
         }
 
         Err(CREDENTIALS_ERROR_MSG.to_string())
     }
 
+    /// Check whether a config file section declares where its credentials come from.
+    fn has_credential_source(content: &str, section_header: &str) -> bool {
+        const SOURCE_KEYS: [&str; 5] = ["sso_session", "sso_start_url", "role_arn", "credential_process", "aws_access_key_id"];
+        let mut in_section = false;
+        for line in content.lines() {
+            let line = line.trim();
+            if line.starts_with('[') {
+                in_section = line.eq_ignore_ascii_case(section_header);
+                continue;
+            }
+            if in_section && SOURCE_KEYS.iter().any(|key| line.starts_with(key)) {
+                return true;
+            }
+        }
+        false
+    }
+
     /// Parse credentials from a specific section in the credentials file.
     /// Returns true if both access key and secret key are found and non-empty.
     fn parse_credentials_from_section(content: &str, section_header: &str) -> bool {
@@ -156,52 +188,120 @@ impl PollyTTSProvider {
     }
 }
 
+/// Approximate on-demand Polly price in USD per million characters.
+fn price_per_million_chars(engine: &str) -> f64 {
+    match engine {
+        "Standard" => 4.0,
+        "Generative" => 30.0,
+        "LongForm" => 100.0,
+        _ => 16.0,
+    }
+}
+
+/// Estimated Polly charge for synthesizing one text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PollyCostEstimate {
+    /// Billed characters (Polly counts every character, including spaces)
+    pub characters: usize,
+    /// Approximate cost in US dollars, ignoring the free tier
+    pub usd: f64,
+}
+
+impl PollyCostEstimate {
+    /// Estimate the cost of speaking `text` with `voice_key` ("VoiceId:Engine").
+    pub fn new(voice_key: Option<&str>, text: &str) -> Self {
+        let engine = voice_key
+            .and_then(|key| key.split_once(':'))
+            .map_or("Neural", |(_, engine)| engine);
+        let characters = text.chars().count();
+        Self {
+            characters,
+            usd: characters as f64 * price_per_million_chars(engine) / 1_000_000.0,
+        }
+    }
+
+    /// Short summary for the status area, e.g. "12,345 chars ≈ $0.20".
+    pub fn summary(&self) -> String {
+        let digits = self.characters.to_string();
+        let mut grouped = String::new();
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        if self.usd < 0.01 {
+            format!("{grouped} chars ≈ <$0.01")
+        } else {
+            format!("{grouped} chars ≈ ${:.2}", self.usd)
+        }
+    }
+}
+
 impl TTSProvider for PollyTTSProvider {
-    fn speak(&mut self, text: &str) -> Result<(), TTSError> {
+    fn synthesize(&mut self, text: &str) -> Result<Synthesis, TTSError> {
         debug!(chars = text.len(), "Polly: synthesizing speech");
 
         // Stop any current playback
         self.player.stop()?;
 
-        // Call AWS Polly to synthesize speech
-        let audio_bytes = self.runtime.block_on(async {
-            let response = self
-                .client
-                .synthesize_speech()
-                .text(text)
-                .output_format(OutputFormat::Pcm)
-                .voice_id(VoiceId::from(self.voice_id.as_str()))
-                .engine(self.engine.clone())
-                .sample_rate("16000")
-                .send()
-                .await
-                .map_err(|e| TTSError::ProcessError(format!("AWS Polly API error: {e}")))?;
-
-            let audio_stream = response.audio_stream;
-            let bytes = audio_stream
-                .collect()
-                .await
-                .map_err(|e| TTSError::ProcessError(format!("Failed to read audio stream: {e}")))?;
-
-            Ok::<_, TTSError>(bytes.into_bytes().to_vec())
-        })?;
-
-        if audio_bytes.is_empty() {
-            return Err(TTSError::ProcessError(
-                "No audio data generated by AWS Polly".into(),
-            ));
-        }
+        let runtime = Arc::clone(&self.runtime);
+        let client = self.client.clone();
+        let voice_id = VoiceId::from(self.voice_id.as_str());
+        let engine = self.engine.clone();
+        // Only standard voices accept <emphasis>
+        let emphasis_supported = engine == Engine::Standard;
+        let pitch = self.pitch;
+        self.player.synthesize_chunks(sentence_chunks(text), move |chunk| {
+            let (input, text_type) = if markup::has_markup(chunk) || pitch != 0.0 {
+                (markup::to_ssml(chunk, emphasis_supported, pitch), TextType::Ssml)
+            } else {
+                (chunk.clone(), TextType::Text)
+            };
+
+            // Call AWS Polly to synthesize speech
+            let audio_bytes = runtime.block_on(async {
+                let response = client
+                    .synthesize_speech()
+                    .text(input)
+                    .text_type(text_type)
+                    .output_format(OutputFormat::Pcm)
+                    .voice_id(voice_id.clone())
+                    .engine(engine.clone())
+                    .sample_rate("16000")
+                    .send()
+                    .await
+                    .map_err(|e| TTSError::ProcessError(format!("AWS Polly API error: {e}")))?;
+
+                let audio_stream = response.audio_stream;
+                let bytes = audio_stream
+                    .collect()
+                    .await
+                    .map_err(|e| TTSError::ProcessError(format!("Failed to read audio stream: {e}")))?;
+
+                Ok::<_, TTSError>(bytes.into_bytes().to_vec())
+            })?;
+
+            if audio_bytes.is_empty() {
+                return Err(TTSError::ProcessError(
+                    "No audio data generated by AWS Polly".into(),
+                ));
+            }
 
-        // Convert PCM to f32 and play
-        let audio_data = AudioPlayer::pcm_to_f32(&audio_bytes);
-        let duration_sec = audio_data.len() as f32 / 16000.0;
-        info!(
-            bytes = audio_bytes.len(),
-            duration_sec = format!("{:.1}", duration_sec),
-            "Polly: audio received"
-        );
+            // Convert PCM to f32
+            let audio_data = AudioPlayer::pcm_to_f32(&audio_bytes);
+            let duration_sec = audio_data.len() as f32 / 16000.0;
+            info!(
+                bytes = audio_bytes.len(),
+                duration_sec = format!("{:.1}", duration_sec),
+                "Polly: audio received"
+            );
+            Ok(audio_data)
+        })
+    }
 
-        self.player.play_audio(audio_data)
+    fn play(&mut self, speech: Speech) -> Result<(), TTSError> {
+        self.player.play_speech(speech)
     }
 
     fn pause(&mut self) -> Result<(), TTSError> {
@@ -224,6 +324,27 @@ impl TTSProvider for PollyTTSProvider {
         self.player.is_paused()
     }
 
+    fn is_buffering(&self) -> bool {
+        self.player.is_buffering()
+    }
+
+    fn set_speed(&mut self, speed: f32) {
+        self.player.set_speed(speed);
+    }
+
+    fn set_pitch(&mut self, semitones: f32) {
+        // Standard voices take SSML prosody pitch; the newer engines do not support it
+        if self.engine == Engine::Standard {
+            self.pitch = semitones;
+        } else {
+            self.player.set_pitch(semitones);
+        }
+    }
+
+    fn set_output(&mut self, output: &OutputSettings) -> Result<(), TTSError> {
+        self.player.set_output(output)
+    }
+
     fn skip_forward(&mut self, seconds: f32) {
         self.player.skip_forward(seconds);
     }
@@ -232,11 +353,38 @@ impl TTSProvider for PollyTTSProvider {
         self.player.skip_backward(seconds);
     }
 
+    fn set_loop(&mut self, region: Option<(f32, f32)>) {
+        self.player.set_loop(region);
+    }
+
     fn get_progress(&self) -> f32 {
         self.player.get_progress()
     }
 
+    fn get_duration(&self) -> f32 {
+        self.player.get_duration()
+    }
+
     fn get_frequency_bands(&self, num_bands: usize) -> Vec<f32> {
         self.player.get_frequency_bands(num_bands)
     }
+
+    fn synthesized_audio(&self) -> Option<SynthesizedAudio> {
+        self.player.synthesized_audio()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cost_estimate_uses_engine_price() {
+        let text = "a".repeat(250_000);
+        assert_eq!(PollyCostEstimate::new(Some("Joanna:Standard"), &text).usd, 1.0);
+        assert_eq!(PollyCostEstimate::new(Some("Joanna:Neural"), &text).usd, 4.0);
+        assert_eq!(PollyCostEstimate::new(None, &text).usd, 4.0);
+        assert_eq!(PollyCostEstimate::new(Some("Danielle:LongForm"), &text).summary(), "250,000 chars ≈ $25.00");
+        assert_eq!(PollyCostEstimate::new(None, "Hi there").summary(), "8 chars ≈ <$0.01");
+    }
 }
@@ -0,0 +1,108 @@
+//! "What did it just say?" replay buffer.
+//!
+//! Every audio player records what it actually plays (after seeks, at normal
+//! speed) into one process-wide ring buffer holding the last few seconds.
+//! The buffer outlives the provider, so the end of a reading can still be
+//! replayed after playback finished and its window closed.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use tracing::debug;
+
+use super::phrase_cache::CachedAudioProvider;
+use super::{prepare_speech, OutputSettings, SynthesizedAudio, Synthesis, TTSError, TTSProvider};
+
+/// Seconds of played audio kept for replay.
+pub const REPLAY_SECONDS: u32 = 15;
+
+/// The most recently played samples, oldest first.
+#[derive(Debug, Default)]
+struct ReplayBuffer {
+    samples: VecDeque<f32>,
+    sample_rate: u32,
+}
+
+impl ReplayBuffer {
+    /// Append played samples, dropping the oldest beyond [`REPLAY_SECONDS`].
+    ///
+    /// A new sample rate starts a new buffer: samples of different rates can't be mixed.
+    fn push(&mut self, samples: &[f32], sample_rate: u32) {
+        if sample_rate != self.sample_rate {
+            self.samples.clear();
+            self.sample_rate = sample_rate;
+        }
+        let capacity = (sample_rate * REPLAY_SECONDS) as usize;
+        let samples = &samples[samples.len().saturating_sub(capacity)..];
+        let overflow = (self.samples.len() + samples.len()).saturating_sub(capacity);
+        self.samples.drain(..overflow);
+        self.samples.extend(samples);
+    }
+
+    fn audio(&self) -> Option<SynthesizedAudio> {
+        (!self.samples.is_empty()).then(|| SynthesizedAudio {
+            samples: Arc::new(self.samples.iter().copied().collect()),
+            sample_rate: self.sample_rate,
+        })
+    }
+}
+
+static BUFFER: LazyLock<Mutex<ReplayBuffer>> = LazyLock::new(|| Mutex::new(ReplayBuffer::default()));
+
+/// Record samples that were just played.
+pub(super) fn record(samples: &[f32], sample_rate: u32) {
+    if let Ok(mut buffer) = BUFFER.lock() {
+        buffer.push(samples, sample_rate);
+    }
+}
+
+/// Whether anything has been played yet.
+pub fn has_recent_audio() -> bool {
+    BUFFER.lock().is_ok_and(|buffer| !buffer.samples.is_empty())
+}
+
+/// Forget the played audio, freeing its memory (released when idle).
+pub fn clear() {
+    if let Ok(mut buffer) = BUFFER.lock() {
+        *buffer = ReplayBuffer::default();
+    }
+}
+
+/// Prepare playing the last [`REPLAY_SECONDS`] of played audio.
+///
+/// Returns the provider, which plays the speech the synthesis produces.
+pub fn prepare_replay(
+    speed: f32,
+    pitch: f32,
+    output: &OutputSettings,
+) -> Result<(Box<dyn TTSProvider>, Synthesis), TTSError> {
+    let audio = BUFFER
+        .lock()
+        .ok()
+        .and_then(|buffer| buffer.audio())
+        .ok_or_else(|| TTSError::ProcessError("Nothing has been read yet".to_string()))?;
+    debug!(
+        seconds = audio.samples.len() as f32 / audio.sample_rate as f32,
+        "Replaying recent audio"
+    );
+    prepare_speech(Box::new(CachedAudioProvider::new(audio)?), None, "", speed, pitch, output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_buffer_keeps_latest_seconds() {
+        let mut buffer = ReplayBuffer::default();
+        buffer.push(&[0.1; 40], 2);
+        assert_eq!(buffer.samples.len(), (2 * REPLAY_SECONDS) as usize);
+        buffer.push(&[0.2, 0.3], 2);
+        let audio = buffer.audio().unwrap();
+        assert_eq!(audio.samples.len(), 30);
+        assert_eq!(audio.samples[28..], [0.2, 0.3]);
+
+        buffer.push(&[0.5], 4);
+        assert_eq!(buffer.audio().unwrap().samples.as_slice(), &[0.5]);
+    }
+}
@@ -0,0 +1,67 @@
+//! Synthesis load limits
+//!
+//! A long Piper reading can keep every core busy for a while. The load setting
+//! runs the synthesizer at a lower priority (a nice level on Linux, a QoS
+//! clamp on macOS, a priority class on Windows) so the rest of the machine
+//! stays responsive; the eco preset also runs in-process voices on fewer
+//! threads, trading a slower start for less heat and noise.
+
+use std::path::Path;
+use std::process::Command;
+
+/// Threads an in-process voice may use in the eco preset.
+#[cfg(feature = "piper-onnx")]
+const ECO_THREADS: usize = 2;
+
+/// How much of the machine synthesis may take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SynthesisLoad {
+    /// Full priority, every core: the fastest start
+    #[default]
+    Normal,
+    /// Lower priority: yields to whatever else is running
+    Background,
+    /// Lowest priority and fewer threads
+    Eco,
+}
+
+impl SynthesisLoad {
+    /// Threads an in-process voice may use (None = as many as it likes).
+    #[cfg(feature = "piper-onnx")]
+    pub fn threads(self) -> Option<usize> {
+        (self == SynthesisLoad::Eco).then_some(ECO_THREADS)
+    }
+
+    /// Command running `program` at this load's priority.
+    ///
+    /// On Windows the priority is set through [`priority_class`](Self::priority_class) instead.
+    pub fn command(self, program: &Path) -> Command {
+        #[cfg(target_os = "linux")]
+        if self != SynthesisLoad::Normal {
+            let nice = if self == SynthesisLoad::Eco { "19" } else { "10" };
+            let mut command = Command::new("nice");
+            command.args(["-n", nice]).arg(program);
+            return command;
+        }
+        #[cfg(target_os = "macos")]
+        if self != SynthesisLoad::Normal {
+            let clamp = if self == SynthesisLoad::Eco { "background" } else { "utility" };
+            let mut command = Command::new("taskpolicy");
+            command.args(["-c", clamp]).arg(program);
+            return command;
+        }
+        Command::new(program)
+    }
+
+    /// Process creation flags for this load's priority class.
+    #[cfg(target_os = "windows")]
+    pub fn priority_class(self) -> u32 {
+        const BELOW_NORMAL_PRIORITY_CLASS: u32 = 0x0000_4000;
+        const IDLE_PRIORITY_CLASS: u32 = 0x0000_0040;
+        match self {
+            SynthesisLoad::Normal => 0,
+            SynthesisLoad::Background => BELOW_NORMAL_PRIORITY_CLASS,
+            SynthesisLoad::Eco => IDLE_PRIORITY_CLASS,
+        }
+    }
+}
@@ -0,0 +1,204 @@
+//! Virtual microphone output.
+//!
+//! Routes synthesized speech into a microphone other apps can record from, so
+//! the reader can "speak" into calls and games.
+//!
+//! - Linux: a PulseAudio / PipeWire (pipewire-pulse) null sink is created with
+//!   `pactl`, and its monitor is remapped into a virtual source named
+//!   "Insight Reader Microphone". A dedicated output stream is moved into the sink.
+//! - Windows / macOS: virtual devices need a driver, so an installed loopback
+//!   device (VB-CABLE, VoiceMeeter, BlackHole, Loopback) is used instead.
+
+use rodio::{OutputStream, OutputStreamHandle};
+
+use super::TTSError;
+
+/// Name shown for the virtual microphone in other apps (Linux).
+pub const VIRTUAL_MIC_DESCRIPTION: &str = "Insight Reader Microphone";
+
+/// Null sink the speech is played into (Linux).
+#[cfg(any(target_os = "linux", test))]
+const VIRTUAL_MIC_SINK: &str = "insight_reader_mic";
+
+/// Virtual source remapped from the sink's monitor (Linux).
+#[cfg(any(target_os = "linux", test))]
+const VIRTUAL_MIC_SOURCE: &str = "insight_reader_mic_source";
+
+/// Name fragments of well-known loopback drivers' playback devices.
+#[cfg_attr(target_os = "linux", allow(dead_code))]
+const LOOPBACK_DEVICE_HINTS: [&str; 4] = ["CABLE Input", "VoiceMeeter Input", "BlackHole", "Loopback Audio"];
+
+/// Pick the first output device that belongs to a loopback driver.
+#[cfg_attr(target_os = "linux", allow(dead_code))]
+fn find_loopback_device(devices: &[String]) -> Option<&String> {
+    LOOPBACK_DEVICE_HINTS
+        .iter()
+        .find_map(|hint| devices.iter().find(|device| device.contains(hint)))
+}
+
+/// Make sure the virtual microphone exists and return the name other apps see.
+pub fn prepare() -> Result<String, TTSError> {
+    #[cfg(target_os = "linux")]
+    {
+        ensure_virtual_mic()?;
+        Ok(VIRTUAL_MIC_DESCRIPTION.to_string())
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        find_loopback_device(&super::list_output_devices())
+            .cloned()
+            .ok_or_else(|| {
+                TTSError::AudioError(
+                    "No loopback device found. Install VB-CABLE (Windows) or BlackHole (macOS).".into(),
+                )
+            })
+    }
+}
+
+/// Open an output stream that plays into the virtual microphone.
+pub(super) fn open_stream() -> Result<(OutputStream, OutputStreamHandle), TTSError> {
+    #[cfg(target_os = "linux")]
+    {
+        ensure_virtual_mic()?;
+        let pid = std::process::id();
+        let before = sink_inputs_for_pid(&pactl(&["list", "sink-inputs"])?, pid);
+        let output = OutputStream::try_default()
+            .map_err(|e| TTSError::AudioError(format!("Failed to open audio output: {e}")))?;
+
+        // The stream shows up as a new sink input shortly after it is opened
+        for _ in 0..10 {
+            let inputs = sink_inputs_for_pid(&pactl(&["list", "sink-inputs"])?, pid);
+            if let Some(input) = inputs.into_iter().filter(|id| !before.contains(id)).max() {
+                pactl(&["move-sink-input", &input.to_string(), VIRTUAL_MIC_SINK])?;
+                tracing::debug!(input, "Output stream moved to virtual microphone");
+                return Ok(output);
+            }
+            std::thread::sleep(std::time::Duration::from_millis(30));
+        }
+        Err(TTSError::AudioError("Could not route audio to the virtual microphone".into()))
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let device = prepare()?;
+        super::audio_player::open_named_output(&device)
+            .ok_or_else(|| TTSError::AudioError(format!("Failed to open loopback device {device}")))
+    }
+}
+
+/// Remove the virtual microphone created by [`prepare`] (Linux only).
+pub fn remove() {
+    #[cfg(target_os = "linux")]
+    {
+        let Ok(modules) = pactl(&["list", "short", "modules"]) else {
+            return;
+        };
+        for id in modules_for_virtual_mic(&modules) {
+            if let Err(e) = pactl(&["unload-module", id]) {
+                tracing::warn!(error = %e, module = id, "Failed to unload virtual microphone module");
+            }
+        }
+        tracing::info!("Virtual microphone removed");
+    }
+}
+
+/// Run `pactl` and return its stdout.
+#[cfg(target_os = "linux")]
+fn pactl(args: &[&str]) -> Result<String, TTSError> {
+    let output = std::process::Command::new("pactl")
+        .args(args)
+        .output()
+        .map_err(|e| TTSError::ProcessError(format!("Failed to run pactl (is PulseAudio or PipeWire installed?): {e}")))?;
+    if !output.status.success() {
+        return Err(TTSError::ProcessError(format!(
+            "pactl {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Create the null sink and remapped source unless they already exist.
+#[cfg(target_os = "linux")]
+fn ensure_virtual_mic() -> Result<(), TTSError> {
+    let sinks = pactl(&["list", "short", "sinks"])?;
+    if sinks.lines().any(|line| line.split_whitespace().nth(1) == Some(VIRTUAL_MIC_SINK)) {
+        return Ok(());
+    }
+
+    // pactl property values can't contain spaces unquoted
+    let description = VIRTUAL_MIC_DESCRIPTION.replace(' ', "_");
+    pactl(&[
+        "load-module",
+        "module-null-sink",
+        &format!("sink_name={VIRTUAL_MIC_SINK}"),
+        &format!("sink_properties=device.description={description}_Output"),
+    ])?;
+    pactl(&[
+        "load-module",
+        "module-remap-source",
+        &format!("master={VIRTUAL_MIC_SINK}.monitor"),
+        &format!("source_name={VIRTUAL_MIC_SOURCE}"),
+        &format!("source_properties=device.description={description}"),
+    ])?;
+    tracing::info!("Virtual microphone created");
+    Ok(())
+}
+
+/// Sink input ids owned by `pid` in `pactl list sink-inputs` output.
+#[cfg(any(target_os = "linux", test))]
+fn sink_inputs_for_pid(listing: &str, pid: u32) -> Vec<u32> {
+    let pid_property = format!("application.process.id = \"{pid}\"");
+    let mut current: Option<u32> = None;
+    let mut inputs = Vec::new();
+    for line in listing.lines() {
+        if let Some(id) = line.trim().strip_prefix("Sink Input #") {
+            current = id.trim().parse().ok();
+        } else if line.trim() == pid_property {
+            inputs.extend(current);
+        }
+    }
+    inputs
+}
+
+/// Ids of the modules that make up the virtual microphone in `pactl list short modules` output.
+#[cfg(any(target_os = "linux", test))]
+fn modules_for_virtual_mic(listing: &str) -> Vec<&str> {
+    listing
+        .lines()
+        .filter(|line| {
+            line.contains(&format!("sink_name={VIRTUAL_MIC_SINK}"))
+                || line.contains(&format!("source_name={VIRTUAL_MIC_SOURCE}"))
+        })
+        .filter_map(|line| line.split_whitespace().next())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_loopback_device() {
+        let devices = vec!["Speakers (Realtek)".to_string(), "CABLE Input (VB-Audio Virtual Cable)".to_string()];
+        assert_eq!(find_loopback_device(&devices), Some(&devices[1]));
+        assert_eq!(find_loopback_device(&devices[..1]), None);
+    }
+
+    #[test]
+    fn test_sink_inputs_for_pid() {
+        let listing = "Sink Input #41\n\tProperties:\n\t\tapplication.process.id = \"100\"\n\
+                       Sink Input #42\n\tProperties:\n\t\tapplication.process.id = \"200\"\n\
+                       Sink Input #43\n\tProperties:\n\t\tapplication.process.id = \"100\"\n";
+        assert_eq!(sink_inputs_for_pid(listing, 100), vec![41, 43]);
+        assert!(sink_inputs_for_pid(listing, 300).is_empty());
+    }
+
+    #[test]
+    fn test_modules_for_virtual_mic() {
+        let listing = "7\tmodule-null-sink\tsink_name=insight_reader_mic sink_properties=x\n\
+                       8\tmodule-remap-source\tmaster=insight_reader_mic.monitor source_name=insight_reader_mic_source\n\
+                       9\tmodule-null-sink\tsink_name=other\n";
+        assert_eq!(modules_for_virtual_mic(listing), vec!["7", "8"]);
+    }
+}
@@ -0,0 +1,207 @@
+//! Minimal WAV decoding for providers whose engines return WAV files, and
+//! encoding for saving synthesized audio.
+
+use tracing::info;
+
+use super::audio_player::{synthesize_chunks, AudioPlayer};
+use super::chunking::sentence_chunks;
+use super::{Speech, Synthesis, TTSError};
+
+/// Decoded mono audio from a 16-bit PCM WAV file.
+pub struct WavAudio {
+    /// Sample rate declared in the `fmt ` chunk
+    pub sample_rate: u32,
+    /// Normalized mono samples (-1.0 to 1.0)
+    pub samples: Vec<f32>,
+}
+
+/// Decode a RIFF/WAVE buffer containing 16-bit PCM into mono f32 samples.
+///
+/// Multi-channel audio is downmixed by averaging the channels.
+pub fn decode_wav(bytes: &[u8]) -> Result<WavAudio, TTSError> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err(TTSError::AudioError("Audio is not a WAV file".into()));
+    }
+
+    let mut format = None;
+    let mut offset = 12;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let len = u32::from_le_bytes([
+            bytes[offset + 4],
+            bytes[offset + 5],
+            bytes[offset + 6],
+            bytes[offset + 7],
+        ]) as usize;
+        let body_start = offset + 8;
+        let body_end = body_start.saturating_add(len).min(bytes.len());
+        let body = &bytes[body_start..body_end];
+
+        match id {
+            b"fmt " if body.len() >= 16 => {
+                let channels = u16::from_le_bytes([body[2], body[3]]);
+                let sample_rate = u32::from_le_bytes([body[4], body[5], body[6], body[7]]);
+                let bits_per_sample = u16::from_le_bytes([body[14], body[15]]);
+                format = Some((sample_rate, channels, bits_per_sample));
+            }
+            b"data" => {
+                let (sample_rate, channels, bits_per_sample) = format.ok_or_else(|| {
+                    TTSError::AudioError("WAV data chunk precedes format chunk".into())
+                })?;
+                if bits_per_sample != 16 {
+                    return Err(TTSError::AudioError(format!(
+                        "Unsupported WAV bit depth: {bits_per_sample}"
+                    )));
+                }
+                return Ok(WavAudio {
+                    sample_rate,
+                    samples: downmix(AudioPlayer::pcm_to_f32(body), channels),
+                });
+            }
+            _ => {}
+        }
+
+        // Chunks are padded to an even number of bytes
+        offset = body_end + (len & 1);
+    }
+
+    Err(TTSError::AudioError("WAV file has no data chunk".into()))
+}
+
+/// Prepare speaking `text` with a server that returns one WAV per request, in sentence chunks.
+///
+/// The first chunk is fetched when the synthesis runs, and its sample rate is
+/// the one the speech plays at; the rest are fetched while earlier chunks play
+/// (see [`synthesize_chunks`]). `engine` names the server in errors and logs.
+pub fn wav_chunks_synthesis<F>(text: &str, engine: &'static str, fetch: F) -> Result<Synthesis, TTSError>
+where
+    F: Fn(&str) -> Result<Vec<u8>, TTSError> + Send + 'static,
+{
+    let mut chunks = sentence_chunks(text).into_iter();
+    let first = chunks
+        .next()
+        .ok_or_else(|| TTSError::ProcessError("No text to synthesize".into()))?;
+    let rest: Vec<String> = chunks.collect();
+    Ok(Synthesis::new(move || synthesize_wav_chunks(first, rest, engine, fetch)))
+}
+
+/// Fetch the first chunk, and return speech fetching the others while it plays.
+fn synthesize_wav_chunks<F>(
+    first: String,
+    rest: Vec<String>,
+    engine: &'static str,
+    fetch: F,
+) -> Result<Speech, TTSError>
+where
+    F: Fn(&str) -> Result<Vec<u8>, TTSError> + Send + 'static,
+{
+    let wav = decode_wav(&fetch(&first)?)?;
+    if wav.samples.is_empty() {
+        return Err(TTSError::ProcessError(format!("No audio data generated by {engine}")));
+    }
+    let sample_rate = wav.sample_rate;
+    info!(
+        engine,
+        sample_rate,
+        duration_sec = format!("{:.1}", wav.samples.len() as f32 / sample_rate as f32),
+        "First chunk received"
+    );
+
+    // The first chunk carries its audio; the others are fetched when their turn comes
+    let chunks: Vec<(Option<Vec<f32>>, String)> = std::iter::once((Some(wav.samples), first))
+        .chain(rest.into_iter().map(|chunk| (None, chunk)))
+        .collect();
+    synthesize_chunks(chunks, sample_rate, move |(ready, chunk)| {
+        if let Some(samples) = ready {
+            return Ok(samples.clone());
+        }
+        let wav = decode_wav(&fetch(chunk)?)?;
+        if wav.sample_rate != sample_rate {
+            return Err(TTSError::AudioError(format!(
+                "{engine} changed its sample rate from {sample_rate} to {} Hz",
+                wav.sample_rate
+            )));
+        }
+        Ok(wav.samples)
+    })
+}
+
+/// `LIST`/`INFO` chunk holding `info` (e.g. `ICOP` copyright), or nothing if it is empty.
+fn info_chunk(info: &[([u8; 4], &str)]) -> Vec<u8> {
+    if info.is_empty() {
+        return Vec::new();
+    }
+    let mut body = b"INFO".to_vec();
+    for (id, value) in info {
+        // NUL-terminated, padded to an even length
+        let mut value = value.as_bytes().to_vec();
+        value.push(0);
+        if value.len() % 2 == 1 {
+            value.push(0);
+        }
+        body.extend_from_slice(id);
+        body.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        body.extend_from_slice(&value);
+    }
+    let mut chunk = b"LIST".to_vec();
+    chunk.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    chunk.extend_from_slice(&body);
+    chunk
+}
+
+/// Encode mono f32 samples as a 16-bit PCM WAV file with `INFO` metadata after the audio.
+pub fn encode_wav_with_info(samples: &[f32], sample_rate: u32, info: &[([u8; 4], &str)]) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let info = info_chunk(info);
+    let mut bytes = Vec::with_capacity(44 + data_len as usize + info.len());
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len + info.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // mono
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * 2).to_le_bytes()); // byte rate
+    bytes.extend_from_slice(&2u16.to_le_bytes()); // block align
+    bytes.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    bytes.extend_from_slice(&info);
+    bytes
+}
+
+/// Average interleaved channels down to mono.
+fn downmix(samples: Vec<f32>, channels: u16) -> Vec<f32> {
+    let channels = channels.max(1) as usize;
+    if channels == 1 {
+        return samples;
+    }
+    samples
+        .chunks_exact(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_round_trip() {
+        let wav = encode_wav_with_info(&[0.0, 1.0, -1.0], 22050, &[]);
+        assert_eq!(wav.len(), 44 + 6);
+        let decoded = decode_wav(&wav).unwrap();
+        assert_eq!(decoded.sample_rate, 22050);
+        assert_eq!(decoded.samples.len(), 3);
+        assert!((decoded.samples[1] - 1.0).abs() < 0.001);
+
+        let tagged = encode_wav_with_info(&[0.0, 1.0, -1.0], 22050, &[(*b"ICOP", "License: CC0")]);
+        assert_eq!(u32::from_le_bytes(tagged[4..8].try_into().unwrap()) as usize, tagged.len() - 8);
+        assert_eq!(&tagged[50..54], b"LIST");
+        assert_eq!(decode_wav(&tagged).unwrap().samples.len(), 3);
+    }
+}
@@ -0,0 +1,211 @@
+//! Native Windows TTS provider implementation.
+//!
+//! Uses `Windows.Media.SpeechSynthesis` (the WinRT speech synthesizer) so the app
+//! works out of the box on Windows without downloading voices or configuring
+//! cloud credentials. The synthesizer returns a WAV stream which is decoded and
+//! played using rodio.
+
+use tracing::{debug, error, info};
+use windows::core::HSTRING;
+use windows::Media::SpeechSynthesis::SpeechSynthesizer;
+use windows::Storage::Streams::DataReader;
+
+use super::audio_player::AudioPlayer;
+use super::wav::decode_wav;
+use super::{markup, OutputSettings, Speech, SynthesizedAudio, Synthesis, TTSError, TTSProvider};
+
+/// Sample rate used until the first synthesized stream tells us otherwise.
+const DEFAULT_SAMPLE_RATE: u32 = 22050;
+
+/// Windows TTS provider using the built-in WinRT speech synthesizer.
+pub struct WindowsTTSProvider {
+    /// Shared audio playback engine
+    player: AudioPlayer,
+}
+
+impl WindowsTTSProvider {
+    /// Create a new Windows TTS provider.
+    ///
+    /// Uses the system default voice configured in Windows Settings.
+    pub fn new() -> Result<Self, TTSError> {
+        info!("Initializing Windows TTS provider");
+
+        let player = AudioPlayer::new(DEFAULT_SAMPLE_RATE)?;
+
+        Ok(Self { player })
+    }
+
+    /// Synthesize `text` to an in-memory WAV file using the WinRT synthesizer.
+    fn synthesize_wav(text: &str) -> Result<Vec<u8>, TTSError> {
+        // WinRT APIs require COM to be initialized on the calling thread
+        unsafe {
+            let hr = windows::Win32::System::Com::CoInitializeEx(
+                None,
+                windows::Win32::System::Com::COINIT_MULTITHREADED,
+            );
+            // If already initialized (S_FALSE = 0x00000001), that's okay
+            if hr.is_err() && hr.0 != 0x00000001 {
+                error!(hr = hr.0, "Failed to initialize Windows Runtime");
+                return Err(TTSError::ProcessError(format!(
+                    "Failed to initialize Windows Runtime: HRESULT 0x{:08X}",
+                    hr.0
+                )));
+            }
+        }
+
+        let result = Self::synthesize_wav_inner(text);
+
+        // Cleanup COM
+        unsafe {
+            windows::Win32::System::Com::CoUninitialize();
+        }
+
+        result
+    }
+
+    fn synthesize_wav_inner(text: &str) -> Result<Vec<u8>, TTSError> {
+        let to_error = |context: &str, e: windows::core::Error| {
+            error!(error = %e, "{context}");
+            TTSError::ProcessError(format!("{context}: {e}"))
+        };
+
+        let synthesizer = SpeechSynthesizer::new()
+            .map_err(|e| to_error("Failed to create speech synthesizer", e))?;
+
+        if let Ok(voice) = synthesizer.Voice() {
+            if let Ok(name) = voice.DisplayName() {
+                debug!(voice = %name, "Using Windows voice");
+            }
+        }
+
+        let stream = synthesizer
+            .SynthesizeTextToStreamAsync(&HSTRING::from(text))
+            .map_err(|e| to_error("Failed to synthesize speech", e))?
+            .get()
+            .map_err(|e| to_error("Failed to synthesize speech", e))?;
+
+        let size = stream
+            .Size()
+            .map_err(|e| to_error("Failed to read speech stream", e))? as u32;
+
+        let input = stream
+            .GetInputStreamAt(0)
+            .map_err(|e| to_error("Failed to read speech stream", e))?;
+        let reader = DataReader::CreateDataReader(&input)
+            .map_err(|e| to_error("Failed to create data reader", e))?;
+        reader
+            .LoadAsync(size)
+            .map_err(|e| to_error("Failed to read speech stream", e))?
+            .get()
+            .map_err(|e| to_error("Failed to read speech stream", e))?;
+
+        let mut bytes = vec![0u8; size as usize];
+        reader
+            .ReadBytes(&mut bytes)
+            .map_err(|e| to_error("Failed to read speech stream", e))?;
+
+        Ok(bytes)
+    }
+
+    /// Synthesize `text` and decode the audio (runs on the synthesis thread).
+    fn synthesize_speech(text: &str) -> Result<Speech, TTSError> {
+        let wav_bytes = Self::synthesize_wav(text)?;
+        let wav = decode_wav(&wav_bytes)?;
+        if wav.samples.is_empty() {
+            return Err(TTSError::ProcessError(
+                "No audio data generated by Windows speech synthesizer".into(),
+            ));
+        }
+
+        let duration_sec = wav.samples.len() as f32 / wav.sample_rate as f32;
+        info!(
+            bytes = wav_bytes.len(),
+            sample_rate = wav.sample_rate,
+            duration_sec = format!("{:.1}", duration_sec),
+            "Windows: audio synthesized"
+        );
+
+        // Voices may use different sample rates
+        Ok(Speech::complete(wav.samples, wav.sample_rate))
+    }
+}
+
+impl TTSProvider for WindowsTTSProvider {
+    fn synthesize(&mut self, text: &str) -> Result<Synthesis, TTSError> {
+        debug!(chars = text.len(), "Windows: synthesizing speech");
+
+        // Stop any current playback
+        self.player.stop()?;
+
+        let text = markup::to_plain_text(text);
+        Ok(Synthesis::new(move || Self::synthesize_speech(&text)))
+    }
+
+    fn play(&mut self, speech: Speech) -> Result<(), TTSError> {
+        self.player.play_speech(speech)
+    }
+
+    fn pause(&mut self) -> Result<(), TTSError> {
+        self.player.pause()
+    }
+
+    fn resume(&mut self) -> Result<(), TTSError> {
+        self.player.resume()
+    }
+
+    fn stop(&mut self) -> Result<(), TTSError> {
+        self.player.stop()
+    }
+
+    fn is_playing(&self) -> bool {
+        self.player.is_playing()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.player.is_paused()
+    }
+
+    fn is_buffering(&self) -> bool {
+        self.player.is_buffering()
+    }
+
+    fn set_speed(&mut self, speed: f32) {
+        self.player.set_speed(speed);
+    }
+
+    fn set_pitch(&mut self, semitones: f32) {
+        self.player.set_pitch(semitones);
+    }
+
+    fn set_output(&mut self, output: &OutputSettings) -> Result<(), TTSError> {
+        self.player.set_output(output)
+    }
+
+    fn skip_forward(&mut self, seconds: f32) {
+        self.player.skip_forward(seconds);
+    }
+
+    fn skip_backward(&mut self, seconds: f32) {
+        self.player.skip_backward(seconds);
+    }
+
+    fn set_loop(&mut self, region: Option<(f32, f32)>) {
+        self.player.set_loop(region);
+    }
+
+    fn get_progress(&self) -> f32 {
+        self.player.get_progress()
+    }
+
+    fn get_duration(&self) -> f32 {
+        self.player.get_duration()
+    }
+
+    fn get_frequency_bands(&self, num_bands: usize) -> Vec<f32> {
+        self.player.get_frequency_bands(num_bands)
+    }
+
+    fn synthesized_audio(&self) -> Option<SynthesizedAudio> {
+        self.player.synthesized_audio()
+    }
+}
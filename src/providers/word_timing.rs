@@ -0,0 +1,177 @@
+//! Word and sentence timing map
+//!
+//! Engines don't report when each word is spoken, so the start of every word
+//! is estimated from the text: a word takes time in proportion to its letters
+//! and punctuation adds a pause. That is close enough to tell, from the
+//! playback progress, which word was just heard (e.g. to spell it out),
+//! where the current sentence began (to pick up from there after a pause) and
+//! which sentence comes next (to read it with another voice).
+
+use std::ops::Range;
+
+/// Extra length of the pause after a comma, colon or semicolon, in letters.
+const CLAUSE_PAUSE: f32 = 3.0;
+
+/// Extra length of the pause after the end of a sentence, in letters.
+const SENTENCE_PAUSE: f32 = 6.0;
+
+/// Longest pre-roll back to the start of a sentence, in seconds.
+const MAX_SENTENCE_PRE_ROLL: f32 = 15.0;
+
+/// Fixed pre-roll lengths offered in the settings, in seconds.
+pub const PRE_ROLL_PRESETS: [u32; 2] = [2, 5];
+
+/// Where playback picks up after a pause or a skip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PreRoll {
+    /// Exactly where it stopped
+    #[default]
+    Off,
+    /// At the start of the sentence it stopped in
+    SentenceStart,
+    /// This many seconds before where it stopped
+    Seconds(u32),
+}
+
+impl PreRoll {
+    /// Seconds (at normal speed) to back up from `progress` of a reading `duration` seconds long.
+    pub fn rewind(self, timings: Option<&WordTimings>, progress: f32, duration: f32) -> f32 {
+        let rewind = match self {
+            PreRoll::Off => 0.0,
+            PreRoll::SentenceStart => timings.map_or(0.0, |timings| {
+                ((progress - timings.sentence_start(progress)) * duration).min(MAX_SENTENCE_PRE_ROLL)
+            }),
+            PreRoll::Seconds(seconds) => seconds as f32,
+        };
+        rewind.clamp(0.0, progress * duration)
+    }
+}
+
+/// Estimated start of every word of a reading.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WordTimings {
+    /// Words in reading order, each with its start as a share of the reading (0.0 to 1.0)
+    words: Vec<(f32, String)>,
+    /// Byte offset in the text of each word
+    offsets: Vec<usize>,
+    /// Start of each sentence, as a share of the reading
+    sentences: Vec<f32>,
+    /// Byte offset in the text of each sentence
+    sentence_offsets: Vec<usize>,
+    /// Length of the text in bytes
+    len: usize,
+}
+
+impl WordTimings {
+    /// Estimate when each word of `text` (plain text) is spoken.
+    pub fn new(text: &str) -> Self {
+        let mut words = Vec::new();
+        let mut offsets = Vec::new();
+        let mut sentences = vec![0.0];
+        let mut sentence_offsets = vec![0];
+        let mut elapsed = 0.0;
+        for token in text.split_whitespace() {
+            let word = token.trim_matches(|c: char| !c.is_alphanumeric());
+            let offset = token.as_ptr() as usize - text.as_ptr() as usize;
+            if !word.is_empty() {
+                words.push((elapsed, word.to_string()));
+                offsets.push(offset);
+            }
+            // One extra letter for the gap between words
+            elapsed += word.chars().count() as f32 + 1.0;
+            match token.chars().last() {
+                Some('.' | '!' | '?' | '…') => {
+                    elapsed += SENTENCE_PAUSE;
+                    sentences.push(elapsed);
+                    sentence_offsets.push(offset + token.len());
+                }
+                Some(',' | ';' | ':') => elapsed += CLAUSE_PAUSE,
+                _ => {}
+            }
+        }
+        if elapsed > 0.0 {
+            for (start, _) in &mut words {
+                *start /= elapsed;
+            }
+            for start in &mut sentences {
+                *start /= elapsed;
+            }
+        }
+        Self { words, offsets, sentences, sentence_offsets, len: text.len() }
+    }
+
+    /// Start of the first word at or after byte `offset` of the text, as a share of the reading.
+    pub fn progress_at(&self, offset: usize) -> f32 {
+        let index = self.offsets.partition_point(|&start| start < offset);
+        self.words.get(index).map_or(1.0, |(start, _)| *start)
+    }
+
+    /// The word being spoken at `progress` (0.0 to 1.0) of the reading.
+    pub fn word_at(&self, progress: f32) -> Option<&str> {
+        let index = self.words.partition_point(|(start, _)| *start <= progress);
+        self.words.get(index.checked_sub(1)?).map(|(_, word)| word.as_str())
+    }
+
+    /// Start of the sentence being spoken at `progress`, as a share of the reading.
+    pub fn sentence_start(&self, progress: f32) -> f32 {
+        let index = self.sentences.partition_point(|start| *start <= progress);
+        index.checked_sub(1).map_or(0.0, |index| self.sentences[index])
+    }
+
+    /// The sentence after the one being spoken at `progress`: its start and
+    /// end as shares of the reading, and where it is in the text.
+    pub fn next_sentence(&self, progress: f32) -> Option<(f32, f32, Range<usize>)> {
+        let index = self.sentences.partition_point(|start| *start <= progress);
+        let start = *self.sentences.get(index).filter(|start| **start < 1.0)?;
+        let end = self.sentences.get(index + 1).copied().unwrap_or(1.0);
+        let range = self.sentence_offsets[index]..self.sentence_offsets.get(index + 1).copied().unwrap_or(self.len);
+        Some((start, end, range))
+    }
+}
+
+/// `word` spelled out letter by letter, e.g. "Ng" → "Ng. N, G."
+pub fn spelled(word: &str) -> String {
+    let letters: Vec<String> = word
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .map(|c| c.to_uppercase().to_string())
+        .collect();
+    format!("{word}. {}.", letters.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_at() {
+        let timings = WordTimings::new("Ask Nietzsche, then \"Wittgenstein\".");
+        assert_eq!(timings.word_at(0.0), Some("Ask"));
+        assert_eq!(timings.word_at(0.2), Some("Nietzsche"));
+        assert_eq!(timings.word_at(0.99), Some("Wittgenstein"));
+        assert_eq!(WordTimings::new("").word_at(0.5), None);
+        assert_eq!(timings.progress_at(0), 0.0);
+        assert_eq!(timings.progress_at(4), timings.progress_at(3));
+        assert_eq!(timings.word_at(timings.progress_at(20)), Some("Wittgenstein"));
+
+        assert_eq!(spelled("Tōkyō"), "Tōkyō. T, Ō, K, Y, Ō.");
+        assert_eq!(spelled("O'Neil"), "O'Neil. O, N, E, I, L.");
+    }
+
+    #[test]
+    fn test_pre_roll() {
+        // "First one." takes 6 + 4 + 6 of 32 letters, "Other one." the other 16
+        let timings = WordTimings::new("First one. Other one.");
+        assert_eq!(timings.sentence_start(0.3), 0.0);
+        assert_eq!(timings.sentence_start(0.75), 0.5);
+        assert_eq!(timings.next_sentence(0.3), Some((0.5, 1.0, 10..21)));
+        assert_eq!(timings.next_sentence(0.75), None);
+
+        assert_eq!(PreRoll::Off.rewind(Some(&timings), 0.75, 10.0), 0.0);
+        assert_eq!(PreRoll::SentenceStart.rewind(Some(&timings), 0.75, 10.0), 2.5);
+        assert_eq!(PreRoll::SentenceStart.rewind(None, 0.75, 10.0), 0.0);
+        assert_eq!(PreRoll::Seconds(2).rewind(None, 0.75, 10.0), 2.0);
+        // Never before the start of the reading
+        assert_eq!(PreRoll::Seconds(5).rewind(None, 0.1, 10.0), 1.0);
+    }
+}
@@ -0,0 +1,273 @@
+//! Reading queue scheduler
+//!
+//! Texts requested while something is already being read are queued instead of
+//! interrupting it. When an item finishes, the next one starts after a short,
+//! configurable pause so consecutive texts don't blur together. An item may
+//! carry its own engine, voice and speed (e.g. from a feed in another
+//! language), so a mixed queue is read without switching voices by hand.
+//!
+//! Waiting items can be reordered, removed or edited from the queue window;
+//! an item being edited is held back (the items after it go first) until the
+//! edit is saved or dropped.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::config;
+use crate::model::TTSBackend;
+
+/// Default pause between queued items, in seconds.
+pub const DEFAULT_QUEUE_PAUSE_SECONDS: u32 = 3;
+
+/// Pause lengths in seconds offered in the playback settings (0 = no pause).
+pub const QUEUE_PAUSE_PRESETS: [u32; 4] = [0, 1, 3, 5];
+
+/// What happens when a reading plays to the end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EndOfReading {
+    /// Start the next queued item, or close the window when nothing is queued
+    #[default]
+    CloseWindow,
+    /// Keep the window open; Play reads the text again and queued items wait for Next
+    StayOpen,
+    /// Start the next queued item, keeping the window open when nothing is queued
+    NextInQueue,
+    /// Run the hook command, then carry on as when closing the window
+    RunHook,
+    /// Start the next queued item, or hide the window to the tray (closing it without one)
+    HideToTray,
+}
+
+/// Engine, voice and speed an item is read with instead of the selected ones.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct VoiceOverride {
+    /// Engine, by its name in the config ("piper", "polly", ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub engine: Option<String>,
+    /// Voice of that engine: a Piper voice key or a Polly voice id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voice: Option<String>,
+    /// Playback speed multiplier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f32>,
+}
+
+impl VoiceOverride {
+    /// Whether nothing is overridden.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The engine to read with, if set and known.
+    pub fn backend(&self) -> Option<TTSBackend> {
+        self.engine.as_deref().and_then(config::backend_from_str)
+    }
+}
+
+/// A text waiting to be read.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct QueuedItem {
+    pub text: String,
+    /// How the item is read (empty = with the selected engine, voice and speed)
+    #[serde(default, skip_serializing_if = "VoiceOverride::is_empty")]
+    pub voice: VoiceOverride,
+    /// Where the pages of an opened PDF start, as shares of `text`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pages: Vec<f32>,
+}
+
+impl From<String> for QueuedItem {
+    fn from(text: String) -> Self {
+        Self { text, ..Default::default() }
+    }
+}
+
+/// Identifies a waiting item for as long as it is queued (indices shift as items are read).
+pub type QueuedItemId = u64;
+
+/// Pending texts plus the countdown before the next one starts.
+#[derive(Debug, Default)]
+pub struct ReadingQueue {
+    /// Items waiting to be read, in reading order
+    items: VecDeque<(QueuedItemId, QueuedItem)>,
+    /// Id given to the next item pushed
+    next_id: QueuedItemId,
+    /// Item being edited, skipped until released
+    held: Option<QueuedItemId>,
+    /// When the next item starts, if a pause is running
+    next_start: Option<Instant>,
+}
+
+impl ReadingQueue {
+    /// Add a text (or an item with its own voice) to the end of the queue.
+    pub fn push(&mut self, item: impl Into<QueuedItem>) -> QueuedItemId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.items.push_back((id, item.into()));
+        id
+    }
+
+    /// Waiting items in reading order, with their ids.
+    pub fn entries(&self) -> impl Iterator<Item = (QueuedItemId, &QueuedItem)> {
+        self.items.iter().map(|(id, item)| (*id, item))
+    }
+
+    /// Take an item out of the queue.
+    pub fn remove(&mut self, id: QueuedItemId) -> Option<QueuedItem> {
+        let index = self.index_of(id)?;
+        if self.held == Some(id) {
+            self.held = None;
+        }
+        self.items.remove(index).map(|(_, item)| item)
+    }
+
+    /// Move an item to `index` in the reading order (clamped to the end).
+    pub fn move_to(&mut self, id: QueuedItemId, index: usize) {
+        if let Some(entry) = self.index_of(id).and_then(|from| self.items.remove(from)) {
+            self.items.insert(index.min(self.items.len()), entry);
+        }
+    }
+
+    /// Replace an item's text; returns false if it is no longer queued.
+    pub fn set_text(&mut self, id: QueuedItemId, text: String) -> bool {
+        match self.items.iter_mut().find(|(other, _)| *other == id) {
+            Some((_, item)) => {
+                item.text = text;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Hold an item back while it is edited (None releases it).
+    pub fn hold(&mut self, id: Option<QueuedItemId>) {
+        self.held = id;
+    }
+
+    fn index_of(&self, id: QueuedItemId) -> Option<usize> {
+        self.items.iter().position(|(other, _)| *other == id)
+    }
+
+    /// Index of the next item to read: the first one not held back.
+    fn next_index(&self) -> Option<usize> {
+        self.items.iter().position(|(id, _)| Some(*id) != self.held)
+    }
+
+    /// Number of texts waiting.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Drop all waiting texts and cancel any running pause.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.held = None;
+        self.next_start = None;
+    }
+
+    /// Whether the pause between two items is running.
+    pub fn is_waiting(&self) -> bool {
+        self.next_start.is_some()
+    }
+
+    /// Time left before the next item starts, if a pause is running.
+    pub fn countdown(&self, now: Instant) -> Option<Duration> {
+        self.next_start.map(|start| start.saturating_duration_since(now))
+    }
+
+    /// The current item finished playing.
+    ///
+    /// Starts the pause if more texts are waiting, or returns the next item
+    /// straight away when `pause` is zero.
+    pub fn item_finished(&mut self, pause: Duration, now: Instant) -> Option<QueuedItem> {
+        self.next_index()?;
+        if pause.is_zero() {
+            return self.take_next();
+        }
+        self.next_start = Some(now + pause);
+        None
+    }
+
+    /// Return the next item once the pause has elapsed.
+    pub fn poll(&mut self, now: Instant) -> Option<QueuedItem> {
+        match self.next_start {
+            Some(start) if now >= start => self.skip_pause(),
+            _ => None,
+        }
+    }
+
+    /// End the pause early and return the next item.
+    pub fn skip_pause(&mut self) -> Option<QueuedItem> {
+        self.next_start = None;
+        self.take_next()
+    }
+
+    /// Remove and return the next item without touching the pause.
+    pub fn take_next(&mut self) -> Option<QueuedItem> {
+        let index = self.next_index()?;
+        self.items.remove(index).map(|(_, item)| item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_between_items() {
+        let now = Instant::now();
+        let mut queue = ReadingQueue::default();
+        queue.push("first".to_string());
+        queue.push("second".to_string());
+
+        assert_eq!(queue.item_finished(Duration::from_secs(3), now), None);
+        assert!(queue.is_waiting());
+        assert_eq!(queue.countdown(now + Duration::from_secs(1)), Some(Duration::from_secs(2)));
+        assert_eq!(queue.poll(now + Duration::from_secs(2)), None);
+        assert_eq!(queue.poll(now + Duration::from_secs(3)).map(|item| item.text).as_deref(), Some("first"));
+        assert!(!queue.is_waiting());
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn test_skip_and_zero_pause() {
+        let now = Instant::now();
+        let mut queue = ReadingQueue::default();
+        assert_eq!(queue.item_finished(Duration::from_secs(3), now), None);
+        assert!(!queue.is_waiting());
+
+        queue.push("first".to_string());
+        let voice = VoiceOverride { engine: Some("piper".into()), voice: Some("pt_BR-faber-medium".into()), speed: None };
+        queue.push(QueuedItem { text: "second".into(), voice: voice.clone(), ..Default::default() });
+        assert_eq!(queue.item_finished(Duration::ZERO, now).map(|item| item.text).as_deref(), Some("first"));
+        queue.item_finished(Duration::from_secs(3), now);
+        // Items keep their own voice
+        assert_eq!(
+            queue.skip_pause(),
+            Some(QueuedItem { text: "second".into(), voice: voice.clone(), ..Default::default() })
+        );
+        assert_eq!(voice.backend(), Some(TTSBackend::Piper));
+        assert_eq!(queue.len(), 0);
+    }
+
+    #[test]
+    fn test_reorder_remove_and_hold() {
+        let mut queue = ReadingQueue::default();
+        let ids: Vec<QueuedItemId> = ["a", "b", "c", "d"].map(|text| queue.push(text.to_string())).to_vec();
+        let texts = |queue: &ReadingQueue| queue.entries().map(|(_, item)| item.text.clone()).collect::<Vec<_>>();
+
+        queue.move_to(ids[3], 0);
+        queue.move_to(ids[0], 10);
+        assert_eq!(texts(&queue), ["d", "b", "c", "a"]);
+        assert_eq!(queue.remove(ids[2]).map(|item| item.text).as_deref(), Some("c"));
+        assert_eq!(queue.remove(ids[2]), None);
+
+        // The held item keeps its place while the others are read
+        queue.hold(Some(ids[3]));
+        assert!(queue.set_text(ids[3], "d, edited".to_string()));
+        assert_eq!(queue.take_next().map(|item| item.text).as_deref(), Some("b"));
+        queue.hold(None);
+        assert_eq!(queue.take_next().map(|item| item.text).as_deref(), Some("d, edited"));
+        assert!(!queue.set_text(ids[3], "gone".to_string()));
+    }
+}
@@ -0,0 +1,116 @@
+//! Headless self-test (`--self-test`)
+//!
+//! Synthesizes a short phrase with the configured provider and voice, checks
+//! that real audio came out and plays the first second of it, without opening
+//! any window. The exit status tells setup scripts what failed.
+
+use std::time::{Duration, Instant};
+
+use crate::config;
+use crate::providers;
+use crate::update::create_provider;
+
+/// Phrase synthesized by the self-test.
+const TEST_PHRASE: &str = "Insight Reader self test.";
+
+/// How long synthesis may take before the test gives up.
+const SYNTHESIS_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// How much of the audio is played.
+const PLAYBACK_DURATION: Duration = Duration::from_secs(1);
+
+/// Samples quieter than this count as silence.
+const SILENCE_THRESHOLD: f32 = 1e-3;
+
+/// Exit status: the provider could not be created.
+const EXIT_PROVIDER: i32 = 2;
+/// Exit status: synthesis failed or produced silence.
+const EXIT_SYNTHESIS: i32 = 3;
+/// Exit status: the audio could not be played.
+const EXIT_PLAYBACK: i32 = 4;
+
+/// Whether `samples` hold something louder than silence.
+fn is_audible(samples: &[f32]) -> bool {
+    samples.iter().any(|sample| sample.abs() > SILENCE_THRESHOLD)
+}
+
+fn fail(code: i32, message: impl std::fmt::Display) -> i32 {
+    eprintln!("FAIL: {message}");
+    code
+}
+
+/// Run the self-test and return the process exit status.
+///
+/// With `silent` the audio is checked but not played.
+pub fn run(silent: bool) -> i32 {
+    let backend = config::load_voice_provider();
+    println!("Provider: {backend:?}");
+
+    let mut provider = match create_provider(backend, None) {
+        Ok(provider) => provider,
+        Err(e) => return fail(EXIT_PROVIDER, format!("could not create the provider: {e}")),
+    };
+
+    // Providers play as soon as audio is ready: keep a silent run silent until it's stopped
+    let mut output = config::load_output_settings();
+    if silent {
+        output.volume = 0.0;
+        output.mirror_device = None;
+        output.virtual_mic = false;
+    }
+    if let Err(e) = provider.set_output(&output) {
+        return fail(EXIT_PLAYBACK, format!("could not open the audio output: {e}"));
+    }
+
+    let started = Instant::now();
+    if let Err(e) = providers::speak_blocking(provider.as_mut(), TEST_PHRASE) {
+        return fail(EXIT_SYNTHESIS, format!("synthesis failed: {e}"));
+    }
+    let audio = loop {
+        if let Some(audio) = provider.synthesized_audio() {
+            break audio;
+        }
+        if started.elapsed() > SYNTHESIS_TIMEOUT {
+            let _ = provider.stop();
+            return fail(EXIT_SYNTHESIS, format!("no audio after {} seconds", SYNTHESIS_TIMEOUT.as_secs()));
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+    let seconds = audio.samples.len() as f32 / audio.sample_rate.max(1) as f32;
+    println!(
+        "Synthesized {seconds:.2} s of audio at {} Hz in {:.2} s",
+        audio.sample_rate,
+        started.elapsed().as_secs_f32()
+    );
+    if !is_audible(&audio.samples) {
+        let _ = provider.stop();
+        return fail(EXIT_SYNTHESIS, "the synthesized audio is empty or silent");
+    }
+
+    if !silent {
+        let playing_since = Instant::now();
+        while playing_since.elapsed() < PLAYBACK_DURATION && provider.is_playing() {
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        if playing_since.elapsed() < PLAYBACK_DURATION && audio.samples.len() as f32 > audio.sample_rate as f32 {
+            return fail(EXIT_PLAYBACK, "playback stopped early");
+        }
+        println!("Played {:.1} s", playing_since.elapsed().as_secs_f32());
+    }
+    let _ = provider.stop();
+
+    println!("OK");
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_audible() {
+        assert!(!is_audible(&[]));
+        assert!(!is_audible(&[0.0, 0.0005, -0.0005]));
+        assert!(is_audible(&[0.0, -0.2, 0.0]));
+    }
+}
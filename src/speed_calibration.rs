@@ -0,0 +1,161 @@
+//! Reading speed calibration
+//!
+//! A short wizard in the playback settings: sample sentences are read at
+//! increasing speeds until the user picks the fastest one they still follow
+//! comfortably. The pick becomes the default speed or, for voices of another
+//! language (people follow a language that isn't their own at lower speeds),
+//! an adjustment applied to the default whenever a voice of that language
+//! reads.
+
+use std::collections::HashMap;
+
+/// Speeds the samples are read at, slowest first.
+pub const CALIBRATION_SPEEDS: [f32; 8] = [1.0, 1.25, 1.5, 1.75, 2.0, 2.25, 2.5, 3.0];
+
+/// Sample sentences by language family; English for languages without any.
+const SAMPLES: &[(&str, [&str; 3])] = &[
+    (
+        "en",
+        [
+            "The library opens at nine, and the reading room fills up quickly on rainy mornings.",
+            "After a long walk along the coast, we stopped at a small café for soup and fresh bread.",
+            "Scientists have found that short breaks during work help people remember what they learned.",
+        ],
+    ),
+    (
+        "de",
+        [
+            "Die Bibliothek öffnet um neun, und an regnerischen Tagen ist der Lesesaal schnell voll.",
+            "Nach einem langen Spaziergang an der Küste machten wir in einem kleinen Café Pause.",
+            "Kurze Pausen bei der Arbeit helfen dabei, sich das Gelernte besser zu merken.",
+        ],
+    ),
+    (
+        "fr",
+        [
+            "La bibliothèque ouvre à neuf heures, et la salle de lecture se remplit vite les jours de pluie.",
+            "Après une longue promenade le long de la côte, nous nous sommes arrêtés dans un petit café.",
+            "De courtes pauses pendant le travail aident à retenir ce que l'on a appris.",
+        ],
+    ),
+    (
+        "es",
+        [
+            "La biblioteca abre a las nueve, y la sala de lectura se llena rápido los días de lluvia.",
+            "Después de un largo paseo por la costa, paramos en un pequeño café a tomar sopa.",
+            "Las pausas cortas durante el trabajo ayudan a recordar lo que se ha aprendido.",
+        ],
+    ),
+    (
+        "it",
+        [
+            "La biblioteca apre alle nove, e nei giorni di pioggia la sala di lettura si riempie in fretta.",
+            "Dopo una lunga passeggiata lungo la costa, ci siamo fermati in un piccolo caffè.",
+            "Brevi pause durante il lavoro aiutano a ricordare quello che si è imparato.",
+        ],
+    ),
+    (
+        "pt",
+        [
+            "A biblioteca abre às nove, e a sala de leitura enche depressa nos dias de chuva.",
+            "Depois de um longo passeio pela costa, paramos num pequeno café para comer uma sopa.",
+            "Pausas curtas durante o trabalho ajudam a lembrar o que se aprendeu.",
+        ],
+    ),
+    (
+        "nl",
+        [
+            "De bibliotheek gaat om negen uur open, en op regenachtige dagen zit de leeszaal snel vol.",
+            "Na een lange wandeling langs de kust stopten we bij een klein café voor soep en brood.",
+            "Korte pauzes tijdens het werk helpen om beter te onthouden wat je hebt geleerd.",
+        ],
+    ),
+];
+
+/// Adjustments closer to 1.0 than this are dropped (the default speed already fits).
+const MIN_ADJUSTMENT: f32 = 0.05;
+
+/// A calibration in progress.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeedCalibration {
+    /// Language family of the voice being calibrated ("de")
+    pub language: String,
+    /// Index into `CALIBRATION_SPEEDS` of the speed being tried
+    pub step: usize,
+    /// Whether the pick becomes the default speed (else an adjustment for `language`)
+    pub make_default: bool,
+}
+
+impl SpeedCalibration {
+    /// Start at the slowest speed.
+    pub fn new(language: impl Into<String>, make_default: bool) -> Self {
+        Self {
+            language: language.into(),
+            step: 0,
+            make_default,
+        }
+    }
+
+    /// Speed being tried.
+    pub fn speed(&self) -> f32 {
+        CALIBRATION_SPEEDS[self.step]
+    }
+
+    /// Try the next speed; false if this is the fastest.
+    pub fn faster(&mut self) -> bool {
+        if self.step + 1 >= CALIBRATION_SPEEDS.len() {
+            return false;
+        }
+        self.step += 1;
+        true
+    }
+
+    /// Sentence read at the current speed (a different one at each step).
+    pub fn sample(&self) -> &'static str {
+        let samples = SAMPLES
+            .iter()
+            .find(|(family, _)| *family == self.language)
+            .unwrap_or(&SAMPLES[0])
+            .1;
+        samples[self.step % samples.len()]
+    }
+}
+
+/// Speed for a voice of `language`: the `default` speed with that language's adjustment.
+pub fn language_speed(default: f32, adjustments: &HashMap<String, f32>, language: Option<&str>) -> f32 {
+    let adjustment = language.and_then(|language| adjustments.get(language)).copied().unwrap_or(1.0);
+    // Same range as the saved default speed
+    (default * adjustment).clamp(0.5, 3.0)
+}
+
+/// Record `picked` as the speed for `language` when the default is `default`.
+pub fn set_adjustment(adjustments: &mut HashMap<String, f32>, language: &str, picked: f32, default: f32) {
+    let adjustment = picked / default;
+    if (adjustment - 1.0).abs() < MIN_ADJUSTMENT {
+        adjustments.remove(language);
+    } else {
+        adjustments.insert(language.to_string(), adjustment);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibration() {
+        let mut calibration = SpeedCalibration::new("de", false);
+        assert!(calibration.sample().starts_with("Die Bibliothek"));
+        while calibration.faster() {}
+        assert_eq!(calibration.speed(), 3.0);
+        assert!(SpeedCalibration::new("ja", true).sample().starts_with("The library"));
+
+        let mut adjustments = HashMap::new();
+        set_adjustment(&mut adjustments, "de", 1.5, 2.0);
+        assert_eq!(language_speed(2.0, &adjustments, Some("de")), 1.5);
+        assert_eq!(language_speed(2.0, &adjustments, Some("fr")), 2.0);
+        assert_eq!(language_speed(2.0, &adjustments, None), 2.0);
+        set_adjustment(&mut adjustments, "de", 2.0, 2.0);
+        assert!(adjustments.is_empty());
+    }
+}
@@ -0,0 +1,463 @@
+//! Where voice models and caches are stored
+//!
+//! By default models live in the user's data folder and caches in the user's
+//! cache folder. A storage folder can be configured instead (e.g. on a
+//! secondary drive); models then go to its `models` folder and caches to its
+//! `cache` folder. Changing it moves the existing files over.
+//!
+//! For lab and school deployments an administrator can also provision a
+//! read-only system-wide install (models, the Piper venv, default settings).
+//! It is searched after the user's own folders and never written to.
+//!
+//! A storage folder may be shared by several users or processes, so writes
+//! into it take a [`lock`] and go through [`write_atomic`]: a reader never
+//! sees a half-written file and two downloads of the same voice don't
+//! interleave.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tracing::{debug, info};
+
+use crate::config;
+
+/// Environment variable pointing at a system-wide install, replacing the platform defaults.
+pub const SYSTEM_DIR_ENV: &str = "INSIGHT_READER_SYSTEM_DIR";
+
+/// Folders a system-wide install may live in, most specific first.
+pub fn system_dirs() -> Vec<PathBuf> {
+    if let Some(dir) = std::env::var_os(SYSTEM_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        return vec![PathBuf::from(dir)];
+    }
+    #[cfg(target_os = "windows")]
+    {
+        std::env::var_os("ProgramData")
+            .map(|dir| PathBuf::from(dir).join("insight-reader"))
+            .into_iter()
+            .collect()
+    }
+    #[cfg(target_os = "macos")]
+    {
+        vec![PathBuf::from("/Library/Application Support/insight-reader")]
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        vec![
+            PathBuf::from("/usr/local/share/insight-reader"),
+            PathBuf::from("/usr/share/insight-reader"),
+        ]
+    }
+}
+
+/// Models folders of the system-wide install.
+pub fn system_models_dirs() -> Vec<PathBuf> {
+    system_dirs().into_iter().map(|dir| dir.join("models")).collect()
+}
+
+/// First existing `relative_path` inside a system-wide install.
+pub fn find_system_file(relative_path: &Path) -> Option<PathBuf> {
+    system_dirs()
+        .into_iter()
+        .map(|dir| dir.join(relative_path))
+        .find(|path| path.exists())
+}
+
+/// Models folder used when no storage folder is configured.
+pub fn default_models_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("insight-reader")
+        .join("models")
+}
+
+/// Cache folder used when no storage folder is configured.
+pub fn default_cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("insight-reader")
+}
+
+/// Models folder inside the storage folder `root`, or the default one.
+pub fn models_dir_in(root: Option<&Path>) -> PathBuf {
+    root.map_or_else(default_models_dir, |root| root.join("models"))
+}
+
+/// Cache folder inside the storage folder `root`, or the default one.
+fn cache_dir_in(root: Option<&Path>) -> PathBuf {
+    root.map_or_else(default_cache_dir, |root| root.join("cache"))
+}
+
+/// Folder voice models are installed to.
+pub fn models_dir() -> PathBuf {
+    models_dir_in(config::load_storage_folder().as_deref())
+}
+
+/// Folder caches are written to.
+pub fn cache_dir() -> PathBuf {
+    cache_dir_in(config::load_storage_folder().as_deref())
+}
+
+/// Private folder `name` in the user's own cache folder, created readable only by the user.
+///
+/// Private folders hold files other users must not read (captures, text being read), so they
+/// are never moved to the storage folder and never fall back to the shared temp folder.
+pub fn private_dir(name: &str) -> io::Result<PathBuf> {
+    create_private_dir(dirs::cache_dir(), "cache", name)
+}
+
+/// Private folder `name` in the user's own data folder, for private files kept across cache cleanups.
+pub fn private_data_dir(name: &str) -> io::Result<PathBuf> {
+    create_private_dir(dirs::data_local_dir(), "data", name)
+}
+
+/// Create the folder `name` for this app in the user's folder `base` (a `kind` folder), readable only by the user.
+fn create_private_dir(base: Option<PathBuf>, kind: &str, name: &str) -> io::Result<PathBuf> {
+    let dir = base
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("no {kind} folder for this user")))?
+        .join("insight-reader")
+        .join(name);
+    fs::create_dir_all(&dir)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))?;
+    }
+    Ok(dir)
+}
+
+/// Create a file at `path` readable only by the user; fails if anything, a symlink included, is there.
+pub fn create_private_file(path: &Path) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)
+}
+
+/// Exclusive lock on a shared folder entry, released when dropped.
+pub struct StorageLock {
+    _file: File,
+}
+
+/// Wait for the exclusive lock `name` in `dir`, shared with other users and processes.
+///
+/// The lock file (`.<name>.lock`) is left in place; removing it would race with the next locker.
+pub fn lock(dir: &Path, name: &str) -> io::Result<StorageLock> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!(".{name}.lock"));
+    let file = OpenOptions::new().create(true).truncate(false).write(true).open(&path)?;
+    file.lock()?;
+    debug!(path = %path.display(), "Storage lock acquired");
+    Ok(StorageLock { _file: file })
+}
+
+/// Write `contents` to `path` so readers see either the old file or all of the new one.
+///
+/// The data goes to a temporary file next to `path`, which is then renamed over it.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    static NEXT_TEMP: AtomicU64 = AtomicU64::new(0);
+    let name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no file name"))?;
+    let temp = path.with_file_name(format!(
+        ".{}.{}-{}.tmp",
+        name.to_string_lossy(),
+        std::process::id(),
+        NEXT_TEMP.fetch_add(1, Ordering::Relaxed)
+    ));
+    let result = File::create(&temp)
+        .and_then(|mut file| file.write_all(contents).and_then(|()| file.sync_all()))
+        .and_then(|()| fs::rename(&temp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+/// Move the file `source` to `target`: renamed when possible, copied then deleted across drives.
+fn move_file(source: &Path, target: &Path) -> io::Result<()> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if fs::rename(source, target).is_err() {
+        fs::copy(source, target)?;
+        fs::remove_file(source)?;
+    }
+    Ok(())
+}
+
+/// Whether the files `a` and `b` hold the same bytes.
+fn same_contents(a: &Path, b: &Path) -> io::Result<bool> {
+    if fs::metadata(a)?.len() != fs::metadata(b)?.len() {
+        return Ok(false);
+    }
+    let (mut a, mut b) = (File::open(a)?, File::open(b)?);
+    let (mut chunk_a, mut chunk_b) = (vec![0; 64 * 1024], vec![0; 64 * 1024]);
+    loop {
+        let read = a.read(&mut chunk_a)?;
+        if read == 0 {
+            return Ok(true);
+        }
+        b.read_exact(&mut chunk_b[..read])?;
+        if chunk_a[..read] != chunk_b[..read] {
+            return Ok(false);
+        }
+    }
+}
+
+/// Folders of the cache that are never moved: the [`private_dir`]s stay in the user's own cache folder.
+const UNMOVED_CACHE_DIRS: &[&str] = &["captures", "command"];
+
+/// What a storage move has done so far, so a failed move can be undone.
+#[derive(Default)]
+struct Moved {
+    /// Files moved (source, target)
+    files: Vec<(PathBuf, PathBuf)>,
+    /// Folders created in the new location, parents first
+    dirs: Vec<PathBuf>,
+}
+
+/// Check that the files of `from` can be moved into `to` without replacing anything there.
+///
+/// A file already in `to` with the same bytes is added to `duplicates` (the copy in `from`);
+/// any other file or folder in the way is an error.
+fn check_targets(from: &Path, to: &Path, skip: &[&str], duplicates: &mut Vec<PathBuf>) -> io::Result<()> {
+    let already_exists =
+        |path: &Path| io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", path.display()));
+    if !from.is_dir() || !to.exists() {
+        return Ok(());
+    }
+    if !to.is_dir() {
+        return Err(already_exists(to));
+    }
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        if skip.iter().any(|name| entry.file_name() == *name) {
+            continue;
+        }
+        let source = entry.path();
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            check_targets(&source, &target, &[], duplicates)?;
+        } else if target.exists() {
+            if !target.is_file() || !same_contents(&source, &target)? {
+                return Err(already_exists(&target));
+            }
+            duplicates.push(source);
+        }
+    }
+    Ok(())
+}
+
+/// Create the folder `dir` and its missing parents, adding each one created to `created`.
+fn create_dirs(dir: &Path, created: &mut Vec<PathBuf>) -> io::Result<()> {
+    if dir.as_os_str().is_empty() || dir.is_dir() {
+        return Ok(());
+    }
+    if let Some(parent) = dir.parent() {
+        create_dirs(parent, created)?;
+    }
+    fs::create_dir(dir)?;
+    created.push(dir.to_path_buf());
+    Ok(())
+}
+
+/// Move the files of `from` into `to`, merging with what's there, except the entries named in `skip`.
+///
+/// Files already in `to` are left alone: [`check_targets`] made sure they are the same.
+fn move_tree(from: &Path, to: &Path, skip: &[&str], moved: &mut Moved) -> io::Result<()> {
+    if !from.is_dir() {
+        return Ok(());
+    }
+    create_dirs(to, &mut moved.dirs)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        if skip.iter().any(|name| entry.file_name() == *name) {
+            continue;
+        }
+        let source = entry.path();
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            move_tree(&source, &target, &[], moved)?;
+            continue;
+        }
+        if target.exists() {
+            continue;
+        }
+        move_file(&source, &target)?;
+        moved.files.push((source, target));
+    }
+    // Only removed once empty; leftovers (e.g. files in use) are kept
+    let _ = fs::remove_dir(from);
+    Ok(())
+}
+
+/// Move models and caches from the storage folder `from` to `to` (None = default locations).
+///
+/// Returns the number of files moved. The caller saves the new folder once this succeeds.
+/// Nothing is moved when a different file of the same name is already in the new folder;
+/// a copy with the same bytes is kept and the old one deleted. If a file can't be moved,
+/// the files already moved are put back; the error names any that couldn't be, and where they are.
+pub fn migrate_storage(from: Option<&Path>, to: Option<&Path>) -> Result<usize, String> {
+    let mut folders = Vec::new();
+    let mut duplicates = Vec::new();
+    for (source, target, skip) in [
+        (models_dir_in(from), models_dir_in(to), &[][..]),
+        (cache_dir_in(from), cache_dir_in(to), UNMOVED_CACHE_DIRS),
+    ] {
+        if source == target {
+            continue;
+        }
+        if target.starts_with(&source) {
+            return Err(format!("{} is inside {}", target.display(), source.display()));
+        }
+        let mut found = Vec::new();
+        if let Err(e) = check_targets(&source, &target, skip, &mut found) {
+            let error = format!("Could not move {} to {}: {e}", source.display(), target.display());
+            return Err(format!("{error}. Nothing was moved."));
+        }
+        duplicates.extend(found.into_iter().map(|file| (source.clone(), file)));
+        folders.push((source, target, skip));
+    }
+
+    let mut moved = Moved::default();
+    for (source, target, skip) in folders {
+        debug!(from = %source.display(), to = %target.display(), "Moving storage folder");
+        if let Err(e) = move_tree(&source, &target, skip, &mut moved) {
+            let error = format!("Could not move {} to {}: {e}", source.display(), target.display());
+            return Err(roll_back(moved, error));
+        }
+    }
+    // The new folder already had these files: drop the old copies, and the folders they leave empty
+    for (root, file) in &duplicates {
+        let _ = fs::remove_file(file);
+        for dir in file.ancestors().skip(1).take_while(|dir| dir.starts_with(root)) {
+            if fs::remove_dir(dir).is_err() {
+                break;
+            }
+        }
+    }
+    info!(files = moved.files.len(), duplicates = duplicates.len(), to = ?to, "Storage moved");
+    Ok(moved.files.len())
+}
+
+/// Put the files of a failed move back and remove the folders it created.
+///
+/// Returns `error`, with the files left in the new folder if any.
+fn roll_back(moved: Moved, error: String) -> String {
+    let stranded: Vec<PathBuf> = moved
+        .files
+        .into_iter()
+        .rev()
+        .filter_map(|(source, target)| move_file(&target, &source).err().map(|_| target))
+        .collect();
+    // Only empty folders are removed, so any holding stranded files stay
+    for dir in moved.dirs.iter().rev() {
+        let _ = fs::remove_dir(dir);
+    }
+    if stranded.is_empty() {
+        info!("Storage move rolled back");
+        return format!("{error}. Nothing was moved.");
+    }
+    let list: Vec<String> = stranded.iter().map(|path| path.display().to_string()).collect();
+    format!("{error}. These files could not be moved back and are still in the new folder: {}", list.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_storage() {
+        let root = std::env::temp_dir().join(format!("insight-reader-storage-test-{}", std::process::id()));
+        let (old, new) = (root.join("old"), root.join("new"));
+        fs::create_dir_all(old.join("models")).unwrap();
+        fs::create_dir_all(old.join("cache").join("audio")).unwrap();
+        fs::write(old.join("models").join("voice.onnx"), b"model").unwrap();
+        fs::write(old.join("cache").join("audio").join("a.pcm"), b"pcm").unwrap();
+        fs::create_dir_all(old.join("cache").join("captures")).unwrap();
+        fs::write(old.join("cache").join("captures").join("capture.png"), b"png").unwrap();
+        fs::create_dir_all(new.join("models")).unwrap();
+        fs::write(new.join("models").join("other.onnx"), b"other").unwrap();
+
+        assert_eq!(migrate_storage(Some(&old), Some(&new)).unwrap(), 2);
+        assert_eq!(fs::read(new.join("models").join("voice.onnx")).unwrap(), b"model");
+        assert!(new.join("models").join("other.onnx").exists());
+        assert!(new.join("cache").join("audio").join("a.pcm").exists());
+        assert!(!old.join("models").exists());
+        // Captures stay private
+        assert!(old.join("cache").join("captures").join("capture.png").exists());
+        assert!(!new.join("cache").join("captures").exists());
+
+        assert!(migrate_storage(Some(&new), Some(&new.join("models").join("nested"))).is_err());
+
+        // A file in the way of a folder stops the move before anything is moved
+        let blocked = root.join("blocked");
+        fs::create_dir_all(blocked.join("cache")).unwrap();
+        fs::write(blocked.join("cache").join("audio"), b"not a folder").unwrap();
+        let error = migrate_storage(Some(&new), Some(&blocked)).unwrap_err();
+        assert!(error.ends_with("Nothing was moved."), "{error}");
+        assert!(new.join("models").join("voice.onnx").exists());
+        assert!(new.join("cache").join("audio").join("a.pcm").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_migrate_storage_same_named_files() {
+        let root = std::env::temp_dir().join(format!("insight-reader-storage-same-test-{}", std::process::id()));
+        let (old, new) = (root.join("old"), root.join("new"));
+        fs::create_dir_all(old.join("models")).unwrap();
+        fs::create_dir_all(new.join("models")).unwrap();
+        fs::write(old.join("models").join("voice.onnx"), b"old model").unwrap();
+        fs::write(new.join("models").join("voice.onnx"), b"new model").unwrap();
+        fs::create_dir_all(old.join("cache").join("audio")).unwrap();
+        fs::write(old.join("cache").join("audio").join("a.pcm"), b"pcm").unwrap();
+
+        // A different file of the same name: nothing is touched, not even the folders to create
+        let error = migrate_storage(Some(&old), Some(&new)).unwrap_err();
+        assert!(error.contains("already exists") && error.ends_with("Nothing was moved."), "{error}");
+        assert_eq!(fs::read(old.join("models").join("voice.onnx")).unwrap(), b"old model");
+        assert_eq!(fs::read(new.join("models").join("voice.onnx")).unwrap(), b"new model");
+        assert!(old.join("cache").join("audio").join("a.pcm").exists());
+        assert!(!new.join("cache").exists());
+
+        // The same file: the new copy is kept and the old one deleted
+        fs::write(old.join("models").join("voice.onnx"), b"new model").unwrap();
+        assert_eq!(migrate_storage(Some(&old), Some(&new)).unwrap(), 1);
+        assert_eq!(fs::read(new.join("models").join("voice.onnx")).unwrap(), b"new model");
+        assert!(new.join("cache").join("audio").join("a.pcm").exists());
+        assert!(!old.join("models").exists());
+
+        // Rolling back removes the folders the move created
+        let mut moved = Moved::default();
+        move_tree(&new.join("cache"), &root.join("other").join("cache"), &[], &mut moved).unwrap();
+        assert!(roll_back(moved, "failed".to_string()).ends_with("Nothing was moved."));
+        assert!(new.join("cache").join("audio").join("a.pcm").exists());
+        assert!(!root.join("other").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_write_atomic_and_lock() {
+        let root = std::env::temp_dir().join(format!("insight-reader-atomic-test-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let path = root.join("voice.onnx");
+        write_atomic(&path, b"first").unwrap();
+        write_atomic(&path, b"second").unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"second");
+
+        let held = lock(&root, "voice").unwrap();
+        assert!(File::open(root.join(".voice.lock")).unwrap().try_lock().is_err());
+        drop(held);
+        assert!(lock(&root, "voice").is_ok());
+
+        // Only the file and its lock are left: no temporary files
+        assert_eq!(fs::read_dir(&root).unwrap().count(), 2);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
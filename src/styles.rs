@@ -1,7 +1,8 @@
 //! Custom style functions for UI components (Iced 0.13+ closure-based API)
 
-use iced::widget::{button, checkbox, container, radio};
-use iced::{Background, Border, Color, Theme};
+use iced::overlay::menu;
+use iced::widget::{button, checkbox, container, pick_list, radio, text_input};
+use iced::{Background, Border, Color, Shadow, Theme};
 
 pub fn window_style(_theme: &Theme) -> container::Style {
     container::Style {
@@ -159,3 +160,60 @@ pub fn white_checkbox_style(_theme: &Theme, status: checkbox::Status) -> checkbo
         text_color: Some(Color::WHITE),
     }
 }
+
+/// Dark text input style with white text and a highlighted border when focused
+pub fn dark_text_input_style(_theme: &Theme, status: text_input::Status) -> text_input::Style {
+    let border_color = match status {
+        text_input::Status::Focused { .. } => Color::from_rgb(0.4, 0.6, 1.0),
+        text_input::Status::Hovered => Color::from_rgba(1.0, 1.0, 1.0, 0.5),
+        _ => Color::from_rgba(1.0, 1.0, 1.0, 0.3),
+    };
+    text_input::Style {
+        background: Background::Color(Color::from_rgb(0.1, 0.1, 0.12)),
+        border: Border {
+            color: border_color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        icon: Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        placeholder: Color::from_rgba(1.0, 1.0, 1.0, 0.4),
+        value: Color::WHITE,
+        selection: Color::from_rgba(0.4, 0.6, 1.0, 0.5),
+    }
+}
+
+/// Dark pick list style matching `dark_text_input_style`
+pub fn dark_pick_list_style(_theme: &Theme, status: pick_list::Status) -> pick_list::Style {
+    let border_color = match status {
+        pick_list::Status::Opened { .. } => Color::from_rgb(0.4, 0.6, 1.0),
+        pick_list::Status::Hovered => Color::from_rgba(1.0, 1.0, 1.0, 0.5),
+        _ => Color::from_rgba(1.0, 1.0, 1.0, 0.3),
+    };
+    pick_list::Style {
+        text_color: Color::WHITE,
+        placeholder_color: Color::from_rgba(1.0, 1.0, 1.0, 0.4),
+        handle_color: Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        background: Background::Color(Color::from_rgb(0.1, 0.1, 0.12)),
+        border: Border {
+            color: border_color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+    }
+}
+
+/// Dark dropdown menu style for pick lists
+pub fn dark_menu_style(_theme: &Theme) -> menu::Style {
+    menu::Style {
+        background: Background::Color(Color::from_rgb(0.1, 0.1, 0.12)),
+        border: Border {
+            color: Color::from_rgba(1.0, 1.0, 1.0, 0.3),
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        text_color: Color::WHITE,
+        selected_text_color: Color::WHITE,
+        selected_background: Background::Color(Color::from_rgba(0.4, 0.6, 1.0, 0.5)),
+        shadow: Shadow::default(),
+    }
+}
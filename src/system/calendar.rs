@@ -0,0 +1,546 @@
+//! Calendar agenda readout
+//!
+//! Reads today's events from a local `.ics` file, an iCalendar URL
+//! (`webcal://` or `https://`) or a CalDAV calendar, and turns them into a
+//! short spoken summary ("10am: Standup. 1pm: Dentist."). Recurring events
+//! are expanded for the common RRULE forms (daily, weekly, monthly, yearly
+//! with INTERVAL, BYDAY, BYMONTHDAY, COUNT and UNTIL). Times with a TZID are
+//! taken as local time, since there is no time zone database here.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{Datelike, Days, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc, Weekday};
+use tracing::{debug, info};
+
+/// Timeout for fetching a remote calendar.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Longest span a COUNT-limited rule is expanded over.
+const MAX_RECURRENCE_DAYS: u64 = 366 * 20;
+
+/// Where the agenda comes from.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct CalendarSettings {
+    /// Path to an .ics file, or an iCalendar / CalDAV URL
+    pub source: String,
+    /// User name for a protected URL (empty = no authentication)
+    pub username: String,
+    /// Password or app token for a protected URL
+    pub password: String,
+}
+
+impl CalendarSettings {
+    /// Whether a calendar has been set up.
+    pub fn is_configured(&self) -> bool {
+        !self.source.trim().is_empty()
+    }
+}
+
+/// When an event starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EventStart {
+    /// All-day event
+    Date(NaiveDate),
+    /// Timed event, in local time
+    DateTime(NaiveDateTime),
+}
+
+impl EventStart {
+    fn date(self) -> NaiveDate {
+        match self {
+            EventStart::Date(date) => date,
+            EventStart::DateTime(date_time) => date_time.date(),
+        }
+    }
+}
+
+/// Supported parts of an RRULE.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Recurrence {
+    freq: String,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<NaiveDate>,
+    /// BYDAY entries with their optional ordinal (e.g. "2TU", "-1FR")
+    by_day: Vec<(Option<i32>, Weekday)>,
+    by_month_day: Vec<i32>,
+}
+
+/// One VEVENT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CalendarEvent {
+    uid: String,
+    summary: String,
+    location: String,
+    start: EventStart,
+    /// Exclusive end date of an all-day event
+    end_date: Option<NaiveDate>,
+    rule: Option<Recurrence>,
+    excluded: Vec<NaiveDate>,
+    /// Occurrence of a recurring event this one replaces
+    recurrence_id: Option<NaiveDate>,
+}
+
+/// An event happening on the requested day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AgendaItem {
+    /// Start time (None = all day)
+    time: Option<NaiveTime>,
+    summary: String,
+    location: String,
+}
+
+/// Split a content line into name, parameters and value, honoring quoted parameters.
+fn split_property(line: &str) -> Option<(String, &str, &str)> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let (name, params) = head.split_once(';').unwrap_or((head, ""));
+    Some((name.to_ascii_uppercase(), params, value))
+}
+
+/// Undo iCalendar TEXT escaping, with line breaks read as spaces.
+fn unescape_text(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => result.push(' '),
+            Some(other) => result.push(other),
+            None => {}
+        }
+    }
+    result.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parse a DATE or DATE-TIME value; UTC times are converted to local time.
+fn parse_date_value(value: &str) -> Option<EventStart> {
+    let value = value.trim();
+    if value.len() == 8 {
+        return NaiveDate::parse_from_str(value, "%Y%m%d").ok().map(EventStart::Date);
+    }
+    let (local, utc) = match value.strip_suffix(['Z', 'z']) {
+        Some(stripped) => (stripped, true),
+        None => (value, false),
+    };
+    let date_time = NaiveDateTime::parse_from_str(local, "%Y%m%dT%H%M%S").ok()?;
+    if utc {
+        Some(EventStart::DateTime(Utc.from_utc_datetime(&date_time).with_timezone(&Local).naive_local()))
+    } else {
+        Some(EventStart::DateTime(date_time))
+    }
+}
+
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    Some(match code {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+fn parse_rrule(value: &str) -> Recurrence {
+    let mut rule = Recurrence { interval: 1, ..Default::default() };
+    for part in value.split(';') {
+        let Some((key, value)) = part.split_once('=') else { continue };
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => rule.freq = value.to_ascii_uppercase(),
+            "INTERVAL" => rule.interval = value.parse().unwrap_or(1).max(1),
+            "COUNT" => rule.count = value.parse().ok(),
+            "UNTIL" => rule.until = parse_date_value(value).map(EventStart::date),
+            "BYDAY" => {
+                rule.by_day = value
+                    .split(',')
+                    .filter_map(|day| {
+                        let day = day.trim().to_ascii_uppercase();
+                        let split = day.len().checked_sub(2)?;
+                        let ordinal = day[..split].parse().ok();
+                        Some((ordinal, parse_weekday(&day[split..])?))
+                    })
+                    .collect();
+            }
+            "BYMONTHDAY" => rule.by_month_day = value.split(',').filter_map(|day| day.trim().parse().ok()).collect(),
+            _ => {}
+        }
+    }
+    rule
+}
+
+/// Unfold the content lines of an iCalendar document (continuation lines start with a space or tab).
+fn unfold_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Parse the events of one or more iCalendar documents.
+fn parse_ics(content: &str) -> Result<Vec<CalendarEvent>, String> {
+    if !content.contains("BEGIN:VCALENDAR") {
+        return Err("Not an iCalendar file".to_string());
+    }
+
+    let mut events = Vec::new();
+    let mut current: Option<CalendarEvent> = None;
+    let mut cancelled = false;
+    // Nested components (alarms) have properties of their own
+    let mut depth = 0;
+    for line in unfold_lines(content) {
+        let Some((name, params, value)) = split_property(&line) else { continue };
+        match (name.as_str(), value.trim().to_ascii_uppercase().as_str()) {
+            ("BEGIN", "VEVENT") => {
+                current = Some(CalendarEvent {
+                    uid: String::new(),
+                    summary: String::new(),
+                    location: String::new(),
+                    start: EventStart::Date(NaiveDate::MIN),
+                    end_date: None,
+                    rule: None,
+                    excluded: Vec::new(),
+                    recurrence_id: None,
+                });
+                cancelled = false;
+                depth = 0;
+                continue;
+            }
+            ("END", "VEVENT") => {
+                if let Some(event) = current.take() {
+                    if !cancelled && event.start != EventStart::Date(NaiveDate::MIN) {
+                        events.push(event);
+                    }
+                }
+                continue;
+            }
+            ("BEGIN", _) if current.is_some() => depth += 1,
+            ("END", _) if current.is_some() => depth -= 1,
+            _ => {}
+        }
+        let Some(event) = current.as_mut().filter(|_| depth == 0) else { continue };
+        match name.as_str() {
+            "UID" => event.uid = value.trim().to_string(),
+            "SUMMARY" => event.summary = unescape_text(value),
+            "LOCATION" => event.location = unescape_text(value),
+            "DTSTART" => {
+                let all_day = params.split(';').any(|param| param.eq_ignore_ascii_case("VALUE=DATE"));
+                match parse_date_value(value) {
+                    Some(EventStart::DateTime(date_time)) if all_day => event.start = EventStart::Date(date_time.date()),
+                    Some(start) => event.start = start,
+                    None => debug!(value, "Unreadable DTSTART, skipping event"),
+                }
+            }
+            "DTEND" => {
+                if let Some(EventStart::Date(date)) = parse_date_value(value) {
+                    event.end_date = Some(date);
+                }
+            }
+            "RRULE" => event.rule = Some(parse_rrule(value)),
+            "EXDATE" => event
+                .excluded
+                .extend(value.split(',').filter_map(parse_date_value).map(EventStart::date)),
+            "RECURRENCE-ID" => event.recurrence_id = parse_date_value(value).map(EventStart::date),
+            "STATUS" => cancelled = value.trim().eq_ignore_ascii_case("CANCELLED"),
+            _ => {}
+        }
+    }
+    Ok(events)
+}
+
+fn days_in_month(day: NaiveDate) -> u32 {
+    day.with_day(1)
+        .and_then(|first| first.checked_add_months(chrono::Months::new(1)))
+        .and_then(|next| next.pred_opt())
+        .map_or(31, |last| last.day())
+}
+
+/// The `ordinal`-th `weekday` of the month of `day` (negative counts from the end).
+fn is_nth_weekday(day: NaiveDate, ordinal: i32, weekday: Weekday) -> bool {
+    if day.weekday() != weekday {
+        return false;
+    }
+    let from_start = (day.day0() / 7) as i32 + 1;
+    let days_in_month = days_in_month(day);
+    let from_end = -(((days_in_month - day.day()) / 7) as i32 + 1);
+    ordinal == from_start || ordinal == from_end
+}
+
+/// Whether `day` fits the rule's pattern (ignoring COUNT and UNTIL).
+fn matches_pattern(rule: &Recurrence, start: NaiveDate, day: NaiveDate) -> bool {
+    let interval = rule.interval as i64;
+    let weekday_matches = |default: bool| {
+        if rule.by_day.is_empty() {
+            return default;
+        }
+        rule.by_day.iter().any(|&(ordinal, weekday)| match ordinal {
+            Some(ordinal) => is_nth_weekday(day, ordinal, weekday),
+            None => day.weekday() == weekday,
+        })
+    };
+    let month_day_matches = || {
+        if rule.by_month_day.is_empty() {
+            return day.day() == start.day();
+        }
+        let days_in_month = days_in_month(day) as i32;
+        rule.by_month_day
+            .iter()
+            .any(|&month_day| month_day == day.day() as i32 || month_day == day.day() as i32 - days_in_month - 1)
+    };
+    let months_apart = (day.year() as i64 * 12 + day.month0() as i64) - (start.year() as i64 * 12 + start.month0() as i64);
+
+    match rule.freq.as_str() {
+        "DAILY" => (day - start).num_days() % interval == 0 && weekday_matches(true),
+        "WEEKLY" => {
+            let week_start = |date: NaiveDate| date - Days::new(date.weekday().num_days_from_monday() as u64);
+            let weeks_apart = (week_start(day) - week_start(start)).num_days() / 7;
+            weeks_apart % interval == 0 && weekday_matches(day.weekday() == start.weekday())
+        }
+        "MONTHLY" => {
+            let day_matches = if rule.by_day.is_empty() { month_day_matches() } else { weekday_matches(false) };
+            months_apart % interval == 0 && day_matches
+        }
+        "YEARLY" => {
+            (day.year() - start.year()) as i64 % interval == 0 && day.month() == start.month() && day.day() == start.day()
+        }
+        _ => false,
+    }
+}
+
+/// Whether a recurring event starting on `start` has an occurrence on `day`.
+fn occurs_on(rule: &Recurrence, start: NaiveDate, day: NaiveDate) -> bool {
+    if day < start || rule.until.is_some_and(|until| day > until) || !matches_pattern(rule, start, day) {
+        return false;
+    }
+    let Some(count) = rule.count else { return true };
+    // DTSTART is always the first occurrence, even if it doesn't fit the pattern
+    let mut occurrences = 1;
+    let span = (day - start).num_days().min(MAX_RECURRENCE_DAYS as i64) as u64;
+    for offset in 1..span {
+        if matches_pattern(rule, start, start + Days::new(offset)) {
+            occurrences += 1;
+        }
+    }
+    day == start || occurrences < count
+}
+
+/// The events happening on `day`, all-day events first, then by start time.
+fn events_on(events: &[CalendarEvent], day: NaiveDate) -> Vec<AgendaItem> {
+    let replaced: HashSet<(&str, NaiveDate)> = events
+        .iter()
+        .filter_map(|event| Some((event.uid.as_str(), event.recurrence_id?)))
+        .collect();
+
+    let mut items: Vec<AgendaItem> = events
+        .iter()
+        .filter(|event| {
+            let start = event.start.date();
+            match &event.rule {
+                Some(rule) if event.recurrence_id.is_none() => {
+                    occurs_on(rule, start, day)
+                        && !event.excluded.contains(&day)
+                        && !replaced.contains(&(event.uid.as_str(), day))
+                }
+                _ => match event.start {
+                    EventStart::Date(_) => start <= day && day < event.end_date.unwrap_or(start + Days::new(1)).max(start + Days::new(1)),
+                    EventStart::DateTime(_) => start == day,
+                },
+            }
+        })
+        .map(|event| AgendaItem {
+            time: match event.start {
+                EventStart::Date(_) => None,
+                EventStart::DateTime(date_time) => Some(date_time.time()),
+            },
+            summary: event.summary.clone(),
+            location: event.location.clone(),
+        })
+        .collect();
+    items.sort_by_key(|item| item.time);
+    items
+}
+
+/// A time as it is said: "10am", "1:30pm".
+fn spoken_time(time: NaiveTime) -> String {
+    let (pm, hour) = time.hour12();
+    let suffix = if pm { "pm" } else { "am" };
+    match time.minute() {
+        0 => format!("{hour}{suffix}"),
+        minute => format!("{hour}:{minute:02}{suffix}"),
+    }
+}
+
+/// Spoken summary of the day's events.
+fn agenda_text(items: &[AgendaItem]) -> String {
+    if items.is_empty() {
+        return "Nothing on your calendar today.".to_string();
+    }
+    let mut text = match items.len() {
+        1 => "One event today.".to_string(),
+        count => format!("{count} events today."),
+    };
+    for item in items {
+        let when = item.time.map(spoken_time);
+        let summary = if item.summary.is_empty() { "Untitled event" } else { item.summary.as_str() };
+        text.push(' ');
+        text.push_str(&match when {
+            Some(when) => format!("{when}: {summary}"),
+            None => format!("All day: {summary}"),
+        });
+        if !item.location.is_empty() {
+            text.push_str(&format!(", at {}", item.location));
+        }
+        if !text.ends_with(['.', '!', '?']) {
+            text.push('.');
+        }
+    }
+    text
+}
+
+/// Download a calendar: a plain iCalendar feed, or a CalDAV collection queried for `day`.
+async fn fetch_remote(settings: &CalendarSettings, url: &str, day: NaiveDate) -> Result<String, String> {
+    let client = reqwest::Client::builder()
+        .timeout(FETCH_TIMEOUT)
+        .user_agent(concat!("insight-reader/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+    let authenticate = |request: reqwest::RequestBuilder| {
+        if settings.username.trim().is_empty() {
+            request
+        } else {
+            request.basic_auth(settings.username.trim(), Some(&settings.password))
+        }
+    };
+    let send = |request: reqwest::RequestBuilder| async move {
+        let response = request.send().await.map_err(|e| format!("Could not reach the calendar: {e}"))?;
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Err("The calendar server rejected the credentials".to_string());
+        }
+        if !status.is_success() {
+            return Err(format!("The calendar server returned {status}"));
+        }
+        response.text().await.map_err(|e| format!("Could not read the calendar: {e}"))
+    };
+
+    let body = send(authenticate(client.get(url))).await?;
+    if body.contains("BEGIN:VCALENDAR") {
+        return Ok(body);
+    }
+
+    // Not an .ics feed: ask the CalDAV server for the day's events
+    debug!(url, "Querying CalDAV calendar");
+    let to_utc = |date: NaiveDate| {
+        Local
+            .from_local_datetime(&date.and_time(NaiveTime::MIN))
+            .earliest()
+            .map(|time| time.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ").to_string())
+            .unwrap_or_default()
+    };
+    let query = format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop><c:calendar-data/></d:prop>
+  <c:filter><c:comp-filter name="VCALENDAR"><c:comp-filter name="VEVENT">
+    <c:time-range start="{}" end="{}"/>
+  </c:comp-filter></c:comp-filter></c:filter>
+</c:calendar-query>"#,
+        to_utc(day),
+        to_utc(day + Days::new(1)),
+    );
+    let report = reqwest::Method::from_bytes(b"REPORT").map_err(|e| e.to_string())?;
+    let request = client
+        .request(report, url)
+        .header("Depth", "1")
+        .header("Content-Type", "application/xml; charset=utf-8")
+        .body(query);
+    let body = send(authenticate(request)).await?;
+    let document = roxmltree::Document::parse(&body).map_err(|_| "Not an iCalendar or CalDAV URL".to_string())?;
+    Ok(document
+        .descendants()
+        .filter(|node| node.tag_name().name() == "calendar-data")
+        .filter_map(|node| node.text())
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Fetch today's events and return the text to read.
+pub async fn fetch_agenda(settings: CalendarSettings) -> Result<String, String> {
+    let source = settings.source.trim();
+    let today = Local::now().date_naive();
+    let content = if let Some(rest) = source.strip_prefix("webcal://") {
+        fetch_remote(&settings, &format!("https://{rest}"), today).await?
+    } else if source.starts_with("http://") || source.starts_with("https://") {
+        fetch_remote(&settings, source, today).await?
+    } else {
+        let path = match source.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir().map(|home| home.join(rest)).unwrap_or_else(|| PathBuf::from(source)),
+            None => PathBuf::from(source),
+        };
+        std::fs::read_to_string(&path).map_err(|e| format!("Could not read {}: {e}", path.display()))?
+    };
+
+    // A CalDAV query with no events returns no calendar data at all
+    let events = if content.trim().is_empty() { Vec::new() } else { parse_ics(&content)? };
+    let items = events_on(&events, today);
+    info!(events = events.len(), today = items.len(), "Agenda fetched");
+    Ok(agenda_text(&items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agenda_for_day() {
+        let ics = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\nUID:standup\r\nSUMMARY:Standup\r\nDTSTART;TZID=Europe/Berlin:20240304T100000\r\n\
+RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR\r\nEXDATE;TZID=Europe/Berlin:20240313T100000\r\n\
+BEGIN:VALARM\r\nSUMMARY:Alarm\r\nEND:VALARM\r\nEND:VEVENT\r\n\
+BEGIN:VEVENT\r\nUID:dentist\r\nSUMMARY:Dentist\r\nLOCATION:Main St\\, Suite 4\r\n\
+DTSTART:20240311T133000\r\nEND:VEVENT\r\n\
+BEGIN:VEVENT\r\nUID:trip\r\nSUMMARY:Conference\r\nDTSTART;VALUE=DATE:20240310\r\nDTEND;VALUE=DATE:20240313\r\nEND:VEVENT\r\n\
+BEGIN:VEVENT\r\nUID:standup\r\nRECURRENCE-ID;TZID=Europe/Berlin:20240315T100000\r\nSUMMARY:Standup (moved)\r\n\
+DTSTART;TZID=Europe/Berlin:20240315T110000\r\nEND:VEVENT\r\n\
+BEGIN:VEVENT\r\nUID:call\r\nSUMMARY:Cancelled call\r\nDTSTART:20240311T090000\r\nSTATUS:CANCELLED\r\nEND:VEVENT\r\n\
+BEGIN:VEVENT\r\nUID:review\r\nSUMMARY:Review\r\nDTSTART:20240301T160000\r\nRRULE:FREQ=MONTHLY;BYDAY=-1FR\r\nEND:VEVENT\r\n\
+END:VCALENDAR\r\n";
+        let events = parse_ics(ics).unwrap();
+        let day = |d| NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+
+        assert_eq!(
+            agenda_text(&events_on(&events, day(11))),
+            "3 events today. All day: Conference. 10am: Standup. 1:30pm: Dentist, at Main St, Suite 4."
+        );
+        // Excluded, moved and past the end of the all-day event
+        assert_eq!(agenda_text(&events_on(&events, day(13))), "Nothing on your calendar today.");
+        assert_eq!(agenda_text(&events_on(&events, day(15))), "One event today. 11am: Standup (moved).");
+        // Last Friday of the month
+        assert_eq!(agenda_text(&events_on(&events, day(29))), "2 events today. 10am: Standup. 4pm: Review.");
+        assert!(parse_ics("not a calendar").is_err());
+
+        let rule = parse_rrule("FREQ=DAILY;INTERVAL=2;COUNT=3");
+        assert!(occurs_on(&rule, day(1), day(5)));
+        assert!(!occurs_on(&rule, day(1), day(7)));
+    }
+}
@@ -0,0 +1,153 @@
+//! Selection reading over AT-SPI2, the Linux accessibility bus
+//!
+//! Asks the active window's accessible objects for their text selection
+//! directly, so selections are read in applications that don't set the
+//! PRIMARY selection, and without going through the clipboard. Objects not
+//! showing on screen are skipped and the search gives up after
+//! [`SEARCH_BUDGET`], so a large web page can't hold up the capture.
+
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::debug;
+use zbus::blocking::{connection, Connection};
+use zbus::zvariant::{DynamicType, OwnedObjectPath, Type};
+
+use super::process_text;
+
+/// Longest the selection search may take before falling back to the clipboard.
+const SEARCH_BUDGET: Duration = Duration::from_millis(500);
+/// Timeout of a single call, for applications that stopped answering.
+const CALL_TIMEOUT: Duration = Duration::from_millis(200);
+/// Most accessible objects visited in one search.
+const MAX_OBJECTS: usize = 5_000;
+
+const ACCESSIBLE: &str = "org.a11y.atspi.Accessible";
+const TEXT: &str = "org.a11y.atspi.Text";
+const REGISTRY: &str = "org.a11y.atspi.Registry";
+const ROOT_PATH: &str = "/org/a11y/atspi/accessible/root";
+
+// Bits of the state set (AtspiStateType)
+const STATE_ACTIVE: u32 = 1;
+const STATE_SHOWING: u32 = 25;
+
+/// Character standing for an embedded child object in a container's text.
+const EMBEDDED_OBJECT: char = '\u{fffc}';
+
+/// An accessible object: its application's bus name and its path.
+type Object = (String, OwnedObjectPath);
+
+/// Whether `state` is set in a state set (two 32-bit words).
+pub(super) fn has_state(states: &[u32], state: u32) -> bool {
+    states.get(state as usize / 32).is_some_and(|bits| bits & (1 << (state % 32)) != 0)
+}
+
+/// Selected text of a container without its children's placeholders, which are searched on their own.
+pub(super) fn own_selected_text(text: &str) -> Option<String> {
+    let text: String = text.chars().filter(|c| *c != EMBEDDED_OBJECT).collect();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Connection to the accessibility bus, whose address the session bus gives out.
+fn accessibility_bus() -> zbus::Result<Connection> {
+    let session = Connection::session()?;
+    let reply = session.call_method(Some("org.a11y.Bus"), "/org/a11y/bus", Some("org.a11y.Bus"), "GetAddress", &())?;
+    let address: String = reply.body().deserialize()?;
+    connection::Builder::address(address.as_str())?.method_timeout(CALL_TIMEOUT).build()
+}
+
+struct Search {
+    connection: Connection,
+    deadline: Instant,
+    visited: usize,
+}
+
+impl Search {
+    /// Call `method` on `object`; failures (object gone, interface missing) are None.
+    fn call<B, R>(&self, object: &Object, interface: &str, method: &str, body: &B) -> Option<R>
+    where
+        B: Serialize + DynamicType,
+        R: DeserializeOwned + Type,
+    {
+        let reply = self
+            .connection
+            .call_method(Some(object.0.as_str()), object.1.as_str(), Some(interface), method, body)
+            .ok()?;
+        reply.body().deserialize().ok()
+    }
+
+    fn children(&self, object: &Object) -> Vec<Object> {
+        self.call(object, ACCESSIBLE, "GetChildren", &()).unwrap_or_default()
+    }
+
+    fn has_state(&self, object: &Object, state: u32) -> bool {
+        self.call::<_, Vec<u32>>(object, ACCESSIBLE, "GetState", &())
+            .is_some_and(|states| has_state(&states, state))
+    }
+
+    /// Text selected in `object`, if it has the Text interface and a selection.
+    fn selection(&self, object: &Object) -> Option<String> {
+        let count: i32 = self.call(object, TEXT, "GetNSelections", &())?;
+        let parts: Vec<String> = (0..count)
+            .filter_map(|index| {
+                let (start, end): (i32, i32) = self.call(object, TEXT, "GetSelection", &(index,))?;
+                let text: String = self.call(object, TEXT, "GetText", &(start, end))?;
+                own_selected_text(&text)
+            })
+            .collect();
+        (!parts.is_empty()).then(|| parts.join("\n"))
+    }
+
+    /// Collect the selections under `object`, in document order.
+    fn collect(&mut self, object: &Object, selections: &mut Vec<String>) {
+        if self.visited >= MAX_OBJECTS || Instant::now() > self.deadline {
+            return;
+        }
+        self.visited += 1;
+        if !self.has_state(object, STATE_SHOWING) {
+            return;
+        }
+        if let Some(text) = self.selection(object) {
+            selections.push(text);
+        }
+        for child in self.children(object) {
+            self.collect(&child, selections);
+        }
+    }
+}
+
+/// Gets the text selected in the active window over AT-SPI.
+/// Returns None if the accessibility bus is unavailable or nothing is selected.
+pub(super) fn get_selected_text_atspi() -> Option<String> {
+    let started = Instant::now();
+    let connection = accessibility_bus()
+        .map_err(|e| debug!(error = %e, "Accessibility bus unavailable"))
+        .ok()?;
+    let mut search = Search { connection, deadline: started + SEARCH_BUDGET, visited: 0 };
+
+    // Applications register under the root; the focused one has the active window
+    let root = (REGISTRY.to_string(), OwnedObjectPath::try_from(ROOT_PATH).ok()?);
+    let windows: Vec<Object> = search
+        .children(&root)
+        .iter()
+        .flat_map(|application| search.children(application))
+        .filter(|window| search.has_state(window, STATE_ACTIVE))
+        .collect();
+
+    let mut selections = Vec::new();
+    for window in &windows {
+        search.collect(window, &mut selections);
+        if !selections.is_empty() {
+            break;
+        }
+    }
+    debug!(
+        windows = windows.len(),
+        visited = search.visited,
+        elapsed_ms = started.elapsed().as_millis() as u64,
+        "AT-SPI selection search finished"
+    );
+    process_text(selections.join("\n"), "AT-SPI selection")
+}
@@ -4,15 +4,21 @@ use super::process_text;
 use tracing::{debug, info};
 
 /// Gets the currently selected text on Linux.
-/// Tries PRIMARY selection first, then falls back to clipboard.
+/// Asks the focused application over AT-SPI first, then tries PRIMARY selection, then clipboard.
 pub(super) fn get_selected_text_linux() -> Option<String> {
     use arboard::{Clipboard, GetExtLinux, LinuxClipboardKind};
     
-    info!("Attempting to read selected text (PRIMARY selection, fallback to clipboard)");
+    info!("Attempting to read selected text (AT-SPI, fallback to PRIMARY selection and clipboard)");
+    
+    // First attempt: the selection itself, from the focused application's accessible text
+    if let Some(text) = super::atspi::get_selected_text_atspi() {
+        return Some(text);
+    }
+    debug!("No selection over AT-SPI, falling back to PRIMARY selection");
     
     let mut clipboard = Clipboard::new().ok()?;
     
-    // First attempt: Try PRIMARY selection (selected text)
+    // Second attempt: Try PRIMARY selection (selected text)
     if let Ok(text) = clipboard.get().clipboard(LinuxClipboardKind::Primary).text() {
         if let Some(result) = process_text(text, "PRIMARY selection") {
             return Some(result);
@@ -1,11 +1,67 @@
 //! macOS-specific clipboard implementation
 
+use core_foundation::base::{CFType, TCFType};
+use core_foundation::string::CFString;
 use super::process_text;
+use tracing::debug;
+
+/// Accessibility API (ApplicationServices framework)
+mod ax {
+    use core_foundation::base::CFTypeRef;
+    use core_foundation::string::CFStringRef;
+
+    pub type AXUIElementRef = CFTypeRef;
+    pub type AXError = i32;
+    pub const AX_ERROR_SUCCESS: AXError = 0;
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        pub fn AXUIElementCreateSystemWide() -> AXUIElementRef;
+        pub fn AXUIElementCopyAttributeValue(
+            element: AXUIElementRef,
+            attribute: CFStringRef,
+            value: *mut CFTypeRef,
+        ) -> AXError;
+    }
+}
+
+/// Copy `attribute` of `element`, None if the element doesn't have it.
+fn copy_attribute(element: &CFType, attribute: &'static str) -> Option<CFType> {
+    let attribute = CFString::from_static_string(attribute);
+    let mut value = std::ptr::null();
+    // SAFETY: both references are valid CF objects for the duration of the call
+    let error = unsafe {
+        ax::AXUIElementCopyAttributeValue(element.as_CFTypeRef(), attribute.as_concrete_TypeRef(), &mut value)
+    };
+    if error != ax::AX_ERROR_SUCCESS || value.is_null() {
+        debug!(error, attribute = %attribute, "Accessibility attribute unavailable");
+        return None;
+    }
+    // SAFETY: the copied value follows the create rule, it is released when the wrapper drops
+    Some(unsafe { CFType::wrap_under_create_rule(value) })
+}
+
+/// Gets the text selected in the focused element through the Accessibility API.
+/// Needs the accessibility permission; returns None without it, or if the app doesn't expose its selection.
+fn get_selected_text_ax() -> Option<String> {
+    // SAFETY: returns a new reference to the system-wide element, released when the wrapper drops
+    let system_wide = unsafe { CFType::wrap_under_create_rule(ax::AXUIElementCreateSystemWide()) };
+    let focused = copy_attribute(&system_wide, "AXFocusedUIElement")?;
+    let selected = copy_attribute(&focused, "AXSelectedText")?.downcast::<CFString>()?;
+    process_text(selected.to_string(), "Accessibility API (AXSelectedText)")
+}
 
 /// Gets the currently selected text on macOS.
+/// Reads the focused element's selection through the Accessibility API first, which leaves
+/// the clipboard alone, then falls back to the clipboard.
 pub(super) fn get_selected_text_macos() -> Option<String> {
     use arboard::Clipboard;
-    
+
+    if let Some(text) = get_selected_text_ax() {
+        return Some(text);
+    }
+    debug!("No selection from the Accessibility API, falling back to clipboard");
+
     Clipboard::new()
         .ok()?
         .get_text()
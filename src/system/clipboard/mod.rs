@@ -1,5 +1,7 @@
 //! Clipboard and selection reading utilities
 
+#[cfg(target_os = "linux")]
+mod atspi;
 #[cfg(target_os = "linux")]
 mod linux;
 #[cfg(target_os = "macos")]
@@ -38,7 +40,7 @@ pub(crate) fn process_text(text: String, source: &str) -> Option<String> {
 }
 
 /// Gets the currently selected text.
-/// - On Linux: Uses arboard to read from PRIMARY selection first, falls back to clipboard
+/// - On Linux: Asks the focused application over AT-SPI, falls back to PRIMARY selection, then clipboard
 /// - On macOS: Uses arboard to read from clipboard
 /// - On Windows: Uses arboard to read from clipboard
 /// - On other platforms: Returns None
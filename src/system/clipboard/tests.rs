@@ -83,6 +83,25 @@ fn test_text_preview_unicode_long() {
     assert_eq!(without_suffix.chars().count(), 200);
 }
 
+// ============================================================================
+// Unit Tests for AT-SPI selection helpers
+// ============================================================================
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_atspi_state_and_selection_text() {
+    use super::atspi::{has_state, own_selected_text};
+    // SHOWING is bit 25 of the first word, bit 33 is in the second
+    assert!(has_state(&[1 << 25, 0], 25));
+    assert!(!has_state(&[1 << 25, 0], 1));
+    assert!(has_state(&[0, 1 << 1], 33));
+    assert!(!has_state(&[], 25));
+
+    // Children's placeholders are dropped, they report their own selections
+    assert_eq!(own_selected_text("Intro \u{fffc} outro"), Some("Intro  outro".to_string()));
+    assert_eq!(own_selected_text("\u{fffc}\n\u{fffc}"), None);
+}
+
 // ============================================================================
 // Integration Tests for copy_to_clipboard()
 // ============================================================================
@@ -0,0 +1,134 @@
+//! Opened documents
+//!
+//! Besides selections, files can be read: opened from the reading queue
+//! window or dropped on one of the app's windows. PDFs have their text
+//! extracted page by page, and where each page starts is kept so the player
+//! can tell which page it is reading; HTML is read as text, and anything else
+//! as it is. Text piped in (`--stdin`) often comes from a terminal program
+//! and has its color codes and overstrikes removed first.
+
+use std::path::Path;
+
+use super::text_processing::html_to_text;
+
+/// Text of an opened file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Document {
+    pub text: String,
+    /// Where each page starts, as the share of `text`'s characters before it (PDFs only)
+    pub pages: Vec<f32>,
+}
+
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| extensions.iter().any(|known| ext.eq_ignore_ascii_case(known)))
+}
+
+/// Read the text of the file at `path`.
+///
+/// PDF parsing is slow on large files (and may panic on malformed ones), so
+/// this is meant to run on a worker thread.
+pub fn open_document(path: &Path) -> Result<Document, String> {
+    if has_extension(path, &["pdf"]) {
+        let pages = pdf_extract::extract_text_by_pages(path)
+            .map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+        let document = join_pages(&pages);
+        if document.text.is_empty() {
+            return Err(format!("No text found in {} (scanned pages need OCR)", path.display()));
+        }
+        return Ok(document);
+    }
+    let content = std::fs::read_to_string(path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+    let text = if has_extension(path, &["html", "htm"]) { html_to_text(&content) } else { content };
+    if text.trim().is_empty() {
+        return Err(format!("{} is empty", path.display()));
+    }
+    Ok(Document { text, pages: Vec::new() })
+}
+
+/// Join the text of `pages` into one document, a paragraph break between pages.
+///
+/// Pages without text (scans, full-page pictures) still count, so page numbers
+/// match the file's.
+fn join_pages(pages: &[String]) -> Document {
+    let mut text = String::new();
+    let mut starts = Vec::with_capacity(pages.len());
+    for page in pages {
+        let page = page.trim();
+        if !page.is_empty() && !text.is_empty() {
+            text.push_str("\n\n");
+        }
+        starts.push(text.chars().count());
+        text.push_str(page);
+    }
+    let total = text.chars().count().max(1) as f32;
+    Document {
+        pages: starts.into_iter().map(|start| start as f32 / total).collect(),
+        text,
+    }
+}
+
+/// Strip terminal formatting from `text`: ANSI escape sequences (colors,
+/// cursor moves) and the backspace overstrikes `man` uses for bold and
+/// underline ("b\x08b", "_\x08u").
+pub fn terminal_to_text(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{1b}' => match chars.next() {
+                // CSI: parameters up to a final byte in '@'..='~'
+                Some('[') => {
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // OSC (window titles, links): up to BEL or ESC \
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\u{7}' || c == '\u{1b}' && chars.next_if_eq(&'\\').is_some() {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            '\u{8}' => {
+                result.pop();
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Page (counting from 1) at `position`, given where the pages start.
+pub fn page_at(pages: &[f32], position: f32) -> Option<usize> {
+    pages.iter().rposition(|start| *start <= position).map(|index| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_pages() {
+        let pages = ["  First page.\n".to_string(), String::new(), "Third.".to_string()];
+        let document = join_pages(&pages);
+        assert_eq!(document.text, "First page.\n\nThird.");
+        assert_eq!(document.pages, [0.0, 11.0 / 19.0, 13.0 / 19.0]);
+        assert_eq!(page_at(&document.pages, 0.2), Some(1));
+        assert_eq!(page_at(&document.pages, 0.9), Some(3));
+        assert_eq!(page_at(&[], 0.5), None);
+    }
+
+    #[test]
+    fn test_terminal_to_text() {
+        let text = "\u{1b}[1;31merror\u{1b}[0m: N\u{8}NA\u{8}AM\u{8}ME\u{8}E\r\n_\u{8}l_\u{8}s\u{1b}]0;title\u{7} done";
+        assert_eq!(terminal_to_text(text), "error: NAME\nls done");
+    }
+}
@@ -0,0 +1,270 @@
+//! RSS/Atom feeds for the morning briefing
+//!
+//! Feeds are subscribed to by URL or imported from an OPML file. Fetching the
+//! briefing downloads every feed, keeps the items that haven't been heard yet
+//! and turns each one into readable text (feed name and title first, HTML
+//! stripped), which is then queued and read like any other text, with the
+//! feed's own voice if it has one.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::Duration;
+
+use tracing::{debug, info, warn};
+
+use super::text_processing::html_to_text;
+use crate::queue::VoiceOverride;
+
+/// New items read per feed in one briefing.
+const MAX_ITEMS_PER_FEED: usize = 5;
+
+/// Item ids remembered as heard (oldest are forgotten first).
+pub const MAX_HEARD_ITEMS: usize = 1000;
+
+/// Timeout for downloading one feed.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// A subscribed feed.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct FeedSubscription {
+    /// Name read before each item (empty = use the feed's own title)
+    #[serde(default)]
+    pub title: String,
+    /// RSS or Atom URL
+    pub url: String,
+    /// Engine, voice and speed its items are read with (e.g. for a feed in another language)
+    #[serde(default, skip_serializing_if = "VoiceOverride::is_empty")]
+    pub voice: VoiceOverride,
+}
+
+impl FeedSubscription {
+    /// Name shown in settings.
+    pub fn display_name(&self) -> &str {
+        if self.title.is_empty() {
+            &self.url
+        } else {
+            &self.title
+        }
+    }
+}
+
+/// A new feed item, ready to be read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BriefingItem {
+    /// Stable id (guid, Atom id or link) used to remember it was heard
+    pub id: String,
+    /// Text to read: feed name, title and body
+    pub text: String,
+    /// How its feed is read
+    pub voice: VoiceOverride,
+}
+
+/// One item of a parsed feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FeedEntry {
+    id: String,
+    title: String,
+    body: String,
+}
+
+/// Parse the feeds listed in an OPML document.
+fn parse_opml(content: &str) -> Result<Vec<FeedSubscription>, String> {
+    let document = roxmltree::Document::parse(content).map_err(|e| format!("Invalid OPML file: {e}"))?;
+    if !document.root_element().has_tag_name("opml") {
+        return Err("Invalid OPML file: missing <opml> root element".to_string());
+    }
+    Ok(document
+        .descendants()
+        .filter(|node| node.has_tag_name("outline"))
+        .filter_map(|outline| {
+            let url = outline.attribute("xmlUrl")?.trim();
+            let title = outline.attribute("title").or_else(|| outline.attribute("text")).unwrap_or_default();
+            (!url.is_empty()).then(|| FeedSubscription {
+                title: title.trim().to_string(),
+                url: url.to_string(),
+                voice: VoiceOverride::default(),
+            })
+        })
+        .collect())
+}
+
+/// Read the feeds from an OPML file (as exported by most feed readers).
+pub fn import_opml(path: &Path) -> Result<Vec<FeedSubscription>, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+    parse_opml(&content)
+}
+
+/// Add `new` feeds that aren't subscribed yet. Returns how many were added.
+pub fn merge_feeds(feeds: &mut Vec<FeedSubscription>, new: Vec<FeedSubscription>) -> usize {
+    let before = feeds.len();
+    for feed in new {
+        if !feeds.iter().any(|existing| existing.url == feed.url) {
+            feeds.push(feed);
+        }
+    }
+    feeds.len() - before
+}
+
+/// Parse an RSS 2.0, RSS 1.0 or Atom document into its title and items.
+fn parse_feed(content: &str) -> Result<(String, Vec<FeedEntry>), String> {
+    let options = roxmltree::ParsingOptions { allow_dtd: true, ..Default::default() };
+    let document = roxmltree::Document::parse_with_options(content, options).map_err(|e| format!("Invalid feed: {e}"))?;
+    let root = document.root_element();
+    if !["rss", "RDF", "feed"].contains(&root.tag_name().name()) {
+        return Err(format!("Not an RSS or Atom feed: <{}>", root.tag_name().name()));
+    }
+
+    // Namespaces vary (content:encoded, Atom, RDF), so match local names only
+    let child_text = |node: roxmltree::Node, name: &str| {
+        node.children()
+            .find(|child| child.tag_name().name() == name)
+            .and_then(|child| child.text())
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(str::to_string)
+    };
+    let atom_link = |node: roxmltree::Node| {
+        node.children()
+            .filter(|child| child.tag_name().name() == "link")
+            .find(|link| link.attribute("rel").is_none_or(|rel| rel == "alternate"))
+            .and_then(|link| link.attribute("href"))
+            .map(str::to_string)
+    };
+
+    let channel = root.children().find(|child| child.tag_name().name() == "channel").unwrap_or(root);
+    let title = child_text(channel, "title").map(|title| html_to_text(&title)).unwrap_or_default();
+    let entries = root
+        .descendants()
+        .filter(|node| matches!(node.tag_name().name(), "item" | "entry"))
+        .filter_map(|item| {
+            let title = child_text(item, "title").map(|title| html_to_text(&title)).unwrap_or_default();
+            let body = ["encoded", "content", "description", "summary"]
+                .iter()
+                .find_map(|name| child_text(item, name))
+                .map(|body| html_to_text(&body))
+                .unwrap_or_default();
+            let id = child_text(item, "guid")
+                .or_else(|| child_text(item, "id"))
+                .or_else(|| child_text(item, "link"))
+                .or_else(|| atom_link(item))
+                .or_else(|| item.attribute(("http://www.w3.org/1999/02/22-rdf-syntax-ns#", "about")).map(str::to_string))
+                .unwrap_or_else(|| title.clone());
+            let has_content = !title.is_empty() || !body.is_empty();
+            (has_content && !id.is_empty()).then_some(FeedEntry { id, title, body })
+        })
+        .collect();
+    Ok((title, entries))
+}
+
+/// Text read for one item: "Feed: Title." then the body.
+fn item_text(feed_title: &str, entry: &FeedEntry) -> String {
+    let mut heading = match (feed_title.is_empty(), entry.title.is_empty()) {
+        (false, false) => format!("{feed_title}: {}", entry.title),
+        (true, false) => entry.title.clone(),
+        (false, true) => feed_title.to_string(),
+        (true, true) => String::new(),
+    };
+    if !heading.is_empty() && !heading.ends_with(['.', '!', '?', '…']) {
+        heading.push('.');
+    }
+    match (heading.is_empty(), entry.body.is_empty()) {
+        (_, true) => heading,
+        (true, false) => entry.body.clone(),
+        (false, false) => format!("{heading}\n\n{}", entry.body),
+    }
+}
+
+/// Download every feed and return the items not in `heard`, feed by feed.
+///
+/// Feeds that fail to download or parse are skipped; an error is returned
+/// only if none of them could be fetched.
+pub async fn fetch_briefing(feeds: Vec<FeedSubscription>, heard: HashSet<String>) -> Result<Vec<BriefingItem>, String> {
+    let client = reqwest::Client::builder()
+        .timeout(FETCH_TIMEOUT)
+        .user_agent(concat!("insight-reader/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+    let mut items = Vec::new();
+    let mut last_error = None;
+    let mut fetched = 0;
+    for feed in &feeds {
+        debug!(url = %feed.url, "Fetching feed");
+        let body = match client.get(&feed.url).send().await.and_then(|r| r.error_for_status()) {
+            Ok(response) => response.text().await,
+            Err(e) => Err(e),
+        };
+        let parsed = body.map_err(|e| e.to_string()).and_then(|body| parse_feed(&body));
+        let (channel_title, entries) = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                warn!(url = %feed.url, error = %e, "Failed to fetch feed");
+                last_error = Some(format!("{}: {e}", feed.display_name()));
+                continue;
+            }
+        };
+        fetched += 1;
+        let feed_title = if feed.title.is_empty() { channel_title } else { feed.title.clone() };
+        let new: Vec<_> = entries
+            .into_iter()
+            .filter(|entry| !heard.contains(&entry.id))
+            .take(MAX_ITEMS_PER_FEED)
+            .map(|entry| BriefingItem {
+                text: item_text(&feed_title, &entry),
+                id: entry.id,
+                voice: feed.voice.clone(),
+            })
+            .collect();
+        debug!(url = %feed.url, new = new.len(), "Feed fetched");
+        items.extend(new);
+    }
+
+    match last_error {
+        Some(error) if fetched == 0 => Err(error),
+        _ => {
+            info!(feeds = fetched, items = items.len(), "Briefing fetched");
+            Ok(items)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_opml_and_feeds() {
+        let opml = r#"<?xml version="1.0"?>
+            <opml version="2.0"><body>
+              <outline text="News">
+                <outline text="Example" title="Example News" type="rss" xmlUrl="https://example.com/rss"/>
+                <outline text="Blog" xmlUrl="https://blog.example.org/atom.xml"/>
+              </outline>
+            </body></opml>"#;
+        let feeds = parse_opml(opml).unwrap();
+        assert_eq!(feeds.len(), 2);
+        assert_eq!(feeds[0].title, "Example News");
+        assert_eq!(feeds[1].url, "https://blog.example.org/atom.xml");
+        assert!(parse_opml("<rss/>").is_err());
+
+        let rss = r#"<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/"><channel>
+              <title>Example News</title>
+              <item><title>First &amp; foremost</title><guid>a1</guid>
+                <description>Short</description>
+                <content:encoded><![CDATA[<p>Hello <b>world</b>.</p><script>x()</script><p>Second&nbsp;para</p>]]></content:encoded>
+              </item>
+              <item><title>Only title</title><link>https://example.com/2</link></item>
+            </channel></rss>"#;
+        let (title, entries) = parse_feed(rss).unwrap();
+        assert_eq!(title, "Example News");
+        assert_eq!(entries[0].id, "a1");
+        assert_eq!(item_text(&title, &entries[0]), "Example News: First & foremost.\n\nHello world.\nSecond para");
+        assert_eq!(entries[1].id, "https://example.com/2");
+
+        let atom = r#"<feed xmlns="http://www.w3.org/2005/Atom"><title>Blog</title>
+              <entry><title>Post</title><id>urn:1</id><link href="https://blog/1"/><summary>Hi&#33;</summary></entry>
+            </feed>"#;
+        let (_, entries) = parse_feed(atom).unwrap();
+        assert_eq!(entries, vec![FeedEntry { id: "urn:1".into(), title: "Post".into(), body: "Hi!".into() }]);
+    }
+}
@@ -0,0 +1,154 @@
+//! Phone handoff folder
+//!
+//! Drops each reading as an MP3 plus a small JSON file describing it into a
+//! folder the user syncs to their phone (Syncthing, Dropbox, ...), so it can
+//! be listened to there without a mobile app. MP3 encoding uses `ffmpeg` or
+//! `lame` when one is installed; otherwise the audio is handed off as WAV.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use tracing::{debug, warn};
+
+use super::recordings::{reading_file_stem, reading_title, ReadingTags, SOFTWARE};
+use crate::providers::encode_wav_with_info;
+
+/// Metadata written next to each handed-off audio file.
+#[derive(Debug, serde::Serialize)]
+struct HandoffMetadata<'a> {
+    /// First words of the text
+    title: String,
+    /// When the reading was made (RFC 3339)
+    created: String,
+    /// Length of the audio at normal speed
+    duration_seconds: f32,
+    /// Provider and voice that read it
+    voice: &'a str,
+    /// License and dataset attribution of the voice, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    voice_license: Option<&'a str>,
+    /// Where the text came from, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_url: Option<&'a str>,
+    /// Software and version that made the reading
+    software: &'a str,
+    /// Audio file name, relative to the JSON file
+    audio_file: String,
+    /// Text that was read
+    text: &'a str,
+}
+
+/// Encode `wav` to `mp3` with the first available encoder, writing `tags` as ID3.
+///
+/// Returns false when no encoder is installed or encoding failed.
+fn encode_mp3(wav: &Path, mp3: &Path, tags: &ReadingTags) -> bool {
+    let mut ffmpeg = Command::new("ffmpeg");
+    ffmpeg.args(["-y", "-loglevel", "error", "-i"]).arg(wav);
+    for (key, value) in id3_fields(tags) {
+        ffmpeg.arg("-metadata").arg(format!("{key}={value}"));
+    }
+    ffmpeg
+        .args(["-id3v2_version", "3", "-codec:a", "libmp3lame", "-q:a", "5", "-f", "mp3"])
+        .arg(mp3);
+
+    let mut lame = Command::new("lame");
+    lame.args(["--quiet", "-V5", "--add-id3v2"]);
+    for (key, value) in id3_fields(tags) {
+        // lame has flags for the common fields; the rest go in as raw frames
+        match key {
+            "title" => lame.arg("--tt").arg(value),
+            "artist" => lame.arg("--ta").arg(value),
+            "comment" => lame.arg("--tc").arg(value),
+            "copyright" => lame.arg("--tv").arg(format!("TCOP={value}")),
+            _ => lame.arg("--tv").arg(format!("TSSE={value}")),
+        };
+    }
+    lame.arg(wav).arg(mp3);
+
+    for (encoder, mut command) in [("ffmpeg", ffmpeg), ("lame", lame)] {
+        match command.stdin(Stdio::null()).output() {
+            Ok(output) if output.status.success() => {
+                debug!(encoder, "Reading encoded to MP3");
+                return true;
+            }
+            Ok(output) => warn!(
+                encoder,
+                stderr = %String::from_utf8_lossy(&output.stderr).trim(),
+                "MP3 encoding failed"
+            ),
+            Err(e) => debug!(encoder, error = %e, "MP3 encoder not available"),
+        }
+    }
+    false
+}
+
+/// ID3 fields of a reading, as ffmpeg metadata keys.
+fn id3_fields(tags: &ReadingTags) -> Vec<(&'static str, &str)> {
+    let mut fields = Vec::new();
+    if !tags.title.is_empty() {
+        fields.push(("title", tags.title.as_str()));
+    }
+    if !tags.voice.is_empty() {
+        fields.push(("artist", tags.voice.as_str()));
+    }
+    if let Some(url) = &tags.source_url {
+        fields.push(("comment", url.as_str()));
+    }
+    if let Some(license) = &tags.license {
+        fields.push(("copyright", license.as_str()));
+    }
+    fields.push(("encoder", SOFTWARE));
+    fields
+}
+
+/// Hand a reading off to `folder`: audio (MP3 when possible) plus metadata JSON.
+///
+/// Files are written under a temporary name and renamed when complete, so
+/// sync tools never pick up half-written audio. `tags` (title, voice,
+/// license, source) go into the audio and JSON metadata. Returns the audio
+/// file path.
+pub fn export_to_handoff(
+    folder: &Path,
+    text: &str,
+    tags: &ReadingTags,
+    samples: &[f32],
+    sample_rate: u32,
+) -> Result<PathBuf, String> {
+    fs::create_dir_all(folder).map_err(|e| format!("Could not create {}: {e}", folder.display()))?;
+    let now = chrono::Local::now();
+    let stem = reading_file_stem(text, now);
+
+    let wav_partial = folder.join(format!(".{stem}.wav.partial"));
+    fs::write(&wav_partial, encode_wav_with_info(samples, sample_rate, &tags.wav_info()))
+        .map_err(|e| format!("Could not write {}: {e}", wav_partial.display()))?;
+
+    let mp3_partial = folder.join(format!(".{stem}.mp3.partial"));
+    let (partial, audio_path) = if encode_mp3(&wav_partial, &mp3_partial, tags) {
+        let _ = fs::remove_file(&wav_partial);
+        (mp3_partial, folder.join(format!("{stem}.mp3")))
+    } else {
+        let _ = fs::remove_file(&mp3_partial);
+        (wav_partial, folder.join(format!("{stem}.wav")))
+    };
+    fs::rename(&partial, &audio_path).map_err(|e| format!("Could not write {}: {e}", audio_path.display()))?;
+
+    let metadata = HandoffMetadata {
+        title: reading_title(text),
+        created: now.to_rfc3339(),
+        duration_seconds: samples.len() as f32 / sample_rate.max(1) as f32,
+        voice: &tags.voice,
+        voice_license: tags.license.as_deref(),
+        source_url: tags.source_url.as_deref(),
+        software: SOFTWARE,
+        audio_file: audio_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        text,
+    };
+    let json = serde_json::to_string_pretty(&metadata).map_err(|e| e.to_string())?;
+    let json_path = folder.join(format!("{stem}.json"));
+    fs::write(&json_path, json).map_err(|e| format!("Could not write {}: {e}", json_path.display()))?;
+    Ok(audio_path)
+}
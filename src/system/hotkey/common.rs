@@ -1,12 +1,15 @@
 //! Shared hotkey implementation code for platforms that support global hotkeys
 
+use std::collections::HashMap;
 use std::sync::mpsc;
 use global_hotkey::{
     hotkey::{Code, HotKey, Modifiers},
-    GlobalHotKeyManager, GlobalHotKeyEvent,
+    GlobalHotKeyManager, GlobalHotKeyEvent, HotKeyState,
 };
 use tracing::{info, warn};
 
+use super::HotkeyAction;
+
 /// Hotkey configuration
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HotkeyConfig {
@@ -31,9 +34,9 @@ impl Default for HotkeyConfig {
 /// Global hotkey manager
 pub struct HotkeyManager {
     manager: GlobalHotKeyManager,
-    receiver: mpsc::Receiver<()>,
-    _sender: mpsc::Sender<()>,
-    current_hotkey: Option<HotKey>,
+    receiver: mpsc::Receiver<u32>,
+    _sender: mpsc::Sender<u32>,
+    registered: HashMap<HotkeyAction, HotKey>,
 }
 
 impl HotkeyManager {
@@ -44,11 +47,13 @@ impl HotkeyManager {
         
         let (sender, receiver) = mpsc::channel();
         
-        // Set up event handler for hotkey presses
+        // Set up event handler for hotkey presses (releases are ignored, or every press would count twice)
         GlobalHotKeyEvent::set_event_handler(Some({
             let sender = sender.clone();
-            move |_event: GlobalHotKeyEvent| {
-                let _ = sender.send(());
+            move |event: GlobalHotKeyEvent| {
+                if event.state == HotKeyState::Pressed {
+                    let _ = sender.send(event.id);
+                }
             }
         }));
         
@@ -58,16 +63,16 @@ impl HotkeyManager {
             manager,
             receiver,
             _sender: sender,
-            current_hotkey: None,
+            registered: HashMap::new(),
         })
     }
     
-    /// Register a hotkey with the given configuration
-    pub fn register(&mut self, config: HotkeyConfig) -> Result<(), Box<dyn std::error::Error>> {
+    /// Register the hotkey triggering `action`, replacing its previous one
+    pub fn register(&mut self, action: HotkeyAction, config: HotkeyConfig) -> Result<(), Box<dyn std::error::Error>> {
         // Unregister existing hotkey if any
-        if let Some(ref hotkey) = self.current_hotkey {
-            if let Err(e) = self.manager.unregister(*hotkey) {
-                warn!(error = %e, "Failed to unregister previous hotkey");
+        if let Some(hotkey) = self.registered.remove(&action) {
+            if let Err(e) = self.manager.unregister(hotkey) {
+                warn!(error = %e, ?action, "Failed to unregister previous hotkey");
             }
         }
         
@@ -76,25 +81,26 @@ impl HotkeyManager {
         self.manager.register(hotkey)
             .map_err(|e| format!("Failed to register hotkey: {e}"))?;
         
-        self.current_hotkey = Some(hotkey);
-        info!(?config, "Hotkey registered successfully");
+        self.registered.insert(action, hotkey);
+        info!(?action, ?config, "Hotkey registered successfully");
         Ok(())
     }
     
-    /// Unregister the current hotkey
+    /// Unregister all hotkeys
     pub fn unregister(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if let Some(ref hotkey) = self.current_hotkey {
-            self.manager.unregister(*hotkey)
+        for (action, hotkey) in self.registered.drain() {
+            self.manager.unregister(hotkey)
                 .map_err(|e| format!("Failed to unregister hotkey: {e}"))?;
-            self.current_hotkey = None;
-            info!("Hotkey unregistered");
+            info!(?action, "Hotkey unregistered");
         }
         Ok(())
     }
     
-    /// Try to receive a hotkey press event (non-blocking)
-    pub fn try_recv(&self) -> Option<()> {
-        self.receiver.try_recv().ok()
+    /// Try to receive a hotkey press event (non-blocking), as the action it triggers
+    pub fn try_recv(&self) -> Option<HotkeyAction> {
+        self.receiver.try_iter().find_map(|id| {
+            self.registered.iter().find(|(_, hotkey)| hotkey.id() == id).map(|(action, _)| *action)
+        })
     }
 }
 
@@ -21,3 +21,39 @@ mod stub;
 
 #[cfg(not(any(target_os = "macos", target_os = "windows")))]
 pub use stub::*;
+
+use global_hotkey::hotkey::{Code, Modifiers};
+
+/// What a global hotkey does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HotkeyAction {
+    /// Read the selected text, showing the main window
+    Read,
+    /// Pause or resume the current reading
+    PauseResume,
+    /// Stop the current reading
+    Stop,
+}
+
+impl HotkeyAction {
+    pub const ALL: [HotkeyAction; 3] = [HotkeyAction::Read, HotkeyAction::PauseResume, HotkeyAction::Stop];
+
+    /// Name shown in settings.
+    pub fn label(self) -> &'static str {
+        match self {
+            HotkeyAction::Read => "Read selection",
+            HotkeyAction::PauseResume => "Pause / resume",
+            HotkeyAction::Stop => "Stop",
+        }
+    }
+
+    /// Default shortcut: Ctrl+R, Ctrl+Shift+Space and Ctrl+Shift+X (Cmd instead of Ctrl on macOS).
+    pub fn default_config(self) -> HotkeyConfig {
+        let base = HotkeyConfig::default().modifiers;
+        match self {
+            HotkeyAction::Read => HotkeyConfig::default(),
+            HotkeyAction::PauseResume => HotkeyConfig { modifiers: base | Modifiers::SHIFT, key: Code::Space },
+            HotkeyAction::Stop => HotkeyConfig { modifiers: base | Modifiers::SHIFT, key: Code::KeyX },
+        }
+    }
+}
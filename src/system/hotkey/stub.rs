@@ -3,6 +3,8 @@
 use global_hotkey::hotkey::{Code, Modifiers};
 use tracing::warn;
 
+use super::HotkeyAction;
+
 /// Hotkey configuration
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HotkeyConfig {
@@ -33,13 +35,13 @@ impl HotkeyManager {
         })
     }
     
-    /// Register a hotkey (stub)
-    pub fn register(&mut self, _config: HotkeyConfig) -> Result<(), Box<dyn std::error::Error>> {
+    /// Register the hotkey triggering an action (stub)
+    pub fn register(&mut self, _action: HotkeyAction, _config: HotkeyConfig) -> Result<(), Box<dyn std::error::Error>> {
         warn!("Global hotkeys not supported on this platform");
         Ok(())
     }
     
-    /// Unregister the current hotkey (stub)
+    /// Unregister all hotkeys (stub)
     pub fn unregister(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         Ok(())
     }
@@ -50,7 +52,7 @@ impl HotkeyManager {
     }
     
     /// Try to receive a hotkey press event (stub)
-    pub fn try_recv(&self) -> Option<()> {
+    pub fn try_recv(&self) -> Option<HotkeyAction> {
         None
     }
 }
@@ -0,0 +1,112 @@
+//! Listening webhook
+//!
+//! When a webhook URL is set, a small JSON summary is POSTed to it every time
+//! a reading is listened to the end, so listening can be logged into a
+//! self-hosted dashboard (Home Assistant, n8n, a short script, ...). Only the
+//! length, word count and source are sent; the text itself only on request.
+
+use std::time::Duration;
+
+use tracing::debug;
+
+/// How long to wait for the webhook to answer.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Summary of a finished reading, as POSTed to the webhook.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ReadingSummary {
+    /// Always "reading_finished", so receivers can tell events apart
+    event: &'static str,
+    /// When the reading started (RFC 3339)
+    started: String,
+    /// When it finished (RFC 3339)
+    finished: String,
+    /// Length of the audio at normal speed
+    duration_seconds: f32,
+    /// Playback speed it was listened at
+    speed: f32,
+    /// Words in the text
+    word_count: usize,
+    /// Where the text came from (e.g. "HotkeyPressed", "ReadArticle")
+    source: String,
+    /// Web page of the text, when it was a read-later article
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    /// Provider and voice that read it
+    voice: String,
+    /// The text itself, only when the user opted in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+impl ReadingSummary {
+    /// Start a summary for a reading of `text` from `source`.
+    pub fn started(source: &str, text: &str, include_text: bool) -> Self {
+        Self {
+            event: "reading_finished",
+            started: chrono::Local::now().to_rfc3339(),
+            finished: String::new(),
+            duration_seconds: 0.0,
+            speed: 1.0,
+            word_count: text.split_whitespace().count(),
+            source: source.to_string(),
+            url: None,
+            voice: String::new(),
+            text: include_text.then(|| text.to_string()),
+        }
+    }
+
+    /// Complete the summary once the reading was listened to the end.
+    pub fn finished(mut self, duration_seconds: f32, speed: f32, voice: String, url: Option<String>) -> Self {
+        self.finished = chrono::Local::now().to_rfc3339();
+        self.duration_seconds = duration_seconds;
+        self.speed = speed;
+        self.voice = voice;
+        self.url = url;
+        self
+    }
+}
+
+/// POST `summary` as JSON to `url`.
+pub async fn post_reading_summary(url: String, summary: ReadingSummary) -> Result<(), String> {
+    debug!(url = %url, words = summary.word_count, "Posting reading summary to webhook");
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .user_agent(concat!("insight-reader/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| e.to_string())?;
+    let response = client
+        .post(&url)
+        .json(&summary)
+        .send()
+        .await
+        .map_err(|e| format!("Could not reach the webhook: {e}"))?;
+    if !response.status().is_success() {
+        return Err(format!("The webhook answered HTTP {}", response.status()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reading_summary() {
+        let summary = ReadingSummary::started("ReadArticle", "One two  three\nfour", false).finished(
+            42.5,
+            1.5,
+            "piper:en_US-lessac-medium".to_string(),
+            Some("https://example.com/post".to_string()),
+        );
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["event"], "reading_finished");
+        assert_eq!(json["word_count"], 4);
+        assert_eq!(json["source"], "ReadArticle");
+        assert_eq!(json["url"], "https://example.com/post");
+        assert!(json.get("text").is_none());
+
+        let with_text = ReadingSummary::started("HotkeyPressed", "Hello", true);
+        assert_eq!(serde_json::to_value(&with_text).unwrap()["text"], "Hello");
+    }
+}
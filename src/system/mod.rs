@@ -2,15 +2,59 @@
 
 mod clipboard;
 mod text_cleanup;
+mod text_transform;
+mod text_processing;
 mod screenshot;
 mod tray;
 mod hotkey;
+mod quiet_hours;
+mod calendar;
+mod documents;
+mod feeds;
+mod handoff;
+mod listen_webhook;
+mod playlists;
+mod positions;
+mod profiles;
+mod read_later;
+mod recordings;
+mod spreadsheets;
+mod transcript;
+mod watch;
+pub mod segmenter;
 
 pub use clipboard::{get_selected_text, copy_to_clipboard};
-pub use text_cleanup::cleanup_text;
-pub use screenshot::{capture_region, extract_text_from_image};
+pub use text_cleanup::cleaned_to_plain_text;
+pub use text_transform::{run_transforms, TransformKind, TransformStep};
+pub use text_processing::{
+    apply_filter_rules, apply_text_processing, compile_filter, detect_chapters, detect_language, detect_text_language,
+    direction_runs, export_lexicon, import_lexicon, language_name, merge_lexicon, rtl_language, sanitize_text,
+    voice_language, FilterRule, LexiconEntry, LinkReading, ListEnumeration, UrlReading, RtlLanguage, TextProcessingOptions,
+    TableReading, ABBREVIATION_RULES,
+};
+pub use screenshot::{
+    capture_region, captures_dir, clear_captures, discard_capture, lines_text, native_ocr_provider, prune_captures,
+    recognize_lines, OcrLine, OcrProvider, RemoteOcrProvider, SeenLines, TesseractOcrProvider,
+    CAPTURE_RETENTION_PRESETS,
+};
 pub use tray::{SystemTray, TrayEvent};
-pub use hotkey::{HotkeyManager, HotkeyConfig, format_hotkey_display};
+pub use hotkey::{HotkeyAction, HotkeyManager, HotkeyConfig, format_hotkey_display};
+pub use quiet_hours::{is_quiet_now, parse_quiet_hours, QuietRange};
+pub use calendar::{fetch_agenda, CalendarSettings};
+pub use documents::{open_document, page_at, terminal_to_text, Document};
+pub use read_later::{fetch_articles, mark_article_read, Article, ReadLaterService, ReadLaterSettings};
+pub use profiles::{detect_network, match_profile, NetworkContext, ReadingProfile};
+pub use feeds::{fetch_briefing, import_opml, merge_feeds, BriefingItem, FeedSubscription, MAX_HEARD_ITEMS};
+pub use handoff::export_to_handoff;
+pub use listen_webhook::{post_reading_summary, ReadingSummary};
+pub use playlists::{export_playlist, import_playlists, load_playlists, merge_playlists, save_playlists, Playlist};
+pub use positions::{document_key, forget_position, load_position, save_position};
+#[cfg(test)]
+pub use positions::use_positions_file;
+pub use recordings::{default_recordings_folder, save_reading, ReadingTags};
+pub use spreadsheets::{frontmost_app, spreadsheet_selection};
+pub use transcript::{append_transcript, default_transcripts_folder, TranscriptFormat, TranscriptMode};
+pub use watch::{check_watched, forget_snapshot, watch_display_name, DocumentChange, WATCH_INTERVAL};
 
 /// Check if running on Wayland with Hyprland compositor
 #[cfg(target_os = "linux")]
@@ -40,3 +84,23 @@ pub fn is_wayland_hyprland() -> bool {
 }
 
 
+
+/// Run `command` with the system shell, without waiting for it to finish.
+pub fn run_shell_command(command: &str) -> std::io::Result<()> {
+    #[cfg(target_os = "windows")]
+    let mut shell = {
+        let mut shell = std::process::Command::new("cmd");
+        shell.arg("/C");
+        shell
+    };
+    #[cfg(not(target_os = "windows"))]
+    let mut shell = {
+        let mut shell = std::process::Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    let mut child = shell.arg(command).spawn()?;
+    // Reap it once it exits
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
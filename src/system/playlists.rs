@@ -0,0 +1,130 @@
+//! Named playlists
+//!
+//! The queue can be saved as a named playlist ("Spanish practice", "Monday
+//! reading") and loaded back into the queue later, each item with its own
+//! voice. Playlists are kept in the data folder and can be shared as JSON
+//! files holding one playlist or a list of them.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tracing::{debug, warn};
+
+use crate::queue::QueuedItem;
+
+/// A named set of texts to read.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Playlist {
+    pub name: String,
+    pub items: Vec<QueuedItem>,
+}
+
+/// One playlist or several, as found in an imported file.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum PlaylistFile {
+    One(Playlist),
+    Many(Vec<Playlist>),
+}
+
+fn playlists_path() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("insight-reader")
+        .join("playlists.json")
+}
+
+/// The saved playlists, in the order they were created.
+pub fn load_playlists() -> Vec<Playlist> {
+    let path = playlists_path();
+    let Ok(data) = fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+    serde_json::from_str(&data).unwrap_or_else(|e| {
+        warn!(path = %path.display(), error = %e, "Invalid playlists file, starting over");
+        Vec::new()
+    })
+}
+
+/// Save the playlists to the data folder.
+pub fn save_playlists(playlists: &[Playlist]) {
+    let path = playlists_path();
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&path, serde_json::to_vec_pretty(playlists).unwrap_or_default()));
+    match result {
+        Ok(()) => debug!(count = playlists.len(), "Playlists saved"),
+        Err(e) => warn!(path = %path.display(), error = %e, "Failed to save playlists"),
+    }
+}
+
+/// Add `imported` playlists to `playlists`, replacing playlists of the same name.
+///
+/// Returns the number of playlists imported.
+pub fn merge_playlists(playlists: &mut Vec<Playlist>, imported: Vec<Playlist>) -> usize {
+    let count = imported.len();
+    for playlist in imported {
+        match playlists
+            .iter_mut()
+            .find(|existing| existing.name.to_lowercase() == playlist.name.to_lowercase())
+        {
+            Some(existing) => *existing = playlist,
+            None => playlists.push(playlist),
+        }
+    }
+    count
+}
+
+/// Read the playlists of a JSON file (one playlist or a list of them).
+pub fn import_playlists(path: &Path) -> Result<Vec<Playlist>, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+    let playlists = match serde_json::from_str(&content).map_err(|e| format!("Invalid playlist file: {e}"))? {
+        PlaylistFile::One(playlist) => vec![playlist],
+        PlaylistFile::Many(playlists) => playlists,
+    };
+    let playlists: Vec<Playlist> = playlists
+        .into_iter()
+        .filter(|playlist| !playlist.name.trim().is_empty())
+        .collect();
+    if playlists.is_empty() {
+        return Err(format!("No playlists found in {}", path.display()));
+    }
+    Ok(playlists)
+}
+
+/// Write one playlist to a JSON file.
+pub fn export_playlist(path: &Path, playlist: &Playlist) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(playlist).map_err(|e| e.to_string())?;
+    fs::write(path, content).map_err(|e| format!("Could not write {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_and_merge() {
+        let dir = std::env::temp_dir().join(format!("insight-reader-playlists-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("spanish.json");
+        let spanish = Playlist {
+            name: "Spanish practice".to_string(),
+            items: vec![QueuedItem::from("Hola".to_string())],
+        };
+        export_playlist(&path, &spanish).unwrap();
+        assert_eq!(import_playlists(&path).unwrap(), std::slice::from_ref(&spanish));
+
+        fs::write(&path, r#"[{"name": "spanish PRACTICE", "items": []}, {"name": "Monday", "items": [{"text": "News"}]}]"#)
+            .unwrap();
+        let mut playlists = vec![spanish];
+        assert_eq!(merge_playlists(&mut playlists, import_playlists(&path).unwrap()), 2);
+        assert_eq!(playlists.len(), 2);
+        assert!(playlists[0].items.is_empty());
+        assert_eq!(playlists[1].items[0].text, "News");
+
+        fs::write(&path, "[]").unwrap();
+        assert!(import_playlists(&path).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+//! Remembered playback positions
+//!
+//! When a reading is stopped part-way, its position is saved under a hash of
+//! the text. Reading the same text again (e.g. re-triggering on the same
+//! selection) then offers to resume from there instead of restarting.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use tracing::{debug, warn};
+
+/// Documents remembered at most; the least recently saved are dropped first.
+const MAX_POSITIONS: usize = 200;
+
+/// A saved position.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+struct SavedPosition {
+    /// Seconds into the reading at normal speed
+    seconds: f32,
+    /// When it was saved (Unix timestamp)
+    saved: i64,
+}
+
+#[cfg(test)]
+thread_local! {
+    /// File used instead of the user's on this thread, see [`use_positions_file`].
+    static POSITIONS_FILE: std::cell::RefCell<Option<PathBuf>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Keep this thread's positions in `path` instead of the user's data, for tests.
+#[cfg(test)]
+pub fn use_positions_file(path: PathBuf) {
+    POSITIONS_FILE.with(|file| *file.borrow_mut() = Some(path));
+}
+
+fn positions_path() -> PathBuf {
+    #[cfg(test)]
+    if let Some(path) = POSITIONS_FILE.with(|file| file.borrow().clone()) {
+        return path;
+    }
+    dirs::data_local_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("insight-reader")
+        .join("positions.json")
+}
+
+fn load_positions() -> HashMap<String, SavedPosition> {
+    let path = positions_path();
+    let Ok(data) = fs::read_to_string(&path) else {
+        return HashMap::new();
+    };
+    serde_json::from_str(&data).unwrap_or_else(|e| {
+        warn!(path = %path.display(), error = %e, "Invalid saved positions, starting over");
+        HashMap::new()
+    })
+}
+
+fn save_positions(positions: &HashMap<String, SavedPosition>) {
+    let path = positions_path();
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&path, serde_json::to_vec(positions).unwrap_or_default()));
+    if let Err(e) = result {
+        warn!(path = %path.display(), error = %e, "Failed to save playback positions");
+    }
+}
+
+/// Drop the least recently saved positions beyond [`MAX_POSITIONS`].
+fn prune(positions: &mut HashMap<String, SavedPosition>) {
+    if positions.len() <= MAX_POSITIONS {
+        return;
+    }
+    let mut by_age: Vec<(String, i64)> = positions.iter().map(|(key, p)| (key.clone(), p.saved)).collect();
+    by_age.sort_by_key(|&(_, saved)| std::cmp::Reverse(saved));
+    for (key, _) in by_age.into_iter().skip(MAX_POSITIONS) {
+        positions.remove(&key);
+    }
+}
+
+/// Key identifying a text, independent of how its whitespace is laid out.
+pub fn document_key(text: &str) -> String {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    format!("{:x}", md5::compute(flat.as_bytes()))
+}
+
+/// Position (seconds at normal speed) the reading of `key` was stopped at, if any.
+pub fn load_position(key: &str) -> Option<f32> {
+    load_positions().get(key).map(|position| position.seconds)
+}
+
+/// Remember that the reading of `key` was stopped `seconds` in.
+pub fn save_position(key: &str, seconds: f32) {
+    let mut positions = load_positions();
+    positions.insert(
+        key.to_string(),
+        SavedPosition { seconds, saved: chrono::Utc::now().timestamp() },
+    );
+    prune(&mut positions);
+    save_positions(&positions);
+    debug!(key, seconds, "Playback position saved");
+}
+
+/// Forget the position of `key`, e.g. once it was read to the end.
+pub fn forget_position(key: &str) {
+    let mut positions = load_positions();
+    if positions.remove(key).is_some() {
+        save_positions(&positions);
+        debug!(key, "Playback position forgotten");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_key_and_prune() {
+        assert_eq!(document_key("Hello  world\n"), document_key("Hello world"));
+        assert_ne!(document_key("Hello world"), document_key("Hello there"));
+
+        let mut positions: HashMap<String, SavedPosition> = (0..MAX_POSITIONS as i64 + 5)
+            .map(|i| (i.to_string(), SavedPosition { seconds: 1.0, saved: i }))
+            .collect();
+        prune(&mut positions);
+        assert_eq!(positions.len(), MAX_POSITIONS);
+        assert!(!positions.contains_key("4"));
+        assert!(positions.contains_key("5"));
+    }
+}
@@ -0,0 +1,161 @@
+//! Workspace profiles
+//!
+//! A profile bundles output settings (device and volume) with the Wi-Fi
+//! networks or hostnames where it applies, e.g. an "office" profile that uses
+//! headphones at a lower volume. The first profile matching the current
+//! network or hostname is applied automatically.
+
+use std::process::Command;
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+use tracing::debug;
+
+use crate::providers::OutputSettings;
+
+/// Output settings applied on matching networks or hosts.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ReadingProfile {
+    /// Display name (e.g., "office")
+    pub name: String,
+    /// Wi-Fi SSIDs or hostnames this profile applies to
+    #[serde(default)]
+    pub matches: Vec<String>,
+    /// Output device and volume used while the profile is active
+    #[serde(default)]
+    pub output: OutputSettings,
+}
+
+/// Where the app is running: current Wi-Fi network and hostname.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkContext {
+    /// Connected Wi-Fi SSID, if any (and detectable on this platform)
+    pub ssid: Option<String>,
+    /// Machine hostname
+    pub hostname: Option<String>,
+}
+
+impl NetworkContext {
+    /// The value a new profile should match by default (SSID, else hostname).
+    pub fn preferred_match(&self) -> Option<&str> {
+        self.ssid.as_deref().or(self.hostname.as_deref())
+    }
+}
+
+/// Index of the first profile matching the network or hostname (case-insensitive).
+pub fn match_profile(profiles: &[ReadingProfile], context: &NetworkContext) -> Option<usize> {
+    let candidates: Vec<&str> = [context.ssid.as_deref(), context.hostname.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect();
+    profiles.iter().position(|profile| {
+        profile
+            .matches
+            .iter()
+            .any(|m| candidates.iter().any(|c| c.eq_ignore_ascii_case(m.trim())))
+    })
+}
+
+/// Detect the current Wi-Fi SSID and hostname (blocking: runs system commands).
+pub fn detect_network() -> NetworkContext {
+    let context = NetworkContext {
+        ssid: detect_ssid(),
+        hostname: detect_hostname(),
+    };
+    debug!(?context, "Network context detected");
+    context
+}
+
+/// Run a command and return its stdout if it succeeded.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let mut command = Command::new(program);
+    command.args(args);
+    #[cfg(target_os = "windows")]
+    {
+        // Prevent a console window from flashing up
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    let output = command.output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn detect_hostname() -> Option<String> {
+    command_output("hostname", &[])
+        .and_then(|output| non_empty(&output))
+        .or_else(|| std::env::var("HOSTNAME").ok().and_then(|h| non_empty(&h)))
+        .or_else(|| std::env::var("COMPUTERNAME").ok().and_then(|h| non_empty(&h)))
+}
+
+#[cfg(target_os = "linux")]
+fn detect_ssid() -> Option<String> {
+    // NetworkManager first, then wireless-tools
+    command_output("nmcli", &["-t", "-f", "active,ssid", "dev", "wifi"])
+        .and_then(|output| {
+            output
+                .lines()
+                .find_map(|line| line.strip_prefix("yes:").and_then(non_empty))
+        })
+        .or_else(|| command_output("iwgetid", &["-r"]).and_then(|output| non_empty(&output)))
+}
+
+#[cfg(target_os = "macos")]
+fn detect_ssid() -> Option<String> {
+    command_output("networksetup", &["-getairportnetwork", "en0"]).and_then(|output| {
+        output
+            .trim()
+            .strip_prefix("Current Wi-Fi Network:")
+            .and_then(non_empty)
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn detect_ssid() -> Option<String> {
+    command_output("netsh", &["wlan", "show", "interfaces"]).and_then(|output| {
+        output.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            (key.trim() == "SSID").then(|| non_empty(value)).flatten()
+        })
+    })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn detect_ssid() -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(name: &str, matches: &[&str]) -> ReadingProfile {
+        ReadingProfile {
+            name: name.to_string(),
+            matches: matches.iter().map(|m| m.to_string()).collect(),
+            output: OutputSettings::default(),
+        }
+    }
+
+    #[test]
+    fn test_match_profile() {
+        let profiles = vec![profile("office", &["CorpWiFi", "work-laptop"]), profile("home", &["HomeNet"])];
+        let context = |ssid: Option<&str>, hostname: Option<&str>| NetworkContext {
+            ssid: ssid.map(str::to_string),
+            hostname: hostname.map(str::to_string),
+        };
+
+        assert_eq!(match_profile(&profiles, &context(Some("homenet"), Some("laptop"))), Some(1));
+        assert_eq!(match_profile(&profiles, &context(None, Some("work-laptop"))), Some(0));
+        assert_eq!(match_profile(&profiles, &context(Some("Cafe"), Some("laptop"))), None);
+        assert_eq!(match_profile(&profiles, &NetworkContext::default()), None);
+    }
+}
@@ -0,0 +1,97 @@
+//! Do-not-disturb schedule
+//!
+//! Quiet hours are daily time ranges (e.g. "22:00-07:00, 12:30-13:00") during
+//! which automatic reads are held in the queue instead of being spoken. Ranges
+//! may wrap past midnight. Reads the user starts by hand are never affected.
+
+use chrono::Timelike;
+
+/// One daily quiet range, in minutes since midnight (end exclusive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl QuietRange {
+    /// Whether `minute` (since midnight) falls inside the range.
+    fn contains(&self, minute: u16) -> bool {
+        if self.start <= self.end {
+            (self.start..self.end).contains(&minute)
+        } else {
+            // Wraps past midnight
+            minute >= self.start || minute < self.end
+        }
+    }
+}
+
+/// Parse a comma-separated list of "HH:MM-HH:MM" ranges.
+pub fn parse_quiet_hours(input: &str) -> Result<Vec<QuietRange>, String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let (start, end) = part
+                .split_once('-')
+                .ok_or_else(|| format!("\"{part}\" is not a range like 22:00-07:00"))?;
+            Ok(QuietRange {
+                start: parse_time(start)?,
+                end: parse_time(end)?,
+            })
+        })
+        .collect()
+}
+
+/// Parse "HH:MM" (or "HH") into minutes since midnight.
+fn parse_time(input: &str) -> Result<u16, String> {
+    let input = input.trim();
+    let (hours, minutes) = input.split_once(':').unwrap_or((input, "0"));
+    let hours: u16 = hours.parse().map_err(|_| format!("Invalid time \"{input}\""))?;
+    let minutes: u16 = minutes.parse().map_err(|_| format!("Invalid time \"{input}\""))?;
+    if hours > 24 || minutes > 59 || (hours == 24 && minutes > 0) {
+        return Err(format!("Invalid time \"{input}\""));
+    }
+    Ok(hours * 60 + minutes)
+}
+
+/// Whether `minute` (since midnight) falls inside any of the ranges.
+pub fn is_quiet_at(ranges: &[QuietRange], minute: u16) -> bool {
+    ranges.iter().any(|range| range.contains(minute))
+}
+
+/// Whether quiet hours are in effect right now (local time).
+pub fn is_quiet_now(ranges: &[QuietRange]) -> bool {
+    let now = chrono::Local::now();
+    is_quiet_at(ranges, (now.hour() * 60 + now.minute()) as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_quiet_hours() {
+        assert_eq!(
+            parse_quiet_hours("22:00-07:00, 12:30-13").unwrap(),
+            vec![
+                QuietRange { start: 22 * 60, end: 7 * 60 },
+                QuietRange { start: 12 * 60 + 30, end: 13 * 60 },
+            ]
+        );
+        assert_eq!(parse_quiet_hours("").unwrap(), vec![]);
+        assert!(parse_quiet_hours("22:00").is_err());
+        assert!(parse_quiet_hours("25:00-07:00").is_err());
+    }
+
+    #[test]
+    fn test_is_quiet_at() {
+        let ranges = parse_quiet_hours("22:00-07:00, 12:30-13:00").unwrap();
+        assert!(is_quiet_at(&ranges, 23 * 60));
+        assert!(is_quiet_at(&ranges, 6 * 60 + 59));
+        assert!(!is_quiet_at(&ranges, 7 * 60));
+        assert!(is_quiet_at(&ranges, 12 * 60 + 45));
+        assert!(!is_quiet_at(&ranges, 13 * 60));
+        assert!(!is_quiet_at(&[], 0));
+    }
+}
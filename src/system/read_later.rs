@@ -0,0 +1,254 @@
+//! Read-later services (Wallabag, Readwise Reader)
+//!
+//! Lists the unread articles saved in the user's read-later service and
+//! archives them once they have been listened to. Both services keep the
+//! article text they extracted when the page was saved, so nothing has to be
+//! scraped here: the stored HTML is turned into text like feed items.
+//! (Pocket shut down its API in 2025, so it isn't offered.)
+
+use std::time::Duration;
+
+use tracing::{debug, info};
+
+use super::text_processing::html_to_text;
+
+/// Readwise Reader API root.
+const READWISE_API_URL: &str = "https://readwise.io/api/v3";
+
+/// Unread articles listed at most.
+const MAX_ARTICLES: usize = 50;
+
+/// Timeout for each API request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Which read-later service to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadLaterService {
+    /// Self-hosted or wallabag.it (OAuth client + user credentials)
+    #[default]
+    Wallabag,
+    /// Readwise Reader (access token)
+    Readwise,
+}
+
+/// Connection settings for the read-later service.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ReadLaterSettings {
+    /// Selected service
+    pub service: ReadLaterService,
+    /// Wallabag server URL (e.g. "https://app.wallabag.it")
+    pub wallabag_url: String,
+    /// Wallabag API client id (created under "API clients management")
+    pub wallabag_client_id: String,
+    /// Wallabag API client secret
+    pub wallabag_client_secret: String,
+    /// Wallabag user name
+    pub wallabag_username: String,
+    /// Wallabag password
+    pub wallabag_password: String,
+    /// Readwise access token (from readwise.io/access_token)
+    pub readwise_token: String,
+}
+
+impl ReadLaterSettings {
+    /// Whether every setting the selected service needs is filled in.
+    pub fn is_configured(&self) -> bool {
+        match self.service {
+            ReadLaterService::Wallabag => [
+                &self.wallabag_url,
+                &self.wallabag_client_id,
+                &self.wallabag_client_secret,
+                &self.wallabag_username,
+                &self.wallabag_password,
+            ]
+            .iter()
+            .all(|value| !value.trim().is_empty()),
+            ReadLaterService::Readwise => !self.readwise_token.trim().is_empty(),
+        }
+    }
+
+    fn wallabag_api(&self, path: &str) -> String {
+        format!("{}/{path}", self.wallabag_url.trim().trim_end_matches('/'))
+    }
+}
+
+/// An unread article.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Article {
+    /// Id in the service, used to archive it
+    pub id: String,
+    /// Article title
+    pub title: String,
+    /// Original page URL
+    pub url: String,
+    /// Text to read: title, then the article body
+    pub text: String,
+}
+
+impl Article {
+    fn new(id: String, title: &str, url: &str, html: &str) -> Self {
+        let title = html_to_text(title);
+        let body = html_to_text(html);
+        let text = match (title.is_empty(), body.is_empty()) {
+            (false, false) => format!("{title}.\n\n{body}"),
+            (false, true) => title.clone(),
+            _ => body,
+        };
+        Self {
+            id,
+            title,
+            url: url.to_string(),
+            text,
+        }
+    }
+}
+
+/// Articles in a Wallabag `/api/entries` response.
+fn parse_wallabag_entries(json: &serde_json::Value) -> Vec<Article> {
+    json["_embedded"]["items"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|item| {
+            let id = item["id"].as_u64()?.to_string();
+            let title = item["title"].as_str().unwrap_or_default();
+            let url = item["url"].as_str().unwrap_or_default();
+            Some(Article::new(id, title, url, item["content"].as_str().unwrap_or_default()))
+        })
+        .collect()
+}
+
+/// Documents in a Readwise Reader `/list` response (highlights and notes skipped).
+fn parse_readwise_documents(json: &serde_json::Value) -> Vec<Article> {
+    json["results"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|document| document["parent_id"].is_null())
+        .filter_map(|document| {
+            let id = document["id"].as_str()?.to_string();
+            let title = document["title"].as_str().unwrap_or_default();
+            let url = document["source_url"].as_str().unwrap_or_default();
+            Some(Article::new(id, title, url, document["html_content"].as_str().unwrap_or_default()))
+        })
+        .collect()
+}
+
+fn http_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))
+}
+
+/// Send `request` and parse the JSON response, turning HTTP errors into messages.
+async fn send_json(request: reqwest::RequestBuilder) -> Result<serde_json::Value, String> {
+    let response = request.send().await.map_err(|e| format!("Request failed: {e}"))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+        return Err("The read-later service rejected the credentials".to_string());
+    }
+    if !status.is_success() {
+        return Err(format!("The read-later service returned {status}"));
+    }
+    response.json().await.map_err(|e| format!("Invalid response: {e}"))
+}
+
+/// Log in to Wallabag and return an access token.
+async fn wallabag_token(client: &reqwest::Client, settings: &ReadLaterSettings) -> Result<String, String> {
+    let form = [
+        ("grant_type", "password"),
+        ("client_id", settings.wallabag_client_id.trim()),
+        ("client_secret", settings.wallabag_client_secret.trim()),
+        ("username", settings.wallabag_username.trim()),
+        ("password", settings.wallabag_password.as_str()),
+    ];
+    let json = send_json(client.post(settings.wallabag_api("oauth/v2/token")).form(&form)).await?;
+    json["access_token"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| "Wallabag login returned no access token".to_string())
+}
+
+/// Fetch the unread articles, newest first.
+pub async fn fetch_articles(settings: ReadLaterSettings) -> Result<Vec<Article>, String> {
+    let client = http_client()?;
+    let mut articles = match settings.service {
+        ReadLaterService::Wallabag => {
+            let token = wallabag_token(&client, &settings).await?;
+            let request = client
+                .get(settings.wallabag_api("api/entries.json"))
+                .bearer_auth(token)
+                .query(&[("archive", "0"), ("sort", "created"), ("order", "desc"), ("detail", "full")])
+                .query(&[("perPage", MAX_ARTICLES)]);
+            parse_wallabag_entries(&send_json(request).await?)
+        }
+        ReadLaterService::Readwise => {
+            let request = client
+                .get(format!("{READWISE_API_URL}/list/"))
+                .header("Authorization", format!("Token {}", settings.readwise_token.trim()))
+                .query(&[("location", "later"), ("withHtmlContent", "true")]);
+            parse_readwise_documents(&send_json(request).await?)
+        }
+    };
+    articles.truncate(MAX_ARTICLES);
+    info!(service = ?settings.service, articles = articles.len(), "Read-later articles fetched");
+    Ok(articles)
+}
+
+/// Archive an article once it has been read. Returns its id.
+pub async fn mark_article_read(settings: ReadLaterSettings, id: String) -> Result<String, String> {
+    debug!(service = ?settings.service, %id, "Archiving read-later article");
+    let client = http_client()?;
+    match settings.service {
+        ReadLaterService::Wallabag => {
+            let token = wallabag_token(&client, &settings).await?;
+            let request = client
+                .patch(settings.wallabag_api(&format!("api/entries/{id}.json")))
+                .bearer_auth(token)
+                .json(&serde_json::json!({ "archive": 1 }));
+            send_json(request).await?;
+        }
+        ReadLaterService::Readwise => {
+            let request = client
+                .patch(format!("{READWISE_API_URL}/update/{id}/"))
+                .header("Authorization", format!("Token {}", settings.readwise_token.trim()))
+                .json(&serde_json::json!({ "location": "archive" }));
+            send_json(request).await?;
+        }
+    }
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_articles() {
+        let wallabag = serde_json::json!({
+            "_embedded": { "items": [
+                { "id": 12, "title": "Rust &amp; you", "url": "https://example.com/a", "content": "<p>Hello</p><p>world</p>" },
+                { "title": "No id" }
+            ]}
+        });
+        let articles = parse_wallabag_entries(&wallabag);
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].id, "12");
+        assert_eq!(articles[0].title, "Rust & you");
+        assert_eq!(articles[0].text, "Rust & you.\n\nHello\nworld");
+
+        let readwise = serde_json::json!({
+            "results": [
+                { "id": "01ab", "title": "Saved", "source_url": "https://example.com/b", "html_content": null, "parent_id": null },
+                { "id": "01cd", "title": "A highlight", "parent_id": "01ab" }
+            ]
+        });
+        let articles = parse_readwise_documents(&readwise);
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].text, "Saved");
+        assert!(parse_readwise_documents(&serde_json::json!({})).is_empty());
+    }
+}
@@ -0,0 +1,149 @@
+//! Saved readings
+//!
+//! When "save readings" is on, every reading is also written to a WAV file in
+//! the recordings folder, with the text it was read from in a `.txt` file of
+//! the same name, so it can be listened to again later (e.g. on a phone).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::providers::encode_wav_with_info;
+
+/// Words of the text used in the file name.
+const TITLE_WORDS: usize = 6;
+
+/// Default folder for saved readings: "Insight Reader" in the user's music folder.
+pub fn default_recordings_folder() -> PathBuf {
+    dirs::audio_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(std::env::temp_dir)
+        .join("Insight Reader")
+}
+
+/// First words of `text`, without punctuation, to name a reading.
+pub(super) fn reading_title(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| word.chars().filter(|c| c.is_alphanumeric()).collect::<String>())
+        .filter(|word| !word.is_empty())
+        .take(TITLE_WORDS)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// File name (without extension) for a reading: timestamp and first words.
+pub(super) fn reading_file_stem(text: &str, time: chrono::DateTime<chrono::Local>) -> String {
+    let title = reading_title(text);
+    let timestamp = time.format("%Y-%m-%d %H-%M-%S");
+    if title.is_empty() {
+        timestamp.to_string()
+    } else {
+        format!("{timestamp} {title}")
+    }
+}
+
+/// Name and version written into exported audio as the encoding software.
+pub(super) const SOFTWARE: &str = concat!("Insight Reader ", env!("CARGO_PKG_VERSION"));
+
+/// Longest title written into audio metadata, in characters.
+const MAX_TAG_TITLE: usize = 120;
+
+/// Metadata tags of an exported reading, so podcast and audiobook apps can
+/// list it properly.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReadingTags {
+    /// First line of the text
+    pub title: String,
+    /// Where the text came from (e.g. a read-later article), when known
+    pub source_url: Option<String>,
+    /// Provider and voice that read it
+    pub voice: String,
+    /// License and dataset attribution of the voice, when known
+    pub license: Option<String>,
+}
+
+impl ReadingTags {
+    pub fn new(text: &str, voice: String, source_url: Option<String>, license: Option<String>) -> Self {
+        Self { title: tag_title(text), source_url, voice, license }
+    }
+
+    /// The tags as WAV `INFO` entries.
+    pub(super) fn wav_info(&self) -> Vec<([u8; 4], &str)> {
+        let mut info = Vec::new();
+        if !self.title.is_empty() {
+            info.push((*b"INAM", self.title.as_str()));
+        }
+        if !self.voice.is_empty() {
+            info.push((*b"IART", self.voice.as_str()));
+        }
+        if let Some(url) = &self.source_url {
+            info.push((*b"ICMT", url.as_str()));
+        }
+        if let Some(license) = &self.license {
+            info.push((*b"ICOP", license.as_str()));
+        }
+        info.push((*b"ISFT", SOFTWARE));
+        info
+    }
+}
+
+/// First non-empty line of `text`, shortened to [`MAX_TAG_TITLE`] characters.
+fn tag_title(text: &str) -> String {
+    let line = text.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or_default();
+    let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= MAX_TAG_TITLE {
+        return line;
+    }
+    let mut title: String = line.chars().take(MAX_TAG_TITLE - 1).collect();
+    title.push('…');
+    title
+}
+
+/// Write a reading to `folder` as a WAV file plus its source text.
+///
+/// `tags` (title, voice, license, ...) are stored in the WAV metadata.
+/// Returns the path of the audio file.
+pub fn save_reading(
+    folder: &Path,
+    text: &str,
+    samples: &[f32],
+    sample_rate: u32,
+    tags: &ReadingTags,
+) -> Result<PathBuf, String> {
+    fs::create_dir_all(folder).map_err(|e| format!("Could not create {}: {e}", folder.display()))?;
+    let stem = reading_file_stem(text, chrono::Local::now());
+    let audio_path = folder.join(format!("{stem}.wav"));
+    fs::write(&audio_path, encode_wav_with_info(samples, sample_rate, &tags.wav_info()))
+        .map_err(|e| format!("Could not write {}: {e}", audio_path.display()))?;
+    let text_path = folder.join(format!("{stem}.txt"));
+    fs::write(&text_path, text).map_err(|e| format!("Could not write {}: {e}", text_path.display()))?;
+    Ok(audio_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_reading_file_stem() {
+        let time = chrono::Local.with_ymd_and_hms(2024, 3, 9, 14, 5, 0).unwrap();
+        assert_eq!(
+            reading_file_stem("The \"quick\" brown fox: jumps over the lazy dog.", time),
+            "2024-03-09 14-05-00 The quick brown fox jumps over"
+        );
+        assert_eq!(reading_file_stem("/// ...", time), "2024-03-09 14-05-00");
+    }
+
+    #[test]
+    fn test_reading_tags() {
+        let tags = ReadingTags::new("\n  Chapter  One \nIt was a dark night.", "piper:en_US-lessac".into(), None, None);
+        assert_eq!(tags.title, "Chapter One");
+        let keys: Vec<[u8; 4]> = tags.wav_info().iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, [*b"INAM", *b"IART", *b"ISFT"]);
+
+        let long = "word ".repeat(100);
+        let title = ReadingTags::new(&long, String::new(), None, None).title;
+        assert_eq!(title.chars().count(), MAX_TAG_TITLE);
+        assert!(title.ends_with('…'));
+    }
+}
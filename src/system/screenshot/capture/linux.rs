@@ -1,6 +1,5 @@
 //! Linux-specific screenshot capture implementation
 
-use std::env;
 use std::path::Path;
 use std::process::Command;
 use tracing::{debug, error, info};
@@ -103,12 +102,11 @@ fn try_grim_slurp(output_path: &Path) -> Option<Result<String, String>> {
     }
 }
 
-/// Captures a screenshot region on Linux using available screenshot tools.
-pub(super) fn capture_region_linux() -> Result<String, String> {
+/// Captures a screenshot region on Linux using available screenshot tools, saving it to `screenshot_path`.
+pub(super) fn capture_region_linux(screenshot_path: &Path) -> Result<String, String> {
     info!("Starting interactive screenshot region selection on Linux");
     
-    let screenshot_path = env::temp_dir().join("insight-reader-screenshot.png");
-    debug!(path = %screenshot_path.display(), "Screenshot will be saved to temp file");
+    debug!(path = %screenshot_path.display(), "Screenshot will be saved to capture file");
     
     // Tools in order of preference
     const TOOLS: &[Tool] = &[
@@ -122,19 +120,19 @@ pub(super) fn capture_region_linux() -> Result<String, String> {
     
     // Try flameshot and maim first
     for tool in &TOOLS[..2] {
-        if let Some(result) = try_tool(tool, &screenshot_path) {
+        if let Some(result) = try_tool(tool, screenshot_path) {
             return result;
         }
     }
     
     // Try grim+slurp (Wayland)
-    if let Some(result) = try_grim_slurp(&screenshot_path) {
+    if let Some(result) = try_grim_slurp(screenshot_path) {
         return result;
     }
     
     // Try remaining tools
     for tool in &TOOLS[2..] {
-        if let Some(result) = try_tool(tool, &screenshot_path) {
+        if let Some(result) = try_tool(tool, screenshot_path) {
             return result;
         }
     }
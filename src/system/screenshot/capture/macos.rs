@@ -1,18 +1,14 @@
 //! macOS-specific screenshot capture implementation
 
-use std::env;
+use std::path::Path;
 use std::process::Command;
 use tracing::{debug, error, info};
 
-/// Captures a screenshot region on macOS using screencapture.
-pub(super) fn capture_region_macos() -> Result<String, String> {
+/// Captures a screenshot region on macOS using screencapture, saving it to `screenshot_path`.
+pub(super) fn capture_region_macos(screenshot_path: &Path) -> Result<String, String> {
     info!("Starting interactive screenshot region selection");
     
-    // Create temporary file path for the screenshot
-    let temp_dir = env::temp_dir();
-    let screenshot_path = temp_dir.join("insight-reader-screenshot.png");
-    
-    debug!(path = %screenshot_path.display(), "Screenshot will be saved to temp file");
+    debug!(path = %screenshot_path.display(), "Screenshot will be saved to capture file");
     
     // Execute screencapture with -i flag for interactive region selection
     // -i: interactive mode (shows crosshair for region selection)
@@ -7,31 +7,36 @@ mod macos;
 #[cfg(target_os = "windows")]
 mod windows;
 
+use super::captures;
+
 /// Captures a screenshot of a selected screen region.
 /// 
 /// On macOS, uses `screencapture -i` for interactive region selection.
 /// On Linux, tries multiple screenshot tools in order of preference.
 /// On Windows, uses PowerShell with Windows Forms for interactive region selection.
-/// Returns the path to the captured image file, or an error message.
+/// Returns the path to the captured image file (a new private file per
+/// capture), or an error message.
 pub fn capture_region() -> Result<String, String> {
+    #[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
+    let screenshot_path = captures::new_capture_path()?;
+
     #[cfg(target_os = "macos")]
-    {
-        macos::capture_region_macos()
-    }
+    let result = macos::capture_region_macos(&screenshot_path);
     
     #[cfg(target_os = "linux")]
-    {
-        linux::capture_region_linux()
-    }
+    let result = linux::capture_region_linux(&screenshot_path);
     
     #[cfg(target_os = "windows")]
-    {
-        windows::capture_region_windows()
-    }
+    let result = windows::capture_region_windows(&screenshot_path);
     
     #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
-    {
+    let result = {
         tracing::warn!("Screenshot region selection not supported on this platform");
         Err("Screenshot region selection is only supported on macOS, Linux, and Windows".to_string())
+    };
+
+    if let Ok(path) = &result {
+        captures::protect(std::path::Path::new(path));
     }
+    result
 }
@@ -1,7 +1,7 @@
 //! Windows-specific screenshot capture implementation
 
-use std::env;
 use std::os::windows::process::CommandExt;
+use std::path::Path;
 use std::process::Command;
 use tracing::{debug, error, info};
 
@@ -181,12 +181,11 @@ $cropped.Dispose()
 exit 0
 "#;
 
-/// Captures a screenshot region on Windows using PowerShell with Windows Forms.
-pub(super) fn capture_region_windows() -> Result<String, String> {
+/// Captures a screenshot region on Windows using PowerShell with Windows Forms, saving it to `screenshot_path`.
+pub(super) fn capture_region_windows(screenshot_path: &Path) -> Result<String, String> {
     info!("Starting interactive screenshot region selection on Windows");
     
-    let screenshot_path = env::temp_dir().join("insight-reader-screenshot.png");
-    debug!(path = %screenshot_path.display(), "Screenshot will be saved to temp file");
+    debug!(path = %screenshot_path.display(), "Screenshot will be saved to capture file");
     
     // Get the path as a string, properly escaped for PowerShell
     // Escape single quotes by doubling them (PowerShell escaping)
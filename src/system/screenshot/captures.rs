@@ -0,0 +1,106 @@
+//! Screenshot capture files
+//!
+//! Every capture gets its own file in a folder only the user can read (in the
+//! user's own cache folder, never the shared storage folder: moving the cache
+//! there leaves this folder behind). Captures are
+//! deleted once their text was extracted, unless they are kept for a while to
+//! debug OCR; older ones are pruned before each new capture.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime};
+
+use tracing::{debug, warn};
+
+/// Retention periods offered in the settings, in hours.
+pub const CAPTURE_RETENTION_PRESETS: [u32; 2] = [1, 24];
+
+/// Fixed path captures were written to by earlier versions, readable by anyone.
+const LEGACY_CAPTURE: &str = "insight-reader-screenshot.png";
+
+/// Folder the captures are kept in.
+pub fn captures_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("insight-reader")
+        .join("captures")
+}
+
+/// Create the captures folder, readable only by the user.
+fn create_private_dir() -> Result<PathBuf, String> {
+    crate::storage::private_dir("captures")
+        .map_err(|e| format!("Could not create {}: {e}", captures_dir().display()))
+}
+
+/// A new, unique path for the next capture (the file itself is written by the capture tool).
+pub(super) fn new_capture_path() -> Result<PathBuf, String> {
+    static NEXT_CAPTURE: AtomicUsize = AtomicUsize::new(0);
+    let dir = create_private_dir()?;
+    let name = format!(
+        "capture-{}-{}-{}.png",
+        chrono::Local::now().format("%Y%m%d-%H%M%S"),
+        std::process::id(),
+        NEXT_CAPTURE.fetch_add(1, Ordering::Relaxed)
+    );
+    Ok(dir.join(name))
+}
+
+/// Make a capture readable only by the user, whatever mode the capture tool wrote it with.
+pub(super) fn protect(path: &std::path::Path) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Err(e) = fs::set_permissions(path, fs::Permissions::from_mode(0o600)) {
+            warn!(path = %path.display(), error = %e, "Failed to restrict capture permissions");
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+/// Delete the capture at `path` once its text was extracted, unless captures are kept.
+pub fn discard_capture(path: &str, retention_hours: Option<u32>) {
+    if retention_hours.is_some() {
+        return;
+    }
+    match fs::remove_file(path) {
+        Ok(()) => debug!(%path, "Capture deleted"),
+        Err(e) => warn!(%path, error = %e, "Failed to delete capture"),
+    }
+}
+
+/// Delete captures older than `retention_hours` (all of them when None), and the legacy capture.
+pub fn prune_captures(retention_hours: Option<u32>) {
+    let max_age = Duration::from_secs(retention_hours.unwrap_or(0) as u64 * 3600);
+    let _ = fs::remove_file(std::env::temp_dir().join(LEGACY_CAPTURE));
+    let Ok(entries) = fs::read_dir(captures_dir()) else {
+        return;
+    };
+    let now = SystemTime::now();
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let age = fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok())
+            .unwrap_or_default();
+        if age >= max_age && fs::remove_file(&path).is_ok() {
+            debug!(path = %path.display(), "Old capture deleted");
+        }
+    }
+}
+
+/// Delete every kept capture; returns how many were deleted.
+pub fn clear_captures() -> Result<usize, String> {
+    let _ = fs::remove_file(std::env::temp_dir().join(LEGACY_CAPTURE));
+    let dir = captures_dir();
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Ok(0);
+    };
+    let mut deleted = 0;
+    for path in entries.flatten().map(|entry| entry.path()) {
+        fs::remove_file(&path).map_err(|e| format!("Could not delete {}: {e}", path.display()))?;
+        deleted += 1;
+    }
+    Ok(deleted)
+}
@@ -0,0 +1,311 @@
+//! Reading order of recognized text
+//!
+//! Engines report text as fragments (whole lines or parts of them) with their
+//! position in the image. Read in raster order, multi-column captures (papers,
+//! newspapers) interleave their columns, so the fragments are first split into
+//! bands at fragments spanning the columns (titles, captions), each band is
+//! split into columns at its vertical gutters, and only then are the fragments
+//! of each column joined into lines, top to bottom. The same geometry tells
+//! where paragraphs start, so they can be read with a pause between them.
+
+/// Position of a fragment in the image, in pixels from the top left corner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub left: f32,
+    pub top: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Bounds {
+    fn right(&self) -> f32 {
+        self.left + self.width
+    }
+
+    fn bottom(&self) -> f32 {
+        self.top + self.height
+    }
+
+    fn middle(&self) -> f32 {
+        self.top + self.height / 2.0
+    }
+
+    /// Smallest bounds containing both.
+    pub(super) fn union(&self, other: &Bounds) -> Bounds {
+        let left = self.left.min(other.left);
+        let top = self.top.min(other.top);
+        Bounds {
+            left,
+            top,
+            width: self.right().max(other.right()) - left,
+            height: self.bottom().max(other.bottom()) - top,
+        }
+    }
+}
+
+/// A recognized line of text, or a fragment of one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcrLine {
+    pub text: String,
+    /// Where the text is (None for engines that only report text)
+    pub bounds: Option<Bounds>,
+    /// Recognition confidence, 0.0 to 1.0 (1.0 when the engine doesn't report it)
+    pub confidence: f32,
+}
+
+impl OcrLine {
+    /// A line without position, from an engine that only reports text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            bounds: None,
+            confidence: 1.0,
+        }
+    }
+}
+
+/// Share of the content width above which a fragment spans the columns.
+const SPANNING_WIDTH: f32 = 0.6;
+
+/// Narrowest gutter between two columns, in line heights.
+const MIN_GUTTER: f32 = 1.0;
+
+/// Gap between two lines above which a new paragraph starts, in line heights.
+const PARAGRAPH_GAP: f32 = 0.8;
+
+/// Smallest indentation of a paragraph's first line, in line heights.
+const MIN_INDENT: f32 = 1.0;
+
+/// Share of the paragraph width a line ending a sentence must leave empty to end the paragraph.
+const SHORT_LINE: f32 = 0.15;
+
+/// Parse the output of the OCR scripts: one fragment per line, as tab-separated
+/// `left top width height confidence text`.
+///
+/// Lines in any other form (e.g. from scripts installed by older versions,
+/// which print plain text) are kept as lines without position.
+pub fn parse_fragments(output: &str) -> Vec<OcrLine> {
+    output
+        .lines()
+        .map(|line| {
+            let fields: Vec<&str> = line.splitn(6, '\t').collect();
+            let numbers: Vec<f32> = fields.iter().take(5).filter_map(|field| field.parse().ok()).collect();
+            if let ([left, top, width, height, confidence], [.., text]) = (numbers.as_slice(), fields.as_slice()) {
+                if fields.len() == 6 {
+                    let bounds = Bounds { left: *left, top: *top, width: *width, height: *height };
+                    return OcrLine {
+                        text: text.trim().to_string(),
+                        bounds: Some(bounds),
+                        confidence: confidence.clamp(0.0, 1.0),
+                    };
+                }
+            }
+            OcrLine::new(line.trim_end())
+        })
+        .filter(|line| !line.text.trim().is_empty())
+        .collect()
+}
+
+/// Put `fragments` in reading order, joining the fragments of each line.
+///
+/// Columns are read one after the other unless `detect_columns` is false, in
+/// which case fragments at the same height are joined across the whole width.
+/// Fragments without position are assumed to be in reading order already.
+pub fn reading_order(mut fragments: Vec<OcrLine>, detect_columns: bool) -> Vec<OcrLine> {
+    if fragments.iter().any(|fragment| fragment.bounds.is_none()) {
+        return fragments;
+    }
+    let Some(line_height) = median_height(&fragments) else {
+        return fragments;
+    };
+    if !detect_columns {
+        return join_rows(fragments, line_height);
+    }
+
+    let left = fragments.iter().filter_map(|f| f.bounds).map(|b| b.left).fold(f32::MAX, f32::min);
+    let right = fragments.iter().filter_map(|f| f.bounds).map(|b| b.right()).fold(f32::MIN, f32::max);
+    let spanning = SPANNING_WIDTH * (right - left);
+
+    fragments.sort_by(|a, b| top(a).total_cmp(&top(b)));
+    let mut lines = Vec::new();
+    let mut band = Vec::new();
+    for fragment in fragments {
+        if fragment.bounds.is_some_and(|b| b.width > spanning) {
+            lines.extend(read_band(std::mem::take(&mut band), line_height));
+            lines.push(fragment);
+        } else {
+            band.push(fragment);
+        }
+    }
+    lines.extend(read_band(band, line_height));
+    lines
+}
+
+fn top(fragment: &OcrLine) -> f32 {
+    fragment.bounds.map_or(0.0, |b| b.top)
+}
+
+/// Median height of the fragments with a position.
+fn median_height(fragments: &[OcrLine]) -> Option<f32> {
+    let mut heights: Vec<f32> = fragments.iter().filter_map(|f| f.bounds).map(|b| b.height).collect();
+    heights.sort_by(f32::total_cmp);
+    heights.get(heights.len() / 2).copied()
+}
+
+/// Lines of a band between spanning fragments, column by column.
+fn read_band(band: Vec<OcrLine>, line_height: f32) -> Vec<OcrLine> {
+    columns(band, line_height)
+        .into_iter()
+        .flat_map(|column| join_rows(column, line_height))
+        .collect()
+}
+
+/// Split fragments into columns at vertical gutters, left to right.
+fn columns(mut fragments: Vec<OcrLine>, line_height: f32) -> Vec<Vec<OcrLine>> {
+    fragments.sort_by(|a, b| {
+        let (a, b) = (a.bounds.map_or(0.0, |b| b.left), b.bounds.map_or(0.0, |b| b.left));
+        a.total_cmp(&b)
+    });
+    let mut columns: Vec<(f32, Vec<OcrLine>)> = Vec::new();
+    for fragment in fragments {
+        let Some(bounds) = fragment.bounds else { continue };
+        match columns.last_mut() {
+            Some((right, column)) if bounds.left < *right + MIN_GUTTER * line_height => {
+                *right = right.max(bounds.right());
+                column.push(fragment);
+            }
+            _ => columns.push((bounds.right(), vec![fragment])),
+        }
+    }
+    columns.into_iter().map(|(_, column)| column).collect()
+}
+
+/// Join fragments at the same height into lines, top to bottom.
+fn join_rows(mut fragments: Vec<OcrLine>, line_height: f32) -> Vec<OcrLine> {
+    let middle = |fragment: &OcrLine| fragment.bounds.map_or(0.0, |b| b.middle());
+    fragments.sort_by(|a, b| middle(a).total_cmp(&middle(b)));
+    let mut rows: Vec<Vec<OcrLine>> = Vec::new();
+    for fragment in fragments {
+        match rows.last_mut() {
+            Some(row) if (middle(&fragment) - middle(&row[0])).abs() < line_height / 2.0 => row.push(fragment),
+            _ => rows.push(vec![fragment]),
+        }
+    }
+    rows.into_iter()
+        .map(|mut row| {
+            row.sort_by(|a, b| {
+                let (a, b) = (a.bounds.map_or(0.0, |b| b.left), b.bounds.map_or(0.0, |b| b.left));
+                a.total_cmp(&b)
+            });
+            let confidence = row.iter().map(|f| f.confidence).sum::<f32>() / row.len() as f32;
+            let bounds = row.iter().filter_map(|f| f.bounds).reduce(|a, b| a.union(&b));
+            let text = row.iter().map(|f| f.text.trim()).collect::<Vec<_>>().join(" ");
+            OcrLine { text, bounds, confidence }
+        })
+        .collect()
+}
+
+/// Text of `lines` (in reading order), paragraph by paragraph.
+///
+/// A paragraph starts after a gap wider than between its lines, at an
+/// indented line, or after a line ending a sentence well before the right
+/// edge. Its lines are joined (rejoining words hyphenated across them) and
+/// paragraphs are separated by a blank line, read with a longer pause. Lines
+/// without position are kept one per line.
+pub fn paragraph_text(lines: &[OcrLine]) -> String {
+    let line_height = median_height(lines).filter(|_| lines.iter().all(|line| line.bounds.is_some()));
+    let Some(line_height) = line_height else {
+        return lines.iter().map(|line| line.text.as_str()).collect::<Vec<_>>().join("\n");
+    };
+
+    let mut text = String::new();
+    // Left and right edges of the current paragraph
+    let mut edges: Option<(f32, f32)> = None;
+    let mut previous: Option<Bounds> = None;
+    for line in lines {
+        let (Some(bounds), line_text) = (line.bounds, line.text.trim()) else {
+            continue;
+        };
+        if line_text.is_empty() {
+            continue;
+        }
+        if let (Some(last), Some((left, right))) = (previous, edges) {
+            let gap = bounds.top - last.bottom();
+            let next_column = bounds.top < last.top;
+            let indented = !next_column && bounds.left - last.left > MIN_INDENT * line_height;
+            let short = right - last.right() > SHORT_LINE * (right - left)
+                && text.ends_with(['.', '!', '?', ':', '؟', '۔']);
+            if gap > PARAGRAPH_GAP * line_height || indented || short {
+                text.push_str("\n\n");
+                edges = None;
+            } else if text.ends_with('-')
+                && text[..text.len() - 1].ends_with(char::is_alphabetic)
+                && line_text.starts_with(char::is_lowercase)
+            {
+                text.pop();
+            } else {
+                text.push(' ');
+            }
+        }
+        text.push_str(line_text);
+        edges = Some(edges.map_or((bounds.left, bounds.right()), |(left, right)| {
+            (left.min(bounds.left), right.max(bounds.right()))
+        }));
+        previous = Some(bounds);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fragment(text: &str, left: f32, top: f32, width: f32) -> OcrLine {
+        OcrLine {
+            text: text.to_string(),
+            bounds: Some(Bounds { left, top, width, height: 10.0 }),
+            confidence: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_reading_order() {
+        let page = vec![
+            fragment("b1", 110.0, 20.0, 90.0),
+            fragment("Title", 0.0, 0.0, 200.0),
+            fragment("a1", 0.0, 20.0, 90.0),
+            fragment("b2", 110.0, 32.0, 90.0),
+            fragment("a2", 0.0, 33.0, 40.0),
+            fragment("again", 45.0, 32.0, 45.0),
+            fragment("The end", 0.0, 50.0, 200.0),
+        ];
+        let text = |lines: Vec<OcrLine>| lines.into_iter().map(|line| line.text).collect::<Vec<_>>();
+        assert_eq!(text(reading_order(page.clone(), true)), ["Title", "a1", "a2 again", "b1", "b2", "The end"]);
+        assert_eq!(text(reading_order(page, false)), ["Title", "a1 b1", "a2 again b2", "The end"]);
+
+        let parsed = parse_fragments("0\t20\t90\t10\t0.5\ta1\tand a tab\nplain text\n\n");
+        assert_eq!(parsed[0].bounds, Some(Bounds { left: 0.0, top: 20.0, width: 90.0, height: 10.0 }));
+        assert_eq!(parsed[0].text, "a1\tand a tab");
+        assert_eq!(parsed[1], OcrLine::new("plain text"));
+        assert_eq!(parsed.len(), 2);
+    }
+
+    #[test]
+    fn test_paragraph_text() {
+        let lines = [
+            fragment("The first paragraph is long enough to fill", 0.0, 0.0, 200.0),
+            fragment("its line, and then some more informa-", 0.0, 12.0, 200.0),
+            fragment("tion. This line is almost full.", 0.0, 24.0, 195.0),
+            fragment("An indented paragraph starts here and", 20.0, 36.0, 180.0),
+            fragment("ends early.", 0.0, 48.0, 60.0),
+            fragment("Short line,", 0.0, 60.0, 60.0),
+            fragment("after a gap", 0.0, 90.0, 60.0),
+        ];
+        assert_eq!(
+            paragraph_text(&lines),
+            "The first paragraph is long enough to fill its line, and then some more information. \
+             This line is almost full.\n\nAn indented paragraph starts here and ends early.\n\nShort line,\n\nafter a gap"
+        );
+        assert_eq!(paragraph_text(&[OcrLine::new("a"), OcrLine::new("b")]), "a\nb");
+    }
+}
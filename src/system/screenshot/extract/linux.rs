@@ -7,6 +7,22 @@ use tracing::{debug, error, info, warn};
 
 use dirs;
 
+use super::layout::parse_fragments;
+use super::{OcrLine, OcrProvider};
+
+/// EasyOCR, run through the bundled Python script.
+pub struct EasyOcrProvider;
+
+impl OcrProvider for EasyOcrProvider {
+    fn name(&self) -> &'static str {
+        "EasyOCR"
+    }
+
+    fn extract_lines(&self, image_path: &str) -> Result<Vec<OcrLine>, String> {
+        Ok(parse_fragments(&extract_text_from_image_linux(image_path)?))
+    }
+}
+
 /// Find Python interpreter in the venv (same location as piper binary)
 fn find_venv_python() -> Option<PathBuf> {
     // Check project-local virtualenv first (development)
@@ -25,11 +41,12 @@ fn find_venv_python() -> Option<PathBuf> {
         }
     }
     
-    None
+    // Check a system-wide install (e.g. /usr/share/insight-reader/venv)
+    crate::storage::find_system_file(&Path::new("venv").join("bin").join("python"))
 }
 
 /// Extracts text from an image on Linux using Python script with EasyOCR.
-pub(super) fn extract_text_from_image_linux(image_path: &str) -> Result<String, String> {
+fn extract_text_from_image_linux(image_path: &str) -> Result<String, String> {
     info!(path = %image_path, "Starting text extraction from image on Linux");
     
     // Verify the image file exists
@@ -62,6 +79,10 @@ pub(super) fn extract_text_from_image_linux(image_path: &str) -> Result<String,
                 .map(|data_dir| data_dir.join("insight-reader").join("bin").join("extract_text_from_image.py"))
                 .filter(|p| p.exists())
         })
+        .or_else(|| {
+            // Check a system-wide install (e.g. /usr/share/insight-reader/bin/)
+            crate::storage::find_system_file(&Path::new("bin").join("extract_text_from_image.py"))
+        })
         .or_else(|| {
             // Check current directory (development)
             Path::new("install/extract_text_from_image.py")
@@ -117,7 +138,7 @@ pub(super) fn extract_text_from_image_linux(image_path: &str) -> Result<String,
         return Err(format!("Text extraction failed: {}", stderr.trim()));
     }
     
-    // Preserve all newlines from OCR output - only trim trailing newline from script output
+    // One fragment per line - only trim trailing newline from script output
     let extracted_text = String::from_utf8_lossy(&output.stdout).trim_end().to_string();
     
     if extracted_text.is_empty() {
@@ -5,8 +5,24 @@ use std::path::Path;
 use std::process::Command;
 use tracing::{debug, error, info, warn};
 
+use super::layout::parse_fragments;
+use super::{OcrLine, OcrProvider};
+
+/// Apple's Vision framework, run through the bundled Swift script.
+pub struct VisionOcrProvider;
+
+impl OcrProvider for VisionOcrProvider {
+    fn name(&self) -> &'static str {
+        "Vision"
+    }
+
+    fn extract_lines(&self, image_path: &str) -> Result<Vec<OcrLine>, String> {
+        Ok(parse_fragments(&extract_text_from_image_macos(image_path)?))
+    }
+}
+
 /// Extracts text from an image on macOS using Swift script with Vision framework.
-pub(super) fn extract_text_from_image_macos(image_path: &str) -> Result<String, String> {
+fn extract_text_from_image_macos(image_path: &str) -> Result<String, String> {
     info!(path = %image_path, "Starting text extraction from image");
     
     // Verify the image file exists
@@ -112,7 +128,7 @@ pub(super) fn extract_text_from_image_macos(image_path: &str) -> Result<String,
         return Err(format!("Text extraction failed: {}", stderr.trim()));
     }
     
-    // Preserve all newlines from OCR output - only trim trailing newline from script output
+    // One fragment per line - only trim trailing newline from script output
     let extracted_text = String::from_utf8_lossy(&output.stdout).trim_end().to_string();
     
     if extracted_text.is_empty() {
@@ -0,0 +1,178 @@
+//! Merging repeated captures of the same area
+//!
+//! Capturing an area again (e.g. a chat window, to catch up on its new
+//! messages) recognizes most of its lines a second time, and rarely exactly
+//! the same way: a letter the engine wasn't sure about flickers between
+//! readings. Lines of each new capture are matched against the lines already
+//! read, by text similarity and position, with more leeway for readings of
+//! low confidence, so only genuinely new lines are read.
+
+use super::OcrLine;
+
+/// Most lines remembered from earlier captures (the oldest are forgotten first).
+const MAX_SEEN_LINES: usize = 500;
+
+/// Similarity two readings in the same place need to be the same line.
+const SAME_PLACE_SIMILARITY: f32 = 0.9;
+
+/// Similarity two readings in different places (e.g. scrolled) need to be the same line.
+const MOVED_SIMILARITY: f32 = 0.95;
+
+/// How much less similarity is needed when the engine had no confidence in a reading.
+const CONFIDENCE_LEEWAY: f32 = 0.3;
+
+/// Lines read from earlier captures of the same region.
+#[derive(Debug, Clone, Default)]
+pub struct SeenLines {
+    lines: Vec<OcrLine>,
+    /// Size in pixels of the captures the lines were read from, if known
+    region: Option<(u32, u32)>,
+}
+
+impl SeenLines {
+    /// The lines of `frame`, a capture of `region` (its size in pixels), not read
+    /// from earlier captures, remembering them all.
+    ///
+    /// Line positions are relative to each capture, so a capture of another size
+    /// is another area: the lines read so far are forgotten and it's read in full.
+    /// Capture tools don't report where on screen the area was, so one of the same
+    /// size is taken for the same area. A line matching an earlier reading keeps
+    /// the more confident text of the two, so a later, clearer reading of a
+    /// flickering line isn't read again.
+    pub fn new_lines(&mut self, region: Option<(u32, u32)>, frame: Vec<OcrLine>) -> Vec<OcrLine> {
+        if region != self.region {
+            self.clear();
+            self.region = region;
+        }
+        let known = self.lines.len();
+        // Each earlier line matches one line of the frame, so repeated lines still count
+        let mut matched = vec![false; known];
+        let mut new = Vec::new();
+        for line in frame {
+            let best = (0..known)
+                .filter(|&index| !matched[index])
+                .filter_map(|index| match_score(&self.lines[index], &line).map(|score| (index, score)))
+                .max_by(|(_, a), (_, b)| a.total_cmp(b));
+            match best {
+                Some((index, _)) => {
+                    matched[index] = true;
+                    let seen = &mut self.lines[index];
+                    if line.confidence > seen.confidence {
+                        seen.text = line.text;
+                        seen.confidence = line.confidence;
+                    }
+                    seen.bounds = line.bounds.or(seen.bounds);
+                }
+                None => {
+                    new.push(line.clone());
+                    self.lines.push(line);
+                }
+            }
+        }
+        let forgotten = self.lines.len().saturating_sub(MAX_SEEN_LINES);
+        self.lines.drain(..forgotten);
+        new
+    }
+
+    /// Forget the lines read so far: the next capture is read in full.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+}
+
+/// How similar `line` is to the earlier reading `seen`, if close enough to be the same line.
+fn match_score(seen: &OcrLine, line: &OcrLine) -> Option<f32> {
+    let similarity = similarity(&normalized(&seen.text), &normalized(&line.text));
+    let same_place = match (seen.bounds, line.bounds) {
+        (Some(a), Some(b)) => {
+            let middle = |top: f32, height: f32| top + height / 2.0;
+            (middle(a.top, a.height) - middle(b.top, b.height)).abs() < a.height.max(b.height)
+        }
+        // Engines without positions: any earlier line may be the same
+        _ => true,
+    };
+    let needed = if same_place { SAME_PLACE_SIMILARITY } else { MOVED_SIMILARITY };
+    let confidence = seen.confidence.min(line.confidence).clamp(0.0, 1.0);
+    let needed = needed - (1.0 - confidence) * CONFIDENCE_LEEWAY;
+    (similarity >= needed).then_some(similarity + if same_place { 1.0 } else { 0.0 })
+}
+
+/// Lowercase letters and digits, words separated by single spaces.
+fn normalized(text: &str) -> Vec<char> {
+    let text: String = text
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .flat_map(char::to_lowercase)
+        .collect();
+    text.split_whitespace().collect::<Vec<_>>().join(" ").chars().collect()
+}
+
+/// Share of characters two strings have in common, from their edit distance (1.0 = identical).
+fn similarity(a: &[char], b: &[char]) -> f32 {
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    1.0 - previous[b.len()] as f32 / longest as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::layout::Bounds;
+    use super::*;
+
+    fn line(text: &str, top: f32, confidence: f32) -> OcrLine {
+        OcrLine {
+            text: text.to_string(),
+            bounds: Some(Bounds { left: 0.0, top, width: 200.0, height: 10.0 }),
+            confidence,
+        }
+    }
+
+    #[test]
+    fn test_new_lines() {
+        let mut seen = SeenLines::default();
+        let region = Some((400, 300));
+        let first = vec![line("Alice: see you at 5", 0.0, 0.9), line("Bob: ok", 12.0, 1.0)];
+        assert_eq!(seen.new_lines(region, first.clone()), first);
+
+        // Flicker in a low-confidence reading, one scrolled line, one new line, a repeated line
+        let second = vec![
+            line("A1ice: see you at S", 0.0, 0.5),
+            line("Bob: ok", 40.0, 1.0),
+            line("Alice: great, bring the map", 52.0, 0.9),
+            line("Bob: ok", 64.0, 1.0),
+        ];
+        let texts = |lines: Vec<OcrLine>| lines.into_iter().map(|line| line.text).collect::<Vec<_>>();
+        assert_eq!(texts(seen.new_lines(region, second)), ["Alice: great, bring the map", "Bob: ok"]);
+        // The more confident reading is kept
+        assert_eq!(seen.lines[0].text, "Alice: see you at 5");
+
+        // A confident, different line in the same place is new
+        assert_eq!(seen.new_lines(region, vec![line("Alice: see you at 6pm", 0.0, 1.0)]).len(), 1);
+
+        seen.clear();
+        assert_eq!(seen.new_lines(region, vec![line("Bob: ok", 0.0, 1.0)]).len(), 1);
+    }
+
+    #[test]
+    fn test_new_lines_of_another_region() {
+        let mut seen = SeenLines::default();
+        let lines = vec![line("Alice: see you at 5", 0.0, 0.9), line("Bob: ok", 12.0, 1.0)];
+        assert_eq!(seen.new_lines(Some((400, 300)), lines.clone()).len(), 2);
+        assert!(seen.new_lines(Some((400, 300)), lines.clone()).is_empty());
+
+        // A capture of another area is read in full, and so is the first area again afterwards
+        assert_eq!(seen.new_lines(Some((640, 120)), lines.clone()).len(), 2);
+        assert_eq!(seen.new_lines(Some((400, 300)), lines).len(), 2);
+    }
+}
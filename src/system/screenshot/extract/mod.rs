@@ -1,34 +1,93 @@
 //! Text extraction from images functionality
+//!
+//! This module defines the [`OcrProvider`] trait and its implementations for
+//! the OCR engines the app can use: the platform's own (Vision on macOS,
+//! Windows.Media.Ocr on Windows, EasyOCR on Linux), Tesseract, and a remote
+//! OCR endpoint. Engines report lines with their position where they can, so
+//! [`recognize_lines`] can put multi-column captures in reading order, and
+//! [`SeenLines`] can tell the new lines of a repeated capture from those read
+//! already.
 
+mod layout;
 #[cfg(target_os = "linux")]
 mod linux;
 #[cfg(target_os = "macos")]
 mod macos;
+mod merge;
+mod remote;
+mod tesseract;
 #[cfg(target_os = "windows")]
 mod windows;
 
-/// Extracts text from an image using platform-native OCR APIs.
-/// 
-/// On macOS, uses Swift script with Vision framework for OCR.
-/// On Linux, uses EasyOCR via Python script.
-/// On Windows, uses built-in Windows.Media.Ocr API (no external dependencies required).
-/// Returns the extracted text, or an error message.
-pub fn extract_text_from_image(image_path: &str) -> Result<String, String> {
+pub use layout::OcrLine;
+#[cfg(target_os = "linux")]
+pub use linux::EasyOcrProvider;
+#[cfg(target_os = "macos")]
+pub use macos::VisionOcrProvider;
+pub use merge::SeenLines;
+pub use remote::RemoteOcrProvider;
+pub use tesseract::TesseractOcrProvider;
+#[cfg(target_os = "windows")]
+pub use windows::WindowsOcrProvider;
+
+/// Abstract interface for OCR engines.
+///
+/// Allows plugging in different OCR engines, like [`TTSProvider`](crate::providers::TTSProvider)
+/// does for speech. [`extract_text`](OcrProvider::extract_text) blocks
+/// (subprocesses, model loading), so the app only calls it from a blocking task.
+pub trait OcrProvider: Send {
+    /// Short engine name, for logs and error messages.
+    fn name(&self) -> &'static str;
+
+    /// Recognize the lines of text (or fragments of lines) in the image at `image_path`.
+    ///
+    /// Lines may come in any order when they have a position. Returns an error
+    /// (e.g. "No text found in image") when nothing was recognized.
+    fn extract_lines(&self, image_path: &str) -> Result<Vec<OcrLine>, String>;
+}
+
+/// Recognize the lines of the image at `image_path` with `provider`, in reading order.
+///
+/// Columns are read one after the other unless `detect_columns` is false.
+pub fn recognize_lines(
+    provider: &dyn OcrProvider,
+    image_path: &str,
+    detect_columns: bool,
+) -> Result<Vec<OcrLine>, String> {
+    let lines = layout::reading_order(provider.extract_lines(image_path)?, detect_columns);
+    if lines.iter().all(|line| line.text.trim().is_empty()) {
+        return Err("No text found in image".to_string());
+    }
+    Ok(lines)
+}
+
+/// Text of recognized `lines`, paragraph by paragraph, or one line per line
+/// when `paragraphs` is false (e.g. for tables).
+pub fn lines_text(lines: &[OcrLine], paragraphs: bool) -> String {
+    if paragraphs {
+        layout::paragraph_text(lines)
+    } else {
+        lines.iter().map(|line| line.text.as_str()).collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// The platform's own OCR engine, or an error on platforms without one.
+pub fn native_ocr_provider() -> Result<Box<dyn OcrProvider>, String> {
     #[cfg(target_os = "macos")]
     {
-        macos::extract_text_from_image_macos(image_path)
+        Ok(Box::new(VisionOcrProvider))
     }
-    
+
     #[cfg(target_os = "linux")]
     {
-        linux::extract_text_from_image_linux(image_path)
+        Ok(Box::new(EasyOcrProvider))
     }
-    
+
     #[cfg(target_os = "windows")]
     {
-        windows::extract_text_from_image_windows(image_path)
+        Ok(Box::new(WindowsOcrProvider))
     }
-    
+
     #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
     {
         tracing::warn!("Text extraction from images not supported on this platform");
@@ -0,0 +1,147 @@
+//! Remote OCR endpoint implementation
+//!
+//! Posts the captured image to a user-configured HTTP endpoint, e.g. a
+//! self-hosted PaddleOCR or docTR server behind a small adapter. That helps
+//! on Linux distributions without a good local engine and for scripts (like
+//! CJK) that local engines recognize poorly.
+//!
+//! The image is sent as the request body (`Content-Type: image/png`). The
+//! response is JSON: either `{"text": "..."}` or a list of lines, as
+//! `{"lines": [...]}` or a bare array, where each line is a string or an
+//! object with a `text` field. Lines are read in the order they are listed.
+
+use std::time::Duration;
+use tracing::{debug, error, info, warn};
+
+use super::{OcrLine, OcrProvider};
+
+/// Timeout for one recognition request (large captures on a CPU-only server are slow).
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// OCR server reached over HTTP.
+pub struct RemoteOcrProvider {
+    /// Endpoint the image is posted to
+    url: String,
+}
+
+impl RemoteOcrProvider {
+    /// Create a provider posting to `url`.
+    pub fn new(url: String) -> Result<Self, String> {
+        let url = url.trim().to_string();
+        if url.is_empty() {
+            return Err("Remote OCR endpoint is not configured".to_string());
+        }
+        Ok(Self { url })
+    }
+}
+
+/// Text of an OCR response, lines joined with newlines (None if it has no text).
+fn text_from_json(value: &serde_json::Value) -> Option<String> {
+    use serde_json::Value;
+
+    let line_text = |line: &Value| match line {
+        Value::String(text) => Some(text.clone()),
+        Value::Object(fields) => fields.get("text").and_then(Value::as_str).map(str::to_string),
+        _ => None,
+    };
+    let lines = match value {
+        Value::Object(fields) => {
+            if let Some(text) = fields.get("text").and_then(Value::as_str) {
+                return Some(text.to_string());
+            }
+            fields.get("lines")?.as_array()?
+        }
+        Value::Array(lines) => lines,
+        _ => return None,
+    };
+    Some(
+        lines
+            .iter()
+            .filter_map(line_text)
+            .filter(|line| !line.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+impl OcrProvider for RemoteOcrProvider {
+    fn name(&self) -> &'static str {
+        "Remote OCR"
+    }
+
+    fn extract_lines(&self, image_path: &str) -> Result<Vec<OcrLine>, String> {
+        info!(path = %image_path, url = %self.url, "Starting text extraction with remote OCR endpoint");
+
+        let image = std::fs::read(image_path).map_err(|e| {
+            error!(path = %image_path, error = %e, "Failed to read image file");
+            format!("Failed to read image file: {}", e)
+        })?;
+
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(concat!("insight-reader/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| format!("Failed to create tokio runtime: {e}"))?;
+
+        let response: serde_json::Value = runtime.block_on(async {
+            let response = client
+                .post(&self.url)
+                .header(reqwest::header::CONTENT_TYPE, "image/png")
+                .body(image)
+                .send()
+                .await
+                .map_err(|e| {
+                    error!(url = %self.url, error = %e, "Remote OCR request failed");
+                    format!("Could not reach the OCR endpoint at {}: {e}", self.url)
+                })?;
+
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                error!(%status, body = %body, "Remote OCR endpoint returned an error");
+                return Err(format!("OCR endpoint error ({status}): {}", body.trim()));
+            }
+            response.json().await.map_err(|e| {
+                error!(error = %e, "Remote OCR endpoint returned invalid JSON");
+                format!("OCR endpoint returned invalid JSON: {e}")
+            })
+        })?;
+
+        let extracted_text = text_from_json(&response).ok_or_else(|| {
+            error!("Remote OCR response has no text or lines field");
+            "OCR endpoint response has no \"text\" or \"lines\" field".to_string()
+        })?;
+
+        if extracted_text.trim().is_empty() {
+            warn!("No text found in image");
+            return Err("No text found in image".to_string());
+        }
+
+        info!(bytes = extracted_text.len(), "Text extracted successfully from image using remote OCR");
+        debug!(text = %extracted_text.chars().take(100).collect::<String>(), "Extracted text preview");
+
+        // Servers send no positions: their lines are taken in the order they came
+        Ok(extracted_text.lines().map(OcrLine::new).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_from_json() {
+        let text = |json: &str| text_from_json(&serde_json::from_str(json).unwrap());
+        assert_eq!(text(r#"{"text": "Hello\nworld"}"#).as_deref(), Some("Hello\nworld"));
+        assert_eq!(
+            text(r#"{"lines": [{"text": "你好", "confidence": 0.98}, {"text": " "}, "世界"]}"#).as_deref(),
+            Some("你好\n世界")
+        );
+        assert_eq!(text(r#"["one", "two"]"#).as_deref(), Some("one\ntwo"));
+        assert_eq!(text(r#"{"result": "?"}"#), None);
+    }
+}
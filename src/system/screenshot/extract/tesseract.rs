@@ -0,0 +1,143 @@
+//! Tesseract text extraction implementation
+//!
+//! Runs the `tesseract` command line program, which is packaged for every
+//! platform and recognizes many languages once their traineddata is installed.
+//! Its TSV output gives every word with its position and confidence.
+
+use std::path::Path;
+use std::process::Command;
+use tracing::{debug, error, info, warn};
+
+use super::layout::Bounds;
+use super::{OcrLine, OcrProvider};
+
+/// Tesseract, run as a command line program found on the PATH.
+pub struct TesseractOcrProvider;
+
+impl OcrProvider for TesseractOcrProvider {
+    fn name(&self) -> &'static str {
+        "Tesseract"
+    }
+
+    fn extract_lines(&self, image_path: &str) -> Result<Vec<OcrLine>, String> {
+        info!(path = %image_path, "Starting text extraction from image with Tesseract");
+
+        if !Path::new(image_path).exists() {
+            error!(path = %image_path, "Image file does not exist");
+            return Err(format!("Image file does not exist: {}", image_path));
+        }
+
+        // "stdout" as the output base makes tesseract print the words instead of writing a file
+        let mut command = Command::new("tesseract");
+        command.arg(image_path).arg("stdout").arg("tsv");
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            const CREATE_NO_WINDOW: u32 = 0x08000000;
+            command.creation_flags(CREATE_NO_WINDOW);
+        }
+        let output = command.output().map_err(|e| {
+            error!(error = %e, "Failed to execute tesseract command");
+            if e.kind() == std::io::ErrorKind::NotFound {
+                "Tesseract is not installed (the tesseract command was not found)".to_string()
+            } else {
+                format!("Failed to execute text extraction: {}", e)
+            }
+        })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            error!(code = output.status.code().unwrap_or(-1), stderr = %stderr.trim(), "Text extraction failed");
+            return Err(format!("Text extraction failed: {}", stderr.trim()));
+        }
+
+        let lines = parse_tsv(&String::from_utf8_lossy(&output.stdout));
+        if lines.is_empty() {
+            warn!("No text found in image");
+            return Err("No text found in image".to_string());
+        }
+
+        info!(lines = lines.len(), "Text extracted successfully from image using Tesseract");
+        debug!(text = %lines[0].text.chars().take(100).collect::<String>(), "Extracted text preview");
+
+        Ok(lines)
+    }
+}
+
+/// Lines of tesseract's TSV output, each made of its words.
+///
+/// Columns: level, page, block, paragraph, line, word, left, top, width,
+/// height, confidence (0-100, -1 for non-word rows), text.
+fn parse_tsv(output: &str) -> Vec<OcrLine> {
+    // Each line with its (page, block, paragraph, line) numbers and word count
+    type LineKey = (u32, u32, u32, u32);
+    let mut lines: Vec<(LineKey, OcrLine, usize)> = Vec::new();
+    for row in output.lines().skip(1) {
+        let fields: Vec<&str> = row.splitn(12, '\t').collect();
+        let [level, page, block, paragraph, line, _, left, top, width, height, confidence, text] = fields[..] else {
+            continue;
+        };
+        let number = |field: &str| field.trim().parse::<f32>().ok();
+        let (Some(confidence), Some(left), Some(top), Some(width), Some(height)) =
+            (number(confidence), number(left), number(top), number(width), number(height))
+        else {
+            continue;
+        };
+        // Level 5 rows are words; the others outline pages, blocks, paragraphs and lines
+        if level != "5" || confidence < 0.0 || text.trim().is_empty() {
+            continue;
+        }
+        let key = (
+            page.parse().unwrap_or(0),
+            block.parse().unwrap_or(0),
+            paragraph.parse().unwrap_or(0),
+            line.parse().unwrap_or(0),
+        );
+        let bounds = Bounds { left, top, width, height };
+        match lines.last_mut() {
+            Some((last_key, ocr_line, words)) if *last_key == key => {
+                ocr_line.text.push(' ');
+                ocr_line.text.push_str(text.trim());
+                ocr_line.bounds = ocr_line.bounds.map(|b| b.union(&bounds));
+                ocr_line.confidence += confidence / 100.0;
+                *words += 1;
+            }
+            _ => lines.push((
+                key,
+                OcrLine {
+                    text: text.trim().to_string(),
+                    bounds: Some(bounds),
+                    confidence: confidence / 100.0,
+                },
+                1,
+            )),
+        }
+    }
+    lines
+        .into_iter()
+        .map(|(_, mut line, words)| {
+            line.confidence /= words as f32;
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tsv() {
+        let tsv = "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\tleft\ttop\twidth\theight\tconf\ttext\n\
+            4\t1\t1\t1\t1\t0\t10\t20\t100\t12\t-1\t\n\
+            5\t1\t1\t1\t1\t1\t10\t20\t40\t12\t90\tHello\n\
+            5\t1\t1\t1\t1\t2\t60\t22\t50\t10\t70\tworld\n\
+            5\t1\t1\t1\t2\t1\t10\t40\t30\t12\t95.5\tAgain\n";
+        let lines = parse_tsv(tsv);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].text, "Hello world");
+        assert_eq!(lines[0].bounds, Some(Bounds { left: 10.0, top: 20.0, width: 100.0, height: 12.0 }));
+        assert!((lines[0].confidence - 0.8).abs() < 1e-6);
+        assert_eq!(lines[1].text, "Again");
+    }
+}
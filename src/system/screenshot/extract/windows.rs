@@ -3,9 +3,25 @@
 use std::path::Path;
 use tracing::{debug, error, info, warn};
 
+use super::layout::Bounds;
+use super::{OcrLine, OcrProvider};
+
+/// The built-in Windows.Media.Ocr API.
+pub struct WindowsOcrProvider;
+
+impl OcrProvider for WindowsOcrProvider {
+    fn name(&self) -> &'static str {
+        "Windows OCR"
+    }
+
+    fn extract_lines(&self, image_path: &str) -> Result<Vec<OcrLine>, String> {
+        extract_text_from_image_windows(image_path)
+    }
+}
+
 /// Extracts text from an image on Windows using the built-in Windows.Media.Ocr API.
 /// This is similar to macOS Vision framework - no external dependencies required.
-pub(super) fn extract_text_from_image_windows(image_path: &str) -> Result<String, String> {
+fn extract_text_from_image_windows(image_path: &str) -> Result<Vec<OcrLine>, String> {
     info!(path = %image_path, "Starting text extraction from image on Windows using native OCR");
     
     // Verify the image file exists
@@ -39,7 +55,7 @@ pub(super) fn extract_text_from_image_windows(image_path: &str) -> Result<String
     result
 }
 
-fn extract_text_with_windows_ocr(image_path: &str) -> Result<String, String> {
+fn extract_text_with_windows_ocr(image_path: &str) -> Result<Vec<OcrLine>, String> {
     use std::fs;
     use windows::{
         core::*,
@@ -152,7 +168,7 @@ fn extract_text_with_windows_ocr(image_path: &str) -> Result<String, String> {
         format!("Failed to extract text: {}", e)
     })?;
     
-    let mut extracted_text_parts = Vec::new();
+    let mut extracted_lines = Vec::new();
     let line_count = lines.Size().map_err(|e| {
         error!(error = %e, "Failed to get lines count");
         format!("Failed to extract text: {}", e)
@@ -171,27 +187,31 @@ fn extract_text_with_windows_ocr(image_path: &str) -> Result<String, String> {
         
         let text_str = text.to_string();
         if !text_str.trim().is_empty() {
-            extracted_text_parts.push(text_str);
+            // The line's position is the union of its words' (the API has no confidence)
+            let bounds = line
+                .Words()
+                .into_iter()
+                .flatten()
+                .filter_map(|word| word.BoundingRect().ok())
+                .map(|rect| Bounds { left: rect.X, top: rect.Y, width: rect.Width, height: rect.Height })
+                .reduce(|a, b| a.union(&b));
+            extracted_lines.push(OcrLine { text: text_str, bounds, confidence: 1.0 });
         }
     }
     
-    // Join all text parts with newlines to preserve line breaks
-    let extracted_text = extracted_text_parts.join("\n");
-    
-    if extracted_text.trim().is_empty() {
+    if extracted_lines.is_empty() {
         warn!("No text found in image");
         return Err("No text found in image".to_string());
     }
     
     info!(
-        bytes = extracted_text.len(),
         lines = line_count,
         "Text extracted successfully from image using Windows OCR"
     );
     debug!(
-        text = %extracted_text.chars().take(100).collect::<String>(),
+        text = %extracted_lines[0].text.chars().take(100).collect::<String>(),
         "Extracted text preview"
     );
     
-    Ok(extracted_text)
+    Ok(extracted_lines)
 }
@@ -1,7 +1,12 @@
 //! Screenshot and region capture utilities
 
 mod capture;
+mod captures;
 mod extract;
 
 pub use capture::capture_region;
-pub use extract::extract_text_from_image;
+pub use captures::{captures_dir, clear_captures, discard_capture, prune_captures, CAPTURE_RETENTION_PRESETS};
+pub use extract::{
+    lines_text, native_ocr_provider, recognize_lines, OcrLine, OcrProvider, RemoteOcrProvider, SeenLines,
+    TesseractOcrProvider,
+};
@@ -0,0 +1,79 @@
+//! Sentence segmentation
+//!
+//! Splits text into sentences for streaming synthesis (see
+//! `providers::chunking`), so long texts start playing after their first
+//! sentences instead of after the whole text. Sentences end at line breaks
+//! (so paragraphs never share one) and at sentence-ending punctuation
+//! followed by a space, except for periods that don't end a sentence:
+//! decimal points ("3.14"), common abbreviations ("Dr.", "e.g."), initials
+//! ("J. R. R. Tolkien") and any period followed by a lowercase word.
+
+/// Abbreviations usually followed by more of the same sentence (lowercase, without their period).
+const ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "mt", "vs", "fig", "approx", "cf", "al", "ca", "resp",
+];
+
+/// Split `text` into sentences, trimmed and without empty ones.
+pub fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        let next_is_space = chars.peek().is_none_or(|(_, next)| next.is_whitespace());
+        let end = match c {
+            '\n' => Some(i),
+            '.' if next_is_space && continues_after_period(&text[start..i], &text[i + 1..]) => None,
+            // Arabic question mark and Urdu full stop end RTL sentences
+            '.' | '!' | '?' | '؟' | '۔' if next_is_space => Some(i + c.len_utf8()),
+            _ => None,
+        };
+        if let Some(end) = end {
+            let sentence = text[start..end].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+            }
+            start = end;
+        }
+    }
+
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+    sentences
+}
+
+/// Whether the sentence goes on after a period ending `before`, with `after` following it.
+fn continues_after_period(before: &str, after: &str) -> bool {
+    let word = before
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches(|c: char| !c.is_alphanumeric());
+    let initial = word.chars().count() == 1 && word.chars().all(char::is_uppercase);
+    // "e.g", "i.e", "U.S": only the last period of these is in the way
+    let dotted = word.contains('.');
+    let abbreviation = ABBREVIATIONS.contains(&word.to_lowercase().as_str());
+    let lowercase_next = after
+        .trim_start()
+        .chars()
+        .find(|c| c.is_alphanumeric())
+        .is_some_and(char::is_lowercase);
+    !word.is_empty() && (initial || dotted || abbreviation || lowercase_next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sentences() {
+        assert_eq!(
+            sentences("Dr. Smith met J. R. R. Tolkien, e.g. at 3.5 p.m. on Friday. He left.\nBye"),
+            ["Dr. Smith met J. R. R. Tolkien, e.g. at 3.5 p.m. on Friday.", "He left.", "Bye"]
+        );
+        assert_eq!(sentences("It costs approx. ten euros. Fine."), ["It costs approx. ten euros.", "Fine."]);
+        assert!(sentences(" \n ").is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+//! Spreadsheet selections
+//!
+//! Cells copied from a spreadsheet arrive as tab-separated rows, which voices
+//! read as a stream of disconnected values. When the selection comes from a
+//! spreadsheet app (told by the name of the frontmost app), it is read row by
+//! row instead: "Row 1: Name is Alice, Age is 30." when the first row looks
+//! like column headers, "Row 1: Alice, 30." otherwise.
+
+use std::process::Command;
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+use tracing::debug;
+
+/// Spreadsheet apps, as the frontmost app's name, window class or window title ends with.
+const SPREADSHEET_APPS: &[&str] = &[
+    "Microsoft Excel",
+    "Excel",
+    "Numbers",
+    "LibreOffice Calc",
+    "libreoffice-calc",
+    "Gnumeric",
+    "calligrasheets",
+];
+
+/// Whether `app` (from `frontmost_app`) is a spreadsheet.
+pub fn is_spreadsheet_app(app: &str) -> bool {
+    let app = app.trim().to_lowercase();
+    SPREADSHEET_APPS.iter().any(|known| {
+        let known = known.to_lowercase();
+        app == known || app.ends_with(&format!(" - {known}"))
+    })
+}
+
+/// Name of the app with the focus (blocking: may run system commands).
+///
+/// The window class on Linux (Hyprland or X11, other Wayland compositors
+/// don't tell), the process name on macOS, the window title on Windows.
+pub fn frontmost_app() -> Option<String> {
+    let app = detect_frontmost_app();
+    debug!(?app, "Frontmost app detected");
+    app
+}
+
+/// Run a command and return its trimmed stdout if it succeeded and printed anything.
+#[cfg_attr(target_os = "windows", allow(dead_code))]
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let mut command = Command::new(program);
+    command.args(args);
+    #[cfg(target_os = "windows")]
+    {
+        // Prevent a console window from flashing up
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    let output = command.output().ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !stdout.is_empty()).then_some(stdout)
+}
+
+#[cfg(target_os = "linux")]
+fn detect_frontmost_app() -> Option<String> {
+    if super::is_wayland_hyprland() {
+        let window: serde_json::Value = serde_json::from_str(&command_output("hyprctl", &["activewindow", "-j"])?).ok()?;
+        return window["class"].as_str().map(str::to_string);
+    }
+    command_output("xdotool", &["getactivewindow", "getwindowclassname"])
+}
+
+#[cfg(target_os = "macos")]
+fn detect_frontmost_app() -> Option<String> {
+    command_output(
+        "osascript",
+        &["-e", "tell application \"System Events\" to get name of first process whose frontmost is true"],
+    )
+}
+
+#[cfg(target_os = "windows")]
+fn detect_frontmost_app() -> Option<String> {
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowTextW};
+
+    let mut title = [0u16; 512];
+    // SAFETY: the foreground window handle is only passed back to the system, which checks it
+    let length = unsafe { GetWindowTextW(GetForegroundWindow(), &mut title) };
+    let title = String::from_utf16_lossy(&title[..usize::try_from(length).ok()?]);
+    (!title.is_empty()).then_some(title)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn detect_frontmost_app() -> Option<String> {
+    None
+}
+
+/// `text`, read row by row if it was selected in the spreadsheet app `source_app`.
+pub fn spreadsheet_selection(text: String, source_app: Option<&str>) -> String {
+    if !source_app.is_some_and(is_spreadsheet_app) {
+        return text;
+    }
+    match read_cells(&text) {
+        Some(rows) => {
+            debug!(app = source_app, "Reading the selected spreadsheet cells row by row");
+            rows
+        }
+        None => text,
+    }
+}
+
+/// Tab-separated `cells`, one sentence per row; None for a single cell.
+fn read_cells(cells: &str) -> Option<String> {
+    let rows: Vec<Vec<&str>> = cells
+        .trim_end_matches(['\r', '\n'])
+        .lines()
+        .map(|line| line.split('\t').map(str::trim).collect())
+        .collect();
+    if rows.len() < 2 && rows.first().is_none_or(|row| row.len() < 2) {
+        return None;
+    }
+
+    // Column headers: a first row of distinct labels, above at least one row of values
+    let first = &rows[0];
+    let headers = (rows.len() > 1
+        && first.iter().all(|cell| cell.chars().any(char::is_alphabetic))
+        && first.iter().enumerate().all(|(i, cell)| !first[..i].contains(cell)))
+    .then_some(first);
+    let values = if headers.is_some() { &rows[1..] } else { &rows[..] };
+
+    let sentences: Vec<String> = values
+        .iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let cells: Vec<String> = match headers {
+                Some(headers) => row
+                    .iter()
+                    .zip(headers.iter())
+                    .filter(|(value, _)| !value.is_empty())
+                    .map(|(value, header)| format!("{header} is {value}"))
+                    .collect(),
+                None => row
+                    .iter()
+                    .map(|value| if value.is_empty() { "blank" } else { value }.to_string())
+                    .collect(),
+            };
+            let cells = if cells.is_empty() { "blank".to_string() } else { cells.join(", ") };
+            format!("Row {}: {cells}.", index + 1)
+        })
+        .collect();
+    Some(sentences.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spreadsheet_selection() {
+        let cells = "Name\tAge\tCity\r\nAlice\t30\tParis\r\nBob\t\tRome\r\n";
+        assert_eq!(
+            spreadsheet_selection(cells.to_string(), Some("Book1.xlsx - Excel")),
+            "Row 1: Name is Alice, Age is 30, City is Paris.\nRow 2: Name is Bob, City is Rome."
+        );
+        assert_eq!(read_cells("12\t\t7\n3\t4\t5").as_deref(), Some("Row 1: 12, blank, 7.\nRow 2: 3, 4, 5."));
+        assert_eq!(read_cells("Total\n"), None);
+        assert_eq!(spreadsheet_selection(cells.to_string(), Some("firefox")), cells);
+        assert!(is_spreadsheet_app("libreoffice-calc"));
+        assert!(!is_spreadsheet_app("Phone numbers.txt - Notepad"));
+    }
+}
@@ -6,19 +6,61 @@
 use pulldown_cmark::{Event, Parser, Tag};
 use tracing::{debug, info, warn};
 
+use super::text_processing::{spoken_image_alt, spoken_link_suffix, TextProcessingOptions};
+use crate::providers::markup;
+
 const CLEANUP_API_URL: &str = "http://insight-reader-backend.i.psilva.org/api/content-cleanup";
 
+/// Pause after a heading, in milliseconds.
+const HEADING_PAUSE_MS: u32 = 600;
+
 /// Convert markdown to plain text by extracting only text content.
 ///
 /// Strips all markdown formatting (bold, italic, headers, links, etc.)
 /// and returns only the readable text content suitable for TTS.
 /// Preserves line breaks to maintain natural pauses in speech.
-fn markdown_to_plain_text(markdown: &str) -> String {
+/// Bold and italic text become emphasis and headings are followed by a
+/// pause, using speech markup that each provider lowers for its engine.
+/// Links and images are read according to `options`.
+fn markdown_to_plain_text(markdown: &str, options: &TextProcessingOptions) -> String {
     let parser = Parser::new(markdown);
     let mut text_parts = Vec::new();
+    // Alt text of the image currently being parsed
+    let mut image_alt: Option<String> = None;
+    // Text of the bold/italic span currently being parsed
+    let mut emphasized: Option<String> = None;
 
     for event in parser {
         match event {
+            Event::Start(Tag::Image(..)) => {
+                image_alt = Some(String::new());
+            }
+            Event::Text(text) | Event::Code(text) if image_alt.is_some() => {
+                if let Some(alt) = image_alt.as_mut() {
+                    alt.push_str(&text);
+                }
+            }
+            Event::End(Tag::Image(..)) => {
+                // Inline element - announce the figure instead of reading alt text as prose
+                let alt = image_alt.take().unwrap_or_default();
+                if let Some(spoken) = spoken_image_alt(&alt, options.read_image_alt_text) {
+                    text_parts.push(format!(" {spoken} "));
+                }
+            }
+            Event::Start(Tag::Emphasis | Tag::Strong) => {
+                emphasized.get_or_insert_with(String::new);
+            }
+            Event::Text(text) | Event::Code(text) if emphasized.is_some() => {
+                if let Some(span) = emphasized.as_mut() {
+                    span.push_str(&text);
+                }
+            }
+            Event::End(Tag::Emphasis | Tag::Strong) => {
+                // A nested span ends the whole emphasized run
+                if let Some(span) = emphasized.take().filter(|span| !span.trim().is_empty()) {
+                    text_parts.push(markup::emphasis(&span));
+                }
+            }
             Event::Text(text) | Event::Code(text) => {
                 // Preserve newlines within text (important for plain text like OCR output)
                 // The text may contain newlines that need to be preserved
@@ -28,10 +70,20 @@ fn markdown_to_plain_text(markdown: &str) -> String {
                 // Line break - preserve as newline for a natural pause
                 text_parts.push("\n".to_string());
             }
+            Event::End(Tag::Link(_, destination, _)) => {
+                // Inline element - announce the link target per the user's policy
+                if let Some(suffix) = spoken_link_suffix(options.link_reading, &destination) {
+                    text_parts.push(suffix);
+                }
+            }
             Event::End(tag) => {
                 // Block element end (paragraphs, headers, etc.) - add double newline for longer pause
                 match tag {
-                    Tag::Paragraph | Tag::Heading(..) | Tag::Item => {
+                    Tag::Heading(..) => {
+                        text_parts.push(markup::pause(HEADING_PAUSE_MS));
+                        text_parts.push("\n\n".to_string());
+                    }
+                    Tag::Paragraph | Tag::Item => {
                         text_parts.push("\n\n".to_string());
                     }
                     _ => {
@@ -77,7 +129,8 @@ struct CleanupResponse {
 ///
 /// Makes a POST request to the cloud service with format: `{"content": text}`.
 /// Returns the `cleaned_content` field from the JSON response, which contains
-/// intelligently processed and refined text optimized for text-to-speech synthesis.
+/// intelligently processed and refined text optimized for text-to-speech synthesis,
+/// possibly as markdown (see [`cleaned_to_plain_text`]).
 pub async fn cleanup_text(text: &str) -> Result<String, String> {
     info!(bytes = text.len(), "Sending text to Natural Reading service");
     debug!(text = %text, "Text being sent to Natural Reading service");
@@ -107,23 +160,32 @@ pub async fn cleanup_text(text: &str) -> Result<String, String> {
         format!("Failed to parse Natural Reading service response: {e}")
     })?;
 
+    info!(bytes = cleanup_response.cleaned_content.len(), "Natural Reading completed");
+    Ok(cleanup_response.cleaned_content)
+}
+
+/// Text cleaned by Natural Reading or a language model, made ready to read.
+///
+/// Cleaned text often comes back as markdown: it is stripped to plain text,
+/// links and images read according to `options`.
+pub fn cleaned_to_plain_text(cleaned: &str, options: &TextProcessingOptions) -> String {
     // Log the text before markdown cleanup
-    debug!(text = %cleanup_response.cleaned_content, "Text before markdown cleanup");
+    debug!(text = %cleaned, "Text before markdown cleanup");
 
     // Check if the response looks like plain text (no markdown syntax)
     // If it's plain text, preserve newlines directly without markdown parsing
-    let has_markdown_syntax = cleanup_response.cleaned_content.contains('#')
-        || cleanup_response.cleaned_content.contains('*')
-        || cleanup_response.cleaned_content.contains('[')
-        || cleanup_response.cleaned_content.contains('`')
-        || cleanup_response.cleaned_content.starts_with("```");
+    let has_markdown_syntax = cleaned.contains('#')
+        || cleaned.contains('*')
+        || cleaned.contains('[')
+        || cleaned.contains('`')
+        || cleaned.starts_with("```");
 
     let plain_text = if has_markdown_syntax {
         // Contains markdown - parse it
-        markdown_to_plain_text(&cleanup_response.cleaned_content)
+        markdown_to_plain_text(cleaned, options)
     } else {
         // Plain text - just normalize spaces within lines while preserving newlines
-        cleanup_response.cleaned_content
+        cleaned
             .lines()
             .map(|line| {
                 // Normalize spaces within the line
@@ -135,16 +197,16 @@ pub async fn cleanup_text(text: &str) -> Result<String, String> {
     };
 
     info!(
-        original_bytes = cleanup_response.cleaned_content.len(),
+        original_bytes = cleaned.len(),
         plain_bytes = plain_text.len(),
-        "Natural Reading completed, markdown stripped"
+        "Cleaned text ready, markdown stripped"
     );
     debug!(
-        original_preview = %cleanup_response.cleaned_content.chars().take(100).collect::<String>(),
+        original_preview = %cleaned.chars().take(100).collect::<String>(),
         plain_preview = %plain_text.chars().take(100).collect::<String>(),
         "Text preview (before and after markdown stripping)"
     );
 
-    Ok(plain_text)
+    plain_text
 }
 
@@ -0,0 +1,131 @@
+//! Abbreviation and acronym expansion
+//!
+//! Voices read "e.g." letter by letter, stumble over "km/h" and say "Dr." as
+//! "drive". Common abbreviations are replaced by their spoken form, each rule
+//! with its own toggle for texts where it reads wrong. Acronyms the voice
+//! doesn't know can also be spelled out letter by letter ("FBI" → "F B I");
+//! acronyms said as words ("NASA", "NATO") are left alone.
+
+use super::lexicon::replace_words;
+
+/// A written abbreviation and how to say it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbbreviationRule {
+    /// Written form (whole words, case-insensitive)
+    pub written: &'static str,
+    /// Spoken form
+    pub spoken: &'static str,
+}
+
+const fn rule(written: &'static str, spoken: &'static str) -> AbbreviationRule {
+    AbbreviationRule { written, spoken }
+}
+
+/// Abbreviations expanded unless their rule is turned off.
+///
+/// Only forms with a single common reading: "St." (saint or street) and "no."
+/// (number or the word) are left to the lexicon.
+pub const ABBREVIATION_RULES: &[AbbreviationRule] = &[
+    rule("e.g.", "for example"),
+    rule("i.e.", "that is"),
+    rule("etc.", "et cetera"),
+    rule("vs.", "versus"),
+    rule("approx.", "approximately"),
+    rule("Mr.", "Mister"),
+    rule("Mrs.", "Missus"),
+    rule("Dr.", "Doctor"),
+    rule("Prof.", "Professor"),
+    rule("Jr.", "Junior"),
+    rule("km/h", "kilometers per hour"),
+    rule("mph", "miles per hour"),
+    rule("km", "kilometers"),
+    rule("kg", "kilograms"),
+];
+
+/// Acronyms voices say as words, never spelled out.
+const SPOKEN_ACRONYMS: &[&str] = &[
+    "NASA", "NATO", "UNESCO", "UNICEF", "LASER", "RADAR", "SCUBA", "AIDS", "COVID", "OPEC", "FIFA", "GIF", "JPEG",
+    "PIN", "RAM", "ROM", "SIM", "WIFI", "ASAP", "OK",
+];
+
+/// Longest word spelled out as an acronym (longer all-caps words are shouted, not abbreviated).
+const MAX_ACRONYM_LEN: usize = 5;
+
+/// Expand the abbreviations of every rule not in `disabled` (by written form).
+pub fn expand_abbreviations(text: &str, disabled: &[String]) -> String {
+    let entries = ABBREVIATION_RULES
+        .iter()
+        .filter(|rule| !disabled.iter().any(|written| written.eq_ignore_ascii_case(rule.written)))
+        .map(|rule| (rule.written, rule.spoken))
+        .collect();
+    replace_words(text, entries)
+}
+
+/// Spell out unknown acronyms letter by letter ("FBI" → "F B I", "CDs" → "C Ds").
+///
+/// Lines without any lowercase letter (headings, shouting) are left alone, and
+/// so are Roman numerals.
+pub fn spell_acronyms(text: &str) -> String {
+    text.split_inclusive('\n')
+        .map(|line| {
+            if !line.chars().any(char::is_lowercase) {
+                return line.to_string();
+            }
+            let mut result = String::with_capacity(line.len());
+            let mut word = String::new();
+            for c in line.chars() {
+                if c.is_alphanumeric() {
+                    word.push(c);
+                    continue;
+                }
+                result.push_str(&spell_acronym(&word));
+                word.clear();
+                result.push(c);
+            }
+            result.push_str(&spell_acronym(&word));
+            result
+        })
+        .collect()
+}
+
+/// `word` spelled out if it is an unknown acronym, else unchanged.
+fn spell_acronym(word: &str) -> String {
+    let letters = word.strip_suffix('s').unwrap_or(word);
+    let is_acronym = (2..=MAX_ACRONYM_LEN).contains(&letters.len())
+        && letters.chars().all(|c| c.is_ascii_uppercase())
+        && !letters.chars().all(|c| matches!(c, 'I' | 'V' | 'X'))
+        && !SPOKEN_ACRONYMS.contains(&letters);
+    if !is_acronym {
+        return word.to_string();
+    }
+    let mut spelled: Vec<String> = letters.chars().map(String::from).collect();
+    if letters.len() < word.len() {
+        if let Some(last) = spelled.last_mut() {
+            last.push('s');
+        }
+    }
+    spelled.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_abbreviations() {
+        assert_eq!(
+            expand_abbreviations("Dr. Lee drove at 90 km/h, i.e. too fast, past shops etc.\nThen", &[]),
+            "Doctor Lee drove at 90 kilometers per hour, that is too fast, past shops et cetera.\nThen"
+        );
+        assert_eq!(expand_abbreviations("Dr. Lee, e.g. here", &["dr.".to_string()]), "Dr. Lee, for example here");
+        assert_eq!(expand_abbreviations("The kmh sign", &[]), "The kmh sign");
+    }
+
+    #[test]
+    fn test_spell_acronyms() {
+        assert_eq!(
+            spell_acronyms("The FBI and NASA burned CDs in World War II.\nBREAKING NEWS"),
+            "The F B I and NASA burned C Ds in World War II.\nBREAKING NEWS"
+        );
+    }
+}
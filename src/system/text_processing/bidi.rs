@@ -0,0 +1,162 @@
+//! Right-to-left text detection
+//!
+//! Captured text is in logical order, which is also speaking order, so RTL
+//! text needs no reordering. What it needs is the right voice: Arabic or
+//! Hebrew read by an English voice comes out as silence or spelled letters.
+//! This module finds the RTL language of a text and splits mixed text into
+//! runs of one direction so each run can be matched with a voice.
+
+/// Languages written right-to-left that voices exist for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RtlLanguage {
+    Arabic,
+    Hebrew,
+    Persian,
+    Urdu,
+}
+
+impl RtlLanguage {
+    /// ISO 639-1 code, as used by the voice lists and flags.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Arabic => "ar",
+            Self::Hebrew => "he",
+            Self::Persian => "fa",
+            Self::Urdu => "ur",
+        }
+    }
+
+    /// English name shown in the UI.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Arabic => "Arabic",
+            Self::Hebrew => "Hebrew",
+            Self::Persian => "Persian",
+            Self::Urdu => "Urdu",
+        }
+    }
+}
+
+/// A run of text in a single direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectionRun<'a> {
+    pub text: &'a str,
+    pub rtl: bool,
+}
+
+fn is_hebrew(c: char) -> bool {
+    matches!(c, '\u{0590}'..='\u{05FF}' | '\u{FB1D}'..='\u{FB4F}')
+}
+
+fn is_arabic_script(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0600}'..='\u{06FF}'
+            | '\u{0750}'..='\u{077F}'
+            | '\u{08A0}'..='\u{08FF}'
+            | '\u{FB50}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFF}'
+    )
+}
+
+/// Whether `c` is a strong right-to-left letter (Hebrew, Arabic, Syriac, Thaana, N'Ko).
+pub fn is_rtl_char(c: char) -> bool {
+    c.is_alphabetic() && (is_hebrew(c) || is_arabic_script(c) || matches!(c, '\u{0700}'..='\u{07FF}'))
+}
+
+/// Whether `c` sets the direction of the text around it.
+fn is_strong(c: char) -> bool {
+    c.is_alphabetic()
+}
+
+/// The RTL language of `text`, if it contains any right-to-left letters.
+///
+/// Arabic script is told apart from Persian and Urdu by the letters only
+/// those alphabets use.
+pub fn rtl_language(text: &str) -> Option<RtlLanguage> {
+    let (mut hebrew, mut arabic, mut persian, mut urdu) = (0, 0, 0, 0);
+    for c in text.chars() {
+        if is_hebrew(c) && c.is_alphabetic() {
+            hebrew += 1;
+        } else if is_arabic_script(c) && c.is_alphabetic() {
+            arabic += 1;
+            match c {
+                // ٹ ڈ ڑ ں ہ ھ ے
+                'ٹ' | 'ڈ' | 'ڑ' | 'ں' | 'ہ' | 'ھ' | 'ے' => urdu += 1,
+                // پ چ ژ گ ک ی (Urdu uses them too)
+                'پ' | 'چ' | 'ژ' | 'گ' | 'ک' | 'ی' => persian += 1,
+                _ => {}
+            }
+        }
+    }
+    if hebrew == 0 && arabic == 0 {
+        None
+    } else if hebrew > arabic {
+        Some(RtlLanguage::Hebrew)
+    } else if urdu > 0 {
+        Some(RtlLanguage::Urdu)
+    } else if persian > 0 {
+        Some(RtlLanguage::Persian)
+    } else {
+        Some(RtlLanguage::Arabic)
+    }
+}
+
+/// Split `text` into runs of one direction.
+///
+/// Neutral characters (spaces, digits, punctuation) stay with the run they
+/// follow, so an English quote inside Arabic becomes its own run with its
+/// trailing space and punctuation. Leading neutrals join the first run.
+pub fn direction_runs(text: &str) -> Vec<DirectionRun<'_>> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    let mut current: Option<bool> = None;
+    for (index, c) in text.char_indices() {
+        if !is_strong(c) {
+            continue;
+        }
+        let rtl = is_rtl_char(c);
+        match current {
+            Some(direction) if direction != rtl => {
+                runs.push(DirectionRun { text: &text[start..index], rtl: direction });
+                start = index;
+                current = Some(rtl);
+            }
+            Some(_) => {}
+            None => current = Some(rtl),
+        }
+    }
+    if start < text.len() {
+        runs.push(DirectionRun { text: &text[start..], rtl: current.unwrap_or(false) });
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rtl_language() {
+        assert_eq!(rtl_language("Hello world"), None);
+        assert_eq!(rtl_language("مرحبا بالعالم"), Some(RtlLanguage::Arabic));
+        assert_eq!(rtl_language("שלום עולם and some English"), Some(RtlLanguage::Hebrew));
+        assert_eq!(rtl_language("زبان فارسی"), Some(RtlLanguage::Persian));
+        assert_eq!(rtl_language("یہ اردو ہے"), Some(RtlLanguage::Urdu));
+    }
+
+    #[test]
+    fn test_direction_runs() {
+        let runs = direction_runs("قال: \"Hello world\" ثم ذهب.");
+        assert_eq!(
+            runs,
+            vec![
+                DirectionRun { text: "قال: \"", rtl: true },
+                DirectionRun { text: "Hello world\" ", rtl: false },
+                DirectionRun { text: "ثم ذهب.", rtl: true },
+            ]
+        );
+        assert_eq!(direction_runs("123 abc"), vec![DirectionRun { text: "123 abc", rtl: false }]);
+        assert!(direction_runs("").is_empty());
+    }
+}
@@ -0,0 +1,149 @@
+//! Chapters of long plain texts
+//!
+//! Markdown and HTML say where their sections start; pasted books, transcripts
+//! and reports usually don't. In texts long enough to need navigating, lines
+//! that look like section headings are taken as chapter starts: "Chapter 12",
+//! "Part II: The Return", short ALL-CAPS lines, and any line after a run of
+//! blank lines.
+
+/// Shortest text chapters are looked for in, in characters.
+const MIN_TEXT_CHARS: usize = 5000;
+
+/// Shortest chapter, in characters (closer headings are one heading, e.g. "CHAPTER 1" then its title).
+const MIN_CHAPTER_CHARS: usize = 500;
+
+/// Longest heading line, in characters.
+const MAX_HEADING_CHARS: usize = 70;
+
+/// Blank lines that separate sections rather than paragraphs.
+const SECTION_BLANK_LINES: usize = 2;
+
+/// Words starting a numbered heading ("Chapter 3", "Part II").
+const HEADING_WORDS: &[&str] = &["chapter", "part", "section", "book", "act", "prologue", "epilogue"];
+
+/// A probable section start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chapter {
+    /// Heading line (or the first words of the section)
+    pub title: String,
+    /// Byte offset of the heading in the text
+    pub offset: usize,
+}
+
+/// Probable chapter starts of `text`, in order; empty for short texts or fewer than two chapters.
+pub fn detect_chapters(text: &str) -> Vec<Chapter> {
+    if text.chars().count() < MIN_TEXT_CHARS {
+        return Vec::new();
+    }
+
+    let mut chapters: Vec<Chapter> = Vec::new();
+    let mut blank_lines = 0;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            blank_lines += 1;
+            continue;
+        }
+        let after_blank = blank_lines > 0 || start == 0;
+        let after_section_break = blank_lines >= SECTION_BLANK_LINES;
+        blank_lines = 0;
+
+        let is_heading = trimmed.chars().count() <= MAX_HEADING_CHARS
+            && after_blank
+            && (is_numbered_heading(trimmed) || is_caps_heading(trimmed));
+        if !is_heading && !after_section_break {
+            continue;
+        }
+        let too_close = chapters
+            .last()
+            .is_some_and(|last| text[last.offset..start].chars().count() < MIN_CHAPTER_CHARS);
+        if too_close {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        chapters.push(Chapter { title: heading_title(trimmed), offset: start + indent });
+    }
+
+    if chapters.len() < 2 {
+        return Vec::new();
+    }
+    chapters
+}
+
+/// "Chapter 3", "PART II: The Return", "Prologue".
+fn is_numbered_heading(line: &str) -> bool {
+    let mut words = line.split_whitespace();
+    let Some(first) = words.next() else {
+        return false;
+    };
+    let first = first.trim_end_matches([':', '.']).to_lowercase();
+    if !HEADING_WORDS.contains(&first.as_str()) {
+        return false;
+    }
+    if first == "prologue" || first == "epilogue" {
+        return true;
+    }
+    words.next().is_some_and(|number| {
+        let number = number.trim_end_matches([':', '.', ',']);
+        !number.is_empty()
+            && (number.chars().all(|c| c.is_ascii_digit())
+                || number.chars().all(|c| "IVXLCivxlc".contains(c))
+                || number.chars().all(char::is_alphabetic) && number.chars().next().is_some_and(char::is_uppercase))
+    })
+}
+
+/// A short line of capitals that doesn't end like a sentence ("THE LONG WINTER").
+fn is_caps_heading(line: &str) -> bool {
+    let letters = line.chars().filter(|c| c.is_alphabetic()).count();
+    letters >= 3 && !line.chars().any(char::is_lowercase) && !line.ends_with([',', ';'])
+}
+
+/// Title of a chapter starting at `line`: the line, cut at a word boundary if long.
+fn heading_title(line: &str) -> String {
+    if line.chars().count() <= MAX_HEADING_CHARS {
+        return line.to_string();
+    }
+    let mut title = String::new();
+    for word in line.split_whitespace() {
+        if title.chars().count() + word.chars().count() + 1 > MAX_HEADING_CHARS {
+            break;
+        }
+        if !title.is_empty() {
+            title.push(' ');
+        }
+        title.push_str(word);
+    }
+    title.push('…');
+    title
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_chapters() {
+        let paragraph = "It was a long day and nothing much happened in it, again. ".repeat(20);
+        let text = format!(
+            "CHAPTER 1\n\nThe Beginning\n\n{paragraph}\n\nChapter Two\n{paragraph}\nNOT A HEADING\n{paragraph}\n\n\n\
+             {paragraph}\n\nTHE END OF IT ALL\n\n{paragraph}"
+        );
+        let titles: Vec<String> = detect_chapters(&text).into_iter().map(|chapter| chapter.title).collect();
+        assert_eq!(
+            titles,
+            [
+                "CHAPTER 1",
+                "Chapter Two",
+                "It was a long day and nothing much happened in it, again. It was a…",
+                "THE END OF IT ALL",
+            ]
+        );
+
+        let chapters = detect_chapters(&text);
+        assert!(text[chapters[1].offset..].starts_with("Chapter Two"));
+        assert!(detect_chapters(&paragraph).is_empty());
+    }
+}
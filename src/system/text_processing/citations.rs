@@ -0,0 +1,123 @@
+//! Citation and footnote skipping
+//!
+//! Papers and encyclopedia articles are peppered with citation markers that
+//! mean nothing by ear: "[3]", "[citation needed]", "(Smith et al., 2020)".
+//! Those are dropped, along with the footnotes and reference lists they point
+//! to: markdown footnote definitions, note lines copied from Wikipedia
+//! ("^ Smith, John…", "[1] Smith…") and sections headed "References",
+//! "Notes" or similar, up to the next markdown heading.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// Bracketed markers: "[3]", "[3, 5–7]", "[a]", "[note 2]", "\[^1]", "[citation needed]".
+static BRACKET_MARKER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"(?i)[ \t]*\[(?:\d+(?:\s*[-–,]\s*\d+)*|[a-z]|note \d+|\^[\w-]+",
+        r"|citation needed|clarification needed|page needed|who\?|when\?|by whom\?|dubious(?: – discuss)?)\]",
+    ))
+    .expect("valid citation marker regex")
+});
+
+/// Author-year citations: "(Smith, 2020)", "(Smith et al. 2020a; Doe & Roe, 2019, p. 4)".
+static AUTHOR_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    let author = r"\p{Lu}[\p{L}'’-]+";
+    // Authors ("Smith", "Smith and Roe", "Smith, Roe, & Doe", "Smith et al."), year, page
+    let authors = format!(r"{author}(?:(?:,\s+|\s+(?:and|&)\s+|,\s+(?:and|&)\s+){author})*(?:\s+et\s+al\.?)?");
+    let year = r"(?:\d{4}[a-z]?|n\.d\.)(?:,\s*(?:pp?\.\s*)?\d+(?:\s*[-–]\s*\d+)?)?";
+    let work = format!(r"(?:(?:see|e\.g\.,?|cf\.)\s+)?{authors},?\s+{year}");
+    Regex::new(&format!(r"[ \t]*\({work}(?:;\s*{work})*\)")).expect("valid author-year citation regex")
+});
+
+/// Headings of the sections listing references or notes.
+const REFERENCE_HEADINGS: &[&str] = &[
+    "references",
+    "notes",
+    "footnotes",
+    "notes and references",
+    "citations",
+    "bibliography",
+    "works cited",
+    "sources",
+];
+
+/// Whether `line` is a markdown heading.
+fn is_heading(line: &str) -> bool {
+    line.trim_start().starts_with('#')
+}
+
+/// Whether `line` alone heads a reference section ("References", "## Notes:").
+fn is_reference_heading(line: &str) -> bool {
+    let title = line.trim().trim_start_matches('#').trim().trim_end_matches(':').trim();
+    REFERENCE_HEADINGS.iter().any(|heading| title.eq_ignore_ascii_case(heading))
+}
+
+/// Whether `line` is a footnote: "[^1]: …", "[1] …" or Wikipedia's "^ …".
+fn is_footnote(line: &str) -> bool {
+    let line = line.trim_start();
+    if line.starts_with("^ ") || (line.starts_with("[^") && line.contains("]:")) {
+        return true;
+    }
+    line.strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .is_some_and(|(number, _)| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Drop citation markers, footnotes and reference sections from `text`.
+pub fn skip_citations(text: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    let mut in_references = false;
+    let mut in_footnote = false;
+    for line in text.lines() {
+        if is_reference_heading(line) {
+            in_references = true;
+            continue;
+        }
+        if in_references {
+            if !is_heading(line) {
+                continue;
+            }
+            in_references = false;
+        }
+        // Markdown footnotes continue on indented lines
+        if in_footnote && line.starts_with([' ', '\t']) && !line.trim().is_empty() {
+            continue;
+        }
+        in_footnote = is_footnote(line);
+        if !in_footnote {
+            lines.push(line);
+        }
+    }
+    let mut result = lines.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    let result = BRACKET_MARKER.replace_all(&result, "");
+    AUTHOR_YEAR.replace_all(&result, "").into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skip_citations() {
+        assert_eq!(
+            skip_citations("The court held[3] that the claim [4, 6–8] failed.[citation needed]"),
+            "The court held that the claim failed."
+        );
+        assert_eq!(
+            skip_citations("Sleep aids memory (Smith et al., 2020; Doe & Roe 2019a, p. 4). Rest too (Walker, n.d.)."),
+            "Sleep aids memory. Rest too."
+        );
+        // Parentheses that aren't citations stay
+        assert_eq!(skip_citations("It rose (by 20%) in 2020 (see Table 2)."), "It rose (by 20%) in 2020 (see Table 2).");
+        assert_eq!(skip_citations("Held in (Paris, France) in 2020."), "Held in (Paris, France) in 2020.");
+
+        let article = "# Otters\nOtters play.[^1]\n\n[^1]: Observed in 2019.\n    More details.\nThey swim.\n\n\
+            ## References\n^ Smith, John (2020). Otters.\n[2] Doe, Jane.\n\n## See also\nBeavers\n";
+        assert_eq!(skip_citations(article), "# Otters\nOtters play.\n\nThey swim.\n\n## See also\nBeavers\n");
+        assert_eq!(skip_citations("Text.\nNotes:\n1. First note\n2. Second"), "Text.");
+    }
+}
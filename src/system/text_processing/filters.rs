@@ -0,0 +1,69 @@
+//! User-defined filter rules
+//!
+//! Some texts carry noise no built-in pass knows about: line numbers in
+//! pasted code or legal documents, citation markers ("[12]"), page headers
+//! repeated on every page. Users write regex search/replace rules for them;
+//! the rules run in order, right after sanitation, so later passes never see
+//! what they removed.
+
+use regex::{Regex, RegexBuilder};
+use tracing::warn;
+
+/// One search/replace rule.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FilterRule {
+    /// Regular expression to search for (`^` and `$` match at line starts and ends)
+    pub pattern: String,
+    /// Replacement text, where `$1` or `${name}` insert captured groups
+    #[serde(default)]
+    pub replacement: String,
+    /// Whether the rule is applied
+    #[serde(default = "enabled_default")]
+    pub enabled: bool,
+}
+
+fn enabled_default() -> bool {
+    true
+}
+
+/// Compile `pattern` the way filter rules are applied.
+pub fn compile_filter(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern).multi_line(true).build()
+}
+
+/// Apply the enabled `rules` to `text`, in order; rules that don't compile are skipped.
+pub fn apply_filter_rules(text: &str, rules: &[FilterRule]) -> String {
+    let mut result = text.to_string();
+    for rule in rules.iter().filter(|rule| rule.enabled) {
+        match compile_filter(&rule.pattern) {
+            Ok(regex) => result = regex.replace_all(&result, rule.replacement.as_str()).into_owned(),
+            Err(err) => warn!(pattern = %rule.pattern, error = %err, "Skipping invalid filter rule"),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, replacement: &str) -> FilterRule {
+        FilterRule { pattern: pattern.to_string(), replacement: replacement.to_string(), enabled: true }
+    }
+
+    #[test]
+    fn test_apply_filter_rules() {
+        let text = "12  The court held[3] that\n13  the claim, as argued[4][5], failed.";
+        let rules = vec![
+            rule(r"^\d+\s+", ""),
+            rule(r"\[\d+\]", ""),
+            rule(r"(claim), as (argued)", "$1 $2"),
+            rule(r"(unclosed", "x"),
+        ];
+        assert_eq!(apply_filter_rules(text, &rules), "The court held that\nthe claim argued, failed.");
+
+        let disabled = vec![FilterRule { enabled: false, ..rule(r"\d", "") }];
+        assert_eq!(apply_filter_rules("a1", &disabled), "a1");
+        assert!(compile_filter("(unclosed").is_err());
+    }
+}
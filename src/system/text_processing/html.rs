@@ -0,0 +1,269 @@
+//! HTML to text
+//!
+//! Feed items and read-later articles arrive as HTML. Only the text is
+//! kept, laid out in lines the way a browser would show it, so markup is
+//! never read aloud. Copied text can be HTML too (page sources, some
+//! editors and browsers put markup in the plain text flavor): it is
+//! recognized by its tags and read the same way, with images and links
+//! announced like in markdown. Tables become markdown tables, so they are
+//! read the way the table pass reads those.
+
+use super::images::spoken_image_alt;
+use super::links::spoken_link_suffix;
+use super::TextProcessingOptions;
+
+/// Elements whose tags mark text as HTML rather than prose with angle brackets.
+const KNOWN_ELEMENTS: &[&str] = &[
+    "html", "head", "body", "meta", "title", "style", "script", "p", "div", "span", "a", "img", "br", "hr", "ul",
+    "ol", "li", "table", "tr", "td", "th", "h1", "h2", "h3", "h4", "h5", "h6", "em", "strong", "b", "i", "u",
+    "blockquote", "pre", "code", "section", "article", "figure", "figcaption",
+];
+
+/// Decode the HTML character references in `text`.
+fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';').filter(|&end| end <= 10) else {
+            result.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            "ndash" => Some('–'),
+            "mdash" => Some('—'),
+            "hellip" => Some('…'),
+            "lsquo" => Some('‘'),
+            "rsquo" => Some('’'),
+            "ldquo" => Some('“'),
+            "rdquo" => Some('”'),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Lowercase element name of a tag's contents ("/P class=x" → "p").
+fn tag_name(tag: &str) -> String {
+    tag.trim_start_matches('/')
+        .split(|c: char| c.is_whitespace() || c == '/')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// Decoded value of attribute `name` in a tag's contents (`img src="a.png" alt='A cat'`).
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let mut search = 0;
+    while let Some(found) = lower[search..].find(name) {
+        let start = search + found;
+        search = start + name.len();
+        let after = lower[search..].trim_start();
+        let preceded = lower[..start].ends_with(char::is_whitespace);
+        if !preceded || !after.starts_with('=') {
+            continue;
+        }
+        let value = tag[tag.len() - after.len() + 1..].trim_start();
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or_default(),
+            _ => value.split(char::is_whitespace).next().unwrap_or_default(),
+        };
+        return Some(decode_entities(value));
+    }
+    None
+}
+
+/// Whether `text` is HTML markup rather than prose that happens to contain angle brackets.
+///
+/// Takes a doctype, or at least two tags of common elements of which one
+/// closes (four without any closing, for sloppy markup).
+pub fn looks_like_html(text: &str) -> bool {
+    let mut known_tags = 0;
+    let mut closing = false;
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+        if tag.to_ascii_lowercase().starts_with("!doctype html") {
+            return true;
+        }
+        // "a < b and c > d" has a space where the element name would be
+        if tag.starts_with(char::is_whitespace) || !KNOWN_ELEMENTS.contains(&tag_name(tag).as_str()) {
+            continue;
+        }
+        known_tags += 1;
+        closing |= tag.starts_with('/') || tag.ends_with('/');
+    }
+    known_tags >= 2 && closing || known_tags >= 4
+}
+
+/// Extract readable text from an HTML fragment (feed items, saved articles).
+///
+/// Tags are dropped, scripts and styles skipped, block elements become line
+/// breaks and whitespace is collapsed the way a browser would.
+pub fn html_to_text(html: &str) -> String {
+    convert(html, None)
+}
+
+/// Extract readable text from copied HTML, announcing images and links according to `options`.
+pub fn copied_html_to_text(html: &str, options: &TextProcessingOptions) -> String {
+    convert(html, Some(options))
+}
+
+/// Text of `html`; images and links are only announced with `options`.
+fn convert(html: &str, options: Option<&TextProcessingOptions>) -> String {
+    // Line breaks in the source are just whitespace; only tags break lines
+    let push_text = |text: &mut String, source: &str| {
+        text.push_str(&decode_entities(source).replace(['\n', '\r'], " "));
+    };
+    let mut text = String::with_capacity(html.len());
+    let mut skipping: Option<String> = None;
+    // Target of the link being read
+    let mut link: Option<String> = None;
+    // Table row being read: where it starts, whether it has header cells, whether it is the table's first
+    let (mut row_start, mut header_row, mut first_row) = (0, false, false);
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        if skipping.is_none() {
+            push_text(&mut text, &rest[..start]);
+        }
+        // A lone '<' is text
+        let Some(end) = rest[start..].find('>') else {
+            if skipping.is_none() {
+                push_text(&mut text, &rest[start..]);
+            }
+            rest = "";
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag_name(tag);
+        if let Some(skipped) = &skipping {
+            if closing && name == *skipped {
+                skipping = None;
+            }
+            continue;
+        }
+        match name.as_str() {
+            "script" | "style" if !closing => skipping = Some(name),
+            "img" => {
+                let alt = attribute(tag, "alt").unwrap_or_default();
+                if let Some(spoken) = options.and_then(|options| spoken_image_alt(&alt, options.read_image_alt_text)) {
+                    text.push_str(&format!(" {spoken} "));
+                }
+            }
+            "a" if !closing => link = attribute(tag, "href"),
+            "a" => {
+                let suffix = options
+                    .zip(link.take())
+                    .and_then(|(options, href)| spoken_link_suffix(options.link_reading, &href));
+                if let Some(suffix) = suffix {
+                    text.push_str(&suffix);
+                }
+            }
+            "table" => {
+                first_row = !closing;
+                text.push('\n');
+            }
+            "tr" if !closing => {
+                text.push('\n');
+                row_start = text.len();
+                header_row = false;
+            }
+            "th" | "td" if !closing => {
+                header_row |= name == "th";
+                text.push_str(" | ");
+            }
+            "tr" => {
+                text.push_str(" |\n");
+                // A separator below the headers, or above the rows of a table without any
+                if std::mem::take(&mut first_row) {
+                    if header_row {
+                        text.push_str("|---|\n");
+                    } else {
+                        text.insert_str(row_start, "|---|\n");
+                    }
+                }
+            }
+            "br" | "p" | "div" | "li" | "blockquote" | "pre" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "ul"
+            | "ol" | "figure" | "section" | "article" => text.push('\n'),
+            _ => {}
+        }
+    }
+    if skipping.is_none() {
+        push_text(&mut text, rest);
+    }
+
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_text() {
+        assert_eq!(
+            html_to_text("<h1>Title</h1>\n<p>One &amp; <em>two</em>\n three.</p><style>p {}</style><ul><li>A&#x21;</li></ul>"),
+            "Title\nOne & two three.\nA!"
+        );
+        assert_eq!(html_to_text("AT&T &unknown; 5 < 6"), "AT&T &unknown; 5 < 6");
+        assert_eq!(
+            html_to_text("<table><tr><th>Name</th><th>Age</th></tr><tr><td>Alice</td><td>30</td></tr></table>"),
+            "| Name | Age |\n|---|\n| Alice | 30 |"
+        );
+        assert_eq!(html_to_text("<table><tr><td>1</td><td>2</td></tr></table>"), "|---|\n| 1 | 2 |");
+    }
+
+    #[test]
+    fn test_copied_html_to_text() {
+        let copied = "<p>See <a href=\"https://www.example.com/a\">this</a>:</p><img src=x.png alt='A &quot;cat&quot;'>";
+        assert!(looks_like_html(copied));
+        let options = TextProcessingOptions {
+            link_reading: super::super::LinkReading::WithDomain,
+            ..TextProcessingOptions::default()
+        };
+        assert_eq!(copied_html_to_text(copied, &options), "See this, example.com:\nImage: A \"cat\".");
+        assert_eq!(html_to_text(copied), "See this:");
+
+        assert!(!looks_like_html("if a<b and c>d, or <b> is bold"));
+        assert!(!looks_like_html("Use <div> for blocks"));
+        assert!(looks_like_html("<!DOCTYPE html>"));
+    }
+}
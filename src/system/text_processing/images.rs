@@ -0,0 +1,32 @@
+//! Image alt text reading
+//!
+//! Figures carry information that is lost when the text is flattened. Parsers
+//! that see images (markdown, HTML, EPUB) call [`spoken_image_alt`] so the alt
+//! text is announced instead of being read as if it were part of the prose.
+
+/// Spoken form of an image's alt text, or `None` if it should be skipped.
+pub fn spoken_image_alt(alt: &str, read_alt_text: bool) -> Option<String> {
+    let alt = alt.split_whitespace().collect::<Vec<_>>().join(" ");
+    if !read_alt_text || alt.is_empty() {
+        return None;
+    }
+
+    let terminator = if alt.ends_with(['.', '!', '?']) { "" } else { "." };
+    Some(format!("Image: {alt}{terminator}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spoken_image_alt() {
+        assert_eq!(
+            spoken_image_alt("A cat\n on a  mat", true).as_deref(),
+            Some("Image: A cat on a mat.")
+        );
+        assert_eq!(spoken_image_alt("Chart!", true).as_deref(), Some("Image: Chart!"));
+        assert_eq!(spoken_image_alt("A cat", false), None);
+        assert_eq!(spoken_image_alt("  ", true), None);
+    }
+}
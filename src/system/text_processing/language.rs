@@ -0,0 +1,121 @@
+//! Per-sentence language detection
+//!
+//! Used to switch voices inside mixed-language text. Detection runs on single
+//! sentences, which are short, so it is limited to the languages the user has
+//! voices for and only trusted when whatlang reports it as reliable.
+
+use whatlang::{Detector, Lang};
+
+/// ISO 639-1 codes (as used in voice keys) and the matching whatlang languages.
+const LANGUAGES: &[(&str, Lang)] = &[
+    ("af", Lang::Afr),
+    ("ar", Lang::Ara),
+    ("bg", Lang::Bul),
+    ("ca", Lang::Cat),
+    ("cs", Lang::Ces),
+    ("da", Lang::Dan),
+    ("de", Lang::Deu),
+    ("el", Lang::Ell),
+    ("en", Lang::Eng),
+    ("es", Lang::Spa),
+    ("fa", Lang::Pes),
+    ("fi", Lang::Fin),
+    ("fr", Lang::Fra),
+    ("he", Lang::Heb),
+    ("hi", Lang::Hin),
+    ("hr", Lang::Hrv),
+    ("hu", Lang::Hun),
+    ("id", Lang::Ind),
+    ("it", Lang::Ita),
+    ("ja", Lang::Jpn),
+    ("ka", Lang::Kat),
+    ("ko", Lang::Kor),
+    ("lv", Lang::Lav),
+    ("ml", Lang::Mal),
+    ("ne", Lang::Nep),
+    ("nl", Lang::Nld),
+    ("no", Lang::Nob),
+    ("pl", Lang::Pol),
+    ("pt", Lang::Por),
+    ("ro", Lang::Ron),
+    ("ru", Lang::Rus),
+    ("sk", Lang::Slk),
+    ("sl", Lang::Slv),
+    ("sr", Lang::Srp),
+    ("sv", Lang::Swe),
+    ("tr", Lang::Tur),
+    ("uk", Lang::Ukr),
+    ("ur", Lang::Urd),
+    ("vi", Lang::Vie),
+    ("zh", Lang::Cmn),
+];
+
+/// The language family of a voice key ("fr_FR-siwis-medium" -> "fr").
+pub fn voice_language(voice_key: &str) -> &str {
+    voice_key.split(['_', '-']).next().unwrap_or(voice_key)
+}
+
+/// Detect the language of `text` among `candidates` (ISO 639-1 codes).
+///
+/// Returns None when the text is too short or ambiguous to tell, or when
+/// fewer than two candidates are known to the detector.
+pub fn detect_language(text: &str, candidates: &[&str]) -> Option<&'static str> {
+    let allowed: Vec<Lang> = LANGUAGES
+        .iter()
+        .filter(|(code, _)| candidates.contains(code))
+        .map(|&(_, lang)| lang)
+        .collect();
+    if allowed.len() < 2 {
+        return None;
+    }
+
+    let info = Detector::with_allowlist(allowed).detect(text)?;
+    if !info.is_reliable() {
+        return None;
+    }
+    LANGUAGES
+        .iter()
+        .find(|&&(_, lang)| lang == info.lang())
+        .map(|&(code, _)| code)
+}
+
+/// Detect the language of a whole text among all languages the detector knows.
+///
+/// Returns None when the text is too short or ambiguous to tell.
+pub fn detect_text_language(text: &str) -> Option<&'static str> {
+    let all: Vec<&str> = LANGUAGES.iter().map(|&(code, _)| code).collect();
+    detect_language(text, &all)
+}
+
+/// English name of a language by its ISO 639-1 code ("es" -> "Spanish").
+pub fn language_name(code: &str) -> Option<&'static str> {
+    LANGUAGES
+        .iter()
+        .find(|&&(known, _)| known == code)
+        .map(|&(_, lang)| lang.eng_name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_language() {
+        let candidates = ["en", "fr", "de"];
+        assert_eq!(
+            detect_language("The quick brown fox jumps over the lazy dog while everyone watches.", &candidates),
+            Some("en")
+        );
+        assert_eq!(
+            detect_language("Je ne sais pas pourquoi elle est partie si tôt ce matin avec les enfants.", &candidates),
+            Some("fr")
+        );
+        assert_eq!(detect_language("The quick brown fox.", &["en"]), None);
+        assert_eq!(voice_language("fr_FR-siwis-medium"), "fr");
+        assert_eq!(
+            detect_text_language("El perro corre por el parque mientras los niños juegan con la pelota."),
+            Some("es")
+        );
+        assert_eq!(language_name("es"), Some("Spanish"));
+    }
+}
@@ -0,0 +1,317 @@
+//! Pronunciation lexicon
+//!
+//! Words the voice gets wrong (acronyms, drug names, project names) are
+//! replaced by how they should be said before synthesis. Lexicons can be
+//! shared as W3C PLS files (the format Polly, Azure and most TTS systems use)
+//! or as simple `grapheme,alias,phoneme` CSV files.
+//!
+//! Entries with only a phoneme are kept for export but not applied: none of
+//! the engines takes phonetic input yet.
+
+use std::path::Path;
+
+/// One lexicon entry: a written form and how to say it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LexiconEntry {
+    /// Written form to match (whole words, case-insensitive)
+    pub grapheme: String,
+    /// Replacement text to speak instead
+    #[serde(default)]
+    pub alias: Option<String>,
+    /// IPA pronunciation (kept for PLS round-trips)
+    #[serde(default)]
+    pub phoneme: Option<String>,
+}
+
+const PLS_NAMESPACE: &str = "http://www.w3.org/2005/01/pronunciation-lexicon";
+
+/// Language written into exported PLS files.
+const PLS_DEFAULT_LANG: &str = "en-US";
+
+/// Replace every lexicon grapheme in `text` by its alias.
+pub fn apply_lexicon(text: &str, lexicon: &[LexiconEntry]) -> String {
+    let entries: Vec<(&str, &str)> = lexicon
+        .iter()
+        .filter_map(|entry| Some((entry.grapheme.as_str(), entry.alias.as_deref()?)))
+        .collect();
+    replace_words(text, entries)
+}
+
+/// Replace every whole-word occurrence of a written form in `text` by its spoken form.
+///
+/// A written form ending in a period that ends a line (or the text) keeps the
+/// period, so "etc." ending a sentence still ends it.
+pub(super) fn replace_words(text: &str, mut entries: Vec<(&str, &str)>) -> String {
+    entries.retain(|(grapheme, _)| !grapheme.is_empty());
+    if entries.is_empty() {
+        return text.to_string();
+    }
+    // Longest first so "e.g." wins over "e"
+    entries.sort_by_key(|(grapheme, _)| std::cmp::Reverse(grapheme.len()));
+
+    let mut result = String::with_capacity(text.len());
+    let mut previous: Option<char> = None;
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        let at_word_start = !previous.is_some_and(char::is_alphanumeric);
+        let matched = at_word_start
+            .then(|| {
+                entries.iter().find_map(|(grapheme, alias)| {
+                    let len = prefix_len_ignore_case(rest, grapheme)?;
+                    let at_word_end = !rest[len..].chars().next().is_some_and(char::is_alphanumeric);
+                    at_word_end.then_some((len, *alias))
+                })
+            })
+            .flatten();
+
+        match matched {
+            Some((len, alias)) => {
+                result.push_str(alias);
+                let line_end = rest[len..].trim_start_matches([' ', '\t']);
+                if rest[..len].ends_with('.') && (line_end.is_empty() || line_end.starts_with('\n')) {
+                    result.push('.');
+                }
+                previous = rest[..len].chars().next_back();
+                i += len;
+            }
+            None => {
+                let c = rest.chars().next().unwrap_or_default();
+                result.push(c);
+                previous = Some(c);
+                i += c.len_utf8();
+            }
+        }
+    }
+    result
+}
+
+/// Byte length of the prefix of `haystack` equal to `needle` ignoring case.
+fn prefix_len_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    let mut haystack_chars = haystack.char_indices();
+    for n in needle.chars() {
+        let (_, h) = haystack_chars.next()?;
+        if !h.to_lowercase().eq(n.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(haystack_chars.next().map_or(haystack.len(), |(index, _)| index))
+}
+
+/// Add `imported` entries to `lexicon`, replacing entries for the same word.
+///
+/// Returns the number of entries imported.
+pub fn merge_lexicon(lexicon: &mut Vec<LexiconEntry>, imported: Vec<LexiconEntry>) -> usize {
+    let count = imported.len();
+    for entry in imported {
+        match lexicon
+            .iter_mut()
+            .find(|existing| existing.grapheme.to_lowercase() == entry.grapheme.to_lowercase())
+        {
+            Some(existing) => *existing = entry,
+            None => lexicon.push(entry),
+        }
+    }
+    count
+}
+
+/// Read a lexicon from a `.pls`/`.xml` or `.csv` file.
+pub fn import_lexicon(path: &Path) -> Result<Vec<LexiconEntry>, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+    let entries = if is_csv(path) { parse_csv(&content)? } else { parse_pls(&content)? };
+    if entries.is_empty() {
+        return Err(format!("No lexicon entries found in {}", path.display()));
+    }
+    Ok(entries)
+}
+
+/// Write the lexicon to a `.pls`/`.xml` or `.csv` file.
+pub fn export_lexicon(path: &Path, lexicon: &[LexiconEntry]) -> Result<(), String> {
+    let content = if is_csv(path) { to_csv(lexicon) } else { to_pls(lexicon) };
+    std::fs::write(path, content).map_err(|e| format!("Could not write {}: {e}", path.display()))
+}
+
+fn is_csv(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+}
+
+/// Parse a PLS document; each grapheme of a lexeme becomes its own entry.
+fn parse_pls(content: &str) -> Result<Vec<LexiconEntry>, String> {
+    let document = roxmltree::Document::parse(content).map_err(|e| format!("Invalid PLS file: {e}"))?;
+    if !document.root_element().has_tag_name("lexicon") {
+        return Err("Invalid PLS file: missing <lexicon> root element".to_string());
+    }
+
+    let child_text = |lexeme: roxmltree::Node, tag: &str| {
+        lexeme
+            .children()
+            .find(|child| child.has_tag_name(tag))
+            .and_then(|child| child.text())
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty())
+    };
+
+    let mut entries = Vec::new();
+    for lexeme in document.descendants().filter(|node| node.has_tag_name("lexeme")) {
+        let alias = child_text(lexeme, "alias");
+        let phoneme = child_text(lexeme, "phoneme");
+        if alias.is_none() && phoneme.is_none() {
+            continue;
+        }
+        for grapheme in lexeme.children().filter(|child| child.has_tag_name("grapheme")) {
+            if let Some(grapheme) = grapheme.text().map(str::trim).filter(|g| !g.is_empty()) {
+                entries.push(LexiconEntry {
+                    grapheme: grapheme.to_string(),
+                    alias: alias.clone(),
+                    phoneme: phoneme.clone(),
+                });
+            }
+        }
+    }
+    Ok(entries)
+}
+
+fn to_pls(lexicon: &[LexiconEntry]) -> String {
+    let mut pls = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <lexicon version=\"1.0\" xmlns=\"{PLS_NAMESPACE}\" alphabet=\"ipa\" xml:lang=\"{PLS_DEFAULT_LANG}\">\n"
+    );
+    for entry in lexicon {
+        pls.push_str("  <lexeme>\n");
+        pls.push_str(&format!("    <grapheme>{}</grapheme>\n", xml_escape(&entry.grapheme)));
+        if let Some(ref phoneme) = entry.phoneme {
+            pls.push_str(&format!("    <phoneme>{}</phoneme>\n", xml_escape(phoneme)));
+        }
+        if let Some(ref alias) = entry.alias {
+            pls.push_str(&format!("    <alias>{}</alias>\n", xml_escape(alias)));
+        }
+        pls.push_str("  </lexeme>\n");
+    }
+    pls.push_str("</lexicon>\n");
+    pls
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Parse `grapheme,alias[,phoneme]` rows; a leading header row is skipped.
+fn parse_csv(content: &str) -> Result<Vec<LexiconEntry>, String> {
+    let mut entries = Vec::new();
+    for (line_number, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = split_csv_line(line).map_err(|e| format!("Line {}: {e}", line_number + 1))?;
+        let field = |index: usize| {
+            fields
+                .get(index)
+                .map(|f| f.trim().to_string())
+                .filter(|f| !f.is_empty())
+        };
+        let Some(grapheme) = field(0) else {
+            continue;
+        };
+        if entries.is_empty() && grapheme.eq_ignore_ascii_case("grapheme") {
+            continue;
+        }
+        let (alias, phoneme) = (field(1), field(2));
+        if alias.is_none() && phoneme.is_none() {
+            return Err(format!("Line {}: \"{grapheme}\" has no alias or phoneme", line_number + 1));
+        }
+        entries.push(LexiconEntry { grapheme, alias, phoneme });
+    }
+    Ok(entries)
+}
+
+/// Split one CSV line, honoring double-quoted fields with `""` escapes.
+fn split_csv_line(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+fn to_csv(lexicon: &[LexiconEntry]) -> String {
+    let quote = |field: &str| {
+        if field.contains([',', '"', '\n']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    };
+    let mut csv = String::from("grapheme,alias,phoneme\n");
+    for entry in lexicon {
+        csv.push_str(&format!(
+            "{},{},{}\n",
+            quote(&entry.grapheme),
+            quote(entry.alias.as_deref().unwrap_or_default()),
+            quote(entry.phoneme.as_deref().unwrap_or_default()),
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(grapheme: &str, alias: &str) -> LexiconEntry {
+        LexiconEntry {
+            grapheme: grapheme.to_string(),
+            alias: Some(alias.to_string()),
+            phoneme: None,
+        }
+    }
+
+    #[test]
+    fn test_apply_lexicon_matches_whole_words() {
+        let lexicon = vec![entry("COPD", "C O P D"), entry("e.g.", "for example"), entry("nginx", "engine x")];
+        assert_eq!(
+            apply_lexicon("Copd patients, e.g. smokers, use Nginx. COPDs", &lexicon),
+            "C O P D patients, for example smokers, use engine x. COPDs"
+        );
+    }
+
+    #[test]
+    fn test_pls_round_trip() {
+        let lexicon = vec![
+            entry("W3C", "World Wide Web Consortium"),
+            LexiconEntry {
+                grapheme: "tomato".to_string(),
+                alias: None,
+                phoneme: Some("təˈmɑːtoʊ".to_string()),
+            },
+            entry("R&D", "research and development"),
+        ];
+        assert_eq!(parse_pls(&to_pls(&lexicon)).unwrap(), lexicon);
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let lexicon = vec![entry("Dr.", "Doctor"), entry("a,b", "\"quoted\" alias")];
+        let csv = to_csv(&lexicon);
+        assert!(csv.starts_with("grapheme,alias,phoneme\n"));
+        assert_eq!(parse_csv(&csv).unwrap(), lexicon);
+        assert!(parse_csv("word,\n").is_err());
+    }
+}
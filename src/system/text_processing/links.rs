@@ -0,0 +1,148 @@
+//! Hyperlink reading policy
+//!
+//! Link targets are only known where the text is still structured (markdown or
+//! HTML); the parsers call [`spoken_link_suffix`] when a link ends so that the
+//! announcement is based on the real target rather than on whatever URL text
+//! happens to survive flattening.
+//!
+//! URLs written out in the text itself are read according to [`UrlReading`],
+//! so a 120-character tracking link is "link to example dot com" rather than
+//! spelled out character by character.
+
+/// How hyperlinks are read aloud.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LinkReading {
+    /// Read only the link text
+    #[default]
+    Text,
+    /// Read the link text followed by "link"
+    WithLink,
+    /// Read the link text followed by the target's domain
+    WithDomain,
+}
+
+/// How URLs written in the text are read aloud.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UrlReading {
+    /// Read the URL as written
+    Full,
+    /// Read the URL without scheme, query string and fragment
+    WithoutQuery,
+    /// Read "link to" and the URL's domain
+    #[default]
+    Domain,
+}
+
+/// Text to append after a link's text, if any, according to `policy`.
+pub fn spoken_link_suffix(policy: LinkReading, destination: &str) -> Option<String> {
+    match policy {
+        LinkReading::Text => None,
+        LinkReading::WithLink => Some(", link".to_string()),
+        // Relative and anchor links have no domain worth reading
+        LinkReading::WithDomain => short_domain(destination).map(|domain| format!(", {domain}")),
+    }
+}
+
+/// Replace the URLs written in `text` ("https://…", "www.…") according to `reading`.
+pub fn read_urls(text: &str, reading: UrlReading) -> String {
+    if reading == UrlReading::Full {
+        return text.to_string();
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = find_url_start(rest) {
+        result.push_str(&rest[..start]);
+        let candidate = &rest[start..];
+        let end = candidate.find(char::is_whitespace).unwrap_or(candidate.len());
+        // Punctuation after the URL belongs to the sentence
+        let url = candidate[..end].trim_end_matches(['.', ',', ';', ':', '!', '?', ')', ']', '}', '"', '\'', '>']);
+        result.push_str(&spoken_url(url, reading).unwrap_or_else(|| url.to_string()));
+        rest = &candidate[url.len()..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Byte offset of the first URL in `text`, at the start of a word.
+fn find_url_start(text: &str) -> Option<usize> {
+    text.match_indices(['h', 'H', 'w', 'W'])
+        .map(|(index, _)| index)
+        .find(|&index| {
+            let at_word_start = text[..index].chars().next_back().is_none_or(|c| !c.is_alphanumeric());
+            let lower = text[index..].chars().take(8).collect::<String>().to_ascii_lowercase();
+            at_word_start && (lower.starts_with("http://") || lower.starts_with("https://") || lower.starts_with("www."))
+        })
+}
+
+/// How `url` is read, if it has a host.
+fn spoken_url(url: &str, reading: UrlReading) -> Option<String> {
+    let absolute = if url.contains("://") { url.to_string() } else { format!("https://{url}") };
+    let domain = short_domain(&absolute)?;
+    match reading {
+        UrlReading::Full => Some(url.to_string()),
+        UrlReading::WithoutQuery => {
+            let rest = absolute.split_once("://")?.1;
+            let rest = rest.split(['?', '#']).next()?.trim_end_matches('/');
+            Some(rest.strip_prefix("www.").unwrap_or(rest).to_string())
+        }
+        UrlReading::Domain => Some(format!("link to {}", domain.replace('.', " dot "))),
+    }
+}
+
+/// Extract a short, speakable domain from a link target.
+///
+/// `https://www.example.com:8080/path` becomes `example.com`;
+/// `mailto:someone@example.com` becomes `example.com`.
+fn short_domain(destination: &str) -> Option<String> {
+    let destination = destination.trim();
+    let rest = if let Some(address) = destination.strip_prefix("mailto:") {
+        address.rsplit_once('@')?.1
+    } else {
+        destination.split_once("://")?.1
+    };
+
+    let authority = rest.split(['/', '?', '#']).next()?;
+    // Drop credentials and port
+    let host = authority.rsplit('@').next()?;
+    let host = host.split(':').next()?;
+    let host = host.strip_prefix("www.").unwrap_or(host);
+
+    if host.is_empty() {
+        None
+    } else {
+        Some(host.to_ascii_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_domain() {
+        assert_eq!(short_domain("https://www.Example.com:8080/a?b#c").as_deref(), Some("example.com"));
+        assert_eq!(short_domain("mailto:someone@example.org").as_deref(), Some("example.org"));
+        assert_eq!(short_domain("/relative/path"), None);
+        assert_eq!(short_domain("#anchor"), None);
+    }
+
+    #[test]
+    fn test_read_urls() {
+        let text = "See https://www.example.com/a/b?utm_source=x&id=1#top, or www.docs.rs/iced.\nAnywhere.";
+        assert_eq!(read_urls(text, UrlReading::Full), text);
+        assert_eq!(read_urls(text, UrlReading::WithoutQuery), "See example.com/a/b, or docs.rs/iced.\nAnywhere.");
+        assert_eq!(
+            read_urls(text, UrlReading::Domain),
+            "See link to example dot com, or link to docs dot rs.\nAnywhere."
+        );
+        assert_eq!(read_urls("The http:// prefix and wwww.", UrlReading::Domain), "The http:// prefix and wwww.");
+    }
+
+    #[test]
+    fn test_spoken_link_suffix() {
+        let url = "https://docs.rs/iced";
+        assert_eq!(spoken_link_suffix(LinkReading::Text, url), None);
+        assert_eq!(spoken_link_suffix(LinkReading::WithLink, url).as_deref(), Some(", link"));
+        assert_eq!(spoken_link_suffix(LinkReading::WithDomain, url).as_deref(), Some(", docs.rs"));
+    }
+}
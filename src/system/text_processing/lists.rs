@@ -0,0 +1,137 @@
+//! List item enumeration
+//!
+//! Flattened lists are hard to follow by ear: items run into each other and the
+//! listener can't tell where one ends and the next begins. This pass detects
+//! bulleted/numbered list lines, announces each item ("First, …" or "Bullet: …")
+//! and terminates it with punctuation so the voice pauses between items.
+
+/// How list items are announced when read aloud.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ListEnumeration {
+    /// Leave list items untouched
+    #[default]
+    Off,
+    /// Prefix items with ordinals ("First, …", "Second, …")
+    Ordinal,
+    /// Prefix items with "Bullet:"
+    Bullet,
+}
+
+const ORDINALS: [&str; 20] = [
+    "First", "Second", "Third", "Fourth", "Fifth", "Sixth", "Seventh", "Eighth", "Ninth",
+    "Tenth", "Eleventh", "Twelfth", "Thirteenth", "Fourteenth", "Fifteenth", "Sixteenth",
+    "Seventeenth", "Eighteenth", "Nineteenth", "Twentieth",
+];
+
+const BULLET_MARKERS: [char; 8] = ['-', '*', '+', '•', '◦', '▪', '‣', '–'];
+
+/// Return the item content if `line` is a bulleted or numbered list item.
+fn list_item_content(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    let mut chars = trimmed.char_indices();
+    let (_, first) = chars.next()?;
+
+    // Bulleted: "- item", "• item"
+    if BULLET_MARKERS.contains(&first) {
+        let rest = &trimmed[first.len_utf8()..];
+        return rest
+            .strip_prefix(char::is_whitespace)
+            .map(str::trim)
+            .filter(|s| !s.is_empty());
+    }
+
+    // Numbered: "1. item", "2) item", "a. item"
+    let marker_end = if first.is_ascii_digit() {
+        trimmed
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_digit())
+            .map(|(i, _)| i)?
+    } else if first.is_ascii_lowercase() {
+        first.len_utf8()
+    } else {
+        return None;
+    };
+
+    let rest = &trimmed[marker_end..];
+    let rest = rest.strip_prefix('.').or_else(|| rest.strip_prefix(')'))?;
+    rest.strip_prefix(char::is_whitespace)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+/// Spoken prefix for the item at `index` (0-based) within its list.
+fn item_prefix(style: ListEnumeration, index: usize) -> String {
+    match style {
+        ListEnumeration::Ordinal => match ORDINALS.get(index) {
+            Some(ordinal) => format!("{ordinal}, "),
+            None => format!("Number {}, ", index + 1),
+        },
+        ListEnumeration::Bullet => "Bullet: ".to_string(),
+        ListEnumeration::Off => String::new(),
+    }
+}
+
+/// Announce list items and make sure each one ends with a pause.
+pub(super) fn enumerate_list_items(text: &str, style: ListEnumeration) -> String {
+    let mut output = Vec::new();
+    let mut index = 0;
+
+    for line in text.lines() {
+        if let Some(content) = list_item_content(line) {
+            let terminator = if content.ends_with(['.', '!', '?', ':', ';']) { "" } else { "." };
+            output.push(format!("{}{}{}", item_prefix(style, index), content, terminator));
+            index += 1;
+        } else {
+            // Blank lines separate items of loose lists; any other text ends the list
+            if !line.trim().is_empty() {
+                index = 0;
+            }
+            output.push(line.to_string());
+        }
+    }
+
+    output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ordinal_bullets() {
+        let text = "Shopping:\n- milk\n- eggs\n- bread";
+        assert_eq!(
+            enumerate_list_items(text, ListEnumeration::Ordinal),
+            "Shopping:\nFirst, milk.\nSecond, eggs.\nThird, bread."
+        );
+    }
+
+    #[test]
+    fn test_bullet_numbered_list() {
+        let text = "1. Open the lid\n2) Pour water!";
+        assert_eq!(
+            enumerate_list_items(text, ListEnumeration::Bullet),
+            "Bullet: Open the lid.\nBullet: Pour water!"
+        );
+    }
+
+    #[test]
+    fn test_counter_resets_between_lists() {
+        let text = "- a\n\n- b\nParagraph\n- c";
+        assert_eq!(
+            enumerate_list_items(text, ListEnumeration::Ordinal),
+            "First, a.\n\nSecond, b.\nParagraph\nFirst, c."
+        );
+    }
+
+    #[test]
+    fn test_non_list_lines_untouched() {
+        let text = "-5 degrees outside\n2024 was a year\nabc";
+        assert_eq!(enumerate_list_items(text, ListEnumeration::Ordinal), text);
+    }
+
+    #[test]
+    fn test_ordinals_past_twenty() {
+        assert_eq!(item_prefix(ListEnumeration::Ordinal, 20), "Number 21, ");
+    }
+}
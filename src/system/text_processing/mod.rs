@@ -0,0 +1,121 @@
+//! Local text processing applied before synthesis
+//!
+//! Unlike Natural Reading (which sends text to a cloud service), these passes
+//! run locally and only reshape the text so it is easier to follow by ear.
+
+mod abbreviations;
+mod bidi;
+mod chapters;
+mod citations;
+mod filters;
+mod html;
+mod images;
+mod language;
+mod lexicon;
+mod links;
+mod lists;
+mod numbers;
+mod sanitize;
+mod tables;
+
+pub use abbreviations::ABBREVIATION_RULES;
+pub use bidi::{direction_runs, rtl_language, RtlLanguage};
+pub use chapters::detect_chapters;
+pub use filters::{apply_filter_rules, compile_filter, FilterRule};
+pub use html::html_to_text;
+pub use images::spoken_image_alt;
+pub use language::{detect_language, detect_text_language, language_name, voice_language};
+pub use lexicon::{export_lexicon, import_lexicon, merge_lexicon, LexiconEntry};
+pub use links::{spoken_link_suffix, LinkReading, UrlReading};
+pub use lists::ListEnumeration;
+pub use sanitize::sanitize_text;
+pub use tables::TableReading;
+
+use tracing::debug;
+
+/// User-configurable options for the local text processing passes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextProcessingOptions {
+    /// Whether text that is HTML markup is read as the page it describes
+    pub strip_html: bool,
+    /// User-defined regex search/replace rules, applied in order right after sanitation
+    pub filter_rules: Vec<FilterRule>,
+    /// How bulleted/numbered list items are announced
+    pub list_enumeration: ListEnumeration,
+    /// How hyperlinks are read (applied where link targets are known)
+    pub link_reading: LinkReading,
+    /// How URLs written in the text are read
+    pub url_reading: UrlReading,
+    /// Whether citation markers ("[3]", "(Smith et al., 2020)"), footnotes and reference sections are skipped
+    pub skip_citations: bool,
+    /// How markdown and HTML tables are read
+    pub table_reading: TableReading,
+    /// Whether image alt text is read as "Image: <alt>" (applied where images are known)
+    pub read_image_alt_text: bool,
+    /// Pronunciation lexicon applied before the other passes (so it overrides abbreviations)
+    pub lexicon: Vec<LexiconEntry>,
+    /// Written forms of the abbreviation rules turned off (see `ABBREVIATION_RULES`)
+    pub disabled_abbreviations: Vec<String>,
+    /// Whether unknown all-caps acronyms are spelled out letter by letter
+    pub spell_acronyms: bool,
+    /// Whether numbers and numeric dates are rewritten the way the voice's locale reads them
+    pub localize_numbers: bool,
+    /// Whether cells selected in a spreadsheet are read row by row (applied when the selection is captured)
+    pub spreadsheet_cells: bool,
+}
+
+impl Default for TextProcessingOptions {
+    fn default() -> Self {
+        Self {
+            strip_html: true,
+            filter_rules: Vec::new(),
+            list_enumeration: ListEnumeration::default(),
+            link_reading: LinkReading::default(),
+            url_reading: UrlReading::default(),
+            skip_citations: true,
+            table_reading: TableReading::default(),
+            read_image_alt_text: true,
+            lexicon: Vec::new(),
+            disabled_abbreviations: Vec::new(),
+            spell_acronyms: false,
+            localize_numbers: true,
+            spreadsheet_cells: true,
+        }
+    }
+}
+
+/// Run all enabled text processing passes over `text`, to be read by a voice for `voice_locale` ("de_DE").
+pub fn apply_text_processing(text: &str, options: &TextProcessingOptions, voice_locale: Option<&str>) -> String {
+    let mut result = if options.strip_html && html::looks_like_html(text) {
+        debug!("Text is HTML, reading its text only");
+        sanitize::sanitize_text(&html::copied_html_to_text(text, options))
+    } else {
+        sanitize::sanitize_text(text)
+    };
+    result = filters::apply_filter_rules(&result, &options.filter_rules);
+    if options.skip_citations {
+        result = citations::skip_citations(&result);
+    }
+    result = tables::read_tables(&result, options.table_reading);
+    // Before the other passes, which would otherwise rewrite parts of the URLs
+    result = links::read_urls(&result, options.url_reading);
+    result = lexicon::apply_lexicon(&result, &options.lexicon);
+    result = abbreviations::expand_abbreviations(&result, &options.disabled_abbreviations);
+    if options.spell_acronyms {
+        result = abbreviations::spell_acronyms(&result);
+    }
+    if let Some(locale) = voice_locale.filter(|_| options.localize_numbers) {
+        result = numbers::localize_numbers(&result, locale);
+    }
+
+    if options.list_enumeration != ListEnumeration::Off {
+        result = lists::enumerate_list_items(&result, options.list_enumeration);
+    }
+
+    debug!(
+        original_bytes = text.len(),
+        processed_bytes = result.len(),
+        "Text processing applied"
+    );
+    result
+}
@@ -0,0 +1,278 @@
+//! Numbers and dates in the voice's language
+//!
+//! Voices read digits in their own language, but the separators around them
+//! are ambiguous: "1.234" is over a thousand in German and just over one in
+//! English, and "03/04/2025" is in March or in April depending on who wrote
+//! it. Numbers are read with the conventions of the voice's locale and
+//! rewritten in a form its engine can't misread: thousands separators
+//! dropped, the decimal separator said as a word and the decimals digit by
+//! digit ("1.234,56" → "1234 Komma 5 6" for a German voice), and numeric
+//! dates with the month named. Locales without rules are left to the engine.
+
+/// Order of day and month in numeric dates (years come last, or first in ISO dates).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateOrder {
+    DayMonth,
+    MonthDay,
+}
+
+/// How a locale writes numbers and dates, and how its voices say them.
+#[derive(Debug, Clone, Copy)]
+struct NumberRules {
+    /// Locale ("en_US") or language family ("de") the rules are for
+    locale: &'static str,
+    decimal_separator: char,
+    /// Characters separating groups of thousands
+    group_separators: &'static [char],
+    /// Spoken decimal separator
+    decimal_word: &'static str,
+    date_order: DateOrder,
+    /// Spoken date, with `{day}`, `{month}` and `{year}` placeholders
+    date_format: &'static str,
+    months: [&'static str; 12],
+}
+
+const ENGLISH_MONTHS: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November",
+    "December",
+];
+
+/// Rules by locale; a language family's entry covers its locales without one of their own.
+const NUMBER_RULES: &[NumberRules] = &[
+    NumberRules {
+        locale: "en_US",
+        decimal_separator: '.',
+        group_separators: &[','],
+        decimal_word: "point",
+        date_order: DateOrder::MonthDay,
+        date_format: "{month} {day}, {year}",
+        months: ENGLISH_MONTHS,
+    },
+    NumberRules {
+        locale: "en",
+        decimal_separator: '.',
+        group_separators: &[','],
+        decimal_word: "point",
+        date_order: DateOrder::DayMonth,
+        date_format: "{day} {month} {year}",
+        months: ENGLISH_MONTHS,
+    },
+    NumberRules {
+        locale: "de",
+        decimal_separator: ',',
+        group_separators: &['.'],
+        decimal_word: "Komma",
+        date_order: DateOrder::DayMonth,
+        date_format: "{day}. {month} {year}",
+        months: [
+            "Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November",
+            "Dezember",
+        ],
+    },
+    NumberRules {
+        locale: "fr",
+        decimal_separator: ',',
+        group_separators: &[' ', '\u{a0}', '\u{202f}'],
+        decimal_word: "virgule",
+        date_order: DateOrder::DayMonth,
+        date_format: "{day} {month} {year}",
+        months: [
+            "janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre",
+            "décembre",
+        ],
+    },
+    NumberRules {
+        locale: "es",
+        decimal_separator: ',',
+        group_separators: &['.'],
+        decimal_word: "coma",
+        date_order: DateOrder::DayMonth,
+        date_format: "{day} de {month} de {year}",
+        months: [
+            "enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre",
+            "noviembre", "diciembre",
+        ],
+    },
+    NumberRules {
+        locale: "it",
+        decimal_separator: ',',
+        group_separators: &['.'],
+        decimal_word: "virgola",
+        date_order: DateOrder::DayMonth,
+        date_format: "{day} {month} {year}",
+        months: [
+            "gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre",
+            "novembre", "dicembre",
+        ],
+    },
+    NumberRules {
+        locale: "pt",
+        decimal_separator: ',',
+        group_separators: &['.'],
+        decimal_word: "vírgula",
+        date_order: DateOrder::DayMonth,
+        date_format: "{day} de {month} de {year}",
+        months: [
+            "janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro",
+            "novembro", "dezembro",
+        ],
+    },
+    NumberRules {
+        locale: "nl",
+        decimal_separator: ',',
+        group_separators: &['.'],
+        decimal_word: "komma",
+        date_order: DateOrder::DayMonth,
+        date_format: "{day} {month} {year}",
+        months: [
+            "januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober",
+            "november", "december",
+        ],
+    },
+];
+
+/// Rules for `locale` ("de_DE", "en-US", "fr"), falling back to its language family.
+fn number_rules(locale: &str) -> Option<&'static NumberRules> {
+    let locale = locale.replace('-', "_");
+    let family = locale.split('_').next().unwrap_or_default();
+    NUMBER_RULES
+        .iter()
+        .find(|rules| rules.locale.eq_ignore_ascii_case(&locale))
+        .or_else(|| NUMBER_RULES.iter().find(|rules| rules.locale.eq_ignore_ascii_case(family)))
+}
+
+/// Rewrite the numbers and numeric dates of `text` the way a voice for `locale` reads them.
+pub fn localize_numbers(text: &str, locale: &str) -> String {
+    let Some(rules) = number_rules(locale) else {
+        return text.to_string();
+    };
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if !chars[i].is_alphanumeric() {
+            result.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        if !chars[i].is_ascii_digit() {
+            // Words with digits ("v1.2.3", "mp3") are left alone, dots and all
+            while i < chars.len()
+                && (chars[i].is_alphanumeric()
+                    || matches!(chars[i], '.' | ',' | '/' | '-')
+                        && chars.get(i + 1).is_some_and(char::is_ascii_digit))
+            {
+                result.push(chars[i]);
+                i += 1;
+            }
+            continue;
+        }
+        // Digits, and separators between digits
+        let mut end = i;
+        while end < chars.len()
+            && (chars[end].is_ascii_digit()
+                || chars.get(end + 1).is_some_and(char::is_ascii_digit) && is_separator(chars[end], rules))
+        {
+            end += 1;
+        }
+        let token: String = chars[i..end].iter().collect();
+        let glued = chars.get(end).is_some_and(|c| c.is_alphanumeric());
+        match spoken_date(&token, rules).or_else(|| spoken_number(&token, rules)) {
+            Some(spoken) if !glued => result.push_str(&spoken),
+            _ => result.push_str(&token),
+        }
+        i = end;
+    }
+    result
+}
+
+fn is_separator(c: char, rules: &NumberRules) -> bool {
+    matches!(c, '.' | ',' | '/' | '-') || rules.group_separators.contains(&c)
+}
+
+/// "1.234,56" (in German) → "1234 Komma 5 6"; None for plain integers and anything not a number.
+fn spoken_number(token: &str, rules: &NumberRules) -> Option<String> {
+    let (integer, decimals) = match token.split_once(rules.decimal_separator) {
+        Some((integer, decimals)) => (integer, Some(decimals)),
+        None => (token, None),
+    };
+    let groups: Vec<&str> = integer.split(rules.group_separators).collect();
+    let grouped = groups.len() > 1;
+    let valid_groups = groups.iter().all(|group| group.chars().all(|c| c.is_ascii_digit()))
+        && (1..=3).contains(&groups[0].len())
+        && groups[1..].iter().all(|group| group.len() == 3);
+    if grouped && !valid_groups || !grouped && !integer.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let digits = groups.concat();
+    match decimals {
+        Some(decimals) if !decimals.is_empty() && decimals.chars().all(|c| c.is_ascii_digit()) => {
+            let decimals: Vec<String> = decimals.chars().map(String::from).collect();
+            Some(format!("{digits} {} {}", rules.decimal_word, decimals.join(" ")))
+        }
+        Some(_) => None,
+        None => grouped.then_some(digits),
+    }
+}
+
+/// "03/04/2025" → "March 4, 2025" (in American English); ISO dates ("2025-03-04") in any locale.
+///
+/// Two-digit years are only taken in slashed dates, where they are common
+/// ("3/4/25"); with dots or dashes they are more likely version numbers.
+fn spoken_date(token: &str, rules: &NumberRules) -> Option<String> {
+    let separator = token.chars().find(|c| matches!(c, '/' | '.' | '-'))?;
+    let parts: Vec<&str> = token.split(separator).collect();
+    let [first, second, third] = parts.as_slice() else {
+        return None;
+    };
+    if !parts.iter().all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+    let (year, month, day) = if first.len() == 4 && separator == '-' {
+        (*first, *second, *third)
+    } else {
+        let (day, month) = match rules.date_order {
+            DateOrder::DayMonth => (*first, *second),
+            DateOrder::MonthDay => (*second, *first),
+        };
+        (*third, month, day)
+    };
+    let year = match year.len() {
+        4 => year.to_string(),
+        2 if separator == '/' => format!("{}{year}", if year < "70" { "20" } else { "19" }),
+        _ => return None,
+    };
+    let (day, month): (usize, usize) = (day.parse().ok()?, month.parse().ok()?);
+    if !(1..=31).contains(&day) || !(1..=12).contains(&month) {
+        return None;
+    }
+    Some(
+        rules
+            .date_format
+            .replace("{day}", &day.to_string())
+            .replace("{month}", rules.months[month - 1])
+            .replace("{year}", &year),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_localize_numbers() {
+        let text = "It cost 1.234,56 € on 03/04/2025, up 2,5 % since 2024-12-31 (v1.2.3, 1.2.10).";
+        assert_eq!(
+            localize_numbers(text, "de_DE"),
+            "It cost 1234 Komma 5 6 € on 3. April 2025, up 2 Komma 5 % since 31. Dezember 2024 (v1.2.3, 1.2.10)."
+        );
+        assert_eq!(
+            localize_numbers("It cost 1,234.56 on 03/04/25, or 3.5 kg.", "en_US"),
+            "It cost 1234 point 5 6 on March 4, 2025, or 3 point 5 kg."
+        );
+        assert_eq!(localize_numbers("On 03/04/25, 1,2, 3", "en-GB"), "On 3 April 2025, 1,2, 3");
+        assert_eq!(localize_numbers("Il a 3 000 euros, 10 ans", "fr_FR"), "Il a 3000 euros, 10 ans");
+        assert_eq!(localize_numbers("1.234,56 and 4G", "ja_JP"), "1.234,56 and 4G");
+    }
+}
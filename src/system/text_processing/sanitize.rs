@@ -0,0 +1,66 @@
+//! Text sanitation
+//!
+//! Text captured from web pages, PDFs and OCR often carries invisible
+//! characters (byte order marks, zero-width spaces, soft hyphens, bidi
+//! controls) that engines read as pauses, spell out or choke on. This pass
+//! removes them and normalizes the rest to NFC before any other processing.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Whether `c` is an invisible formatting character to drop.
+///
+/// Zero-width joiner / non-joiner are kept: they shape Persian and Indic
+/// scripts and join emoji sequences.
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}' // soft hyphen
+            | '\u{061C}' // Arabic letter mark
+            | '\u{180E}' // Mongolian vowel separator
+            | '\u{200B}' // zero-width space
+            | '\u{200E}' | '\u{200F}' // left-to-right / right-to-left marks
+            | '\u{202A}'..='\u{202E}' // bidi embeddings and overrides
+            | '\u{2060}'..='\u{2064}' // word joiner and invisible operators
+            | '\u{2066}'..='\u{2069}' // bidi isolates
+            | '\u{FEFF}' // byte order mark / zero-width no-break space
+            | '\u{FFFD}' // replacement character left by bad decoding
+    )
+}
+
+/// Strip invisible and control characters from `text` and normalize it to NFC.
+///
+/// Line breaks and tabs are kept (CRLF and Unicode line separators become
+/// `\n`) so the later passes still see the original layout.
+pub fn sanitize_text(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                result.push('\n');
+            }
+            '\u{2028}' | '\u{2029}' | '\u{0085}' => result.push('\n'),
+            '\n' | '\t' => result.push(c),
+            '\u{00A0}' | '\u{202F}' => result.push(' '),
+            _ if c.is_control() || is_invisible(c) => {}
+            _ => result.push(c),
+        }
+    }
+    result.nfc().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_text() {
+        assert_eq!(sanitize_text("\u{FEFF}Hello\u{200B} wor\u{00AD}ld"), "Hello world");
+        assert_eq!(sanitize_text("\u{202B}שלום\u{202C} and\u{00A0}more\u{0007}"), "שלום and more");
+        assert_eq!(sanitize_text("one\r\ntwo\rthree\u{2029}four\tfive"), "one\ntwo\nthree\nfour\tfive");
+        // Decomposed "é" is composed; ZWJ emoji sequences survive
+        assert_eq!(sanitize_text("cafe\u{0301}"), "caf\u{00E9}");
+        assert_eq!(sanitize_text("👩\u{200D}💻"), "👩\u{200D}💻");
+    }
+}
@@ -0,0 +1,132 @@
+//! Table narration
+//!
+//! Markdown tables (and HTML tables, which the HTML pass turns into them)
+//! read as a soup of values and pipes. Each row is read as a sentence
+//! instead, naming the column of every value: "Row 1: column Name is Alice,
+//! column Age is 30." Tables can also be skipped entirely.
+
+/// How tables are read aloud.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableReading {
+    /// Read row by row, naming each value's column
+    #[default]
+    Rows,
+    /// Leave tables out of the reading
+    Skip,
+    /// Read the table's text as written
+    AsWritten,
+}
+
+/// Whether `line` can be a table row (cells separated by pipes).
+fn is_row(line: &str) -> bool {
+    line.contains('|') && !line.trim().is_empty()
+}
+
+/// Whether `line` separates a table's header from its rows ("|---|:---:|").
+fn is_separator(line: &str) -> bool {
+    let line = line.trim();
+    line.contains('|') && line.contains('-') && line.chars().all(|c| matches!(c, '|' | '-' | ':' | ' ' | '\t'))
+}
+
+/// Cells of a table row, outer pipes dropped (`\|` is a pipe inside a cell).
+fn cells(line: &str) -> Vec<String> {
+    let line = line.trim().replace("\\|", "\u{0}");
+    let line = line.strip_prefix('|').unwrap_or(&line);
+    let line = line.strip_suffix('|').unwrap_or(line);
+    line.split('|').map(|cell| cell.trim().replace('\u{0}', "|")).collect()
+}
+
+/// Replace the tables in `text` according to `reading`.
+///
+/// A table is a row of headers above a separator line, then its rows; a
+/// separator with rows but no headers above it starts a table without headers.
+pub fn read_tables(text: &str, reading: TableReading) -> String {
+    if reading == TableReading::AsWritten || !text.contains('|') {
+        return text.to_string();
+    }
+    let lines: Vec<&str> = text.lines().collect();
+    let mut result: Vec<String> = Vec::with_capacity(lines.len());
+    let mut i = 0;
+    while i < lines.len() {
+        let (headers, start) = if is_separator(lines[i]) && lines.get(i + 1).is_some_and(|line| is_row(line)) {
+            (None, i + 1)
+        } else if is_row(lines[i]) && lines.get(i + 1).is_some_and(|line| is_separator(line)) {
+            (Some(cells(lines[i])), i + 2)
+        } else {
+            result.push(lines[i].to_string());
+            i += 1;
+            continue;
+        };
+        let mut end = start;
+        while end < lines.len() && is_row(lines[end]) && !is_separator(lines[end]) {
+            end += 1;
+        }
+        if reading == TableReading::Rows {
+            let rows: Vec<Vec<String>> = lines[start..end].iter().map(|line| cells(line)).collect();
+            result.extend(narrate_table(headers.as_deref(), &rows));
+        }
+        i = end;
+    }
+    let mut result = result.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// One sentence per row; single-column tables are read as plain lines.
+fn narrate_table(headers: Option<&[String]>, rows: &[Vec<String>]) -> Vec<String> {
+    let columns = rows.iter().map(Vec::len).chain(headers.map(<[String]>::len)).max().unwrap_or(0);
+    if columns < 2 || rows.is_empty() {
+        return headers
+            .into_iter()
+            .flatten()
+            .chain(rows.iter().flatten())
+            .filter(|cell| !cell.is_empty())
+            .cloned()
+            .collect();
+    }
+    rows.iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let values: Vec<String> = match headers {
+                Some(headers) => row
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, value)| !value.is_empty())
+                    .map(|(column, value)| match headers.get(column).filter(|header| !header.is_empty()) {
+                        Some(header) => format!("column {header} is {value}"),
+                        None => format!("column {} is {value}", column + 1),
+                    })
+                    .collect(),
+                None => row
+                    .iter()
+                    .map(|value| if value.is_empty() { "blank" } else { value }.to_string())
+                    .collect(),
+            };
+            let values = if values.is_empty() { "blank".to_string() } else { values.join(", ") };
+            format!("Row {}: {values}.", index + 1)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_tables() {
+        let text = "Staff:\n\n| Name | Age | Notes |\n|:-----|----:|---|\n| Alice | 30 | a \\| b |\n| Bob | | |\n\nDone.";
+        assert_eq!(
+            read_tables(text, TableReading::Rows),
+            "Staff:\n\nRow 1: column Name is Alice, column Age is 30, column Notes is a | b.\nRow 2: column Name is Bob.\n\nDone."
+        );
+        assert_eq!(read_tables(text, TableReading::Skip), "Staff:\n\n\nDone.");
+        assert_eq!(read_tables(text, TableReading::AsWritten), text);
+
+        // Without headers (HTML tables without <th>), and single columns
+        assert_eq!(read_tables("|---|\n| 1 | 2 |\n| 3 | |", TableReading::Rows), "Row 1: 1, 2.\nRow 2: 3, blank.");
+        assert_eq!(read_tables("| Fruit |\n|---|\n| Apple |", TableReading::Rows), "Fruit\nApple");
+        assert_eq!(read_tables("a | b, no separator", TableReading::Rows), "a | b, no separator");
+    }
+}
@@ -0,0 +1,190 @@
+//! Text transforms
+//!
+//! Before synthesis, text can go through a chain of transforms: cleanup by
+//! the Natural Reading service, or cleanup and translation by a language
+//! model behind an OpenAI-compatible endpoint or an Ollama server. Each
+//! backend implements [`TextTransformProvider`]; the pipeline only runs the
+//! chain configured in settings, each step getting the previous one's output,
+//! so adding a backend doesn't touch it.
+
+mod ollama;
+mod openai;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use tracing::info;
+
+use super::text_cleanup::cleanup_text;
+
+pub use ollama::{OllamaTransform, DEFAULT_OLLAMA_MODEL, DEFAULT_OLLAMA_URL};
+pub use openai::{OpenAiTransform, DEFAULT_OPENAI_MODEL, DEFAULT_OPENAI_URL};
+
+/// Instruction sent to language models along with the text, until the user writes their own.
+pub const DEFAULT_TRANSFORM_PROMPT: &str = "Rewrite the following text so it reads well aloud: drop navigation, \
+    footnote markers and other page clutter, write out symbols, and otherwise keep the wording. \
+    Answer with the rewritten text only.";
+
+/// Longest a language model may take to answer; local models on a CPU are slow on long texts.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// Client for the language model backends, giving up after [`REQUEST_TIMEOUT`].
+fn http_client() -> reqwest::Client {
+    reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build().unwrap_or_default()
+}
+
+/// Result of a transform, still to be awaited.
+pub type TransformFuture<'a> = Pin<Box<dyn Future<Output = Result<String, String>> + Send + 'a>>;
+
+/// A backend that rewrites text before it is read (cleanup, translation).
+pub trait TextTransformProvider: Send + Sync {
+    /// Name shown in logs, e.g. "Ollama (llama3.2)".
+    fn name(&self) -> String;
+
+    /// Rewrite `text`; errors are shown to the user as they are.
+    fn transform<'a>(&'a self, text: &'a str) -> TransformFuture<'a>;
+}
+
+/// The Natural Reading cloud service.
+pub struct NaturalReadingTransform;
+
+impl TextTransformProvider for NaturalReadingTransform {
+    fn name(&self) -> String {
+        "Natural Reading".to_string()
+    }
+
+    fn transform<'a>(&'a self, text: &'a str) -> TransformFuture<'a> {
+        Box::pin(cleanup_text(text))
+    }
+}
+
+/// Keeps the text as the local text processing passes left it.
+///
+/// Those passes run before any transform, so this step changes nothing: it
+/// stands for "local rules only" in a chain, and for testing chains without
+/// any service.
+pub struct LocalRulesTransform;
+
+impl TextTransformProvider for LocalRulesTransform {
+    fn name(&self) -> String {
+        "Local rules".to_string()
+    }
+
+    fn transform<'a>(&'a self, text: &'a str) -> TransformFuture<'a> {
+        Box::pin(async move { Ok(text.to_string()) })
+    }
+}
+
+/// Kind of a transform step, as picked in settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformKind {
+    NaturalReading,
+    OpenAi,
+    Ollama,
+    LocalRules,
+}
+
+/// One step of the configured transform chain.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TransformStep {
+    NaturalReading,
+    /// A chat completions endpoint (OpenAI, LM Studio, llama.cpp, vLLM…)
+    OpenAi {
+        /// Base URL, e.g. "https://api.openai.com/v1"
+        url: String,
+        /// Bearer token (empty for local servers)
+        #[serde(default)]
+        api_key: String,
+        model: String,
+        prompt: String,
+    },
+    Ollama {
+        /// Server URL, e.g. "http://localhost:11434"
+        url: String,
+        model: String,
+        prompt: String,
+    },
+    LocalRules,
+}
+
+impl TransformStep {
+    /// A step of `kind` with the default endpoint, model and prompt.
+    pub fn new(kind: TransformKind) -> Self {
+        match kind {
+            TransformKind::NaturalReading => TransformStep::NaturalReading,
+            TransformKind::OpenAi => TransformStep::OpenAi {
+                url: DEFAULT_OPENAI_URL.to_string(),
+                api_key: String::new(),
+                model: DEFAULT_OPENAI_MODEL.to_string(),
+                prompt: DEFAULT_TRANSFORM_PROMPT.to_string(),
+            },
+            TransformKind::Ollama => TransformStep::Ollama {
+                url: DEFAULT_OLLAMA_URL.to_string(),
+                model: DEFAULT_OLLAMA_MODEL.to_string(),
+                prompt: DEFAULT_TRANSFORM_PROMPT.to_string(),
+            },
+            TransformKind::LocalRules => TransformStep::LocalRules,
+        }
+    }
+
+    pub fn kind(&self) -> TransformKind {
+        match self {
+            TransformStep::NaturalReading => TransformKind::NaturalReading,
+            TransformStep::OpenAi { .. } => TransformKind::OpenAi,
+            TransformStep::Ollama { .. } => TransformKind::Ollama,
+            TransformStep::LocalRules => TransformKind::LocalRules,
+        }
+    }
+
+    /// The backend running this step.
+    pub fn provider(&self) -> Box<dyn TextTransformProvider> {
+        match self {
+            TransformStep::NaturalReading => Box::new(NaturalReadingTransform),
+            TransformStep::OpenAi { url, api_key, model, prompt } => {
+                Box::new(OpenAiTransform::new(url, api_key, model, prompt))
+            }
+            TransformStep::Ollama { url, model, prompt } => Box::new(OllamaTransform::new(url, model, prompt)),
+            TransformStep::LocalRules => Box::new(LocalRulesTransform),
+        }
+    }
+
+    /// Short description for the settings list, e.g. "Ollama · llama3.2".
+    pub fn label(&self) -> String {
+        match self {
+            TransformStep::NaturalReading => "Natural Reading".to_string(),
+            TransformStep::OpenAi { url, model, .. } => format!("OpenAI-compatible · {model} · {url}"),
+            TransformStep::Ollama { url, model, .. } => format!("Ollama · {model} · {url}"),
+            TransformStep::LocalRules => "Local rules only".to_string(),
+        }
+    }
+}
+
+/// Run `text` through `providers` in order, each one getting the previous one's output.
+pub async fn run_transforms(providers: Vec<Box<dyn TextTransformProvider>>, text: String) -> Result<String, String> {
+    let mut text = text;
+    for provider in &providers {
+        info!(transform = %provider.name(), bytes = text.len(), "Running text transform");
+        text = provider.transform(&text).await?;
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_steps() {
+        let steps = vec![TransformStep::new(TransformKind::Ollama), TransformStep::LocalRules];
+        let json = serde_json::to_string(&steps).unwrap();
+        assert!(json.contains("\"kind\":\"ollama\""));
+        assert_eq!(serde_json::from_str::<Vec<TransformStep>>(&json).unwrap(), steps);
+        assert_eq!(steps[0].kind(), TransformKind::Ollama);
+
+        let providers = vec![TransformStep::LocalRules.provider(), TransformStep::LocalRules.provider()];
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(runtime.block_on(run_transforms(providers, "Text".to_string())).unwrap(), "Text");
+    }
+}
@@ -0,0 +1,78 @@
+//! Ollama servers
+//!
+//! Local models served by Ollama: the prompt goes as the system prompt and
+//! the text as the prompt, without streaming.
+
+use serde_json::{json, Value};
+use tracing::warn;
+
+use super::{http_client, TextTransformProvider, TransformFuture};
+
+/// Where Ollama listens by default.
+pub const DEFAULT_OLLAMA_URL: &str = "http://localhost:11434";
+
+/// Model used until the user picks one.
+pub const DEFAULT_OLLAMA_MODEL: &str = "llama3.2";
+
+/// Rewrites text with a model served by Ollama.
+pub struct OllamaTransform {
+    client: reqwest::Client,
+    url: String,
+    model: String,
+    prompt: String,
+}
+
+impl OllamaTransform {
+    pub fn new(url: &str, model: &str, prompt: &str) -> Self {
+        Self {
+            client: http_client(),
+            url: url.trim().trim_end_matches('/').to_string(),
+            model: model.trim().to_string(),
+            prompt: prompt.to_string(),
+        }
+    }
+
+    async fn generate(&self, text: &str) -> Result<String, String> {
+        let body = json!({
+            "model": self.model,
+            "system": self.prompt,
+            "prompt": text,
+            "stream": false,
+        });
+        let response = self
+            .client
+            .post(format!("{}/api/generate", self.url))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                warn!(error = %e, url = %self.url, "Failed to connect to Ollama");
+                format!("Failed to connect to Ollama at {}: {e}", self.url)
+            })?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            warn!(?status, body = %body, "Ollama returned error");
+            return Err(format!("Ollama error ({status}): {body}"));
+        }
+        let answer: Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Ollama response: {e}"))?;
+        answer["response"]
+            .as_str()
+            .map(|content| content.trim().to_string())
+            .filter(|content| !content.is_empty())
+            .ok_or_else(|| "Ollama answered without any text".to_string())
+    }
+}
+
+impl TextTransformProvider for OllamaTransform {
+    fn name(&self) -> String {
+        format!("Ollama ({})", self.model)
+    }
+
+    fn transform<'a>(&'a self, text: &'a str) -> TransformFuture<'a> {
+        Box::pin(self.generate(text))
+    }
+}
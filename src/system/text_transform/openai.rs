@@ -0,0 +1,97 @@
+//! OpenAI-compatible chat endpoints
+//!
+//! OpenAI itself, and the local servers speaking its API (LM Studio,
+//! llama.cpp, vLLM): the prompt goes as the system message and the text as
+//! the user message; the answer is the rewritten text.
+
+use serde_json::{json, Value};
+use tracing::warn;
+
+use super::{http_client, TextTransformProvider, TransformFuture};
+
+/// Base URL of the OpenAI API.
+pub const DEFAULT_OPENAI_URL: &str = "https://api.openai.com/v1";
+
+/// Model used until the user picks one.
+pub const DEFAULT_OPENAI_MODEL: &str = "gpt-4o-mini";
+
+/// Rewrites text with a chat completions endpoint.
+pub struct OpenAiTransform {
+    client: reqwest::Client,
+    url: String,
+    api_key: String,
+    model: String,
+    prompt: String,
+}
+
+impl OpenAiTransform {
+    pub fn new(url: &str, api_key: &str, model: &str, prompt: &str) -> Self {
+        Self {
+            client: http_client(),
+            url: url.trim().trim_end_matches('/').to_string(),
+            api_key: api_key.trim().to_string(),
+            model: model.trim().to_string(),
+            prompt: prompt.to_string(),
+        }
+    }
+
+    async fn complete(&self, text: &str) -> Result<String, String> {
+        let body = json!({
+            "model": self.model,
+            "temperature": 0,
+            "messages": [
+                { "role": "system", "content": self.prompt },
+                { "role": "user", "content": text },
+            ],
+        });
+        let mut request = self.client.post(format!("{}/chat/completions", self.url)).json(&body);
+        if !self.api_key.is_empty() {
+            request = request.bearer_auth(&self.api_key);
+        }
+        let response = request.send().await.map_err(|e| {
+            warn!(error = %e, url = %self.url, "Failed to connect to OpenAI-compatible endpoint");
+            format!("Failed to connect to {}: {e}", self.url)
+        })?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            warn!(?status, body = %body, "OpenAI-compatible endpoint returned error");
+            return Err(format!("OpenAI-compatible endpoint error ({status}): {body}"));
+        }
+        let answer: Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse OpenAI-compatible endpoint response: {e}"))?;
+        chat_content(&answer).ok_or_else(|| "OpenAI-compatible endpoint answered without any text".to_string())
+    }
+}
+
+/// Text of the first choice of a chat completions answer.
+fn chat_content(answer: &Value) -> Option<String> {
+    answer["choices"][0]["message"]["content"]
+        .as_str()
+        .map(|content| content.trim().to_string())
+        .filter(|content| !content.is_empty())
+}
+
+impl TextTransformProvider for OpenAiTransform {
+    fn name(&self) -> String {
+        format!("OpenAI-compatible ({})", self.model)
+    }
+
+    fn transform<'a>(&'a self, text: &'a str) -> TransformFuture<'a> {
+        Box::pin(self.complete(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chat_content() {
+        let answer = json!({ "choices": [{ "message": { "role": "assistant", "content": " Hello.\n" } }] });
+        assert_eq!(chat_content(&answer).as_deref(), Some("Hello."));
+        assert_eq!(chat_content(&json!({ "error": "quota" })), None);
+    }
+}
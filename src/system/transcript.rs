@@ -0,0 +1,154 @@
+//! Session transcript log
+//!
+//! When enabled, every reading is appended to one file per day in the
+//! transcripts folder: time, where the text came from, word count, and an
+//! excerpt or the full text. Markdown is easy to skim; JSON Lines is easy to
+//! search with tools ("what was that article on Tuesday?").
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// How much of each reading goes into the transcript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TranscriptMode {
+    /// No transcript is written
+    #[default]
+    Off,
+    /// The first words of each reading
+    Excerpt,
+    /// The whole text of each reading
+    FullText,
+}
+
+/// File format of the daily transcript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TranscriptFormat {
+    #[default]
+    Markdown,
+    /// One JSON object per line
+    Jsonl,
+}
+
+/// Characters of text kept in an excerpt.
+const EXCERPT_CHARS: usize = 200;
+
+/// Folder the daily transcripts are written to.
+pub fn default_transcripts_folder() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("insight-reader")
+        .join("transcripts")
+}
+
+/// Readable name for where a reading came from, from the context it was started with.
+fn source_label(context: &str) -> &str {
+    match context {
+        "SelectedTextFetched" | "WindowOpened" | "TextCleanupResponse" => "Selection",
+        "ReadExtractedText" => "Screenshot",
+        "PlayBriefing" => "Morning briefing",
+        "ReadAgenda" => "Agenda",
+        "ReadArticle" => "Read later",
+        "ReadingQueue" | "NextQueuedItem" | "SkipQueuePause" | "QuietHoursEnded" => "Queue",
+        "ReadAgain" => "Read again",
+        other => other,
+    }
+}
+
+/// The text on one line, cut at a word before [`EXCERPT_CHARS`] characters.
+fn excerpt(text: &str) -> String {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match flat.char_indices().nth(EXCERPT_CHARS) {
+        Some((end, _)) => {
+            let cut = &flat[..end];
+            format!("{}…", cut.rfind(' ').map_or(cut, |space| &cut[..space]))
+        }
+        None => flat,
+    }
+}
+
+/// One transcript entry, including its trailing newline(s).
+fn format_entry(
+    time: chrono::DateTime<chrono::Local>,
+    context: &str,
+    text: &str,
+    mode: TranscriptMode,
+    format: TranscriptFormat,
+) -> String {
+    let source = source_label(context);
+    let words = text.split_whitespace().count();
+    let body = match mode {
+        TranscriptMode::FullText => text.trim().to_string(),
+        _ => excerpt(text),
+    };
+    match format {
+        TranscriptFormat::Markdown => {
+            format!("## {} · {source} · {words} words\n\n{body}\n\n", time.format("%H:%M"))
+        }
+        TranscriptFormat::Jsonl => {
+            let entry = serde_json::json!({
+                "time": time.to_rfc3339(),
+                "source": source,
+                "words": words,
+                "text": body,
+            });
+            format!("{entry}\n")
+        }
+    }
+}
+
+/// Append a reading to today's transcript in `folder`.
+///
+/// Returns the path of the transcript file.
+pub fn append_transcript(
+    folder: &Path,
+    context: &str,
+    text: &str,
+    mode: TranscriptMode,
+    format: TranscriptFormat,
+) -> Result<PathBuf, String> {
+    fs::create_dir_all(folder).map_err(|e| format!("Could not create {}: {e}", folder.display()))?;
+    let now = chrono::Local::now();
+    let extension = match format {
+        TranscriptFormat::Markdown => "md",
+        TranscriptFormat::Jsonl => "jsonl",
+    };
+    let path = folder.join(format!("{}.{extension}", now.format("%Y-%m-%d")));
+
+    let mut entry = String::new();
+    if format == TranscriptFormat::Markdown && !path.exists() {
+        entry.push_str(&format!("# Readings on {}\n\n", now.format("%A, %B %-d, %Y")));
+    }
+    entry.push_str(&format_entry(now, context, text, mode, format));
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(entry.as_bytes()))
+        .map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_transcript_entries() {
+        let time = chrono::Local.with_ymd_and_hms(2026, 3, 10, 14, 5, 0).unwrap();
+        let text = "Rust 2027 edition\n\n".to_string() + &"word ".repeat(60);
+
+        let markdown = format_entry(time, "ReadArticle", &text, TranscriptMode::Excerpt, TranscriptFormat::Markdown);
+        assert!(markdown.starts_with("## 14:05 · Read later · 63 words\n\nRust 2027 edition word word"));
+        assert!(markdown.ends_with("word…\n\n"));
+
+        let json = format_entry(time, "ReadAgenda", "Two  events\ntoday", TranscriptMode::FullText, TranscriptFormat::Jsonl);
+        let value: serde_json::Value = serde_json::from_str(json.trim_end()).unwrap();
+        assert_eq!(value["source"], "Agenda");
+        assert_eq!(value["words"], 3);
+        assert_eq!(value["text"], "Two  events\ntoday");
+        assert!(json.ends_with("}\n"));
+    }
+}
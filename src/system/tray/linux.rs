@@ -12,11 +12,16 @@ use crate::system::{HotkeyConfig, format_hotkey_display};
 // Embedded logo asset - using PNG file for Linux (same as macOS)
 const LOGO_PNG: &[u8] = include_bytes!("../../../assets/logo.png");
 
+/// Indicator label shown next to the icon during quiet hours
+const QUIET_HOURS_BADGE: &str = "☾";
+
 /// System tray handle
 pub struct SystemTray {
     _tray_icon: Option<()>, // Placeholder - actual TrayIcon lives in GTK thread
     _gtk_thread: Option<thread::JoinHandle<()>>,
     receiver: mpsc::Receiver<TrayEvent>,
+    /// Quiet-hours state updates for the GTK thread (which owns the icon)
+    quiet_hours_sender: mpsc::Sender<bool>,
 }
 
 /// Events from the system tray
@@ -25,6 +30,13 @@ pub enum TrayEvent {
     ShowWindow,
     HideWindow,
     ReadSelected,
+    PhraseBoard,
+    TypeToTalk,
+    MorningBriefing,
+    ReadLater,
+    Queue,
+    Agenda,
+    Replay,
     Quit,
 }
 
@@ -32,6 +44,7 @@ impl SystemTray {
     /// Create and initialize the system tray icon
     pub fn new(hotkey_config: Option<&HotkeyConfig>) -> Result<Self, Box<dyn std::error::Error>> {
         let (sender, receiver) = mpsc::channel();
+        let (quiet_hours_sender, quiet_hours_receiver) = mpsc::channel::<bool>();
         
         // Prepare data for the GTK thread
         let read_selected_label = if let Some(config) = hotkey_config {
@@ -54,6 +67,7 @@ impl SystemTray {
                     _tray_icon: None,
                     _gtk_thread: None,
                     receiver,
+                    quiet_hours_sender,
                 });
             }
         };
@@ -75,11 +89,25 @@ impl SystemTray {
             let read_selected_item = MenuItem::new(&read_selected_label, true, None);
             let show_item = MenuItem::new("Show Window", true, None);
             let hide_item = MenuItem::new("Hide Window", true, None);
+            let phrase_board_item = MenuItem::new("Phrase Board", true, None);
+            let type_to_talk_item = MenuItem::new("Type to Talk", true, None);
+            let morning_briefing_item = MenuItem::new("Morning Briefing", true, None);
+            let read_later_item = MenuItem::new("Read Later", true, None);
+            let queue_item = MenuItem::new("Reading Queue", true, None);
+            let agenda_item = MenuItem::new("Today's Agenda", true, None);
+            let replay_item = MenuItem::new("Replay Last 15 Seconds", true, None);
             let quit_item = MenuItem::new("Quit", true, None);
             
             let read_selected_id = read_selected_item.id();
             let show_id = show_item.id();
             let hide_id = hide_item.id();
+            let phrase_board_id = phrase_board_item.id();
+            let type_to_talk_id = type_to_talk_item.id();
+            let morning_briefing_id = morning_briefing_item.id();
+            let read_later_id = read_later_item.id();
+            let queue_id = queue_item.id();
+            let agenda_id = agenda_item.id();
+            let replay_id = replay_item.id();
             let quit_id = quit_item.id();
             
             let separator = PredefinedMenuItem::separator();
@@ -92,6 +120,13 @@ impl SystemTray {
             menu.append(&separator).ok();
             menu.append(&show_item).ok();
             menu.append(&hide_item).ok();
+            menu.append(&phrase_board_item).ok();
+            menu.append(&type_to_talk_item).ok();
+            menu.append(&morning_briefing_item).ok();
+            menu.append(&read_later_item).ok();
+            menu.append(&queue_item).ok();
+            menu.append(&agenda_item).ok();
+            menu.append(&replay_item).ok();
             menu.append(&separator).ok();
             menu.append(&quit_item).ok();
             
@@ -99,6 +134,13 @@ impl SystemTray {
             let sender_clone = sender_for_thread.clone();
             let show_id = show_id.clone();
             let hide_id = hide_id.clone();
+            let phrase_board_id = phrase_board_id.clone();
+            let type_to_talk_id = type_to_talk_id.clone();
+            let morning_briefing_id = morning_briefing_id.clone();
+            let read_later_id = read_later_id.clone();
+            let queue_id = queue_id.clone();
+            let agenda_id = agenda_id.clone();
+            let replay_id = replay_id.clone();
             let read_selected_id = read_selected_id.clone();
             let quit_id = quit_id.clone();
             MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
@@ -106,6 +148,13 @@ impl SystemTray {
                     id if id == show_id => Some(TrayEvent::ShowWindow),
                     id if id == hide_id => Some(TrayEvent::HideWindow),
                     id if id == read_selected_id => Some(TrayEvent::ReadSelected),
+                    id if id == phrase_board_id => Some(TrayEvent::PhraseBoard),
+                    id if id == type_to_talk_id => Some(TrayEvent::TypeToTalk),
+                    id if id == morning_briefing_id => Some(TrayEvent::MorningBriefing),
+                    id if id == read_later_id => Some(TrayEvent::ReadLater),
+                    id if id == queue_id => Some(TrayEvent::Queue),
+                    id if id == agenda_id => Some(TrayEvent::Agenda),
+                    id if id == replay_id => Some(TrayEvent::Replay),
                     id if id == quit_id => Some(TrayEvent::Quit),
                     _ => None,
                 };
@@ -123,9 +172,16 @@ impl SystemTray {
                 .build();
             
             match tray_result {
-                Ok(_tray_icon) => {
+                Ok(tray_icon) => {
                     info!("System tray icon created successfully");
                     let _ = tray_ready_tx.send(Some(()));
+                    // Apply quiet-hours badge updates from the app on this thread
+                    gtk::glib::timeout_add_local(std::time::Duration::from_millis(500), move || {
+                        while let Ok(active) = quiet_hours_receiver.try_recv() {
+                            tray_icon.set_title(active.then_some(QUIET_HOURS_BADGE));
+                        }
+                        gtk::glib::ControlFlow::Continue
+                    });
                     // Keep GTK event loop running (this blocks, but that's OK in a separate thread)
                     gtk::main();
                 }
@@ -153,6 +209,7 @@ impl SystemTray {
             _tray_icon: tray_created.then_some(()),
             _gtk_thread: Some(gtk_thread),
             receiver,
+            quiet_hours_sender,
         })
     }
     
@@ -160,6 +217,11 @@ impl SystemTray {
     pub fn try_recv(&self) -> Option<TrayEvent> {
         self.receiver.try_recv().ok()
     }
+    
+    /// Show or clear the quiet-hours badge (indicator label; tooltips are unsupported on Linux)
+    pub fn set_quiet_hours(&self, active: bool) {
+        let _ = self.quiet_hours_sender.send(active);
+    }
 }
 
 /// Load the app logo and convert it to RGBA format for the tray icon
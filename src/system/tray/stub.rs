@@ -30,4 +30,7 @@ impl SystemTray {
     pub fn try_recv(&self) -> Option<TrayEvent> {
         None
     }
+    
+    /// Show or clear the quiet-hours badge (stub - does nothing on non-macOS)
+    pub fn set_quiet_hours(&self, _active: bool) {}
 }
@@ -5,7 +5,7 @@ use tray_icon::{
     menu::{Menu, MenuItem, MenuEvent, PredefinedMenuItem},
     TrayIconBuilder, TrayIcon,
 };
-use tracing::info;
+use tracing::{info, warn};
 use crate::system::{HotkeyConfig, format_hotkey_display};
 
 // Embedded logo asset - using ICO file for Windows
@@ -13,7 +13,7 @@ const LOGO_ICO: &[u8] = include_bytes!("../../../assets/logo.ico");
 
 /// System tray handle
 pub struct SystemTray {
-    _tray_icon: TrayIcon,
+    tray_icon: TrayIcon,
     receiver: mpsc::Receiver<TrayEvent>,
 }
 
@@ -23,6 +23,13 @@ pub enum TrayEvent {
     ShowWindow,
     HideWindow,
     ReadSelected,
+    PhraseBoard,
+    TypeToTalk,
+    MorningBriefing,
+    ReadLater,
+    Queue,
+    Agenda,
+    Replay,
     Quit,
 }
 
@@ -43,12 +50,26 @@ impl SystemTray {
         let read_selected_item = MenuItem::new(&read_selected_label, true, None);
         let show_item = MenuItem::new("Show Window", true, None);
         let hide_item = MenuItem::new("Hide Window", true, None);
+        let phrase_board_item = MenuItem::new("Phrase Board", true, None);
+        let type_to_talk_item = MenuItem::new("Type to Talk", true, None);
+        let morning_briefing_item = MenuItem::new("Morning Briefing", true, None);
+        let read_later_item = MenuItem::new("Read Later", true, None);
+        let queue_item = MenuItem::new("Reading Queue", true, None);
+        let agenda_item = MenuItem::new("Today's Agenda", true, None);
+        let replay_item = MenuItem::new("Replay Last 15 Seconds", true, None);
         let quit_item = MenuItem::new("Quit", true, None);
         
         // Store menu item IDs
         let read_selected_item_id = read_selected_item.id();
         let show_item_id = show_item.id();
         let hide_item_id = hide_item.id();
+        let phrase_board_item_id = phrase_board_item.id();
+        let type_to_talk_item_id = type_to_talk_item.id();
+        let morning_briefing_item_id = morning_briefing_item.id();
+        let read_later_item_id = read_later_item.id();
+        let queue_item_id = queue_item.id();
+        let agenda_item_id = agenda_item.id();
+        let replay_item_id = replay_item.id();
         let quit_item_id = quit_item.id();
         
         // Create menu - Read Selected first, then separator, then other items, then separator before Quit
@@ -58,6 +79,13 @@ impl SystemTray {
         menu.append(&separator)?;
         menu.append(&show_item)?;
         menu.append(&hide_item)?;
+        menu.append(&phrase_board_item)?;
+        menu.append(&type_to_talk_item)?;
+        menu.append(&morning_briefing_item)?;
+        menu.append(&read_later_item)?;
+        menu.append(&queue_item)?;
+        menu.append(&agenda_item)?;
+        menu.append(&replay_item)?;
         menu.append(&separator)?;
         menu.append(&quit_item)?;
         
@@ -71,6 +99,13 @@ impl SystemTray {
         let sender_clone = sender.clone();
         let show_id = show_item_id.clone();
         let hide_id = hide_item_id.clone();
+        let phrase_board_id = phrase_board_item_id.clone();
+        let type_to_talk_id = type_to_talk_item_id.clone();
+        let morning_briefing_id = morning_briefing_item_id.clone();
+        let read_later_id = read_later_item_id.clone();
+        let queue_id = queue_item_id.clone();
+        let agenda_id = agenda_item_id.clone();
+        let replay_id = replay_item_id.clone();
         let read_selected_id = read_selected_item_id.clone();
         let quit_id = quit_item_id.clone();
         MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
@@ -78,6 +113,13 @@ impl SystemTray {
                 id if id == show_id => Some(TrayEvent::ShowWindow),
                 id if id == hide_id => Some(TrayEvent::HideWindow),
                 id if id == read_selected_id => Some(TrayEvent::ReadSelected),
+                id if id == phrase_board_id => Some(TrayEvent::PhraseBoard),
+                id if id == type_to_talk_id => Some(TrayEvent::TypeToTalk),
+                id if id == morning_briefing_id => Some(TrayEvent::MorningBriefing),
+                id if id == read_later_id => Some(TrayEvent::ReadLater),
+                id if id == queue_id => Some(TrayEvent::Queue),
+                id if id == agenda_id => Some(TrayEvent::Agenda),
+                id if id == replay_id => Some(TrayEvent::Replay),
                 id if id == quit_id => Some(TrayEvent::Quit),
                 _ => None,
             };
@@ -98,7 +140,7 @@ impl SystemTray {
         info!("System tray icon created successfully");
         
         Ok(Self {
-            _tray_icon: tray_icon,
+            tray_icon,
             receiver,
         })
     }
@@ -107,6 +149,14 @@ impl SystemTray {
     pub fn try_recv(&self) -> Option<TrayEvent> {
         self.receiver.try_recv().ok()
     }
+    
+    /// Show or clear the quiet-hours badge (tooltip; Windows trays have no title)
+    pub fn set_quiet_hours(&self, active: bool) {
+        let tooltip = if active { "Insight Reader (quiet hours)" } else { "Insight Reader" };
+        if let Err(e) = self.tray_icon.set_tooltip(Some(tooltip)) {
+            warn!(error = %e, "Failed to update tray tooltip");
+        }
+    }
 }
 
 /// Load the app logo and convert it to RGBA format for the tray icon
@@ -0,0 +1,196 @@
+//! Watched documents
+//!
+//! A watched URL or file is fetched again every few minutes and compared with
+//! the snapshot taken the time before. Only the paragraphs that were added are
+//! read, so status pages, changelogs and shared documents can be followed by
+//! ear. The first check of a document only takes its snapshot.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use tracing::{debug, info, warn};
+
+use super::text_processing::html_to_text;
+
+/// How often watched documents are checked.
+pub const WATCH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Timeout for downloading one document.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Added paragraphs read per document in one check (e.g. when a page was rewritten).
+const MAX_ADDED_PARAGRAPHS: usize = 30;
+
+/// New paragraphs of a watched document, ready to be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentChange {
+    /// URL or path of the document
+    pub source: String,
+    /// Text to read: document name, then the added paragraphs
+    pub text: String,
+}
+
+fn is_url(source: &str) -> bool {
+    source.starts_with("http://") || source.starts_with("https://")
+}
+
+/// Short name of a document, read before its changes (host and path, or file name).
+pub fn watch_display_name(source: &str) -> String {
+    if is_url(source) {
+        let rest = source.split_once("://").map_or(source, |(_, rest)| rest);
+        return rest.trim_end_matches('/').to_string();
+    }
+    Path::new(source)
+        .file_name()
+        .map_or_else(|| source.to_string(), |name| name.to_string_lossy().to_string())
+}
+
+fn snapshot_path(source: &str) -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("insight-reader")
+        .join("snapshots")
+        .join(format!("{:x}.txt", md5::compute(source.as_bytes())))
+}
+
+/// Delete the snapshot of a document that is no longer watched.
+pub fn forget_snapshot(source: &str) {
+    let _ = fs::remove_file(snapshot_path(source));
+}
+
+/// Paragraphs of a text: its non-empty lines, whitespace collapsed.
+fn paragraphs(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Paragraphs of `new` that are not in `old`, in document order.
+///
+/// Paragraphs that moved are not new; a paragraph repeated more often than
+/// before counts as added.
+fn added_paragraphs(old: &str, new: &str) -> Vec<String> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for paragraph in paragraphs(old) {
+        *seen.entry(paragraph).or_default() += 1;
+    }
+    paragraphs(new)
+        .into_iter()
+        .filter(|paragraph| match seen.get_mut(paragraph) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                false
+            }
+            _ => true,
+        })
+        .collect()
+}
+
+/// Readable text of a document: HTML is stripped, anything else is read as is.
+async fn fetch_document(client: &reqwest::Client, source: &str) -> Result<String, String> {
+    let (body, is_html) = if is_url(source) {
+        let response = client
+            .get(source)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| e.to_string())?;
+        let is_html = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.contains("html"));
+        (response.text().await.map_err(|e| e.to_string())?, is_html)
+    } else {
+        let path = Path::new(source);
+        let body = fs::read_to_string(path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+        let is_html = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm"));
+        (body, is_html)
+    };
+    Ok(if is_html { html_to_text(&body) } else { body })
+}
+
+/// Compare `current` with the saved snapshot of `source`, then save it as the new snapshot.
+///
+/// Returns the text to read, or None when nothing was added (or this was the first snapshot).
+fn take_snapshot(source: &str, current: &str) -> Option<String> {
+    let path = snapshot_path(source);
+    let previous = fs::read_to_string(&path).ok();
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&path, current));
+    if let Err(e) = result {
+        warn!(path = %path.display(), error = %e, "Failed to save document snapshot");
+    }
+    let added = added_paragraphs(&previous?, current);
+    if added.is_empty() {
+        return None;
+    }
+    let mut text = format!("{} changed.", watch_display_name(source));
+    for paragraph in added.iter().take(MAX_ADDED_PARAGRAPHS) {
+        text.push_str("\n\n");
+        text.push_str(paragraph);
+    }
+    Some(text)
+}
+
+/// Fetch every watched document and collect what was added since the last check.
+///
+/// Documents that fail to load are skipped; an error is returned only if
+/// none of them could be checked.
+pub async fn check_watched(sources: Vec<String>) -> Result<Vec<DocumentChange>, String> {
+    let client = reqwest::Client::builder()
+        .timeout(FETCH_TIMEOUT)
+        .user_agent(concat!("insight-reader/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+    let mut changes = Vec::new();
+    let mut last_error = None;
+    let mut checked = 0;
+    for source in &sources {
+        debug!(%source, "Checking watched document");
+        let current = match fetch_document(&client, source).await {
+            Ok(current) => current,
+            Err(e) => {
+                warn!(%source, error = %e, "Failed to fetch watched document");
+                last_error = Some(format!("{}: {e}", watch_display_name(source)));
+                continue;
+            }
+        };
+        checked += 1;
+        if let Some(text) = take_snapshot(source, &current) {
+            changes.push(DocumentChange { source: source.clone(), text });
+        }
+    }
+
+    match last_error {
+        Some(error) if checked == 0 => Err(error),
+        _ => {
+            info!(documents = checked, changed = changes.len(), "Watched documents checked");
+            Ok(changes)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_added_paragraphs() {
+        let old = "# Status\n\nAll systems operational.\n\nAPI: ok\n";
+        let new = "# Status\n\nAPI:   degraded\n\nAll systems operational.\n\nAPI: ok\nAPI: ok\n";
+        assert_eq!(added_paragraphs(old, new), ["API: degraded", "API: ok"]);
+        assert!(added_paragraphs(new, old).is_empty());
+
+        assert_eq!(watch_display_name("https://status.example.com/"), "status.example.com");
+        assert_eq!(watch_display_name("/home/me/notes/CHANGELOG.md"), "CHANGELOG.md");
+    }
+}
@@ -0,0 +1,202 @@
+//! Headless harness for the `update()` state machine.
+//!
+//! Drives the app the way the iced runtime would, without windows, audio or
+//! models: messages go through `update()` as usual, and the work its tasks
+//! would do (cleanup, synthesis) is stood in for by the harness, which
+//! answers with the messages those tasks produce. Readings are spoken by
+//! [`MockTTSProvider`], so playback, queueing and window lifecycle can be
+//! tested end to end. Playback positions are kept in a temporary file per
+//! harness, so the user's remembered positions are neither read nor changed.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use iced::window;
+
+use crate::model::{App, Message, PlaybackState, WindowRole};
+use crate::providers::mock::MockTTSProvider;
+use crate::providers;
+use crate::update::update;
+
+/// An app with its main window open, driven by hand.
+pub struct Harness {
+    pub app: App,
+    runtime: tokio::runtime::Runtime,
+    positions_file: PathBuf,
+}
+
+impl Harness {
+    /// A fresh app (default settings, no saved positions) with the main window open.
+    pub fn new() -> Self {
+        static HARNESSES: AtomicUsize = AtomicUsize::new(0);
+        let positions_file = std::env::temp_dir().join(format!(
+            "insight-reader-harness-{}-{}-positions.json",
+            std::process::id(),
+            HARNESSES.fetch_add(1, Ordering::Relaxed)
+        ));
+        crate::system::use_positions_file(positions_file.clone());
+
+        let mut app = App::default();
+        app.windows.insert(window::Id::unique(), WindowRole::Main);
+        // Queued items start straight away rather than after a countdown
+        app.queue_pause_seconds = 0;
+        Self { app, runtime: tokio::runtime::Runtime::new().expect("tokio runtime"), positions_file }
+    }
+
+    /// Pass `message` to `update()`; the task it returns is dropped, the harness stands in for it.
+    pub fn send(&mut self, message: Message) {
+        let _ = update(&mut self.app, message);
+    }
+
+    /// Text captured from the selection, as the capture task would report it.
+    pub fn capture(&mut self, text: &str) {
+        self.send(Message::SelectedTextFetched(Some(text.to_string())));
+    }
+
+    /// Answer the pending cleanup with `result`.
+    pub fn finish_cleanup(&mut self, result: Result<&str, &str>) {
+        self.send(Message::TextCleanupResponse(result.map(str::to_string).map_err(str::to_string)));
+    }
+
+    /// Synthesize the pending reading with the mock provider and start playing it.
+    pub fn finish_synthesis(&mut self) {
+        let text = self.app.reading_text.clone().expect("a reading waiting for synthesis");
+        let provider = Box::new(MockTTSProvider::new());
+        let speed = self.app.reading_speed();
+        let output = self.app.effective_output();
+        let (provider, synthesis) =
+            providers::prepare_speech(provider, None, &text, speed, 0.0, output).expect("mock speech");
+        self.app.synthesizing = Some((synthesis.id(), provider));
+        let result = self
+            .runtime
+            .block_on(providers::synthesize_in_background(synthesis))
+            .map_err(|e| e.to_string());
+        self.send(Message::TTSInitialized(result));
+    }
+
+    /// One tick of the playback timer.
+    pub fn tick(&mut self) {
+        self.send(Message::Tick);
+    }
+
+    /// Skip to the end of the reading and tick until playback stopped.
+    pub fn play_to_end(&mut self) {
+        for _ in 0..100 {
+            if self.app.playback_state == PlaybackState::Stopped {
+                return;
+            }
+            self.send(Message::SkipForward);
+            self.tick();
+        }
+        panic!("reading never finished");
+    }
+}
+
+impl Drop for Harness {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.positions_file);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "The quick brown fox jumps over the lazy dog, then naps in the sun for a while. \
+        It wakes up at dusk and trots back home across the fields.";
+
+    #[test]
+    fn test_capture_speak_seek_finish() {
+        let mut harness = Harness::new();
+        harness.capture(TEXT);
+        assert!(harness.app.is_loading);
+        assert_eq!(harness.app.status_text.as_deref(), Some("Synthesizing voice..."));
+
+        harness.finish_synthesis();
+        assert!(!harness.app.is_loading);
+        assert_eq!(harness.app.playback_state, PlaybackState::Playing);
+        harness.tick();
+        assert!(harness.app.progress < 0.1);
+
+        harness.send(Message::SkipForward);
+        harness.tick();
+        let duration = harness.app.provider.as_ref().unwrap().get_duration();
+        assert!((harness.app.progress * duration - 5.0).abs() < 0.5);
+
+        harness.send(Message::PlayPause);
+        assert_eq!(harness.app.playback_state, PlaybackState::Paused);
+        harness.send(Message::PlayPause);
+        assert_eq!(harness.app.playback_state, PlaybackState::Playing);
+
+        // Played to the end with nothing queued: the main window closes
+        harness.play_to_end();
+        assert!(!harness.app.has_window(WindowRole::Main));
+    }
+
+    #[test]
+    fn test_cleanup_before_speaking() {
+        let mut harness = Harness::new();
+        harness.app.text_cleanup_enabled = true;
+        harness.capture(TEXT);
+        assert_eq!(harness.app.status_text.as_deref(), Some("Processing content..."));
+        assert!(harness.app.reading_text.is_none());
+
+        harness.finish_cleanup(Ok("A fox naps."));
+        assert_eq!(harness.app.status_text.as_deref(), Some("Synthesizing voice..."));
+        harness.finish_synthesis();
+        assert_eq!(harness.app.reading_text.as_deref(), Some("A fox naps."));
+        assert_eq!(harness.app.playback_state, PlaybackState::Playing);
+
+        // A failed cleanup stops loading and shows the error in settings
+        let mut harness = Harness::new();
+        harness.app.text_cleanup_enabled = true;
+        harness.capture(TEXT);
+        harness.finish_cleanup(Err("Service unavailable"));
+        assert!(!harness.app.is_loading);
+        assert_eq!(harness.app.error_message.as_deref(), Some("Service unavailable"));
+        assert!(harness.app.has_window(WindowRole::Settings));
+    }
+
+    #[test]
+    fn test_large_text_guard() {
+        let mut harness = Harness::new();
+        harness.app.large_text_limit_kb = Some(1);
+        let paragraph = "A long paragraph. ".repeat(20);
+        harness.capture(&[paragraph.as_str(); 10].join("\n\n"));
+        assert!(!harness.app.is_loading);
+        assert_eq!(harness.app.large_text.as_ref().map(|large| large.paragraphs), Some(2));
+
+        harness.send(Message::ReadLargeText(Some(2)));
+        assert!(harness.app.large_text.is_none());
+        harness.finish_synthesis();
+        let reading = harness.app.reading_text.as_deref().unwrap();
+        assert_eq!(reading.matches("A long paragraph.").count(), 40);
+
+        // Captured while reading: confirmed before it is queued
+        harness.capture(&[paragraph.as_str(); 10].join("\n\n"));
+        assert_eq!(harness.app.reading_queue.len(), 0);
+        assert!(harness.app.large_text.is_some());
+        harness.send(Message::ReadLargeText(None));
+        assert_eq!(harness.app.reading_queue.len(), 1);
+        assert_eq!(harness.app.playback_state, PlaybackState::Playing);
+    }
+
+    #[test]
+    fn test_queue_while_reading() {
+        let mut harness = Harness::new();
+        harness.capture(TEXT);
+        harness.finish_synthesis();
+        harness.capture("Second selection.");
+        assert_eq!(harness.app.reading_queue.len(), 1);
+
+        // The queued text starts once the first reading ends, in the same window
+        harness.play_to_end();
+        assert!(harness.app.is_loading);
+        assert!(harness.app.has_window(WindowRole::Main));
+        harness.finish_synthesis();
+        assert_eq!(harness.app.reading_text.as_deref(), Some("Second selection."));
+
+        harness.play_to_end();
+        assert!(!harness.app.has_window(WindowRole::Main));
+    }
+}
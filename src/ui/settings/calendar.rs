@@ -0,0 +1,99 @@
+//! Calendar agenda configuration UI component
+
+use iced::widget::{button, column, container, row, text, text_input, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::Message;
+use crate::styles::{circle_button_style, dark_text_input_style, section_style};
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text(content: &str, size: u32) -> text::Text<'_> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// One labeled row of the section (label column matches the other settings sections).
+fn setting_row<'a>(label: &'a str, controls: Element<'a, Message>) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 14))
+            .width(Length::Fixed(120.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(16.0)),
+        container(controls)
+            .width(Length::Fill)
+            .align_x(Alignment::Start),
+    ]
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Create the calendar settings section for the settings window
+pub fn calendar_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    let calendar = &app.calendar;
+    let source_controls = row![
+        text_input("Path to .ics file, or calendar URL", &calendar.source)
+            .on_input(Message::CalendarSourceChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(328.0))
+            .style(dark_text_input_style),
+        button(white_text(if app.agenda_fetching { "Fetching…" } else { "Read Agenda" }, 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((calendar.is_configured() && !app.agenda_fetching).then_some(Message::ReadAgenda)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    // Credentials only matter for a remote calendar
+    let is_remote = ["http://", "https://", "webcal://"]
+        .iter()
+        .any(|scheme| calendar.source.trim().starts_with(scheme));
+    let mut content = column![setting_row("Calendar", source_controls.into())].width(Length::Fill);
+    if is_remote {
+        content = content.push(Space::new().height(Length::Fixed(8.0)));
+        content = content.push(setting_row(
+            "Username",
+            text_input("Leave empty for a public calendar", &calendar.username)
+                .on_input(Message::CalendarUsernameChanged)
+                .size(13)
+                .padding([6.0, 8.0])
+                .width(Length::Fixed(328.0))
+                .style(dark_text_input_style)
+                .into(),
+        ));
+        content = content.push(Space::new().height(Length::Fixed(8.0)));
+        content = content.push(setting_row(
+            "Password",
+            text_input("Password or app password", &calendar.password)
+                .on_input(Message::CalendarPasswordChanged)
+                .secure(true)
+                .size(13)
+                .padding([6.0, 8.0])
+                .width(Length::Fixed(328.0))
+                .style(dark_text_input_style)
+                .into(),
+        ));
+    }
+
+    let (status_text, status_color) = match &app.calendar_status {
+        Some(Ok(message)) => (message.clone(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        None => (
+            "Reads today's events from an .ics file, an iCalendar link or a CalDAV calendar.".to_string(),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        ),
+    };
+    content = content.push(Space::new().height(Length::Fixed(8.0)));
+    content = content.push(text(status_text).size(11).style(move |_theme| iced::widget::text::Style {
+        color: Some(status_color),
+    }));
+
+    container(content.padding([12.0, 16.0]))
+        .style(section_style)
+        .into()
+}
@@ -0,0 +1,86 @@
+//! External command provider configuration UI component
+
+use iced::widget::{button, column, container, pick_list, row, text, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::{Message, TTSBackend};
+use crate::styles::{circle_button_style, dark_menu_style, dark_pick_list_style, section_style};
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text<'a>(content: impl text::IntoFragment<'a>, size: u32) -> text::Text<'a> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// Create the command voice settings (only shown when the command provider is selected)
+pub fn command_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    if app.selected_backend != TTSBackend::Command {
+        return column![].spacing(0).into();
+    }
+
+    let path = crate::providers::command_voices_path()
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|| "command-voices.toml".to_string());
+
+    let selected = app
+        .command_voice
+        .clone()
+        .filter(|name| app.command_voices.contains(name))
+        .or_else(|| app.command_voices.first().cloned());
+    let voice_controls: Element<'a, Message> = if app.command_voices.is_empty() {
+        white_text("No voices defined yet", 13).into()
+    } else {
+        pick_list(app.command_voices.clone(), selected, Message::CommandVoiceSelected)
+            .text_size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(240.0))
+            .style(dark_pick_list_style)
+            .menu_style(dark_menu_style)
+            .into()
+    };
+
+    let (hint, hint_color) = match &app.command_voices_error {
+        Some(error) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        None => (
+            format!("Voices are [[voice]] tables (name, command, args, format, sample_rate) in {path}."),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        ),
+    };
+    let hint = text(hint)
+        .size(11)
+        .style(move |_theme| iced::widget::text::Style {
+            color: Some(hint_color),
+        });
+
+    container(
+        container(
+            column![
+                row![
+                    container(white_text("Voice", 13))
+                        .width(Length::Fixed(80.0))
+                        .align_x(Alignment::Start),
+                    Space::new().width(Length::Fixed(8.0)),
+                    voice_controls,
+                    Space::new().width(Length::Fixed(12.0)),
+                    button(white_text("Reload", 12))
+                        .style(circle_button_style)
+                        .padding([6.0, 12.0])
+                        .on_press(Message::ReloadCommandVoices),
+                ]
+                .align_y(Alignment::Center),
+                Space::new().height(Length::Fixed(8.0)),
+                hint,
+            ]
+            .spacing(0)
+            .padding([12.0, 16.0])
+        )
+        .width(Length::Fill)
+        .style(section_style)
+    )
+    .padding([16, 16]) // Extra top padding to show it's part of the provider section
+    .width(Length::Fill)
+    .into()
+}
@@ -0,0 +1,74 @@
+//! Coqui TTS server configuration UI component
+
+use iced::widget::{column, container, row, text, text_input, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::{Message, TTSBackend};
+use crate::styles::{dark_text_input_style, section_style};
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text(content: &str, size: u32) -> text::Text<'_> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// Labeled text input row used for the server settings (shared with other HTTP providers).
+pub(super) fn labeled_input<'a>(
+    label: &'a str,
+    placeholder: &'a str,
+    value: &'a str,
+    on_input: fn(String) -> Message,
+) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 13))
+            .width(Length::Fixed(80.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(8.0)),
+        text_input(placeholder, value)
+            .on_input(on_input)
+            .size(13)
+            .padding([6.0, 8.0])
+            .style(dark_text_input_style),
+    ]
+    .align_y(Alignment::Center)
+    .into()
+}
+
+/// Create the Coqui server settings (only shown when Coqui is selected)
+pub fn coqui_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    if app.selected_backend != TTSBackend::Coqui {
+        return column![].spacing(0).into();
+    }
+
+    let hint = white_text("Run `tts-server` (Coqui TTS) or an XTTS server locally, then point this at it.", 11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    container(
+        container(
+            column![
+                labeled_input("Server URL", "http://localhost:5002", &app.coqui_config.url, Message::CoquiUrlChanged),
+                Space::new().height(Length::Fixed(8.0)),
+                labeled_input(
+                    "Speaker",
+                    "Default speaker (optional)",
+                    &app.coqui_config.speaker,
+                    Message::CoquiSpeakerChanged,
+                ),
+                Space::new().height(Length::Fixed(8.0)),
+                hint,
+            ]
+            .spacing(0)
+            .padding([12.0, 16.0])
+        )
+        .width(Length::Fill)
+        .style(section_style)
+    )
+    .padding([16, 16]) // Extra top padding to show it's part of the provider section
+    .width(Length::Fill)
+    .into()
+}
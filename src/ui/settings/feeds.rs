@@ -0,0 +1,133 @@
+//! Morning briefing (RSS/Atom feeds) configuration UI component
+
+use iced::widget::{button, column, container, row, scrollable, text, text_input, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::Message;
+use crate::styles::{circle_button_style, dark_text_input_style, section_style, transparent_button_style};
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text<'a>(content: impl text::IntoFragment<'a>, size: u32) -> text::Text<'a> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// One labeled row of the section (label column matches the other settings sections).
+fn setting_row<'a>(label: &'a str, controls: Element<'a, Message>) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 14))
+            .width(Length::Fixed(120.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(16.0)),
+        container(controls)
+            .width(Length::Fill)
+            .align_x(Alignment::Start),
+    ]
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Create the morning briefing settings section for the settings window
+pub fn feeds_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    let briefing_controls = row![
+        white_text(format!("{} feeds", app.feeds.len()), 13),
+        Space::new().width(Length::Fixed(12.0)),
+        button(white_text(if app.briefing_fetching { "Fetching…" } else { "Play Briefing" }, 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((!app.feeds.is_empty() && !app.briefing_fetching).then_some(Message::PlayBriefing)),
+    ]
+    .align_y(Alignment::Center);
+
+    let add_controls = row![
+        text_input("Feed URL (RSS or Atom)", &app.new_feed_url)
+            .on_input(Message::NewFeedUrlChanged)
+            .on_submit(Message::AddFeed)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(328.0))
+            .style(dark_text_input_style),
+        button(white_text("Add", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((!app.new_feed_url.trim().is_empty()).then_some(Message::AddFeed)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let import_controls = row![
+        text_input("Path to .opml file", &app.opml_path)
+            .on_input(Message::OpmlPathChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(328.0))
+            .style(dark_text_input_style),
+        button(white_text("Import", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((!app.opml_path.trim().is_empty()).then_some(Message::ImportOpml)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    // Grow with the list up to a few rows, then scroll
+    let list_height = (app.feeds.len() as f32 * 22.0).min(132.0);
+    let mut feed_list = column![].spacing(4);
+    for (index, feed) in app.feeds.iter().enumerate() {
+        feed_list = feed_list.push(
+            row![
+                white_text(feed.display_name(), 12).width(Length::Fill),
+                text_input("Selected voice", feed.voice.voice.as_deref().unwrap_or_default())
+                    .on_input(move |voice| Message::FeedVoiceChanged(index, voice))
+                    .size(11)
+                    .padding([2.0, 6.0])
+                    .width(Length::Fixed(150.0))
+                    .style(dark_text_input_style),
+                button(white_text("✕", 12))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press(Message::RemoveFeed(index)),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+        );
+    }
+
+    let (status_text, status_color) = match &app.feeds_status {
+        Some(Ok(message)) => (message.clone(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        None => (
+            "New items are queued and read one after another; press N or ⏭ to skip to the next one. \
+             A feed with its own voice (e.g. pt_BR-faber-medium) is read with it."
+                .to_string(),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        ),
+    };
+    let feeds_hint = text(status_text)
+        .size(11)
+        .style(move |_theme| iced::widget::text::Style {
+            color: Some(status_color),
+        });
+
+    container(
+        column![
+            setting_row("Briefing", briefing_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            feeds_hint,
+            Space::new().height(Length::Fixed(8.0)),
+            scrollable(feed_list).height(Length::Fixed(list_height)),
+            Space::new().height(Length::Fixed(8.0)),
+            add_controls,
+            Space::new().height(Length::Fixed(8.0)),
+            import_controls,
+        ]
+        .width(Length::Fill)
+        .padding([12.0, 16.0])
+    )
+    .style(section_style)
+    .into()
+}
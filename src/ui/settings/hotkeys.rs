@@ -5,7 +5,7 @@ use iced::{Alignment, Color, Element, Length};
 
 use crate::model::Message;
 use crate::styles::{circle_button_style, section_style, white_checkbox_style};
-use crate::system::{format_hotkey_display};
+use crate::system::{format_hotkey_display, HotkeyAction};
 
 /// Helper to create white text with consistent styling (matching view.rs pattern).
 fn white_text(content: &str, size: u32) -> text::Text<'_> {
@@ -18,36 +18,48 @@ fn white_text(content: &str, size: u32) -> text::Text<'_> {
 
 /// Create the hotkey settings section for the settings window
 pub fn hotkey_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
-    // Format hotkey display string
-    let hotkey_display = format_hotkey_display(&app.hotkey_config);
-    
     // Check if hotkeys are disabled due to Wayland/Hyprland
     let is_disabled = app.hotkeys_disabled_wayland;
     
     // Hotkey enabled checkbox (disabled if on Wayland/Hyprland)
-    let checkbox_label = format!("Enable global hotkey ({})", hotkey_display);
     let mut hotkey_checkbox = checkbox(if is_disabled { false } else { app.hotkey_enabled })
-        .label(checkbox_label)
+        .label("Enable global hotkeys")
         .style(white_checkbox_style);
     if !is_disabled {
         hotkey_checkbox = hotkey_checkbox.on_toggle(Message::HotkeyToggled);
     }
     
-    // Set Hotkey button (disabled if on Wayland/Hyprland)
-    let set_button_text = if app.listening_for_hotkey { "Cancel" } else { "Set Hotkey" };
-    let mut set_button = button(white_text(set_button_text, 12))
-        .style(circle_button_style)
-        .padding([6.0, 12.0]);
-    if !is_disabled {
-        set_button = set_button.on_press(if app.listening_for_hotkey {
-            Message::StopListeningForHotkey
-        } else {
-            Message::StartListeningForHotkey
-        });
+    // One row per action: its shortcut and a Set button (disabled if on Wayland/Hyprland)
+    let mut action_rows = column![].spacing(6);
+    for action in HotkeyAction::ALL {
+        let listening = app.listening_for_hotkey == Some(action);
+        let mut set_button = button(white_text(if listening { "Cancel" } else { "Set" }, 12))
+            .style(circle_button_style)
+            .padding([4.0, 12.0]);
+        if !is_disabled {
+            set_button = set_button.on_press(if listening {
+                Message::StopListeningForHotkey
+            } else {
+                Message::StartListeningForHotkey(action)
+            });
+        }
+        action_rows = action_rows.push(
+            row![
+                white_text(action.label(), 12).width(Length::Fixed(110.0)),
+                text(format_hotkey_display(app.hotkey_config_for(action)))
+                    .size(12)
+                    .style(|_theme| iced::widget::text::Style {
+                        color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+                    })
+                    .width(Length::Fixed(120.0)),
+                set_button,
+            ]
+            .align_y(Alignment::Center),
+        );
     }
     
     // Status/info message (listening status or Wayland/Hyprland info)
-    let status_message: Option<Element<'a, Message>> = if app.listening_for_hotkey && !is_disabled {
+    let status_message: Option<Element<'a, Message>> = if app.listening_for_hotkey.is_some() && !is_disabled {
         Some(white_text("Press your key combination...", 11)
             .style(|_theme| iced::widget::text::Style {
                 color: Some(Color::from_rgb(0.4, 0.6, 1.0)),
@@ -75,13 +87,9 @@ pub fn hotkey_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Me
     };
     
     let hotkey_control = column![
-        row![
-            hotkey_checkbox,
-            Space::new().width(Length::Fixed(12.0)),
-            set_button,
-        ]
-        .align_y(Alignment::Center)
-        .spacing(0),
+        hotkey_checkbox,
+        Space::new().height(Length::Fixed(8.0)),
+        action_rows,
         if let Some(msg) = status_message {
             column![
                 Space::new().height(Length::Fixed(6.0)),
@@ -97,7 +105,7 @@ pub fn hotkey_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Me
     container(
         row![
             container(
-                white_text("Global Hotkey", 14)
+                white_text("Global Hotkeys", 14)
             )
             .width(Length::Fixed(120.0))
             .align_x(Alignment::Start),
@@ -1,3 +1,17 @@
 //! Settings window UI components
 
+pub mod calendar;
+pub mod command;
+pub mod coqui;
+pub mod feeds;
 pub mod hotkeys;
+pub mod opentts;
+pub mod output;
+pub mod piper;
+pub mod playback;
+pub mod polly;
+pub mod read_later;
+pub mod storage;
+pub mod text_processing;
+pub mod transforms;
+pub mod watch;
@@ -0,0 +1,45 @@
+//! OpenTTS / Mimic 3 server configuration UI component
+
+use iced::widget::{column, container, text, Space};
+use iced::{Color, Element, Length};
+
+use super::coqui::labeled_input;
+use crate::model::{Message, TTSBackend};
+use crate::styles::section_style;
+
+/// Create the OpenTTS server settings (only shown when OpenTTS is selected)
+pub fn opentts_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    if app.selected_backend != TTSBackend::OpenTTS {
+        return column![].spacing(0).into();
+    }
+
+    let hint = text("Works with OpenTTS (port 5500) and mimic3-server (port 59125).")
+        .size(11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    container(
+        container(
+            column![
+                labeled_input("Server URL", "http://localhost:5500", &app.opentts_config.url, Message::OpenTTSUrlChanged),
+                Space::new().height(Length::Fixed(8.0)),
+                labeled_input(
+                    "Voice",
+                    "e.g. en_US/vctk_low#p239 (optional)",
+                    &app.opentts_config.voice,
+                    Message::OpenTTSVoiceChanged,
+                ),
+                Space::new().height(Length::Fixed(8.0)),
+                hint,
+            ]
+            .spacing(0)
+            .padding([12.0, 16.0])
+        )
+        .width(Length::Fill)
+        .style(section_style)
+    )
+    .padding([16, 16]) // Extra top padding to show it's part of the provider section
+    .width(Length::Fill)
+    .into()
+}
@@ -0,0 +1,253 @@
+//! Audio output and workspace profile configuration UI component
+
+use iced::widget::{button, checkbox, column, container, pick_list, radio, row, slider, text, text_input, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::{Message, DEFAULT_OUTPUT_DEVICE, NO_MIRROR_DEVICE};
+use crate::providers::ChannelRouting;
+use crate::styles::{
+    circle_button_style, dark_menu_style, dark_pick_list_style, dark_text_input_style, section_style,
+    transparent_button_style, white_checkbox_style, white_radio_style,
+};
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text<'a>(content: impl text::IntoFragment<'a>, size: u32) -> text::Text<'a> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// Dimmed helper text below a row.
+fn hint_text<'a>(content: impl text::IntoFragment<'a>) -> text::Text<'a> {
+    text(content)
+        .size(11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        })
+}
+
+/// One labeled row of the section (label column matches the other settings sections).
+fn setting_row<'a>(label: &'a str, controls: Element<'a, Message>) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 14))
+            .width(Length::Fixed(120.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(16.0)),
+        container(controls)
+            .width(Length::Fill)
+            .align_x(Alignment::Start),
+    ]
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Short description of a device for the profile list.
+fn device_label(device: Option<&str>) -> &str {
+    device.unwrap_or(DEFAULT_OUTPUT_DEVICE)
+}
+
+/// Create the audio output settings section for the settings window
+pub fn output_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    let output = app.effective_output();
+
+    let mut devices = vec![DEFAULT_OUTPUT_DEVICE.to_string()];
+    devices.extend(app.output_devices.iter().cloned());
+    let selected_device = device_label(output.device.as_deref()).to_string();
+    let device_controls = pick_list(devices, Some(selected_device), Message::OutputDeviceSelected)
+        .text_size(13)
+        .padding([6.0, 8.0])
+        .width(Length::Fixed(280.0))
+        .style(dark_pick_list_style)
+        .menu_style(dark_menu_style);
+
+    let mut mirror_devices = vec![NO_MIRROR_DEVICE.to_string()];
+    mirror_devices.extend(app.output_devices.iter().cloned());
+    let selected_mirror = output.mirror_device.clone().unwrap_or_else(|| NO_MIRROR_DEVICE.to_string());
+    let mirror_controls = pick_list(mirror_devices, Some(selected_mirror), Message::MirrorDeviceSelected)
+        .text_size(13)
+        .padding([6.0, 8.0])
+        .width(Length::Fixed(280.0))
+        .style(dark_pick_list_style)
+        .menu_style(dark_menu_style);
+
+    let volume_controls = row![
+        slider(0.0..=1.0, output.volume, Message::VolumeChanged)
+            .step(0.05)
+            .on_release(Message::VolumeReleased)
+            .width(Length::Fixed(200.0)),
+        white_text(format!("{:.0}%", output.volume * 100.0), 13),
+    ]
+    .spacing(12)
+    .align_y(Alignment::Center);
+
+    let channel_controls = [
+        ("Both sides", ChannelRouting::Both),
+        ("Left only", ChannelRouting::Left),
+        ("Right only", ChannelRouting::Right),
+    ]
+    .into_iter()
+    .fold(row![].spacing(16).align_y(Alignment::Center), |controls, (label, channels)| {
+        controls.push(
+            radio(label, channels, Some(output.channels), Message::OutputChannelsSelected).style(white_radio_style),
+        )
+    });
+
+    let virtual_mic_hint_text = match &app.virtual_mic_status {
+        _ if !output.virtual_mic => {
+            "Lets other apps use speech as microphone input, e.g. to talk in calls and games.".to_string()
+        }
+        Some(Ok(name)) => format!("Select \"{name}\" as the microphone in your call or game. Replaces \"Also Play On\"."),
+        Some(Err(error)) => error.clone(),
+        None => "Setting up the virtual microphone…".to_string(),
+    };
+    let virtual_mic_hint_color = if output.virtual_mic && matches!(app.virtual_mic_status, Some(Err(_))) {
+        Color::from_rgb(1.0, 0.45, 0.45)
+    } else {
+        Color::from_rgba(1.0, 1.0, 1.0, 0.6)
+    };
+    let virtual_mic_hint = text(virtual_mic_hint_text)
+        .size(11)
+        .style(move |_theme| iced::widget::text::Style {
+            color: Some(virtual_mic_hint_color),
+        });
+
+    let active_name = app
+        .active_profile
+        .and_then(|index| app.profiles.get(index))
+        .map(|profile| profile.name.as_str());
+    let output_hint = match active_name {
+        Some(name) => format!("Editing the \"{name}\" profile, active on this network."),
+        None => "Used when no workspace profile matches.".to_string(),
+    };
+
+    let mut profile_list = column![].spacing(4);
+    for (index, profile) in app.profiles.iter().enumerate() {
+        let marker = if app.active_profile == Some(index) { "● " } else { "" };
+        let mirror = if profile.output.virtual_mic {
+            " + virtual mic".to_string()
+        } else {
+            profile
+                .output
+                .mirror_device
+                .as_deref()
+                .map(|device| format!(" + {device}"))
+                .unwrap_or_default()
+        };
+        let summary = format!(
+            "{marker}{} — {} · {}{mirror} · {:.0}%",
+            profile.name,
+            profile.matches.join(", "),
+            device_label(profile.output.device.as_deref()),
+            profile.output.volume * 100.0,
+        );
+        profile_list = profile_list.push(
+            row![
+                white_text(summary, 12).width(Length::Fill),
+                button(white_text("✕", 12))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press(Message::DeleteProfile(index)),
+            ]
+            .align_y(Alignment::Center),
+        );
+    }
+
+    let match_placeholder = app.network.preferred_match().unwrap_or("SSID or hostname");
+    let add_controls = row![
+        text_input("Name (e.g. office)", &app.new_profile_name)
+            .on_input(Message::NewProfileNameChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(140.0))
+            .style(dark_text_input_style),
+        text_input(match_placeholder, &app.new_profile_match)
+            .on_input(Message::NewProfileMatchChanged)
+            .on_submit(Message::AddProfile)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(180.0))
+            .style(dark_text_input_style),
+        button(white_text("Add", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press(Message::AddProfile),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let detected = match (&app.network.ssid, &app.network.hostname) {
+        (Some(ssid), Some(host)) => format!("Here: Wi-Fi \"{ssid}\", host \"{host}\""),
+        (None, Some(host)) => format!("Here: host \"{host}\" (no Wi-Fi detected)"),
+        (Some(ssid), None) => format!("Here: Wi-Fi \"{ssid}\""),
+        (None, None) => "Detecting network…".to_string(),
+    };
+
+    container(
+        column![
+            setting_row("Output Device", device_controls.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Also Play On", mirror_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            hint_text("Plays to a second device at the same time, e.g. a loopback device for recording."),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row(
+                "Virtual Mic",
+                checkbox(output.virtual_mic)
+                    .label("Speak into a virtual microphone")
+                    .on_toggle(Message::VirtualMicToggled)
+                    .style(white_checkbox_style)
+                    .into(),
+            ),
+            Space::new().height(Length::Fixed(8.0)),
+            virtual_mic_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Volume", volume_controls.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Channels", channel_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            hint_text("For single-ear headsets: speech plays on one side of the output device only."),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row(
+                "Loudness",
+                checkbox(output.normalize_loudness)
+                    .label("Play every voice at the same loudness")
+                    .on_toggle(Message::NormalizeLoudnessToggled)
+                    .style(white_checkbox_style)
+                    .into(),
+            ),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row(
+                "Pauses",
+                checkbox(output.shorten_pauses)
+                    .label("Shorten long pauses between sentences")
+                    .on_toggle(Message::ShortenPausesToggled)
+                    .style(white_checkbox_style)
+                    .into(),
+            ),
+            Space::new().height(Length::Fixed(8.0)),
+            hint_text(output_hint),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row(
+                "Profiles",
+                checkbox(app.profiles_auto_switch)
+                    .label("Switch output by Wi-Fi network or hostname")
+                    .on_toggle(Message::ProfilesAutoSwitchToggled)
+                    .style(white_checkbox_style)
+                    .into(),
+            ),
+            Space::new().height(Length::Fixed(8.0)),
+            profile_list,
+            Space::new().height(Length::Fixed(8.0)),
+            add_controls,
+            Space::new().height(Length::Fixed(8.0)),
+            hint_text(format!("{detected}. New profiles copy the current output settings.")),
+        ]
+        .width(Length::Fill)
+        .padding([12.0, 16.0])
+    )
+    .style(section_style)
+    .into()
+}
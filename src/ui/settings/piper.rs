@@ -0,0 +1,168 @@
+//! Piper configuration UI component (language voices, CPU usage, voice packs)
+
+use iced::widget::{button, checkbox, column, container, radio, row, text, text_input, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::{Message, TTSBackend};
+use crate::providers::synthesis_load::SynthesisLoad;
+use crate::styles::{
+    dark_text_input_style, section_style, transparent_button_style, white_checkbox_style, white_radio_style,
+};
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text<'a>(content: impl text::IntoFragment<'a>, size: u32) -> text::Text<'a> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// Dimmed helper text below a row.
+fn hint_text<'a>(content: impl text::IntoFragment<'a>) -> text::Text<'a> {
+    text(content)
+        .size(11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        })
+}
+
+/// One labeled row of the section (label column matches the other settings sections).
+fn setting_row<'a>(label: &'a str, controls: Element<'a, Message>) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 14))
+            .width(Length::Fixed(120.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(16.0)),
+        container(controls)
+            .width(Length::Fill)
+            .align_x(Alignment::Start),
+    ]
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Create the Piper settings (only shown when Piper is selected)
+pub fn piper_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    if app.selected_backend != TTSBackend::Piper {
+        return column![].spacing(0).into();
+    }
+
+    let mixed_checkbox = checkbox(app.mixed_language)
+        .label("Read passages in other languages with their own voice")
+        .on_toggle(Message::MixedLanguageToggled)
+        .style(white_checkbox_style);
+
+    let auto_checkbox = checkbox(app.auto_language)
+        .label("Read each text with the voice for its language")
+        .on_toggle(Message::AutoLanguageToggled)
+        .style(white_checkbox_style);
+
+    let mut voice_list = column![].spacing(4);
+    if app.mixed_language || app.auto_language {
+        for (language, voice_key) in &app.language_voices {
+            voice_list = voice_list.push(
+                row![
+                    white_text(format!("{} — {voice_key}", language.to_uppercase()), 12).width(Length::Fill),
+                    button(white_text("✕", 12))
+                        .style(transparent_button_style)
+                        .padding([0.0, 4.0])
+                        .on_press(Message::RemoveLanguageVoice(language.clone())),
+                ]
+                .align_y(Alignment::Center),
+            );
+        }
+        voice_list = voice_list.push(hint_text(
+            "Selecting a voice makes it the voice for its language; other languages use the current voice.",
+        ));
+    }
+
+    let compare_controls = text_input("Voice key, e.g. en_US-amy-medium", &app.compare_voice)
+        .on_input(Message::CompareVoiceChanged)
+        .size(13)
+        .padding([6.0, 8.0])
+        .width(Length::Fixed(280.0))
+        .style(dark_text_input_style);
+    let compare_hint: Element<'a, Message> = if app.compare_voice.is_empty()
+        || crate::voices::download::is_voice_downloaded(&app.compare_voice)
+    {
+        hint_text("Press B while reading to hear the next sentence in this voice; the reading then carries on.").into()
+    } else {
+        text("This voice isn't downloaded yet.")
+            .size(11)
+            .style(|_theme| iced::widget::text::Style {
+                color: Some(Color::from_rgb(1.0, 0.45, 0.45)),
+            })
+            .into()
+    };
+
+    let load_selected = Some(app.synthesis_load);
+    let load_controls = row![
+        radio("Normal", SynthesisLoad::Normal, load_selected, Message::SynthesisLoadSelected).style(white_radio_style),
+        radio("Background", SynthesisLoad::Background, load_selected, Message::SynthesisLoadSelected)
+            .style(white_radio_style),
+        radio("Eco", SynthesisLoad::Eco, load_selected, Message::SynthesisLoadSelected).style(white_radio_style),
+    ]
+    .spacing(16);
+    let load_hint = hint_text(match app.synthesis_load {
+        SynthesisLoad::Normal => "Piper runs at full priority for the quickest start.",
+        SynthesisLoad::Background => "Piper yields to other programs; readings may take a little longer to start.",
+        SynthesisLoad::Eco => "Lowest priority and fewer threads: slower to start, but the machine stays cool and quiet.",
+    });
+
+    // Import/export need a path; both are disabled while one is running
+    let has_path = !app.voice_pack_path.trim().is_empty() && !app.voice_pack_busy;
+    let pack_controls = row![
+        text_input("Path to a .tar or .tar.gz voice pack", &app.voice_pack_path)
+            .on_input(Message::VoicePackPathChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(280.0))
+            .style(dark_text_input_style),
+        button(white_text("Import", 12))
+            .style(transparent_button_style)
+            .padding([4.0, 8.0])
+            .on_press_maybe(has_path.then_some(Message::ImportVoicePack)),
+        button(white_text("Export", 12))
+            .style(transparent_button_style)
+            .padding([4.0, 8.0])
+            .on_press_maybe(has_path.then_some(Message::ExportVoicePack)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let pack_hint: Element<'a, Message> = match &app.voice_pack_status {
+        Some(Err(e)) => text(e.as_str())
+            .size(11)
+            .style(|_theme| iced::widget::text::Style {
+                color: Some(Color::from_rgb(1.0, 0.45, 0.45)),
+            })
+            .into(),
+        Some(Ok(message)) => hint_text(message.as_str()).into(),
+        None => hint_text("Install voices on machines without internet access, or export the installed ones to a pack.").into(),
+    };
+
+    container(
+        container(
+            column![
+                setting_row("Mixed Languages", mixed_checkbox.into()),
+                setting_row("Auto Language", auto_checkbox.into()),
+                voice_list,
+                setting_row("Compare Voice", compare_controls.into()),
+                compare_hint,
+                setting_row("CPU Usage", load_controls.into()),
+                load_hint,
+                setting_row("Voice Packs", pack_controls.into()),
+                pack_hint,
+            ]
+            .spacing(8)
+            .padding([12.0, 16.0]),
+        )
+        .width(Length::Fill)
+        .style(section_style),
+    )
+    .padding([0, 16])
+    .width(Length::Fill)
+    .into()
+}
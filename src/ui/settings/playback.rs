@@ -0,0 +1,475 @@
+//! Playback configuration UI component
+
+use iced::widget::{button, checkbox, column, container, radio, row, slider, text, text_input, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::break_reminder::{BREAK_INTERVAL_PRESETS, DEFAULT_BREAK_MESSAGE};
+use crate::model::{Message, FINISH_TARGET_PRESETS, IDLE_RELEASE_PRESETS, LARGE_TEXT_PRESETS, MAX_PITCH_SEMITONES};
+use crate::providers::word_timing::{PreRoll, PRE_ROLL_PRESETS};
+use crate::queue::{EndOfReading, QUEUE_PAUSE_PRESETS};
+use crate::speed_calibration::CALIBRATION_SPEEDS;
+use crate::styles::{
+    circle_button_style, dark_text_input_style, section_style, white_checkbox_style, white_radio_style,
+};
+use crate::system::{TranscriptFormat, TranscriptMode};
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text<'a>(content: impl text::IntoFragment<'a>, size: u32) -> text::Text<'a> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// One labeled row of the section (label column matches the other settings sections).
+fn setting_row<'a>(label: &'a str, controls: Element<'a, Message>) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 14))
+            .width(Length::Fixed(120.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(16.0)),
+        container(controls)
+            .width(Length::Fill)
+            .align_x(Alignment::Start),
+    ]
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// A small button of the section.
+fn small_button(label: &str, message: Option<Message>) -> Element<'_, Message> {
+    button(white_text(label, 12))
+        .style(circle_button_style)
+        .padding([6.0, 12.0])
+        .on_press_maybe(message)
+        .into()
+}
+
+/// Calibration wizard steps, or the button starting it and the speeds calibrated so far.
+fn speed_calibration_controls(app: &crate::model::App) -> Element<'_, Message> {
+    let Some(calibration) = &app.speed_calibration else {
+        let mut adjustments: Vec<(&String, &f32)> = app.language_speeds.iter().collect();
+        adjustments.sort_by(|a, b| a.0.cmp(b.0));
+        let mut controls = column![row![
+            small_button("Calibrate…", Some(Message::StartSpeedCalibration)),
+            white_text(format!("Default speed {}×", app.playback_speed), 13),
+        ]
+        .spacing(12)
+        .align_y(Alignment::Center)]
+        .spacing(6);
+        for (language, adjustment) in adjustments {
+            let name = crate::system::language_name(language).unwrap_or(language);
+            controls = controls.push(
+                row![
+                    white_text(format!("{name}: {:.0}% of the default speed", adjustment * 100.0), 13),
+                    small_button("Reset", Some(Message::ResetLanguageSpeed(language.clone()))),
+                ]
+                .spacing(12)
+                .align_y(Alignment::Center),
+            );
+        }
+        return controls.into();
+    };
+
+    let fastest = calibration.step + 1 >= CALIBRATION_SPEEDS.len();
+    let language = crate::system::language_name(&calibration.language).unwrap_or(&calibration.language);
+    column![
+        row![
+            white_text(format!("{language} sample at {}×", calibration.speed()), 13),
+            small_button("Replay", Some(Message::ReplaySpeedSample)),
+            small_button("Faster", (!fastest).then_some(Message::SpeedCalibrationFaster)),
+            small_button("Use This Speed", Some(Message::FinishSpeedCalibration)),
+            small_button("Too Fast", (calibration.step > 0).then_some(Message::SpeedCalibrationTooFast)),
+            small_button("Cancel", Some(Message::CancelSpeedCalibration)),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+        checkbox(calibration.make_default)
+            .label(format!("Make it the default speed (uncheck to only adjust {language} voices)"))
+            .on_toggle(Message::SpeedCalibrationDefaultToggled)
+            .style(white_checkbox_style),
+    ]
+    .spacing(8)
+    .into()
+}
+
+/// Create the playback settings section for the settings window
+pub fn playback_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    let speed_hint = white_text(
+        "Go faster while the sample is easy to follow; \"Too Fast\" keeps the speed before the current one.",
+        11,
+    )
+    .style(|_theme| iced::widget::text::Style {
+        color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+    });
+
+    let pitch_label = match app.pitch_semitones {
+        0.0 => "Voice default".to_string(),
+        semitones => format!("{semitones:+} semitones"),
+    };
+    let pitch_controls = row![
+        slider(-MAX_PITCH_SEMITONES..=MAX_PITCH_SEMITONES, app.pitch_semitones, Message::PitchChanged)
+            .step(1.0)
+            .on_release(Message::PitchReleased)
+            .width(Length::Fixed(200.0)),
+        text(pitch_label)
+            .size(13)
+            .style(|_theme| iced::widget::text::Style {
+                color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+            }),
+    ]
+    .spacing(12)
+    .align_y(Alignment::Center);
+
+    let selected = Some(app.finish_target_minutes);
+    let target_controls = FINISH_TARGET_PRESETS.iter().fold(
+        row![radio("Off", None, selected, Message::FinishTargetSelected).style(white_radio_style)].spacing(16),
+        |controls, &minutes| {
+            controls.push(
+                radio(format!("{minutes} min"), Some(minutes), selected, Message::FinishTargetSelected)
+                    .style(white_radio_style),
+            )
+        },
+    );
+
+    let hint = white_text("Suggests a faster speed under the speed badge when reading would run longer.", 11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    let pause_selected = Some(app.queue_pause_seconds);
+    let pause_controls = QUEUE_PAUSE_PRESETS.iter().fold(row![].spacing(16), |controls, &seconds| {
+        let label = if seconds == 0 { "None".to_string() } else { format!("{seconds} s") };
+        controls.push(
+            radio(label, seconds, pause_selected, Message::QueuePauseSelected).style(white_radio_style),
+        )
+    });
+
+    let end_selected = Some(app.end_of_reading);
+    let end_controls = [
+        ("Close", EndOfReading::CloseWindow),
+        ("Stay open", EndOfReading::StayOpen),
+        ("Next in queue", EndOfReading::NextInQueue),
+        ("Run hook", EndOfReading::RunHook),
+        ("Hide to tray", EndOfReading::HideToTray),
+    ]
+    .into_iter()
+    .fold(row![].spacing(16), |controls, (label, action)| {
+        controls.push(radio(label, action, end_selected, Message::EndOfReadingSelected).style(white_radio_style))
+    });
+
+    let end_hook = text_input("Command, e.g. notify-send \"Done reading\"", &app.end_of_reading_hook)
+        .on_input_maybe((app.end_of_reading == EndOfReading::RunHook).then_some(Message::EndOfReadingHookChanged))
+        .size(13)
+        .padding([6.0, 8.0])
+        .width(Length::Fixed(380.0))
+        .style(dark_text_input_style);
+
+    let end_hint = white_text(
+        match app.end_of_reading {
+            EndOfReading::CloseWindow => "Queued items are read first; the window closes after the last one.",
+            EndOfReading::StayOpen => "Play reads the text again; queued items wait for ⏭.",
+            EndOfReading::NextInQueue => "Queued items are read first; the window stays open after the last one.",
+            EndOfReading::RunHook => "Runs the command after each reading; queued items follow, then the window closes.",
+            EndOfReading::HideToTray => "Queued items are read first; the window then goes to the tray.",
+        },
+        11,
+    )
+    .style(|_theme| iced::widget::text::Style {
+        color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+    });
+
+    let pre_roll_selected = Some(app.pre_roll);
+    let pre_roll_controls = PRE_ROLL_PRESETS.iter().fold(
+        row![
+            radio("Off", PreRoll::Off, pre_roll_selected, Message::PreRollSelected).style(white_radio_style),
+            radio("Sentence", PreRoll::SentenceStart, pre_roll_selected, Message::PreRollSelected)
+                .style(white_radio_style),
+        ]
+        .spacing(16),
+        |controls, &seconds| {
+            controls.push(
+                radio(format!("{seconds} s"), PreRoll::Seconds(seconds), pre_roll_selected, Message::PreRollSelected)
+                    .style(white_radio_style),
+            )
+        },
+    );
+
+    let pre_roll_hint = white_text("Backs up when resuming or skipping, so the reading doesn't pick up mid-word.", 11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    let skim_control = checkbox(app.skim_mode)
+        .label("Read only headings and the first sentence of each paragraph")
+        .on_toggle(Message::SkimModeToggled)
+        .style(white_checkbox_style);
+
+    let skim_hint = white_text("Press F while skimming to read the rest in full from the current paragraph.", 11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    let review_control = checkbox(app.review_before_reading)
+        .label("Show selected text for editing before reading it")
+        .on_toggle(Message::ReviewBeforeReadingToggled)
+        .style(white_checkbox_style);
+
+    let review_hint = white_text("Trim long selections before they are synthesized; Natural Reading runs after.", 11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    let break_selected = Some(app.break_interval_minutes);
+    let break_controls = BREAK_INTERVAL_PRESETS.iter().fold(
+        row![radio("Off", None, break_selected, Message::BreakIntervalSelected).style(white_radio_style)].spacing(16),
+        |controls, &minutes| {
+            controls.push(
+                radio(format!("{minutes} min"), Some(minutes), break_selected, Message::BreakIntervalSelected)
+                    .style(white_radio_style),
+            )
+        },
+    );
+
+    let idle_selected = Some(app.idle_release_minutes);
+    let idle_controls = IDLE_RELEASE_PRESETS.iter().fold(
+        row![radio("Never", None, idle_selected, Message::IdleReleaseSelected).style(white_radio_style)].spacing(16),
+        |controls, &minutes| {
+            controls.push(
+                radio(format!("{minutes} min"), Some(minutes), idle_selected, Message::IdleReleaseSelected)
+                    .style(white_radio_style),
+            )
+        },
+    );
+
+    let large_selected = Some(app.large_text_limit_kb);
+    let large_controls = LARGE_TEXT_PRESETS.iter().fold(
+        row![radio("Off", None, large_selected, Message::LargeTextLimitSelected).style(white_radio_style)].spacing(16),
+        |controls, &kb| {
+            controls.push(
+                radio(format!("{kb} KB"), Some(kb), large_selected, Message::LargeTextLimitSelected)
+                    .style(white_radio_style),
+            )
+        },
+    );
+
+    let large_hint = white_text("Larger selections ask first, offering to read only their first paragraphs.", 11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    let idle_hint = white_text("Frees the audio device, voice model and cached audio until the next reading.", 11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    let break_message = text_input(DEFAULT_BREAK_MESSAGE, &app.break_message)
+        .on_input(Message::BreakMessageChanged)
+        .size(13)
+        .padding([6.0, 8.0])
+        .width(Length::Fixed(380.0))
+        .style(dark_text_input_style);
+
+    let break_hint = white_text("Spoken when the current reading ends; {minutes} is replaced with the time listened.", 11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    let quiet_controls = row![
+        checkbox(app.quiet_hours_enabled)
+            .label("Enabled")
+            .on_toggle(Message::QuietHoursToggled)
+            .style(white_checkbox_style),
+        text_input("22:00-07:00, 12:30-13:00", &app.quiet_hours_input)
+            .on_input(Message::QuietHoursChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(220.0))
+            .style(dark_text_input_style),
+    ]
+    .spacing(16)
+    .align_y(Alignment::Center);
+
+    let quiet_hint_text = match &app.quiet_hours_error {
+        Some(error) => error.clone(),
+        None if app.quiet_hours_active => "Quiet hours are on now: automatic reads wait in the queue.".to_string(),
+        None => "Automatic reads are queued silently during these hours.".to_string(),
+    };
+    let quiet_hint_color = if app.quiet_hours_error.is_some() {
+        Color::from_rgb(1.0, 0.45, 0.45)
+    } else {
+        Color::from_rgba(1.0, 1.0, 1.0, 0.6)
+    };
+    let quiet_hint = text(quiet_hint_text)
+        .size(11)
+        .style(move |_theme| iced::widget::text::Style {
+            color: Some(quiet_hint_color),
+        });
+
+    let default_folder = crate::system::default_recordings_folder().display().to_string();
+    let recording_controls = row![
+        checkbox(app.save_readings)
+            .label("Save")
+            .on_toggle(Message::SaveReadingsToggled)
+            .style(white_checkbox_style),
+        text_input(&default_folder, &app.recordings_folder)
+            .on_input(Message::RecordingsFolderChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(300.0))
+            .style(dark_text_input_style),
+    ]
+    .spacing(16)
+    .align_y(Alignment::Center);
+
+    let recording_hint = white_text("Each reading is also written to a WAV file, with its text alongside.", 11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    let handoff_controls = text_input("Folder synced to your phone (Syncthing, Dropbox)", &app.handoff_folder)
+        .on_input(Message::HandoffFolderChanged)
+        .size(13)
+        .padding([6.0, 8.0])
+        .width(Length::Fixed(380.0))
+        .style(dark_text_input_style);
+
+    let handoff_hint = white_text("Each reading is dropped there as MP3 (via ffmpeg or lame, else WAV) with a JSON description.", 11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    let webhook_controls = row![
+        text_input("https://dashboard.local/hooks/listening", &app.listen_webhook)
+            .on_input(Message::ListenWebhookChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(300.0))
+            .style(dark_text_input_style),
+        checkbox(app.listen_webhook_text)
+            .label("Include text")
+            .on_toggle(Message::ListenWebhookTextToggled)
+            .style(white_checkbox_style),
+    ]
+    .spacing(16)
+    .align_y(Alignment::Center);
+
+    let (webhook_hint_text, webhook_hint_color) = match &app.listen_webhook_status {
+        Some(Ok(message)) => (message.clone(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        None => (
+            "Each finished reading is POSTed there as JSON: duration, word count and source.".to_string(),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        ),
+    };
+    let webhook_hint = text(webhook_hint_text)
+        .size(11)
+        .style(move |_theme| iced::widget::text::Style {
+            color: Some(webhook_hint_color),
+        });
+
+    let transcript_selected = Some(app.transcript_mode);
+    let transcript_controls = row![
+        radio("Off", TranscriptMode::Off, transcript_selected, Message::TranscriptModeSelected)
+            .style(white_radio_style),
+        radio("Excerpt", TranscriptMode::Excerpt, transcript_selected, Message::TranscriptModeSelected)
+            .style(white_radio_style),
+        radio("Full text", TranscriptMode::FullText, transcript_selected, Message::TranscriptModeSelected)
+            .style(white_radio_style),
+    ]
+    .spacing(16);
+
+    let format_selected = Some(app.transcript_format);
+    let transcript_format = row![
+        radio("Markdown", TranscriptFormat::Markdown, format_selected, Message::TranscriptFormatSelected)
+            .style(white_radio_style),
+        radio("JSON Lines", TranscriptFormat::Jsonl, format_selected, Message::TranscriptFormatSelected)
+            .style(white_radio_style),
+    ]
+    .spacing(16);
+
+    let transcript_hint = text(format!(
+        "Each reading is logged with its time, source and word count to a daily file in {}.",
+        crate::system::default_transcripts_folder().display()
+    ))
+    .size(11)
+    .style(|_theme| iced::widget::text::Style {
+        color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+    });
+
+    container(
+        column![
+            setting_row("Speed", speed_calibration_controls(app)),
+            Space::new().height(Length::Fixed(8.0)),
+            speed_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Pitch", pitch_controls.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Finish Within", target_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Queue Pause", pause_controls.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("At the End", end_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            setting_row("", end_hook.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            end_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Pre-roll", pre_roll_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            pre_roll_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Skim", skim_control.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            skim_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Review", review_control.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            review_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Large Texts", large_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            large_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Idle Release", idle_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            idle_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Break Reminder", break_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            setting_row("", break_message.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            break_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Quiet Hours", quiet_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            quiet_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Save Readings", recording_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            recording_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Phone Folder", handoff_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            handoff_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Listen Webhook", webhook_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            webhook_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Transcript", transcript_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            setting_row("", transcript_format.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            transcript_hint,
+        ]
+        .width(Length::Fill)
+        .padding([12.0, 16.0])
+    )
+    .style(section_style)
+    .into()
+}
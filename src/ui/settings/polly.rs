@@ -0,0 +1,93 @@
+//! AWS Polly account and cost configuration UI component
+
+use iced::widget::{column, container, pick_list, radio, row, text, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::{Message, TTSBackend, AWS_PROFILE_FROM_ENV, AWS_REGION_FROM_CONFIG, POLLY_COST_WARNING_PRESETS};
+use crate::styles::{dark_menu_style, dark_pick_list_style, section_style, white_radio_style};
+use crate::voices::aws::POLLY_REGIONS;
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text(content: &str, size: u32) -> text::Text<'_> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// One labeled row of the section (label column matches the other settings sections).
+fn setting_row<'a>(label: &'a str, controls: Element<'a, Message>) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 14))
+            .width(Length::Fixed(120.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(16.0)),
+        container(controls)
+            .width(Length::Fill)
+            .align_x(Alignment::Start),
+    ]
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Create the AWS Polly account settings (only shown when AWS Polly is selected)
+pub fn polly_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    if app.selected_backend != TTSBackend::AwsPolly {
+        return column![].spacing(0).into();
+    }
+
+    let mut rows = column![].spacing(12);
+
+    // The profile picker only makes sense when profiles were found
+    if !app.aws_profiles.is_empty() {
+        let mut profiles = vec![AWS_PROFILE_FROM_ENV.to_string()];
+        profiles.extend(app.aws_profiles.iter().cloned());
+        let selected = app.aws_profile.clone().unwrap_or_else(|| AWS_PROFILE_FROM_ENV.to_string());
+        let profile_controls = pick_list(profiles, Some(selected), Message::AwsProfileSelected)
+            .text_size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(240.0))
+            .style(dark_pick_list_style)
+            .menu_style(dark_menu_style);
+        rows = rows.push(setting_row("AWS Profile", profile_controls.into()));
+    }
+
+    let mut regions = vec![AWS_REGION_FROM_CONFIG.to_string()];
+    regions.extend(POLLY_REGIONS.iter().map(|region| region.to_string()));
+    let selected_region = app.aws_region.clone().unwrap_or_else(|| AWS_REGION_FROM_CONFIG.to_string());
+    let region_controls = pick_list(regions, Some(selected_region), Message::AwsRegionSelected)
+        .text_size(13)
+        .padding([6.0, 8.0])
+        .width(Length::Fixed(240.0))
+        .style(dark_pick_list_style)
+        .menu_style(dark_menu_style);
+    rows = rows.push(setting_row("AWS Region", region_controls.into()));
+
+    let selected_cost = Some(app.polly_cost_warning_cents);
+    let cost_controls = POLLY_COST_WARNING_PRESETS.iter().fold(
+        row![radio("Off", None, selected_cost, Message::PollyCostWarningSelected).style(white_radio_style)].spacing(16),
+        |controls, &cents| {
+            controls.push(
+                radio(
+                    format!("${:.2}", f64::from(cents) / 100.0),
+                    Some(cents),
+                    selected_cost,
+                    Message::PollyCostWarningSelected,
+                )
+                .style(white_radio_style),
+            )
+        },
+    );
+    rows = rows.push(setting_row("Cost Warning", cost_controls.into()));
+
+    container(
+        container(rows.padding([12.0, 16.0]))
+            .width(Length::Fill)
+            .style(section_style),
+    )
+    .padding([0, 16])
+    .width(Length::Fill)
+    .into()
+}
@@ -0,0 +1,126 @@
+//! Read-later service (Wallabag, Readwise Reader) configuration UI component
+
+use iced::widget::{button, column, container, radio, row, text, text_input, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::Message;
+use crate::styles::{circle_button_style, dark_text_input_style, section_style, white_radio_style};
+use crate::system::ReadLaterService;
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text(content: &str, size: u32) -> text::Text<'_> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// One labeled row of the section (label column matches the other settings sections).
+fn setting_row<'a>(label: &'a str, controls: Element<'a, Message>) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 14))
+            .width(Length::Fixed(120.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(16.0)),
+        container(controls)
+            .width(Length::Fill)
+            .align_x(Alignment::Start),
+    ]
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Text input for one credential; secrets are masked.
+fn credential_input<'a>(
+    placeholder: &'a str,
+    value: &'a str,
+    secure: bool,
+    on_input: fn(String) -> Message,
+) -> Element<'a, Message> {
+    text_input(placeholder, value)
+        .on_input(on_input)
+        .secure(secure)
+        .size(13)
+        .padding([6.0, 8.0])
+        .width(Length::Fixed(328.0))
+        .style(dark_text_input_style)
+        .into()
+}
+
+/// Create the read-later settings section for the settings window
+pub fn read_later_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    let settings = &app.read_later;
+    let service = Some(settings.service);
+    let service_controls = row![
+        radio("Wallabag", ReadLaterService::Wallabag, service, Message::ReadLaterServiceSelected)
+            .style(white_radio_style),
+        radio("Readwise Reader", ReadLaterService::Readwise, service, Message::ReadLaterServiceSelected)
+            .style(white_radio_style),
+    ]
+    .spacing(16);
+
+    let credentials: Element<'a, Message> = match settings.service {
+        ReadLaterService::Wallabag => column![
+            setting_row(
+                "Server",
+                credential_input("https://app.wallabag.it", &settings.wallabag_url, false, Message::WallabagUrlChanged),
+            ),
+            setting_row(
+                "Client ID",
+                credential_input("API client id", &settings.wallabag_client_id, false, Message::WallabagClientIdChanged),
+            ),
+            setting_row(
+                "Client Secret",
+                credential_input(
+                    "API client secret",
+                    &settings.wallabag_client_secret,
+                    true,
+                    Message::WallabagClientSecretChanged,
+                ),
+            ),
+            setting_row(
+                "Username",
+                credential_input("Username", &settings.wallabag_username, false, Message::WallabagUsernameChanged),
+            ),
+            setting_row(
+                "Password",
+                credential_input("Password", &settings.wallabag_password, true, Message::WallabagPasswordChanged),
+            ),
+        ]
+        .spacing(8)
+        .into(),
+        ReadLaterService::Readwise => setting_row(
+            "Access Token",
+            credential_input("From readwise.io/access_token", &settings.readwise_token, true, Message::ReadwiseTokenChanged),
+        ),
+    };
+
+    let articles_controls = button(white_text("Open Articles", 12))
+        .style(circle_button_style)
+        .padding([6.0, 12.0])
+        .on_press_maybe(settings.is_configured().then_some(Message::OpenReadLater));
+
+    let hint = text("Credentials are stored in the config file. Articles listened to the end are archived.")
+        .size(11)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        });
+
+    container(
+        column![
+            setting_row("Read Later", service_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            credentials,
+            Space::new().height(Length::Fixed(8.0)),
+            setting_row("", articles_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            hint,
+        ]
+        .width(Length::Fill)
+        .padding([12.0, 16.0])
+    )
+    .style(section_style)
+    .into()
+}
@@ -0,0 +1,173 @@
+//! Model and cache storage configuration UI component
+
+use iced::widget::{button, checkbox, column, container, radio, row, text, text_input, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::Message;
+use crate::providers::audio_cache::{audio_cache_dir, format_megabytes, CACHE_LIMIT_PRESETS};
+use crate::styles::{circle_button_style, dark_text_input_style, section_style, white_checkbox_style, white_radio_style};
+use crate::system::{captures_dir, CAPTURE_RETENTION_PRESETS};
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text(content: &str, size: u32) -> text::Text<'_> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// One labeled row of the section (label column matches the other settings sections).
+fn setting_row<'a>(label: &'a str, controls: Element<'a, Message>) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 14))
+            .width(Length::Fixed(120.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(16.0)),
+        container(controls)
+            .width(Length::Fill)
+            .align_x(Alignment::Start),
+    ]
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Create the storage settings section for the settings window
+pub fn storage_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    // Moving is offered once the typed folder differs from the current one
+    let changed = app.storage_folder_input.trim() != app.storage_folder.trim();
+    let folder_controls = row![
+        text_input("Default data and cache folders", &app.storage_folder_input)
+            .on_input(Message::StorageFolderChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(300.0))
+            .style(dark_text_input_style),
+        button(white_text(if app.storage_moving { "Moving…" } else { "Move Files" }, 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((changed && !app.storage_moving).then_some(Message::MoveStorage)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let current_folder = Some(app.storage_folder.trim())
+        .filter(|folder| !folder.is_empty())
+        .map(std::path::Path::new);
+    let (status_text, status_color) = match &app.storage_status {
+        Some(Ok(message)) => (message.clone(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        None => (
+            format!(
+                "Voice models are in {}. Moving takes the installed voices and caches along.",
+                crate::storage::models_dir_in(current_folder).display()
+            ),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        ),
+    };
+
+    let limit_selected = Some(app.audio_cache_limit_mb);
+    let cache_controls = CACHE_LIMIT_PRESETS.iter().fold(
+        row![checkbox(app.audio_cache_enabled)
+            .label("Enabled")
+            .on_toggle(Message::AudioCacheToggled)
+            .style(white_checkbox_style)]
+        .spacing(16)
+        .align_y(Alignment::Center),
+        |controls, &limit_mb| {
+            let label = if limit_mb >= 1000 { format!("{} GB", limit_mb / 1000) } else { format!("{limit_mb} MB") };
+            controls.push(
+                radio(label, limit_mb, limit_selected, Message::AudioCacheLimitSelected).style(white_radio_style),
+            )
+        },
+    );
+    let cache_controls = cache_controls.push(
+        button(white_text("Clear Cache", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((app.audio_cache_size != Some(0)).then_some(Message::ClearAudioCache)),
+    );
+
+    let (cache_text, cache_color) = match &app.audio_cache_status {
+        Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        status => {
+            let usage = app
+                .audio_cache_size
+                .map(|bytes| format!("{} used in {}.", format_megabytes(bytes), audio_cache_dir().display()))
+                .unwrap_or_else(|| format!("Kept in {}.", audio_cache_dir().display()));
+            let cleared = match status {
+                Some(Ok(message)) => format!("{message} "),
+                _ => String::new(),
+            };
+            (
+                format!("{cleared}Readings play instantly from the cache when read again with the same voice. {usage}"),
+                Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+            )
+        }
+    };
+
+    let retention_selected = Some(app.capture_retention_hours);
+    let capture_controls = CAPTURE_RETENTION_PRESETS.iter().fold(
+        row![radio("Delete after OCR", None, retention_selected, Message::CaptureRetentionSelected)
+            .style(white_radio_style)]
+        .spacing(16)
+        .align_y(Alignment::Center),
+        |controls, &hours| {
+            let label = if hours >= 24 { format!("Keep {} d", hours / 24) } else { format!("Keep {hours} h") };
+            controls.push(
+                radio(label, Some(hours), retention_selected, Message::CaptureRetentionSelected)
+                    .style(white_radio_style),
+            )
+        },
+    );
+    let capture_controls = capture_controls.push(
+        button(white_text("Clear Captures", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press(Message::ClearCaptureCache),
+    );
+
+    let (capture_text, capture_color) = match &app.capture_cache_status {
+        Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        status => {
+            let cleared = match status {
+                Some(Ok(message)) => format!("{message} "),
+                _ => String::new(),
+            };
+            (
+                format!(
+                    "{cleared}Screenshots for OCR are only readable by you, in {}. Keep them only to debug text recognition.",
+                    captures_dir().display()
+                ),
+                Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+            )
+        }
+    };
+
+    container(
+        column![
+            setting_row("Storage", folder_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            text(status_text).size(11).style(move |_theme| iced::widget::text::Style {
+                color: Some(status_color),
+            }),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Audio Cache", cache_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            text(cache_text).size(11).style(move |_theme| iced::widget::text::Style {
+                color: Some(cache_color),
+            }),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Captures", capture_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            text(capture_text).size(11).style(move |_theme| iced::widget::text::Style {
+                color: Some(capture_color),
+            }),
+        ]
+        .width(Length::Fill)
+        .padding([12.0, 16.0]),
+    )
+    .style(section_style)
+    .into()
+}
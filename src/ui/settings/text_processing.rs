@@ -0,0 +1,364 @@
+//! Text processing configuration UI component
+
+use iced::widget::{button, checkbox, column, container, radio, row, scrollable, text, text_editor, text_input, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::Message;
+use crate::styles::{
+    circle_button_style, dark_text_input_style, section_style, transparent_button_style, white_checkbox_style,
+    white_radio_style,
+};
+use crate::system::{apply_filter_rules, LinkReading, ListEnumeration, TableReading, UrlReading, ABBREVIATION_RULES};
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text(content: &str, size: u32) -> text::Text<'_> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// One labeled row of the section (label column matches the other settings sections).
+fn setting_row<'a>(label: &'a str, controls: Element<'a, Message>) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 14))
+            .width(Length::Fixed(120.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(16.0)),
+        container(controls)
+            .width(Length::Fill)
+            .align_x(Alignment::Start),
+    ]
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Create the text processing settings section for the settings window
+pub fn text_processing_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    let list_selected = Some(app.text_processing.list_enumeration);
+    let list_controls = row![
+        radio("Off", ListEnumeration::Off, list_selected, Message::ListEnumerationSelected)
+            .style(white_radio_style),
+        radio("First, second, …", ListEnumeration::Ordinal, list_selected, Message::ListEnumerationSelected)
+            .style(white_radio_style),
+        radio("Bullet:", ListEnumeration::Bullet, list_selected, Message::ListEnumerationSelected)
+            .style(white_radio_style),
+    ]
+    .spacing(16);
+
+    let link_selected = Some(app.text_processing.link_reading);
+    let link_controls = row![
+        radio("Text only", LinkReading::Text, link_selected, Message::LinkReadingSelected)
+            .style(white_radio_style),
+        radio("Text + \"link\"", LinkReading::WithLink, link_selected, Message::LinkReadingSelected)
+            .style(white_radio_style),
+        radio("Text + domain", LinkReading::WithDomain, link_selected, Message::LinkReadingSelected)
+            .style(white_radio_style),
+    ]
+    .spacing(16);
+
+    let url_selected = Some(app.text_processing.url_reading);
+    let url_controls = row![
+        radio("As written", UrlReading::Full, url_selected, Message::UrlReadingSelected).style(white_radio_style),
+        radio("Without query", UrlReading::WithoutQuery, url_selected, Message::UrlReadingSelected)
+            .style(white_radio_style),
+        radio("\"Link to\" domain", UrlReading::Domain, url_selected, Message::UrlReadingSelected)
+            .style(white_radio_style),
+    ]
+    .spacing(16);
+
+    let table_selected = Some(app.text_processing.table_reading);
+    let table_controls = row![
+        radio("Row by row", TableReading::Rows, table_selected, Message::TableReadingSelected)
+            .style(white_radio_style),
+        radio("Skip", TableReading::Skip, table_selected, Message::TableReadingSelected).style(white_radio_style),
+        radio("As written", TableReading::AsWritten, table_selected, Message::TableReadingSelected)
+            .style(white_radio_style),
+    ]
+    .spacing(16);
+
+    let image_control = checkbox(app.text_processing.read_image_alt_text)
+        .label("Read image alt text (\"Image: …\")")
+        .on_toggle(Message::ImageAltTextToggled)
+        .style(white_checkbox_style);
+
+    let html_control = checkbox(app.text_processing.strip_html)
+        .label("Read copied HTML as the page it shows (tags stripped, images and links announced)")
+        .on_toggle(Message::StripHtmlToggled)
+        .style(white_checkbox_style);
+
+    // Four rules per row, each checkbox labeled with the written form
+    let disabled = &app.text_processing.disabled_abbreviations;
+    let mut abbreviation_rows = column![].spacing(6);
+    for rules in ABBREVIATION_RULES.chunks(4) {
+        let mut rule_row = row![].spacing(16);
+        for rule in rules {
+            let enabled = !disabled.iter().any(|written| written.eq_ignore_ascii_case(rule.written));
+            rule_row = rule_row.push(
+                checkbox(enabled)
+                    .label(rule.written)
+                    .on_toggle(move |enabled| Message::AbbreviationToggled(rule.written, enabled))
+                    .style(white_checkbox_style)
+                    .width(Length::Fixed(90.0)),
+            );
+        }
+        abbreviation_rows = abbreviation_rows.push(rule_row);
+    }
+    let abbreviation_hint =
+        text("Checked abbreviations are read in full (\"Dr.\" as \"Doctor\"); lexicon entries take precedence.")
+            .size(11)
+            .style(|_theme| iced::widget::text::Style {
+                color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+            });
+
+    let citation_control = checkbox(app.text_processing.skip_citations)
+        .label("Skip citations (\"[3]\", \"(Smith et al., 2020)\"), footnotes and reference lists")
+        .on_toggle(Message::SkipCitationsToggled)
+        .style(white_checkbox_style);
+
+    let acronym_control = checkbox(app.text_processing.spell_acronyms)
+        .label("Spell out unknown acronyms (\"FBI\" as \"F B I\")")
+        .on_toggle(Message::SpellAcronymsToggled)
+        .style(white_checkbox_style);
+
+    let number_control = checkbox(app.text_processing.localize_numbers)
+        .label("Read numbers and dates the way the voice's language writes them")
+        .on_toggle(Message::LocalizeNumbersToggled)
+        .style(white_checkbox_style);
+
+    let spreadsheet_control = checkbox(app.text_processing.spreadsheet_cells)
+        .label("Read cells selected in a spreadsheet row by row (\"Row 1: Name is Alice, Age is 30\")")
+        .on_toggle(Message::SpreadsheetCellsToggled)
+        .style(white_checkbox_style);
+
+    // Filter rules, in the order they are applied
+    let filter_rules = &app.text_processing.filter_rules;
+    let filter_count = text(format!("{} rules, applied top to bottom", filter_rules.len()))
+        .size(13)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        });
+    let mut filter_list = column![].spacing(4);
+    for (index, rule) in filter_rules.iter().enumerate() {
+        filter_list = filter_list.push(
+            row![
+                checkbox(rule.enabled)
+                    .on_toggle(move |enabled| Message::FilterRuleToggled(index, enabled))
+                    .style(white_checkbox_style),
+                text(format!("/{}/ → \"{}\"", rule.pattern, rule.replacement))
+                    .size(12)
+                    .style(|_theme| iced::widget::text::Style {
+                        color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+                    })
+                    .width(Length::Fill),
+                button(white_text("↑", 12))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press_maybe((index > 0).then_some(Message::MoveFilterRuleUp(index))),
+                button(white_text("✕", 12))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press(Message::DeleteFilterRule(index)),
+            ]
+            .spacing(4)
+            .align_y(Alignment::Center),
+        );
+    }
+    let filter_add_controls = row![
+        text_input("Pattern, e.g. \\[\\d+\\]", &app.new_filter_pattern)
+            .on_input(Message::NewFilterPatternChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(180.0))
+            .style(dark_text_input_style),
+        text_input("Replace with (empty deletes)", &app.new_filter_replacement)
+            .on_input(Message::NewFilterReplacementChanged)
+            .on_submit(Message::AddFilterRule)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(180.0))
+            .style(dark_text_input_style),
+        button(white_text("Add", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((!app.new_filter_pattern.is_empty()).then_some(Message::AddFilterRule)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+    let (filter_hint_text, filter_hint_color) = match &app.filter_error {
+        Some(error) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        None => (
+            "Regular expressions; ^ and $ match at each line, $1 inserts a captured group.".to_string(),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        ),
+    };
+    let filter_hint = text(filter_hint_text)
+        .size(11)
+        .style(move |_theme| iced::widget::text::Style {
+            color: Some(filter_hint_color),
+        });
+    // Try the rules on a sample: what the voice would get, before the other passes
+    let filter_test_input = app.filter_test.text();
+    let filter_test_output = text(apply_filter_rules(filter_test_input.trim_end(), filter_rules))
+        .size(12)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        });
+    let filter_test = column![
+        text_editor(&app.filter_test)
+            .placeholder("Paste sample text to try the rules on")
+            .on_action(Message::FilterTestAction)
+            .size(12)
+            .height(Length::Fixed(72.0)),
+        filter_test_output,
+    ]
+    .spacing(6);
+
+    let lexicon = &app.text_processing.lexicon;
+    let lexicon_count = text(format!("{} entries", lexicon.len()))
+        .size(13)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        });
+    // Grow with the list up to a few rows, then scroll
+    let list_height = (lexicon.len() as f32 * 22.0).min(132.0);
+    let file_controls = row![
+        text_input("Path to .pls or .csv file", &app.lexicon_path)
+            .on_input(Message::LexiconPathChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(240.0))
+            .style(dark_text_input_style),
+        button(white_text("Import", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((!app.lexicon_path.trim().is_empty()).then_some(Message::ImportLexicon)),
+        button(white_text("Export", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe(
+                (!app.lexicon_path.trim().is_empty() && !lexicon.is_empty()).then_some(Message::ExportLexicon)
+            ),
+        button(white_text("Clear", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((!lexicon.is_empty()).then_some(Message::ClearLexicon)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let add_controls = row![
+        text_input("Word", &app.new_lexicon_word)
+            .on_input(Message::NewLexiconWordChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(140.0))
+            .style(dark_text_input_style),
+        text_input("Spoken as", &app.new_lexicon_alias)
+            .on_input(Message::NewLexiconAliasChanged)
+            .on_submit(Message::AddLexiconEntry)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(180.0))
+            .style(dark_text_input_style),
+        button(white_text("Add", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press(Message::AddLexiconEntry),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let mut entry_list = column![].spacing(4);
+    for (index, entry) in lexicon.iter().enumerate() {
+        let spoken = entry
+            .alias
+            .as_deref()
+            .map(|alias| format!("\"{alias}\""))
+            .or_else(|| entry.phoneme.as_deref().map(|phoneme| format!("/{phoneme}/ (not applied)")))
+            .unwrap_or_default();
+        entry_list = entry_list.push(
+            row![
+                text(format!("{} → {spoken}", entry.grapheme))
+                    .size(12)
+                    .style(|_theme| iced::widget::text::Style {
+                        color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+                    })
+                    .width(Length::Fill),
+                button(white_text("✕", 12))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press(Message::DeleteLexiconEntry(index)),
+            ]
+            .align_y(Alignment::Center),
+        );
+    }
+
+    let (status_text, status_color) = match &app.lexicon_status {
+        Some(Ok(message)) => (message.clone(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        None => (
+            "Share dictionaries as PLS (used by most TTS systems) or CSV: grapheme,alias,phoneme.".to_string(),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        ),
+    };
+    let lexicon_hint = text(status_text)
+        .size(11)
+        .style(move |_theme| iced::widget::text::Style {
+            color: Some(status_color),
+        });
+
+    container(
+        column![
+            setting_row("List Items", list_controls.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Links", link_controls.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("URLs", url_controls.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Tables", table_controls.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Images", image_control.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("HTML", html_control.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Citations", citation_control.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Abbreviations", abbreviation_rows.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            abbreviation_hint,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Acronyms", acronym_control.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Numbers", number_control.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Spreadsheets", spreadsheet_control.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Filters", filter_count.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            filter_list,
+            Space::new().height(Length::Fixed(8.0)),
+            filter_add_controls,
+            Space::new().height(Length::Fixed(8.0)),
+            filter_hint,
+            Space::new().height(Length::Fixed(8.0)),
+            filter_test,
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Lexicon", lexicon_count.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            file_controls,
+            Space::new().height(Length::Fixed(8.0)),
+            lexicon_hint,
+            Space::new().height(Length::Fixed(8.0)),
+            scrollable(entry_list).height(Length::Fixed(list_height)),
+            Space::new().height(Length::Fixed(8.0)),
+            add_controls,
+        ]
+        .width(Length::Fill)
+        .padding([12.0, 16.0])
+    )
+    .style(section_style)
+    .into()
+}
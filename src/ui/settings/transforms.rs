@@ -0,0 +1,139 @@
+//! Text transform chain configuration UI component
+
+use iced::widget::{button, column, container, radio, row, text, text_input, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::Message;
+use crate::styles::{
+    circle_button_style, dark_text_input_style, section_style, transparent_button_style, white_radio_style,
+};
+use crate::system::{TransformKind, TransformStep};
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text(content: &str, size: u32) -> text::Text<'_> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// One labeled row of the section (label column matches the other settings sections).
+fn setting_row<'a>(label: &'a str, controls: Element<'a, Message>) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 14))
+            .width(Length::Fixed(120.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(16.0)),
+        container(controls)
+            .width(Length::Fill)
+            .align_x(Alignment::Start),
+    ]
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Create the text transforms settings section for the settings window
+pub fn transforms_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    let steps = &app.text_transforms;
+    let mut step_list = column![].spacing(4);
+    if steps.is_empty() {
+        step_list = step_list.push(white_text("No transforms: text is read after local processing only", 12));
+    }
+    for (index, step) in steps.iter().enumerate() {
+        step_list = step_list.push(
+            row![
+                text(format!("{}. {}", index + 1, step.label()))
+                    .size(12)
+                    .style(|_theme| iced::widget::text::Style {
+                        color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+                    })
+                    .width(Length::Fill),
+                button(white_text("↑", 12))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press_maybe((index > 0).then_some(Message::MoveTransformStepUp(index))),
+                button(white_text("✕", 12))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press(Message::DeleteTransformStep(index)),
+            ]
+            .spacing(4)
+            .align_y(Alignment::Center),
+        );
+    }
+
+    let kind_selected = Some(app.new_transform.kind());
+    let kind_controls = row![
+        radio("Natural Reading", TransformKind::NaturalReading, kind_selected, Message::TransformKindSelected)
+            .style(white_radio_style),
+        radio("OpenAI-compatible", TransformKind::OpenAi, kind_selected, Message::TransformKindSelected)
+            .style(white_radio_style),
+        radio("Ollama", TransformKind::Ollama, kind_selected, Message::TransformKindSelected)
+            .style(white_radio_style),
+        radio("Local rules", TransformKind::LocalRules, kind_selected, Message::TransformKindSelected)
+            .style(white_radio_style),
+    ]
+    .spacing(16);
+
+    let input = |placeholder, value, on_input: fn(String) -> Message| {
+        text_input(placeholder, value)
+            .on_input(on_input)
+            .size(13)
+            .padding([6.0, 8.0])
+            .style(dark_text_input_style)
+    };
+    // Only language model steps have an endpoint to set up
+    let mut step_fields = column![].spacing(6);
+    match &app.new_transform {
+        TransformStep::OpenAi { url, api_key, model, prompt } => {
+            step_fields = step_fields
+                .push(input("Base URL", url, Message::NewTransformUrlChanged))
+                .push(
+                    input("API key (empty for local servers)", api_key, Message::NewTransformApiKeyChanged)
+                        .secure(true),
+                )
+                .push(input("Model", model, Message::NewTransformModelChanged))
+                .push(input("Prompt", prompt, Message::NewTransformPromptChanged));
+        }
+        TransformStep::Ollama { url, model, prompt } => {
+            step_fields = step_fields
+                .push(input("Server URL", url, Message::NewTransformUrlChanged))
+                .push(input("Model", model, Message::NewTransformModelChanged))
+                .push(input("Prompt", prompt, Message::NewTransformPromptChanged));
+        }
+        TransformStep::NaturalReading | TransformStep::LocalRules => {}
+    }
+    let add_button = button(white_text("Add to chain", 12))
+        .style(circle_button_style)
+        .padding([6.0, 12.0])
+        .on_press(Message::AddTransformStep);
+
+    let hint = text(
+        "Steps run top to bottom when Natural Reading is on, each on the previous one's output. \
+         To translate, add a language model step with a prompt such as \"Translate into French\".",
+    )
+    .size(11)
+    .style(|_theme| iced::widget::text::Style {
+        color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+    });
+
+    container(
+        column![
+            setting_row("Transforms", step_list.into()),
+            Space::new().height(Length::Fixed(12.0)),
+            setting_row("Add", kind_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            step_fields,
+            Space::new().height(Length::Fixed(8.0)),
+            add_button,
+            Space::new().height(Length::Fixed(8.0)),
+            hint,
+        ]
+        .width(Length::Fill)
+        .padding([12.0, 16.0])
+    )
+    .style(section_style)
+    .into()
+}
@@ -0,0 +1,112 @@
+//! Watched documents configuration UI component
+
+use iced::widget::{button, column, container, row, scrollable, text, text_input, Space};
+use iced::{Alignment, Color, Element, Length};
+
+use crate::model::Message;
+use crate::styles::{circle_button_style, dark_text_input_style, section_style, transparent_button_style};
+
+/// Helper to create white text with consistent styling (matching view.rs pattern).
+fn white_text<'a>(content: impl text::IntoFragment<'a>, size: u32) -> text::Text<'a> {
+    text(content)
+        .size(size)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        })
+}
+
+/// One labeled row of the section (label column matches the other settings sections).
+fn setting_row<'a>(label: &'a str, controls: Element<'a, Message>) -> Element<'a, Message> {
+    row![
+        container(white_text(label, 14))
+            .width(Length::Fixed(120.0))
+            .align_x(Alignment::Start),
+        Space::new().width(Length::Fixed(16.0)),
+        container(controls)
+            .width(Length::Fill)
+            .align_x(Alignment::Start),
+    ]
+    .align_y(Alignment::Center)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Create the watched documents settings section for the settings window
+pub fn watch_settings_section<'a>(app: &'a crate::model::App) -> Element<'a, Message> {
+    let check_controls = row![
+        white_text(format!("{} watched", app.watched_documents.len()), 13),
+        Space::new().width(Length::Fixed(12.0)),
+        button(white_text(if app.watch_checking { "Checking…" } else { "Check Now" }, 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe(
+                (!app.watched_documents.is_empty() && !app.watch_checking).then_some(Message::CheckWatched),
+            ),
+    ]
+    .align_y(Alignment::Center);
+
+    let add_controls = row![
+        text_input("Page URL or file path", &app.new_watch_source)
+            .on_input(Message::NewWatchSourceChanged)
+            .on_submit(Message::AddWatch)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fixed(328.0))
+            .style(dark_text_input_style),
+        button(white_text("Watch", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((!app.new_watch_source.trim().is_empty()).then_some(Message::AddWatch)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    // Grow with the list up to a few rows, then scroll
+    let list_height = (app.watched_documents.len() as f32 * 22.0).min(132.0);
+    let mut watch_list = column![].spacing(4);
+    for (index, source) in app.watched_documents.iter().enumerate() {
+        watch_list = watch_list.push(
+            row![
+                white_text(crate::system::watch_display_name(source), 12).width(Length::Fill),
+                button(white_text("✕", 12))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press(Message::RemoveWatch(index)),
+            ]
+            .align_y(Alignment::Center),
+        );
+    }
+
+    let (status_text, status_color) = match &app.watch_status {
+        Some(Ok(message)) => (message.clone(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        None => (
+            format!(
+                "Checked every {} minutes; only paragraphs added since the last check are read.",
+                crate::system::WATCH_INTERVAL.as_secs() / 60
+            ),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        ),
+    };
+    let watch_hint = text(status_text)
+        .size(11)
+        .style(move |_theme| iced::widget::text::Style {
+            color: Some(status_color),
+        });
+
+    container(
+        column![
+            setting_row("Watch Changes", check_controls.into()),
+            Space::new().height(Length::Fixed(8.0)),
+            watch_hint,
+            Space::new().height(Length::Fixed(8.0)),
+            scrollable(watch_list).height(Length::Fixed(list_height)),
+            Space::new().height(Length::Fixed(8.0)),
+            add_controls,
+        ]
+        .width(Length::Fill)
+        .padding([12.0, 16.0])
+    )
+    .style(section_style)
+    .into()
+}
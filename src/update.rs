@@ -2,26 +2,33 @@
 
 use iced::window;
 use iced::{Size, Task};
-use std::sync::mpsc;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info, trace, warn};
 
+use crate::break_reminder;
 use crate::config;
+use crate::latency::{self, Stage};
 use crate::logging;
-use crate::model::{App, Message, OCRBackend, PlaybackState, TTSBackend};
-use crate::providers::{PiperTTSProvider, PollyTTSProvider, TTSProvider};
+use crate::model::{App, Message, OCRBackend, PlaybackState, TTSBackend, WindowRole};
+use crate::queue::{EndOfReading, QueuedItem, VoiceOverride};
+use crate::providers::{self, CommandProvider, CoquiTTSProvider, OpenTTSProvider, PiperTTSProvider, PollyTTSProvider, TTSProvider};
+use crate::speed_calibration::{self, SpeedCalibration};
 use crate::system;
 
-// Wrapper to make TTSProvider Send (required for cross-thread usage)
-// SAFETY: This is safe because we only move the provider between threads during initialization,
-// and rodio's types are actually safe to send between threads in practice.
-struct SendTTSProvider(Box<dyn TTSProvider>);
-unsafe impl Send for SendTTSProvider {}
-
-// Static storage for provider during async initialization
-static PENDING_PROVIDER: std::sync::Mutex<Option<SendTTSProvider>> = std::sync::Mutex::new(None);
-
 const SKIP_SECONDS: f32 = 5.0;
 const NUM_BANDS: usize = 10;
+/// Readings stopped earlier than this (seconds) are not worth resuming.
+const MIN_RESUME_SECONDS: f32 = 10.0;
+/// Readings stopped past this progress count as finished.
+const FINISHED_PROGRESS: f32 = 0.98;
+/// How far into a reading (seconds) resuming is still offered.
+const RESUME_OFFER_SECONDS: f32 = 20.0;
+/// Shortest A–B loop, in seconds.
+const MIN_LOOP_SECONDS: f32 = 0.5;
+/// Time between hearing a word and pressing the spell key, in seconds.
+const SPELL_REACTION_SECONDS: f32 = 0.4;
+/// Seconds into a chapter after which "previous chapter" restarts it instead.
+const CHAPTER_RESTART_SECONDS: f32 = 3.0;
 
 /// Check if an error string indicates an AWS credential/authentication issue.
 fn is_aws_credential_error(error_str: &str) -> bool {
@@ -50,7 +57,15 @@ where
 {
     if let Some(ref mut provider) = app.provider {
         trace!(seconds = SKIP_SECONDS, direction, "Skip requested");
+        let before = provider.get_progress();
         skip_fn(provider.as_mut());
+        let after = provider.get_progress();
+        let duration = provider.get_duration();
+        let rewind = app.pre_roll.rewind(app.word_timings.as_ref(), after, duration);
+        // A pre-roll that would undo a skip forward is left out
+        if rewind > 0.0 && (after < before || after * duration - rewind > before * duration) {
+            provider.skip_backward(rewind);
+        }
         app.progress = provider.get_progress();
         debug!(progress = app.progress, direction, "Skip applied");
     } else {
@@ -59,6 +74,290 @@ where
     Task::none()
 }
 
+/// Jump to the next detected chapter, or back to the start of the current one.
+///
+/// Going back early in a chapter goes to the one before, as with tracks in a
+/// media player. Chapters not synthesized yet can't be reached until they are.
+fn jump_to_chapter(app: &mut App, forward: bool) -> Task<Message> {
+    let Some(ref mut provider) = app.provider else {
+        return Task::none();
+    };
+    let duration = provider.get_duration();
+    if app.chapters.is_empty() || duration <= 0.0 {
+        return Task::none();
+    }
+    let progress = provider.get_progress();
+    let target = if forward {
+        app.chapters.iter().find(|(start, _)| *start > progress + f32::EPSILON)
+    } else {
+        let restart = CHAPTER_RESTART_SECONDS / duration;
+        app.chapters.iter().rev().find(|(start, _)| *start < progress - restart)
+    };
+    let (start, title) = match target {
+        Some((start, title)) => (*start, title.as_str()),
+        None if !forward => (0.0, "the beginning"),
+        None => return Task::none(),
+    };
+    let seconds = (start - progress) * duration;
+    if seconds > 0.0 {
+        provider.skip_forward(seconds);
+    } else {
+        provider.skip_backward(-seconds);
+    }
+    app.progress = provider.get_progress();
+    info!(%title, progress = app.progress, "Jumped to chapter");
+    Task::none()
+}
+
+/// Whether something is being read, synthesized, or waiting in the queue pause.
+fn is_reading(app: &App) -> bool {
+    app.playback_state != PlaybackState::Stopped || app.is_loading || app.reading_queue.is_waiting()
+}
+
+/// Save where the current reading was stopped, or forget it if it was (nearly) finished.
+///
+/// Must run before the provider is stopped or replaced.
+fn remember_position(app: &mut App) {
+    app.resume_offer = None;
+    app.resume_target = None;
+    let Some(key) = app.reading_key.take() else {
+        return;
+    };
+    // While loading, the provider still belongs to the previous reading
+    let Some(provider) = app.provider.as_ref().filter(|_| !app.is_loading && !app.speaking_phrase) else {
+        return;
+    };
+    let progress = provider.get_progress();
+    let seconds = progress * provider.get_duration();
+    if progress >= FINISHED_PROGRESS {
+        system::forget_position(&key);
+    } else if seconds >= MIN_RESUME_SECONDS {
+        info!(seconds, "Remembering where the reading was stopped");
+        system::save_position(&key, seconds);
+    }
+}
+
+/// Note which text is about to be read, offering to resume it if it was stopped part-way before.
+fn begin_document(app: &mut App, text: &str) {
+    let key = system::document_key(text);
+    app.resume_offer = system::load_position(&key);
+    if let Some(seconds) = app.resume_offer {
+        info!(seconds, "Text was read before, offering to resume");
+    }
+    app.reading_key = Some(key);
+}
+
+/// Start reading `item` (a text, or a queued item with its own voice),
+/// reopening the main window first if it is hidden.
+fn start_reading(app: &mut App, item: impl Into<QueuedItem>, context: &'static str) -> Task<Message> {
+    if app.window_hidden || !app.has_window(WindowRole::Main) {
+        let (window_id, open_task) = open_main_window();
+        app.windows.insert(window_id, WindowRole::Main);
+        app.window_hidden = false;
+        return Task::batch([open_task, process_text_for_tts(app, item, context)]);
+    }
+    process_text_for_tts(app, item, context)
+}
+
+/// Entry point for automatic reads (clipboard watcher, notification reader, ...).
+///
+/// During quiet hours, or while something is already being read, the text is
+/// queued silently instead of interrupting.
+fn read_automatically(app: &mut App, text: String, context: &'static str) -> Task<Message> {
+    if app.quiet_hours_active || is_reading(app) {
+        app.reading_queue.push(text);
+        info!(context, quiet = app.quiet_hours_active, queued = app.reading_queue.len(), "Automatic read queued");
+        return Task::none();
+    }
+    start_reading(app, text, context)
+}
+
+/// Recompute whether quiet hours are in effect and update the tray badge.
+/// Returns true if quiet hours just ended.
+pub fn refresh_quiet_hours(app: &mut App) -> bool {
+    let active = app.quiet_hours_enabled && system::is_quiet_now(&app.quiet_hours);
+    if active == app.quiet_hours_active {
+        return false;
+    }
+    info!(active, "Quiet hours changed");
+    app.quiet_hours_active = active;
+    if let Some(ref tray) = app.system_tray {
+        tray.set_quiet_hours(active);
+    }
+    !active
+}
+
+/// Extract the text of the file at `path` on a worker thread, then read it.
+fn open_file_task(app: &mut App, path: std::path::PathBuf) -> Task<Message> {
+    if path.as_os_str().is_empty() {
+        return Task::none();
+    }
+    app.open_file_status = Some(Ok("Opening…".to_string()));
+    Task::perform(
+        async move {
+            let result = extract_file_text(path.clone()).await;
+            (path, result)
+        },
+        |(path, result)| Message::FileOpened(path, result),
+    )
+}
+
+/// Register the hotkey of every action with the hotkey manager.
+///
+/// Actions are registered one by one, so a shortcut taken by another program only
+/// loses its own action; the error names the actions that failed.
+pub fn register_hotkeys(app: &mut App) -> Result<(), String> {
+    let configs = system::HotkeyAction::ALL.map(|action| (action, app.hotkey_config_for(action).clone()));
+    let Some(ref mut hotkey_manager) = app.hotkey_manager else {
+        return Ok(());
+    };
+    let failures: Vec<String> = configs
+        .into_iter()
+        .filter_map(|(action, config)| {
+            let e = hotkey_manager.register(action, config).err()?;
+            warn!(?action, error = %e, "Failed to register hotkey");
+            Some(format!("{}: {e}", action.label()))
+        })
+        .collect();
+    if failures.is_empty() { Ok(()) } else { Err(failures.join("; ")) }
+}
+
+/// Read the file given on the command line instead of the selection.
+///
+/// A file that can't be read is treated like an empty selection.
+pub fn read_file_argument(path: std::path::PathBuf) -> Task<Message> {
+    info!(path = %path.display(), "Reading the file given on the command line");
+    Task::perform(
+        async move {
+            let result = extract_file_text(path.clone()).await;
+            (path, result)
+        },
+        |(path, result)| match result {
+            Ok(document) => Message::FileOpened(path, Ok(document)),
+            Err(e) => {
+                warn!(error = %e, "Failed to read the file given on the command line");
+                Message::SelectedTextFetched(None)
+            }
+        },
+    )
+}
+
+/// Read the text piped in on standard input (`--stdin`) instead of the selection.
+///
+/// Reading ends when the writer closes the pipe, so the window shows its
+/// loading state while a slow command (a build) is still running.
+pub fn read_stdin_task() -> Task<Message> {
+    info!("Reading text from standard input");
+    Task::perform(
+        async {
+            tokio::task::spawn_blocking(|| {
+                // Invalid UTF-8 (e.g. a stray Latin-1 byte) is replaced rather than losing all the input
+                let mut bytes = Vec::new();
+                if let Err(e) = std::io::Read::read_to_end(&mut std::io::stdin(), &mut bytes) {
+                    warn!(error = %e, "Failed to read standard input");
+                }
+                String::from_utf8_lossy(&bytes).into_owned()
+            })
+            .await
+            .unwrap_or_else(|e| {
+                warn!(error = %e, "Failed to join blocking task for standard input");
+                String::new()
+            })
+        },
+        |text| {
+            let text = system::terminal_to_text(&text);
+            Message::SelectedTextFetched((!text.trim().is_empty()).then_some(text))
+        },
+    )
+}
+
+/// Text of the file at `path`, extracted on a worker thread.
+async fn extract_file_text(path: std::path::PathBuf) -> Result<system::Document, String> {
+    let opened = path.clone();
+    tokio::task::spawn_blocking(move || system::open_document(&opened))
+        .await
+        .unwrap_or_else(|e| Err(format!("Could not read {}: {e}", path.display())))
+}
+
+/// Detect the current Wi-Fi network and hostname on a worker thread.
+pub fn detect_network_task() -> Task<Message> {
+    Task::perform(
+        async {
+            tokio::task::spawn_blocking(system::detect_network)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!(error = %e, "Failed to join blocking task for network detection");
+                    system::NetworkContext::default()
+                })
+        },
+        Message::NetworkDetected,
+    )
+}
+
+/// Re-evaluate which workspace profile applies and switch output if it changed.
+fn refresh_active_profile(app: &mut App) {
+    let active = if app.profiles_auto_switch {
+        system::match_profile(&app.profiles, &app.network)
+    } else {
+        None
+    };
+    if active == app.active_profile {
+        return;
+    }
+    let name = active.and_then(|index| app.profiles.get(index)).map(|p| p.name.as_str());
+    info!(profile = ?name, "Workspace profile changed");
+    app.active_profile = active;
+    apply_output(app);
+}
+
+/// Apply the effective output settings to the playing provider, if any.
+fn apply_output(app: &mut App) {
+    let output = app.effective_output().clone();
+    if let Some(ref mut provider) = app.provider {
+        if let Err(e) = provider.set_output(&output) {
+            error!(error = %e, "Failed to apply output settings");
+        }
+    }
+}
+
+/// Create (Linux) or find (loopback driver) the virtual microphone off the UI thread.
+pub fn prepare_virtual_mic_task() -> Task<Message> {
+    Task::perform(
+        async {
+            tokio::task::spawn_blocking(|| providers::virtual_mic::prepare().map_err(|e| e.to_string()))
+                .await
+                .unwrap_or_else(|e| Err(format!("Failed to join blocking task: {e}")))
+        },
+        Message::VirtualMicPrepared,
+    )
+}
+
+/// Output settings edited by the settings controls: the active profile's, else the defaults.
+fn editable_output(app: &mut App) -> &mut providers::OutputSettings {
+    match app.active_profile.and_then(|index| app.profiles.get_mut(index)) {
+        Some(profile) => &mut profile.output,
+        None => &mut app.output,
+    }
+}
+
+/// Persist whichever output settings `editable_output` points at.
+fn save_output(app: &App) {
+    if app.active_profile.is_some() {
+        config::save_profiles(app.profiles_auto_switch, &app.profiles);
+    } else {
+        config::save_output_settings(&app.output);
+    }
+}
+
+/// Status line shown while waiting to start the next queued item.
+fn queue_countdown_status(seconds: u32, queued: usize) -> String {
+    if queued > 1 {
+        format!("Next in {seconds}s… ({queued} queued)")
+    } else {
+        format!("Next in {seconds}s…")
+    }
+}
+
 /// Set loading state on the app with a status message.
 fn set_loading_state(app: &mut App, status: &str) {
     app.is_loading = true;
@@ -66,6 +365,24 @@ fn set_loading_state(app: &mut App, status: &str) {
     app.status_text = Some(status.to_string());
 }
 
+/// Status shown while synthesizing; Polly adds a character count and cost estimate.
+fn synthesis_status(app: &App, text: &str) -> String {
+    if app.selected_backend != TTSBackend::AwsPolly {
+        return "Synthesizing voice...".to_string();
+    }
+    let estimate = providers::PollyCostEstimate::new(app.selected_polly_voice.as_deref(), text);
+    let over_threshold = app
+        .polly_cost_warning_cents
+        .is_some_and(|cents| estimate.usd * 100.0 > f64::from(cents));
+    if over_threshold {
+        warn!(characters = estimate.characters, usd = estimate.usd, "Polly synthesis above cost warning threshold");
+        format!("⚠ Large selection: {} with AWS Polly", estimate.summary())
+    } else {
+        info!(characters = estimate.characters, usd = estimate.usd, "Polly synthesis cost estimate");
+        format!("Synthesizing voice... ({})", estimate.summary())
+    }
+}
+
 /// Clear loading state on the app.
 fn clear_loading_state(app: &mut App) {
     app.is_loading = false;
@@ -80,6 +397,15 @@ fn open_url(url: &str) {
     }
 }
 
+/// Offer the report of a crash from the last run, in a small window.
+pub fn offer_crash_report(app: &mut App, report: std::path::PathBuf) -> Task<Message> {
+    info!(report = %report.display(), "Insight Reader crashed last time, offering the report");
+    app.crash_report = Some(report);
+    let (window_id, task) = open_info_window(Size::new(520.0, 260.0));
+    app.windows.insert(window_id, WindowRole::CrashReport);
+    task
+}
+
 /// Open the settings window with error display enabled.
 /// Returns the window ID and task mapped to Message::WindowOpened.
 fn open_settings_window() -> (window::Id, Task<Message>) {
@@ -110,16 +436,59 @@ fn open_info_window(size: Size) -> (window::Id, Task<Message>) {
     (window_id, task.map(Message::WindowOpened))
 }
 
-/// Helper to close a window if the window_id is Some.
-fn close_window_if_some(window_id: Option<window::Id>) -> Task<Message> {
-    window_id.map_or_else(Task::none, window::close)
+/// Close the window with `role`, if one is open.
+///
+/// The window is forgotten right away, so nothing targets it while it closes.
+fn close_window(app: &mut App, role: WindowRole) -> Task<Message> {
+    let Some(id) = app.window_id(role) else {
+        return Task::none();
+    };
+    Task::batch([window::close(id), forget_window(app, id)])
+}
+
+/// Forget the window `id`, closed or closing, with the state only it used.
+fn forget_window(app: &mut App, id: window::Id) -> Task<Message> {
+    let Some(role) = app.windows.remove(&id) else {
+        return Task::none();
+    };
+    debug!(?id, ?role, "Window forgotten");
+    match role {
+        WindowRole::Settings => app.show_settings_modal = false,
+        WindowRole::Queue => {
+            app.queue_drag = None;
+            app.queue_editor = None;
+            app.reading_queue.hold(None);
+        }
+        WindowRole::CrashReport => {
+            app.crash_report = None;
+            app.crash_report_status = None;
+        }
+        WindowRole::PhraseBoard => app.editing_phrases = false,
+        WindowRole::ExtractedText => {
+            app.extracted_text = None;
+            app.extracted_text_editor = None;
+        }
+        WindowRole::Review => app.review_editor = None,
+        // Hide window instead of exiting if system tray is available
+        WindowRole::Main => {
+            if app.system_tray.is_some() {
+                info!("Main window closed, hiding to system tray");
+                app.window_hidden = true;
+            } else {
+                info!("Main window closed, exiting (no system tray)");
+                return iced::exit();
+            }
+        }
+        _ => {}
+    }
+    Task::none()
 }
 
 /// Open the main window with standard settings.
 /// Returns the window ID and task mapped to Message::WindowOpened.
 fn open_main_window() -> (window::Id, Task<Message>) {
     let (window_id, task) = window::open(window::Settings {
-        size: Size::new(410.0, 70.0),
+        size: Size::new(460.0, 70.0),
         resizable: false,
         decorations: false,
         transparent: true,
@@ -137,14 +506,42 @@ fn open_main_window() -> (window::Id, Task<Message>) {
     (window_id, task.map(Message::WindowOpened))
 }
 
+/// Open the latency overlay in the top right corner, above other windows, unless it is open.
+pub fn open_debug_overlay(app: &mut App) -> Task<Message> {
+    if app.has_window(WindowRole::DebugOverlay) {
+        return Task::none();
+    }
+    debug!("Opening debug overlay window");
+    let (window_id, task) = window::open(window::Settings {
+        size: Size::new(300.0, 210.0),
+        resizable: false,
+        decorations: false,
+        transparent: false,
+        visible: true,
+        level: window::Level::AlwaysOnTop,
+        position: window::Position::SpecificWith(|window_size, monitor_size| {
+            let margin = 20.0;
+            iced::Point::new(monitor_size.width - window_size.width - margin, margin)
+        }),
+        ..Default::default()
+    });
+    app.windows.insert(window_id, WindowRole::DebugOverlay);
+    task.map(Message::WindowOpened)
+}
+
 /// Fetch selected text asynchronously.
 /// Returns a Task that will complete with SelectedTextFetched message.
-fn fetch_selected_text_task(context: &'static str) -> Task<Message> {
+fn fetch_selected_text_task(app: &App, context: &'static str) -> Task<Message> {
+    let source_app = detect_source_app(app);
     Task::perform(
         async move {
             debug!("Fetching selected text: {}", context);
-            let result = tokio::task::spawn_blocking(|| {
-                crate::system::get_selected_text()
+            let result = tokio::task::spawn_blocking(move || {
+                let started = Instant::now();
+                let text = crate::system::get_selected_text();
+                latency::record(Stage::Capture, started.elapsed());
+                let source_app = source_app.and_then(|detection| detection.join().ok().flatten());
+                text.map(|text| system::spreadsheet_selection(text, source_app.as_deref()))
             })
             .await;
             result.unwrap_or_else(|e| {
@@ -156,12 +553,19 @@ fn fetch_selected_text_task(context: &'static str) -> Task<Message> {
     )
 }
 
+/// Start detecting which app the selection is in, if spreadsheet cells are read row by row.
+///
+/// Started right away, before the reader's window opens and takes the focus.
+pub fn detect_source_app(app: &App) -> Option<std::thread::JoinHandle<Option<String>>> {
+    app.text_processing.spreadsheet_cells.then(|| std::thread::spawn(system::frontmost_app))
+}
+
 /// Open settings window if not already open, setting error message and modal state.
 /// Returns the task if window was opened, otherwise Task::none().
 fn open_settings_if_needed(app: &mut App, error_msg: String) -> Task<Message> {
-    let task = if app.settings_window_id.is_none() {
+    let task = if !app.has_window(WindowRole::Settings) {
         let (window_id, task) = open_settings_window();
-        app.settings_window_id = Some(window_id);
+        app.windows.insert(window_id, WindowRole::Settings);
         app.show_settings_modal = true;
         task
     } else {
@@ -172,40 +576,228 @@ fn open_settings_if_needed(app: &mut App, error_msg: String) -> Task<Message> {
     task
 }
 
-/// Process text: send to cleanup API if enabled, otherwise return task to initialize TTS directly.
+/// Read `text` with the Piper voice set for its language, when that isn't the selected voice's.
+///
+/// Languages without a downloaded voice are read by the selected voice, with
+/// an offer to pick one.
+fn pick_language_voice(app: &mut App, text: &str) {
+    app.language_prompt = None;
+    if !app.auto_language || app.selected_backend != TTSBackend::Piper || !app.item_voice.is_empty() {
+        return;
+    }
+    let Some(language) = system::detect_text_language(text) else {
+        return;
+    };
+    if app.voice_language_family().as_deref() == Some(language) {
+        return;
+    }
+    let voice_key = app
+        .language_voices
+        .get(language)
+        .filter(|voice_key| crate::voices::download::is_voice_downloaded(voice_key));
+    match voice_key {
+        Some(voice_key) => {
+            info!(language, voice = %voice_key, "Reading with the voice for the detected language");
+            app.item_voice = VoiceOverride {
+                engine: Some("piper".to_string()),
+                voice: Some(voice_key.clone()),
+                speed: None,
+            };
+        }
+        None => {
+            info!(language, "No voice downloaded for the detected language");
+            app.language_prompt = Some(language.to_string());
+        }
+    }
+}
+
+/// Process text: apply local text processing, then send to cleanup API if enabled,
+/// otherwise return task to initialize TTS directly.
 /// Sets loading state before returning.
+///
+/// A queued item is read with its own voice, if it has one.
 fn process_text_for_tts(
     app: &mut App,
-    text: String,
+    item: impl Into<QueuedItem>,
     context: &'static str,
 ) -> Task<Message> {
-    if app.text_cleanup_enabled {
+    let item = item.into();
+    app.reading_item = Some(item.clone());
+    app.finished_item = None;
+    app.item_voice = item.voice;
+    app.item_pages = item.pages;
+    pick_language_voice(app, &item.text);
+    let text = system::apply_text_processing(&item.text, &app.text_processing, app.voice_locale().as_deref());
+    log_transcript(app, &text, context);
+    begin_listen_summary(app, &text, context);
+    remember_position(app);
+    app.reading_article = None;
+    app.recording_text = (app.save_readings || !app.handoff_folder.trim().is_empty()).then(|| text.clone());
+    if let Some(language) = system::rtl_language(&text) {
+        if !app.voice_language_family().is_some_and(|family| family.starts_with(language.code())) {
+            warn!(language = language.name(), "Selected voice does not match the text's right-to-left language");
+        }
+    }
+    if app.text_cleanup_enabled && !app.text_transforms.is_empty() {
         set_loading_state(app, "Processing content...");
-        info!(context, "Natural Reading enabled, sending to service");
+        info!(context, steps = app.text_transforms.len(), "Natural Reading enabled, running text transforms");
+        let options = app.text_processing.clone();
+        let providers = app.text_transforms.iter().map(system::TransformStep::provider).collect();
         Task::perform(
-            async move { system::cleanup_text(&text).await },
+            async move {
+                let started = Instant::now();
+                let result = system::run_transforms(providers, text)
+                    .await
+                    .map(|cleaned| system::cleaned_to_plain_text(&cleaned, &options));
+                latency::record(Stage::Cleanup, started.elapsed());
+                result
+            },
             Message::TextCleanupResponse,
         )
     } else {
-        set_loading_state(app, "Synthesizing voice...");
+        let text = skim_text(app, text);
+        begin_document(app, &text);
+        let status = synthesis_status(app, &text);
+        set_loading_state(app, &status);
         info!(context, "Initializing TTS directly");
-        initialize_tts_async(app.selected_backend, text, context, app.selected_polly_voice.clone())
+        initialize_tts_async(app, text, context)
+    }
+}
+
+/// Read the captured `text`, unless it is over the large-text limit: then hold it until the user confirms.
+fn read_captured(app: &mut App, text: String, context: &'static str) -> Task<Message> {
+    match hold_if_large(app, text) {
+        Some(text) => process_text_for_tts(app, text, context),
+        None => {
+            app.status_text = None;
+            Task::none()
+        }
+    }
+}
+
+/// Hold `text` for confirmation if it is over the large-text limit; gives it back otherwise.
+fn hold_if_large(app: &mut App, text: String) -> Option<String> {
+    let Some(limit_kb) = app.large_text_limit_kb.filter(|kb| text.len() > *kb as usize * 1024) else {
+        return Some(text);
+    };
+    let large = crate::model::LargeText::new(text, limit_kb as usize * 1024, app.playback_speed);
+    info!(
+        bytes = large.text.len(),
+        limit_kb,
+        minutes = large.seconds / 60.0,
+        "Large selection, asking before reading it"
+    );
+    app.large_text = Some(large);
+    None
+}
+
+/// Show the captured `text` in the review window, to be read once the user presses Speak.
+fn open_review(app: &mut App, text: String) -> Task<Message> {
+    info!(bytes = text.len(), "Opening the captured text for review");
+    app.review_editor = Some(iced::widget::text_editor::Content::with_text(&text));
+    app.status_text = Some("Review the text, then press Speak".to_string());
+    if app.has_window(WindowRole::Review) {
+        return Task::none();
+    }
+    let (window_id, task) = window::open(window::Settings {
+        size: Size::new(600.0, 400.0),
+        resizable: true,
+        decorations: true,
+        transparent: false,
+        visible: true,
+        position: window::Position::Centered,
+        ..Default::default()
+    });
+    app.windows.insert(window_id, WindowRole::Review);
+    task.map(Message::WindowOpened)
+}
+
+/// In skim mode, the headings and first sentences of `text`, to read instead of all of it.
+fn skim_text(app: &mut App, text: String) -> String {
+    app.skim = None;
+    if !app.skim_mode {
+        return text;
+    }
+    // Skimmed as plain text: cutting paragraphs could leave speech markup unbalanced
+    let skim = providers::Skim::new(&providers::markup::to_plain_text(&text));
+    if skim.text.is_empty() {
+        return text;
+    }
+    info!(bytes = text.len(), skimmed_bytes = skim.text.len(), "Skimming the reading");
+    // Page starts don't carry over to the skim
+    app.item_pages.clear();
+    let skimmed = skim.text.clone();
+    app.skim = Some(skim);
+    skimmed
+}
+
+/// Reload the Polly voice list after the AWS profile or region changed.
+fn reload_polly_voices(app: &mut App) -> Task<Message> {
+    app.polly_voices = None;
+    app.polly_error_message = None;
+    match PollyTTSProvider::check_credentials() {
+        Ok(()) => {
+            app.error_message = None;
+            Task::perform(
+                async { crate::voices::aws::fetch_polly_voices().await },
+                Message::PollyVoicesLoaded,
+            )
+        }
+        Err(e) => {
+            warn!("AWS credentials not found for the selected profile");
+            app.error_message = Some(e);
+            Task::none()
+        }
     }
 }
 
+/// Keep the prepared provider in `pending` and synthesize its speech on a worker thread.
+///
+/// The provider owns the audio output, which can't leave the UI thread; only the
+/// synthesis moves, and the id it shares with the [`providers::ReadySpeech`] pairs them up again.
+fn synthesize_prepared(
+    pending: &mut Option<(u64, Box<dyn TTSProvider>)>,
+    prepared: Result<(Box<dyn TTSProvider>, providers::Synthesis), providers::TTSError>,
+) -> impl std::future::Future<Output = Result<providers::ReadySpeech, providers::TTSError>> + Send + 'static {
+    let synthesis = prepared.map(|(provider, synthesis)| {
+        *pending = Some((synthesis.id(), provider));
+        synthesis
+    });
+    async move { providers::synthesize_in_background(synthesis?).await }
+}
+
+/// Start playing `ready` with the provider waiting for it in `pending`.
+///
+/// None when the speech is stale: its provider was dropped or another synthesis started since.
+fn play_ready_speech(
+    pending: &mut Option<(u64, Box<dyn TTSProvider>)>,
+    ready: &providers::ReadySpeech,
+) -> Option<Result<Box<dyn TTSProvider>, String>> {
+    if pending.as_ref().map(|(id, _)| *id) != Some(ready.id()) {
+        return None;
+    }
+    let (_, mut provider) = pending.take()?;
+    let speech = ready.take()?;
+    Some(provider.play(speech).map(|()| provider).map_err(|e| e.to_string()))
+}
+
 /// Initialize TTS provider and start speaking with the given text asynchronously.
 /// Returns a Task that will complete when synthesis is done.
-/// This prevents blocking the UI thread during TTS synthesis.
-fn initialize_tts_async(
-    backend: TTSBackend,
-    text: String,
-    context: &'static str,
-    polly_voice_id: Option<String>,
-) -> Task<Message> {
+/// The provider is created on the UI thread, which keeps its audio output;
+/// synthesis runs on a worker thread, so the UI never blocks on subprocesses or network calls.
+fn initialize_tts_async(app: &mut App, text: String, context: &'static str) -> Task<Message> {
+    let voice = app.item_voice.clone();
+    if voice.engine.is_some() && voice.backend().is_none() {
+        warn!(engine = ?voice.engine, "Unknown engine for this item, using the selected one");
+    }
+    let backend = voice.backend().unwrap_or(app.selected_backend);
+    let (speed, pitch) = (voice.speed.unwrap_or_else(|| app.reading_speed()), app.pitch_semitones);
     info!(
         context,
         backend = ?backend,
         bytes = text.len(),
+        speed,
+        pitch,
         "Starting async TTS initialization"
     );
 
@@ -220,118 +812,1830 @@ fn initialize_tts_async(
         }
     }
 
-    // Create provider (this is fast and happens on main thread)
-    let provider_result = match backend {
-        TTSBackend::Piper => PiperTTSProvider::new().map(|p| Box::new(p) as Box<dyn TTSProvider>),
-        TTSBackend::AwsPolly => {
-            // Use provided voice ID or fall back to config/default
-            let voice_id = polly_voice_id.or_else(|| config::load_selected_polly_voice());
-            PollyTTSProvider::new(voice_id).map(|p| Box::new(p) as Box<dyn TTSProvider>)
-        }
+    // Replay the reading from the audio cache, or cache it once synthesized
+    // (the cache is keyed by the selected voice, so not for items with their own)
+    let cache_voice = (app.audio_cache_enabled && voice.is_empty()).then(|| audio_cache_key(app));
+    app.audio_cache_pending = cache_voice.clone().map(|voice| (voice, text.clone()));
+    let plain = providers::markup::to_plain_text(&text);
+    let timings = providers::word_timing::WordTimings::new(&plain);
+    app.chapters = system::detect_chapters(&plain)
+        .into_iter()
+        .map(|chapter| (timings.progress_at(chapter.offset), chapter.title))
+        .collect();
+    if !app.chapters.is_empty() {
+        info!(chapters = app.chapters.len(), "Chapters detected");
     }
-    .map_err(|e| format!("{}", e));
+    // Processing changes the text's length, so page starts are placed at the same share of it
+    let chars = plain.chars().count();
+    app.pages = app
+        .item_pages
+        .iter()
+        .map(|share| {
+            let offset = plain.char_indices().nth((share * chars as f32) as usize).map_or(plain.len(), |(i, _)| i);
+            timings.progress_at(offset)
+        })
+        .collect();
+    app.word_timings = Some(timings);
+    app.reading_text = Some(plain);
+    app.voice_comparison = None;
 
-    match provider_result {
-        Ok(provider) => {
-            // Wrap provider to make it Send-safe for cross-thread usage
-            let send_provider = SendTTSProvider(provider);
-            
-            // Spawn thread to do blocking synthesis
-            let (tx, rx) = mpsc::channel();
-            
-            std::thread::spawn(move || {
-                let mut send_provider = send_provider;
-                let provider = &mut send_provider.0;
-                info!(text = %text, "Synthesizing text");
-                let result = provider.speak(&text);
-                
-                match result {
-                    Ok(()) => {
-                        info!(context, "TTS synthesis completed successfully");
-                        if let Ok(mut guard) = PENDING_PROVIDER.lock() {
-                            *guard = Some(send_provider);
-                        }
-                        let _ = tx.send(Ok(()));
-                    }
-                    Err(e) => {
-                        error!(error = %e, "TTS speak failed");
-                        let error_msg = format_tts_error(&format!("{}", e), backend);
-                        let _ = tx.send(Err(error_msg));
-                    }
+    let started = Instant::now();
+    let polly_voice_id = app.selected_polly_voice.clone();
+    let provider = match (backend, voice.voice) {
+        (TTSBackend::Piper, Some(voice_key)) => create_piper_provider(Some(&voice_key)),
+        (TTSBackend::AwsPolly, Some(voice_id)) => create_provider(backend, Some(voice_id)),
+        _ => create_provider(backend, polly_voice_id),
+    };
+    let prepared = provider.and_then(|provider| {
+        providers::prepare_speech(provider, cache_voice, &text, speed, pitch, app.effective_output())
+    });
+    let synthesis = synthesize_prepared(&mut app.synthesizing, prepared);
+    Task::perform(
+        async move {
+            match synthesis.await {
+                Ok(ready) => {
+                    latency::record(Stage::FirstAudio, started.elapsed());
+                    info!(context, "TTS synthesis completed successfully");
+                    Ok(ready)
                 }
-            });
+                Err(e) => {
+                    error!(error = %e, "TTS speak failed");
+                    Err(format_tts_error(&e.to_string(), backend))
+                }
+            }
+        },
+        Message::TTSInitialized,
+    )
+}
 
-            // Return a task that waits for synthesis (non-blocking for UI)
-            Task::perform(
-                async move {
-                    tokio::task::spawn_blocking(move || {
-                        rx.recv().unwrap_or_else(|e| Err(format!("Channel error: {}", e)))
-                    })
-                    .await
-                    .unwrap_or_else(|e| Err(format!("Task join error: {}", e)))
-                },
-                Message::TTSInitialized,
-            )
-        }
-        Err(e) => {
-            let error_msg = format_tts_error(&e, backend);
-            Task::perform(
-                async move { Err(error_msg) },
-                Message::TTSInitialized,
-            )
-        }
+/// License and dataset attribution of the selected Piper voice, when known.
+fn voice_attribution(app: &App) -> Option<String> {
+    if app.selected_backend != TTSBackend::Piper {
+        return None;
+    }
+    let key = app.selected_voice.as_deref()?;
+    let license = match app.voice_licenses.get(key) {
+        Some(Ok(license)) => license.clone(),
+        _ => crate::voices::license::local_voice_license(key)?,
+    };
+    Some(format!("Voice {key}. {}", license.summary()))
+}
+
+/// Measure the audio cache in the background.
+fn measure_audio_cache() -> Task<Message> {
+    Task::perform(
+        async { tokio::task::spawn_blocking(providers::audio_cache::cache_size).await.unwrap_or_default() },
+        Message::AudioCacheMeasured,
+    )
+}
+
+/// Web page of the read-later article being read, if any.
+fn reading_article_url(app: &App) -> Option<String> {
+    let id = app.reading_article.as_deref()?;
+    let article = app.read_later_articles.iter().find(|article| article.id == id)?;
+    Some(article.url.clone()).filter(|url| !url.is_empty())
+}
+
+/// Save the finished reading and/or hand it off to the phone folder, as configured.
+fn export_reading(app: &App, text: String, audio: providers::SynthesizedAudio) -> Task<Message> {
+    let mut tasks = Vec::new();
+    let tags = system::ReadingTags::new(&text, phrase_voice_key(app), reading_article_url(app), voice_attribution(app));
+    if app.save_readings {
+        let folder = recordings_folder(app);
+        let (text, audio, tags) = (text.clone(), audio.clone(), tags.clone());
+        tasks.push(Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    system::save_reading(&folder, &text, &audio.samples, audio.sample_rate, &tags)
+                })
+                .await
+                .unwrap_or_else(|e| Err(format!("Saving task failed: {e}")))
+            },
+            Message::ReadingSaved,
+        ));
+    }
+    let handoff_folder = app.handoff_folder.trim();
+    if !handoff_folder.is_empty() {
+        let folder = std::path::PathBuf::from(handoff_folder);
+        tasks.push(Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    system::export_to_handoff(&folder, &text, &tags, &audio.samples, audio.sample_rate)
+                })
+                .await
+                .unwrap_or_else(|e| Err(format!("Handoff task failed: {e}")))
+            },
+            Message::HandoffExported,
+        ));
     }
+    Task::batch(tasks)
 }
 
-pub fn update(app: &mut App, message: Message) -> Task<Message> {
-    match message {
-        Message::SkipBackward => {
-            handle_skip(app, |p| p.skip_backward(SKIP_SECONDS), "backward")
+/// Start the webhook summary of a reading, if the listening webhook is on.
+fn begin_listen_summary(app: &mut App, text: &str, context: &str) {
+    app.listened_reading = (!app.listen_webhook.trim().is_empty())
+        .then(|| system::ReadingSummary::started(context, text, app.listen_webhook_text));
+}
+
+/// Post the summary of the reading that just finished to the listening webhook.
+fn post_listen_summary(app: &mut App, duration: f32) -> Task<Message> {
+    let Some(summary) = app.listened_reading.take() else {
+        return Task::none();
+    };
+    let summary = summary.finished(duration, app.playback_speed, phrase_voice_key(app), reading_article_url(app));
+    Task::perform(
+        system::post_reading_summary(app.listen_webhook.trim().to_string(), summary),
+        Message::ListenWebhookSent,
+    )
+}
+
+/// Add a reading to today's transcript, if the transcript log is on.
+fn log_transcript(app: &App, text: &str, context: &str) {
+    if app.transcript_mode == system::TranscriptMode::Off {
+        return;
+    }
+    let folder = system::default_transcripts_folder();
+    match system::append_transcript(&folder, context, text, app.transcript_mode, app.transcript_format) {
+        Ok(path) => debug!(path = %path.display(), context, "Reading added to transcript"),
+        Err(e) => warn!(error = %e, "Failed to write transcript"),
+    }
+}
+
+/// Folder for saved readings: the configured one or the default.
+fn recordings_folder(app: &App) -> std::path::PathBuf {
+    match app.recordings_folder.trim() {
+        "" => system::default_recordings_folder(),
+        folder => std::path::PathBuf::from(folder),
+    }
+}
+
+/// Speak a phrase board entry or typed line right away, interrupting playback.
+fn speak_phrase(app: &mut App, text: String) -> Task<Message> {
+    speak_phrase_at(app, text, app.playback_speed)
+}
+
+/// Speak a phrase at `speed`, interrupting playback.
+fn speak_phrase_at(app: &mut App, text: String, speed: f32) -> Task<Message> {
+    // Phrases are never saved as readings
+    app.recording_text = None;
+    app.listened_reading = None;
+    app.audio_cache_pending = None;
+    app.word_timings = None;
+    app.reading_text = None;
+    app.voice_comparison = None;
+    app.chapters.clear();
+    app.pages.clear();
+    remember_position(app);
+    // Phrases are answers in a conversation: interrupt whatever is playing
+    if let Some(ref mut provider) = app.provider {
+        if let Err(e) = provider.stop() {
+            error!(error = %e, "Failed to stop playback");
         }
-        Message::SkipForward => {
+    }
+    info!(phrase = %text, "Speaking phrase");
+    set_loading_state(app, "Speaking phrase...");
+    speak_phrase_task(app, text, speed)
+}
+
+/// Read the speed calibration sample at the speed being tried.
+fn speak_calibration_sample(app: &mut App) -> Task<Message> {
+    let Some(calibration) = app.speed_calibration.as_ref() else {
+        return Task::none();
+    };
+    let (text, speed) = (calibration.sample().to_string(), calibration.speed());
+    info!(speed, "Reading speed calibration sample");
+    speak_phrase_at(app, text, speed)
+}
+
+/// Drop cached audio for lines that are neither on the phrase board nor in the talk history.
+fn retain_cached_phrases(app: &App) {
+    let keep: Vec<String> = app.phrases.iter().chain(&app.talk_history).cloned().collect();
+    providers::phrase_cache::retain(&keep);
+}
+
+/// Speak a phrase, replaying its cached audio when available.
+///
+/// Phrases skip text processing: they are read exactly as the user wrote them.
+fn speak_phrase_task(app: &mut App, text: String, speed: f32) -> Task<Message> {
+    let backend = app.selected_backend;
+    let voice = audio_cache_key(app);
+    let polly_voice_id = app.selected_polly_voice.clone();
+    let create = move || create_provider(backend, polly_voice_id);
+    let (pitch, output) = (app.pitch_semitones, app.effective_output());
+    let prepared = providers::phrase_cache::prepare_phrase(create, voice, &text, speed, pitch, output);
+    let synthesis = synthesize_prepared(&mut app.synthesizing, prepared);
+    Task::perform(
+        async move {
+            synthesis
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Phrase speak failed");
+                    format_tts_error(&e.to_string(), backend)
+                })
+        },
+        Message::PhraseReady,
+    )
+}
+
+/// Identifies everything about the selected voice that changes how a phrase sounds.
+fn phrase_voice_key(app: &App) -> String {
+    match app.selected_backend {
+        TTSBackend::Piper => format!("piper:{}", app.selected_voice.as_deref().unwrap_or_default()),
+        // Standard Polly voices bake the pitch into the audio (SSML prosody)
+        TTSBackend::AwsPolly if app.pitch_semitones != 0.0 => format!(
+            "polly:{}@{}st",
+            app.selected_polly_voice.as_deref().unwrap_or_default(),
+            app.pitch_semitones
+        ),
+        TTSBackend::AwsPolly => format!("polly:{}", app.selected_polly_voice.as_deref().unwrap_or_default()),
+        TTSBackend::WindowsSpeech => "windows".to_string(),
+        TTSBackend::Coqui => format!("coqui:{}#{}", app.coqui_config.url, app.coqui_config.speaker),
+        TTSBackend::OpenTTS => format!("opentts:{}#{}", app.opentts_config.url, app.opentts_config.voice),
+        TTSBackend::Command => format!("command:{}", app.command_voice.as_deref().unwrap_or_default()),
+    }
+}
+
+/// Key of cached audio: the voice, plus every setting baked into the synthesized audio.
+fn audio_cache_key(app: &App) -> String {
+    let mut key = phrase_voice_key(app);
+    if app.selected_backend == TTSBackend::Piper && app.mixed_language {
+        let voices: Vec<String> =
+            app.language_voices.iter().map(|(language, voice)| format!("{language}={voice}")).collect();
+        key.push_str(&format!("+mixed[{}]", voices.join(",")));
+    }
+    let output = app.effective_output();
+    if output.normalize_loudness {
+        key.push_str("+normalized");
+    }
+    if output.shorten_pauses {
+        key.push_str("+short-pauses");
+    }
+    key
+}
+
+/// Whether anything is held that `release_idle_resources` would free.
+pub fn holds_idle_resources(app: &App) -> bool {
+    app.provider.is_some() || !providers::phrase_cache::is_empty() || providers::replay_buffer::has_recent_audio()
+}
+
+/// Drop what the last reading left behind after `minutes` idle: its engine (with any
+/// loaded model), the audio output stream and the audio kept for instant replays.
+///
+/// Nothing needs re-acquiring explicitly: each reading creates its engine and
+/// output again, and phrases are synthesized again when next spoken.
+fn release_idle_resources(app: &mut App, minutes: u32) {
+    info!(minutes, "Idle, releasing engine, audio output and cached audio");
+    app.provider = None;
+    app.word_timings = None;
+    app.reading_text = None;
+    app.chapters.clear();
+    app.pages.clear();
+    app.skim = None;
+    app.audio_cache_pending = None;
+    app.recording_text = None;
+    providers::phrase_cache::clear();
+    providers::replay_buffer::clear();
+}
+
+/// Once the last reading ended and nothing is queued: close or hide the window, or keep it open.
+fn end_reading(app: &mut App) -> Task<Message> {
+    match app.end_of_reading {
+        EndOfReading::NextInQueue | EndOfReading::StayOpen => {
+            info!("Playback finished, keeping window open");
+            app.frequency_bands = vec![0.0; NUM_BANDS];
+            Task::none()
+        }
+        EndOfReading::HideToTray if app.system_tray.is_some() => {
+            info!("Playback finished, hiding window to tray");
+            update(app, Message::HideWindow)
+        }
+        EndOfReading::CloseWindow | EndOfReading::RunHook | EndOfReading::HideToTray => {
+            info!("Playback finished, stopping and closing window");
+            close_window(app, WindowRole::Main)
+        }
+    }
+}
+
+/// Run the end-of-reading hook command, if one is set.
+fn run_end_of_reading_hook(app: &App) {
+    let hook = app.end_of_reading_hook.trim();
+    if hook.is_empty() {
+        return;
+    }
+    match system::run_shell_command(hook) {
+        Ok(()) => info!(hook, "End-of-reading hook started"),
+        Err(e) => warn!(error = %e, hook, "Failed to run end-of-reading hook"),
+    }
+}
+
+/// Hold the reading while a replay (or spelling) plays; stops one already playing.
+fn pause_for_replay(app: &mut App) {
+    if let Some(mut replay) = app.replay_provider.take() {
+        let _ = replay.stop();
+    }
+    if app.playback_state == PlaybackState::Playing {
+        if let Some(ref mut provider) = app.provider {
+            match provider.pause() {
+                Ok(()) => {
+                    app.playback_state = PlaybackState::Paused;
+                    app.resume_after_replay = true;
+                }
+                Err(e) => error!(error = %e, "Failed to pause playback for replay"),
+            }
+        }
+    }
+}
+
+/// Pause the reading and read the sentence of the pending voice comparison with the comparison voice.
+fn speak_voice_comparison(app: &mut App) -> Task<Message> {
+    let Some(comparison) = app.voice_comparison.as_mut() else {
+        return Task::none();
+    };
+    comparison.speaking = true;
+    let text = comparison.text.clone();
+    let voice_key = app.compare_voice.clone();
+    info!(voice = %voice_key, "Reading the next sentence with the comparison voice");
+    pause_for_replay(app);
+    let (speed, pitch, output) = (app.reading_speed(), app.pitch_semitones, app.effective_output());
+    let prepared = create_piper_provider(Some(&voice_key))
+        .and_then(|provider| providers::prepare_speech(provider, None, &text, speed, pitch, output));
+    let synthesis = synthesize_prepared(&mut app.replay_synthesizing, prepared);
+    Task::perform(
+        async move {
+            synthesis
+                .await
+                .map_err(|e| format_tts_error(&e.to_string(), TTSBackend::Piper))
+        },
+        Message::ReplayReady,
+    )
+}
+
+/// Select a Piper voice and remember it as the voice for its language.
+fn select_piper_voice(app: &mut App, voice_key: String) {
+    app.language_voices
+        .insert(crate::system::voice_language(&voice_key).to_string(), voice_key.clone());
+    config::save_language_voices(app.mixed_language, &app.language_voices);
+    if app.language_prompt.as_deref() == Some(crate::system::voice_language(&voice_key)) {
+        app.language_prompt = None;
+    }
+    app.selected_voice = Some(voice_key.clone());
+    config::save_selected_voice(voice_key);
+}
+
+/// Create the OCR engine for `backend`.
+pub(crate) fn create_ocr_provider(backend: OCRBackend) -> Result<Box<dyn system::OcrProvider>, String> {
+    match backend {
+        OCRBackend::Default => system::native_ocr_provider(),
+        OCRBackend::Tesseract => Ok(Box::new(system::TesseractOcrProvider)),
+        OCRBackend::Remote => {
+            system::RemoteOcrProvider::new(config::load_ocr_endpoint()).map(|p| Box::new(p) as Box<dyn system::OcrProvider>)
+        }
+        OCRBackend::BetterOCR => Err("Better OCR is not available yet".to_string()),
+    }
+}
+
+/// Create a Piper provider for `voice_key`, or the selected voice (may block).
+fn create_piper_provider(voice_key: Option<&str>) -> Result<Box<dyn TTSProvider>, providers::TTSError> {
+    let load = config::load_synthesis_load();
+    let provider = match voice_key {
+        Some(voice_key) => PiperTTSProvider::with_voice(voice_key, load),
+        None => PiperTTSProvider::new(load),
+    };
+    provider.map(|mut p| {
+        let (mixed_language, language_voices) = config::load_language_voices();
+        if mixed_language {
+            p.set_language_voices(&language_voices);
+        }
+        Box::new(p) as Box<dyn TTSProvider>
+    })
+}
+
+/// Create the provider for `backend` (may load a voice model; synthesis itself runs later).
+pub(crate) fn create_provider(
+    backend: TTSBackend,
+    polly_voice_id: Option<String>,
+) -> Result<Box<dyn TTSProvider>, providers::TTSError> {
+    match backend {
+        TTSBackend::Piper => create_piper_provider(None),
+        TTSBackend::AwsPolly => {
+            // Use provided voice ID or fall back to config/default
+            let voice_id = polly_voice_id.or_else(config::load_selected_polly_voice);
+            PollyTTSProvider::new(voice_id).map(|p| Box::new(p) as Box<dyn TTSProvider>)
+        }
+        #[cfg(target_os = "windows")]
+        TTSBackend::WindowsSpeech => {
+            providers::WindowsTTSProvider::new().map(|p| Box::new(p) as Box<dyn TTSProvider>)
+        }
+        #[cfg(not(target_os = "windows"))]
+        TTSBackend::WindowsSpeech => Err(providers::TTSError::ProcessError(
+            "Windows speech is only available on Windows".into(),
+        )),
+        TTSBackend::Coqui => {
+            CoquiTTSProvider::new(config::load_coqui_config()).map(|p| Box::new(p) as Box<dyn TTSProvider>)
+        }
+        TTSBackend::OpenTTS => {
+            OpenTTSProvider::new(config::load_opentts_config()).map(|p| Box::new(p) as Box<dyn TTSProvider>)
+        }
+        TTSBackend::Command => {
+            let voices = providers::load_command_voices().map_err(providers::TTSError::ProcessError)?;
+            let selected = config::load_command_voice();
+            let voice = voices
+                .iter()
+                .find(|voice| selected.as_deref() == Some(voice.name.as_str()))
+                .or(voices.first())
+                .cloned()
+                .ok_or_else(|| {
+                    let path = providers::command_voices_path().unwrap_or_default();
+                    providers::TTSError::ProcessError(format!("No command voices defined in {}", path.display()))
+                })?;
+            CommandProvider::new(voice).map(|p| Box::new(p) as Box<dyn TTSProvider>)
+        }
+    }
+}
+
+pub fn update(app: &mut App, message: Message) -> Task<Message> {
+    match message {
+        Message::SkipBackward => {
+            handle_skip(app, |p| p.skip_backward(SKIP_SECONDS), "backward")
+        }
+        Message::SkipForward => {
             handle_skip(app, |p| p.skip_forward(SKIP_SECONDS), "forward")
         }
-        Message::PlayPause => {
-            let Some(ref mut provider) = app.provider else {
-                warn!("PlayPause received with no active provider");
+        Message::NextChapter => jump_to_chapter(app, true),
+        Message::PreviousChapter => jump_to_chapter(app, false),
+        Message::ExpandSkim => {
+            if app.is_loading || app.speaking_phrase || app.playback_state == PlaybackState::Stopped {
+                return Task::none();
+            }
+            let (Some(skim), Some(provider)) = (app.skim.take(), app.provider.as_mut()) else {
+                return Task::none();
+            };
+            let text = skim.expand(provider.get_progress()).to_string();
+            if let Err(e) = provider.stop() {
+                error!(error = %e, "Failed to stop the skimmed reading");
+            }
+            info!(bytes = text.len(), "Reading the skimmed text in full from here");
+            app.item_pages.clear();
+            begin_document(app, &text);
+            let status = synthesis_status(app, &text);
+            set_loading_state(app, &status);
+            initialize_tts_async(app, text, "ExpandSkim")
+        }
+        Message::PlayPause => {
+            let Some(ref mut provider) = app.provider else {
+                warn!("PlayPause received with no active provider");
+                return Task::none();
+            };
+            
+            match app.playback_state {
+                PlaybackState::Playing => {
+                    if let Err(e) = provider.pause() {
+                        error!(error = %e, "Failed to pause playback");
+                    } else {
+                        app.playback_state = PlaybackState::Paused;
+                        info!("Playback paused");
+                    }
+                }
+                PlaybackState::Paused => {
+                    // Pick up a little earlier so no word is cut in half
+                    let rewind = app.pre_roll.rewind(
+                        app.word_timings.as_ref(),
+                        provider.get_progress(),
+                        provider.get_duration(),
+                    );
+                    if rewind > 0.0 {
+                        debug!(seconds = rewind, "Pre-roll before resuming");
+                        provider.skip_backward(rewind);
+                    }
+                    if let Err(e) = provider.resume() {
+                        error!(error = %e, "Failed to resume playback");
+                    } else {
+                        app.playback_state = PlaybackState::Playing;
+                        info!("Playback resumed");
+                    }
+                }
+                PlaybackState::Stopped => {
+                    // A reading that ended with the window kept open is read again
+                    if let Some(item) = app.finished_item.take() {
+                        info!("Reading the finished text again");
+                        return process_text_for_tts(app, item, "ReadAgain");
+                    }
+                }
+            }
+            Task::none()
+        }
+        Message::Stop => {
+            remember_position(app);
+            if let Some(ref mut provider) = app.provider {
+                if let Err(e) = provider.stop() {
+                    error!(error = %e, "Failed to stop playback");
+                }
+            }
+            app.playback_state = PlaybackState::Stopped;
+            app.progress = 0.0;
+            app.loop_start = None;
+            app.loop_region = None;
+            app.frequency_bands = vec![0.0; NUM_BANDS];
+            app.buffering = false;
+            app.reading_queue.clear();
+            app.reading_article = None;
+            app.listened_reading = None;
+            app.audio_cache_pending = None;
+            app.word_timings = None;
+            app.reading_text = None;
+            app.voice_comparison = None;
+            app.chapters.clear();
+            app.pages.clear();
+            app.skim = None;
+            app.speaking_break_reminder = false;
+            if let Some(mut replay) = app.replay_provider.take() {
+                let _ = replay.stop();
+            }
+            app.resume_after_replay = false;
+            clear_loading_state(app);
+            info!("Playback stopped, closing main window");
+            close_window(app, WindowRole::Main)
+        }
+        Message::CycleSpeed => {
+            app.playback_speed = crate::model::next_speed_preset(app.playback_speed);
+            info!(speed = app.playback_speed, "Playback speed changed");
+            let speed = app.reading_speed();
+            if let Some(ref mut provider) = app.provider {
+                provider.set_speed(speed);
+            }
+            config::save_playback_speed(app.playback_speed);
+            Task::none()
+        }
+        Message::StartSpeedCalibration => {
+            let language = app.voice_language_family().unwrap_or_else(|| "en".to_string());
+            info!(%language, "Speed calibration started");
+            app.speed_calibration = Some(SpeedCalibration::new(language, true));
+            speak_calibration_sample(app)
+        }
+        Message::SpeedCalibrationFaster => {
+            if !app.speed_calibration.as_mut().is_some_and(SpeedCalibration::faster) {
+                return Task::none();
+            }
+            speak_calibration_sample(app)
+        }
+        Message::ReplaySpeedSample => speak_calibration_sample(app),
+        Message::SpeedCalibrationDefaultToggled(make_default) => {
+            if let Some(ref mut calibration) = app.speed_calibration {
+                calibration.make_default = make_default;
+            }
+            Task::none()
+        }
+        Message::SpeedCalibrationTooFast => {
+            if let Some(ref mut calibration) = app.speed_calibration {
+                calibration.step = calibration.step.saturating_sub(1);
+            }
+            update(app, Message::FinishSpeedCalibration)
+        }
+        Message::FinishSpeedCalibration => {
+            let Some(calibration) = app.speed_calibration.take() else {
+                return Task::none();
+            };
+            let picked = calibration.speed();
+            info!(language = %calibration.language, picked, calibration.make_default, "Speed calibrated");
+            if calibration.make_default {
+                app.playback_speed = picked;
+                config::save_playback_speed(picked);
+                app.language_speeds.remove(&calibration.language);
+            } else {
+                speed_calibration::set_adjustment(
+                    &mut app.language_speeds,
+                    &calibration.language,
+                    picked,
+                    app.playback_speed,
+                );
+            }
+            config::save_language_speeds(&app.language_speeds);
+            Task::none()
+        }
+        Message::CancelSpeedCalibration => {
+            info!("Speed calibration cancelled");
+            app.speed_calibration = None;
+            Task::none()
+        }
+        Message::ResetLanguageSpeed(language) => {
+            info!(%language, "Language speed adjustment removed");
+            app.language_speeds.remove(&language);
+            config::save_language_speeds(&app.language_speeds);
+            Task::none()
+        }
+        Message::FinishTargetSelected(minutes) => {
+            info!(?minutes, "Finish target changed");
+            app.finish_target_minutes = minutes;
+            config::save_finish_target_minutes(minutes);
+            Task::none()
+        }
+        Message::QueuePauseSelected(seconds) => {
+            info!(seconds, "Queue pause changed");
+            app.queue_pause_seconds = seconds;
+            config::save_queue_pause_seconds(seconds);
+            Task::none()
+        }
+        Message::EndOfReadingSelected(action) => {
+            info!(?action, "End-of-reading action changed");
+            app.end_of_reading = action;
+            config::save_end_of_reading(action, app.end_of_reading_hook.trim());
+            Task::none()
+        }
+        Message::EndOfReadingHookChanged(hook) => {
+            config::save_end_of_reading(app.end_of_reading, hook.trim());
+            app.end_of_reading_hook = hook;
+            Task::none()
+        }
+        Message::PreRollSelected(pre_roll) => {
+            info!(?pre_roll, "Pre-roll changed");
+            app.pre_roll = pre_roll;
+            config::save_pre_roll(pre_roll);
+            Task::none()
+        }
+        Message::BreakIntervalSelected(minutes) => {
+            info!(?minutes, "Break reminder interval changed");
+            app.break_interval_minutes = minutes;
+            config::save_break_interval_minutes(minutes);
+            Task::none()
+        }
+        Message::LargeTextLimitSelected(kb) => {
+            info!(?kb, "Large text limit changed");
+            app.large_text_limit_kb = kb;
+            config::save_large_text_limit_kb(kb);
+            Task::none()
+        }
+        Message::ReadLargeText(paragraphs) => {
+            let Some(large) = app.large_text.take() else {
+                return Task::none();
+            };
+            let text = match paragraphs {
+                Some(count) => large.first_paragraphs(count).to_string(),
+                None => large.text,
+            };
+            info!(?paragraphs, bytes = text.len(), "Reading the large selection");
+            // Selected while something else was being read: it waits its turn
+            if is_reading(app) {
+                app.reading_queue.push(text);
+                return Task::none();
+            }
+            process_text_for_tts(app, text, "LargeText")
+        }
+        Message::DismissLargeText => {
+            info!("Large selection dropped");
+            app.large_text = None;
+            if is_reading(app) {
+                return Task::none();
+            }
+            close_window(app, WindowRole::Main)
+        }
+        Message::IdleReleaseSelected(minutes) => {
+            info!(?minutes, "Idle release time changed");
+            app.idle_release_minutes = minutes;
+            app.last_active = Instant::now();
+            config::save_idle_release_minutes(minutes);
+            Task::none()
+        }
+        Message::SkimModeToggled(enabled) => {
+            info!(enabled, "Skim mode toggled");
+            app.skim_mode = enabled;
+            config::save_skim_mode(enabled);
+            Task::none()
+        }
+        Message::ReviewBeforeReadingToggled(enabled) => {
+            info!(enabled, "Review before reading toggled");
+            app.review_before_reading = enabled;
+            config::save_review_before_reading(enabled);
+            Task::none()
+        }
+        Message::ReviewEditorAction(action) => {
+            if let Some(ref mut editor) = app.review_editor {
+                editor.perform(action);
+            }
+            Task::none()
+        }
+        Message::SpeakReviewed => {
+            let Some(text) = app.review_editor.take().map(|editor| editor.text()) else {
+                return Task::none();
+            };
+            let close = close_window(app, WindowRole::Review);
+            if text.trim().is_empty() {
+                warn!("Reviewed text is empty - closing window");
+                return Task::batch([close, close_window(app, WindowRole::Main)]);
+            }
+            info!(bytes = text.len(), "Reading the reviewed text");
+            app.status_text = None;
+            Task::batch([close, process_text_for_tts(app, text, "Review")])
+        }
+        Message::CancelReview => {
+            info!("Review cancelled");
+            app.review_editor = None;
+            let close = close_window(app, WindowRole::Review);
+            if is_reading(app) {
+                return close;
+            }
+            Task::batch([close, close_window(app, WindowRole::Main)])
+        }
+        Message::IdleCheck => {
+            let Some(minutes) = app.idle_release_minutes else {
+                return Task::none();
+            };
+            let busy = app.playback_state != PlaybackState::Stopped
+                || app.is_loading
+                || app.downloading_voice.is_some()
+                || app.reading_queue.is_waiting()
+                || app.replay_provider.is_some();
+            if busy {
+                app.last_active = Instant::now();
+            } else if app.last_active.elapsed() >= Duration::from_secs(u64::from(minutes) * 60) {
+                release_idle_resources(app, minutes);
+            }
+            Task::none()
+        }
+        Message::BreakMessageChanged(message) => {
+            app.break_message = message;
+            config::save_break_message(&app.break_message);
+            Task::none()
+        }
+        Message::ReplayRecent => {
+            if !providers::replay_buffer::has_recent_audio() {
+                info!("Replay requested but nothing has been played yet");
+                return Task::none();
+            }
+            pause_for_replay(app);
+            info!(seconds = providers::replay_buffer::REPLAY_SECONDS, "Replaying recent audio");
+            let (speed, pitch, output) = (app.playback_speed, app.pitch_semitones, app.effective_output());
+            let prepared = providers::replay_buffer::prepare_replay(speed, pitch, output);
+            let synthesis = synthesize_prepared(&mut app.replay_synthesizing, prepared);
+            Task::perform(async move { synthesis.await.map_err(|e| e.to_string()) }, Message::ReplayReady)
+        }
+        Message::ReplayReady(result) => {
+            let result = match result {
+                Ok(ready) => match play_ready_speech(&mut app.replay_synthesizing, &ready) {
+                    Some(played) => played,
+                    None => {
+                        debug!("Discarding the speech of a superseded replay");
+                        return Task::none();
+                    }
+                },
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(provider) => app.replay_provider = Some(provider),
+                Err(e) => {
+                    warn!(error = %e, "Replay failed");
+                    app.voice_comparison = None;
+                    if std::mem::take(&mut app.resume_after_replay) && app.playback_state == PlaybackState::Paused {
+                        return update(app, Message::PlayPause);
+                    }
+                }
+            }
+            Task::none()
+        }
+        Message::SpellWord => {
+            let Some(provider) = app.provider.as_ref().filter(|_| !app.is_loading) else {
+                return Task::none();
+            };
+            // Step back by the time it took to react to the word
+            let duration = provider.get_duration().max(f32::EPSILON);
+            let heard = provider.get_progress() - SPELL_REACTION_SECONDS * app.playback_speed / duration;
+            let Some(word) = app.word_timings.as_ref().and_then(|timings| timings.word_at(heard.max(0.0))) else {
+                info!("Spelling requested but no word has been read yet");
+                return Task::none();
+            };
+            info!(%word, "Spelling the word just heard");
+            let text = providers::word_timing::spelled(word);
+            pause_for_replay(app);
+            let backend = app.selected_backend;
+            // Letters are spelled no faster than normal speed
+            let (speed, pitch, output) = (app.playback_speed.min(1.0), app.pitch_semitones, app.effective_output());
+            let prepared = create_provider(backend, app.selected_polly_voice.clone())
+                .and_then(|provider| providers::prepare_speech(provider, None, &text, speed, pitch, output));
+            let synthesis = synthesize_prepared(&mut app.replay_synthesizing, prepared);
+            Task::perform(
+                async move { synthesis.await.map_err(|e| format_tts_error(&e.to_string(), backend)) },
+                Message::ReplayReady,
+            )
+        }
+        Message::LoopMarked => {
+            let Some(ref mut provider) = app.provider else {
+                return Task::none();
+            };
+            let position = provider.get_progress() * provider.get_duration();
+            if app.loop_region.take().is_some() {
+                info!("Loop cleared");
+                provider.set_loop(None);
+            } else if let Some(start) = app.loop_start.take() {
+                // Marking B before A swaps them; a near-empty region is dropped
+                let (start, end) = (start.min(position), start.max(position));
+                if end - start >= MIN_LOOP_SECONDS {
+                    info!(start, end, "Looping region");
+                    provider.set_loop(Some((start, end)));
+                    app.loop_region = Some((start, end));
+                }
+            } else {
+                debug!(position, "Loop start marked");
+                app.loop_start = Some(position);
+            }
+            Task::none()
+        }
+        Message::ResumeSavedPosition => {
+            if let Some(seconds) = app.resume_offer.take() {
+                info!(seconds, "Resuming where the reading was stopped last time");
+                app.resume_target = Some(seconds);
+            }
+            Task::none()
+        }
+        Message::SkipQueuePause => {
+            match app.reading_queue.skip_pause() {
+                Some(item) => {
+                    info!(remaining = app.reading_queue.len(), "Queue pause skipped");
+                    process_text_for_tts(app, item, "SkipQueuePause")
+                }
+                None => Task::none(),
+            }
+        }
+        Message::NextQueuedItem => {
+            // Not while synthesizing: the pending result would start on top of the next item
+            if app.is_loading || app.reading_queue.len() == 0 {
+                return Task::none();
+            }
+            remember_position(app);
+            if let Some(ref mut provider) = app.provider {
+                if let Err(e) = provider.stop() {
+                    error!(error = %e, "Failed to stop playback");
+                }
+            }
+            app.playback_state = PlaybackState::Stopped;
+            match app.reading_queue.skip_pause() {
+                Some(item) => {
+                    info!(remaining = app.reading_queue.len(), "Skipped to next queued item");
+                    process_text_for_tts(app, item, "NextQueuedItem")
+                }
+                None => Task::none(),
+            }
+        }
+        Message::NewFeedUrlChanged(url) => {
+            app.new_feed_url = url;
+            Task::none()
+        }
+        Message::AddFeed => {
+            let url = app.new_feed_url.trim().to_string();
+            if url.is_empty() {
+                return Task::none();
+            }
+            let feed = system::FeedSubscription { title: String::new(), url, voice: Default::default() };
+            if system::merge_feeds(&mut app.feeds, vec![feed]) == 0 {
+                app.feeds_status = Some(Err("Already subscribed to that feed.".to_string()));
+            } else {
+                info!(url = %app.new_feed_url.trim(), "Feed added");
+                config::save_feeds(&app.feeds);
+                app.feeds_status = None;
+                app.new_feed_url.clear();
+            }
+            Task::none()
+        }
+        Message::FeedVoiceChanged(index, voice) => {
+            if let Some(feed) = app.feeds.get_mut(index) {
+                feed.voice.voice = Some(voice.trim().to_string()).filter(|voice| !voice.is_empty());
+                config::save_feeds(&app.feeds);
+            }
+            Task::none()
+        }
+        Message::RemoveFeed(index) => {
+            if index < app.feeds.len() {
+                let feed = app.feeds.remove(index);
+                info!(url = %feed.url, "Feed removed");
+                config::save_feeds(&app.feeds);
+            }
+            Task::none()
+        }
+        Message::OpmlPathChanged(path) => {
+            app.opml_path = path;
+            Task::none()
+        }
+        Message::ImportOpml => {
+            let path = std::path::PathBuf::from(app.opml_path.trim());
+            app.feeds_status = Some(match system::import_opml(&path) {
+                Ok(feeds) => {
+                    let count = system::merge_feeds(&mut app.feeds, feeds);
+                    config::save_feeds(&app.feeds);
+                    info!(count, path = %path.display(), "Feeds imported");
+                    Ok(format!("Imported {count} feeds."))
+                }
+                Err(e) => {
+                    warn!(error = %e, "OPML import failed");
+                    Err(e)
+                }
+            });
+            Task::none()
+        }
+        Message::PlayBriefing => {
+            if app.feeds.is_empty() {
+                app.feeds_status = Some(Err("Add a feed to get a briefing.".to_string()));
+                return open_settings_if_needed(app, "Add a feed in Settings to get a morning briefing.".to_string());
+            }
+            if app.briefing_fetching {
+                return Task::none();
+            }
+            info!(feeds = app.feeds.len(), "Fetching morning briefing");
+            app.briefing_fetching = true;
+            app.feeds_status = Some(Ok("Fetching feeds…".to_string()));
+            let heard = app.heard_feed_items.iter().cloned().collect();
+            Task::perform(system::fetch_briefing(app.feeds.clone(), heard), Message::BriefingFetched)
+        }
+        Message::BriefingFetched(result) => {
+            app.briefing_fetching = false;
+            let items = match result {
+                Ok(items) => items,
+                Err(e) => {
+                    warn!(error = %e, "Morning briefing failed");
+                    app.feeds_status = Some(Err(e));
+                    return Task::none();
+                }
+            };
+            if items.is_empty() {
+                info!("Morning briefing: no new items");
+                app.feeds_status = Some(Ok("No new items.".to_string()));
+                return Task::none();
+            }
+
+            // Queued items count as heard, so skipping one doesn't bring it back
+            app.heard_feed_items.extend(items.iter().map(|item| item.id.clone()));
+            let forgotten = app.heard_feed_items.len().saturating_sub(system::MAX_HEARD_ITEMS);
+            app.heard_feed_items.drain(..forgotten);
+            config::save_heard_feed_items(&app.heard_feed_items);
+
+            info!(items = items.len(), "Morning briefing queued");
+            app.feeds_status = Some(Ok(format!("Queued {} new items.", items.len())));
+            for item in items {
+                app.reading_queue.push(QueuedItem { text: item.text, voice: item.voice, ..Default::default() });
+            }
+            if is_reading(app) {
+                return Task::none();
+            }
+            match app.reading_queue.take_next() {
+                Some(item) => start_reading(app, item, "PlayBriefing"),
+                None => Task::none(),
+            }
+        }
+        Message::NewWatchSourceChanged(source) => {
+            app.new_watch_source = source;
+            Task::none()
+        }
+        Message::AddWatch => {
+            let source = app.new_watch_source.trim().to_string();
+            if source.is_empty() {
+                return Task::none();
+            }
+            if app.watched_documents.contains(&source) {
+                app.watch_status = Some(Err("That document is already watched.".to_string()));
+                return Task::none();
+            }
+            info!(%source, "Watching document");
+            app.watched_documents.push(source);
+            config::save_watched_documents(&app.watched_documents);
+            app.new_watch_source.clear();
+            // Take the first snapshot right away, so changes count from now
+            update(app, Message::CheckWatched)
+        }
+        Message::RemoveWatch(index) => {
+            if index < app.watched_documents.len() {
+                let source = app.watched_documents.remove(index);
+                info!(%source, "Stopped watching document");
+                system::forget_snapshot(&source);
+                config::save_watched_documents(&app.watched_documents);
+            }
+            Task::none()
+        }
+        Message::CheckWatched => {
+            if app.watched_documents.is_empty() || app.watch_checking {
+                return Task::none();
+            }
+            app.watch_checking = true;
+            Task::perform(system::check_watched(app.watched_documents.clone()), Message::WatchedChecked)
+        }
+        Message::WatchedChecked(result) => {
+            app.watch_checking = false;
+            let checked_at = chrono::Local::now().format("%H:%M");
+            let changes = match result {
+                Ok(changes) => changes,
+                Err(e) => {
+                    warn!(error = %e, "Checking watched documents failed");
+                    app.watch_status = Some(Err(e));
+                    return Task::none();
+                }
+            };
+            app.watch_status = Some(Ok(match changes.len() {
+                0 => format!("Checked at {checked_at}: no changes."),
+                count => format!("Checked at {checked_at}: {count} changed, reading the new paragraphs."),
+            }));
+            let tasks: Vec<_> = changes
+                .into_iter()
+                .map(|change| {
+                    info!(source = %change.source, "Watched document changed");
+                    read_automatically(app, change.text, "WatchedDocument")
+                })
+                .collect();
+            Task::batch(tasks)
+        }
+        Message::ReadAgenda => {
+            if !app.calendar.is_configured() {
+                app.calendar_status = Some(Err("Set a calendar file or URL to hear your agenda.".to_string()));
+                return open_settings_if_needed(app, "Set a calendar in Settings to hear today's agenda.".to_string());
+            }
+            if app.agenda_fetching {
+                return Task::none();
+            }
+            info!("Fetching today's agenda");
+            app.agenda_fetching = true;
+            app.calendar_status = Some(Ok("Fetching calendar…".to_string()));
+            Task::perform(system::fetch_agenda(app.calendar.clone()), Message::AgendaFetched)
+        }
+        Message::AgendaFetched(result) => {
+            app.agenda_fetching = false;
+            let text = match result {
+                Ok(text) => text,
+                Err(e) => {
+                    warn!(error = %e, "Agenda fetch failed");
+                    app.calendar_status = Some(Err(e));
+                    return Task::none();
+                }
+            };
+            app.calendar_status = Some(Ok(text.clone()));
+            if is_reading(app) {
+                info!("Agenda queued");
+                app.reading_queue.push(text);
+                return Task::none();
+            }
+            start_reading(app, text, "ReadAgenda")
+        }
+        Message::CalendarSourceChanged(source) => {
+            app.calendar.source = source;
+            app.calendar_status = None;
+            config::save_calendar_settings(&app.calendar);
+            Task::none()
+        }
+        Message::CalendarUsernameChanged(username) => {
+            app.calendar.username = username;
+            config::save_calendar_settings(&app.calendar);
+            Task::none()
+        }
+        Message::CalendarPasswordChanged(password) => {
+            app.calendar.password = password;
+            config::save_calendar_settings(&app.calendar);
+            Task::none()
+        }
+        Message::QuietHoursToggled(enabled) => {
+            info!(enabled, "Quiet hours toggled");
+            app.quiet_hours_enabled = enabled;
+            config::save_quiet_hours(enabled, &app.quiet_hours_input);
+            update(app, Message::QuietHoursCheck)
+        }
+        Message::QuietHoursChanged(input) => {
+            match system::parse_quiet_hours(&input) {
+                Ok(ranges) => {
+                    debug!(ranges = ranges.len(), "Quiet hours updated");
+                    app.quiet_hours = ranges;
+                    app.quiet_hours_error = None;
+                    config::save_quiet_hours(app.quiet_hours_enabled, &input);
+                }
+                Err(e) => app.quiet_hours_error = Some(e),
+            }
+            app.quiet_hours_input = input;
+            update(app, Message::QuietHoursCheck)
+        }
+        Message::SaveReadingsToggled(enabled) => {
+            info!(enabled, "Saving readings toggled");
+            app.save_readings = enabled;
+            config::save_recordings(enabled, &app.recordings_folder);
+            Task::none()
+        }
+        Message::RecordingsFolderChanged(folder) => {
+            config::save_recordings(app.save_readings, folder.trim());
+            app.recordings_folder = folder;
+            Task::none()
+        }
+        Message::ReadingSaved(result) => {
+            match result {
+                Ok(path) => info!(path = %path.display(), "Reading saved"),
+                Err(e) => {
+                    error!(error = %e, "Failed to save reading");
+                    app.error_message = Some(format!("Failed to save reading: {e}"));
+                }
+            }
+            Task::none()
+        }
+        Message::HandoffFolderChanged(folder) => {
+            config::save_handoff_folder(folder.trim());
+            app.handoff_folder = folder;
+            Task::none()
+        }
+        Message::ListenWebhookChanged(url) => {
+            config::save_listen_webhook(url.trim(), app.listen_webhook_text);
+            app.listen_webhook = url;
+            app.listen_webhook_status = None;
+            Task::none()
+        }
+        Message::ListenWebhookTextToggled(include_text) => {
+            info!(include_text, "Listening webhook text toggled");
+            app.listen_webhook_text = include_text;
+            config::save_listen_webhook(app.listen_webhook.trim(), include_text);
+            Task::none()
+        }
+        Message::ListenWebhookSent(result) => {
+            match &result {
+                Ok(()) => info!("Reading summary posted to webhook"),
+                Err(e) => warn!(error = %e, "Failed to post reading summary to webhook"),
+            }
+            app.listen_webhook_status = Some(result.map(|()| {
+                format!("Last reading sent at {}.", chrono::Local::now().format("%H:%M"))
+            }));
+            Task::none()
+        }
+        Message::TranscriptModeSelected(mode) => {
+            info!(?mode, "Transcript log mode selected");
+            app.transcript_mode = mode;
+            config::save_transcript(mode, app.transcript_format);
+            Task::none()
+        }
+        Message::TranscriptFormatSelected(format) => {
+            info!(?format, "Transcript format selected");
+            app.transcript_format = format;
+            config::save_transcript(app.transcript_mode, format);
+            Task::none()
+        }
+        Message::HandoffExported(result) => {
+            match result {
+                Ok(path) => info!(path = %path.display(), "Reading handed off to phone folder"),
+                Err(e) => {
+                    error!(error = %e, "Failed to hand off reading");
+                    app.error_message = Some(format!("Failed to send reading to phone folder: {e}"));
+                }
+            }
+            Task::none()
+        }
+        Message::QuietHoursCheck => {
+            let ended = refresh_quiet_hours(app);
+            if ended && !is_reading(app) {
+                // Read what was held back during quiet hours
+                if let Some(item) = app.reading_queue.take_next() {
+                    info!(remaining = app.reading_queue.len(), "Quiet hours ended, reading held items");
+                    return start_reading(app, item, "QuietHoursEnded");
+                }
+            }
+            Task::none()
+        }
+        Message::OutputDevicesLoaded(devices) => {
+            debug!(count = devices.len(), "Audio output devices listed");
+            app.output_devices = devices;
+            Task::none()
+        }
+        Message::OutputDeviceSelected(device) => {
+            let device = (device != crate::model::DEFAULT_OUTPUT_DEVICE).then_some(device);
+            info!(?device, profile = ?app.active_profile, "Output device changed");
+            editable_output(app).device = device;
+            save_output(app);
+            apply_output(app);
+            Task::none()
+        }
+        Message::MirrorDeviceSelected(device) => {
+            let device = (device != crate::model::NO_MIRROR_DEVICE).then_some(device);
+            info!(?device, profile = ?app.active_profile, "Mirror output device changed");
+            editable_output(app).mirror_device = device;
+            save_output(app);
+            apply_output(app);
+            Task::none()
+        }
+        Message::VirtualMicToggled(enabled) => {
+            info!(enabled, profile = ?app.active_profile, "Virtual microphone toggled");
+            editable_output(app).virtual_mic = enabled;
+            save_output(app);
+            apply_output(app);
+            if enabled {
+                prepare_virtual_mic_task()
+            } else {
+                app.virtual_mic_status = None;
+                if !app.output.virtual_mic && !app.profiles.iter().any(|p| p.output.virtual_mic) {
+                    providers::virtual_mic::remove();
+                }
+                Task::none()
+            }
+        }
+        Message::NormalizeLoudnessToggled(enabled) => {
+            info!(enabled, profile = ?app.active_profile, "Loudness normalization toggled");
+            editable_output(app).normalize_loudness = enabled;
+            save_output(app);
+            apply_output(app);
+            Task::none()
+        }
+        Message::ShortenPausesToggled(enabled) => {
+            info!(enabled, profile = ?app.active_profile, "Pause shortening toggled");
+            editable_output(app).shorten_pauses = enabled;
+            save_output(app);
+            apply_output(app);
+            Task::none()
+        }
+        Message::OutputChannelsSelected(channels) => {
+            info!(?channels, profile = ?app.active_profile, "Output channels changed");
+            editable_output(app).channels = channels;
+            save_output(app);
+            apply_output(app);
+            Task::none()
+        }
+        Message::VirtualMicPrepared(result) => {
+            match &result {
+                Ok(name) => {
+                    info!(name, "Virtual microphone ready");
+                    // The provider couldn't route to it if it didn't exist yet
+                    apply_output(app);
+                }
+                Err(e) => warn!(error = %e, "Virtual microphone unavailable"),
+            }
+            app.virtual_mic_status = Some(result);
+            Task::none()
+        }
+        Message::VolumeChanged(volume) => {
+            editable_output(app).volume = volume;
+            apply_output(app);
+            Task::none()
+        }
+        Message::VolumeReleased => {
+            debug!(volume = app.effective_output().volume, "Volume changed");
+            save_output(app);
+            Task::none()
+        }
+        Message::PitchChanged(semitones) => {
+            app.pitch_semitones = semitones;
+            if let Some(ref mut provider) = app.provider {
+                provider.set_pitch(semitones);
+            }
+            Task::none()
+        }
+        Message::PitchReleased => {
+            info!(semitones = app.pitch_semitones, "Pitch changed");
+            config::save_pitch_semitones(app.pitch_semitones);
+            Task::none()
+        }
+        Message::ProfilesAutoSwitchToggled(enabled) => {
+            info!(enabled, "Workspace profile auto-switching toggled");
+            app.profiles_auto_switch = enabled;
+            config::save_profiles(enabled, &app.profiles);
+            refresh_active_profile(app);
+            if enabled { detect_network_task() } else { Task::none() }
+        }
+        Message::ProfileCheck => detect_network_task(),
+        Message::NetworkDetected(network) => {
+            if network != app.network {
+                debug!(?network, "Network context changed");
+                app.network = network;
+            }
+            refresh_active_profile(app);
+            Task::none()
+        }
+        Message::NewProfileNameChanged(name) => {
+            app.new_profile_name = name;
+            Task::none()
+        }
+        Message::NewProfileMatchChanged(matches) => {
+            app.new_profile_match = matches;
+            Task::none()
+        }
+        Message::AddProfile => {
+            let name = app.new_profile_name.trim().to_string();
+            let mut matches: Vec<String> = app
+                .new_profile_match
+                .split(',')
+                .map(str::trim)
+                .filter(|m| !m.is_empty())
+                .map(str::to_string)
+                .collect();
+            if matches.is_empty() {
+                matches.extend(app.network.preferred_match().map(str::to_string));
+            }
+            if name.is_empty() || matches.is_empty() {
+                warn!("Workspace profile needs a name and a network or hostname");
+                return Task::none();
+            }
+            info!(name = %name, ?matches, "Workspace profile added");
+            let output = app.effective_output().clone();
+            app.profiles.push(system::ReadingProfile { name, matches, output });
+            app.new_profile_name.clear();
+            app.new_profile_match.clear();
+            config::save_profiles(app.profiles_auto_switch, &app.profiles);
+            refresh_active_profile(app);
+            Task::none()
+        }
+        Message::DeleteProfile(index) => {
+            if index < app.profiles.len() {
+                let removed = app.profiles.remove(index);
+                info!(name = %removed.name, "Workspace profile deleted");
+                config::save_profiles(app.profiles_auto_switch, &app.profiles);
+                // Indices shifted: re-match from scratch
+                app.active_profile = None;
+                refresh_active_profile(app);
+                if app.active_profile.is_none() {
+                    apply_output(app);
+                }
+            }
+            Task::none()
+        }
+        Message::OpenPhraseBoard => {
+            if app.has_window(WindowRole::PhraseBoard) {
+                debug!("Phrase board already open, ignoring request");
+                return Task::none();
+            }
+
+            debug!("Opening phrase board window");
+            let (window_id, task) = open_info_window(Size::new(520.0, 420.0));
+            app.windows.insert(window_id, WindowRole::PhraseBoard);
+            task
+        }
+        Message::ClosePhraseBoard => {
+            app.editing_phrases = false;
+            close_window(app, WindowRole::PhraseBoard)
+        }
+        Message::OpenReadLater => {
+            if app.has_window(WindowRole::ReadLater) {
+                debug!("Read-later window already open, ignoring request");
+                return Task::none();
+            }
+
+            debug!("Opening read-later window");
+            let (window_id, task) = open_info_window(Size::new(560.0, 480.0));
+            app.windows.insert(window_id, WindowRole::ReadLater);
+            if app.read_later_articles.is_empty() {
+                return Task::batch([task, update(app, Message::RefreshReadLater)]);
+            }
+            task
+        }
+        Message::CloseReadLater => close_window(app, WindowRole::ReadLater),
+        Message::DebugOverlayToggled(enabled) => {
+            info!(enabled, "Debug overlay toggled");
+            app.debug_overlay = enabled;
+            config::save_debug_overlay(enabled);
+            if enabled {
+                open_debug_overlay(app)
+            } else {
+                close_window(app, WindowRole::DebugOverlay)
+            }
+        }
+        Message::ResetLatency => {
+            latency::reset();
+            Task::none()
+        }
+        Message::RefreshReadLater => {
+            if !app.read_later.is_configured() {
+                app.read_later_error = Some("Set up Wallabag or Readwise Reader in Settings first.".to_string());
                 return Task::none();
-            };
-            
-            match app.playback_state {
-                PlaybackState::Playing => {
-                    if let Err(e) = provider.pause() {
-                        error!(error = %e, "Failed to pause playback");
-                    } else {
-                        app.playback_state = PlaybackState::Paused;
-                        info!("Playback paused");
-                    }
-                }
-                PlaybackState::Paused => {
-                    if let Err(e) = provider.resume() {
-                        error!(error = %e, "Failed to resume playback");
-                    } else {
-                        app.playback_state = PlaybackState::Playing;
-                        info!("Playback resumed");
-                    }
+            }
+            if app.read_later_loading {
+                return Task::none();
+            }
+            app.read_later_loading = true;
+            app.read_later_error = None;
+            Task::perform(system::fetch_articles(app.read_later.clone()), Message::ReadLaterFetched)
+        }
+        Message::ReadLaterFetched(result) => {
+            app.read_later_loading = false;
+            match result {
+                Ok(articles) => app.read_later_articles = articles,
+                Err(e) => {
+                    warn!(error = %e, "Failed to fetch read-later articles");
+                    app.read_later_error = Some(e);
                 }
-                PlaybackState::Stopped => {}
             }
             Task::none()
         }
-        Message::Stop => {
+        Message::ReadArticle(index) => {
+            let Some(article) = app.read_later_articles.get(index).cloned() else {
+                return Task::none();
+            };
+            info!(id = %article.id, title = %article.title, "Reading read-later article");
             if let Some(ref mut provider) = app.provider {
                 if let Err(e) = provider.stop() {
                     error!(error = %e, "Failed to stop playback");
                 }
             }
             app.playback_state = PlaybackState::Stopped;
-            app.progress = 0.0;
-            app.frequency_bands = vec![0.0; NUM_BANDS];
-            clear_loading_state(app);
-            info!("Playback stopped, closing main window");
-            window::latest().and_then(window::close)
+            let task = start_reading(app, article.text, "ReadArticle");
+            app.reading_article = Some(article.id);
+            task
+        }
+        Message::ArticleMarkedRead(result) => {
+            match result {
+                Ok(id) => {
+                    info!(%id, "Read-later article archived");
+                    app.read_later_articles.retain(|article| article.id != id);
+                }
+                Err(e) => {
+                    warn!(error = %e, "Failed to archive read-later article");
+                    app.read_later_error = Some(format!("Could not mark the article as read: {e}"));
+                }
+            }
+            Task::none()
+        }
+        Message::ReadLaterServiceSelected(service) => {
+            debug!(?service, "Read-later service changed");
+            app.read_later.service = service;
+            app.read_later_articles.clear();
+            config::save_read_later_settings(&app.read_later);
+            Task::none()
+        }
+        Message::OpenQueue => {
+            if app.has_window(WindowRole::Queue) {
+                debug!("Queue window already open, ignoring request");
+                return Task::none();
+            }
+
+            debug!("Opening queue window");
+            let (window_id, task) = open_info_window(Size::new(520.0, 600.0));
+            app.windows.insert(window_id, WindowRole::Queue);
+            task
+        }
+        Message::CloseQueue => close_window(app, WindowRole::Queue),
+        Message::QueueDragStarted(id) => {
+            app.queue_drag = Some(id);
+            Task::none()
+        }
+        Message::QueueDraggedOver(index) => {
+            if let Some(id) = app.queue_drag {
+                app.reading_queue.move_to(id, index);
+            }
+            Task::none()
+        }
+        Message::QueueDragEnded => {
+            if app.queue_drag.take().is_some() {
+                debug!("Queued item moved");
+            }
+            Task::none()
+        }
+        Message::RemoveQueuedItem(id) => {
+            if app.reading_queue.remove(id).is_some() {
+                info!(remaining = app.reading_queue.len(), "Queued item removed");
+            }
+            if app.queue_editor.as_ref().is_some_and(|(editing, _)| *editing == id) {
+                app.queue_editor = None;
+            }
+            Task::none()
+        }
+        Message::EditQueuedItem(id) => {
+            let Some(text) = app
+                .reading_queue
+                .entries()
+                .find(|(other, _)| *other == id)
+                .map(|(_, item)| item.text.clone())
+            else {
+                return Task::none();
+            };
+            debug!(id, "Editing queued item");
+            app.reading_queue.hold(Some(id));
+            app.queue_editor = Some((id, iced::widget::text_editor::Content::with_text(&text)));
+            Task::none()
+        }
+        Message::QueueEditorAction(action) => {
+            if let Some((_, ref mut content)) = app.queue_editor {
+                content.perform(action);
+            }
+            Task::none()
+        }
+        Message::SaveQueuedItem => {
+            let Some((id, content)) = app.queue_editor.take() else {
+                return Task::none();
+            };
+            app.reading_queue.hold(None);
+            let text = content.text();
+            if text.trim().is_empty() {
+                app.reading_queue.remove(id);
+                info!("Queued item emptied, removed");
+            } else if !app.reading_queue.set_text(id, text.clone()) {
+                // Cleared from the queue (e.g. by Stop) while being edited
+                app.reading_queue.push(text);
+                info!("Edited item was no longer queued, queued again");
+            }
+            Task::none()
+        }
+        Message::CancelQueuedItemEdit => {
+            app.queue_editor = None;
+            app.reading_queue.hold(None);
+            Task::none()
+        }
+        Message::NewPlaylistNameChanged(name) => {
+            app.new_playlist_name = name;
+            Task::none()
+        }
+        Message::SaveQueueAsPlaylist => {
+            let name = app.new_playlist_name.trim().to_string();
+            if name.is_empty() {
+                return Task::none();
+            }
+            let items: Vec<QueuedItem> = app.reading_queue.entries().map(|(_, item)| item.clone()).collect();
+            if items.is_empty() {
+                app.playlist_status = Some(Err("Queue some texts first: the waiting items are saved.".to_string()));
+                return Task::none();
+            }
+            info!(%name, items = items.len(), "Queue saved as playlist");
+            app.playlist_status = Some(Ok(format!("Saved \"{name}\" with {} items.", items.len())));
+            system::merge_playlists(&mut app.playlists, vec![system::Playlist { name, items }]);
+            system::save_playlists(&app.playlists);
+            app.new_playlist_name.clear();
+            Task::none()
+        }
+        Message::LoadPlaylist(index) => {
+            let Some(playlist) = app.playlists.get(index).cloned() else {
+                return Task::none();
+            };
+            info!(name = %playlist.name, items = playlist.items.len(), "Playlist queued");
+            app.playlist_status = Some(Ok(format!("Queued \"{}\".", playlist.name)));
+            for item in playlist.items {
+                app.reading_queue.push(item);
+            }
+            if is_reading(app) {
+                return Task::none();
+            }
+            match app.reading_queue.take_next() {
+                Some(item) => start_reading(app, item, "LoadPlaylist"),
+                None => Task::none(),
+            }
+        }
+        Message::DeletePlaylist(index) => {
+            if index < app.playlists.len() {
+                let playlist = app.playlists.remove(index);
+                info!(name = %playlist.name, "Playlist deleted");
+                system::save_playlists(&app.playlists);
+            }
+            Task::none()
+        }
+        Message::PlaylistPathChanged(path) => {
+            app.playlist_path = path;
+            Task::none()
+        }
+        Message::ImportPlaylists => {
+            let path = std::path::PathBuf::from(app.playlist_path.trim());
+            app.playlist_status = Some(match system::import_playlists(&path) {
+                Ok(playlists) => {
+                    let count = system::merge_playlists(&mut app.playlists, playlists);
+                    system::save_playlists(&app.playlists);
+                    info!(count, path = %path.display(), "Playlists imported");
+                    Ok(format!("Imported {count} playlists."))
+                }
+                Err(e) => {
+                    warn!(error = %e, "Playlist import failed");
+                    Err(e)
+                }
+            });
+            Task::none()
+        }
+        Message::OpenFilePathChanged(path) => {
+            app.open_file_path = path;
+            Task::none()
+        }
+        Message::OpenFile => {
+            let path = std::path::PathBuf::from(app.open_file_path.trim());
+            open_file_task(app, path)
+        }
+        Message::FileDropped(path) => {
+            info!(path = %path.display(), "File dropped");
+            app.open_file_path = path.display().to_string();
+            open_file_task(app, path)
+        }
+        Message::FileOpened(path, result) => match result {
+            Ok(document) => {
+                let name = path.file_name().unwrap_or(path.as_os_str()).to_string_lossy().into_owned();
+                info!(path = %path.display(), bytes = document.text.len(), pages = document.pages.len(), "File opened");
+                app.open_file_status = Some(Ok(match document.pages.len() {
+                    0 => format!("Reading {name}."),
+                    pages => format!("Reading {name}, {pages} pages."),
+                }));
+                let item = QueuedItem { text: document.text, pages: document.pages, ..Default::default() };
+                start_reading(app, item, "FileOpened")
+            }
+            Err(e) => {
+                warn!(error = %e, "Opening the file failed");
+                app.open_file_status = Some(Err(e));
+                Task::none()
+            }
+        },
+        Message::ExportPlaylist(index) => {
+            let Some(playlist) = app.playlists.get(index) else {
+                return Task::none();
+            };
+            let path = std::path::PathBuf::from(app.playlist_path.trim());
+            app.playlist_status = Some(match system::export_playlist(&path, playlist) {
+                Ok(()) => {
+                    info!(name = %playlist.name, path = %path.display(), "Playlist exported");
+                    Ok(format!("Exported \"{}\".", playlist.name))
+                }
+                Err(e) => {
+                    warn!(error = %e, "Playlist export failed");
+                    Err(e)
+                }
+            });
+            Task::none()
+        }
+        Message::WallabagUrlChanged(url) => {
+            app.read_later.wallabag_url = url;
+            config::save_read_later_settings(&app.read_later);
+            Task::none()
+        }
+        Message::WallabagClientIdChanged(client_id) => {
+            app.read_later.wallabag_client_id = client_id;
+            config::save_read_later_settings(&app.read_later);
+            Task::none()
+        }
+        Message::WallabagClientSecretChanged(client_secret) => {
+            app.read_later.wallabag_client_secret = client_secret;
+            config::save_read_later_settings(&app.read_later);
+            Task::none()
+        }
+        Message::WallabagUsernameChanged(username) => {
+            app.read_later.wallabag_username = username;
+            config::save_read_later_settings(&app.read_later);
+            Task::none()
+        }
+        Message::WallabagPasswordChanged(password) => {
+            app.read_later.wallabag_password = password;
+            config::save_read_later_settings(&app.read_later);
+            Task::none()
+        }
+        Message::ReadwiseTokenChanged(token) => {
+            app.read_later.readwise_token = token;
+            config::save_read_later_settings(&app.read_later);
+            Task::none()
+        }
+        Message::SpeakPhrase(index) => match app.phrases.get(index).cloned() {
+            Some(text) => speak_phrase(app, text),
+            None => Task::none(),
+        },
+        Message::PhraseReady(result) => {
+            let spoken = result.is_ok();
+            let task = update(app, Message::TTSInitialized(result));
+            app.speaking_phrase = spoken && app.playback_state == PlaybackState::Playing;
+            app.speaking_break_reminder &= app.speaking_phrase;
+            task
+        }
+        Message::TogglePhraseEditing => {
+            app.editing_phrases = !app.editing_phrases;
+            Task::none()
+        }
+        Message::NewPhraseChanged(phrase) => {
+            app.new_phrase = phrase;
+            Task::none()
+        }
+        Message::AddPhrase => {
+            let phrase = app.new_phrase.trim().to_string();
+            if phrase.is_empty() || app.phrases.contains(&phrase) {
+                return Task::none();
+            }
+            info!(%phrase, "Phrase added");
+            app.phrases.push(phrase);
+            app.new_phrase.clear();
+            config::save_phrases(&app.phrases);
+            Task::none()
+        }
+        Message::DeletePhrase(index) => {
+            if index < app.phrases.len() {
+                let removed = app.phrases.remove(index);
+                info!(phrase = %removed, "Phrase deleted");
+                config::save_phrases(&app.phrases);
+                retain_cached_phrases(app);
+            }
+            Task::none()
+        }
+        Message::OpenCrashReport => {
+            let Some(report) = app.crash_report.clone() else {
+                return Task::none();
+            };
+            app.crash_report_status = Some(match open::that(&report) {
+                Ok(()) => Ok("Report opened.".to_string()),
+                Err(e) => {
+                    error!(error = %e, report = %report.display(), "Failed to open crash report");
+                    Err(format!("Could not open the report: {e}"))
+                }
+            });
+            Task::none()
+        }
+        Message::CopyCrashReport => {
+            let Some(report) = app.crash_report.clone() else {
+                return Task::none();
+            };
+            let copied = std::fs::read_to_string(&report)
+                .map_err(|e| e.to_string())
+                .and_then(|content| system::copy_to_clipboard(&content));
+            app.crash_report_status = Some(match copied {
+                Ok(()) => Ok("Report copied to the clipboard.".to_string()),
+                Err(e) => {
+                    error!(error = %e, "Failed to copy crash report");
+                    Err(format!("Could not copy the report: {e}"))
+                }
+            });
+            Task::none()
+        }
+        Message::CloseCrashReport => {
+            app.crash_report = None;
+            app.crash_report_status = None;
+            close_window(app, WindowRole::CrashReport)
+        }
+        Message::OpenTypeToTalk => {
+            if app.has_window(WindowRole::TypeToTalk) {
+                debug!("Type-to-talk window already open, ignoring request");
+                return Task::none();
+            }
+
+            debug!("Opening type-to-talk window");
+            let (window_id, task) = open_info_window(Size::new(520.0, 420.0));
+            app.windows.insert(window_id, WindowRole::TypeToTalk);
+            task.chain(iced::widget::operation::focus(crate::model::TALK_INPUT_ID))
+        }
+        Message::CloseTypeToTalk => close_window(app, WindowRole::TypeToTalk),
+        Message::TalkInputChanged(input) => {
+            let echo = app.typing_echo.echo(&app.talk_input, &input);
+            app.talk_input = input;
+            match echo {
+                Some(echo) => speak_phrase(app, echo),
+                None => Task::none(),
+            }
         }
+        Message::TypingEchoSelected(echo) => {
+            info!(?echo, "Typing echo selected");
+            app.typing_echo = echo;
+            config::save_typing_echo(echo);
+            Task::none()
+        }
+        Message::TalkSubmit => {
+            let line = app.talk_input.trim().to_string();
+            if line.is_empty() {
+                return Task::none();
+            }
+            app.talk_input.clear();
+            app.talk_history.push(line.clone());
+            if app.talk_history.len() > crate::model::MAX_TALK_HISTORY {
+                app.talk_history.remove(0);
+                retain_cached_phrases(app);
+            }
+            speak_phrase(app, line)
+        }
+        Message::ResayTalk(index) => match app.talk_history.get(index).cloned() {
+            Some(line) => speak_phrase(app, line),
+            None => Task::none(),
+        },
         Message::Tick => {
+            // Ticks only run while something is playing, paused or loading
+            app.last_active = Instant::now();
+            latency::tick(app.last_active);
+
+            // Carry on with the reading once a replay finished (after the
+            // sentence the comparison voice read, if that was the replay)
+            if app.replay_provider.as_ref().is_some_and(|replay| !replay.is_playing()) {
+                app.replay_provider = None;
+                if let Some(comparison) = app.voice_comparison.take().filter(|comparison| comparison.speaking) {
+                    let duration = app.provider.as_ref().map_or(0.0, |provider| provider.get_duration());
+                    app.resume_target = Some(comparison.end * duration);
+                }
+                if std::mem::take(&mut app.resume_after_replay) && app.playback_state == PlaybackState::Paused {
+                    info!("Replay finished, resuming reading");
+                    return update(app, Message::PlayPause);
+                }
+            }
+
+            // Count down the pause between queued items
+            if app.reading_queue.is_waiting() {
+                if let Some(item) = app.reading_queue.poll(Instant::now()) {
+                    info!(remaining = app.reading_queue.len(), "Starting next queued item");
+                    return process_text_for_tts(app, item, "ReadingQueue");
+                }
+                app.status_text = app.reading_queue.countdown(Instant::now()).map(|left| {
+                    queue_countdown_status(left.as_secs_f32().ceil() as u32, app.reading_queue.len())
+                });
+                return Task::none();
+            }
+
+            // Cache the reading once all of its audio has been synthesized
+            // (not while the next one is loading: the audio would be the old reading's)
+            if app.audio_cache_pending.is_some() && !app.is_loading {
+                if let Some(audio) = app.provider.as_ref().and_then(|p| p.synthesized_audio()) {
+                    let (voice, text) = app.audio_cache_pending.take().unwrap_or_default();
+                    let limit_mb = app.audio_cache_limit_mb;
+                    return Task::perform(
+                        async move {
+                            tokio::task::spawn_blocking(move || {
+                                providers::audio_cache::store(&voice, &text, &audio, limit_mb)
+                            })
+                            .await
+                            .unwrap_or_else(|e| Err(format!("Caching task failed: {e}")))
+                        },
+                        Message::ReadingCached,
+                    );
+                }
+            }
+
+            // Save / hand off the reading once all of its audio has been synthesized
+            if app.recording_text.is_some() {
+                if let Some(audio) = app.provider.as_ref().and_then(|p| p.synthesized_audio()) {
+                    let text = app.recording_text.take().unwrap_or_default();
+                    return export_reading(app, text, audio);
+                }
+            }
+
             // Handle loading animation (for TTS or voice downloads)
             if app.is_loading || app.downloading_voice.is_some() {
                 app.loading_animation_time += 0.15; // Increment animation time (faster animation)
@@ -354,14 +2658,109 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                         })
                         .collect();
                 }
-            } else if let Some(ref provider) = app.provider {
+            } else if let Some(ref mut provider) = app.provider {
+                // Streamed audio can only be skipped as far as it is synthesized: keep
+                // moving toward the resume target until it is reached
+                if let Some(target) = app.resume_target {
+                    let position = provider.get_progress() * provider.get_duration();
+                    if position + 1.0 >= target {
+                        app.resume_target = None;
+                    } else {
+                        provider.skip_forward(target - position);
+                    }
+                }
                 app.progress = provider.get_progress();
+                if app.resume_offer.is_some() && app.progress * provider.get_duration() > RESUME_OFFER_SECONDS {
+                    app.resume_offer = None;
+                }
+                // The sentence for the comparison voice is up
+                let comparison_due = app
+                    .voice_comparison
+                    .as_ref()
+                    .is_some_and(|comparison| !comparison.speaking && app.progress >= comparison.start);
+                if comparison_due && app.playback_state == PlaybackState::Playing {
+                    return speak_voice_comparison(app);
+                }
+
+                // Finish-time estimate and "finish within" suggestion
+                let duration = provider.get_duration();
+                let remaining_at_normal = crate::model::remaining_seconds(duration, app.progress, 1.0);
+                app.remaining_seconds = crate::model::remaining_seconds(duration, app.progress, app.playback_speed);
+                app.suggested_speed = app.finish_target_minutes.and_then(|minutes| {
+                    crate::model::suggested_speed(remaining_at_normal, app.playback_speed, minutes)
+                });
+                let fft_started = Instant::now();
                 app.frequency_bands = provider.get_frequency_bands(NUM_BANDS);
+                latency::record(Stage::Fft, fft_started.elapsed());
+                app.buffering = provider.is_buffering();
+                if provider.is_playing() {
+                    app.break_timer.listening(Instant::now());
+                }
 
                 if !provider.is_playing() && !provider.is_paused() {
-                    info!("Playback finished, stopping and closing window");
                     app.playback_state = PlaybackState::Stopped;
-                    return window::latest().and_then(window::close);
+                    app.buffering = false;
+                    app.resume_offer = None;
+                    app.resume_target = None;
+                    if let Some(key) = app.reading_key.take() {
+                        system::forget_position(&key);
+                    }
+                    if app.speaking_phrase {
+                        app.speaking_phrase = false;
+                        app.frequency_bands = vec![0.0; NUM_BANDS];
+                        if std::mem::take(&mut app.speaking_break_reminder) {
+                            // The reminder sat between two readings: carry on with the queue
+                            info!("Break reminder finished");
+                        } else {
+                            // Keep the windows open for the next phrase
+                            info!("Phrase finished");
+                            return Task::none();
+                        }
+                    }
+                    let listen_summary = post_listen_summary(app, duration);
+                    // A read-later article listened to the end is archived
+                    let archive_article = match app.reading_article.take() {
+                        Some(id) => {
+                            info!(%id, "Article finished, archiving it");
+                            Task::perform(
+                                system::mark_article_read(app.read_later.clone(), id),
+                                Message::ArticleMarkedRead,
+                            )
+                        }
+                        None => Task::none(),
+                    };
+                    if let Some(minutes) = app.break_interval_minutes {
+                        if app.break_timer.is_due(Duration::from_secs(minutes as u64 * 60), Instant::now()) {
+                            let text = break_reminder::reminder_text(&app.break_message, app.break_timer.listened());
+                            info!(minutes, "Speaking break reminder");
+                            app.break_timer.reminded();
+                            let reminder = speak_phrase(app, text);
+                            app.speaking_break_reminder = true;
+                            return Task::batch([listen_summary, archive_article, reminder]);
+                        }
+                    }
+                    if app.end_of_reading == EndOfReading::StayOpen {
+                        info!("Playback finished, staying open to read it again");
+                        app.frequency_bands = vec![0.0; NUM_BANDS];
+                        app.finished_item = app.reading_item.clone();
+                        return Task::batch([listen_summary, archive_article]);
+                    }
+                    if app.end_of_reading == EndOfReading::RunHook {
+                        run_end_of_reading_hook(app);
+                    }
+                    let pause = Duration::from_secs(app.queue_pause_seconds as u64);
+                    let next = if let Some(item) = app.reading_queue.item_finished(pause, Instant::now()) {
+                        info!(remaining = app.reading_queue.len(), "Playback finished, starting next queued item");
+                        process_text_for_tts(app, item, "ReadingQueue")
+                    } else if app.reading_queue.is_waiting() {
+                        info!(seconds = app.queue_pause_seconds, "Playback finished, pausing before next queued item");
+                        app.frequency_bands = vec![0.0; NUM_BANDS];
+                        app.status_text = Some(queue_countdown_status(app.queue_pause_seconds, app.reading_queue.len()));
+                        Task::none()
+                    } else {
+                        end_reading(app)
+                    };
+                    return Task::batch([listen_summary, archive_article, next]);
                 }
             } else {
                 trace!("Tick received with no active provider");
@@ -369,7 +2768,7 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             Task::none()
         }
         Message::Settings => {
-            if app.settings_window_id.is_some() {
+            if app.has_window(WindowRole::Settings) {
                 debug!("Settings window already open, ignoring request");
                 return Task::none();
             }
@@ -377,17 +2776,18 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             debug!("Settings clicked");
             let (window_id, task) = open_settings_window();
             debug!(?window_id, "Opening settings window");
-            app.settings_window_id = Some(window_id);
+            app.windows.insert(window_id, WindowRole::Settings);
             app.show_settings_modal = true;
-            task
+            Task::batch([task, measure_audio_cache()])
         }
         Message::CloseSettings => {
             app.show_settings_modal = false;
-            close_window_if_some(app.settings_window_id.take())
+            close_window(app, WindowRole::Settings)
         }
         Message::ProviderSelected(backend) => {
             info!(?backend, "TTS provider selected");
             app.selected_backend = backend;
+            crate::crash_report::set_capability("tts_provider", format!("{backend:?}"));
             
             // Check AWS credentials if AWS Polly is selected
             if backend == TTSBackend::AwsPolly {
@@ -415,15 +2815,44 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                     }
                 }
             } else {
-                // Clear error message when switching to Piper
+                // Clear error message when switching away from AWS Polly
                 app.error_message = None;
                 app.polly_error_message = None;
             }
             
+            // Pick up voices added to command-voices.toml since startup
+            if backend == TTSBackend::Command {
+                (app.command_voices, app.command_voices_error) = crate::model::load_command_voice_names();
+            }
+
             // Persist the selected backend so future runs remember the choice.
             config::save_voice_provider(backend);
             Task::none()
         }
+        Message::PollyCostWarningSelected(cents) => {
+            info!(?cents, "Polly cost warning threshold changed");
+            app.polly_cost_warning_cents = cents;
+            config::save_polly_cost_warning_cents(cents);
+            Task::none()
+        }
+        Message::AwsProfileSelected(profile) => {
+            let profile = (profile != crate::model::AWS_PROFILE_FROM_ENV).then_some(profile);
+            info!(?profile, "AWS profile selected");
+            crate::voices::aws::set_aws_profile(profile.as_deref());
+            config::save_aws_profile(profile.as_deref());
+            app.aws_profile = profile;
+            // Voices and credentials depend on the profile: reload them
+            reload_polly_voices(app)
+        }
+        Message::AwsRegionSelected(region) => {
+            let region = (region != crate::model::AWS_REGION_FROM_CONFIG).then_some(region);
+            info!(?region, "AWS region selected");
+            crate::voices::aws::set_aws_region(region.as_deref());
+            config::save_aws_region(region.as_deref());
+            app.aws_region = region;
+            // Voice availability differs between regions
+            reload_polly_voices(app)
+        }
         Message::LogLevelSelected(level) => {
             info!(?level, "Log level selected");
             app.log_level = level;
@@ -440,65 +2869,79 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             config::save_text_cleanup_enabled(enabled);
             Task::none()
         }
-        Message::WindowOpened(id) => {
-            info!(?id, "Window opened event received");
-            if app.main_window_id.is_none() {
-                app.main_window_id = Some(id);
-                info!("Main window ID set - UI is now visible");
-                
-                // If we already have pending text (from async fetch), initialize TTS now
-                if let Some(text) = app.pending_text.take() {
-                    return process_text_for_tts(app, text, "WindowOpened");
-                }
-            } else {
-                debug!(?id, "Window opened but main window ID already set");
-            }
-            app.current_window_id = Some(id);
+        Message::TransformKindSelected(kind) => {
+            app.new_transform = system::TransformStep::new(kind);
             Task::none()
         }
-        Message::WindowClosed(id) => {
-            debug!(?id, "Window closed");
-            if app.settings_window_id == Some(id) {
-                app.settings_window_id = None;
-                app.show_settings_modal = false;
-            }
-            if app.voice_selection_window_id == Some(id) {
-                app.voice_selection_window_id = None;
-            }
-            if app.polly_info_window_id == Some(id) {
-                app.polly_info_window_id = None;
+        Message::NewTransformUrlChanged(value) => {
+            if let system::TransformStep::OpenAi { url, .. } | system::TransformStep::Ollama { url, .. } =
+                &mut app.new_transform
+            {
+                *url = value;
             }
-            if app.screenshot_window_id == Some(id) {
-                app.screenshot_window_id = None;
+            Task::none()
+        }
+        Message::NewTransformApiKeyChanged(value) => {
+            if let system::TransformStep::OpenAi { api_key, .. } = &mut app.new_transform {
+                *api_key = value;
             }
-            if app.ocr_info_window_id == Some(id) {
-                app.ocr_info_window_id = None;
+            Task::none()
+        }
+        Message::NewTransformModelChanged(value) => {
+            if let system::TransformStep::OpenAi { model, .. } | system::TransformStep::Ollama { model, .. } =
+                &mut app.new_transform
+            {
+                *model = value;
             }
-            if app.text_cleanup_info_window_id == Some(id) {
-                app.text_cleanup_info_window_id = None;
+            Task::none()
+        }
+        Message::NewTransformPromptChanged(value) => {
+            if let system::TransformStep::OpenAi { prompt, .. } | system::TransformStep::Ollama { prompt, .. } =
+                &mut app.new_transform
+            {
+                *prompt = value;
             }
-            if app.extracted_text_dialog_window_id == Some(id) {
-                app.extracted_text_dialog_window_id = None;
-                app.extracted_text = None;
-                app.extracted_text_editor = None;
+            Task::none()
+        }
+        Message::AddTransformStep => {
+            let step = app.new_transform.clone();
+            info!(step = %step.label(), "Text transform added");
+            app.text_transforms.push(step);
+            config::save_text_transforms(&app.text_transforms);
+            Task::none()
+        }
+        Message::MoveTransformStepUp(index) => {
+            if index > 0 && index < app.text_transforms.len() {
+                app.text_transforms.swap(index - 1, index);
+                debug!(from = index, to = index - 1, "Text transform moved");
+                config::save_text_transforms(&app.text_transforms);
             }
-            if app.current_window_id == Some(id) {
-                app.current_window_id = None;
+            Task::none()
+        }
+        Message::DeleteTransformStep(index) => {
+            if index < app.text_transforms.len() {
+                let step = app.text_transforms.remove(index);
+                info!(step = %step.label(), "Text transform deleted");
+                config::save_text_transforms(&app.text_transforms);
             }
-            // Hide window instead of exiting if system tray is available
-            if app.main_window_id == Some(id) {
-                if app.system_tray.is_some() {
-                    info!("Main window closed, hiding to system tray");
-                    app.window_hidden = true;
-                    // Don't clear main_window_id so we know to reopen it later
-                    // The window is already closed by the user, so we just mark it as hidden
-                } else {
-                    info!("Main window closed, exiting (no system tray)");
-                    return iced::exit();
+            Task::none()
+        }
+        Message::WindowOpened(id) => {
+            let role = app.window_role(id);
+            info!(?id, ?role, "Window opened event received");
+            if role == Some(WindowRole::Main) {
+                info!("Main window open - UI is now visible");
+                // If we already have pending text (from async fetch), initialize TTS now
+                if let Some(text) = app.pending_text.take() {
+                    return read_captured(app, text, "WindowOpened");
                 }
             }
             Task::none()
         }
+        Message::WindowClosed(id) => {
+            debug!(?id, "Window closed");
+            forget_window(app, id)
+        }
         Message::SelectedTextFetched(text) => {
             info!("Selected text fetched asynchronously");
             if let Some(ref t) = text {
@@ -508,15 +2951,29 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             }
             
             // Initialize TTS if window is already open, otherwise store for later
-            if let Some(window_id) = app.main_window_id {
-                if let Some(text) = text {
-                    return process_text_for_tts(app, text, "SelectedTextFetched");
+            if app.has_window(WindowRole::Main) {
+                match (text, is_reading(app)) {
+                    (Some(text), true) => {
+                        // Large selections are confirmed before they are queued too
+                        if let Some(text) = hold_if_large(app, text) {
+                            app.reading_queue.push(text);
+                            info!(queued = app.reading_queue.len(), "Already reading, text added to queue");
+                        }
+                        return Task::none();
+                    }
+                    (Some(text), false) if app.review_before_reading => return open_review(app, text),
+                    (Some(text), false) => return read_captured(app, text, "SelectedTextFetched"),
+                    (None, true) => {
+                        debug!("No text selected, keeping current reading");
+                        return Task::none();
+                    }
+                    (None, false) => {}
                 }
                 warn!("No text selected - closing window");
-                return window::close(window_id);
+                return close_window(app, WindowRole::Main);
             }
             
-            // Window not ready yet, store text for WindowOpened handler
+            // No main window yet, store text for WindowOpened handler
             app.pending_text = text;
             trace!("Window not ready yet, text stored for later initialization");
             Task::none()
@@ -525,43 +2982,56 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             match result {
                 Ok(cleaned_text) => {
                     info!(bytes = cleaned_text.len(), "Natural Reading successful, initializing TTS");
+                    let cleaned_text = skim_text(app, cleaned_text);
+                    begin_document(app, &cleaned_text);
                     // Update status to show we're now synthesizing
-                    app.status_text = Some("Synthesizing voice...".to_string());
-                    return initialize_tts_async(app.selected_backend, cleaned_text, "TextCleanupResponse", app.selected_polly_voice.clone());
+                    app.status_text = Some(synthesis_status(app, &cleaned_text));
+                    return initialize_tts_async(app, cleaned_text, "TextCleanupResponse");
                 }
                 Err(e) => {
-                    error!(error = %e, "Natural Reading service failed");
+                    error!(error = %e, "Text transform failed");
                     clear_loading_state(app);
                     return open_settings_if_needed(app, e);
                 }
             }
         }
         Message::TTSInitialized(result) => {
+            // Synthesis finished after the user pressed Stop: drop the result
+            if !app.is_loading {
+                app.synthesizing = None;
+                info!("TTS initialization finished after stop, discarding");
+                return Task::none();
+            }
+            let result = match result {
+                Ok(ready) => match play_ready_speech(&mut app.synthesizing, &ready) {
+                    Some(played) => played,
+                    None => {
+                        debug!("Discarding the speech of a superseded synthesis");
+                        return Task::none();
+                    }
+                },
+                Err(e) => {
+                    app.synthesizing = None;
+                    Err(e)
+                }
+            };
+
             // Clear loading state regardless of result
             clear_loading_state(app);
             
             match result {
-                Ok(()) => {
-                    // Retrieve provider from static storage
-                    let Ok(mut guard) = PENDING_PROVIDER.lock() else {
-                        error!("Failed to lock PENDING_PROVIDER mutex");
-                        app.error_message = Some("Internal error: mutex lock failed".to_string());
-                        return Task::none();
-                    };
-                    
-                    let Some(send_provider) = guard.take() else {
-                        error!("TTS initialization succeeded but no provider found in storage");
-                        app.error_message = Some("Internal error: provider not found".to_string());
-                        return Task::none();
-                    };
-                    
-                    app.provider = Some(send_provider.0);
+                Ok(provider) => {
+                    app.provider = Some(provider);
                     app.playback_state = PlaybackState::Playing;
+                    app.speaking_phrase = false;
+                    app.loop_start = None;
+                    app.loop_region = None;
                     app.error_message = None;
                     info!("TTS provider initialized and playback started");
                 }
                 Err(e) => {
                     error!(error = %e, "TTS initialization failed");
+                    app.audio_cache_pending = None;
                     
                     // For "No audio data generated" errors, show in status text instead of opening settings
                     if e.contains("No audio data generated by piper") {
@@ -591,7 +3061,7 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             Task::none()
         }
         Message::StartDrag => {
-            if let Some(id) = app.main_window_id {
+            if let Some(id) = app.window_id(WindowRole::Main) {
                 window::drag(id)
             } else {
                 Task::none()
@@ -606,7 +3076,7 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                 Err(e) => {
                     error!(error = %e, "Failed to load voices.json");
                     // Show error to user in settings window if it's open
-                    if app.settings_window_id.is_some() {
+                    if app.has_window(WindowRole::Settings) {
                         app.error_message = Some(format!("Failed to load voices: {}. Check your internet connection.", e));
                     }
                 }
@@ -643,7 +3113,7 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             Task::none()
         }
         Message::OpenVoiceSelection(lang_code) => {
-            if app.voice_selection_window_id.is_some() {
+            if app.has_window(WindowRole::VoiceSelection) {
                 debug!("Voice selection window already open, ignoring request");
                 return Task::none();
             }
@@ -660,25 +3130,25 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                 position: window::Position::Centered,
                 ..Default::default()
             });
-            app.voice_selection_window_id = Some(window_id);
+            app.windows.insert(window_id, WindowRole::VoiceSelection);
             task.map(Message::WindowOpened)
         }
         Message::CloseVoiceSelection => {
-            close_window_if_some(app.voice_selection_window_id.take())
+            close_window(app, WindowRole::VoiceSelection)
         }
         Message::OpenPollyInfo => {
-            if app.polly_info_window_id.is_some() {
+            if app.has_window(WindowRole::PollyInfo) {
                 debug!("Polly info window already open, ignoring request");
                 return Task::none();
             }
             
             debug!("Opening AWS Polly pricing info window");
             let (window_id, task) = open_info_window(Size::new(500.0, 400.0));
-            app.polly_info_window_id = Some(window_id);
+            app.windows.insert(window_id, WindowRole::PollyInfo);
             task
         }
         Message::ClosePollyInfo => {
-            close_window_if_some(app.polly_info_window_id.take())
+            close_window(app, WindowRole::PollyInfo)
         }
         Message::OpenPollyPricingUrl => {
             let url = "https://aws.amazon.com/polly/pricing/";
@@ -694,50 +3164,133 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             }
             info!(?backend, "OCR backend selected");
             app.selected_ocr_backend = backend;
+            crate::crash_report::set_capability("ocr_backend", format!("{backend:?}"));
             config::save_ocr_backend(backend);
             Task::none()
         }
+        Message::OcrEndpointChanged(url) => {
+            debug!(url = %url, "Remote OCR endpoint changed");
+            app.ocr_endpoint = url;
+            config::save_ocr_endpoint(&app.ocr_endpoint);
+            Task::none()
+        }
+        Message::OcrSingleColumnToggled(single_column) => {
+            info!(single_column, "OCR single column setting changed");
+            app.ocr_single_column = single_column;
+            config::save_ocr_single_column(single_column);
+            Task::none()
+        }
+        Message::OcrNewLinesOnlyToggled(new_lines_only) => {
+            info!(new_lines_only, "OCR new lines only setting changed");
+            app.ocr_new_lines_only = new_lines_only;
+            // The next capture is read in full either way
+            app.seen_capture_lines.clear();
+            config::save_ocr_new_lines_only(new_lines_only);
+            Task::none()
+        }
         Message::OpenOCRInfo => {
-            if app.ocr_info_window_id.is_some() {
+            if app.has_window(WindowRole::OcrInfo) {
                 debug!("OCR info window already open, ignoring request");
                 return Task::none();
             }
             
             debug!("Opening Better OCR info window");
             let (window_id, task) = open_info_window(Size::new(500.0, 300.0));
-            app.ocr_info_window_id = Some(window_id);
+            app.windows.insert(window_id, WindowRole::OcrInfo);
             task
         }
         Message::CloseOCRInfo => {
-            close_window_if_some(app.ocr_info_window_id.take())
+            close_window(app, WindowRole::OcrInfo)
         }
         Message::OpenTextCleanupInfo => {
-            if app.text_cleanup_info_window_id.is_some() {
+            if app.has_window(WindowRole::TextCleanupInfo) {
                 debug!("Natural Reading info window already open, ignoring request");
                 return Task::none();
             }
             
             debug!("Opening Natural Reading info window");
             let (window_id, task) = open_info_window(Size::new(500.0, 300.0));
-            app.text_cleanup_info_window_id = Some(window_id);
+            app.windows.insert(window_id, WindowRole::TextCleanupInfo);
             task
         }
         Message::CloseTextCleanupInfo => {
-            close_window_if_some(app.text_cleanup_info_window_id.take())
+            close_window(app, WindowRole::TextCleanupInfo)
         }
         Message::VoiceSelected(voice_key) => {
             info!(voice = %voice_key, "Voice selected");
             match app.selected_backend {
-                TTSBackend::Piper => {
-                    app.selected_voice = Some(voice_key.clone());
-                    config::save_selected_voice(voice_key);
-                }
+                TTSBackend::Piper => select_piper_voice(app, voice_key),
                 TTSBackend::AwsPolly => {
                     app.selected_polly_voice = Some(voice_key.clone());
                     config::save_selected_polly_voice(voice_key);
                 }
+                TTSBackend::WindowsSpeech | TTSBackend::Coqui | TTSBackend::OpenTTS | TTSBackend::Command => {
+                    // These providers use the system default voice / configured speaker
+                    debug!(backend = ?app.selected_backend, "Voice selection ignored for provider");
+                }
+            }
+            close_window(app, WindowRole::VoiceSelection)
+        }
+        Message::MixedLanguageToggled(enabled) => {
+            info!(enabled, "Mixed-language reading toggled");
+            app.mixed_language = enabled;
+            config::save_language_voices(enabled, &app.language_voices);
+            Task::none()
+        }
+        Message::CompareVoiceChanged(voice_key) => {
+            app.compare_voice = voice_key.trim().to_string();
+            config::save_compare_voice(&app.compare_voice);
+            Task::none()
+        }
+        Message::CompareVoice => {
+            if app.is_loading || app.speaking_phrase || app.playback_state != PlaybackState::Playing {
+                return Task::none();
+            }
+            // Pressed again before the sentence came up: never mind
+            if app.voice_comparison.as_ref().is_some_and(|comparison| !comparison.speaking) {
+                info!("Voice comparison cancelled");
+                app.voice_comparison = None;
+                return Task::none();
+            }
+            if app.compare_voice.is_empty() || !crate::voices::download::is_voice_downloaded(&app.compare_voice) {
+                info!(voice = %app.compare_voice, "Voice comparison requested without a downloaded comparison voice");
+                return Task::none();
             }
-            close_window_if_some(app.voice_selection_window_id.take())
+            let next = app
+                .word_timings
+                .as_ref()
+                .and_then(|timings| timings.next_sentence(app.progress))
+                .zip(app.reading_text.as_deref());
+            let Some(((start, end, range), text)) = next else {
+                info!("Voice comparison requested but no sentence is left");
+                return Task::none();
+            };
+            let sentence = text[range].trim().to_string();
+            debug!(start, end, %sentence, "Next sentence will be read with the comparison voice");
+            app.voice_comparison = Some(crate::model::VoiceComparison { start, end, text: sentence, speaking: false });
+            Task::none()
+        }
+        Message::AutoLanguageToggled(enabled) => {
+            info!(enabled, "Automatic language voices toggled");
+            app.auto_language = enabled;
+            config::save_auto_language(enabled);
+            Task::none()
+        }
+        Message::DismissLanguagePrompt => {
+            app.language_prompt = None;
+            Task::none()
+        }
+        Message::SynthesisLoadSelected(load) => {
+            info!(?load, "Synthesis load changed");
+            app.synthesis_load = load;
+            config::save_synthesis_load(load);
+            Task::none()
+        }
+        Message::RemoveLanguageVoice(language) => {
+            debug!(language = %language, "Removing language voice");
+            app.language_voices.remove(&language);
+            config::save_language_voices(app.mixed_language, &app.language_voices);
+            Task::none()
         }
         Message::VoiceDownloadRequested(voice_key) => {
             info!(voice = %voice_key, "Voice download requested");
@@ -774,8 +3327,7 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                     info!(voice = %voice_key, "Voice downloaded successfully");
                     app.status_text = Some("Voice downloaded successfully".to_string());
                     // Auto-select the downloaded voice
-                    app.selected_voice = Some(voice_key.clone());
-                    config::save_selected_voice(voice_key);
+                    select_piper_voice(app, voice_key);
                 }
                 Err(e) => {
                     error!(error = %e, "Voice download failed");
@@ -784,14 +3336,247 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             }
             Task::none()
         }
+        Message::VoiceLicenseToggled(voice_key) => {
+            if app.voice_license_shown.as_deref() == Some(voice_key.as_str()) {
+                app.voice_license_shown = None;
+                return Task::none();
+            }
+            app.voice_license_shown = Some(voice_key.clone());
+            if matches!(app.voice_licenses.get(&voice_key), Some(Ok(_))) {
+                return Task::none();
+            }
+            let Some(voice_info) = app.voices.as_ref().and_then(|voices| voices.get(&voice_key).cloned()) else {
+                return Task::none();
+            };
+            debug!(voice = %voice_key, "Looking up voice license");
+            app.voice_licenses.remove(&voice_key);
+            Task::perform(crate::voices::license::fetch_voice_license(voice_info), move |result| {
+                Message::VoiceLicenseLoaded(voice_key.clone(), result)
+            })
+        }
+        Message::VoiceLicenseLoaded(voice_key, result) => {
+            if let Err(ref e) = result {
+                warn!(voice = %voice_key, error = %e, "Voice license lookup failed");
+            }
+            app.voice_licenses.insert(voice_key, result);
+            Task::none()
+        }
+        Message::StorageFolderChanged(folder) => {
+            app.storage_folder_input = folder;
+            Task::none()
+        }
+        Message::MoveStorage => {
+            if app.storage_moving || is_reading(app) {
+                app.storage_status = Some(Err("Stop reading before moving the voice models".to_string()));
+                return Task::none();
+            }
+            let from = Some(app.storage_folder.trim()).filter(|folder| !folder.is_empty()).map(std::path::PathBuf::from);
+            let to = Some(app.storage_folder_input.trim())
+                .filter(|folder| !folder.is_empty())
+                .map(std::path::PathBuf::from);
+            if to.as_ref().is_some_and(|folder| folder.is_relative()) {
+                app.storage_status = Some(Err("Enter the full path of the storage folder".to_string()));
+                return Task::none();
+            }
+            info!(?from, ?to, "Moving storage");
+            app.storage_moving = true;
+            app.storage_status = Some(Ok("Moving voice models and caches…".to_string()));
+            Task::perform(
+                async move {
+                    let target = to.clone();
+                    let result = tokio::task::spawn_blocking(move || {
+                        crate::storage::migrate_storage(from.as_deref(), target.as_deref())
+                    })
+                    .await
+                    .unwrap_or_else(|e| Err(format!("Move task failed: {e}")));
+                    (to, result)
+                },
+                |(to, result)| Message::StorageMoved(to, result),
+            )
+        }
+        Message::StorageMoved(to, result) => {
+            app.storage_moving = false;
+            match result {
+                Ok(files) => {
+                    // The folder moved to, even if the field was edited meanwhile
+                    config::save_storage_folder(to.as_deref());
+                    app.storage_status = Some(Ok(format!(
+                        "Moved {files} files. Voice models are now in {}.",
+                        crate::storage::models_dir().display()
+                    )));
+                    app.storage_folder = to.map(|folder| folder.display().to_string()).unwrap_or_default();
+                }
+                Err(e) => {
+                    error!(error = %e, "Failed to move storage");
+                    app.storage_status = Some(Err(e));
+                }
+            }
+            Task::none()
+        }
+        Message::AudioCacheToggled(enabled) => {
+            info!(enabled, "Audio cache toggled");
+            app.audio_cache_enabled = enabled;
+            config::save_audio_cache(enabled, app.audio_cache_limit_mb);
+            Task::none()
+        }
+        Message::AudioCacheLimitSelected(limit_mb) => {
+            info!(limit_mb, "Audio cache limit selected");
+            app.audio_cache_limit_mb = limit_mb;
+            config::save_audio_cache(app.audio_cache_enabled, limit_mb);
+            measure_audio_cache()
+        }
+        Message::AudioCacheMeasured(bytes) => {
+            app.audio_cache_size = Some(bytes);
+            Task::none()
+        }
+        Message::ClearAudioCache => {
+            info!("Clearing audio cache");
+            Task::perform(
+                async {
+                    tokio::task::spawn_blocking(providers::audio_cache::clear)
+                        .await
+                        .unwrap_or_else(|e| Err(format!("Clearing task failed: {e}")))
+                },
+                Message::AudioCacheCleared,
+            )
+        }
+        Message::AudioCacheCleared(result) => {
+            app.audio_cache_status = Some(match result {
+                Ok(bytes) => {
+                    info!(bytes, "Audio cache cleared");
+                    app.audio_cache_size = Some(0);
+                    Ok(format!("Cleared {}.", providers::audio_cache::format_megabytes(bytes)))
+                }
+                Err(e) => {
+                    error!(error = %e, "Failed to clear audio cache");
+                    Err(e)
+                }
+            });
+            Task::none()
+        }
+        Message::CaptureRetentionSelected(hours) => {
+            info!(?hours, "Capture retention changed");
+            app.capture_retention_hours = hours;
+            config::save_capture_retention_hours(hours);
+            Task::none()
+        }
+        Message::ClearCaptureCache => {
+            info!("Clearing screenshot captures");
+            Task::perform(
+                async {
+                    tokio::task::spawn_blocking(system::clear_captures)
+                        .await
+                        .unwrap_or_else(|e| Err(format!("Clearing task failed: {e}")))
+                },
+                Message::CaptureCacheCleared,
+            )
+        }
+        Message::CaptureCacheCleared(result) => {
+            app.capture_cache_status = Some(match result {
+                Ok(count) => {
+                    info!(count, "Screenshot captures cleared");
+                    Ok(format!("Deleted {count} capture{}.", if count == 1 { "" } else { "s" }))
+                }
+                Err(e) => {
+                    error!(error = %e, "Failed to clear screenshot captures");
+                    Err(e)
+                }
+            });
+            Task::none()
+        }
+        Message::ReadingCached(result) => {
+            if let Err(e) = result {
+                warn!(error = %e, "Failed to cache reading");
+            }
+            Task::none()
+        }
+        Message::VoicePackPathChanged(path) => {
+            app.voice_pack_path = path;
+            Task::none()
+        }
+        Message::ImportVoicePack => {
+            let path = std::path::PathBuf::from(app.voice_pack_path.trim());
+            if path.as_os_str().is_empty() || app.voice_pack_busy {
+                return Task::none();
+            }
+            app.voice_pack_busy = true;
+            app.voice_pack_status = Some(Ok("Importing voices…".to_string()));
+            Task::perform(
+                async move {
+                    tokio::task::spawn_blocking(move || crate::voices::pack::import_pack(&path))
+                        .await
+                        .unwrap_or_else(|e| Err(format!("Import task failed: {e}")))
+                },
+                Message::VoicePackImported,
+            )
+        }
+        Message::VoicePackImported(result) => {
+            app.voice_pack_busy = false;
+            match result {
+                Ok(pack) => {
+                    info!(voices = pack.keys.len(), "Voice pack imported");
+                    // List the pack's voices even when voices.json couldn't be fetched (offline)
+                    let voices = app.voices.get_or_insert_with(std::collections::HashMap::new);
+                    for voice in pack.infos {
+                        voices.entry(voice.key.clone()).or_insert(voice);
+                    }
+                    let current_installed = app
+                        .selected_voice
+                        .as_deref()
+                        .is_some_and(crate::voices::download::is_voice_downloaded);
+                    if !current_installed {
+                        if let Some(key) = pack.keys.first() {
+                            select_piper_voice(app, key.clone());
+                        }
+                    }
+                    app.voice_pack_status = Some(Ok(format!("Installed {}", pack.keys.join(", "))));
+                }
+                Err(e) => {
+                    error!(error = %e, "Voice pack import failed");
+                    app.voice_pack_status = Some(Err(e));
+                }
+            }
+            Task::none()
+        }
+        Message::ExportVoicePack => {
+            let path = std::path::PathBuf::from(app.voice_pack_path.trim());
+            if path.as_os_str().is_empty() || app.voice_pack_busy {
+                return Task::none();
+            }
+            app.voice_pack_busy = true;
+            app.voice_pack_status = Some(Ok("Exporting voices…".to_string()));
+            let known = app.voices.clone();
+            Task::perform(
+                async move {
+                    tokio::task::spawn_blocking(move || crate::voices::pack::export_pack(&path, known.as_ref()))
+                        .await
+                        .unwrap_or_else(|e| Err(format!("Export task failed: {e}")))
+                },
+                Message::VoicePackExported,
+            )
+        }
+        Message::VoicePackExported(result) => {
+            app.voice_pack_busy = false;
+            app.voice_pack_status = Some(match result {
+                Ok(count) => Ok(format!("Exported {count} voices to {}", app.voice_pack_path.trim())),
+                Err(e) => {
+                    error!(error = %e, "Voice pack export failed");
+                    Err(e)
+                }
+            });
+            Task::none()
+        }
         Message::ScreenshotRequested => {
             info!("Screenshot button clicked, starting region selection");
+            let retention_hours = app.capture_retention_hours;
             // Spawn async task to capture screenshot region
             Task::perform(
-                async {
+                async move {
                     debug!("Starting async screenshot capture task");
                     // Use spawn_blocking for the blocking shell command
-                    let result = tokio::task::spawn_blocking(|| {
+                    let result = tokio::task::spawn_blocking(move || {
+                        // Drop captures left from earlier readings (or past their retention)
+                        crate::system::prune_captures(retention_hours);
                         debug!("Executing capture_region in blocking thread");
                         crate::system::capture_region()
                     })
@@ -810,17 +3595,25 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                 Ok(file_path) => {
                     info!(path = %file_path, "Screenshot captured successfully");
                     app.screenshot_path = Some(file_path.clone());
+                    app.screenshot_image = std::fs::read(&file_path).ok().map(iced::widget::image::Handle::from_bytes);
+                    app.screenshot_size = image::image_dimensions(&file_path).ok();
                     app.status_text = Some("Extracting text from image...".to_string());
                     
                     // Automatically extract text from the screenshot
                     let file_path_clone = file_path.clone();
+                    let backend = app.selected_ocr_backend;
+                    let detect_columns = !app.ocr_single_column;
                     Task::perform(
                         async move {
                             debug!("Starting async text extraction from screenshot");
                             // Use spawn_blocking for the blocking shell command
                             let result = tokio::task::spawn_blocking(move || {
-                                debug!("Executing extract_text_from_image in blocking thread");
-                                crate::system::extract_text_from_image(&file_path_clone)
+                                let started = Instant::now();
+                                let provider = create_ocr_provider(backend)?;
+                                debug!(engine = provider.name(), "Extracting text in blocking thread");
+                                let lines = system::recognize_lines(provider.as_ref(), &file_path_clone, detect_columns);
+                                latency::record(Stage::Ocr, started.elapsed());
+                                lines
                             })
                             .await;
                             debug!("Text extraction task completed");
@@ -845,6 +3638,21 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             }
         }
         Message::ScreenshotTextExtracted(result) => {
+            if let Some(path) = &app.screenshot_path {
+                system::discard_capture(path, app.capture_retention_hours);
+            }
+            let result = result.and_then(|lines| {
+                let lines = if app.ocr_new_lines_only {
+                    app.seen_capture_lines.new_lines(app.screenshot_size, lines)
+                } else {
+                    lines
+                };
+                if lines.is_empty() {
+                    return Err("No new text since the last capture".to_string());
+                }
+                // Tables read as a single column keep their rows apart
+                Ok(system::lines_text(&lines, !app.ocr_single_column))
+            });
             match result {
                 Ok(extracted_text) => {
                     info!(bytes = extracted_text.len(), "Text extracted from screenshot successfully");
@@ -859,7 +3667,7 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                     app.extracted_text_editor = Some(iced::widget::text_editor::Content::with_text(&extracted_text));
                     
                     // Open the extracted text dialog window
-                    if app.extracted_text_dialog_window_id.is_none() {
+                    if !app.has_window(WindowRole::ExtractedText) {
                         let (window_id, task) = window::open(window::Settings {
                             size: Size::new(600.0, 400.0),
                             resizable: true,
@@ -869,7 +3677,7 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                             position: window::Position::Centered,
                             ..Default::default()
                         });
-                        app.extracted_text_dialog_window_id = Some(window_id);
+                        app.windows.insert(window_id, WindowRole::ExtractedText);
                         return task.map(Message::WindowOpened);
                     }
                 }
@@ -878,6 +3686,8 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                     // Don't show error if no text was found (image might not contain text)
                     if e.contains("No text found") {
                         app.status_text = Some("No text found in image".to_string());
+                    } else if e.contains("No new text") {
+                        app.status_text = Some(e);
                     } else {
                         app.error_message = Some(format!("Text extraction failed: {}", e));
                         app.status_text = Some("Text extraction failed".to_string());
@@ -887,12 +3697,12 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             Task::none()
         }
         Message::OpenScreenshotViewer => {
-            if app.screenshot_window_id.is_some() {
+            if app.has_window(WindowRole::Screenshot) {
                 debug!("Screenshot window already open, ignoring request");
                 return Task::none();
             }
             
-            if app.screenshot_path.is_none() {
+            if app.screenshot_image.is_none() {
                 debug!("No screenshot available to display");
                 return Task::none();
             }
@@ -907,14 +3717,14 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                 position: window::Position::Centered,
                 ..Default::default()
             });
-            app.screenshot_window_id = Some(window_id);
+            app.windows.insert(window_id, WindowRole::Screenshot);
             task.map(Message::WindowOpened)
         }
         Message::CloseScreenshotViewer => {
-            close_window_if_some(app.screenshot_window_id.take())
+            close_window(app, WindowRole::Screenshot)
         }
         Message::OpenExtractedTextDialog => {
-            if app.extracted_text_dialog_window_id.is_some() {
+            if app.has_window(WindowRole::ExtractedText) {
                 debug!("Extracted text dialog already open, ignoring request");
                 return Task::none();
             }
@@ -934,13 +3744,13 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                 position: window::Position::Centered,
                 ..Default::default()
             });
-            app.extracted_text_dialog_window_id = Some(window_id);
+            app.windows.insert(window_id, WindowRole::ExtractedText);
             task.map(Message::WindowOpened)
         }
         Message::CloseExtractedTextDialog => {
             app.extracted_text = None;
             app.extracted_text_editor = None;
-            close_window_if_some(app.extracted_text_dialog_window_id.take())
+            close_window(app, WindowRole::ExtractedText)
         }
         Message::CopyExtractedTextToClipboard => {
             let text_to_copy = app.extracted_text_editor.as_ref()
@@ -976,7 +3786,9 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
         Message::ReadExtractedText => {
             let text_to_read = app.extracted_text_editor.as_ref()
                 .map(|e| e.text())
-                .or_else(|| app.extracted_text.clone());
+                .or_else(|| app.extracted_text.clone())
+                // Sanitation keeps line breaks, so it is safe even for OCR text
+                .map(|text| system::sanitize_text(&text));
             
             let Some(text_to_read) = text_to_read else {
                 warn!("No extracted text available to read");
@@ -989,10 +3801,16 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             }
             
             info!(bytes = text_to_read.len(), "Sending extracted text to TTS (bypassing text cleanup)");
+            log_transcript(app, &text_to_read, "ReadExtractedText");
+            begin_listen_summary(app, &text_to_read, "ReadExtractedText");
             // OCR text: skip all preprocessing (cleanup API, markdown parsing, etc.)
             // Send directly to TTS to preserve original formatting and line breaks
-            set_loading_state(app, "Synthesizing voice...");
-            initialize_tts_async(app.selected_backend, text_to_read, "ReadExtractedText", app.selected_polly_voice.clone())
+            let status = synthesis_status(app, &text_to_read);
+            set_loading_state(app, &status);
+            app.item_voice = VoiceOverride::default();
+            app.item_pages.clear();
+            pick_language_voice(app, &text_to_read);
+            initialize_tts_async(app, text_to_read, "ReadExtractedText")
         }
         Message::TrayEventReceived => {
             // Poll for tray events and convert them to messages
@@ -1002,6 +3820,13 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                         crate::system::TrayEvent::ShowWindow => Message::ShowWindow,
                         crate::system::TrayEvent::HideWindow => Message::HideWindow,
                         crate::system::TrayEvent::ReadSelected => Message::ReadSelected,
+                        crate::system::TrayEvent::PhraseBoard => Message::OpenPhraseBoard,
+                        crate::system::TrayEvent::TypeToTalk => Message::OpenTypeToTalk,
+                        crate::system::TrayEvent::MorningBriefing => Message::PlayBriefing,
+                        crate::system::TrayEvent::ReadLater => Message::OpenReadLater,
+                        crate::system::TrayEvent::Queue => Message::OpenQueue,
+                        crate::system::TrayEvent::Agenda => Message::ReadAgenda,
+                        crate::system::TrayEvent::Replay => Message::ReplayRecent,
                         crate::system::TrayEvent::Quit => Message::Quit,
                     };
                     return Task::perform(async { message }, |msg| msg);
@@ -1011,10 +3836,10 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
         }
         Message::ShowWindow => {
             // Reopen the window if it was hidden/closed
-            if app.window_hidden || app.main_window_id.is_none() {
+            if app.window_hidden || !app.has_window(WindowRole::Main) {
                 info!("Reopening main window from tray");
                 let (window_id, open_task) = open_main_window();
-                app.main_window_id = Some(window_id);
+                app.windows.insert(window_id, WindowRole::Main);
                 app.window_hidden = false;
                 return open_task;
             }
@@ -1022,21 +3847,21 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
         }
         Message::HideWindow => {
             // Close the window (user can reopen from tray)
-            if let Some(window_id) = app.main_window_id {
+            if app.has_window(WindowRole::Main) {
                 info!("Hiding main window to tray");
                 app.window_hidden = true;
-                return window::close(window_id);
+                return close_window(app, WindowRole::Main);
             }
             Task::none()
         }
         Message::ReadSelected => {
             info!("Read Selected triggered from tray menu");
             // Ensure window is visible when reading
-            let fetch_task = fetch_selected_text_task("tray menu");
-            if app.window_hidden || app.main_window_id.is_none() {
+            let fetch_task = fetch_selected_text_task(app, "tray menu");
+            if app.window_hidden || !app.has_window(WindowRole::Main) {
                 // Show window first, then fetch text
                 let (window_id, open_task) = open_main_window();
-                app.main_window_id = Some(window_id);
+                app.windows.insert(window_id, WindowRole::Main);
                 app.window_hidden = false;
                 return Task::batch([open_task, fetch_task]);
             }
@@ -1044,26 +3869,35 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
         }
         Message::Quit => {
             info!("Quitting application from tray menu");
+            remember_position(app);
             iced::exit()
         }
         Message::HotkeyPressed => {
             // Check if hotkey event actually occurred
-            if let Some(ref mut hotkey_manager) = app.hotkey_manager {
-                if hotkey_manager.try_recv().is_some() {
-                    info!("Hotkey pressed - triggering read");
+            let Some(action) = app.hotkey_manager.as_ref().and_then(|manager| manager.try_recv()) else {
+                return Task::none();
+            };
+            info!(?action, "Hotkey pressed");
+            match action {
+                system::HotkeyAction::Read => {
                     // Use the same logic as ReadSelected
-                    let fetch_task = fetch_selected_text_task("hotkey");
-                    if app.window_hidden || app.main_window_id.is_none() {
+                    let fetch_task = fetch_selected_text_task(app, "hotkey");
+                    if app.window_hidden || !app.has_window(WindowRole::Main) {
                         // Show window first, then fetch text
                         let (window_id, open_task) = open_main_window();
-                        app.main_window_id = Some(window_id);
+                        app.windows.insert(window_id, WindowRole::Main);
                         app.window_hidden = false;
                         return Task::batch([open_task, fetch_task]);
                     }
-                    return fetch_task;
+                    fetch_task
+                }
+                system::HotkeyAction::PauseResume if app.provider.is_some() => update(app, Message::PlayPause),
+                system::HotkeyAction::Stop if is_reading(app) => update(app, Message::Stop),
+                _ => {
+                    debug!(?action, "Nothing being read, hotkey ignored");
+                    Task::none()
                 }
             }
-            Task::none()
         }
         Message::HotkeyConfigChanged(config) => {
             // Ignore if hotkeys are disabled due to Wayland/Hyprland
@@ -1077,7 +3911,7 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             // Update hotkey registration if enabled
             if app.hotkey_enabled {
                 if let Some(ref mut hotkey_manager) = app.hotkey_manager {
-                    if let Err(e) = hotkey_manager.register(config) {
+                    if let Err(e) = hotkey_manager.register(system::HotkeyAction::Read, config) {
                         error!(error = %e, "Failed to register new hotkey");
                         app.error_message = Some(format!("Failed to register hotkey: {e}"));
                     } else {
@@ -1099,50 +3933,52 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             info!(enabled, "Hotkey toggled");
             app.hotkey_enabled = enabled;
             
-            if let Some(ref mut hotkey_manager) = app.hotkey_manager {
-                if enabled {
-                    if let Err(e) = hotkey_manager.register(app.hotkey_config.clone()) {
-                        error!(error = %e, "Failed to register hotkey");
-                        app.error_message = Some(format!("Failed to register hotkey: {e}"));
-                        app.hotkey_enabled = false; // Revert if registration failed
-                    } else {
-                        info!("Hotkey registered successfully");
-                        app.error_message = None;
-                    }
-                } else if let Err(e) = hotkey_manager.unregister() {
-                    warn!(error = %e, "Failed to unregister hotkey");
+            if enabled {
+                // The actions that did register stay on
+                if let Err(e) = register_hotkeys(app) {
+                    app.error_message = Some(e);
+                } else {
+                    info!("Hotkeys registered successfully");
+                    app.error_message = None;
+                }
+            } else if let Some(ref mut hotkey_manager) = app.hotkey_manager {
+                if let Err(e) = hotkey_manager.unregister() {
+                    warn!(error = %e, "Failed to unregister hotkeys");
                 } else {
-                    info!("Hotkey unregistered successfully");
+                    info!("Hotkeys unregistered successfully");
                 }
             }
             
             crate::config::save_hotkey_config(&app.hotkey_config, app.hotkey_enabled);
             Task::none()
         }
-        Message::StartListeningForHotkey => {
+        Message::StartListeningForHotkey(action) => {
             // Ignore if hotkeys are disabled due to Wayland/Hyprland
             if app.hotkeys_disabled_wayland {
                 return Task::none();
             }
             
-            info!("Starting to listen for hotkey input");
-            app.listening_for_hotkey = true;
+            info!(?action, "Starting to listen for hotkey input");
+            app.listening_for_hotkey = Some(action);
             app.error_message = None; // Clear any previous errors
             Task::none()
         }
         Message::StopListeningForHotkey => {
             info!("Stopped listening for hotkey input");
-            app.listening_for_hotkey = false;
+            app.listening_for_hotkey = None;
             app.error_message = None;
             Task::none()
         }
         Message::HotkeyCaptured(key, modifiers) => {
+            // Exit listening mode
+            let Some(action) = app.listening_for_hotkey.take() else {
+                return Task::none();
+            };
             // Ignore if hotkeys are disabled due to Wayland/Hyprland
             if app.hotkeys_disabled_wayland {
-                app.listening_for_hotkey = false;
                 return Task::none();
             }
-            info!(?key, ?modifiers, "Hotkey combination captured");
+            info!(?action, ?key, ?modifiers, "Hotkey combination captured");
             
             // Convert Iced key/modifiers to global_hotkey format
             use crate::ui::settings::hotkeys::{iced_key_to_global_hotkey_code, iced_modifiers_to_global_hotkey_modifiers};
@@ -1150,7 +3986,6 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             let Some(code) = iced_key_to_global_hotkey_code(&key) else {
                 error!("Invalid key captured: {:?}", key);
                 app.error_message = Some("Invalid key. Please try again.".to_string());
-                app.listening_for_hotkey = false;
                 return Task::none();
             };
             
@@ -1160,7 +3995,6 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             if gh_modifiers.is_empty() {
                 error!("No modifiers in captured hotkey");
                 app.error_message = Some("Hotkey must include at least one modifier (Ctrl/Cmd, Shift, or Alt).".to_string());
-                app.listening_for_hotkey = false;
                 return Task::none();
             }
             
@@ -1170,17 +4004,26 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
                 key: code,
             };
             
-            // Exit listening mode
-            app.listening_for_hotkey = false;
+            // One combination can only trigger one action
+            if let Some(other) = system::HotkeyAction::ALL
+                .into_iter()
+                .find(|other| *other != action && *app.hotkey_config_for(*other) == new_config)
+            {
+                app.error_message = Some(format!("Already used for \"{}\".", other.label()));
+                return Task::none();
+            }
             
             // Update the hotkey configuration
-            // This will trigger HotkeyConfigChanged internally
-            app.hotkey_config = new_config.clone();
+            match action {
+                system::HotkeyAction::Read => app.hotkey_config = new_config.clone(),
+                system::HotkeyAction::PauseResume => app.pause_hotkey_config = new_config.clone(),
+                system::HotkeyAction::Stop => app.stop_hotkey_config = new_config.clone(),
+            }
             
             // Update hotkey registration if enabled
             if app.hotkey_enabled {
                 if let Some(ref mut hotkey_manager) = app.hotkey_manager {
-                    if let Err(e) = hotkey_manager.register(new_config.clone()) {
+                    if let Err(e) = hotkey_manager.register(action, new_config.clone()) {
                         error!(error = %e, "Failed to register new hotkey");
                         app.error_message = Some(format!("Failed to register hotkey: {e}"));
                     } else {
@@ -1191,7 +4034,245 @@ pub fn update(app: &mut App, message: Message) -> Task<Message> {
             }
             
             // Save to config
-            crate::config::save_hotkey_config(&app.hotkey_config, app.hotkey_enabled);
+            crate::config::save_action_hotkey(action, &new_config);
+            Task::none()
+        }
+        Message::ListEnumerationSelected(style) => {
+            info!(?style, "List enumeration style selected");
+            app.text_processing.list_enumeration = style;
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::LinkReadingSelected(policy) => {
+            info!(?policy, "Link reading policy selected");
+            app.text_processing.link_reading = policy;
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::UrlReadingSelected(reading) => {
+            info!(?reading, "URL reading selected");
+            app.text_processing.url_reading = reading;
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::TableReadingSelected(reading) => {
+            info!(?reading, "Table reading selected");
+            app.text_processing.table_reading = reading;
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::ImageAltTextToggled(enabled) => {
+            info!(enabled, "Image alt text reading toggled");
+            app.text_processing.read_image_alt_text = enabled;
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::AbbreviationToggled(written, enabled) => {
+            info!(written, enabled, "Abbreviation rule toggled");
+            let disabled = &mut app.text_processing.disabled_abbreviations;
+            disabled.retain(|other| !other.eq_ignore_ascii_case(written));
+            if !enabled {
+                disabled.push(written.to_string());
+            }
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::StripHtmlToggled(enabled) => {
+            info!(enabled, "HTML stripping toggled");
+            app.text_processing.strip_html = enabled;
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::SkipCitationsToggled(enabled) => {
+            info!(enabled, "Citation skipping toggled");
+            app.text_processing.skip_citations = enabled;
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::SpellAcronymsToggled(enabled) => {
+            info!(enabled, "Acronym spelling toggled");
+            app.text_processing.spell_acronyms = enabled;
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::LocalizeNumbersToggled(enabled) => {
+            info!(enabled, "Localized numbers toggled");
+            app.text_processing.localize_numbers = enabled;
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::SpreadsheetCellsToggled(enabled) => {
+            info!(enabled, "Spreadsheet cell reading toggled");
+            app.text_processing.spreadsheet_cells = enabled;
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::LexiconPathChanged(path) => {
+            app.lexicon_path = path;
+            Task::none()
+        }
+        Message::ImportLexicon => {
+            let path = std::path::PathBuf::from(app.lexicon_path.trim());
+            app.lexicon_status = Some(match system::import_lexicon(&path) {
+                Ok(entries) => {
+                    let count = system::merge_lexicon(&mut app.text_processing.lexicon, entries);
+                    config::save_text_processing_options(&app.text_processing);
+                    info!(count, path = %path.display(), "Lexicon imported");
+                    Ok(format!("Imported {count} entries."))
+                }
+                Err(e) => {
+                    warn!(error = %e, "Lexicon import failed");
+                    Err(e)
+                }
+            });
+            Task::none()
+        }
+        Message::ExportLexicon => {
+            let path = std::path::PathBuf::from(app.lexicon_path.trim());
+            app.lexicon_status = Some(match system::export_lexicon(&path, &app.text_processing.lexicon) {
+                Ok(()) => {
+                    info!(path = %path.display(), "Lexicon exported");
+                    Ok(format!("Exported {} entries.", app.text_processing.lexicon.len()))
+                }
+                Err(e) => {
+                    warn!(error = %e, "Lexicon export failed");
+                    Err(e)
+                }
+            });
+            Task::none()
+        }
+        Message::ClearLexicon => {
+            info!("Lexicon cleared");
+            app.text_processing.lexicon.clear();
+            app.lexicon_status = None;
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::NewLexiconWordChanged(word) => {
+            app.new_lexicon_word = word;
+            Task::none()
+        }
+        Message::NewLexiconAliasChanged(alias) => {
+            app.new_lexicon_alias = alias;
+            Task::none()
+        }
+        Message::AddLexiconEntry => {
+            let word = app.new_lexicon_word.trim();
+            let alias = app.new_lexicon_alias.trim();
+            if word.is_empty() || alias.is_empty() {
+                return Task::none();
+            }
+            let entry = system::LexiconEntry {
+                grapheme: word.to_string(),
+                alias: Some(alias.to_string()),
+                phoneme: None,
+            };
+            info!(word, alias, "Lexicon entry added");
+            system::merge_lexicon(&mut app.text_processing.lexicon, vec![entry]);
+            app.new_lexicon_word.clear();
+            app.new_lexicon_alias.clear();
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::DeleteLexiconEntry(index) => {
+            if index < app.text_processing.lexicon.len() {
+                let entry = app.text_processing.lexicon.remove(index);
+                info!(word = %entry.grapheme, "Lexicon entry deleted");
+                config::save_text_processing_options(&app.text_processing);
+            }
+            Task::none()
+        }
+        Message::NewFilterPatternChanged(pattern) => {
+            app.new_filter_pattern = pattern;
+            app.filter_error = None;
+            Task::none()
+        }
+        Message::NewFilterReplacementChanged(replacement) => {
+            app.new_filter_replacement = replacement;
+            Task::none()
+        }
+        Message::AddFilterRule => {
+            if app.new_filter_pattern.is_empty() {
+                return Task::none();
+            }
+            if let Err(e) = system::compile_filter(&app.new_filter_pattern) {
+                warn!(error = %e, "Invalid filter rule pattern");
+                app.filter_error = Some(e.to_string());
+                return Task::none();
+            }
+            let rule = system::FilterRule {
+                pattern: std::mem::take(&mut app.new_filter_pattern),
+                replacement: std::mem::take(&mut app.new_filter_replacement),
+                enabled: true,
+            };
+            info!(pattern = %rule.pattern, replacement = %rule.replacement, "Filter rule added");
+            app.text_processing.filter_rules.push(rule);
+            config::save_text_processing_options(&app.text_processing);
+            Task::none()
+        }
+        Message::FilterRuleToggled(index, enabled) => {
+            if let Some(rule) = app.text_processing.filter_rules.get_mut(index) {
+                info!(pattern = %rule.pattern, enabled, "Filter rule toggled");
+                rule.enabled = enabled;
+                config::save_text_processing_options(&app.text_processing);
+            }
+            Task::none()
+        }
+        Message::MoveFilterRuleUp(index) => {
+            if index > 0 && index < app.text_processing.filter_rules.len() {
+                app.text_processing.filter_rules.swap(index - 1, index);
+                debug!(from = index, to = index - 1, "Filter rule moved");
+                config::save_text_processing_options(&app.text_processing);
+            }
+            Task::none()
+        }
+        Message::DeleteFilterRule(index) => {
+            if index < app.text_processing.filter_rules.len() {
+                let rule = app.text_processing.filter_rules.remove(index);
+                info!(pattern = %rule.pattern, "Filter rule deleted");
+                config::save_text_processing_options(&app.text_processing);
+            }
+            Task::none()
+        }
+        Message::FilterTestAction(action) => {
+            app.filter_test.perform(action);
+            Task::none()
+        }
+        Message::CoquiUrlChanged(url) => {
+            debug!(url = %url, "Coqui server URL changed");
+            app.coqui_config.url = url;
+            config::save_coqui_config(&app.coqui_config);
+            Task::none()
+        }
+        Message::CoquiSpeakerChanged(speaker) => {
+            debug!(speaker = %speaker, "Coqui speaker changed");
+            app.coqui_config.speaker = speaker;
+            config::save_coqui_config(&app.coqui_config);
+            Task::none()
+        }
+        Message::OpenTTSUrlChanged(url) => {
+            debug!(url = %url, "OpenTTS server URL changed");
+            app.opentts_config.url = url;
+            config::save_opentts_config(&app.opentts_config);
+            Task::none()
+        }
+        Message::OpenTTSVoiceChanged(voice) => {
+            debug!(voice = %voice, "OpenTTS voice changed");
+            app.opentts_config.voice = voice;
+            config::save_opentts_config(&app.opentts_config);
+            Task::none()
+        }
+        Message::CommandVoiceSelected(name) => {
+            info!(name = %name, "Command voice selected");
+            config::save_command_voice(&name);
+            app.command_voice = Some(name);
+            Task::none()
+        }
+        Message::ReloadCommandVoices => {
+            let (voices, error) = crate::model::load_command_voice_names();
+            info!(count = voices.len(), "Command voices reloaded");
+            app.command_voices = voices;
+            app.command_voices_error = error;
             Task::none()
         }
     }
@@ -1,21 +1,44 @@
 //! UI rendering logic
 
-use iced::widget::{button, checkbox, column, container, mouse_area, progress_bar, radio, row, scrollable, svg, text, text_editor, Space};
+use iced::widget::{button, checkbox, column, container, mouse_area, progress_bar, radio, row, scrollable, svg, text, text_editor, text_input, tooltip, Space};
 use iced::{Alignment, Background, Color, ContentFit, Element, Length};
 
 use crate::flags;
-use crate::model::{App, LanguageInfo, LogLevel, Message, OCRBackend, PlaybackState, TTSBackend};
+use crate::latency;
+use crate::model::{App, LanguageInfo, LogLevel, Message, OCRBackend, PlaybackState, TTSBackend, TypingEcho};
 use crate::styles::{
-    circle_button_style, close_button_style, error_container_style, header_style,
+    circle_button_style, close_button_style, dark_text_input_style, error_container_style, header_style,
     modal_content_style, section_style, transparent_button_style, wave_bar_style,
     white_checkbox_style, white_radio_style, window_style,
 };
-use crate::ui::settings::hotkeys;
+use crate::ui::settings::{
+    calendar, command, coqui, feeds, hotkeys, opentts, output, piper, playback, polly, read_later, storage, text_processing,
+    transforms, watch,
+};
 
 const MIN_HEIGHT: f32 = 4.0;
 const MAX_HEIGHT: f32 = 24.0;
 const NUM_BARS: usize = 10;
 
+/// Format a playback speed multiplier for the speed badge (e.g. "1×", "1.25×").
+fn format_speed(speed: f32) -> String {
+    let formatted = format!("{speed:.2}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    format!("{trimmed}×")
+}
+
+/// Wall-clock time `seconds` from now (e.g. "15:04").
+fn format_finish_time(seconds: f32) -> String {
+    let finish = chrono::Local::now() + chrono::Duration::seconds(seconds.round() as i64);
+    finish.format("%H:%M").to_string()
+}
+
+/// Format a duration in seconds as "m:ss".
+fn format_remaining(seconds: f32) -> String {
+    let total = seconds.round() as u32;
+    format!("{}:{:02}", total / 60, total % 60)
+}
+
 /// Convert AWS Polly engine string to display name.
 ///
 /// Only "LongForm" needs transformation to "Long-Form"; all others are returned as-is.
@@ -97,7 +120,7 @@ fn clipboard_icon(size: f32) -> svg::Svg<'static> {
 }
 
 /// Helper to create white text with consistent styling.
-fn white_text(content: &str, size: u32) -> text::Text<'_> {
+fn white_text<'a>(content: impl text::IntoFragment<'a>, size: u32) -> text::Text<'a> {
     text(content)
         .size(size)
         .style(|_theme| iced::widget::text::Style {
@@ -242,7 +265,7 @@ pub fn settings_window_view<'a>(app: &'a App) -> Element<'a, Message> {
     };
 
     // TTS Provider section
-    let provider_controls = column![
+    let mut provider_controls = column![
         radio(
             "Piper (offline, CPU)",
             TTSBackend::Piper,
@@ -283,6 +306,53 @@ pub fn settings_window_view<'a>(app: &'a App) -> Element<'a, Message> {
     ]
     .spacing(0);
 
+    provider_controls = provider_controls
+        .push(Space::new().height(Length::Fixed(6.0)))
+        .push(
+            radio(
+                "Coqui TTS server (local, HTTP)",
+                TTSBackend::Coqui,
+                Some(app.selected_backend),
+                Message::ProviderSelected,
+            )
+            .style(white_radio_style),
+        )
+        .push(Space::new().height(Length::Fixed(6.0)))
+        .push(
+            radio(
+                "OpenTTS / Mimic 3 server (HTTP)",
+                TTSBackend::OpenTTS,
+                Some(app.selected_backend),
+                Message::ProviderSelected,
+            )
+            .style(white_radio_style),
+        )
+        .push(Space::new().height(Length::Fixed(6.0)))
+        .push(
+            radio(
+                "External command (festival, flite, scripts)",
+                TTSBackend::Command,
+                Some(app.selected_backend),
+                Message::ProviderSelected,
+            )
+            .style(white_radio_style),
+        );
+
+    // Native Windows speech is only offered where it can actually run
+    if cfg!(target_os = "windows") {
+        provider_controls = provider_controls
+            .push(Space::new().height(Length::Fixed(6.0)))
+            .push(
+                radio(
+                    "Windows (built-in, offline)",
+                    TTSBackend::WindowsSpeech,
+                    Some(app.selected_backend),
+                    Message::ProviderSelected,
+                )
+                .style(white_radio_style),
+            );
+    }
+
     // AWS Polly error message display (if present and AWS Polly is selected)
     let polly_error_display: Element<'a, Message> = if app.selected_backend == TTSBackend::AwsPolly {
         if let Some(error_msg) = &app.polly_error_message {
@@ -450,9 +520,14 @@ pub fn settings_window_view<'a>(app: &'a App) -> Element<'a, Message> {
             .width(Length::Fill)
             .padding([12.0, 16.0]),
             error_display,
+            polly::polly_settings_section(app),
             polly_error_display,
             piper_voice_section,
+            piper::piper_settings_section(app),
             polly_voice_section,
+            coqui::coqui_settings_section(app),
+            opentts::opentts_settings_section(app),
+            command::command_settings_section(app),
         ]
         .spacing(8)
     )
@@ -494,12 +569,31 @@ pub fn settings_window_view<'a>(app: &'a App) -> Element<'a, Message> {
     )
     .style(section_style);
 
+    // Debug overlay toggle, next to the log level it helps with
+    let debug_overlay_section = container(
+        column![
+            checkbox(app.debug_overlay)
+                .label("Show debug overlay (capture, cleanup, first audio, tick and FFT timings)")
+                .on_toggle(Message::DebugOverlayToggled)
+                .style(white_checkbox_style),
+            text("Also available for one run with --debug-overlay.")
+                .size(11)
+                .style(|_theme| iced::widget::text::Style {
+                    color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+                }),
+        ]
+        .spacing(6)
+        .width(Length::Fill)
+        .padding([12.0, 16.0])
+    )
+    .style(section_style);
+
     // Natural Reading section
     let text_cleanup_control = column![
         row![
             checkbox(app.text_cleanup_enabled)
                 .label("Enable Natural Reading (cloud-powered text enhancement)")
-                // Checkbox disabled - feature work in progress
+                .on_toggle(Message::TextCleanupToggled)
                 .style(white_checkbox_style),
             Space::new().width(Length::Fixed(8.0)),
             // Info icon button (circled i)
@@ -525,7 +619,7 @@ pub fn settings_window_view<'a>(app: &'a App) -> Element<'a, Message> {
         column![
             Space::new().height(Length::Fixed(6.0)),
             row![
-                white_text("Coming soon", 11)
+                white_text("Runs the transforms below before reading", 11)
                     .style(|_theme| iced::widget::text::Style {
                         color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.7)),
                     }),
@@ -579,6 +673,27 @@ pub fn settings_window_view<'a>(app: &'a App) -> Element<'a, Message> {
         }
     };
     
+    // Endpoint settings, only shown when the remote server is selected
+    let remote_ocr_controls: Element<'a, Message> = if app.selected_ocr_backend == OCRBackend::Remote {
+        column![
+            Space::new().height(Length::Fixed(6.0)),
+            text_input("http://localhost:8866/ocr", &app.ocr_endpoint)
+                .on_input(Message::OcrEndpointChanged)
+                .size(13)
+                .padding([6.0, 8.0])
+                .width(Length::Fixed(320.0))
+                .style(dark_text_input_style),
+            Space::new().height(Length::Fixed(4.0)),
+            white_text("Captures are posted as PNG; the server answers with JSON \"text\" or \"lines\".", 11)
+                .style(|_theme| iced::widget::text::Style {
+                    color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+                }),
+        ]
+        .into()
+    } else {
+        Space::new().height(Length::Fixed(0.0)).into()
+    };
+
     let ocr_controls = column![
         radio(
             default_ocr_label,
@@ -588,6 +703,23 @@ pub fn settings_window_view<'a>(app: &'a App) -> Element<'a, Message> {
         )
         .style(white_radio_style),
         Space::new().height(Length::Fixed(6.0)),
+        radio(
+            "Tesseract (local, needs tesseract installed)",
+            OCRBackend::Tesseract,
+            Some(app.selected_ocr_backend),
+            Message::OCRBackendSelected
+        )
+        .style(white_radio_style),
+        Space::new().height(Length::Fixed(6.0)),
+        radio(
+            "Remote OCR server (self-hosted, e.g. PaddleOCR)",
+            OCRBackend::Remote,
+            Some(app.selected_ocr_backend),
+            Message::OCRBackendSelected
+        )
+        .style(white_radio_style),
+        remote_ocr_controls,
+        Space::new().height(Length::Fixed(6.0)),
         row![
             radio(
                 "Better OCR (Cloud service) - Coming soon",
@@ -624,6 +756,16 @@ pub fn settings_window_view<'a>(app: &'a App) -> Element<'a, Message> {
         ]
         .align_y(Alignment::Center)
         .spacing(0),
+        Space::new().height(Length::Fixed(10.0)),
+        checkbox(app.ocr_single_column)
+            .label("Read captures as a single column (e.g. for tables)")
+            .on_toggle(Message::OcrSingleColumnToggled)
+            .style(white_checkbox_style),
+        Space::new().height(Length::Fixed(6.0)),
+        checkbox(app.ocr_new_lines_only)
+            .label("Only read new lines when capturing the same area again")
+            .on_toggle(Message::OcrNewLinesOnlyToggled)
+            .style(white_checkbox_style),
     ]
     .spacing(0);
 
@@ -661,9 +803,29 @@ pub fn settings_window_view<'a>(app: &'a App) -> Element<'a, Message> {
                         Space::new().height(Length::Fixed(12.0)),
                         text_cleanup_section,
                         Space::new().height(Length::Fixed(12.0)),
+                        transforms::transforms_settings_section(app),
+                        Space::new().height(Length::Fixed(12.0)),
+                        text_processing::text_processing_settings_section(app),
+                        Space::new().height(Length::Fixed(12.0)),
+                        playback::playback_settings_section(app),
+                        Space::new().height(Length::Fixed(12.0)),
+                        feeds::feeds_settings_section(app),
+                        Space::new().height(Length::Fixed(12.0)),
+                        watch::watch_settings_section(app),
+                        Space::new().height(Length::Fixed(12.0)),
+                        calendar::calendar_settings_section(app),
+                        Space::new().height(Length::Fixed(12.0)),
+                        read_later::read_later_settings_section(app),
+                        Space::new().height(Length::Fixed(12.0)),
+                        output::output_settings_section(app),
+                        Space::new().height(Length::Fixed(12.0)),
                         provider_section,
                         Space::new().height(Length::Fixed(12.0)),
+                        storage::storage_settings_section(app),
+                        Space::new().height(Length::Fixed(12.0)),
                         log_level_section,
+                        Space::new().height(Length::Fixed(12.0)),
+                        debug_overlay_section,
                     ]
                     .padding([20.0, 24.0])
                     .spacing(0)
@@ -692,9 +854,9 @@ pub fn settings_window_view<'a>(app: &'a App) -> Element<'a, Message> {
 
 /// Main window view
 ///
-/// Layout structure (window is 380×70):
+/// Layout structure (window is 460×70):
 /// ┌──────────────────────────────────────────────────────┐
-/// │  [vol] ||||||||  [-5s] [+5s] [▶] [■]          [⚙]   │
+/// │  [vol] ||||||||  [-5s] [+5s] [▶] [■] [📷]  1.5×  [⚙]  │
 /// │  ════════════════════════════════════════════════    │
 /// └──────────────────────────────────────────────────────┘
 pub fn main_view(app: &App) -> Element<'_, Message> {
@@ -723,16 +885,19 @@ pub fn main_view(app: &App) -> Element<'_, Message> {
         play_icon(16.0).into()
     };
 
-    // 3. Control buttons row
-    let controls = row![
+    // 3. Control buttons row (with "next" while more items are queued)
+    let mut controls = row![
         circle_button(white_text("-5s", 12), Message::SkipBackward),
         circle_button(white_text("+5s", 12), Message::SkipForward),
         circle_button(play_pause_icon, Message::PlayPause),
         circle_button(stop_icon(16.0), Message::Stop),
-        circle_button(camera_icon(16.0), Message::ScreenshotRequested),
     ]
     .spacing(6)
     .align_y(Alignment::Center);
+    if app.reading_queue.len() > 0 && (app.playback_state != PlaybackState::Stopped || app.finished_item.is_some()) {
+        controls = controls.push(circle_button(white_text("⏭", 14), Message::NextQueuedItem));
+    }
+    let controls = controls.push(circle_button(camera_icon(16.0), Message::ScreenshotRequested));
 
     // 4. Base content row (without gear): [volume] [waveform] [controls]
     let content_row = row![
@@ -747,10 +912,78 @@ pub fn main_view(app: &App) -> Element<'_, Message> {
 
     // 5. Progress bar OR status text directly under the content row (not under gear)
     // Progress bar extends from left edge of content_row to right edge of screenshot button
-    let (progress_or_status, gap_height): (Element<Message>, f32) = if let Some(status) = &app.status_text {
+    let (progress_or_status, gap_height): (Element<Message>, f32) = if let Some(large) = &app.large_text {
+        // Selection over the large-text limit: confirm before synthesizing all of it
+        let minutes = (large.seconds / 60.0).round().max(1.0);
+        let mut large_content = row![
+            text(format!("Read {minutes} minutes of audio?"))
+                .size(11)
+                .style(|_theme| iced::widget::text::Style {
+                    color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.7)),
+                }),
+            Space::new().width(Length::Fixed(8.0)),
+            button(white_text("Read All", 11))
+                .style(transparent_button_style)
+                .padding([0.0, 4.0])
+                .on_press(Message::ReadLargeText(None)),
+        ]
+        .align_y(Alignment::Center);
+        if large.paragraphs > 0 {
+            let label = match large.paragraphs {
+                1 => "First Paragraph".to_string(),
+                count => format!("First {count} Paragraphs"),
+            };
+            large_content = large_content.push(
+                button(white_text(label, 11))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press(Message::ReadLargeText(Some(large.paragraphs))),
+            );
+        }
+        large_content = large_content.push(
+            button(white_text("✕", 11))
+                .style(transparent_button_style)
+                .padding([0.0, 4.0])
+                .on_press(Message::DismissLargeText),
+        );
+        let elem = container(large_content)
+        .width(Length::Fill)
+        .height(Length::Fixed(33.0))
+        .padding([-6.0, 16.0])
+        .into();
+        (elem, -8.0)
+    } else if let Some(status) = &app.status_text {
         // Show status text during loading (pushed up above where progress bar would be)
+        let status_text = text(status)
+            .size(11)
+            .style(|_theme| iced::widget::text::Style {
+                color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.7)),
+            });
+        // Between queued items the countdown gets a skip button
+        let status_content: Element<Message> = if app.reading_queue.is_waiting() {
+            row![
+                status_text,
+                Space::new().width(Length::Fixed(8.0)),
+                button(white_text("Skip", 11))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press(Message::SkipQueuePause),
+            ]
+            .align_y(Alignment::Center)
+            .into()
+        } else {
+            status_text.into()
+        };
+        let elem = container(status_content)
+        .width(Length::Fill)
+        .height(Length::Fixed(33.0))
+        .padding([-6.0, 16.0])
+        .into();
+        (elem, -8.0)
+    } else if app.buffering {
+        // Playback caught up with a slow provider: say why it went quiet
         let elem = container(
-            text(status)
+            text("Buffering…")
                 .size(11)
                 .style(|_theme| iced::widget::text::Style {
                     color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.7)),
@@ -761,6 +994,59 @@ pub fn main_view(app: &App) -> Element<'_, Message> {
         .padding([-6.0, 16.0])
         .into();
         (elem, -8.0)
+    } else if let Some(language) = &app.language_prompt {
+        // Detected language without a downloaded voice: offer to pick one
+        let name = crate::system::language_name(language).unwrap_or(language.as_str());
+        let mut prompt_content = row![
+            text(format!("{name} text"))
+                .size(11)
+                .style(|_theme| iced::widget::text::Style {
+                    color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.7)),
+                }),
+            Space::new().width(Length::Fixed(8.0)),
+        ]
+        .align_y(Alignment::Center);
+        if let Some(code) = app.voice_list_code(language) {
+            prompt_content = prompt_content.push(
+                button(white_text(format!("Choose {name} Voice"), 11))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press(Message::OpenVoiceSelection(code)),
+            );
+        }
+        prompt_content = prompt_content.push(
+            button(white_text("✕", 11))
+                .style(transparent_button_style)
+                .padding([0.0, 4.0])
+                .on_press(Message::DismissLanguagePrompt),
+        );
+        let elem = container(prompt_content)
+        .width(Length::Fill)
+        .height(Length::Fixed(33.0))
+        .padding([-6.0, 16.0])
+        .into();
+        (elem, -8.0)
+    } else if let Some(seconds) = app.resume_offer.filter(|_| app.playback_state != PlaybackState::Stopped) {
+        // Read before: offer to carry on where it was stopped
+        let resume_content = row![
+            text("Read before")
+                .size(11)
+                .style(|_theme| iced::widget::text::Style {
+                    color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.7)),
+                }),
+            Space::new().width(Length::Fixed(8.0)),
+            button(white_text(format!("Resume from {}", format_remaining(seconds)), 11))
+                .style(transparent_button_style)
+                .padding([0.0, 4.0])
+                .on_press(Message::ResumeSavedPosition),
+        ]
+        .align_y(Alignment::Center);
+        let elem = container(resume_content)
+        .width(Length::Fill)
+        .height(Length::Fixed(33.0))
+        .padding([-6.0, 16.0])
+        .into();
+        (elem, -8.0)
     } else {
         // Show progress bar during playback (stays in same position)
         // Extends from left padding (16.0) to end of screenshot button
@@ -779,16 +1065,104 @@ pub fn main_view(app: &App) -> Element<'_, Message> {
     ]
     .width(Length::Shrink);
 
-    // 6. Settings gear (transparent button) on the right
+    // 6. Speed badge (click or press S to cycle presets) and settings gear on the right
+    let speed_label = text(format_speed(app.playback_speed))
+        .size(12)
+        .style(|_theme| iced::widget::text::Style {
+            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.85)),
+        });
+    let speed_button = button(speed_label)
+        .style(transparent_button_style)
+        .padding([2.0, 6.0])
+        .on_press(Message::CycleSpeed);
+
+    // Finish time under the badge; the tooltip adds the remaining time and any
+    // speed needed to meet the "finish within" target
+    let speed_badge: Element<Message> = if app.remaining_seconds > 0.0 && app.playback_state != PlaybackState::Stopped {
+        let finish_time = format_finish_time(app.remaining_seconds);
+        let (label, details) = match (app.suggested_speed, app.finish_target_minutes) {
+            (Some(speed), Some(minutes)) => (
+                format!("try {}", format_speed(speed)),
+                format!(
+                    "{} left, ends {finish_time}. Speed up to {} to finish within {minutes} min.",
+                    format_remaining(app.remaining_seconds),
+                    format_speed(speed)
+                ),
+            ),
+            _ => (
+                format!("ends {finish_time}"),
+                format!("{} left at {}", format_remaining(app.remaining_seconds), format_speed(app.playback_speed)),
+            ),
+        };
+        // Long texts without structure get detected chapters, reached with [ and ]
+        let current_chapter = app.chapters.iter().rposition(|(start, _)| *start <= app.progress);
+        let details = match current_chapter {
+            Some(index) => format!(
+                "{details}\nChapter {} of {}: {}. Press [ or ] to change chapter.",
+                index + 1,
+                app.chapters.len(),
+                app.chapters[index].1
+            ),
+            None if !app.chapters.is_empty() => {
+                format!("{details}\n{} chapters ahead. Press ] for the first.", app.chapters.len())
+            }
+            None => details,
+        };
+        // Opened PDFs tell which page is being read
+        let page = crate::system::page_at(&app.pages, app.progress);
+        let details = match page {
+            Some(page) => format!("{details}\nPage {page} of {}.", app.pages.len()),
+            None => details,
+        };
+        let details = if app.skim.is_some() {
+            format!("{details}\nSkimming headings and first sentences. Press F to read in full from here.")
+        } else {
+            details
+        };
+        // While an A–B loop is being marked or played, the label shows it instead
+        let label = match (app.loop_region, app.loop_start) {
+            (Some((start, end)), _) => format!("⟲ {}–{}", format_remaining(start), format_remaining(end)),
+            (None, Some(start)) => format!("A {} · L", format_remaining(start)),
+            (None, None) if app.skim.is_some() => "skim · F".to_string(),
+            (None, None) => match page {
+                Some(page) => format!("p. {page} · {label}"),
+                None => label,
+            },
+        };
+        let badge = column![
+            speed_button,
+            text(label)
+                .size(9)
+                .style(|_theme| iced::widget::text::Style {
+                    color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+                }),
+        ]
+        .align_x(Alignment::Center);
+        tooltip(
+            badge,
+            container(text(details).size(11).style(|_theme| iced::widget::text::Style {
+                color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.9)),
+            }))
+            .padding([4.0, 8.0])
+            .style(section_style),
+            tooltip::Position::Left,
+        )
+        .into()
+    } else {
+        speed_button.into()
+    };
+
     let settings_btn = button(settings_icon(18.0))
         .style(transparent_button_style)
         .padding([0.0, 0.0])
         .on_press(Message::Settings);
 
-    // 7. Final row: [content_column | spacer | gear], centered with padding
+    // 7. Final row: [content_column | spacer | speed | gear], centered with padding
     let content = row![
         content_column,
         Space::new().width(Length::Fill),
+        speed_badge,
+        Space::new().width(Length::Fixed(4.0)),
         settings_btn,
     ]
     .align_y(Alignment::Center)
@@ -899,7 +1273,27 @@ pub fn voice_selection_window_view<'a>(app: &'a App) -> Element<'a, Message> {
                     .spacing(8)
                 };
                 
+                        // Dataset license, looked up on request
+                        let voice_row = voice_row.push(
+                            button(white_text("License", 11))
+                                .style(transparent_button_style)
+                                .padding([4.0, 8.0])
+                                .on_press(Message::VoiceLicenseToggled(voice_key.clone())),
+                        );
                         controls = controls.push(voice_row);
+                        if app.voice_license_shown.as_deref() == Some(voice_key.as_str()) {
+                            let (details, color) = match app.voice_licenses.get(&voice_key) {
+                                Some(Ok(license)) => (license.summary(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+                                Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+                                None => ("Looking up the license…".to_string(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+                            };
+                            controls = controls.push(row![
+                                Space::new().width(Length::Fixed(28.0)),
+                                text(details).size(11).style(move |_theme| iced::widget::text::Style {
+                                    color: Some(color),
+                                }),
+                            ]);
+                        }
                     }
                     
                     scrollable(controls).into()
@@ -1028,6 +1422,7 @@ pub fn voice_selection_window_view<'a>(app: &'a App) -> Element<'a, Message> {
                     .find(|voice| voice.language.code == *lang_code)
                     .map(|voice| voice.language.clone())
             }),
+            TTSBackend::WindowsSpeech | TTSBackend::Coqui | TTSBackend::OpenTTS | TTSBackend::Command => None,
         };
         
         let name = if let Some(lang_info) = lang_info {
@@ -1288,6 +1683,55 @@ pub fn ocr_info_window_view<'a>(_app: &'a App) -> Element<'a, Message> {
 }
 
 /// Extracted text dialog window - displays extracted text with copy button
+/// Banner for right-to-left text: its language flag, and a voice picker when
+/// the selected voice can't read it.
+fn rtl_voice_banner<'a>(app: &'a App, text: &str) -> Option<Element<'a, Message>> {
+    let language = crate::system::rtl_language(text)?;
+    let mixed = crate::system::direction_runs(text)
+        .iter()
+        .any(|run| !run.rtl && run.text.chars().any(char::is_alphabetic));
+    let label = if mixed {
+        format!("{} text with left-to-right passages", language.name())
+    } else {
+        format!("{} text", language.name())
+    };
+
+    let voice_matches = app
+        .voice_language_family()
+        .is_some_and(|family| family.starts_with(language.code()));
+    let voice_action: Element<'a, Message> = if voice_matches {
+        Space::new().width(Length::Shrink).into()
+    } else if let Some(code) = app.voice_language_code(language) {
+        button(white_text(format!("Choose {} Voice", language.name()), 12))
+            .style(circle_button_style)
+            .padding([4.0, 10.0])
+            .on_press(Message::OpenVoiceSelection(code))
+            .into()
+    } else {
+        white_text(format!("The selected voice can't read {}.", language.name()), 12)
+            .style(|_theme| iced::widget::text::Style {
+                color: Some(Color::from_rgb(1.0, 0.45, 0.45)),
+            })
+            .into()
+    };
+
+    Some(
+        container(
+            row![
+                flags::get_flag_icon(language.code()),
+                Space::new().width(Length::Fixed(8.0)),
+                white_text(label, 12),
+                Space::new().width(Length::Fill),
+                voice_action,
+            ]
+            .align_y(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .padding([8.0, 24.0])
+        .into(),
+    )
+}
+
 pub fn extracted_text_dialog_view<'a>(app: &'a App) -> Element<'a, Message> {
 
     // Display the extracted text in an editable text area
@@ -1385,6 +1829,10 @@ pub fn extracted_text_dialog_view<'a>(app: &'a App) -> Element<'a, Message> {
             .width(Length::Fill)
             .padding([20.0, 24.0])
             .style(header_style),
+            app.extracted_text
+                .as_deref()
+                .and_then(|text| rtl_voice_banner(app, text))
+                .unwrap_or_else(|| Space::new().height(Length::Shrink).into()),
             // Text content area (editable text input)
             text_content,
         ]
@@ -1400,15 +1848,737 @@ pub fn extracted_text_dialog_view<'a>(app: &'a App) -> Element<'a, Message> {
     .into()
 }
 
+/// Review window - the captured text, editable before it is read
+pub fn review_window_view<'a>(app: &'a App) -> Element<'a, Message> {
+    let Some(editor) = app.review_editor.as_ref() else {
+        return container(white_text("No text to review", 14))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x(Length::Fill)
+            .center_y(Length::Fill)
+            .style(modal_content_style)
+            .into();
+    };
+
+    // What reading it costs: characters, and the price with Polly
+    let reviewed = editor.text();
+    let size = if app.selected_backend == TTSBackend::AwsPolly {
+        crate::providers::PollyCostEstimate::new(app.selected_polly_voice.as_deref(), &reviewed).summary()
+    } else {
+        format!("{} chars", reviewed.trim().chars().count())
+    };
+
+    let speak_button = button(
+        container(
+            row![
+                play_icon(16.0),
+                Space::new().width(Length::Fixed(6.0)),
+                white_text("Speak", 13)
+                    .style(|_theme| iced::widget::text::Style {
+                        color: Some(Color::WHITE),
+                    })
+            ]
+            .align_y(Alignment::Center)
+        )
+        .padding([8.0, 16.0])
+    )
+    .style(transparent_button_style)
+    .on_press_maybe((!reviewed.trim().is_empty()).then_some(Message::SpeakReviewed));
+
+    container(
+        column![
+            container(
+                row![
+                    white_text("Review Text", 20)
+                        .style(|_theme| iced::widget::text::Style {
+                            color: Some(Color::WHITE),
+                        }),
+                    Space::new().width(Length::Fixed(12.0)),
+                    text(size)
+                        .size(12)
+                        .style(|_theme| iced::widget::text::Style {
+                            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+                        }),
+                    Space::new().width(Length::Fill),
+                    speak_button,
+                    Space::new().width(Length::Fixed(16.0)),
+                    close_button(Message::CancelReview),
+                ]
+                .width(Length::Fill)
+                .align_y(Alignment::Center)
+            )
+            .width(Length::Fill)
+            .padding([20.0, 24.0])
+            .style(header_style),
+            container(text_editor(editor).on_action(Message::ReviewEditorAction).height(Length::Fill))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(8),
+        ]
+        .spacing(0)
+        .width(Length::Fill)
+        .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .style(modal_content_style)
+    .into()
+}
+
+/// Phrase board window - a grid of phrases spoken with one click
+pub fn phrase_board_window_view<'a>(app: &'a App) -> Element<'a, Message> {
+    const COLUMNS: usize = 3;
+
+    let mut grid = column![].spacing(8);
+    for (row_index, phrases) in app.phrases.chunks(COLUMNS).enumerate() {
+        let mut phrase_row = row![].spacing(8);
+        for (column_index, phrase) in phrases.iter().enumerate() {
+            let index = row_index * COLUMNS + column_index;
+            let phrase_button = button(
+                container(white_text(phrase, 15).center())
+                    .width(Length::Fill)
+                    .center_x(Length::Fill)
+                    .padding([10.0, 6.0]),
+            )
+            .width(Length::Fill)
+            .style(circle_button_style)
+            .on_press(Message::SpeakPhrase(index));
+            let cell: Element<'a, Message> = if app.editing_phrases {
+                row![
+                    phrase_button,
+                    button(white_text("✕", 12))
+                        .style(transparent_button_style)
+                        .padding([0.0, 4.0])
+                        .on_press(Message::DeletePhrase(index)),
+                ]
+                .align_y(Alignment::Center)
+                .width(Length::FillPortion(1))
+                .into()
+            } else {
+                container(phrase_button).width(Length::FillPortion(1)).into()
+            };
+            phrase_row = phrase_row.push(cell);
+        }
+        // Pad the last row so buttons keep the same width
+        for _ in phrases.len()..COLUMNS {
+            phrase_row = phrase_row.push(Space::new().width(Length::FillPortion(1)));
+        }
+        grid = grid.push(phrase_row);
+    }
+    if app.phrases.is_empty() {
+        grid = grid.push(white_text("No phrases yet. Click Edit to add some.", 13));
+    }
+
+    let edit_button = button(white_text(if app.editing_phrases { "Done" } else { "Edit" }, 13))
+        .style(transparent_button_style)
+        .padding([6.0, 12.0])
+        .on_press(Message::TogglePhraseEditing);
+
+    let mut content = column![scrollable(grid).height(Length::Fill)]
+        .spacing(12)
+        .padding([16.0, 24.0])
+        .height(Length::Fill);
+    if app.editing_phrases {
+        content = content.push(
+            row![
+                text_input("New phrase (e.g. I'll be right back)", &app.new_phrase)
+                    .on_input(Message::NewPhraseChanged)
+                    .on_submit(Message::AddPhrase)
+                    .size(13)
+                    .padding([6.0, 8.0])
+                    .width(Length::Fill)
+                    .style(dark_text_input_style),
+                button(white_text("Add", 12))
+                    .style(circle_button_style)
+                    .padding([6.0, 12.0])
+                    .on_press(Message::AddPhrase),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+    }
+
+    container(
+        column![
+            container(
+                row![
+                    white_text("Phrase Board", 20)
+                        .style(|_theme| iced::widget::text::Style {
+                            color: Some(Color::WHITE),
+                        }),
+                    Space::new().width(Length::Fill),
+                    edit_button,
+                    Space::new().width(Length::Fixed(16.0)),
+                    close_button(Message::ClosePhraseBoard),
+                ]
+                .width(Length::Fill)
+                .align_y(Alignment::Center)
+            )
+            .width(Length::Fill)
+            .padding([20.0, 24.0])
+            .style(header_style),
+            content,
+        ]
+        .spacing(0)
+        .width(Length::Fill)
+        .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .style(modal_content_style)
+    .into()
+}
+
+/// Read-later window - lists unread Wallabag / Readwise Reader articles to read aloud
+pub fn read_later_window_view<'a>(app: &'a App) -> Element<'a, Message> {
+    let mut list = column![].spacing(8);
+    for (index, article) in app.read_later_articles.iter().enumerate() {
+        let is_reading = app.reading_article.as_deref() == Some(article.id.as_str());
+        let title = if article.title.is_empty() { article.url.as_str() } else { article.title.as_str() };
+        list = list.push(
+            row![
+                column![
+                    white_text(title, 14),
+                    text(&article.url)
+                        .size(11)
+                        .style(|_theme| iced::widget::text::Style {
+                            color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.5)),
+                        }),
+                ]
+                .spacing(2)
+                .width(Length::Fill),
+                button(white_text(if is_reading { "Reading…" } else { "Read" }, 12))
+                    .style(circle_button_style)
+                    .padding([6.0, 12.0])
+                    .on_press_maybe((!is_reading).then_some(Message::ReadArticle(index))),
+            ]
+            .spacing(12)
+            .align_y(Alignment::Center),
+        );
+    }
+
+    let status = if let Some(error) = &app.read_later_error {
+        Some((error.clone(), Color::from_rgb(1.0, 0.45, 0.45)))
+    } else if app.read_later_loading {
+        Some(("Loading articles…".to_string(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)))
+    } else if app.read_later_articles.is_empty() {
+        let hint = if app.read_later.is_configured() {
+            "No unread articles."
+        } else {
+            "Set up Wallabag or Readwise Reader in Settings."
+        };
+        Some((hint.to_string(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)))
+    } else {
+        None
+    };
+
+    let mut content = column![].spacing(12).padding([16.0, 24.0]).height(Length::Fill);
+    if let Some((status_text, status_color)) = status {
+        content = content.push(text(status_text).size(13).style(move |_theme| iced::widget::text::Style {
+            color: Some(status_color),
+        }));
+    }
+    content = content.push(scrollable(list).height(Length::Fill));
+    content = content.push(
+        text("Articles listened to the end are archived.")
+            .size(11)
+            .style(|_theme| iced::widget::text::Style {
+                color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+            }),
+    );
+
+    let refresh_button = button(white_text("Refresh", 13))
+        .style(transparent_button_style)
+        .padding([6.0, 12.0])
+        .on_press_maybe((!app.read_later_loading).then_some(Message::RefreshReadLater));
+
+    container(
+        column![
+            container(
+                row![
+                    white_text("Read Later", 20)
+                        .style(|_theme| iced::widget::text::Style {
+                            color: Some(Color::WHITE),
+                        }),
+                    Space::new().width(Length::Fill),
+                    refresh_button,
+                    Space::new().width(Length::Fixed(16.0)),
+                    close_button(Message::CloseReadLater),
+                ]
+                .width(Length::Fill)
+                .align_y(Alignment::Center)
+            )
+            .width(Length::Fill)
+            .padding([20.0, 24.0])
+            .style(header_style),
+            content,
+        ]
+        .spacing(0)
+        .width(Length::Fill)
+        .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .style(modal_content_style)
+    .into()
+}
+
+/// Debug overlay - live latency numbers of each stage of reading
+pub fn debug_overlay_view<'a>(_app: &'a App) -> Element<'a, Message> {
+    let dim = |_theme: &iced::Theme| iced::widget::text::Style {
+        color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+    };
+    let cell = |content: String| container(text(content).size(12).style(dim)).width(Length::Fixed(64.0));
+
+    let mut rows = column![row![
+        container(white_text("Stage", 12)).width(Length::Fill),
+        cell("Last".to_string()),
+        cell("Avg".to_string()),
+        cell("Max".to_string()),
+    ]]
+    .spacing(6)
+    .padding([10.0, 16.0]);
+    for stage in latency::Stage::ALL {
+        let timings = latency::timings(stage);
+        let shown = |duration| {
+            if timings.count == 0 { "—".to_string() } else { latency::format_duration(duration) }
+        };
+        rows = rows.push(row![
+            container(white_text(stage.label(), 12)).width(Length::Fill),
+            cell(shown(timings.last)),
+            cell(shown(timings.average)),
+            cell(shown(timings.max)),
+        ]);
+    }
+
+    container(
+        column![
+            container(
+                row![
+                    white_text("Latency", 14),
+                    Space::new().width(Length::Fill),
+                    button(white_text("Reset", 12))
+                        .style(transparent_button_style)
+                        .padding([4.0, 10.0])
+                        .on_press(Message::ResetLatency),
+                    Space::new().width(Length::Fixed(8.0)),
+                    close_button(Message::DebugOverlayToggled(false)),
+                ]
+                .width(Length::Fill)
+                .align_y(Alignment::Center)
+            )
+            .width(Length::Fill)
+            .padding([8.0, 16.0])
+            .style(header_style),
+            rows,
+        ]
+        .spacing(0)
+        .width(Length::Fill)
+        .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .style(modal_content_style)
+    .into()
+}
+
+/// Longest preview of a queued item's text, in characters.
+const QUEUE_PREVIEW_CHARS: usize = 90;
+
+/// Reading queue window - waiting items, to reorder by dragging, remove or edit before they play
+pub fn queue_window_view<'a>(app: &'a App) -> Element<'a, Message> {
+    let hint_style = |_theme: &iced::Theme| iced::widget::text::Style {
+        color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+    };
+
+    let content: Element<'a, Message> = if let Some((_, ref editor_content)) = app.queue_editor {
+        column![
+            text_editor(editor_content)
+                .on_action(Message::QueueEditorAction)
+                .height(Length::Fill),
+            row![
+                text("It waits until saved; the items after it are read meanwhile.")
+                    .size(11)
+                    .style(hint_style)
+                    .width(Length::Fill),
+                button(white_text("Cancel", 12))
+                    .style(transparent_button_style)
+                    .padding([6.0, 12.0])
+                    .on_press(Message::CancelQueuedItemEdit),
+                button(white_text("Save", 12))
+                    .style(circle_button_style)
+                    .padding([6.0, 12.0])
+                    .on_press(Message::SaveQueuedItem),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        ]
+        .spacing(12)
+        .padding([16.0, 24.0])
+        .height(Length::Fill)
+        .into()
+    } else {
+        let mut list = column![].spacing(6);
+        for (index, (id, item)) in app.reading_queue.entries().enumerate() {
+            let mut preview: String = item.text.split_whitespace().collect::<Vec<_>>().join(" ");
+            if let Some((cut, _)) = preview.char_indices().nth(QUEUE_PREVIEW_CHARS) {
+                preview.truncate(cut);
+                preview.push('…');
+            }
+            let voice = [item.voice.engine.as_deref(), item.voice.voice.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" · ");
+            let mut details = column![white_text(preview, 13)].spacing(2).width(Length::Fill);
+            if !voice.is_empty() {
+                details = details.push(text(voice).size(11).style(|_theme| iced::widget::text::Style {
+                    color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.5)),
+                }));
+            }
+            let handle = mouse_area(container(white_text("≡", 16)).padding([4.0, 6.0]))
+                .on_press(Message::QueueDragStarted(id))
+                .interaction(iced::mouse::Interaction::Grab);
+            let entry = row![
+                handle,
+                details,
+                button(white_text("Edit", 12))
+                    .style(circle_button_style)
+                    .padding([6.0, 12.0])
+                    .on_press(Message::EditQueuedItem(id)),
+                button(white_text("✕", 12))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press(Message::RemoveQueuedItem(id)),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center);
+            // Rows passed over while dragging make room for the dragged item
+            let entry = if app.queue_drag == Some(id) {
+                container(entry).style(|_theme| container::Style {
+                    background: Some(Background::Color(Color::from_rgba(1.0, 1.0, 1.0, 0.08))),
+                    ..Default::default()
+                })
+            } else {
+                container(entry)
+            };
+            list = list.push(mouse_area(entry).on_enter(Message::QueueDraggedOver(index)));
+        }
+
+        let mut content = column![].spacing(12).padding([16.0, 24.0]).height(Length::Fill);
+        if app.reading_queue.len() == 0 {
+            content = content.push(
+                text("Nothing queued. Texts read while something is playing wait here.")
+                    .size(13)
+                    .style(hint_style),
+            );
+        }
+        content = content.push(
+            mouse_area(scrollable(list).height(Length::Fill))
+                .on_release(Message::QueueDragEnded)
+                .on_exit(Message::QueueDragEnded),
+        );
+        content = content.push(
+            text("Drag ≡ to change the order. Items are read top to bottom.")
+                .size(11)
+                .style(hint_style),
+        );
+        content = content.push(open_file_section(app));
+        content = content.push(playlists_section(app));
+        content.into()
+    };
+
+    container(
+        column![
+            container(
+                row![
+                    white_text("Reading Queue", 20)
+                        .style(|_theme| iced::widget::text::Style {
+                            color: Some(Color::WHITE),
+                        }),
+                    Space::new().width(Length::Fill),
+                    close_button(Message::CloseQueue),
+                ]
+                .width(Length::Fill)
+                .align_y(Alignment::Center)
+            )
+            .width(Length::Fill)
+            .padding([20.0, 24.0])
+            .style(header_style),
+            content,
+        ]
+        .spacing(0)
+        .width(Length::Fill)
+        .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .style(modal_content_style)
+    .into()
+}
+
+/// Reading a file from the queue window: a path to open (files can also be dropped on any window).
+fn open_file_section(app: &App) -> Element<'_, Message> {
+    let controls = row![
+        text_input("Path to a PDF, text or HTML file", &app.open_file_path)
+            .on_input(Message::OpenFilePathChanged)
+            .on_submit(Message::OpenFile)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fill)
+            .style(dark_text_input_style),
+        button(white_text("Read", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((!app.open_file_path.trim().is_empty()).then_some(Message::OpenFile)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let (status_text, status_color) = match &app.open_file_status {
+        Some(Ok(message)) => (message.clone(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        None => (
+            "Or drop a file on any window. PDFs are read page by page.".to_string(),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        ),
+    };
+
+    column![
+        white_text("Open File", 14),
+        controls,
+        text(status_text).size(11).style(move |_theme| iced::widget::text::Style {
+            color: Some(status_color),
+        }),
+    ]
+    .spacing(8)
+    .into()
+}
+
+/// Saved playlists of the queue window: save the queue, load, import and export.
+fn playlists_section(app: &App) -> Element<'_, Message> {
+    let mut list = column![].spacing(4);
+    for (index, playlist) in app.playlists.iter().enumerate() {
+        list = list.push(
+            row![
+                white_text(format!("{} ({} items)", playlist.name, playlist.items.len()), 13).width(Length::Fill),
+                button(white_text("Load", 12))
+                    .style(circle_button_style)
+                    .padding([4.0, 10.0])
+                    .on_press(Message::LoadPlaylist(index)),
+                button(white_text("Export", 12))
+                    .style(circle_button_style)
+                    .padding([4.0, 10.0])
+                    .on_press_maybe((!app.playlist_path.trim().is_empty()).then_some(Message::ExportPlaylist(index))),
+                button(white_text("✕", 12))
+                    .style(transparent_button_style)
+                    .padding([0.0, 4.0])
+                    .on_press(Message::DeletePlaylist(index)),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+    }
+    // Grow with the list up to a few rows, then scroll
+    let list_height = (app.playlists.len() as f32 * 30.0).min(120.0);
+
+    let save_controls = row![
+        text_input("Playlist name (e.g. Spanish practice)", &app.new_playlist_name)
+            .on_input(Message::NewPlaylistNameChanged)
+            .on_submit(Message::SaveQueueAsPlaylist)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fill)
+            .style(dark_text_input_style),
+        button(white_text("Save Queue", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((!app.new_playlist_name.trim().is_empty()).then_some(Message::SaveQueueAsPlaylist)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let file_controls = row![
+        text_input("Path to .json file", &app.playlist_path)
+            .on_input(Message::PlaylistPathChanged)
+            .size(13)
+            .padding([6.0, 8.0])
+            .width(Length::Fill)
+            .style(dark_text_input_style),
+        button(white_text("Import", 12))
+            .style(circle_button_style)
+            .padding([6.0, 12.0])
+            .on_press_maybe((!app.playlist_path.trim().is_empty()).then_some(Message::ImportPlaylists)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let (status_text, status_color) = match &app.playlist_status {
+        Some(Ok(message)) => (message.clone(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        None => (
+            "Loading a playlist adds its items to the end of the queue.".to_string(),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        ),
+    };
+
+    column![
+        white_text("Playlists", 14),
+        scrollable(list).height(Length::Fixed(list_height)),
+        save_controls,
+        file_controls,
+        text(status_text).size(11).style(move |_theme| iced::widget::text::Style {
+            color: Some(status_color),
+        }),
+    ]
+    .spacing(8)
+    .into()
+}
+
+/// Crash report window - offered on launch after a crash, to open or copy the local report
+pub fn crash_report_window_view<'a>(app: &'a App) -> Element<'a, Message> {
+    let report_path = app
+        .crash_report
+        .as_ref()
+        .map(|path| path.display().to_string())
+        .unwrap_or_default();
+
+    let (status_text, status_color) = match &app.crash_report_status {
+        Some(Ok(message)) => (message.clone(), Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+        Some(Err(error)) => (error.clone(), Color::from_rgb(1.0, 0.45, 0.45)),
+        None => (
+            "It stays on this computer: attach it to a bug report if you like.".to_string(),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.6),
+        ),
+    };
+
+    let content = column![
+        white_text("Insight Reader closed unexpectedly last time.", 14),
+        white_text(
+            "A crash report was saved with technical details and recent log messages, but none of the text you read.",
+            13,
+        ),
+        text(report_path)
+            .size(11)
+            .style(|_theme| iced::widget::text::Style {
+                color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.5)),
+            }),
+        row![
+            button(white_text("Open Report", 12))
+                .style(circle_button_style)
+                .padding([6.0, 12.0])
+                .on_press(Message::OpenCrashReport),
+            button(white_text("Copy Report", 12))
+                .style(circle_button_style)
+                .padding([6.0, 12.0])
+                .on_press(Message::CopyCrashReport),
+        ]
+        .spacing(8),
+        text(status_text)
+            .size(11)
+            .style(move |_theme| iced::widget::text::Style {
+                color: Some(status_color),
+            }),
+    ]
+    .spacing(10)
+    .padding([16.0, 24.0]);
+
+    container(
+        column![modal_header("Crash Report", Message::CloseCrashReport), content]
+            .spacing(0)
+            .width(Length::Fill)
+            .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .style(modal_content_style)
+    .into()
+}
+
+/// Type-to-talk window - speaks each typed line on Enter and keeps the session history
+pub fn type_to_talk_window_view<'a>(app: &'a App) -> Element<'a, Message> {
+    let mut history = column![].spacing(6);
+    for (index, line) in app.talk_history.iter().enumerate() {
+        history = history.push(
+            row![
+                white_text(line, 14).width(Length::Fill),
+                tooltip(
+                    button(white_text("↻", 14))
+                        .style(transparent_button_style)
+                        .padding([2.0, 8.0])
+                        .on_press(Message::ResayTalk(index)),
+                    white_text("Say again", 12),
+                    tooltip::Position::Left,
+                ),
+            ]
+            .align_y(Alignment::Center),
+        );
+    }
+    if app.talk_history.is_empty() {
+        history = history.push(
+            white_text("Type a line and press Enter to say it.", 13)
+                .style(|_theme| iced::widget::text::Style {
+                    color: Some(Color::from_rgba(1.0, 1.0, 1.0, 0.6)),
+                }),
+        );
+    }
+
+    let input = text_input("Type here, Enter to speak", &app.talk_input)
+        .id(crate::model::TALK_INPUT_ID)
+        .on_input(Message::TalkInputChanged)
+        .on_submit(Message::TalkSubmit)
+        .size(15)
+        .padding([10.0, 12.0])
+        .width(Length::Fill)
+        .style(dark_text_input_style);
+
+    let virtual_mic = checkbox(app.effective_output().virtual_mic)
+        .label("Speak into virtual microphone")
+        .on_toggle(Message::VirtualMicToggled)
+        .style(white_checkbox_style);
+
+    let echo_selected = Some(app.typing_echo);
+    let echo_controls = row![
+        white_text("Echo while typing:", 13),
+        radio("Off", TypingEcho::Off, echo_selected, Message::TypingEchoSelected).style(white_radio_style),
+        radio("Characters", TypingEcho::Characters, echo_selected, Message::TypingEchoSelected)
+            .style(white_radio_style),
+        radio("Words", TypingEcho::Words, echo_selected, Message::TypingEchoSelected).style(white_radio_style),
+    ]
+    .spacing(16)
+    .align_y(Alignment::Center);
+
+    container(
+        column![
+            modal_header("Type to Talk", Message::CloseTypeToTalk),
+            column![
+                scrollable(history).anchor_bottom().height(Length::Fill),
+                input,
+                echo_controls,
+                virtual_mic,
+            ]
+            .spacing(12)
+            .padding([16.0, 24.0])
+            .height(Length::Fill),
+        ]
+        .spacing(0)
+        .width(Length::Fill)
+        .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .style(modal_content_style)
+    .into()
+}
+
 /// Screenshot viewer window - displays the captured screenshot
 pub fn screenshot_viewer_view<'a>(app: &'a App) -> Element<'a, Message> {
 
     // Display the screenshot image if available
-    let image_content: Element<'a, Message> = if let Some(ref screenshot_path) = app.screenshot_path {
-        // Load image from file path using Iced's image widget
-        use iced::widget::image::{Image, Handle};
-        let image_handle = Handle::from_path(screenshot_path);
-        let img = Image::new(image_handle)
+    let image_content: Element<'a, Message> = if let Some(ref image_handle) = app.screenshot_image {
+        // The capture file may already be deleted, so show the copy kept in memory
+        use iced::widget::image::Image;
+        let img = Image::new(image_handle.clone())
             .width(Length::Fill)
             .height(Length::Fill)
             .content_fit(ContentFit::Contain);
@@ -3,6 +3,7 @@
 //! Handles fetching and organizing voices from AWS Polly using the AWS SDK.
 
 use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
 use tracing::{debug, trace};
 
 use crate::model::LanguageInfo;
@@ -100,13 +101,112 @@ pub async fn fetch_polly_voices() -> Result<HashMap<String, PollyVoiceInfo>, Str
     Ok(voices)
 }
 
-/// Detect AWS region from environment or config file.
+/// `AWS_PROFILE` as the app was started with, before any profile was picked in settings.
+static INITIAL_PROFILE: OnceLock<Option<String>> = OnceLock::new();
+
+/// Select the named profile for every AWS SDK client created from now on.
+///
+/// `None` restores the profile from the environment the app was started with.
+pub fn set_aws_profile(profile: Option<&str>) {
+    let initial = INITIAL_PROFILE.get_or_init(|| std::env::var("AWS_PROFILE").ok());
+    match profile.or(initial.as_deref()) {
+        Some(profile) => {
+            debug!(profile, "Using AWS profile");
+            std::env::set_var("AWS_PROFILE", profile);
+        }
+        None => std::env::remove_var("AWS_PROFILE"),
+    }
+}
+
+/// List the profiles declared in `~/.aws/credentials` and `~/.aws/config`.
+///
+/// "default" comes first, the rest are sorted by name.
+pub fn list_aws_profiles() -> Vec<String> {
+    let Some(aws_dir) = dirs::home_dir().map(|home| home.join(".aws")) else {
+        return Vec::new();
+    };
+
+    let mut profiles: Vec<String> = Vec::new();
+    for (file, config_file) in [("credentials", false), ("config", true)] {
+        let Ok(content) = std::fs::read_to_string(aws_dir.join(file)) else {
+            continue;
+        };
+        for name in parse_profile_names(&content, config_file) {
+            if !profiles.contains(&name) {
+                profiles.push(name);
+            }
+        }
+    }
+    profiles.sort_by(|a, b| (a != "default").cmp(&(b != "default")).then_with(|| a.cmp(b)));
+    debug!(count = profiles.len(), "AWS profiles listed");
+    profiles
+}
+
+/// Profile names declared in a shared credentials file (`[name]`) or
+/// config file (`[default]`, `[profile name]`; other sections are skipped).
+fn parse_profile_names(content: &str, config_file: bool) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let section = line.trim().strip_prefix('[')?.strip_suffix(']')?.trim();
+            let name = if !config_file || section == "default" {
+                section
+            } else {
+                section.strip_prefix("profile ")?.trim()
+            };
+            (!name.is_empty()).then(|| name.to_string())
+        })
+        .collect()
+}
+
+/// Regions where Amazon Polly is available, offered in the provider settings.
+pub const POLLY_REGIONS: [&str; 19] = [
+    "us-east-1",
+    "us-east-2",
+    "us-west-1",
+    "us-west-2",
+    "ca-central-1",
+    "sa-east-1",
+    "eu-west-1",
+    "eu-west-2",
+    "eu-west-3",
+    "eu-central-1",
+    "eu-north-1",
+    "af-south-1",
+    "me-south-1",
+    "ap-south-1",
+    "ap-northeast-1",
+    "ap-northeast-2",
+    "ap-northeast-3",
+    "ap-southeast-1",
+    "ap-southeast-2",
+];
+
+/// Region picked in settings, overriding the detected one.
+static REGION_OVERRIDE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Use `region` for every AWS SDK client created from now on.
+///
+/// `None` goes back to detecting it from the environment and `~/.aws/config`.
+pub fn set_aws_region(region: Option<&str>) {
+    debug!(?region, "Using AWS region override");
+    if let Ok(mut current) = REGION_OVERRIDE.lock() {
+        *current = region.map(str::to_string);
+    }
+}
+
+/// Detect AWS region from settings, environment or config file.
 ///
 /// Priority:
-/// 1. AWS_REGION or AWS_DEFAULT_REGION environment variables
-/// 2. ~/.aws/config file (default profile)
-/// 3. Falls back to us-east-1
+/// 1. Region picked in settings ([`set_aws_region`])
+/// 2. AWS_REGION or AWS_DEFAULT_REGION environment variables
+/// 3. ~/.aws/config file (default profile)
+/// 4. Falls back to us-east-1
 pub fn detect_aws_region() -> String {
+    if let Some(region) = REGION_OVERRIDE.lock().ok().and_then(|region| region.clone()) {
+        return region;
+    }
+
     // Check environment variables first
     if let Ok(region) = std::env::var("AWS_REGION") {
         if !region.is_empty() {
@@ -359,3 +459,17 @@ pub fn get_voices_for_language<'a>(
         .filter(|voice| voice.language.code == language_code)
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_profile_names() {
+        let credentials = "[default]\naws_access_key_id = A\n\n[work]\naws_access_key_id = B\n";
+        assert_eq!(parse_profile_names(credentials, false), vec!["default", "work"]);
+
+        let config = "[default]\nregion = us-east-1\n[profile sso-dev]\nsso_session = corp\n[sso-session corp]\n";
+        assert_eq!(parse_profile_names(config, true), vec!["default", "sso-dev"]);
+    }
+}
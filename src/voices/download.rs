@@ -3,20 +3,18 @@
 //! Downloads voice model files (.onnx and .onnx.json) from Hugging Face.
 
 use std::fs;
-use std::io::Write;
 use std::path::{Path, PathBuf};
 
-use dirs::data_dir;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 use crate::model::VoiceInfo;
 
-const HUGGINGFACE_BASE_URL: &str = "https://huggingface.co/rhasspy/piper-voices/resolve/main";
+pub(super) const HUGGINGFACE_BASE_URL: &str = "https://huggingface.co/rhasspy/piper-voices/resolve/main";
 
 /// Download a voice model from Hugging Face
 ///
-/// Downloads both the .onnx and .onnx.json files to:
-/// `~/.local/share/insight-reader/models/{voice_key}/`
+/// Downloads both the .onnx and .onnx.json files to the models folder
+/// (`~/.local/share/insight-reader/models/` unless a storage folder is set).
 pub async fn download_voice(
     voice_key: &str,
     voice_info: &VoiceInfo,
@@ -41,6 +39,17 @@ pub async fn download_voice(
         .find(|(path, _)| path.ends_with(".onnx.json"))
         .ok_or_else(|| format!("No .onnx.json file found for voice {voice_key}"))?;
     
+    // Another user or process sharing the models folder may be downloading it too
+    let (lock_dir, lock_name) = (model_dir.clone(), voice_key.to_string());
+    let _lock = tokio::task::spawn_blocking(move || crate::storage::lock(&lock_dir, &lock_name))
+        .await
+        .map_err(|e| format!("Lock task failed: {e}"))?
+        .map_err(|e| format!("Failed to lock {}: {e}", model_dir.display()))?;
+    if model_dir.join(format!("{voice_key}.onnx")).exists() && model_dir.join(format!("{voice_key}.onnx.json")).exists() {
+        info!(voice_key = %voice_key, "Voice was downloaded meanwhile, skipping");
+        return Ok(model_dir.join(voice_key));
+    }
+
     // Download .onnx file
     let onnx_url = format!("{}/{}", HUGGINGFACE_BASE_URL, onnx_file.0);
     let onnx_path = model_dir.join(format!("{}.onnx", voice_key));
@@ -51,6 +60,15 @@ pub async fn download_voice(
     let json_path = model_dir.join(format!("{}.onnx.json", voice_key));
     download_file(&json_url, &json_path, Some(&json_file.1.md5_digest)).await?;
     
+    // The model card names the license; the voice works without it
+    if let Some((card, info)) = voice_info.files.iter().find(|(path, _)| path.ends_with("MODEL_CARD")) {
+        let card_url = format!("{}/{}", HUGGINGFACE_BASE_URL, card);
+        let card_path = model_dir.join(super::license::model_card_file_name(voice_key));
+        if let Err(e) = download_file(&card_url, &card_path, Some(&info.md5_digest)).await {
+            warn!(voice_key = %voice_key, error = %e, "Failed to download the voice's model card");
+        }
+    }
+    
     info!(voice_key = %voice_key, path = %model_dir.display(), "Voice download completed");
     Ok(model_dir.join(voice_key))
 }
@@ -91,10 +109,8 @@ async fn download_file(
         debug!(path = %path.display(), "MD5 checksum verified");
     }
     
-    // Write file
-    let mut file = fs::File::create(path)
-        .map_err(|e| format!("Failed to create file {}: {e}", path.display()))?;
-    file.write_all(&bytes)
+    // Write file (atomically: the models folder may be shared)
+    crate::storage::write_atomic(path, &bytes)
         .map_err(|e| format!("Failed to write file {}: {e}", path.display()))?;
     
     debug!(path = %path.display(), bytes = bytes.len(), "File downloaded successfully");
@@ -102,11 +118,8 @@ async fn download_file(
 }
 
 /// Get the model directory for a voice key
-fn get_model_directory(_voice_key: &str) -> Result<PathBuf, String> {
-    let data_dir = data_dir()
-        .ok_or_else(|| "Failed to get data directory".to_string())?;
-    
-    Ok(data_dir.join("insight-reader").join("models"))
+pub(super) fn get_model_directory(_voice_key: &str) -> Result<PathBuf, String> {
+    Ok(crate::storage::models_dir())
 }
 
 /// Check if a voice is already downloaded
@@ -116,10 +129,13 @@ pub fn is_voice_downloaded(voice_key: &str) -> bool {
         Err(_) => return false,
     };
     
-    let onnx_path = model_dir.join(format!("{}.onnx", voice_key));
-    let json_path = model_dir.join(format!("{}.onnx.json", voice_key));
-    
-    onnx_path.exists() && json_path.exists()
+    // Voices provisioned in a system-wide install count as downloaded too
+    std::iter::once(model_dir)
+        .chain(crate::storage::system_models_dirs())
+        .any(|dir| {
+            dir.join(format!("{}.onnx", voice_key)).exists()
+                && dir.join(format!("{}.onnx.json", voice_key)).exists()
+        })
 }
 
 #[cfg(test)]
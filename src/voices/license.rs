@@ -0,0 +1,130 @@
+//! Piper voice licenses and attribution
+//!
+//! Each voice in piper-voices ships a `MODEL_CARD` naming the dataset it was
+//! trained on and that dataset's license. The card is saved next to the model
+//! when a voice is downloaded and fetched from Hugging Face otherwise, so the
+//! voice list can show what usage is permitted and exported audio can carry
+//! the attribution.
+
+use std::fs;
+use std::path::PathBuf;
+
+use tracing::{debug, warn};
+
+use super::download::HUGGINGFACE_BASE_URL;
+use crate::model::VoiceInfo;
+
+/// License and dataset attribution of a voice.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VoiceLicense {
+    /// Licenses of the training data (names or URLs)
+    pub licenses: Vec<String>,
+    /// Where the training datasets come from
+    pub datasets: Vec<String>,
+}
+
+impl VoiceLicense {
+    /// One line for display and audio metadata, e.g. "License: CC BY 4.0 · Dataset: https://…".
+    pub fn summary(&self) -> String {
+        let licenses = if self.licenses.is_empty() {
+            "not stated".to_string()
+        } else {
+            self.licenses.join("; ")
+        };
+        if self.datasets.is_empty() {
+            format!("License: {licenses}")
+        } else {
+            format!("License: {licenses} · Dataset: {}", self.datasets.join("; "))
+        }
+    }
+}
+
+/// File name the model card of `voice_key` is saved under in the models folder.
+pub fn model_card_file_name(voice_key: &str) -> String {
+    format!("{voice_key}.MODEL_CARD")
+}
+
+/// Read the `* License:` and `* URL:` lines of a model card.
+fn parse_model_card(card: &str) -> VoiceLicense {
+    let mut license = VoiceLicense::default();
+    for line in card.lines() {
+        let line = line.trim().trim_start_matches(['*', '-']).trim();
+        let Some((field, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim().to_string();
+        if value.is_empty() {
+            continue;
+        }
+        match field.trim().to_ascii_lowercase().as_str() {
+            "license" => license.licenses.push(value),
+            "url" => license.datasets.push(value),
+            _ => {}
+        }
+    }
+    license
+}
+
+/// Model card of an installed voice, in the user's or the system-wide models folder.
+fn local_model_card(voice_key: &str) -> Option<PathBuf> {
+    let name = model_card_file_name(voice_key);
+    std::iter::once(crate::storage::models_dir())
+        .chain(crate::storage::system_models_dirs())
+        .map(|dir| dir.join(&name))
+        .find(|path| path.exists())
+}
+
+/// License of an installed voice, when its model card was saved with it.
+pub fn local_voice_license(voice_key: &str) -> Option<VoiceLicense> {
+    let card = fs::read_to_string(local_model_card(voice_key)?).ok()?;
+    Some(parse_model_card(&card))
+}
+
+/// License of `voice`: from its saved model card, else fetched from Hugging Face.
+///
+/// A fetched card is saved next to the model if the voice is installed.
+pub async fn fetch_voice_license(voice: VoiceInfo) -> Result<VoiceLicense, String> {
+    if let Some(license) = local_voice_license(&voice.key) {
+        return Ok(license);
+    }
+    let card_path = voice
+        .files
+        .keys()
+        .find(|path| path.ends_with("MODEL_CARD"))
+        .ok_or_else(|| format!("No model card is published for {}", voice.key))?;
+    let url = format!("{HUGGINGFACE_BASE_URL}/{card_path}");
+    debug!(url = %url, "Fetching voice model card");
+    let response = reqwest::get(&url).await.map_err(|e| format!("Failed to fetch the model card: {e}"))?;
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch the model card: HTTP {}", response.status()));
+    }
+    let card = response.text().await.map_err(|e| format!("Failed to read the model card: {e}"))?;
+
+    if super::download::is_voice_downloaded(&voice.key) {
+        let path = crate::storage::models_dir().join(model_card_file_name(&voice.key));
+        if let Err(e) = crate::storage::write_atomic(&path, card.as_bytes()) {
+            warn!(path = %path.display(), error = %e, "Failed to save the model card");
+        }
+    }
+    Ok(parse_model_card(&card))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_model_card() {
+        let card = "# Model card for lessac (medium)\n\n\
+            * Language: en_US (English, United States)\n\
+            * Speakers: 1\n\n\
+            ## Dataset\n\n\
+            * URL: https://www.cstr.ed.ac.uk/projects/blizzard/2011/lessac_blizzard2011/\n\
+            * License: https://www.cstr.ed.ac.uk/projects/blizzard/2011/lessac_blizzard2011/license.html\n";
+        let license = parse_model_card(card);
+        assert_eq!(license.datasets, ["https://www.cstr.ed.ac.uk/projects/blizzard/2011/lessac_blizzard2011/"]);
+        assert_eq!(license.licenses.len(), 1);
+        assert!(license.summary().starts_with("License: https://www.cstr.ed.ac.uk/"));
+        assert_eq!(parse_model_card("# Empty").summary(), "License: not stated");
+    }
+}
@@ -4,6 +4,8 @@
 
 pub mod aws;
 pub mod download;
+pub mod license;
+pub mod pack;
 
 use std::collections::HashMap;
 use tracing::debug;
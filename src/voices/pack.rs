@@ -0,0 +1,270 @@
+//! Voice packs for offline installs
+//!
+//! A voice pack is a tar archive (optionally gzip-compressed) holding Piper
+//! models (`.onnx` + `.onnx.json`) and a `manifest.json` listing each voice,
+//! its files with their MD5 checksums and, when known, its voices.json entry.
+//! Packs are exported from the voices installed on one machine and imported
+//! on machines that can't reach Hugging Face.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufReader, Read, Write};
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use tracing::{debug, info, warn};
+
+use super::download::get_model_directory;
+use crate::model::VoiceInfo;
+
+/// Name of the manifest inside a pack.
+const MANIFEST: &str = "manifest.json";
+
+/// Manifest format version written by this version.
+const MANIFEST_FORMAT: u32 = 1;
+
+/// Contents of a pack's `manifest.json`.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct PackManifest {
+    format: u32,
+    voices: Vec<PackVoice>,
+}
+
+/// One voice in a pack.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct PackVoice {
+    key: String,
+    /// File name → MD5 checksum
+    files: HashMap<String, String>,
+    /// The voice's voices.json entry, so it can be listed offline
+    #[serde(default)]
+    info: Option<VoiceInfo>,
+}
+
+/// Voices imported from a pack.
+#[derive(Debug, Clone)]
+pub struct ImportedPack {
+    /// Keys of the installed voices
+    pub keys: Vec<String>,
+    /// voices.json entries found in the manifest
+    pub infos: Vec<VoiceInfo>,
+}
+
+/// Whether `name` is a bare file name, safe to join onto the models folder.
+fn is_plain_file_name(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && !name.contains(['/', '\\'])
+}
+
+/// Keys of the voices installed in `dir` (both the model and its config present).
+fn installed_voice_keys(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut keys: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str()?.strip_suffix(".onnx").map(str::to_string))
+        .filter(|key| dir.join(format!("{key}.onnx.json")).is_file())
+        .collect();
+    keys.sort();
+    keys
+}
+
+/// Check a manifest: known format, plain file names, each voice with its model and config.
+fn validate_manifest(manifest: &PackManifest) -> Result<(), String> {
+    if manifest.format > MANIFEST_FORMAT {
+        return Err(format!(
+            "This voice pack was made by a newer version (format {}); please update Insight Reader",
+            manifest.format
+        ));
+    }
+    if manifest.voices.is_empty() {
+        return Err("The voice pack contains no voices".to_string());
+    }
+    for voice in &manifest.voices {
+        if !is_plain_file_name(&voice.key) {
+            return Err(format!("Invalid voice name in pack: {}", voice.key));
+        }
+        // A voice is its model and config, nothing else gets installed under its name
+        let expected = [format!("{}.onnx", voice.key), format!("{}.onnx.json", voice.key)];
+        for name in &expected {
+            if !voice.files.contains_key(name) {
+                return Err(format!("Voice {} is missing {name} in the pack manifest", voice.key));
+            }
+        }
+        if let Some(name) = voice.files.keys().find(|name| !expected.contains(name)) {
+            return Err(format!("Unexpected file {name} for voice {} in the pack manifest", voice.key));
+        }
+    }
+    Ok(())
+}
+
+/// Open a pack for reading, decompressing it if it is gzipped.
+fn open_archive(path: &Path) -> Result<tar::Archive<Box<dyn Read>>, String> {
+    let mut file = File::open(path).map_err(|e| format!("Could not open {}: {e}", path.display()))?;
+    let mut magic = [0u8; 2];
+    let read = file.read(&mut magic).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+    drop(file);
+    let file = BufReader::new(File::open(path).map_err(|e| format!("Could not open {}: {e}", path.display()))?);
+    let reader: Box<dyn Read> = match &magic[..read] {
+        [0x1f, 0x8b] => Box::new(GzDecoder::new(file)),
+        b"PK" => return Err("Zip voice packs are not supported; use a .tar or .tar.gz pack".to_string()),
+        _ => Box::new(file),
+    };
+    Ok(tar::Archive::new(reader))
+}
+
+/// Install the voices of the pack at `path` into the models folder.
+///
+/// Files are unpacked into a staging folder and checked against the manifest
+/// before anything is installed, so a damaged pack changes nothing.
+pub fn import_pack(path: &Path) -> Result<ImportedPack, String> {
+    info!(path = %path.display(), "Importing voice pack");
+    let models_dir = get_model_directory("")?;
+    let staging = models_dir.join(format!(".pack-import-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&staging);
+    fs::create_dir_all(&staging).map_err(|e| format!("Could not create {}: {e}", staging.display()))?;
+
+    let result = unpack_and_install(path, &staging, &models_dir);
+    let _ = fs::remove_dir_all(&staging);
+    result
+}
+
+fn unpack_and_install(path: &Path, staging: &Path, models_dir: &Path) -> Result<ImportedPack, String> {
+    let mut archive = open_archive(path)?;
+    let entries = archive.entries().map_err(|e| format!("Could not read the voice pack: {e}"))?;
+    for entry in entries {
+        let mut entry = entry.map_err(|e| format!("Could not read the voice pack: {e}"))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        // Files are looked up by name only; any folders in the archive are ignored
+        let entry_path = entry.path().map_err(|e| format!("Invalid path in voice pack: {e}"))?;
+        let Some(name) = entry_path.file_name().and_then(|name| name.to_str()).map(str::to_string) else {
+            continue;
+        };
+        let target = staging.join(&name);
+        let mut file = File::create(&target).map_err(|e| format!("Could not write {}: {e}", target.display()))?;
+        std::io::copy(&mut entry, &mut file).map_err(|e| format!("Could not unpack {name}: {e}"))?;
+    }
+
+    let manifest_text = fs::read_to_string(staging.join(MANIFEST))
+        .map_err(|_| format!("{} is not a voice pack (no {MANIFEST})", path.display()))?;
+    let manifest: PackManifest =
+        serde_json::from_str(&manifest_text).map_err(|e| format!("Invalid voice pack manifest: {e}"))?;
+    validate_manifest(&manifest)?;
+
+    for voice in &manifest.voices {
+        for (name, expected) in &voice.files {
+            let bytes = fs::read(staging.join(name)).map_err(|_| format!("{name} is missing from the voice pack"))?;
+            let computed = format!("{:x}", md5::compute(&bytes));
+            if !computed.eq_ignore_ascii_case(expected) {
+                return Err(format!("{name} is damaged (checksum mismatch)"));
+            }
+        }
+    }
+
+    fs::create_dir_all(models_dir).map_err(|e| format!("Could not create {}: {e}", models_dir.display()))?;
+    for voice in &manifest.voices {
+        // Same lock as downloads, in case the models folder is shared
+        let _lock = crate::storage::lock(models_dir, &voice.key)
+            .map_err(|e| format!("Could not lock {}: {e}", models_dir.display()))?;
+        for name in voice.files.keys() {
+            let target = models_dir.join(name);
+            // Rename within the models folder; copy if that fails (e.g. permissions on the old file)
+            fs::rename(staging.join(name), &target)
+                .or_else(|_| fs::read(staging.join(name)).and_then(|bytes| crate::storage::write_atomic(&target, &bytes)))
+                .map_err(|e| format!("Could not install {}: {e}", target.display()))?;
+        }
+        debug!(voice = %voice.key, "Voice installed from pack");
+    }
+
+    let keys: Vec<String> = manifest.voices.iter().map(|voice| voice.key.clone()).collect();
+    info!(voices = keys.len(), "Voice pack imported");
+    Ok(ImportedPack {
+        keys,
+        infos: manifest.voices.into_iter().filter_map(|voice| voice.info).collect(),
+    })
+}
+
+/// Write every installed voice into a pack at `path`; gzip-compressed if it ends in `.gz` or `.tgz`.
+///
+/// `known` is used to include each voice's voices.json entry. Returns the number of voices.
+pub fn export_pack(path: &Path, known: Option<&HashMap<String, VoiceInfo>>) -> Result<usize, String> {
+    info!(path = %path.display(), "Exporting voice pack");
+    let models_dir = get_model_directory("")?;
+    let keys = installed_voice_keys(&models_dir);
+    if keys.is_empty() {
+        return Err("No Piper voices are installed".to_string());
+    }
+
+    let mut voices = Vec::with_capacity(keys.len());
+    for key in &keys {
+        let mut files = HashMap::new();
+        for name in [format!("{key}.onnx"), format!("{key}.onnx.json")] {
+            let bytes = fs::read(models_dir.join(&name)).map_err(|e| format!("Could not read {name}: {e}"))?;
+            files.insert(name, format!("{:x}", md5::compute(&bytes)));
+        }
+        let info = known.and_then(|known| known.get(key)).cloned();
+        if info.is_none() {
+            warn!(voice = %key, "No voices.json entry for voice, exporting without it");
+        }
+        voices.push(PackVoice { key: key.clone(), files, info });
+    }
+    let manifest = PackManifest { format: MANIFEST_FORMAT, voices };
+    let manifest_json =
+        serde_json::to_vec_pretty(&manifest).map_err(|e| format!("Could not write the manifest: {e}"))?;
+
+    let file = File::create(path).map_err(|e| format!("Could not create {}: {e}", path.display()))?;
+    let name = path.to_string_lossy().to_ascii_lowercase();
+    let writer: Box<dyn Write> = if name.ends_with(".gz") || name.ends_with(".tgz") {
+        Box::new(GzEncoder::new(file, flate2::Compression::default()))
+    } else {
+        Box::new(file)
+    };
+    let mut builder = tar::Builder::new(writer);
+    let write_error = |e: std::io::Error| format!("Could not write {}: {e}", path.display());
+
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest_json.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(chrono::Utc::now().timestamp().max(0) as u64);
+    header.set_cksum();
+    builder.append_data(&mut header, MANIFEST, manifest_json.as_slice()).map_err(write_error)?;
+    for voice in &manifest.voices {
+        for name in voice.files.keys() {
+            builder.append_path_with_name(models_dir.join(name), name).map_err(write_error)?;
+        }
+    }
+    builder.into_inner().and_then(|mut writer| writer.flush()).map_err(write_error)?;
+
+    info!(voices = keys.len(), "Voice pack exported");
+    Ok(keys.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_validation() {
+        let voice = |key: &str, files: &[&str]| PackVoice {
+            key: key.to_string(),
+            files: files.iter().map(|name| (name.to_string(), String::new())).collect(),
+            info: None,
+        };
+        let manifest = |voices| PackManifest { format: MANIFEST_FORMAT, voices };
+
+        assert!(validate_manifest(&manifest(vec![voice("en_US-amy-low", &["en_US-amy-low.onnx", "en_US-amy-low.onnx.json"])])).is_ok());
+        assert!(validate_manifest(&manifest(vec![voice("en_US-amy-low", &["en_US-amy-low.onnx"])])).is_err());
+        assert!(validate_manifest(&manifest(vec![voice(
+            "en_US-amy-low",
+            &["en_US-amy-low.onnx", "en_US-amy-low.onnx.json", "piper_phonemize.so"]
+        )]))
+        .is_err());
+        assert!(validate_manifest(&manifest(vec![voice("../evil", &["../evil.onnx", "../evil.onnx.json"])])).is_err());
+        assert!(validate_manifest(&manifest(vec![])).is_err());
+        assert!(!is_plain_file_name("models/voice.onnx"));
+        assert!(is_plain_file_name("de_DE-thorsten-high.onnx.json"));
+    }
+}